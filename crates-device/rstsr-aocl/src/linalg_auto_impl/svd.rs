@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/svd.rs
\ No newline at end of file
@@ -1,4 +1,2 @@
-pub(crate) use crate::DeviceBLAS;
-pub(crate) use crate::DeviceRayonAutoImpl;
-pub(crate) use rstsr_aocl_ffi as lapack_ffi;
-pub use rstsr_core::prelude_dev::*;
+pub(crate) use crate::BlasBackend;
+pub use rstsr_blas_traits::device_blas::prelude_dev::*;
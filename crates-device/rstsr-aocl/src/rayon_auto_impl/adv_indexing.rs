@@ -1 +0,0 @@
-../../../../rstsr-core/src/feature_rayon/auto_impl/adv_indexing.rs
\ No newline at end of file
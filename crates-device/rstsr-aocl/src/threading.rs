@@ -1,7 +1,7 @@
 //! AOCL threading
 
 use crate::prelude_dev::*;
-use rstsr_blas_traits::prelude_dev::*;
+use rstsr_blas_traits::threading::with_num_threads_scoped;
 
 /* #region threading number control */
 
@@ -35,27 +35,23 @@ pub fn with_num_threads<F, R>(nthreads: usize, f: F) -> R
 where
     F: FnOnce() -> R,
 {
-    let n = get_num_threads();
-    set_num_threads(nthreads);
-    let r = f();
-    set_num_threads(n);
-    return r;
+    with_num_threads_scoped(nthreads, false, get_num_threads, set_num_threads, f)
 }
 
 /* #endregion */
 
 /* #region trait impl */
 
-impl BlasThreadAPI for DeviceBLAS {
-    fn get_blas_num_threads(&self) -> usize {
-        crate::threading::get_num_threads()
+impl CblasBackendAPI for BlasBackend {
+    fn set_num_threads(nthreads: usize) {
+        crate::threading::set_num_threads(nthreads);
     }
 
-    fn set_blas_num_threads(&self, nthreads: usize) {
-        crate::threading::set_num_threads(nthreads);
+    fn get_num_threads() -> usize {
+        crate::threading::get_num_threads()
     }
 
-    fn with_blas_num_threads<T>(&self, nthreads: usize, f: impl FnOnce() -> T) -> T {
+    fn with_num_threads<R>(nthreads: usize, f: impl FnOnce() -> R) -> R {
         crate::threading::with_num_threads(nthreads, f)
     }
 }
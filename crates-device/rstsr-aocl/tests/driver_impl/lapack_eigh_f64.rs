@@ -145,4 +145,40 @@ mod test {
         assert!((fingerprint(&w) - -2437.094304861363).abs() < 1e-8);
         assert!((fingerprint(&v.abs()) - 30.756098926747757).abs() < 1e-8);
     }
+
+    #[test]
+    fn test_subset_by_index_row_major() {
+        // row-major eigenvectors of a subset by index have leading dimension `iu - il + 1 < n`
+        let device = DeviceBLAS::default();
+        let a = rt::asarray((get_vec::<f64>('a'), [1024, 1024].c(), &device)).into_dim::<Ix2>();
+        let b = rt::asarray((get_vec::<f64>('b'), [1024, 1024].c(), &device)).into_dim::<Ix2>();
+        let (il, iu) = (3, 7);
+
+        let (w_ref, _) = DSYEV::default().a(a.view()).jobz('N').build().unwrap().run().unwrap();
+        let w_ref = w_ref.i(il - 1..iu);
+        for a in [a.view(), a.t()] {
+            let (w, v) = DSYEVR::default().a(a.view()).range('I').il(il).iu(iu).build().unwrap().run().unwrap();
+            let v = v.unwrap();
+            assert_eq!(v.shape(), &[1024, iu - il + 1]);
+            assert!((&w - &w_ref).l2_norm() < 1e-10);
+            assert!((&a % &v - &v * &w).l2_norm() < 1e-8);
+
+            let (w, v) = DSYEVX::default().a(a.view()).range('I').il(il).iu(iu).build().unwrap().run().unwrap();
+            let v = v.unwrap();
+            assert_eq!(v.shape(), &[1024, iu - il + 1]);
+            assert!((&w - &w_ref).l2_norm() < 1e-10);
+            assert!((&a % &v - &v * &w).l2_norm() < 1e-8);
+        }
+
+        let (w_ref, _) = DSYGV::default().a(a.view()).b(b.view()).jobz('N').build().unwrap().run().unwrap();
+        let w_ref = w_ref.i(il - 1..iu);
+        for (a, b) in [(a.view(), b.view()), (a.t(), b.t())] {
+            let (w, v) =
+                DSYGVX::default().a(a.view()).b(b.view()).range('I').il(il).iu(iu).build().unwrap().run().unwrap();
+            let v = v.unwrap();
+            assert_eq!(v.shape(), &[1024, iu - il + 1]);
+            assert!((&w - &w_ref).l2_norm() < 1e-10);
+            assert!((&a % &v - &b % &v * &w).l2_norm() < 1e-8);
+        }
+    }
 }
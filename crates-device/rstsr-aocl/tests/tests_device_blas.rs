@@ -0,0 +1,3 @@
+mod device_blas;
+
+pub use rstsr_aocl::AOCL as BackendType;
@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/cholesky.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../../../rstsr-core/src/feature_rayon/auto_impl/creation.rs
\ No newline at end of file
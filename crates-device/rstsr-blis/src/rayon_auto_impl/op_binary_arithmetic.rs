@@ -1 +0,0 @@
-../../../../rstsr-core/src/feature_rayon/auto_impl/op_binary_arithmetic.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../../../rstsr-sci-traits/src/integrate/auto_impl_rayon.rs
\ No newline at end of file
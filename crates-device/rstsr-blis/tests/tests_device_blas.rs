@@ -0,0 +1,3 @@
+mod device_blas;
+
+pub use rstsr_blis::BLIS as BackendType;
@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/inv.rs
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod device_blas;
+
+pub use rstsr_kml::KML as BackendType;
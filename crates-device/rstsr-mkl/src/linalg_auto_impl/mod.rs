@@ -1,12 +0,0 @@
-pub mod cholesky;
-pub mod det;
-pub mod eigh;
-pub mod eigvalsh;
-pub mod inv;
-pub mod pinv;
-pub mod slogdet;
-pub mod solve_general;
-pub mod solve_symmetric;
-pub mod solve_triangular;
-pub mod svd;
-pub mod svdvals;
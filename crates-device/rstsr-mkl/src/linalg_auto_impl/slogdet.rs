@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/slogdet.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/solve_general.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/solve_symmetric.rs
\ No newline at end of file
@@ -1,2 +0,0 @@
-pub mod distance_auto_impl;
-pub mod integrate_auto_impl;
@@ -2,28 +2,18 @@
 #![allow(non_camel_case_types)]
 #![doc = include_str!("../readme.md")]
 
-pub mod conversion;
-pub mod device;
-pub mod matmul;
-pub mod matmul_impl;
 pub mod prelude_dev;
-pub mod rayon_auto_impl;
 pub mod threading;
 
 pub mod driver_impl;
-#[cfg(feature = "linalg")]
-pub mod linalg_auto_impl;
 
-#[cfg(feature = "sci")]
-pub mod sci_auto_impl;
+pub use rstsr_blas_traits::device_blas::DeviceBLAS;
 
-use rstsr_core::prelude_dev::DeviceCpuRayon;
+/// OpenBLAS backend of [`DeviceBLAS`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OpenBLAS;
 
-#[derive(Clone, Debug)]
-pub struct DeviceOpenBLAS {
-    base: DeviceCpuRayon,
-}
+pub type DeviceOpenBLAS = DeviceBLAS<OpenBLAS>;
 
 pub(crate) use rstsr_openblas_ffi as lapack_ffi;
-pub(crate) use DeviceOpenBLAS as DeviceBLAS;
-pub(crate) use DeviceOpenBLAS as DeviceRayonAutoImpl;
+pub(crate) use OpenBLAS as BlasBackend;
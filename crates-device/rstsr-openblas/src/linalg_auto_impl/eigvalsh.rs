@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/eigvalsh.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../../../rstsr-linalg-traits/src/blas_impl/solve_triangular.rs
\ No newline at end of file
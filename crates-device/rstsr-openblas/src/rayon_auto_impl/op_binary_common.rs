@@ -1 +0,0 @@
-../../../../rstsr-core/src/feature_rayon/auto_impl/op_binary_common.rs
\ No newline at end of file
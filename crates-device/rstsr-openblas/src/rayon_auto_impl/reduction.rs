@@ -1 +0,0 @@
-../../../../rstsr-core/src/feature_rayon/auto_impl/reduction.rs
\ No newline at end of file
@@ -3,15 +3,11 @@
 use crate::prelude_dev::*;
 #[cfg(any(feature = "openmp", feature = "dynamic_loading"))]
 use core::ffi::c_int;
-use rstsr_blas_traits::prelude_dev::*;
+use rstsr_blas_traits::threading::with_num_threads_scoped;
 use std::sync::Mutex;
 
 use rstsr_openblas_ffi::cblas::{OPENBLAS_OPENMP, OPENBLAS_SEQUENTIAL, OPENBLAS_THREAD};
 
-/* #region required openmp ffi */
-
-/* #endregion */
-
 /* #region parallel scheme */
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -110,31 +106,39 @@ pub fn get_num_threads() -> usize {
     INTERNAL.lock().unwrap().get_num_threads()
 }
 
+/// Whether setting number of threads only affects the calling thread.
+///
+/// OpenMP-built OpenBLAS sets the per-thread ICV by `omp_set_num_threads`,
+/// while pthread-built OpenBLAS has a process-global setting.
+pub fn is_num_threads_local() -> bool {
+    INTERNAL.lock().unwrap().get_parallel() != OPENBLAS_THREAD
+}
+
 pub fn with_num_threads<F, R>(nthreads: usize, f: F) -> R
 where
     F: FnOnce() -> R,
 {
-    let n = get_num_threads();
-    set_num_threads(nthreads);
-    let r = f();
-    set_num_threads(n);
-    return r;
+    with_num_threads_scoped(nthreads, is_num_threads_local(), get_num_threads, set_num_threads, f)
 }
 
 /* #endregion */
 
 /* #region trait impl */
 
-impl BlasThreadAPI for DeviceBLAS {
-    fn get_blas_num_threads(&self) -> usize {
+impl CblasBackendAPI for BlasBackend {
+    fn set_num_threads(nthreads: usize) {
+        crate::threading::set_num_threads(nthreads);
+    }
+
+    fn get_num_threads() -> usize {
         crate::threading::get_num_threads()
     }
 
-    fn set_blas_num_threads(&self, nthreads: usize) {
-        crate::threading::set_num_threads(nthreads);
+    fn is_num_threads_local() -> bool {
+        crate::threading::is_num_threads_local()
     }
 
-    fn with_blas_num_threads<T>(&self, nthreads: usize, f: impl FnOnce() -> T) -> T {
+    fn with_num_threads<R>(nthreads: usize, f: impl FnOnce() -> R) -> R {
         crate::threading::with_num_threads(nthreads, f)
     }
 }
@@ -0,0 +1,3 @@
+mod device_blas;
+
+pub use rstsr_openblas::OpenBLAS as BackendType;
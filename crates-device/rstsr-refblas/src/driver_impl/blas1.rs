@@ -0,0 +1,71 @@
+//! Reference BLAS level 1 drivers.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use num::{Float, Zero};
+use rstsr_blas_traits::prelude::*;
+
+impl<T> AXPYDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_axpy(n: usize, alpha: T, x: *const T, incx: usize, y: *mut T, incy: usize) {
+        for i in 0..n {
+            *y.add(i * incy) = *y.add(i * incy) + alpha * *x.add(i * incx);
+        }
+    }
+}
+
+impl<T, const CONJ: bool> DOTDriverAPI<T, CONJ> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_dot(n: usize, x: *const T, incx: usize, y: *const T, incy: usize) -> T {
+        (0..n).fold(T::zero(), |acc, i| acc + cj::<T, CONJ>(*x.add(i * incx)) * *y.add(i * incy))
+    }
+}
+
+impl<T> NRM2DriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_nrm2(n: usize, x: *const T, incx: usize) -> T::Real {
+        // scale by the largest magnitude to avoid overflow
+        let scale = (0..n).fold(T::Real::zero(), |acc, i| acc.max(Float::abs((*x.add(i * incx)).abs())));
+        if scale == T::Real::zero() {
+            return scale;
+        }
+        let ssq = (0..n).fold(T::Real::zero(), |acc, i| acc + norm_sqr(*x.add(i * incx) / real::<T>(scale)));
+        scale * ssq.sqrt()
+    }
+}
+
+impl<T> SCALDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_scal(n: usize, alpha: T, x: *mut T, incx: usize) {
+        for i in 0..n {
+            *x.add(i * incx) = alpha * *x.add(i * incx);
+        }
+    }
+}
+
+impl<T> IAMAXDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_iamax(n: usize, x: *const T, incx: usize) -> usize {
+        // first index of the maximum |re| + |im|, 0-based
+        let mut idx = 0;
+        let mut vmax = T::Real::zero();
+        for i in 0..n {
+            let v = cabs1(*x.add(i * incx));
+            if i == 0 || v > vmax {
+                idx = i;
+                vmax = v;
+            }
+        }
+        idx
+    }
+}
@@ -0,0 +1,121 @@
+//! Reference BLAS level 2 drivers.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+impl<T> GEMVDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gemv(
+        order: FlagOrder,
+        trans: FlagTrans,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let (ny, nx) = match trans {
+            FlagTrans::N | FlagTrans::CN => (m, n),
+            FlagTrans::T | FlagTrans::C => (n, m),
+        };
+        for i in 0..ny {
+            let sum = (0..nx).fold(T::zero(), |acc, j| acc + a.get_op(i, j, trans) * *x.add(j * incx));
+            // y is not referenced when beta is zero
+            let yi = y.add(i * incy);
+            *yi = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *yi };
+        }
+    }
+}
+
+impl<T, const HERMI: bool> SYHEMVDriverAPI<T, HERMI> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_syhemv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        for i in 0..n {
+            let sum = (0..n).fold(T::zero(), |acc, j| acc + a.get_sym::<HERMI>(i, j, uplo) * *x.add(j * incx));
+            let yi = y.add(i * incy);
+            *yi = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * *yi };
+        }
+    }
+}
+
+impl<T> TRMVDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_trmv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        diag: FlagDiag,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        x: *mut T,
+        incx: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        // transpose flips the referenced triangle
+        let upper = match trans {
+            FlagTrans::N | FlagTrans::CN => uplo == FlagUpLo::U,
+            FlagTrans::T | FlagTrans::C => uplo == FlagUpLo::L,
+        };
+        let xs = (0..n).map(|j| *x.add(j * incx)).collect::<Vec<T>>();
+        for i in 0..n {
+            let diag_val = if diag == FlagDiag::U { T::one() } else { a.get_op(i, i, trans) };
+            let cols = if upper { (i + 1)..n } else { 0..i };
+            let sum = cols.fold(diag_val * xs[i], |acc, j| acc + a.get_op(i, j, trans) * xs[j]);
+            *x.add(i * incx) = sum;
+        }
+    }
+}
+
+impl<T, const CONJ: bool> GERDriverAPI<T, CONJ> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_ger(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: usize,
+        y: *const T,
+        incy: usize,
+        a: *mut T,
+        lda: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        for j in 0..n {
+            let yj = alpha * cj::<T, CONJ>(*y.add(j * incy));
+            for i in 0..m {
+                a.set(i, j, a.get(i, j) + *x.add(i * incx) * yj);
+            }
+        }
+    }
+}
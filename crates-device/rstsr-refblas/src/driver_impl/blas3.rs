@@ -0,0 +1,352 @@
+//! Reference BLAS level 3 drivers.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use num::Zero;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+/// Read `op(A)` of triangular matrix `A` as dense matrix.
+///
+/// Also returns whether `op(A)` is upper triangular, since transpose flips the
+/// triangle.
+unsafe fn read_tri_op<T>(a: MatPtr<T>, na: usize, uplo: FlagUpLo, transa: FlagTrans, diag: FlagDiag) -> (Mat<T>, bool)
+where
+    T: BlasFloat + Copy,
+{
+    let upper = match transa {
+        FlagTrans::N | FlagTrans::CN => uplo == FlagUpLo::U,
+        FlagTrans::T | FlagTrans::C => uplo == FlagUpLo::L,
+    };
+    let mut t = Mat::<T>::zeros(na, na);
+    for j in 0..na {
+        for i in 0..na {
+            if i == j {
+                t[(i, i)] = if diag == FlagDiag::U { T::one() } else { a.get_op(i, i, transa) };
+            } else if (i < j) == upper {
+                t[(i, j)] = a.get_op(i, j, transa);
+            }
+        }
+    }
+    (t, upper)
+}
+
+impl<T> GEMMDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gemm(
+        order: FlagOrder,
+        transa: FlagTrans,
+        transb: FlagTrans,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            for i in 0..m {
+                let sum = (0..k).fold(T::zero(), |acc, l| acc + a.get_op(i, l, transa) * b.get_op(l, j, transb));
+                // C is not referenced when beta is zero
+                let val = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * c.get(i, j) };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T> SYRKDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_syrk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            // only the `uplo` triangle of C is referenced and updated
+            let rows = match uplo {
+                FlagUpLo::U => 0..(j + 1),
+                FlagUpLo::L => j..n,
+            };
+            for i in rows {
+                let sum = (0..k).fold(T::zero(), |acc, l| acc + a.get_op(i, l, trans) * a.get_op(j, l, trans));
+                let val = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * c.get(i, j) };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T> HERKDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_herk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T::Real,
+        a: *const T,
+        lda: usize,
+        beta: T::Real,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            let rows = match uplo {
+                FlagUpLo::U => 0..(j + 1),
+                FlagUpLo::L => j..n,
+            };
+            for i in rows {
+                let sum = (0..k).fold(T::zero(), |acc, l| acc + a.get_op(i, l, trans) * a.get_op(j, l, trans).conj());
+                let val = sum * alpha;
+                let val = if beta == T::Real::zero() { val } else { val + c.get(i, j) * beta };
+                // imaginary part of diagonal is set to zero
+                let val = if i == j { real(val.re()) } else { val };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T> SYR2KDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_syr2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            let rows = match uplo {
+                FlagUpLo::U => 0..(j + 1),
+                FlagUpLo::L => j..n,
+            };
+            for i in rows {
+                let sum = (0..k).fold(T::zero(), |acc, l| {
+                    acc + a.get_op(i, l, trans) * b.get_op(j, l, trans) + b.get_op(i, l, trans) * a.get_op(j, l, trans)
+                });
+                let val = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * c.get(i, j) };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T> HER2KDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_her2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T::Real,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            let rows = match uplo {
+                FlagUpLo::U => 0..(j + 1),
+                FlagUpLo::L => j..n,
+            };
+            for i in rows {
+                let (sum_ab, sum_ba) = (0..k).fold((T::zero(), T::zero()), |(ab, ba), l| {
+                    (
+                        ab + a.get_op(i, l, trans) * b.get_op(j, l, trans).conj(),
+                        ba + b.get_op(i, l, trans) * a.get_op(j, l, trans).conj(),
+                    )
+                });
+                let val = alpha * sum_ab + alpha.conj() * sum_ba;
+                let val = if beta == T::Real::zero() { val } else { val + c.get(i, j) * beta };
+                // imaginary part of diagonal is set to zero
+                let val = if i == j { real(val.re()) } else { val };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T, const HERMI: bool> SYHEMMDriverAPI<T, HERMI> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_syhemm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let c = MatPtr::new(c, ldc, order);
+        for j in 0..n {
+            for i in 0..m {
+                let sum = match side {
+                    FlagSide::L => (0..m).fold(T::zero(), |acc, l| acc + a.get_sym::<HERMI>(i, l, uplo) * b.get(l, j)),
+                    FlagSide::R => (0..n).fold(T::zero(), |acc, l| acc + b.get(i, l) * a.get_sym::<HERMI>(l, j, uplo)),
+                };
+                let val = if beta == T::zero() { alpha * sum } else { alpha * sum + beta * c.get(i, j) };
+                c.set(i, j, val);
+            }
+        }
+    }
+}
+
+impl<T> TRSMDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_trsm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let na = match side {
+            FlagSide::L => m,
+            FlagSide::R => n,
+        };
+        let (t, upper) = read_tri_op(a, na, uplo, transa, diag);
+
+        match side {
+            // op(A) X = alpha B, column by column
+            FlagSide::L => {
+                for j in 0..n {
+                    let rows: Vec<usize> = if upper { (0..m).rev().collect() } else { (0..m).collect() };
+                    for &i in rows.iter() {
+                        let mut x = alpha * b.get(i, j);
+                        for l in 0..m {
+                            if l != i && (l > i) == upper {
+                                x = x - t[(i, l)] * b.get(l, j);
+                            }
+                        }
+                        b.set(i, j, x / t[(i, i)]);
+                    }
+                }
+            },
+            // X op(A) = alpha B, row by row
+            FlagSide::R => {
+                for i in 0..m {
+                    let cols: Vec<usize> = if upper { (0..n).collect() } else { (0..n).rev().collect() };
+                    for &j in cols.iter() {
+                        let mut x = alpha * b.get(i, j);
+                        for l in 0..n {
+                            if l != j && (l < j) == upper {
+                                x = x - b.get(i, l) * t[(l, j)];
+                            }
+                        }
+                        b.set(i, j, x / t[(j, j)]);
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl<T> TRMMDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_trmm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let na = match side {
+            FlagSide::L => m,
+            FlagSide::R => n,
+        };
+        let (t, _) = read_tri_op(a, na, uplo, transa, diag);
+        let b_old = Mat::read(b, m, n);
+        let prod = match side {
+            FlagSide::L => t.matmul(&b_old),
+            FlagSide::R => b_old.matmul(&t),
+        };
+        for j in 0..n {
+            for i in 0..m {
+                b.set(i, j, alpha * prod[(i, j)]);
+            }
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Reference LAPACK drivers for hermitian eigenvalue problems.
+//!
+//! Both the simple (`xSYEV`) and divide-and-conquer (`xSYEVD`) drivers are
+//! served by the cyclic Jacobi method, which is slow but accurate. Eigenvalues
+//! are returned in ascending order, same to LAPACK.
+//!
+//! The subset drivers (`xSYEVR`, `xSYEVX`, `xSYGVX`) still solve the full
+//! spectrum, and then pick the requested eigenpairs.
+
+use crate::driver_impl::lapack_solve::potf2;
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use core::ops::Range;
+use duplicate::duplicate_item;
+use num::Zero;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+/// Diagonalize hermitian `a` by Jacobi method, and write sorted eigenvalues
+/// to `w`. Returns eigenvectors (as columns), or `None` if not converged.
+unsafe fn eigh_full<T>(mut a: Mat<T>, w: *mut T::Real) -> Option<Mat<T>>
+where
+    T: BlasFloat + Copy,
+{
+    let n = a.nrow;
+    let mut v = Mat::<T>::eye(n);
+    if !jacobi_eigh(&mut a, &mut v) {
+        return None;
+    }
+    let mut idx: Vec<usize> = (0..n).collect();
+    idx.sort_by(|&i, &j| a[(i, i)].re().partial_cmp(&a[(j, j)].re()).unwrap());
+    let mut vecs = Mat::<T>::zeros(n, n);
+    for (jnew, &jold) in idx.iter().enumerate() {
+        *w.add(jnew) = a[(jold, jold)].re();
+        (0..n).for_each(|i| vecs[(i, jnew)] = v[(i, jold)]);
+    }
+    Some(vecs)
+}
+
+/// Indices of sorted eigenvalues `w` selected by LAPACK's `range` argument,
+/// or `None` if `range` is not recognized.
+fn select_range<R: PartialOrd + Copy>(
+    w: &[R],
+    range: char,
+    vl: R,
+    vu: R,
+    il: usize,
+    iu: usize,
+) -> Option<Range<usize>> {
+    match range.to_ascii_uppercase() {
+        'A' => Some(0..w.len()),
+        // half-open interval (vl, vu], same to LAPACK
+        'V' => Some(w.partition_point(|&x| x <= vl)..w.partition_point(|&x| x <= vu)),
+        'I' => Some(il.saturating_sub(1)..iu.min(w.len())),
+        _ => None,
+    }
+}
+
+/// Write selected eigenpairs to `w` and `z` (if `jobz = 'V'`), returns number
+/// of eigenvalues found.
+unsafe fn write_subset<T>(
+    jobz: char,
+    idx: Range<usize>,
+    w_full: &[T::Real],
+    vecs: &Mat<T>,
+    w: *mut T::Real,
+    z: MatPtr<T>,
+) -> usize
+where
+    T: BlasFloat + Copy,
+{
+    let n = vecs.nrow;
+    for (jnew, jold) in idx.clone().enumerate() {
+        *w.add(jnew) = w_full[jold];
+        if jobz.eq_ignore_ascii_case(&'V') {
+            (0..n).for_each(|i| z.set(i, jnew, vecs[(i, jold)]));
+        }
+    }
+    idx.len()
+}
+
+#[duplicate_item(
+    DriverAPI         driver_func   ;
+   [SYEVDriverAPI ] [driver_syev ];
+   [SYEVDDriverAPI] [driver_syevd];
+)]
+impl<T> DriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_func(
+        order: FlagOrder,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let mat = Mat::read_sym::<true>(a, n, uplo);
+        let Some(vecs) = eigh_full(mat, w) else {
+            return n as blas_int;
+        };
+        if jobz.eq_ignore_ascii_case(&'V') {
+            vecs.write(a, n, n);
+        }
+        0
+    }
+}
+
+/// Generalized problem by Cholesky reduction to the standard problem.
+/// Returns back-transformed eigenvectors, or the LAPACK error code.
+unsafe fn sygv_full<T>(
+    itype: blas_int,
+    uplo: FlagUpLo,
+    n: usize,
+    a: MatPtr<T>,
+    b: MatPtr<T>,
+    w: *mut T::Real,
+) -> core::result::Result<Mat<T>, blas_int>
+where
+    T: BlasFloat + Copy,
+{
+    // Cholesky factorization B = L L^H (or U^H U with U = L^H)
+    let info = potf2(uplo, n, b);
+    if info != 0 {
+        return Err(n as blas_int + info);
+    }
+    let mut l = Mat::<T>::zeros(n, n);
+    for j in 0..n {
+        for i in j..n {
+            l[(i, j)] = match uplo {
+                FlagUpLo::L => b.get(i, j),
+                FlagUpLo::U => b.get(j, i).conj(),
+            };
+        }
+    }
+
+    // reduce to standard problem
+    let mat = Mat::read_sym::<true>(a, n, uplo);
+    let mat = match itype {
+        1 => {
+            // inv(L) A inv(L)^H
+            let mut x = mat;
+            l.solve_lower(&mut x, false);
+            let mut x = x.adjoint();
+            l.solve_lower(&mut x, false);
+            x
+        },
+        _ => {
+            // L^H A L
+            l.adjoint().matmul(&mat).matmul(&l)
+        },
+    };
+
+    let Some(mut vecs) = eigh_full(mat, w) else {
+        return Err(n as blas_int);
+    };
+
+    // back-transform eigenvectors
+    match itype {
+        1 | 2 => l.solve_lower(&mut vecs, true),
+        _ => vecs = l.matmul(&vecs),
+    }
+    Ok(vecs)
+}
+
+#[duplicate_item(
+    DriverAPI         driver_func   ;
+   [SYGVDriverAPI ] [driver_sygv ];
+   [SYGVDDriverAPI] [driver_sygvd];
+)]
+impl<T> DriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_func(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        if !(1..=3).contains(&itype) {
+            return -2;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let vecs = match sygv_full(itype, uplo, n, a, b, w) {
+            Ok(vecs) => vecs,
+            Err(info) => return info,
+        };
+        if jobz.eq_ignore_ascii_case(&'V') {
+            vecs.write(a, n, n);
+        }
+        0
+    }
+}
+
+#[duplicate_item(
+    DriverAPI         driver_func    aux       fill_aux(i)                                                         ;
+   [SYEVRDriverAPI] [driver_syevr] [isuppz] [*isuppz.add(2 * i) = 1; *isuppz.add(2 * i + 1) = n as blas_int];
+   [SYEVXDriverAPI] [driver_syevx] [ifail ] [*ifail.add(i) = 0                                              ];
+)]
+impl<T> DriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_func(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        _abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        aux: *mut blas_int,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let mat = Mat::read_sym::<true>(a, n, uplo);
+        let mut w_full = vec![T::Real::zero(); n];
+        let Some(vecs) = eigh_full(mat, w_full.as_mut_ptr()) else {
+            return n as blas_int;
+        };
+        let Some(idx) = select_range(&w_full, range, vl, vu, il, iu) else {
+            return -2;
+        };
+        // support of eigenvectors is the whole vector, and none failed to converge
+        for i in 0..idx.len() {
+            fill_aux([i]);
+        }
+        *m = write_subset(jobz, idx, &w_full, &vecs, w, MatPtr::new(z, ldz, order));
+        0
+    }
+}
+
+impl<T> SYGVXDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_sygvx(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        _abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        ifail: *mut blas_int,
+    ) -> blas_int {
+        if !(1..=3).contains(&itype) {
+            return -1;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let mut w_full = vec![T::Real::zero(); n];
+        let vecs = match sygv_full(itype, uplo, n, a, b, w_full.as_mut_ptr()) {
+            Ok(vecs) => vecs,
+            Err(info) => return info,
+        };
+        let Some(idx) = select_range(&w_full, range, vl, vu, il, iu) else {
+            return -3;
+        };
+        (0..idx.len()).for_each(|i| *ifail.add(i) = 0);
+        *m = write_subset(jobz, idx, &w_full, &vecs, w, MatPtr::new(z, ldz, order));
+        0
+    }
+}
@@ -0,0 +1,215 @@
+//! Reference LAPACK drivers for Schur decompositions.
+//!
+//! The kernels (Hessenberg reduction, QR and QZ iterations, reordering and
+//! quasi-triangular Sylvester solver) are shared with other devices, see
+//! `rstsr_native_impl::cpu_serial::lapack_schur`. Drivers here only copy
+//! matrices to and from col-major work arrays.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use num::One;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+use rstsr_native_impl::prelude_dev::*;
+
+impl<T> GEHRDDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gehrd(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *mut T,
+    ) -> blas_int {
+        if ilo < 1 || ilo > n.max(1) {
+            return -3;
+        }
+        if ihi < ilo.min(n) || ihi > n {
+            return -4;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let mut h = Mat::read(a, n, n);
+        let mut t = vec![T::zero(); n.saturating_sub(1)];
+        gehrd_cpu_serial(&mut h.data, n, ilo - 1, ihi, &mut t);
+        h.write(a, n, n);
+        t.iter().enumerate().for_each(|(k, &tk)| *tau.add(k) = tk);
+        0
+    }
+}
+
+impl<T> ORGHRDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        if ilo < 1 || ilo > n.max(1) {
+            return -2;
+        }
+        if ihi < ilo.min(n) || ihi > n {
+            return -3;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let mut h = Mat::read(a, n, n);
+        let t: Vec<T> = (0..n.saturating_sub(1)).map(|k| *tau.add(k)).collect();
+        orghr_cpu_serial(&mut h.data, n, ilo - 1, ihi, &t);
+        h.write(a, n, n);
+        0
+    }
+}
+
+impl<T> GEESDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let mut t = Mat::read(a, n, n);
+        let (z, info) = gees_cpu_serial(&mut t.data, n);
+        if info != 0 {
+            return info as blas_int;
+        }
+        t.write(a, n, n);
+        let wr = core::slice::from_raw_parts_mut(wr, n);
+        let wi = core::slice::from_raw_parts_mut(wi, n);
+        schur_eigvals_cpu_serial(&t.data, n, wr, wi);
+        if jobvs.eq_ignore_ascii_case(&'V') {
+            Mat { data: z, nrow: n, ncol: n }.write(MatPtr::new(vs, ldvs, order), n, n);
+        }
+        0
+    }
+}
+
+impl<T> GGESDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut T::Real,
+        alphai: *mut T::Real,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let b = MatPtr::new(b, ldb, order);
+        let mut s = Mat::read(a, n, n);
+        let mut t = Mat::read(b, n, n);
+        let alphar = core::slice::from_raw_parts_mut(alphar, n);
+        let alphai = core::slice::from_raw_parts_mut(alphai, n);
+        let beta = core::slice::from_raw_parts_mut(beta, n);
+        let (q, z, info) = gges_cpu_serial(&mut s.data, &mut t.data, n, alphar, alphai, beta);
+        if info != 0 {
+            return info as blas_int;
+        }
+        s.write(a, n, n);
+        t.write(b, n, n);
+        if jobvsl.eq_ignore_ascii_case(&'V') {
+            Mat { data: q, nrow: n, ncol: n }.write(MatPtr::new(vsl, ldvsl, order), n, n);
+        }
+        if jobvsr.eq_ignore_ascii_case(&'V') {
+            Mat { data: z, nrow: n, ncol: n }.write(MatPtr::new(vsr, ldvsr, order), n, n);
+        }
+        0
+    }
+}
+
+impl<T> TRSENDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        m: &mut usize,
+    ) -> blas_int {
+        let wantq = compq.eq_ignore_ascii_case(&'V');
+        let t_ptr = MatPtr::new(t, ldt, order);
+        let q_ptr = MatPtr::new(q, ldq, order);
+        let mut tm = Mat::read(t_ptr, n, n);
+        let mut qm = if wantq { Mat::read(q_ptr, n, n) } else { Mat::eye(n) };
+        let select: Vec<bool> = (0..n).map(|i| *select.add(i) != 0).collect();
+        *m = trsen_cpu_serial(&mut tm.data, &mut qm.data, n, &select);
+        tm.write(t_ptr, n, n);
+        if wantq {
+            qm.write(q_ptr, n, n);
+        }
+        let wr = core::slice::from_raw_parts_mut(wr, n);
+        let wi = core::slice::from_raw_parts_mut(wi, n);
+        schur_eigvals_cpu_serial(&tm.data, n, wr, wi);
+        0
+    }
+}
+
+impl<T> TRSYLDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut T::Real,
+    ) -> blas_int {
+        if isgn != 1 && isgn != -1 {
+            return -3;
+        }
+        let am = Mat::read(MatPtr::new(a, lda, order), m, m);
+        let bm = Mat::read(MatPtr::new(b, ldb, order), n, n);
+        let c = MatPtr::new(c, ldc, order);
+        let mut cm = Mat::read(c, m, n);
+        let sgn: T = real(rcast(isgn as f64));
+        let info = trsyl_cpu_serial(trana, tranb, sgn, m, n, &am.data, &bm.data, &mut cm.data);
+        cm.write(c, m, n);
+        *scale = T::Real::one();
+        info as blas_int
+    }
+}
@@ -0,0 +1,661 @@
+//! Reference LAPACK drivers for linear equations.
+//!
+//! Factorizations follow the unblocked LAPACK algorithms (`xGETF2`, `xPOTF2`,
+//! `xSYTF2`/`xHETF2`), so that the factors and pivots have the same meaning as
+//! those from LAPACK: `ipiv` is 1-based, and for symmetric indefinite
+//! factorization, negative `ipiv` marks 2x2 diagonal blocks.
+//!
+//! QR factorization (`xGEQRF`, `xORGQR`) uses the Householder kernels shared
+//! with Schur decompositions in `rstsr_native_impl::cpu_serial::lapack_schur`.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use num::traits::Float;
+use num::{One, Zero};
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+use rstsr_native_impl::prelude_dev::*;
+
+/* #region LU */
+
+/// LU factorization with partial pivoting (`xGETF2`).
+unsafe fn getf2<T>(m: usize, n: usize, a: MatPtr<T>, ipiv: *mut blas_int) -> blas_int
+where
+    T: BlasFloat + Copy,
+{
+    let mut info = 0;
+    for j in 0..m.min(n) {
+        // find pivot
+        let mut jp = j;
+        let mut vmax = cabs1(a.get(j, j));
+        for i in j + 1..m {
+            let v = cabs1(a.get(i, j));
+            if v > vmax {
+                jp = i;
+                vmax = v;
+            }
+        }
+        *ipiv.add(j) = (jp + 1) as blas_int;
+        if a.get(jp, j) != T::zero() {
+            if jp != j {
+                (0..n).for_each(|l| a.swap((j, l), (jp, l)));
+            }
+            let ajj = a.get(j, j);
+            (j + 1..m).for_each(|i| a.set(i, j, a.get(i, j) / ajj));
+        } else if info == 0 {
+            info = (j + 1) as blas_int;
+        }
+        // rank-1 update of trailing submatrix
+        for l in j + 1..n {
+            let ajl = a.get(j, l);
+            if ajl != T::zero() {
+                (j + 1..m).for_each(|i| a.set(i, l, a.get(i, l) - a.get(i, j) * ajl));
+            }
+        }
+    }
+    info
+}
+
+/// Solve `A X = B` by LU factors of `A` (`xGETRS`, no transpose).
+unsafe fn getrs<T>(n: usize, nrhs: usize, a: MatPtr<T>, ipiv: *const blas_int, b: MatPtr<T>)
+where
+    T: BlasFloat + Copy,
+{
+    for i in 0..n {
+        let ip = *ipiv.add(i) as usize - 1;
+        if ip != i {
+            (0..nrhs).for_each(|j| b.swap((i, j), (ip, j)));
+        }
+    }
+    for j in 0..nrhs {
+        // L y = b, unit diagonal
+        for i in 0..n {
+            let x = (0..i).fold(b.get(i, j), |acc, l| acc - a.get(i, l) * b.get(l, j));
+            b.set(i, j, x);
+        }
+        // U x = y
+        for i in (0..n).rev() {
+            let x = (i + 1..n).fold(b.get(i, j), |acc, l| acc - a.get(i, l) * b.get(l, j));
+            b.set(i, j, x / a.get(i, i));
+        }
+    }
+}
+
+impl<T> GETRFDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_getrf(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+    ) -> blas_int {
+        getf2(m, n, MatPtr::new(a, lda, order), ipiv)
+    }
+}
+
+impl<T> GETRIDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        if let Some(i) = (0..n).find(|&i| a.get(i, i) == T::zero()) {
+            return (i + 1) as blas_int;
+        }
+        let mut inv = Mat::<T>::eye(n);
+        getrs(n, n, a, ipiv, MatPtr::new(inv.data.as_mut_ptr(), n.max(1), ColMajor));
+        inv.write(a, n, n);
+        0
+    }
+}
+
+impl<T> GESVDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gesv(
+        order: FlagOrder,
+        n: usize,
+        nrhs: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+        b: *mut T,
+        ldb: usize,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let info = getf2(n, n, a, ipiv);
+        if info != 0 {
+            return info;
+        }
+        getrs(n, nrhs, a, ipiv, MatPtr::new(b, ldb, order));
+        0
+    }
+}
+
+/* #endregion */
+
+/* #region Cholesky */
+
+/// Cholesky factorization (`xPOTF2`), only the `uplo` triangle is referenced.
+pub(crate) unsafe fn potf2<T>(uplo: FlagUpLo, n: usize, a: MatPtr<T>) -> blas_int
+where
+    T: BlasFloat + Copy,
+{
+    // upper factor is stored as the adjoint of lower factor
+    let get = |i: usize, j: usize| match uplo {
+        FlagUpLo::L => a.get(i, j),
+        FlagUpLo::U => a.get(j, i).conj(),
+    };
+    let set = |i: usize, j: usize, val: T| match uplo {
+        FlagUpLo::L => a.set(i, j, val),
+        FlagUpLo::U => a.set(j, i, val.conj()),
+    };
+    for j in 0..n {
+        let s = (0..j).fold(get(j, j).re(), |acc, k| acc - norm_sqr(get(j, k)));
+        if s <= T::Real::zero() || Float::is_nan(s) {
+            set(j, j, real(s));
+            return (j + 1) as blas_int;
+        }
+        let d = Float::sqrt(s);
+        set(j, j, real(d));
+        for i in j + 1..n {
+            let x = (0..j).fold(get(i, j), |acc, k| acc - get(i, k) * get(j, k).conj());
+            set(i, j, x / d);
+        }
+    }
+    0
+}
+
+impl<T> POTRFDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
+        potf2(uplo, n, MatPtr::new(a, lda, order))
+    }
+}
+
+/* #endregion */
+
+/* #region QR */
+
+impl<T> GEQRFDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_geqrf(order: FlagOrder, m: usize, n: usize, a: *mut T, lda: usize, tau: *mut T) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let mut r = Mat::read(a, m, n);
+        let mut t = vec![T::zero(); m.min(n)];
+        geqrf_cpu_serial(&mut r.data, m, n, &mut t);
+        r.write(a, m, n);
+        t.iter().enumerate().for_each(|(k, &tk)| *tau.add(k) = tk);
+        0
+    }
+}
+
+impl<T> ORGQRDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_orgqr(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        k: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        if n > m {
+            return -2;
+        }
+        if k > n {
+            return -3;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let mut q = Mat::read(a, m, n);
+        let t: Vec<T> = (0..k).map(|i| *tau.add(i)).collect();
+        orgqr_cpu_serial(&mut q.data, m, n, k, &t);
+        q.write(a, m, n);
+        0
+    }
+}
+
+/* #endregion */
+
+/* #region symmetric indefinite */
+
+/// Position and value of maximum `cabs1` of `a(i, j)` over `idx`.
+unsafe fn iamax<T>(idx: impl Iterator<Item = (usize, usize)>, a: MatPtr<T>) -> Option<((usize, usize), T::Real)>
+where
+    T: BlasFloat + Copy,
+{
+    let mut result: Option<((usize, usize), T::Real)> = None;
+    for (i, j) in idx {
+        let v = cabs1(a.get(i, j));
+        if result.is_none_or(|(_, vmax)| v > vmax) {
+            result = Some(((i, j), v));
+        }
+    }
+    result
+}
+
+/// Bunch-Kaufman diagonal pivoting factorization (`xSYTF2`, or `xHETF2` if
+/// `HERMI`).
+unsafe fn sytf2<T, const HERMI: bool>(uplo: FlagUpLo, n: usize, a: MatPtr<T>, ipiv: *mut blas_int) -> blas_int
+where
+    T: BlasFloat + Copy,
+{
+    let zero = T::Real::zero();
+    let one = T::Real::one();
+    let alpha = (one + Float::sqrt(rcast::<T::Real>(17.0))) / rcast::<T::Real>(8.0);
+    let absdiag = |x: T| if HERMI { Float::abs(x.re()) } else { cabs1(x) };
+    let setreal = |i: usize| {
+        if HERMI {
+            a.set(i, i, real(a.get(i, i).re()))
+        }
+    };
+    let mut info = 0;
+
+    match uplo {
+        FlagUpLo::L => {
+            let mut k = 0;
+            while k < n {
+                let mut kstep = 1;
+                let absakk = absdiag(a.get(k, k));
+                let (imax, colmax) = match iamax((k + 1..n).map(|i| (i, k)), a) {
+                    Some(((imax, _), colmax)) => (imax, colmax),
+                    None => (k, zero),
+                };
+                let kp;
+                if Float::max(absakk, colmax) == zero || Float::is_nan(absakk) {
+                    // column is zero: set info and continue
+                    if info == 0 {
+                        info = (k + 1) as blas_int;
+                    }
+                    kp = k;
+                    setreal(k);
+                } else {
+                    if absakk >= alpha * colmax {
+                        kp = k;
+                    } else {
+                        let idx = (k..imax).map(|j| (imax, j)).chain((imax + 1..n).map(|j| (j, imax)));
+                        let rowmax = iamax(idx, a).map_or(zero, |(_, v)| v);
+                        if absakk >= alpha * colmax * (colmax / rowmax) {
+                            kp = k;
+                        } else if absdiag(a.get(imax, imax)) >= alpha * rowmax {
+                            kp = imax;
+                        } else {
+                            kp = imax;
+                            kstep = 2;
+                        }
+                    }
+
+                    // interchange rows and columns kk and kp in trailing submatrix
+                    let kk = k + kstep - 1;
+                    if kp != kk {
+                        (kp + 1..n).for_each(|i| a.swap((i, kk), (i, kp)));
+                        for j in kk + 1..kp {
+                            let t = cj::<T, HERMI>(a.get(j, kk));
+                            a.set(j, kk, cj::<T, HERMI>(a.get(kp, j)));
+                            a.set(kp, j, t);
+                        }
+                        if HERMI {
+                            a.set(kp, kk, a.get(kp, kk).conj());
+                        }
+                        a.swap((kk, kk), (kp, kp));
+                        setreal(kk);
+                        setreal(kp);
+                        if kstep == 2 {
+                            setreal(k);
+                            a.swap((k + 1, k), (kp, k));
+                        }
+                    } else {
+                        setreal(k);
+                        if kstep == 2 {
+                            setreal(k + 1);
+                        }
+                    }
+
+                    // update trailing submatrix
+                    if kstep == 1 {
+                        let d11 = T::one() / a.get(k, k);
+                        for j in k + 1..n {
+                            let temp = -(d11 * cj::<T, HERMI>(a.get(j, k)));
+                            (j..n).for_each(|i| a.set(i, j, a.get(i, j) + a.get(i, k) * temp));
+                            setreal(j);
+                        }
+                        (k + 1..n).for_each(|i| a.set(i, k, a.get(i, k) * d11));
+                    } else if k + 2 < n {
+                        if HERMI {
+                            let d = a.get(k + 1, k).abs();
+                            let d11 = a.get(k + 1, k + 1).re() / d;
+                            let d22 = a.get(k, k).re() / d;
+                            let tt = one / (d11 * d22 - one);
+                            let d21 = a.get(k + 1, k) / d;
+                            let d = tt / d;
+                            for j in k + 2..n {
+                                let wk = (a.get(j, k) * d11 - d21 * a.get(j, k + 1)) * d;
+                                let wkp1 = (a.get(j, k + 1) * d22 - d21.conj() * a.get(j, k)) * d;
+                                for i in j..n {
+                                    let val = a.get(i, j) - a.get(i, k) * wk.conj() - a.get(i, k + 1) * wkp1.conj();
+                                    a.set(i, j, val);
+                                }
+                                a.set(j, k, wk);
+                                a.set(j, k + 1, wkp1);
+                                setreal(j);
+                            }
+                        } else {
+                            let d21 = a.get(k + 1, k);
+                            let d11 = a.get(k + 1, k + 1) / d21;
+                            let d22 = a.get(k, k) / d21;
+                            let t = T::one() / (d11 * d22 - T::one());
+                            let d21 = t / d21;
+                            for j in k + 2..n {
+                                let wk = d21 * (d11 * a.get(j, k) - a.get(j, k + 1));
+                                let wkp1 = d21 * (d22 * a.get(j, k + 1) - a.get(j, k));
+                                for i in j..n {
+                                    let val = a.get(i, j) - a.get(i, k) * wk - a.get(i, k + 1) * wkp1;
+                                    a.set(i, j, val);
+                                }
+                                a.set(j, k, wk);
+                                a.set(j, k + 1, wkp1);
+                            }
+                        }
+                    }
+                }
+
+                // store pivot indices
+                if kstep == 1 {
+                    *ipiv.add(k) = (kp + 1) as blas_int;
+                } else {
+                    *ipiv.add(k) = -((kp + 1) as blas_int);
+                    *ipiv.add(k + 1) = -((kp + 1) as blas_int);
+                }
+                k += kstep;
+            }
+        },
+        FlagUpLo::U => {
+            // `k` counts columns left to be factorized; current column is `k - 1`
+            let mut k = n;
+            while k > 0 {
+                let kc = k - 1;
+                let mut kstep = 1;
+                let absakk = absdiag(a.get(kc, kc));
+                let (imax, colmax) = match iamax((0..kc).map(|i| (i, kc)), a) {
+                    Some(((imax, _), colmax)) => (imax, colmax),
+                    None => (kc, zero),
+                };
+                let kp;
+                if Float::max(absakk, colmax) == zero || Float::is_nan(absakk) {
+                    if info == 0 {
+                        info = (kc + 1) as blas_int;
+                    }
+                    kp = kc;
+                    setreal(kc);
+                } else {
+                    if absakk >= alpha * colmax {
+                        kp = kc;
+                    } else {
+                        let idx = (imax + 1..=kc).map(|j| (imax, j)).chain((0..imax).map(|j| (j, imax)));
+                        let rowmax = iamax(idx, a).map_or(zero, |(_, v)| v);
+                        if absakk >= alpha * colmax * (colmax / rowmax) {
+                            kp = kc;
+                        } else if absdiag(a.get(imax, imax)) >= alpha * rowmax {
+                            kp = imax;
+                        } else {
+                            kp = imax;
+                            kstep = 2;
+                        }
+                    }
+
+                    // interchange rows and columns kk and kp in leading submatrix
+                    let kk = kc + 1 - kstep;
+                    if kp != kk {
+                        (0..kp).for_each(|i| a.swap((i, kk), (i, kp)));
+                        for j in kp + 1..kk {
+                            let t = cj::<T, HERMI>(a.get(j, kk));
+                            a.set(j, kk, cj::<T, HERMI>(a.get(kp, j)));
+                            a.set(kp, j, t);
+                        }
+                        if HERMI {
+                            a.set(kp, kk, a.get(kp, kk).conj());
+                        }
+                        a.swap((kk, kk), (kp, kp));
+                        setreal(kk);
+                        setreal(kp);
+                        if kstep == 2 {
+                            setreal(kc);
+                            a.swap((kc - 1, kc), (kp, kc));
+                        }
+                    } else {
+                        setreal(kc);
+                        if kstep == 2 {
+                            setreal(kc - 1);
+                        }
+                    }
+
+                    // update leading submatrix
+                    if kstep == 1 {
+                        let d11 = T::one() / a.get(kc, kc);
+                        for j in 0..kc {
+                            let temp = -(d11 * cj::<T, HERMI>(a.get(j, kc)));
+                            (0..=j).for_each(|i| a.set(i, j, a.get(i, j) + a.get(i, kc) * temp));
+                            setreal(j);
+                        }
+                        (0..kc).for_each(|i| a.set(i, kc, a.get(i, kc) * d11));
+                    } else if kc >= 2 {
+                        if HERMI {
+                            let d = a.get(kc - 1, kc).abs();
+                            let d22 = a.get(kc - 1, kc - 1).re() / d;
+                            let d11 = a.get(kc, kc).re() / d;
+                            let tt = one / (d11 * d22 - one);
+                            let d12 = a.get(kc - 1, kc) / d;
+                            let d = tt / d;
+                            for j in (0..kc - 1).rev() {
+                                let wkm1 = (a.get(j, kc - 1) * d11 - d12.conj() * a.get(j, kc)) * d;
+                                let wk = (a.get(j, kc) * d22 - d12 * a.get(j, kc - 1)) * d;
+                                for i in (0..=j).rev() {
+                                    let val = a.get(i, j) - a.get(i, kc) * wk.conj() - a.get(i, kc - 1) * wkm1.conj();
+                                    a.set(i, j, val);
+                                }
+                                a.set(j, kc, wk);
+                                a.set(j, kc - 1, wkm1);
+                                setreal(j);
+                            }
+                        } else {
+                            let d12 = a.get(kc - 1, kc);
+                            let d22 = a.get(kc - 1, kc - 1) / d12;
+                            let d11 = a.get(kc, kc) / d12;
+                            let t = T::one() / (d11 * d22 - T::one());
+                            let d12 = t / d12;
+                            for j in (0..kc - 1).rev() {
+                                let wkm1 = d12 * (d11 * a.get(j, kc - 1) - a.get(j, kc));
+                                let wk = d12 * (d22 * a.get(j, kc) - a.get(j, kc - 1));
+                                for i in (0..=j).rev() {
+                                    let val = a.get(i, j) - a.get(i, kc) * wk - a.get(i, kc - 1) * wkm1;
+                                    a.set(i, j, val);
+                                }
+                                a.set(j, kc, wk);
+                                a.set(j, kc - 1, wkm1);
+                            }
+                        }
+                    }
+                }
+
+                // store pivot indices
+                if kstep == 1 {
+                    *ipiv.add(kc) = (kp + 1) as blas_int;
+                } else {
+                    *ipiv.add(kc) = -((kp + 1) as blas_int);
+                    *ipiv.add(kc - 1) = -((kp + 1) as blas_int);
+                }
+                k -= kstep;
+            }
+        },
+    }
+    info
+}
+
+/// Solve `A X = B` by factors from [`sytf2`] (`xSYTRS`, or `xHETRS` if
+/// `HERMI`).
+unsafe fn sytrs<T, const HERMI: bool>(
+    uplo: FlagUpLo,
+    n: usize,
+    nrhs: usize,
+    a: MatPtr<T>,
+    ipiv: *const blas_int,
+    b: MatPtr<T>,
+) where
+    T: BlasFloat + Copy,
+{
+    let piv = |k: usize| *ipiv.add(k);
+    let swap_rows = |i: usize, j: usize| {
+        if i != j {
+            (0..nrhs).for_each(|l| b.swap((i, l), (j, l)));
+        }
+    };
+    // b(k, :) -= sum_{i in idx} op(a(i, col)) b(i, :)
+    let dot_update = |k: usize, col: usize, idx: core::ops::Range<usize>| {
+        for l in 0..nrhs {
+            let x = idx.clone().fold(b.get(k, l), |acc, i| acc - cj::<T, HERMI>(a.get(i, col)) * b.get(i, l));
+            b.set(k, l, x);
+        }
+    };
+    // b(idx, :) -= a(idx, col) b(k, :)
+    let axpy_update = |k: usize, col: usize, idx: core::ops::Range<usize>| {
+        for l in 0..nrhs {
+            let bk = b.get(k, l);
+            idx.clone().for_each(|i| b.set(i, l, b.get(i, l) - a.get(i, col) * bk));
+        }
+    };
+    // solve 2x2 diagonal block on rows (k1, k2), with off-diagonal a(k2, k1)
+    // for lower or a(k1, k2) for upper
+    let solve_2x2 = |k1: usize, k2: usize| {
+        let (akm1k, akm1, ak) = match uplo {
+            FlagUpLo::L => {
+                let akm1k = a.get(k2, k1);
+                (akm1k, a.get(k1, k1) / cj::<T, HERMI>(akm1k), a.get(k2, k2) / akm1k)
+            },
+            FlagUpLo::U => {
+                let akm1k = a.get(k1, k2);
+                (akm1k, a.get(k1, k1) / akm1k, a.get(k2, k2) / cj::<T, HERMI>(akm1k))
+            },
+        };
+        let denom = akm1 * ak - T::one();
+        for l in 0..nrhs {
+            let (bkm1, bk) = match uplo {
+                FlagUpLo::L => (b.get(k1, l) / cj::<T, HERMI>(akm1k), b.get(k2, l) / akm1k),
+                FlagUpLo::U => (b.get(k1, l) / akm1k, b.get(k2, l) / cj::<T, HERMI>(akm1k)),
+            };
+            b.set(k1, l, (ak * bkm1 - bk) / denom);
+            b.set(k2, l, (akm1 * bk - bkm1) / denom);
+        }
+    };
+    let scale_1x1 = |k: usize| {
+        let d = if HERMI { real::<T>(T::Real::one() / a.get(k, k).re()) } else { T::one() / a.get(k, k) };
+        (0..nrhs).for_each(|l| b.set(k, l, b.get(k, l) * d));
+    };
+
+    match uplo {
+        FlagUpLo::L => {
+            // solve L D Y = B
+            let mut k = 0;
+            while k < n {
+                if piv(k) > 0 {
+                    swap_rows(k, piv(k) as usize - 1);
+                    axpy_update(k, k, k + 1..n);
+                    scale_1x1(k);
+                    k += 1;
+                } else {
+                    swap_rows(k + 1, (-piv(k)) as usize - 1);
+                    axpy_update(k, k, k + 2..n);
+                    axpy_update(k + 1, k + 1, k + 2..n);
+                    solve_2x2(k, k + 1);
+                    k += 2;
+                }
+            }
+            // solve L^T X = Y (or L^H)
+            let mut k = n;
+            while k > 0 {
+                let kc = k - 1;
+                if piv(kc) > 0 {
+                    dot_update(kc, kc, kc + 1..n);
+                    swap_rows(kc, piv(kc) as usize - 1);
+                    k -= 1;
+                } else {
+                    dot_update(kc, kc, kc + 1..n);
+                    dot_update(kc - 1, kc - 1, kc + 1..n);
+                    swap_rows(kc, (-piv(kc)) as usize - 1);
+                    k -= 2;
+                }
+            }
+        },
+        FlagUpLo::U => {
+            // solve U D Y = B
+            let mut k = n;
+            while k > 0 {
+                let kc = k - 1;
+                if piv(kc) > 0 {
+                    swap_rows(kc, piv(kc) as usize - 1);
+                    axpy_update(kc, kc, 0..kc);
+                    scale_1x1(kc);
+                    k -= 1;
+                } else {
+                    swap_rows(kc - 1, (-piv(kc)) as usize - 1);
+                    axpy_update(kc, kc, 0..kc - 1);
+                    axpy_update(kc - 1, kc - 1, 0..kc - 1);
+                    solve_2x2(kc - 1, kc);
+                    k -= 2;
+                }
+            }
+            // solve U^T X = Y (or U^H)
+            let mut k = 0;
+            while k < n {
+                if piv(k) > 0 {
+                    dot_update(k, k, 0..k);
+                    swap_rows(k, piv(k) as usize - 1);
+                    k += 1;
+                } else {
+                    dot_update(k, k, 0..k);
+                    dot_update(k + 1, k + 1, 0..k);
+                    swap_rows(k, (-piv(k)) as usize - 1);
+                    k += 2;
+                }
+            }
+        },
+    }
+}
+
+impl<T, const HERMI: bool> SYSVDriverAPI<T, HERMI> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_sysv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        nrhs: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+        b: *mut T,
+        ldb: usize,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let info = sytf2::<T, HERMI>(uplo, n, a, ipiv);
+        if info != 0 {
+            return info;
+        }
+        sytrs::<T, HERMI>(uplo, n, nrhs, a, ipiv, MatPtr::new(b, ldb, order));
+        0
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,294 @@
+//! Reference LAPACK drivers for singular value decomposition.
+//!
+//! Both `xGESVD` and `xGESDD` are served by the one-sided Jacobi method.
+//! Singular values are returned in descending order, same to LAPACK.
+//!
+//! The subset driver (`xGESVDX`) and the preconditioned Jacobi driver
+//! (`xGEJSV`) also perform the full decomposition, and then pick or reshape
+//! the requested factors.
+
+use crate::driver_impl::util::*;
+use crate::BlasBackend;
+use num::traits::Float;
+use num::{One, Zero};
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+/// SVD `A = U S V^H` of `m x n` matrix, for job characters `'A'`, `'S'`,
+/// `'O'`, `'N'` of `xGESVD`.
+///
+/// Matrix `A` is overwritten only if one of the jobs is `'O'`.
+#[allow(clippy::too_many_arguments)]
+unsafe fn svd_jacobi<T>(
+    jobu: char,
+    jobvt: char,
+    m: usize,
+    n: usize,
+    a: MatPtr<T>,
+    s: *mut T::Real,
+    u: MatPtr<T>,
+    vt: MatPtr<T>,
+) -> blas_int
+where
+    T: BlasFloat + Copy,
+{
+    let jobu = jobu.to_ascii_uppercase();
+    let jobvt = jobvt.to_ascii_uppercase();
+    let minmn = m.min(n);
+
+    // work on A^H if m < n, so that the Jacobi matrix is always tall
+    let trans = m < n;
+    let (mm, nn) = if trans { (n, m) } else { (m, n) };
+    let (job_left, job_right) = if trans { (jobvt, jobu) } else { (jobu, jobvt) };
+    let mut g = Mat::read(a, m, n);
+    if trans {
+        g = g.adjoint();
+    }
+    let mut v = Mat::<T>::eye(nn);
+    if !jacobi_svd(&mut g, &mut v) {
+        return minmn as blas_int;
+    }
+
+    // singular values and descending order
+    let sigma: Vec<T::Real> =
+        (0..nn).map(|j| Float::sqrt((0..mm).fold(T::Real::zero(), |acc, i| acc + norm_sqr(g[(i, j)])))).collect();
+    let mut idx: Vec<usize> = (0..nn).collect();
+    idx.sort_by(|&i, &j| sigma[j].partial_cmp(&sigma[i]).unwrap());
+    idx.iter().enumerate().for_each(|(jnew, &jold)| *s.add(jnew) = sigma[jold]);
+
+    // left singular vectors of the tall matrix; vectors of tiny singular values
+    // are regenerated to keep orthonormality
+    let need_left = job_left != 'N';
+    let need_right = job_right != 'N';
+    let ncol_left = if job_left == 'A' { mm } else { nn };
+    let mut ul = Mat::<T>::zeros(mm, ncol_left);
+    if need_left {
+        let smax = sigma.iter().fold(T::Real::zero(), |acc, &x| Float::max(acc, x));
+        let tol = smax * <T::Real as Float>::epsilon() * rcast::<T::Real>(mm as f64);
+        let mut valid = vec![false; ncol_left];
+        for (jnew, &jold) in idx.iter().enumerate() {
+            if sigma[jold] > tol {
+                (0..mm).for_each(|i| ul[(i, jnew)] = g[(i, jold)] / sigma[jold]);
+                valid[jnew] = true;
+            }
+        }
+        let mut full = Mat::<T>::zeros(mm, mm);
+        (0..ncol_left).for_each(|j| (0..mm).for_each(|i| full[(i, j)] = ul[(i, j)]));
+        let mut valid_full = valid.clone();
+        valid_full.resize(mm, false);
+        complete_unitary(&mut full, &mut valid_full);
+        (0..ncol_left).for_each(|j| (0..mm).for_each(|i| ul[(i, j)] = full[(i, j)]));
+    }
+    let mut ur = Mat::<T>::zeros(nn, nn);
+    if need_right {
+        for (jnew, &jold) in idx.iter().enumerate() {
+            (0..nn).for_each(|i| ur[(i, jnew)] = v[(i, jold)]);
+        }
+    }
+
+    // U = ul, V = ur for tall; U = ur, V = ul for wide
+    let (umat, vmat) = if trans { (ur, ul) } else { (ul, ur) };
+    match jobu {
+        'A' => umat.write(u, m, m),
+        'S' => umat.write(u, m, minmn),
+        'O' => umat.write(a, m, minmn),
+        _ => (),
+    }
+    let vhmat = vmat.adjoint();
+    match jobvt {
+        'A' => vhmat.write(vt, n, n),
+        'S' => vhmat.write(vt, minmn, n),
+        'O' => vhmat.write(a, minmn, n),
+        _ => (),
+    }
+    0
+}
+
+impl<T> GESVDDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gesvd(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut T::Real,
+    ) -> blas_int {
+        if jobu.eq_ignore_ascii_case(&'O') && jobvt.eq_ignore_ascii_case(&'O') {
+            return -3;
+        }
+        let a = MatPtr::new(a, lda, order);
+        let u = MatPtr::new(u, ldu, order);
+        let vt = MatPtr::new(vt, ldvt, order);
+        let info = svd_jacobi(jobu, jobvt, m, n, a, s, u, vt);
+        // no bidiagonalization is involved, so superdiagonal is always zero
+        (0..m.min(n).saturating_sub(1)).for_each(|i| *superb.add(i) = T::Real::zero());
+        info
+    }
+}
+
+impl<T> GESDDDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gesdd(
+        order: FlagOrder,
+        jobz: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+    ) -> blas_int {
+        let a = MatPtr::new(a, lda, order);
+        let u = MatPtr::new(u, ldu, order);
+        let vt = MatPtr::new(vt, ldvt, order);
+        // for jobz = 'O', the thin factor overwrites A, and the square one is
+        // written to U or VT
+        let (jobu, jobvt) = match jobz.to_ascii_uppercase() {
+            'O' if m >= n => ('O', 'A'),
+            'O' => ('A', 'O'),
+            job => (job, job),
+        };
+        svd_jacobi(jobu, jobvt, m, n, a, s, u, vt)
+    }
+}
+
+impl<T> GESVDXDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        let minmn = m.min(n);
+        let want_u = jobu.eq_ignore_ascii_case(&'V');
+        let want_vt = jobvt.eq_ignore_ascii_case(&'V');
+
+        // full thin decomposition into work arrays
+        let mut s_full = vec![T::Real::zero(); minmn];
+        let mut u_full = Mat::<T>::zeros(m, minmn);
+        let mut vt_full = Mat::<T>::zeros(minmn, n);
+        let u_ptr = MatPtr::new(u_full.data.as_mut_ptr(), m.max(1), ColMajor);
+        let vt_ptr = MatPtr::new(vt_full.data.as_mut_ptr(), minmn.max(1), ColMajor);
+        let a = MatPtr::new(a, lda, order);
+        let (job_u, job_vt) = (if want_u { 'S' } else { 'N' }, if want_vt { 'S' } else { 'N' });
+        let info = svd_jacobi(job_u, job_vt, m, n, a, s_full.as_mut_ptr(), u_ptr, vt_ptr);
+        if info != 0 {
+            return info;
+        }
+
+        // singular values are descending; value range is half-open (vl, vu]
+        let idx = match range.to_ascii_uppercase() {
+            'A' => 0..minmn,
+            'V' => s_full.partition_point(|&x| x > vu)..s_full.partition_point(|&x| x > vl),
+            'I' => il.saturating_sub(1)..iu.min(minmn),
+            _ => return -3,
+        };
+        let u = MatPtr::new(u, ldu, order);
+        let vt = MatPtr::new(vt, ldvt, order);
+        for (jnew, jold) in idx.clone().enumerate() {
+            *s.add(jnew) = s_full[jold];
+            if want_u {
+                (0..m).for_each(|i| u.set(i, jnew, u_full[(i, jold)]));
+            }
+            if want_vt {
+                (0..n).for_each(|j| vt.set(jnew, j, vt_full[(jold, j)]));
+            }
+        }
+        *ns = idx.len();
+        // Jacobi iteration has no failed eigenvectors of the tridiagonal problem
+        (0..(12 * minmn).saturating_sub(1)).for_each(|i| *superb.add(i) = 0);
+        0
+    }
+}
+
+impl<T> GEJSVDriverAPI<T> for BlasBackend
+where
+    T: BlasFloat + Copy,
+{
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        _joba: char,
+        jobu: char,
+        jobv: char,
+        _jobr: char,
+        _jobt: char,
+        _jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut T::Real,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        // xGEJSV only handles tall matrices
+        if n > m {
+            return -8;
+        }
+        let job_u = match jobu.to_ascii_uppercase() {
+            'F' => 'A',
+            'U' => 'S',
+            _ => 'N',
+        };
+        let want_v = jobv.eq_ignore_ascii_case(&'V') || jobv.eq_ignore_ascii_case(&'J');
+
+        // right singular vectors are returned as V instead of V^H
+        let mut vt_full = Mat::<T>::zeros(n, n);
+        let vt_ptr = MatPtr::new(vt_full.data.as_mut_ptr(), n.max(1), ColMajor);
+        let a = MatPtr::new(a, lda, order);
+        let u = MatPtr::new(u, ldu, order);
+        let info = svd_jacobi(job_u, if want_v { 'A' } else { 'N' }, m, n, a, sva, u, vt_ptr);
+        if info != 0 {
+            return info;
+        }
+        if want_v {
+            vt_full.adjoint().write(MatPtr::new(v, ldv, order), n, n);
+        }
+
+        // singular values are not scaled, and rank estimates are not performed
+        (0..7).for_each(|i| *stat.add(i) = T::Real::zero());
+        *stat = T::Real::one();
+        *stat.add(1) = T::Real::one();
+        *istat = n as blas_int;
+        *istat.add(1) = n as blas_int;
+        *istat.add(2) = 0;
+        0
+    }
+}
@@ -0,0 +1,8 @@
+pub mod blas1;
+pub mod blas2;
+pub mod blas3;
+pub mod lapack_eigh;
+pub mod lapack_schur;
+pub mod lapack_solve;
+pub mod lapack_svd;
+pub mod util;
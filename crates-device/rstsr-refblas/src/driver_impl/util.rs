@@ -0,0 +1,418 @@
+//! Matrix accessors and dense helpers shared by the reference kernels.
+//!
+//! The drivers receive raw pointers with leading dimension and storage order,
+//! as CBLAS/LAPACKE do. Instead of transposing row-major input into
+//! column-major buffers, elements are accessed through [`MatPtr`], so the same
+//! kernel handles both orders.
+
+use core::ops::{Index, IndexMut};
+use num::traits::{Float, NumCast};
+use num::Zero;
+use rstsr_blas_traits::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+/// Maximum number of sweeps for Jacobi eigen/singular value solvers.
+pub(crate) const JACOBI_MAX_SWEEPS: usize = 100;
+
+/* #region scalar helpers */
+
+#[inline]
+pub(crate) fn rcast<R: Float>(x: f64) -> R {
+    <R as NumCast>::from(x).unwrap()
+}
+
+/// Promote real value to (possibly complex) scalar.
+#[inline]
+pub(crate) fn real<T: BlasFloat>(x: T::Real) -> T {
+    T::one() * x
+}
+
+/// `|re(x)| + |im(x)|`, the cheap absolute value used by LAPACK pivoting.
+#[inline]
+pub(crate) fn cabs1<T: BlasFloat>(x: T) -> T::Real {
+    Float::abs(x.re()) + Float::abs(x.im())
+}
+
+/// Conjugate only if `HERMI`.
+#[inline]
+pub(crate) fn cj<T: BlasFloat, const HERMI: bool>(x: T) -> T {
+    if HERMI {
+        x.conj()
+    } else {
+        x
+    }
+}
+
+#[inline]
+pub(crate) fn norm_sqr<T: BlasFloat>(x: T) -> T::Real {
+    let (re, im) = (x.re(), x.im());
+    re * re + im * im
+}
+
+/* #endregion */
+
+/* #region raw matrix accessor */
+
+/// Matrix by raw pointer, leading dimension and storage order.
+///
+/// Element `(i, j)` is located at `i * ld + j` for row-major, and `i + j * ld`
+/// for col-major.
+pub(crate) struct MatPtr<T> {
+    ptr: *mut T,
+    ld: usize,
+    order: FlagOrder,
+}
+
+impl<T> Clone for MatPtr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatPtr<T> {}
+
+impl<T> MatPtr<T>
+where
+    T: BlasFloat + Copy,
+{
+    pub(crate) fn new(ptr: *const T, ld: usize, order: FlagOrder) -> Self {
+        Self { ptr: ptr as *mut T, ld, order }
+    }
+
+    #[inline]
+    fn offset(&self, i: usize, j: usize) -> usize {
+        match self.order {
+            RowMajor => i * self.ld + j,
+            ColMajor => i + j * self.ld,
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn get(&self, i: usize, j: usize) -> T {
+        *self.ptr.add(self.offset(i, j))
+    }
+
+    #[inline]
+    pub(crate) unsafe fn set(&self, i: usize, j: usize, val: T) {
+        *self.ptr.add(self.offset(i, j)) = val;
+    }
+
+    #[inline]
+    pub(crate) unsafe fn swap(&self, (i1, j1): (usize, usize), (i2, j2): (usize, usize)) {
+        core::ptr::swap(self.ptr.add(self.offset(i1, j1)), self.ptr.add(self.offset(i2, j2)));
+    }
+
+    /// Element `(i, j)` of `op(A)`.
+    #[inline]
+    pub(crate) unsafe fn get_op(&self, i: usize, j: usize, trans: FlagTrans) -> T {
+        match trans {
+            FlagTrans::N => self.get(i, j),
+            FlagTrans::T => self.get(j, i),
+            FlagTrans::C => self.get(j, i).conj(),
+            FlagTrans::CN => self.get(i, j).conj(),
+        }
+    }
+
+    /// Element `(i, j)` of symmetric (or hermitian) matrix, where only the
+    /// `uplo` triangle is referenced.
+    ///
+    /// For hermitian matrix, imaginary part of diagonal is assumed to be zero.
+    #[inline]
+    pub(crate) unsafe fn get_sym<const HERMI: bool>(&self, i: usize, j: usize, uplo: FlagUpLo) -> T {
+        let stored = match uplo {
+            FlagUpLo::U => i <= j,
+            FlagUpLo::L => i >= j,
+        };
+        if HERMI && i == j {
+            real(self.get(i, i).re())
+        } else if stored {
+            self.get(i, j)
+        } else {
+            cj::<T, HERMI>(self.get(j, i))
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region dense col-major matrix */
+
+/// Owned col-major matrix for the work arrays of reference LAPACK kernels.
+pub(crate) struct Mat<T> {
+    pub(crate) data: Vec<T>,
+    pub(crate) nrow: usize,
+    pub(crate) ncol: usize,
+}
+
+impl<T> Index<(usize, usize)> for Mat<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i + j * self.nrow]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat<T> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i + j * self.nrow]
+    }
+}
+
+impl<T> Mat<T>
+where
+    T: BlasFloat + Copy,
+{
+    pub(crate) fn zeros(nrow: usize, ncol: usize) -> Self {
+        Self { data: vec![T::zero(); nrow * ncol], nrow, ncol }
+    }
+
+    pub(crate) fn eye(n: usize) -> Self {
+        let mut mat = Self::zeros(n, n);
+        (0..n).for_each(|i| mat[(i, i)] = T::one());
+        mat
+    }
+
+    pub(crate) unsafe fn read(a: MatPtr<T>, nrow: usize, ncol: usize) -> Self {
+        let mut mat = Self::zeros(nrow, ncol);
+        for j in 0..ncol {
+            for i in 0..nrow {
+                mat[(i, j)] = a.get(i, j);
+            }
+        }
+        mat
+    }
+
+    /// Full symmetric (or hermitian) matrix from its `uplo` triangle.
+    pub(crate) unsafe fn read_sym<const HERMI: bool>(a: MatPtr<T>, n: usize, uplo: FlagUpLo) -> Self {
+        let mut mat = Self::zeros(n, n);
+        for j in 0..n {
+            for i in 0..n {
+                mat[(i, j)] = a.get_sym::<HERMI>(i, j, uplo);
+            }
+        }
+        mat
+    }
+
+    /// Write the leading `nrow` x `ncol` block into `a`.
+    pub(crate) unsafe fn write(&self, a: MatPtr<T>, nrow: usize, ncol: usize) {
+        for j in 0..ncol {
+            for i in 0..nrow {
+                a.set(i, j, self[(i, j)]);
+            }
+        }
+    }
+
+    pub(crate) fn adjoint(&self) -> Self {
+        let mut mat = Self::zeros(self.ncol, self.nrow);
+        for j in 0..self.ncol {
+            for i in 0..self.nrow {
+                mat[(j, i)] = self[(i, j)].conj();
+            }
+        }
+        mat
+    }
+
+    pub(crate) fn matmul(&self, other: &Self) -> Self {
+        let mut mat = Self::zeros(self.nrow, other.ncol);
+        for j in 0..other.ncol {
+            for l in 0..self.ncol {
+                let b = other[(l, j)];
+                if b == T::zero() {
+                    continue;
+                }
+                for i in 0..self.nrow {
+                    mat[(i, j)] = mat[(i, j)] + self[(i, l)] * b;
+                }
+            }
+        }
+        mat
+    }
+
+    /// Solve `L X = B` (or `L^H X = B` if `adjoint`) in-place of `b`, for
+    /// lower-triangular `L` with non-unit diagonal.
+    pub(crate) fn solve_lower(&self, b: &mut Self, adjoint: bool) {
+        let n = self.nrow;
+        for j in 0..b.ncol {
+            if !adjoint {
+                for i in 0..n {
+                    let mut x = b[(i, j)];
+                    for l in 0..i {
+                        x = x - self[(i, l)] * b[(l, j)];
+                    }
+                    b[(i, j)] = x / self[(i, i)];
+                }
+            } else {
+                for i in (0..n).rev() {
+                    let mut x = b[(i, j)];
+                    for l in i + 1..n {
+                        x = x - self[(l, i)].conj() * b[(l, j)];
+                    }
+                    b[(i, j)] = x / self[(i, i)].conj();
+                }
+            }
+        }
+    }
+
+    /// Apply plane rotation `[[c, s], [-s e^*, c e^*]]` to columns `p` and `q`,
+    /// where `e` is a unit phase.
+    #[inline]
+    pub(crate) fn rotate_cols(&mut self, p: usize, q: usize, c: T::Real, s: T::Real, e: T) {
+        let ec = e.conj();
+        for k in 0..self.nrow {
+            let (xp, xq) = (self[(k, p)], self[(k, q)]);
+            self[(k, p)] = xp * c - xq * ec * s;
+            self[(k, q)] = xp * s + xq * ec * c;
+        }
+    }
+
+    /// Apply adjoint of the rotation in [`Mat::rotate_cols`] to rows `p` and
+    /// `q`.
+    #[inline]
+    pub(crate) fn rotate_rows(&mut self, p: usize, q: usize, c: T::Real, s: T::Real, e: T) {
+        for k in 0..self.ncol {
+            let (xp, xq) = (self[(p, k)], self[(q, k)]);
+            self[(p, k)] = xp * c - xq * e * s;
+            self[(q, k)] = xp * s + xq * e * c;
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region jacobi solvers */
+
+/// Rotation `(c, s)` that annihilates the off-diagonal element of real
+/// symmetric 2x2 matrix `[[app, apq], [apq, aqq]]`, with `|apq| = r`.
+#[inline]
+fn jacobi_rotation<R: Float>(app: R, aqq: R, r: R) -> (R, R) {
+    let theta = (aqq - app) / (rcast::<R>(2.0) * r);
+    let t = theta.signum() / (theta.abs() + (theta * theta + R::one()).sqrt());
+    let c = R::one() / (t * t + R::one()).sqrt();
+    (c, t * c)
+}
+
+/// Cyclic Jacobi eigen solver for hermitian matrix.
+///
+/// On exit, `a` is diagonal (eigenvalues, unsorted), and `v` is multiplied by
+/// the accumulated unitary transformation. Returns `false` if not converged.
+pub(crate) fn jacobi_eigh<T>(a: &mut Mat<T>, v: &mut Mat<T>) -> bool
+where
+    T: BlasFloat + Copy,
+{
+    let n = a.nrow;
+    let eps = <T::Real as Float>::epsilon();
+    let norm = Float::sqrt(a.data.iter().fold(T::Real::zero(), |acc, &x| acc + norm_sqr(x)));
+    let tol = eps * norm;
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[(p, q)];
+                let r = apq.abs();
+                if r <= tol || Float::is_nan(r) {
+                    continue;
+                }
+                rotated = true;
+                let e = apq / r;
+                let (app, aqq) = (a[(p, p)].re(), a[(q, q)].re());
+                let (c, s) = jacobi_rotation(app, aqq, r);
+                a.rotate_cols(p, q, c, s, e);
+                a.rotate_rows(p, q, c, s, e);
+                v.rotate_cols(p, q, c, s, e);
+                let t = s / c;
+                a[(p, q)] = T::zero();
+                a[(q, p)] = T::zero();
+                a[(p, p)] = real(app - t * r);
+                a[(q, q)] = real(aqq + t * r);
+            }
+        }
+        if !rotated {
+            return true;
+        }
+    }
+    false
+}
+
+/// One-sided (Hestenes) Jacobi SVD for matrix with `nrow >= ncol`.
+///
+/// On exit, columns of `a` are mutually orthogonal (`U * S`), and `v` is
+/// multiplied by the accumulated unitary transformation. Returns `false` if
+/// not converged.
+///
+/// Columns with norm below `eps * |A|_F` are not rotated: for rank-deficient
+/// `A` they only carry rounding errors, and rotating them against each other
+/// may never settle.
+pub(crate) fn jacobi_svd<T>(a: &mut Mat<T>, v: &mut Mat<T>) -> bool
+where
+    T: BlasFloat + Copy,
+{
+    let (m, n) = (a.nrow, a.ncol);
+    let eps = <T::Real as Float>::epsilon();
+    let tol = eps * Float::sqrt(rcast::<T::Real>(m as f64));
+    let small = a.data.iter().fold(T::Real::zero(), |acc, &x| acc + norm_sqr(x)) * eps * eps;
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (T::Real::zero(), T::Real::zero(), T::zero());
+                for i in 0..m {
+                    let (xp, xq) = (a[(i, p)], a[(i, q)]);
+                    alpha = alpha + norm_sqr(xp);
+                    beta = beta + norm_sqr(xq);
+                    gamma = gamma + xp.conj() * xq;
+                }
+                let r = gamma.abs();
+                if Float::min(alpha, beta) <= small || r <= tol * Float::sqrt(alpha * beta) || Float::is_nan(r) {
+                    continue;
+                }
+                rotated = true;
+                let e = gamma / r;
+                let (c, s) = jacobi_rotation(alpha, beta, r);
+                a.rotate_cols(p, q, c, s, e);
+                v.rotate_cols(p, q, c, s, e);
+            }
+        }
+        if !rotated {
+            return true;
+        }
+    }
+    false
+}
+
+/// Fill columns of `q` not marked in `valid` by orthonormal vectors, so that
+/// `q` becomes unitary (`q` must be square, and valid columns orthonormal).
+pub(crate) fn complete_unitary<T>(q: &mut Mat<T>, valid: &mut [bool])
+where
+    T: BlasFloat + Copy,
+{
+    let n = q.nrow;
+    let half = rcast::<T::Real>(0.5);
+    let mut candidate = 0;
+    for j in 0..q.ncol {
+        if valid[j] {
+            continue;
+        }
+        while candidate < n {
+            let mut x = vec![T::zero(); n];
+            x[candidate] = T::one();
+            candidate += 1;
+            // twice is enough (Kahan-Parlett)
+            for _ in 0..2 {
+                for k in (0..q.ncol).filter(|&k| valid[k]) {
+                    let proj = (0..n).fold(T::zero(), |acc, i| acc + q[(i, k)].conj() * x[i]);
+                    (0..n).for_each(|i| x[i] = x[i] - q[(i, k)] * proj);
+                }
+            }
+            let norm = Float::sqrt(x.iter().fold(T::Real::zero(), |acc, &xi| acc + norm_sqr(xi)));
+            if norm > half {
+                (0..n).for_each(|i| q[(i, j)] = x[i] / norm);
+                valid[j] = true;
+                break;
+            }
+        }
+    }
+}
+
+/* #endregion */
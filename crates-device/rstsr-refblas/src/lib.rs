@@ -0,0 +1,18 @@
+#![allow(clippy::needless_return)]
+#![allow(non_camel_case_types)]
+#![doc = include_str!("../readme.md")]
+
+pub mod prelude_dev;
+pub mod threading;
+
+pub mod driver_impl;
+
+pub use rstsr_blas_traits::device_blas::DeviceBLAS;
+
+/// Reference (pure Rust) backend of [`DeviceBLAS`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RefBLAS;
+
+pub type DeviceRefBLAS = DeviceBLAS<RefBLAS>;
+
+pub(crate) use RefBLAS as BlasBackend;
@@ -0,0 +1,51 @@
+//! Reference BLAS threading
+//!
+//! Kernels of the reference device are sequential, so the number of threads
+//! is only recorded here to keep the [`BlasThreadAPI`](rstsr_blas_traits::threading::BlasThreadAPI)
+//! contract of other BLAS devices (e.g. `with_blas_num_threads` restores the previous value).
+
+use crate::prelude_dev::*;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use rstsr_blas_traits::threading::with_num_threads_scoped;
+
+/* #region threading number control */
+
+static NUM_THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// Set number of threads for reference BLAS.
+///
+/// This function should be safe to call from multiple threads.
+pub fn set_num_threads(n: usize) {
+    NUM_THREADS.store(n, Ordering::Relaxed);
+}
+
+pub fn get_num_threads() -> usize {
+    NUM_THREADS.load(Ordering::Relaxed)
+}
+
+pub fn with_num_threads<F, R>(nthreads: usize, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    with_num_threads_scoped(nthreads, false, get_num_threads, set_num_threads, f)
+}
+
+/* #endregion */
+
+/* #region trait impl */
+
+impl CblasBackendAPI for BlasBackend {
+    fn set_num_threads(nthreads: usize) {
+        crate::threading::set_num_threads(nthreads);
+    }
+
+    fn get_num_threads() -> usize {
+        crate::threading::get_num_threads()
+    }
+
+    fn with_num_threads<R>(nthreads: usize, f: impl FnOnce() -> R) -> R {
+        crate::threading::with_num_threads(nthreads, f)
+    }
+}
+
+/* #endregion */
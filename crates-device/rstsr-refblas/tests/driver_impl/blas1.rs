@@ -0,0 +1,67 @@
+use super::*;
+use rstsr_blas_traits::blas1::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_daxpy_dscal() {
+        let device = DeviceBLAS::default();
+        let x = rand_f64_vec(8, 0, &device);
+        let y = rand_f64_vec(8, 1, &device);
+        let y_ref = &x * 2.0 + &y;
+
+        // owned output from view
+        let y_out = DAXPY::default().x(x.view()).y(y.view()).alpha(2.0).build().unwrap().run().unwrap().into_owned();
+        assert!((&y_out - &y_ref).abs().max_all() < 1e-12);
+
+        // strided inputs and in-place output
+        let x_full = rand_f64_vec(16, 0, &device);
+        let mut y_full = rand_f64_vec(16, 1, &device);
+        let y_ref = x_full.slice(slice!(None, None, -2)) + y_full.slice(slice!(None, None, 2));
+        DAXPY::default()
+            .x(x_full.slice(slice!(None, None, -2)).into_dim::<Ix1>())
+            .y(y_full.slice_mut(slice!(None, None, 2)).into_dim::<Ix1>())
+            .build()
+            .unwrap()
+            .run()
+            .unwrap();
+        assert!((y_full.slice(slice!(None, None, 2)) - &y_ref).abs().max_all() < 1e-12);
+
+        // scal in-place
+        let mut x = rand_f64_vec(8, 0, &device);
+        let x_ref = &x * 0.5;
+        DSCAL::default().x(x.view_mut()).alpha(0.5).build().unwrap().run().unwrap();
+        assert!((&x - &x_ref).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_zdot_nrm2_iamax() {
+        let device = DeviceBLAS::default();
+        let x = rand_c64([7, 1], 0, &device).into_shape(7).into_dim::<Ix1>();
+        let y = rand_c64([7, 1], 2, &device).into_shape(7).into_dim::<Ix1>();
+
+        let dotu = ZDOTU::default().x(x.view()).y(y.view()).build().unwrap().run().unwrap();
+        let dotu_ref = (&x * &y).sum_all();
+        assert!((dotu - dotu_ref).norm() < 1e-12);
+
+        let dotc = ZDOTC::default().x(x.view()).y(y.view()).build().unwrap().run().unwrap();
+        let dotc_ref = (x.mapv(|v| v.conj()) * &y).sum_all();
+        assert!((dotc - dotc_ref).norm() < 1e-12);
+
+        let nrm = DZNRM2::default().x(x.view()).build().unwrap().run().unwrap();
+        let nrm_ref = x.mapv(|v| v.norm_sqr()).sum_all().sqrt();
+        assert!((nrm - nrm_ref).abs() < 1e-12);
+
+        let mut x = rand_f64_vec(9, 3, &device);
+        x[[6]] = 10.0;
+        x[[2]] = -10.0;
+        let idx = IDAMAX::default().x(x.view()).build().unwrap().run().unwrap();
+        assert_eq!(idx, 2);
+        // reversed view has the first maximum at another index
+        let idx =
+            IDAMAX::default().x(x.slice(slice!(None, None, -1)).into_dim::<Ix1>()).build().unwrap().run().unwrap();
+        assert_eq!(idx, 2);
+    }
+}
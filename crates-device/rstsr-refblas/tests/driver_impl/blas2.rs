@@ -0,0 +1,104 @@
+use super::*;
+use rstsr_blas_traits::blas2::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dgemv() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([7, 5], 0, &device);
+        let x = rand_f64_vec(5, 1, &device);
+        let y_ref = &a % &x;
+
+        // c-contiguous and f-contiguous
+        let y = DGEMV::default().a(a.view()).x(x.view()).build().unwrap().run().unwrap().into_owned();
+        assert!((&y - &y_ref).abs().max_all() < 1e-12);
+        let a_f = a.to_contig(ColMajor);
+        let y = DGEMV::default().a(a_f.view()).x(x.view()).build().unwrap().run().unwrap().into_owned();
+        assert!((&y - &y_ref).abs().max_all() < 1e-12);
+
+        // transposed, alpha, beta and strided output
+        let z = rand_f64_vec(7, 2, &device);
+        let mut y_full = rand_f64_vec(10, 3, &device);
+        let y_ref = (&a.t() % &z) * 2.0 + y_full.slice(slice!(None, None, -2)) * 0.5;
+        DGEMV::default()
+            .a(a.view())
+            .x(z.view())
+            .y(y_full.slice_mut(slice!(None, None, -2)).into_dim::<Ix1>())
+            .trans(Trans)
+            .alpha(2.0)
+            .beta(0.5)
+            .build()
+            .unwrap()
+            .run()
+            .unwrap();
+        assert!((y_full.slice(slice!(None, None, -2)) - &y_ref).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_zhemv() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 6], 0, &device);
+        let a = &a + &conj_t(&a);
+        let x = rand_c64([6, 1], 2, &device).into_shape(6).into_dim::<Ix1>();
+        let y_ref = &a % &x;
+        for uplo in [Lower, Upper] {
+            // only the referenced triangle should be used
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            let y = ZHEMV::default().a(a_tri.view()).x(x.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&y.into_owned() - &y_ref).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dtrmv() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let x = rand_f64_vec(6, 1, &device);
+        for uplo in [Lower, Upper] {
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            for trans in [NoTrans, Trans] {
+                let op_a = match trans {
+                    NoTrans => a_tri.view(),
+                    _ => a_tri.t(),
+                };
+                let y = DTRMV::default()
+                    .a(a.view())
+                    .x(x.view())
+                    .uplo(uplo)
+                    .trans(trans)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&y - &op_a % &x).abs().max_all() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zgerc() {
+        let device = DeviceBLAS::default();
+        let x = rand_c64([5, 1], 0, &device);
+        let y = rand_c64([4, 1], 2, &device);
+        let mut a = rand_c64([5, 4], 4, &device);
+        let a_ref = &a + &x % conj_t(&y);
+        let (x, y) = (x.into_shape(5).into_dim::<Ix1>(), y.into_shape(4).into_dim::<Ix1>());
+        ZGERC::default().x(x.view()).y(y.view()).a(a.view_mut()).build().unwrap().run().unwrap();
+        assert!((&a - &a_ref).abs().max_all() < 1e-12);
+
+        // unconjugated, owned output
+        let a = ZGERU::default().x(x.view()).y(y.view()).build().unwrap().run().unwrap().into_owned();
+        let a_ref = x.i((.., None)) % y.i((None, ..));
+        assert!((&a - &a_ref).abs().max_all() < 1e-12);
+    }
+}
@@ -0,0 +1,337 @@
+use super::*;
+use rstsr_blas_traits::blas3::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dgemm() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([7, 5], 0, &device);
+        let b = rand_f64([5, 6], 1, &device);
+        let c_ref = &a % &b;
+
+        // c-contiguous
+        let c = DGEMM::default().a(a.view()).b(b.view()).build().unwrap().run().unwrap().into_owned();
+        assert!((&c - &c_ref).abs().max_all() < 1e-12);
+
+        // transposed views
+        let at = a.t().into_contig(ColMajor);
+        let bt = b.t().into_contig(RowMajor);
+        let c = DGEMM::default()
+            .a(at.view())
+            .b(bt.view())
+            .transa(Trans)
+            .transb(Trans)
+            .build()
+            .unwrap()
+            .run()
+            .unwrap()
+            .into_owned();
+        assert!((&c - &c_ref).abs().max_all() < 1e-12);
+
+        // alpha, beta and col-major
+        let mut c = rand_f64([7, 6], 2, &device);
+        let c_ref = 2.0 * &c_ref + 0.5 * &c;
+        DGEMM::default()
+            .a(a.view())
+            .b(b.view())
+            .c(c.view_mut())
+            .alpha(2.0)
+            .beta(0.5)
+            .order(ColMajor)
+            .build()
+            .unwrap()
+            .run()
+            .unwrap();
+        assert!((&c - &c_ref).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_zgemm() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 7], 0, &device);
+        let b = rand_c64([5, 6], 2, &device);
+        let c_ref = &conj_t(&a) % &b;
+        let c = ZGEMM::default().a(a.view()).b(b.view()).transa(ConjTrans).build().unwrap().run().unwrap();
+        assert!((&c.into_owned() - &c_ref).abs().max_all() < 1e-12);
+
+        // lazily conjugated operands, in both output orders
+        for order in [RowMajor, ColMajor] {
+            let c = ZGEMM::default().a_conj(a.h()).b(b.view()).order(order).build().unwrap().run().unwrap();
+            assert!((&c.into_owned() - &c_ref).abs().max_all() < 1e-12);
+            let c = ZGEMM::default()
+                .a(a.view())
+                .conja(true)
+                .transa(Trans)
+                .b(b.view())
+                .order(order)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap();
+            assert!((&c.into_owned() - &c_ref).abs().max_all() < 1e-12);
+            // conj(A)^H = A^T
+            let c_ref = &a.t() % b.mapv(|x| x.conj());
+            let c = ZGEMM::default()
+                .a_conj(a.conj_view())
+                .transa(ConjTrans)
+                .b_conj(b.conj_view())
+                .order(order)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap();
+            assert!((&c.into_owned() - &c_ref).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dsymm() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 5], 0, &device);
+        let a = &a + &a.t();
+        let b = rand_f64([5, 4], 1, &device);
+        let b_t = b.t().into_contig(RowMajor);
+        for uplo in [Lower, Upper] {
+            // only the referenced triangle should be used
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            let c = DSYMM::default().a(a_tri.view()).b(b.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&c.into_owned() - (&a % &b)).abs().max_all() < 1e-12);
+            let c = DSYMM::default()
+                .a(a_tri.view())
+                .b(b_t.view())
+                .uplo(uplo)
+                .side(FlagSide::R)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap();
+            assert!((&c.into_owned() - (&b_t % &a)).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_zhemm() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 5], 0, &device);
+        let a = &a + &conj_t(&a);
+        let b = rand_c64([5, 4], 2, &device);
+        for uplo in [Lower, Upper] {
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            let c = ZHEMM::default().a(a_tri.view()).b(b.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&c.into_owned() - (&a % &b)).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_dtrsm() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device) + 6.0 * rt::eye((6, &device)).into_dim::<Ix2>();
+        let b = rand_f64([6, 3], 1, &device);
+        let b_t = b.t().into_contig(RowMajor);
+        for uplo in [Lower, Upper] {
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            for transa in [NoTrans, Trans] {
+                let op_a = match transa {
+                    NoTrans => a_tri.view(),
+                    _ => a_tri.t(),
+                };
+                let x = DTRSM::default()
+                    .a(a_tri.view())
+                    .b(b.view())
+                    .uplo(uplo)
+                    .transa(transa)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&op_a % &x - &b).abs().max_all() < 1e-12);
+                let x = DTRSM::default()
+                    .a(a_tri.view())
+                    .b(b_t.view())
+                    .uplo(uplo)
+                    .transa(transa)
+                    .side(FlagSide::R)
+                    .alpha(2.0)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&x % &op_a - &b_t * 2.0).abs().max_all() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dsyrk() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 7], 0, &device);
+        let c_ref = &a % &a.t();
+        let a_t = a.t().into_contig(ColMajor);
+        for uplo in [Lower, Upper] {
+            let tri = |c: &Tensor<f64, DeviceBLAS, Ix2>| match uplo {
+                Lower => rt::tril(c),
+                Upper => rt::triu(c),
+            };
+            // only the `uplo` triangle is computed
+            let c = DSYRK::default().a(a.view()).uplo(uplo).build().unwrap().run().unwrap().into_owned();
+            assert!((tri(&c) - tri(&c_ref)).abs().max_all() < 1e-12);
+            let c = DSYRK::default()
+                .a(a_t.view())
+                .uplo(uplo)
+                .trans(Trans)
+                .symmetrize(true)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap()
+                .into_owned();
+            assert!((&c - &c_ref).abs().max_all() < 1e-12);
+            // alpha, beta and col-major output
+            let mut c = rand_f64([5, 5], 1, &device).into_contig(ColMajor);
+            let c_ref = 2.0 * &c_ref + 0.5 * &c;
+            let c = DSYRK::default()
+                .a(a.view())
+                .c(c.view_mut())
+                .alpha(2.0)
+                .beta(0.5)
+                .uplo(uplo)
+                .symmetrize(true)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap()
+                .into_owned();
+            assert!((tri(&c) - tri(&c_ref)).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_zherk() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([7, 5], 0, &device);
+        let c_ref = &conj_t(&a) % &a;
+        let a_f = a.to_contig(ColMajor).into_owned();
+        for uplo in [Lower, Upper] {
+            for a in [&a, &a_f] {
+                let c = ZHERK::default()
+                    .a(a.view())
+                    .uplo(uplo)
+                    .trans(ConjTrans)
+                    .symmetrize(true)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&c - &c_ref).abs().max_all() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dsyr2k() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 7], 0, &device);
+        let b = rand_f64([5, 7], 1, &device);
+        let c_ref = 2.0 * (&a % &b.t() + &b % &a.t());
+        for uplo in [Lower, Upper] {
+            let c = DSYR2K::default()
+                .a(a.view())
+                .b(b.view())
+                .alpha(2.0)
+                .uplo(uplo)
+                .symmetrize(true)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap()
+                .into_owned();
+            assert!((&c - &c_ref).abs().max_all() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_zher2k() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 7], 0, &device);
+        let b = rand_c64([5, 7], 2, &device);
+        let alpha = c64::new(0.5, 1.5);
+        let c_ref = alpha * (&a % &conj_t(&b)) + alpha.conj() * (&b % &conj_t(&a));
+        for order in [RowMajor, ColMajor] {
+            let a = a.to_contig(order).into_owned();
+            let b = b.to_contig(order).into_owned();
+            for uplo in [Lower, Upper] {
+                let c = ZHER2K::default()
+                    .a(a.view())
+                    .b(b.view())
+                    .alpha(alpha)
+                    .uplo(uplo)
+                    .symmetrize(true)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&c - &c_ref).abs().max_all() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_dtrmm() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let b = rand_f64([6, 3], 1, &device);
+        let b_t = b.t().into_contig(RowMajor);
+        for uplo in [Lower, Upper] {
+            let a_tri = match uplo {
+                Lower => rt::tril(&a),
+                Upper => rt::triu(&a),
+            };
+            for transa in [NoTrans, Trans] {
+                let op_a = match transa {
+                    NoTrans => a_tri.view(),
+                    _ => a_tri.t(),
+                };
+                let x = DTRMM::default()
+                    .a(a.view())
+                    .b(b.view())
+                    .uplo(uplo)
+                    .transa(transa)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&x - &op_a % &b).abs().max_all() < 1e-12);
+                let x = DTRMM::default()
+                    .a(a.view())
+                    .b(b_t.view())
+                    .uplo(uplo)
+                    .transa(transa)
+                    .side(FlagSide::R)
+                    .alpha(2.0)
+                    .build()
+                    .unwrap()
+                    .run()
+                    .unwrap()
+                    .into_owned();
+                assert!((&x - (&b_t % &op_a) * 2.0).abs().max_all() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use super::*;
+use rstsr_blas_traits::lapack_eigh::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dsyev() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([8, 8], 0, &device);
+        let a = &a + &a.t();
+        for uplo in [Lower, Upper] {
+            let (w, v) = DSYEV::default().a(a.view()).uplo(uplo).build().unwrap().run().unwrap();
+            let v = v.into_owned();
+            // ascending order
+            assert!((0..7).all(|i| w[[i]] <= w[[i + 1]]));
+            assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+            assert!((v.t() % &v - rt::eye((8, &device))).abs().max_all() < 1e-10);
+            // eigenvalues only
+            let (w_n, _) = DSYEVD::default().a(a.view()).uplo(uplo).jobz('N').build().unwrap().run().unwrap();
+            assert!((&w_n - &w).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zheev() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 6], 0, &device);
+        let a = &a + &conj_t(&a);
+        for uplo in [Lower, Upper] {
+            let (w, v) = ZHEEV::default().a(a.view()).uplo(uplo).build().unwrap().run().unwrap();
+            let v = v.into_owned();
+            let w = w.mapv(|x| c64::new(x, 0.0));
+            assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+            assert!((conj_t(&v) % &v - rt::eye((6, &device))).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_dsygv() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let a = &a + &a.t();
+        let b = rand_f64([6, 6], 1, &device);
+        let b = &b % &b.t() + rt::eye((6, &device)).into_dim::<Ix2>();
+        for uplo in [Lower, Upper] {
+            for itype in [1, 2, 3] {
+                let (w, v) =
+                    DSYGV::default().a(a.view()).b(b.view()).uplo(uplo).itype(itype).build().unwrap().run().unwrap();
+                let v = v.into_owned();
+                let wv = &v * w.i((None, ..));
+                let diff = match itype {
+                    1 => &a % &v - &b % &wv,
+                    2 => &a % &b % &v - &wv,
+                    _ => &b % &a % &v - &wv,
+                };
+                assert!(diff.abs().max_all() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zhegvd() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 5], 0, &device);
+        let a = &a + &conj_t(&a);
+        let b = rand_c64([5, 5], 2, &device);
+        let b = &b % &conj_t(&b) + rt::eye((5, &device)).into_dim::<Ix2>();
+        let (w, v) = ZHEGVD::default().a(a.view()).b(b.view()).build().unwrap().run().unwrap();
+        let v = v.into_owned();
+        let w = w.mapv(|x| c64::new(x, 0.0));
+        assert!((&a % &v - &b % (&v * w.i((None, ..)))).abs().max_all() < 1e-9);
+        // B-orthonormality
+        assert!((conj_t(&v) % &b % &v - rt::eye((5, &device))).abs().max_all() < 1e-9);
+    }
+
+    #[test]
+    fn test_dsyevr() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([8, 8], 0, &device);
+        let a = &a + &a.t();
+        let (w_full, _) = DSYEV::default().a(a.view()).jobz('N').build().unwrap().run().unwrap();
+        for uplo in [Lower, Upper] {
+            let (w, v) =
+                DSYEVR::default().a(a.view()).uplo(uplo).range('I').il(2).iu(4).build().unwrap().run().unwrap();
+            let v = v.unwrap();
+            assert_eq!(v.shape(), &[8, 3]);
+            assert!((&w - w_full.i(1..4)).abs().max_all() < 1e-10);
+            assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+            // by value, with eigenvalues only
+            let (vl, vu) = (w_full[[0]], w_full[[3]]);
+            let (w, v) = DSYEVX::default()
+                .a(a.view())
+                .uplo(uplo)
+                .jobz('N')
+                .range('V')
+                .vl(vl)
+                .vu(vu)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap();
+            assert!(v.is_none());
+            assert!((&w - w_full.i(1..4)).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zhegvx() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 5], 0, &device);
+        let a = &a + &conj_t(&a);
+        let b = rand_c64([5, 5], 2, &device);
+        let b = &b % &conj_t(&b) + rt::eye((5, &device)).into_dim::<Ix2>();
+        let (w_full, _) = ZHEGV::default().a(a.view()).b(b.view()).jobz('N').build().unwrap().run().unwrap();
+        let (w, v) = ZHEGVX::default().a(a.view()).b(b.view()).range('I').il(1).iu(2).build().unwrap().run().unwrap();
+        let v = v.unwrap();
+        assert!((&w - w_full.i(..2)).abs().max_all() < 1e-9);
+        let w = w.mapv(|x| c64::new(x, 0.0));
+        assert!((&a % &v - &b % (&v * w.i((None, ..)))).abs().max_all() < 1e-9);
+    }
+}
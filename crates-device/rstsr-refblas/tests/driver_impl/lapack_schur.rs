@@ -0,0 +1,183 @@
+use super::*;
+use rstsr_blas_traits::lapack_schur::*;
+use rstsr_blas_traits::lapack_svd::*;
+
+/// Check that `t` is upper quasi-triangular with non-adjacent 2x2 blocks.
+fn is_quasi_triangular(t: &Tensor<f64, DeviceBLAS, Ix2>) -> bool {
+    let n = t.shape()[0];
+    let lower = (0..n).all(|j| (j + 2..n).all(|i| t[[i, j]] == 0.0));
+    let blocks = (1..n.max(2) - 1).all(|k| t[[k, k - 1]] == 0.0 || t[[k + 1, k]] == 0.0);
+    lower && blocks
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dgees() {
+        let device = DeviceBLAS::default();
+        for n in [1, 2, 5, 9] {
+            let a = rand_f64([n, n], n as u64, &device);
+            let a_f = a.to_contig(ColMajor).into_owned();
+            for a in [&a, &a_f] {
+                let (t, wr, wi, z) = DGEES::default().a(a.view()).build().unwrap().run().unwrap();
+                let (t, z) = (t.into_owned(), z.unwrap());
+                assert!(is_quasi_triangular(&t));
+                assert!((z.t() % &z - rt::eye((n, &device))).abs().max_all() < 1e-10);
+                assert!((&z % &t % z.t() - a).abs().max_all() < 1e-10);
+                // eigenvalues: trace and complex pairs
+                assert!((wr.sum_all() - a.diagonal(None).sum_all()).abs() < 1e-10);
+                assert!((wi.sum_all()).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_zgees() {
+        let device = DeviceBLAS::default();
+        let n = 6;
+        let a = rand_c64([n, n], 0, &device);
+        let (t, wr, wi, z) = ZGEES::default().a(a.view()).build().unwrap().run().unwrap();
+        let (t, z) = (t.into_owned(), z.unwrap());
+        assert!((0..n).all(|j| (j + 1..n).all(|i| t[[i, j]] == c64::new(0.0, 0.0))));
+        assert!((conj_t(&z) % &z - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&z % &t % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        assert!((0..n).all(|i| (c64::new(wr[[i]], wi[[i]]) - t[[i, i]]).norm() < 1e-14));
+    }
+
+    #[test]
+    fn test_dgehrd_dorghr() {
+        let device = DeviceBLAS::default();
+        let n = 7;
+        let a = rand_f64([n, n], 0, &device);
+        let (h, tau) = DGEHRD::default().a(a.view()).build().unwrap().run().unwrap();
+        let h = h.into_owned();
+        assert_eq!(tau.shape(), &[n - 1]);
+        let q = DORGHR::default().a(h.view()).tau(tau.view()).build().unwrap().run().unwrap().into_owned();
+        let h = rt::triu((h, -1));
+        assert!((q.t() % &q - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&q % &h % q.t() - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_dgges() {
+        let device = DeviceBLAS::default();
+        for n in [1, 3, 6] {
+            let a = rand_f64([n, n], 0, &device);
+            let b = rand_f64([n, n], 1, &device);
+            let (s, t, alphar, alphai, beta, q, z) =
+                DGGES::default().a(a.view()).b(b.view()).build().unwrap().run().unwrap();
+            let (s, t, q, z) = (s.into_owned(), t.into_owned(), q.unwrap(), z.unwrap());
+            assert!(is_quasi_triangular(&s));
+            assert!((0..n).all(|j| (j + 1..n).all(|i| t[[i, j]] == 0.0)));
+            assert!((q.t() % &q - rt::eye((n, &device))).abs().max_all() < 1e-10);
+            assert!((z.t() % &z - rt::eye((n, &device))).abs().max_all() < 1e-10);
+            assert!((&q % &s % z.t() - &a).abs().max_all() < 1e-10);
+            assert!((&q % &t % z.t() - &b).abs().max_all() < 1e-10);
+            // generalized eigenvalues satisfy det(beta A - alpha B) = 0
+            for i in 0..n {
+                let lam = c64::new(alphar[[i]], alphai[[i]]) / beta[[i]];
+                let m = a.mapv(|x| c64::new(x, 0.0)) - b.mapv(|x| c64::new(x, 0.0)) * lam;
+                let (s, _, _, _) = ZGESVD::default().a(m.view()).compute_uv(false).build().unwrap().run().unwrap();
+                assert!(s[[n - 1]] < 1e-8 * (1.0 + lam.norm()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_zgges() {
+        let device = DeviceBLAS::default();
+        let n = 5;
+        let a = rand_c64([n, n], 0, &device);
+        let b = rand_c64([n, n], 2, &device);
+        let (s, t, _, _, beta, q, z) = ZGGES::default().a(a.view()).b(b.view()).build().unwrap().run().unwrap();
+        let (s, t, q, z) = (s.into_owned(), t.into_owned(), q.unwrap(), z.unwrap());
+        assert!((0..n).all(|j| (j + 1..n).all(|i| s[[i, j]].norm() == 0.0 && t[[i, j]].norm() == 0.0)));
+        assert!((0..n).all(|i| t[[i, i]].im == 0.0 && t[[i, i]].re >= 0.0 && beta[[i]] == t[[i, i]]));
+        assert!((conj_t(&q) % &q - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((conj_t(&z) % &z - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&q % &s % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        assert!((&q % &t % conj_t(&z) - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_dtrsen() {
+        let device = DeviceBLAS::default();
+        let n = 8;
+        let a = rand_f64([n, n], 3, &device);
+        let (t, wr, wi, z) = DGEES::default().a(a.view()).build().unwrap().run().unwrap();
+        let (t, z) = (t.into_owned(), z.unwrap());
+        // select eigenvalues with negative real part
+        let select = (0..n).map(|i| wr[[i]] < 0.0).collect::<Vec<_>>();
+        let nsel = select.iter().filter(|&&x| x).count();
+        let (t2, z2, wr2, wi2, m) =
+            DTRSEN::default().t(t.view()).q(z.view()).select(select).build().unwrap().run().unwrap();
+        let (t2, z2) = (t2.into_owned(), z2.unwrap().into_owned());
+        assert_eq!(m, nsel);
+        assert!(is_quasi_triangular(&t2));
+        assert!((0..m).all(|i| wr2[[i]] < 0.0) && (m..n).all(|i| wr2[[i]] >= 0.0));
+        assert!((z2.t() % &z2 - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&z2 % &t2 % z2.t() - &a).abs().max_all() < 1e-10);
+        // spectrum is preserved
+        let mut ev1 = (0..n).map(|i| (wr[[i]], wi[[i]])).collect::<Vec<_>>();
+        let mut ev2 = (0..n).map(|i| (wr2[[i]], wi2[[i]])).collect::<Vec<_>>();
+        ev1.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        ev2.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!(ev1.iter().zip(&ev2).all(|(x, y)| (x.0 - y.0).abs() < 1e-10 && (x.1 - y.1).abs() < 1e-10));
+    }
+
+    #[test]
+    fn test_ztrsen() {
+        let device = DeviceBLAS::default();
+        let n = 6;
+        let a = rand_c64([n, n], 4, &device);
+        let (t, wr, _, z) = ZGEES::default().a(a.view()).build().unwrap().run().unwrap();
+        let (t, z) = (t.into_owned(), z.unwrap());
+        let select = (0..n).map(|i| wr[[i]] > 0.0).collect::<Vec<_>>();
+        let (t2, z2, wr2, _, m) =
+            ZTRSEN::default().t(t.view()).q(z.view()).select(select).build().unwrap().run().unwrap();
+        let (t2, z2) = (t2.into_owned(), z2.unwrap().into_owned());
+        assert!((0..m).all(|i| wr2[[i]] > 0.0) && (m..n).all(|i| wr2[[i]] <= 0.0));
+        assert!((0..n).all(|j| (j + 1..n).all(|i| t2[[i, j]].norm() == 0.0)));
+        assert!((conj_t(&z2) % &z2 - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&z2 % &t2 % conj_t(&z2) - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_dtrsyl() {
+        let device = DeviceBLAS::default();
+        let (m, n) = (5, 4);
+        let (a, b) = (rand_f64([m, m], 0, &device), rand_f64([n, n], 1, &device));
+        let (ta, _, _, _) = DGEES::default().a(a.view()).build().unwrap().run().unwrap();
+        let (tb, _, _, _) = DGEES::default().a(b.view()).build().unwrap().run().unwrap();
+        let (ta, tb) = (ta.into_owned(), tb.into_owned());
+        let c = rand_f64([m, n], 2, &device);
+        for (trana, tranb, isgn) in
+            [(NoTrans, NoTrans, 1), (Trans, NoTrans, -1), (NoTrans, Trans, 1), (Trans, Trans, -1)]
+        {
+            let (x, scale) = DTRSYL::default()
+                .a(ta.view())
+                .b(tb.view())
+                .c(c.view())
+                .trana(trana)
+                .tranb(tranb)
+                .isgn(isgn)
+                .build()
+                .unwrap()
+                .run()
+                .unwrap();
+            let x = x.into_owned();
+            let opa = if trana == NoTrans { ta.view() } else { ta.t() };
+            let opb = if tranb == NoTrans { tb.view() } else { tb.t() };
+            let lhs = &opa % &x + (isgn as f64) * (&x % &opb);
+            assert!((lhs - scale * &c).abs().max_all() < 1e-10);
+        }
+
+        // NaN propagates to the solution instead of panicking in pivoting
+        let mut ta_nan = ta.clone();
+        ta_nan[[0, 0]] = f64::NAN;
+        let (x, _) = DTRSYL::default().a(ta_nan.view()).b(tb.view()).c(c.view()).build().unwrap().run().unwrap();
+        assert!(x.into_owned().iter().any(|v| v.is_nan()));
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+use rstsr_blas_traits::lapack_solve::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dgesv() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let b = rand_f64([6, 3], 1, &device);
+        let (_, _, x) = DGESV::default().a(a.view()).b(b.view()).build().unwrap().run().unwrap();
+        assert!((&a % x.into_owned() - &b).abs().max_all() < 1e-10);
+        // col-major input
+        let a_f = a.to_contig(ColMajor);
+        let b_f = b.to_contig(ColMajor);
+        let (_, _, x) = DGESV::default().a(a_f.view()).b(b_f.view()).build().unwrap().run().unwrap();
+        assert!((&a % x.into_owned() - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_dsysv() {
+        let device = DeviceBLAS::default();
+        // symmetric indefinite, with zero diagonal to force 2x2 pivots
+        let a = rand_f64([7, 7], 0, &device);
+        let mut a = &a + &a.t();
+        (0..7).for_each(|i| a[[i, i]] = 0.0);
+        let b = rand_f64([7, 2], 1, &device);
+        for uplo in [Lower, Upper] {
+            let (_, _, x) = DSYSV::default().a(a.view()).b(b.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&a % x.into_owned() - &b).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zsysv_zhesv() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 6], 0, &device);
+        let b = rand_c64([6, 2], 2, &device);
+        let a_sy = &a + &a.t();
+        let a_he = &a + &conj_t(&a);
+        for uplo in [Lower, Upper] {
+            let (_, _, x) = ZSYSV::default().a(a_sy.view()).b(b.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&a_sy % x.into_owned() - &b).abs().max_all() < 1e-10);
+            let (_, _, x) = ZHESV::default().a(a_he.view()).b(b.view()).uplo(uplo).build().unwrap().run().unwrap();
+            assert!((&a_he % x.into_owned() - &b).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_dgeqrf_dorgqr() {
+        let device = DeviceBLAS::default();
+        let (m, n) = (7, 4);
+        // rank-deficient: the second column vanishes
+        let mut a = rand_f64([m, n], 0, &device);
+        (0..m).for_each(|i| a[[i, 1]] = 0.0);
+        let a_f = a.to_contig(ColMajor).into_owned();
+        for a in [&a, &a_f] {
+            let (r, tau) = DGEQRF::default().a(a.view()).build().unwrap().run().unwrap();
+            let r = r.into_owned();
+            assert_eq!(tau.shape(), &[n]);
+            let q = DORGQR::default().a(r.view()).tau(tau.view()).build().unwrap().run().unwrap().into_owned();
+            let r = rt::triu(r.i((..n, ..)).to_owned());
+            assert!((q.t() % &q - rt::eye((n, &device))).abs().max_all() < 1e-10);
+            assert!((&q % &r - a).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zgeqrf_zungqr() {
+        let device = DeviceBLAS::default();
+        let (m, n) = (6, 5);
+        let a = rand_c64([m, n], 0, &device);
+        let (r, tau) = ZGEQRF::default().a(a.view()).build().unwrap().run().unwrap();
+        let r = r.into_owned();
+        let q = ZUNGQR::default().a(r.view()).tau(tau.view()).build().unwrap().run().unwrap().into_owned();
+        let r = rt::triu(r.i((..n, ..)).to_owned());
+        assert!((conj_t(&q) % &q - rt::eye((n, &device))).abs().max_all() < 1e-10);
+        assert!((&q % &r - &a).abs().max_all() < 1e-10);
+    }
+}
@@ -0,0 +1,118 @@
+use super::*;
+use rstsr_blas_traits::lapack_svd::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dgesvd() {
+        let device = DeviceBLAS::default();
+        for shape in [[7, 4], [4, 7], [5, 5]] {
+            let a = rand_f64(shape, 0, &device);
+            let k = shape[0].min(shape[1]);
+
+            let (s, u, vt, _) = DGESVD::default().a(a.view()).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert_eq!(u.shape(), &[shape[0], shape[0]]);
+            assert_eq!(vt.shape(), &[shape[1], shape[1]]);
+            assert!((0..k - 1).all(|i| s[[i]] >= s[[i + 1]]));
+            assert!((u.t() % &u - rt::eye((shape[0], &device))).abs().max_all() < 1e-10);
+            assert!((&vt % vt.t() - rt::eye((shape[1], &device))).abs().max_all() < 1e-10);
+            let a_rebuilt = (u.i((.., ..k)) * s.i((None, ..))) % vt.i((..k, ..));
+            assert!((&a_rebuilt - &a).abs().max_all() < 1e-10);
+
+            let (s_thin, u, vt, _) = DGESVD::default().a(a.view()).full_matrices(false).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert!((&s_thin - &s).abs().max_all() < 1e-10);
+            assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+            let (s_n, u, vt, _) =
+                DGESVD::default().a(a.view()).full_matrices(false).compute_uv(false).build().unwrap().run().unwrap();
+            assert!(u.is_none() && vt.is_none());
+            assert!((&s_n - &s).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zgesdd() {
+        let device = DeviceBLAS::default();
+        for shape in [[6, 3], [3, 6]] {
+            let a = rand_c64(shape, 0, &device);
+            let k = shape[0].min(shape[1]);
+            let (s, u, vt) = ZGESDD::default().a(a.view()).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert!((conj_t(&u) % &u - rt::eye((shape[0], &device))).abs().max_all() < 1e-10);
+            assert!((&vt % conj_t(&vt) - rt::eye((shape[1], &device))).abs().max_all() < 1e-10);
+            let s = s.mapv(|x| c64::new(x, 0.0));
+            let a_rebuilt = (u.i((.., ..k)) * s.i((None, ..))) % vt.i((..k, ..));
+            assert!((&a_rebuilt - &a).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let device = DeviceBLAS::default();
+        // rank-2 matrix, left vectors of zero singular values must still be
+        // orthonormal
+        let x = rand_f64([6, 2], 0, &device);
+        let y = rand_f64([2, 4], 1, &device);
+        let a = &x % &y;
+        let (s, u, _, _) = DGESVD::default().a(a.view()).build().unwrap().run().unwrap();
+        let u = u.unwrap();
+        assert!(s[[2]] < 1e-12 && s[[3]] < 1e-12);
+        assert!((u.t() % &u - rt::eye((6, &device))).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_dgesvdx() {
+        let device = DeviceBLAS::default();
+        for shape in [[7, 4], [4, 7]] {
+            let a = rand_f64(shape, 0, &device);
+            let (s, u, vt) = DGESVDX::default().a(a.view()).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert_eq!(s.shape(), &[4]);
+            assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+            // subset by index (1-based)
+            let (s_i, u_i, vt_i) =
+                DGESVDX::default().a(a.view()).range('I').il(2).iu(3).build().unwrap().run().unwrap();
+            let (u_i, vt_i) = (u_i.unwrap(), vt_i.unwrap());
+            assert_eq!(u_i.shape(), &[shape[0], 2]);
+            assert_eq!(vt_i.shape(), &[2, shape[1]]);
+            assert!((&s_i - s.i(1..3)).abs().max_all() < 1e-10);
+            assert!((&a % vt_i.t() - u_i * s_i.i((None, ..))).abs().max_all() < 1e-10);
+
+            // subset by value, half-open interval (vl, vu]
+            let (vl, vu) = (s[[2]], s[[0]]);
+            let (s_v, _, vt_v) =
+                DGESVDX::default().a(a.view()).range('V').vl(vl).vu(vu).jobu('N').build().unwrap().run().unwrap();
+            assert_eq!(s_v.shape(), &[2]);
+            assert!((&s_v - s.i(..2)).abs().max_all() < 1e-10);
+            assert!(vt_v.is_some());
+        }
+    }
+
+    #[test]
+    fn test_zgejsv() {
+        let device = DeviceBLAS::default();
+        for shape in [[6, 3], [3, 6]] {
+            let a = rand_c64(shape, 0, &device);
+            let (s_ref, _, _) = ZGESDD::default().a(a.view()).build().unwrap().run().unwrap();
+            let (s, u, vt) = ZGEJSV::default().a(a.view()).full_matrices(false).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert!((&s - &s_ref).abs().max_all() < 1e-10);
+            let s = s.mapv(|x| c64::new(x, 0.0));
+            assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+            let (_, u, vt) = ZGEJSV::default().a(a.view()).build().unwrap().run().unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            assert!((conj_t(&u) % &u - rt::eye((shape[0], &device))).abs().max_all() < 1e-10);
+            assert!((&vt % conj_t(&vt) - rt::eye((shape[1], &device))).abs().max_all() < 1e-10);
+
+            let (s_n, u, vt) = ZGEJSV::default().a(a.view()).compute_u(false).build().unwrap().run().unwrap();
+            assert!(u.is_none() && vt.is_some());
+            assert!((&s_n - &s_ref).abs().max_all() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Driver tests of the reference device.
+//!
+//! Native LAPACK fingerprints are not reproducible by a different algorithm,
+//! so these tests check residuals and orthonormality on small deterministic
+//! matrices instead.
+
+mod blas1;
+mod blas2;
+mod blas3;
+mod lapack_eigh;
+mod lapack_schur;
+mod lapack_solve;
+mod lapack_svd;
+
+use num::Complex;
+use rstsr_core::prelude::*;
+pub use rstsr_core::prelude_dev::FlagSide;
+use rstsr_refblas::DeviceRefBLAS as DeviceBLAS;
+
+#[allow(non_camel_case_types)]
+pub type c64 = Complex<f64>;
+
+/// Deterministic pseudo-random numbers in `[-1, 1)`.
+pub fn rand_vec(n: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (0..n)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+        })
+        .collect()
+}
+
+pub fn rand_f64(shape: [usize; 2], seed: u64, device: &DeviceBLAS) -> Tensor<f64, DeviceBLAS, Ix2> {
+    rt::asarray((rand_vec(shape[0] * shape[1], seed), shape.c(), device)).into_dim::<Ix2>()
+}
+
+pub fn rand_f64_vec(n: usize, seed: u64, device: &DeviceBLAS) -> Tensor<f64, DeviceBLAS, Ix1> {
+    rt::asarray((rand_vec(n, seed), device)).into_dim::<Ix1>()
+}
+
+pub fn rand_c64(shape: [usize; 2], seed: u64, device: &DeviceBLAS) -> Tensor<c64, DeviceBLAS, Ix2> {
+    let re = rand_vec(shape[0] * shape[1], seed);
+    let im = rand_vec(shape[0] * shape[1], seed + 1);
+    let vec = re.into_iter().zip(im).map(|(re, im)| c64::new(re, im)).collect::<Vec<_>>();
+    rt::asarray((vec, shape.c(), device)).into_dim::<Ix2>()
+}
+
+pub fn conj_t(a: &Tensor<c64, DeviceBLAS, Ix2>) -> Tensor<c64, DeviceBLAS, Ix2> {
+    a.t().mapv(|x| x.conj())
+}
@@ -0,0 +1,189 @@
+use super::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Hermitian positive definite matrix.
+    fn hpd(n: usize, seed: u64, device: &DeviceBLAS) -> Tensor<c64, DeviceBLAS, Ix2> {
+        let b = rand_c64([n, n], seed, device);
+        &b % conj_t(&b) + eye(n, device)
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let device = DeviceBLAS::default();
+        let b = hpd(6, 0, &device);
+        let c = rt::linalg::cholesky(b.view()).into_dim::<Ix2>();
+        assert!((&c % conj_t(&c) - &b).abs().max_all() < 1e-10);
+        let c = rt::linalg::cholesky((b.view(), Upper)).into_dim::<Ix2>();
+        assert!((conj_t(&c) % &c - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_eigh() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 6], 0, &device);
+        let a = &a + conj_t(&a);
+        let b = hpd(6, 2, &device);
+
+        let (w, v) = rt::linalg::eigh((a.view(), Upper)).into();
+        let v = v.into_dim::<Ix2>();
+        let w = w.mapv(|x| c64::new(x, 0.0));
+        assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+
+        let (w, v) = rt::linalg::eigh((a.view(), b.view())).into();
+        let v = v.into_dim::<Ix2>();
+        let w = w.mapv(|x| c64::new(x, 0.0));
+        assert!((&a % &v - &b % (&v * w.i((None, ..)))).abs().max_all() < 1e-9);
+    }
+
+    #[test]
+    fn test_inv_det() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 5], 0, &device);
+        let a_inv = rt::linalg::inv(a.view());
+        assert!((&a % &a_inv - eye::<c64>(5, &device)).abs().max_all() < 1e-10);
+        // det(A) det(inv(A)) = 1
+        let det = rt::linalg::det(a.view()) * rt::linalg::det(a_inv.view());
+        assert!((det - c64::new(1.0, 0.0)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 6], 0, &device);
+        let b = rand_c64([6, 2], 2, &device);
+
+        let x = rt::linalg::solve_general((a.view(), b.view()));
+        assert!((&a % &x - &b).abs().max_all() < 1e-10);
+
+        let a_he = &a + conj_t(&a);
+        let x = rt::linalg::solve_symmetric((a_he.view(), b.view(), true, Upper));
+        assert!((&a_he % &x - &b).abs().max_all() < 1e-10);
+
+        let a_sy = &a + a.t();
+        let x = rt::linalg::solve_symmetric((a_sy.view(), b.view(), false, Lower));
+        assert!((&a_sy % &x - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_svd() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([4, 7], 0, &device);
+        let (u, s, vt) = rt::linalg::svd((a.view(), false)).into();
+        let s = s.mapv(|x| c64::new(x, 0.0));
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_svd_args() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([4, 7], 0, &device);
+        let s_ref = rt::linalg::svdvals(a.view());
+        for driver in ["gesvdx", "gejsv"] {
+            let args = rt::linalg::SVDArgs::default().a(a.view()).full_matrices(false).driver(driver);
+            let (u, s, vt) = rt::linalg::svd(args).into();
+            assert!((&s - &s_ref).abs().max_all() < 1e-10);
+            let s = s.mapv(|x| c64::new(x, 0.0));
+            assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+        }
+        // thin U and VT by default when a subset is requested
+        let args = rt::linalg::SVDArgs::default().a(a.view()).subset_by_index((0, 1));
+        let (u, s, vt) = rt::linalg::svd(args).into();
+        assert!((&s - s_ref.i(..2)).abs().max_all() < 1e-10);
+        let s = s.mapv(|x| c64::new(x, 0.0));
+        assert!((&a % conj_t(&vt) - u * s.i((None, ..))).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_rsvd() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([12, 3], 0, &device) % rand_c64([3, 16], 2, &device);
+        let s_ref = rt::linalg::svdvals(a.view());
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 3)).into();
+        assert!((&s - s_ref.i(..3)).abs().max_all() < 1e-10);
+        assert!((conj_t(&u) % &u - eye::<c64>(3, &device)).abs().max_all() < 1e-10);
+        let s = s.mapv(|x| c64::new(x, 0.0));
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_polar() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([6, 4], 0, &device);
+        let (u, p) = rt::linalg::polar(a.view()).into();
+        assert!((conj_t(&u) % &u - eye::<c64>(4, &device)).abs().max_all() < 1e-10);
+        assert!((&p - conj_t(&p)).abs().max_all() < 1e-10);
+        assert!((&u % &p - &a).abs().max_all() < 1e-10);
+        let a = rand_c64([4, 4], 2, &device);
+        let (u, p) = rt::linalg::polar((a.view(), FlagSide::L)).into();
+        assert!((&p - conj_t(&p)).abs().max_all() < 1e-10);
+        assert!((&p % &u - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_orth_null_space() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([4, 2], 0, &device) % rand_c64([2, 6], 2, &device);
+        let q = rt::linalg::orth(a.view());
+        assert_eq!(q.shape(), &[4, 2]);
+        assert!((conj_t(&q) % &q - eye::<c64>(2, &device)).abs().max_all() < 1e-10);
+        assert!((&q % (conj_t(&q) % &a) - &a).abs().max_all() < 1e-10);
+        let n = rt::linalg::null_space(a.view());
+        assert_eq!(n.shape(), &[6, 4]);
+        assert!((conj_t(&n) % &n - eye::<c64>(4, &device)).abs().max_all() < 1e-10);
+        assert!((&a % &n).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_lowdin_canonical_orth() {
+        let device = DeviceBLAS::default();
+        let b = rand_c64([8, 5], 0, &device);
+        let s = conj_t(&b) % &b;
+        let x = rt::linalg::lowdin_orth(s.view());
+        assert!((&x - conj_t(&x)).abs().max_all() < 1e-10);
+        assert!((&x % &s % &x - eye::<c64>(5, &device)).abs().max_all() < 1e-10);
+        let x = rt::linalg::canonical_orth(s.view());
+        assert!((conj_t(&x) % &s % &x - eye::<c64>(5, &device)).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([20, 11], 0, &device);
+        let (q, r) = rt::linalg::gram_schmidt((a.view(), 3)).into();
+        assert!((conj_t(&q) % &q - eye::<c64>(11, &device)).abs().max_all() < 1e-12);
+        assert!((&q % &r - &a).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_schur_qz() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([5, 5], 0, &device);
+        let b = rand_c64([5, 5], 2, &device);
+        let (t, z) = rt::linalg::schur(a.view()).into();
+        assert!((0..5).all(|j| (j + 1..5).all(|i| t[[i, j]] == c64::new(0.0, 0.0))));
+        assert!((&z % &t % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        let (h, q) = rt::linalg::hessenberg(a.view()).into();
+        assert!((conj_t(&q) % &q - eye::<c64>(5, &device)).abs().max_all() < 1e-10);
+        assert!((&q % &h % conj_t(&q) - &a).abs().max_all() < 1e-10);
+        let (aa, bb, q, z) = rt::linalg::qz((a.view(), b.view())).into();
+        assert!((&q % &aa % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        assert!((&q % &bb % conj_t(&z) - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_sylvester_lyapunov() {
+        let device = DeviceBLAS::default();
+        let a = rand_c64([4, 4], 0, &device);
+        let b = rand_c64([3, 3], 2, &device);
+        let c = rand_c64([4, 3], 4, &device);
+        let x = rt::linalg::solve_sylvester((&a, &b, &c));
+        assert!((&a % &x + &x % &b - &c).abs().max_all() < 1e-10);
+        let q = rand_c64([4, 4], 6, &device);
+        let q = &q + conj_t(&q);
+        let x = rt::linalg::solve_continuous_lyapunov((&a, &q));
+        assert!((&a % &x + &x % conj_t(&a) - &q).abs().max_all() < 1e-10);
+    }
+}
@@ -0,0 +1,480 @@
+use super::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Symmetric positive definite matrix.
+    fn spd(n: usize, seed: u64, device: &DeviceBLAS) -> Tensor<f64, DeviceBLAS, Ix2> {
+        let b = rand_f64([n, n], seed, device);
+        &b % b.t() + eye(n, device)
+    }
+
+    #[test]
+    fn test_cholesky() {
+        let device = DeviceBLAS::default();
+        let mut b = spd(8, 0, &device);
+
+        // default
+        let c = rt::linalg::cholesky(b.view()).into_dim::<Ix2>();
+        assert!((&c - rt::tril(&c)).abs().max_all() < 1e-14);
+        assert!((&c % c.t() - &b).abs().max_all() < 1e-10);
+
+        // upper
+        let c = rt::linalg::cholesky((b.view(), Upper)).into_dim::<Ix2>();
+        assert!((&c - rt::triu(&c)).abs().max_all() < 1e-14);
+        assert!((c.t() % &c - &b).abs().max_all() < 1e-10);
+
+        // mutable changes itself
+        rt::linalg::cholesky((b.view_mut(), Upper));
+        assert!((&b - &c).abs().max_all() < 1e-14);
+    }
+
+    #[test]
+    fn test_det() {
+        let device = DeviceBLAS::default();
+        // det of triangular factors are known
+        let l = rt::tril(&rand_f64([5, 5], 0, &device)) + eye::<f64>(5, &device) * 2.0;
+        let u = rt::triu(&rand_f64([5, 5], 1, &device)) + eye::<f64>(5, &device) * 2.0;
+        let det_ref = (0..5).map(|i| l[[i, i]] * u[[i, i]]).product::<f64>();
+        let mut a = &l % &u;
+        let det = rt::linalg::det(a.view_mut());
+        assert!((det - det_ref).abs() < 1e-10 * det_ref.abs());
+    }
+
+    #[test]
+    fn test_eigh() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([8, 8], 0, &device);
+        let mut a = &a + a.t();
+        let b = spd(8, 1, &device);
+
+        // default, a
+        let (w, v) = rt::linalg::eigh(a.view()).into();
+        let v = v.into_dim::<Ix2>();
+        assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+
+        // upper, a
+        let (w_upper, _) = rt::linalg::eigh((a.view(), Upper)).into();
+        assert!((&w_upper - &w).abs().max_all() < 1e-10);
+
+        // default, a b
+        let (w, v) = rt::linalg::eigh((a.view(), b.view())).into();
+        let v = v.into_dim::<Ix2>();
+        assert!((&a % &v - &b % (&v * w.i((None, ..)))).abs().max_all() < 1e-9);
+
+        // upper, itype 3, a b
+        let (w, v) = rt::linalg::eigh((a.view(), b.view(), Upper, 3)).into();
+        let v = v.into_dim::<Ix2>();
+        assert!((&b % &a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-9);
+
+        // mutable changes itself into eigenvectors
+        let trace = (0..8).map(|i| a[[i, i]]).sum::<f64>();
+        let (w, _) = rt::linalg::eigh(a.view_mut()).into();
+        assert!((w.sum_all() - trace).abs() < 1e-10);
+        assert!((a.t() % &a - eye::<f64>(8, &device)).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_eigh_subset() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([8, 8], 0, &device);
+        let a = &a + a.t();
+        let b = spd(8, 1, &device);
+        let w_full = rt::linalg::eigvalsh(a.view());
+
+        // lowest three roots, by index
+        for driver in ["evr", "evx"] {
+            let args = rt::linalg::EighArgs::default().a(a.view()).subset_by_index((0, 2)).driver(driver);
+            let (w, v) = rt::linalg::eigh(args).into();
+            let v = v.into_owned();
+            assert_eq!(v.shape(), &[8, 3]);
+            assert!((&w - w_full.i(..3)).abs().max_all() < 1e-10);
+            assert!((&a % &v - &v * w.i((None, ..))).abs().max_all() < 1e-10);
+        }
+
+        // by value, half-open interval (vl, vu]
+        let (vl, vu) = (w_full[[2]], w_full[[5]]);
+        let w = rt::linalg::eigvalsh(
+            rt::linalg::EighArgs::default().a(a.view()).subset_by_value((vl, vu)).eigvals_only(true),
+        );
+        assert!((&w - w_full.i(3..6)).abs().max_all() < 1e-10);
+
+        // generalized
+        let w_full = rt::linalg::eigvalsh((a.view(), b.view()));
+        let args = rt::linalg::EighArgs::default().a(a.view()).b(b.view()).subset_by_index((5, 7));
+        let (w, v) = rt::linalg::eigh(args).into();
+        let v = v.into_owned();
+        assert!((&w - w_full.i(5..)).abs().max_all() < 1e-9);
+        assert!((&a % &v - &b % (&v * w.i((None, ..)))).abs().max_all() < 1e-9);
+
+        // drivers that can not compute subset
+        let args = rt::linalg::EighArgs::default().a(a.view()).subset_by_index((0, 2)).driver("evd");
+        assert!(rt::linalg::eigh_f(args).is_err());
+    }
+
+    #[test]
+    fn test_eigvalsh() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let a = &a + a.t();
+        let w = rt::linalg::eigvalsh(a.view());
+        // trace and frobenius norm are preserved
+        let trace = (0..6).map(|i| a[[i, i]]).sum::<f64>();
+        assert!((w.sum_all() - trace).abs() < 1e-10);
+        assert!(((&w * &w).sum_all() - (&a * &a).sum_all()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_inv() {
+        let device = DeviceBLAS::default();
+        let mut a = rand_f64([7, 7], 0, &device);
+        let a_inv = rt::linalg::inv(a.view());
+        assert!((&a % &a_inv - eye::<f64>(7, &device)).abs().max_all() < 1e-10);
+
+        // mutable changes itself
+        rt::linalg::inv(a.view_mut());
+        assert!((&a - &a_inv).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_pinv() {
+        let device = DeviceBLAS::default();
+        // rank-3 matrices of both shapes
+        for (m, n) in [(8, 5), (5, 8)] {
+            let a = rand_f64([m, 3], 0, &device) % rand_f64([3, n], 1, &device);
+            let (a_pinv, rank) = rt::linalg::pinv(a.view()).into();
+            assert_eq!(rank, 3);
+            assert_eq!(a_pinv.shape(), &[n, m]);
+            assert!((&a % &a_pinv % &a - &a).abs().max_all() < 1e-10);
+            assert!((&a_pinv % &a % &a_pinv - &a_pinv).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_slogdet() {
+        let device = DeviceBLAS::default();
+        let mut a = rand_f64([6, 6], 0, &device);
+        let det = rt::linalg::det(a.view());
+        let (sign, logabsdet) = rt::linalg::slogdet(a.view_mut()).into();
+        assert_eq!(sign, det.signum());
+        assert!((logabsdet - det.abs().ln()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_general() {
+        let device = DeviceBLAS::default();
+        let mut a = rand_f64([7, 7], 0, &device);
+        let mut b = rand_f64([7, 3], 1, &device);
+        let a_ref = a.to_owned();
+        let b_ref = b.to_owned();
+
+        // default
+        let x = rt::linalg::solve_general((a.view(), b.view()));
+        assert!((&a_ref % &x - &b_ref).abs().max_all() < 1e-10);
+
+        // mutable changes itself
+        rt::linalg::solve_general((a.view_mut(), b.view_mut()));
+        assert!((&b - &x).abs().max_all() < 1e-12);
+
+        // vector
+        let bv = b_ref.i((.., 0)).to_owned();
+        let x = rt::linalg::solve_general((a_ref.view(), bv.view()));
+        assert!((&a_ref % &x - &bv).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_symmetric() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([7, 7], 0, &device);
+        let a = &a + a.t();
+        let mut b = rand_f64([7, 3], 1, &device);
+        let b_ref = b.to_owned();
+
+        // default
+        let x = rt::linalg::solve_symmetric((a.view(), b.view()));
+        assert!((&a % &x - &b_ref).abs().max_all() < 1e-10);
+
+        // upper, mutable changes itself
+        rt::linalg::solve_symmetric((a.view(), b.view_mut(), Upper));
+        assert!((&b - &x).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_triangular() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([7, 7], 0, &device) + eye::<f64>(7, &device) * 4.0;
+        let mut b = rand_f64([7, 3], 1, &device);
+        let b_ref = b.to_owned();
+
+        // default, lower
+        let x = rt::linalg::solve_triangular((a.view(), b.view()));
+        assert!((rt::tril(&a) % &x - &b_ref).abs().max_all() < 1e-10);
+
+        // upper, mutable changes itself
+        rt::linalg::solve_triangular((a.view(), b.view_mut(), Upper));
+        assert!((rt::triu(&a) % &b - &b_ref).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_svd() {
+        let device = DeviceBLAS::default();
+        for (m, n) in [(9, 5), (5, 9)] {
+            let a = rand_f64([m, n], 0, &device);
+            let k = m.min(n);
+
+            // full matrices
+            let (u, s, vt) = rt::linalg::svd(a.view()).into();
+            let (u, vt) = (u.into_dim::<Ix2>(), vt.into_dim::<Ix2>());
+            assert_eq!(u.shape(), &[m, m]);
+            assert_eq!(vt.shape(), &[n, n]);
+            assert!((u.t() % &u - eye::<f64>(m, &device)).abs().max_all() < 1e-10);
+            assert!((&vt % vt.t() - eye::<f64>(n, &device)).abs().max_all() < 1e-10);
+            assert!(((u.i((.., ..k)) * s.i((None, ..))) % vt.i((..k, ..)) - &a).abs().max_all() < 1e-10);
+
+            // thin
+            let (u, s, vt) = rt::linalg::svd((a.view(), false)).into();
+            assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_svd_args() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([8, 5], 0, &device);
+        let s_ref = rt::linalg::svdvals(a.view());
+
+        // subset by index, selected by gesvdx; thin U and VT by default
+        let args = rt::linalg::SVDArgs::default().a(a.view()).subset_by_index((1, 2));
+        let (u, s, vt) = rt::linalg::svd(args).into();
+        assert_eq!(u.shape(), &[8, 2]);
+        assert!((&s - s_ref.i(1..3)).abs().max_all() < 1e-10);
+        assert!((&a % vt.t() - u * s.i((None, ..))).abs().max_all() < 1e-10);
+
+        // subset by value (vl, vu], singular values only
+        let args =
+            rt::linalg::SVDArgs::default().a(a.view()).compute_uv(false).subset_by_value((s_ref[[3]], s_ref[[1]]));
+        let (u, s, vt) = rt::linalg::svd_partial(args).into();
+        assert!(u.is_none() && vt.is_none());
+        assert!((&s - s_ref.i(1..3)).abs().max_all() < 1e-10);
+
+        // `svd` always returns both U and VT
+        let args = rt::linalg::SVDArgs::default().a(a.view()).compute_uv(false);
+        assert!(rt::linalg::svd_f(args).is_err());
+        let args = rt::linalg::SVDArgs::default().a(a.view()).compute_u_only(true);
+        assert!(rt::linalg::svd_f(args).is_err());
+
+        // subset is not supported by gesdd
+        let args = rt::linalg::SVDArgs::default().a(a.view()).subset_by_index((0, 1)).driver("gesdd");
+        assert!(rt::linalg::svd_f(args).is_err());
+
+        // high accuracy Jacobi SVD
+        let args = rt::linalg::SVDArgs::default().a(a.view()).full_matrices(false).driver("gejsv");
+        let (u, s, vt) = rt::linalg::svd(args).into();
+        assert!((&s - &s_ref).abs().max_all() < 1e-10);
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_rsvd() {
+        let device = DeviceBLAS::default();
+        // rank-4 matrix, recovered exactly by rank-4 randomized SVD
+        let a = rand_f64([30, 4], 0, &device) % rand_f64([4, 20], 1, &device);
+        let s_ref = rt::linalg::svdvals(a.view());
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 4)).into();
+        assert_eq!(u.shape(), &[30, 4]);
+        assert_eq!(vt.shape(), &[4, 20]);
+        assert!((&s - s_ref.i(..4)).abs().max_all() < 1e-10);
+        assert!((u.t() % &u - eye::<f64>(4, &device)).abs().max_all() < 1e-10);
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+        // leading singular values of a full-rank matrix, with power iterations
+        let a = rand_f64([40, 25], 2, &device);
+        let s_ref = rt::linalg::svdvals(a.view());
+        let (_, s, _) = rt::linalg::rsvd((a.view(), 3, 10, 8)).into();
+        assert!(((&s - s_ref.i(..3)) / s_ref.i(..3)).abs().max_all() < 1e-2);
+
+        // rank-deficient samples: only the first 3 columns are nonzero
+        let mut a = rand_f64([30, 20], 3, &device);
+        (0..30).for_each(|i| (3..20).for_each(|j| a[[i, j]] = 0.0));
+        let s_ref = rt::linalg::svdvals(a.view());
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 3)).into();
+        assert!((&s - s_ref.i(..3)).abs().max_all() < 1e-10);
+        assert!((u.t() % &u - eye::<f64>(3, &device)).abs().max_all() < 1e-10);
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+        // zero matrix
+        let a = rand_f64([30, 20], 4, &device) * 0.0;
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 4)).into();
+        assert_eq!((&s).abs().max_all(), 0.0);
+        assert!((u.t() % &u - eye::<f64>(4, &device)).abs().max_all() < 1e-10);
+        assert_eq!(vt.shape(), &[4, 20]);
+        assert_eq!(((u * s.i((None, ..))) % vt).abs().max_all(), 0.0);
+    }
+
+    #[test]
+    fn test_svdvals() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 4], 0, &device);
+        let s = rt::linalg::svdvals(a.view());
+        let w = rt::linalg::eigvalsh((a.t() % &a).view());
+        // squared singular values are eigenvalues of A^T A, in reversed order
+        let s2 = &s * &s;
+        assert!((0..4).all(|i| (s2[[i]] - w[[3 - i]]).abs() < 1e-10));
+    }
+
+    #[test]
+    fn test_polar() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 5], 0, &device);
+        // right: A = U P
+        let (u, p) = rt::linalg::polar(a.view()).into();
+        assert!((u.t() % &u - eye::<f64>(5, &device)).abs().max_all() < 1e-10);
+        assert!((&p - p.t()).abs().max_all() < 1e-10);
+        assert!(rt::linalg::eigvalsh(p.view()).min_all() > 0.0);
+        assert!((&u % &p - &a).abs().max_all() < 1e-10);
+        // left: A = P U
+        let (u, p) = rt::linalg::polar((a.view(), FlagSide::L)).into();
+        assert!((&p - p.t()).abs().max_all() < 1e-10);
+        assert!((&p % &u - &a).abs().max_all() < 1e-10);
+        // rectangular: U has orthonormal columns
+        let a = rand_f64([7, 4], 1, &device);
+        let (u, p) = rt::linalg::polar(a.view()).into();
+        assert_eq!(p.shape(), &[4, 4]);
+        assert!((u.t() % &u - eye::<f64>(4, &device)).abs().max_all() < 1e-10);
+        assert!((&u % &p - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_orth_null_space() {
+        let device = DeviceBLAS::default();
+        // rank-3 matrix of shape (6, 5)
+        let a = rand_f64([6, 3], 0, &device) % rand_f64([3, 5], 1, &device);
+
+        let q = rt::linalg::orth(a.view());
+        assert_eq!(q.shape(), &[6, 3]);
+        assert!((q.t() % &q - eye::<f64>(3, &device)).abs().max_all() < 1e-10);
+        // columns of A are in the range of Q
+        assert!((&q % (q.t() % &a) - &a).abs().max_all() < 1e-10);
+
+        let n = rt::linalg::null_space(a.view());
+        assert_eq!(n.shape(), &[5, 2]);
+        assert!((n.t() % &n - eye::<f64>(2, &device)).abs().max_all() < 1e-10);
+        assert!((&a % &n).abs().max_all() < 1e-10);
+
+        // large rcond drops everything except the largest singular value
+        let q = rt::linalg::orth((a.view(), 0.999));
+        assert_eq!(q.shape(), &[6, 1]);
+        let n = rt::linalg::null_space((a.view(), 0.999));
+        assert_eq!(n.shape(), &[5, 4]);
+    }
+
+    #[test]
+    fn test_lowdin_canonical_orth() {
+        let device = DeviceBLAS::default();
+        let b = rand_f64([8, 5], 0, &device);
+        let s = b.t() % &b;
+
+        // Lowdin: X = S^-1/2, symmetric, X S X = I
+        let x = rt::linalg::lowdin_orth(s.view());
+        assert!((&x - x.t()).abs().max_all() < 1e-10);
+        assert!((&x % &s % &x - eye::<f64>(5, &device)).abs().max_all() < 1e-10);
+
+        // canonical: X^T S X = I
+        let x = rt::linalg::canonical_orth(s.view());
+        assert_eq!(x.shape(), &[5, 5]);
+        assert!((x.t() % &s % &x - eye::<f64>(5, &device)).abs().max_all() < 1e-10);
+
+        // near-linear-dependent basis: one direction is dropped
+        let mut b = b.into_owned();
+        let col = b.i((.., 0)).to_owned() + b.i((.., 1)).to_owned() * 1e-9;
+        b.i_mut((.., 4)).assign(&col);
+        let s = b.t() % &b;
+        let x = rt::linalg::canonical_orth(s.view());
+        assert_eq!(x.shape(), &[5, 4]);
+        assert!((x.t() % &s % &x - eye::<f64>(4, &device)).abs().max_all() < 1e-6);
+        let x = rt::linalg::canonical_orth((s.view(), 1e-20));
+        assert_eq!(x.shape(), &[5, 5]);
+    }
+
+    #[test]
+    fn test_gram_schmidt() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([40, 23], 0, &device);
+        for block_size in [1, 4, 7, 32] {
+            let (q, r) = rt::linalg::gram_schmidt((a.view(), block_size)).into();
+            assert!((q.t() % &q - eye::<f64>(23, &device)).abs().max_all() < 1e-12);
+            assert!((&q % &r - &a).abs().max_all() < 1e-12);
+            assert!((0..23).all(|i| (0..i).all(|j| r[[i, j]] == 0.0)));
+        }
+        // ill-conditioned columns: orthogonality is kept by re-orthogonalization
+        let mut a = rand_f64([30, 10], 1, &device);
+        for j in 1..10 {
+            let col = a.i((.., 0)).to_owned() + a.i((.., j)).to_owned() * 1e-8;
+            a.i_mut((.., j)).assign(&col);
+        }
+        let (q, r) = rt::linalg::gram_schmidt((&a, 4)).into();
+        assert!((q.t() % &q - eye::<f64>(10, &device)).abs().max_all() < 1e-12);
+        assert!((&q % &r - &a).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_schur_ordschur() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([6, 6], 0, &device);
+        let (t, z) = rt::linalg::schur(a.view()).into();
+        assert!((z.t() % &z - eye::<f64>(6, &device)).abs().max_all() < 1e-10);
+        assert!((&z % &t % z.t() - &a).abs().max_all() < 1e-10);
+        assert!((0..6).all(|j| (j + 2..6).all(|i| t[[i, j]] == 0.0)));
+
+        // move eigenvalues of the last diagonal block to the top
+        let n = 6;
+        let last = if t[[n - 1, n - 2]] != 0.0 { n - 2 } else { n - 1 };
+        let select = (0..n).map(|i| i >= last).collect::<Vec<_>>();
+        let (t2, z2) = rt::linalg::ordschur((&t, &z, select.as_slice())).into();
+        assert!((&z2 % &t2 % z2.t() - &a).abs().max_all() < 1e-10);
+        assert!((t2[[0, 0]] - t[[last, last]]).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([7, 7], 0, &device);
+        let (h, q) = rt::linalg::hessenberg(&a).into();
+        assert!((0..7).all(|j| (j + 2..7).all(|i| h[[i, j]] == 0.0)));
+        assert!((q.t() % &q - eye::<f64>(7, &device)).abs().max_all() < 1e-10);
+        assert!((&q % &h % q.t() - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_qz() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 5], 0, &device);
+        let b = rand_f64([5, 5], 1, &device);
+        let (aa, bb, q, z) = rt::linalg::qz((&a, &b)).into();
+        assert!((0..5).all(|j| (j + 1..5).all(|i| bb[[i, j]] == 0.0)));
+        assert!((&q % &aa % z.t() - &a).abs().max_all() < 1e-10);
+        assert!((&q % &bb % z.t() - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_sylvester_lyapunov() {
+        let device = DeviceBLAS::default();
+        let a = rand_f64([5, 5], 0, &device);
+        let b = rand_f64([3, 3], 1, &device);
+        let c = rand_f64([5, 3], 2, &device);
+        let x = rt::linalg::solve_sylvester((&a, &b, &c));
+        assert!((&a % &x + &x % &b - &c).abs().max_all() < 1e-10);
+        let x_mixed = rt::linalg::solve_sylvester((&a, &b, c.view()));
+        assert!((&x_mixed - &x).abs().max_all() < 1e-12);
+        let x_mixed = rt::linalg::solve_sylvester((a.view(), b.view(), &c));
+        assert!((&x_mixed - &x).abs().max_all() < 1e-12);
+
+        // stable A: shift spectrum to the left half-plane
+        let a = rand_f64([5, 5], 3, &device) - 3.0 * eye::<f64>(5, &device);
+        let q = spd(5, 4, &device);
+        let x = rt::linalg::solve_continuous_lyapunov((&a, &q));
+        assert!((&a % &x + &x % a.t() - &q).abs().max_all() < 1e-10);
+        assert!((&x - x.t()).abs().max_all() < 1e-10);
+    }
+}
@@ -0,0 +1,47 @@
+//! Linalg function tests of the reference device.
+//!
+//! Results are checked by residuals instead of fingerprints, since the
+//! reference algorithms do not reproduce native LAPACK bit-by-bit.
+
+mod func_c64;
+mod func_f64;
+
+use num::Complex;
+use rstsr::prelude::*;
+use rstsr_refblas::DeviceRefBLAS as DeviceBLAS;
+
+#[allow(non_camel_case_types)]
+pub type c64 = Complex<f64>;
+
+/// Deterministic pseudo-random numbers in `[-1, 1)`.
+pub fn rand_vec(n: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (0..n)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+        })
+        .collect()
+}
+
+pub fn rand_f64(shape: [usize; 2], seed: u64, device: &DeviceBLAS) -> Tensor<f64, DeviceBLAS, Ix2> {
+    rt::asarray((rand_vec(shape[0] * shape[1], seed), shape.c(), device)).into_dim::<Ix2>()
+}
+
+pub fn rand_c64(shape: [usize; 2], seed: u64, device: &DeviceBLAS) -> Tensor<c64, DeviceBLAS, Ix2> {
+    let re = rand_vec(shape[0] * shape[1], seed);
+    let im = rand_vec(shape[0] * shape[1], seed + 1);
+    let vec = re.into_iter().zip(im).map(|(re, im)| c64::new(re, im)).collect::<Vec<_>>();
+    rt::asarray((vec, shape.c(), device)).into_dim::<Ix2>()
+}
+
+pub fn eye<T>(n: usize, device: &DeviceBLAS) -> Tensor<T, DeviceBLAS, Ix2>
+where
+    T: num::Num + Clone + Send + Sync,
+{
+    rt::eye((n, device)).into_dim::<Ix2>()
+}
+
+pub fn conj_t(a: &Tensor<c64, DeviceBLAS, Ix2>) -> Tensor<c64, DeviceBLAS, Ix2> {
+    a.t().mapv(|x| x.conj())
+}
@@ -0,0 +1,6 @@
+//! Scientific function tests of the reference device.
+//!
+//! These cover functions of `rstsr-sci-traits` that require linalg, so that
+//! they are tested on a device without faer.
+
+mod optimize;
@@ -0,0 +1,103 @@
+use rstsr::prelude::sci::{newton_trust_region, NewtonConfig, DIIS};
+use rstsr::prelude::*;
+use rstsr_refblas::DeviceRefBLAS as DeviceBLAS;
+
+#[test]
+fn test_diis_linear_fixed_point() {
+    // fixed-point iteration x <- A x + b; DIIS solves it within dimension + 1 iterations
+    let device = DeviceBLAS::default();
+    let n = 6;
+    let a = (0..n * n).map(|k| 0.5 * ((k * 7 % 11) as f64 / 11.0 - 0.3)).collect::<Vec<_>>();
+    let a = rt::asarray((a, [n, n].c(), &device));
+    let b = rt::asarray((vec![1.0, -0.5, 0.3, 0.8, -1.2, 0.1], &device));
+    let f = |x: &Tensor<f64, DeviceBLAS, IxD>| &a % x + &b;
+
+    let mut diis = DIIS::new(8);
+    let mut x = rt::zeros(([n], &device)).into_dim::<IxD>();
+    let mut err_norm = f64::INFINITY;
+    for _ in 0..n + 2 {
+        let x_new = f(&x);
+        let err = &x_new - &x;
+        x = diis.update(x_new.view(), err.view());
+        err_norm = diis.last_error_norm_sq().unwrap().sqrt();
+    }
+    assert!(err_norm < 1e-9);
+    assert!((&f(&x) - &x).l2_norm() < 1e-9);
+}
+
+#[test]
+fn test_diis_complex_linear_fixed_point() {
+    use num::complex::{c64, Complex64};
+
+    let device = DeviceBLAS::default();
+    let n = 5;
+    let a = (0..n * n).map(|k| c64(0.4 * ((k * 7 % 11) as f64 / 11.0 - 0.3), 0.2 * ((k * 3 % 7) as f64 / 7.0 - 0.5)));
+    let a = rt::asarray((a.collect::<Vec<_>>(), [n, n].c(), &device));
+    let b = rt::asarray((vec![c64(1.0, 0.5), c64(-0.5, 0.0), c64(0.3, -0.7), c64(0.8, 0.2), c64(-1.2, 1.0)], &device));
+    let f = |x: &Tensor<Complex64, DeviceBLAS, IxD>| &a % x + &b;
+
+    let mut diis = DIIS::new(8);
+    let mut x = rt::zeros(([n], &device)).into_dim::<IxD>();
+    for _ in 0..n + 2 {
+        let x_new = f(&x);
+        let err = &x_new - &x;
+        x = diis.update(x_new.view(), err.view());
+    }
+    assert!(diis.last_error_norm_sq().unwrap().sqrt() < 1e-9);
+    assert!((&f(&x) - &x).l2_norm() < 1e-9);
+}
+
+#[test]
+fn test_diis_singular_subspace() {
+    // identical error vectors make B-matrix singular, which is handled by pinv
+    let device = DeviceBLAS::default();
+    let mut diis = DIIS::new(3).with_min_space(2);
+    let x = rt::asarray((vec![1.0, 2.0, 3.0, 4.0], [2, 2].c(), &device));
+    let err = rt::asarray((vec![0.1, 0.2], &device));
+    let out = diis.update(x.view(), err.view());
+    assert!((&out - &x).l2_norm() < 1e-14);
+    let x2 = &x * 3.0;
+    let out = diis.update(x2.view(), err.view());
+    let ratio: f64 = out.raw()[0] / x.raw()[0];
+    assert!(ratio.is_finite());
+    assert!((&out - &x * ratio).l2_norm() < 1e-12);
+}
+
+#[test]
+fn test_newton_trust_region() {
+    let device = DeviceBLAS::default();
+    // Rosenbrock function of two variables
+    let fg = |x: TensorView<'_, f64, DeviceBLAS, Ix1>| {
+        let (a, b) = (x[[0]], x[[1]]);
+        let f = 100.0 * (b - a * a).powi(2) + (1.0 - a).powi(2);
+        let g = vec![-400.0 * a * (b - a * a) - 2.0 * (1.0 - a), 200.0 * (b - a * a)];
+        (f, rt::asarray((g, x.device())).into_dim::<Ix1>())
+    };
+    let hess = |x: TensorView<'_, f64, DeviceBLAS, Ix1>| {
+        let (a, b) = (x[[0]], x[[1]]);
+        let h = vec![1200.0 * a * a - 400.0 * b + 2.0, -400.0 * a, -400.0 * a, 200.0];
+        rt::asarray((h, [2, 2].c(), x.device())).into_dim::<Ix2>()
+    };
+    let x0 = rt::asarray((vec![-1.2, 1.0], &device)).into_dim::<Ix1>();
+    let result = newton_trust_region(fg, hess, x0.view(), &NewtonConfig::default());
+    assert!(result.converged);
+    assert!((result.x[[0]] - 1.0).abs() < 1e-8);
+    assert!((result.x[[1]] - 1.0).abs() < 1e-8);
+    assert!(result.nit < 50);
+
+    // quadratic objective is solved by one Newton step
+    let a = rt::asarray((vec![4.0, 1.0, 0.0, 1.0, 3.0, -1.0, 0.0, -1.0, 2.0], [3, 3].c(), &device)).into_dim::<Ix2>();
+    let b = rt::asarray((vec![1.0, 2.0, 3.0], &device)).into_dim::<Ix1>();
+    let fg = |x: TensorView<'_, f64, DeviceBLAS, Ix1>| {
+        let ax = &a % &x;
+        let f = 0.5 * (&ax * &x).sum() - (&b * &x).sum();
+        (f, &ax - &b)
+    };
+    let hess = |_: TensorView<'_, f64, DeviceBLAS, Ix1>| a.to_owned();
+    let x0 = rt::zeros(([3], &device)).into_dim::<Ix1>();
+    let config = NewtonConfig { initial_trust_radius: 100.0, ..Default::default() };
+    let result = newton_trust_region(fg, hess, x0.view(), &config);
+    assert!(result.converged);
+    assert_eq!(result.nit, 1);
+    assert!((&a % &result.x - &b).l2_norm() < 1e-12);
+}
@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod test {
+
+    #[test]
+    fn workable() {
+        use rstsr_core::prelude::*;
+        use rstsr_refblas::DeviceRefBLAS;
+
+        // specify the number of threads of 16
+        let device = DeviceRefBLAS::new(16);
+        // if you want to use the default number of threads, use the following line
+        // let device = DeviceRefBLAS::default();
+
+        let a = rt::linspace((0.0, 1.0, 1048576, &device)).into_shape([16, 256, 256]);
+        let b = rt::linspace((1.0, 2.0, 1048576, &device)).into_shape([16, 256, 256]);
+
+        let c = &a % &b;
+
+        // mean of all elements is also performed in parallel
+        let c_mean = c.mean_all();
+        println!("{c_mean:?}");
+        assert!((c_mean - 213.2503660477036) < 1e-6);
+
+        let c_std = c.std_all();
+        println!("{c_std:?}");
+        assert!((c_std - 148.88523481701804) < 1e-6);
+    }
+}
@@ -0,0 +1,3 @@
+mod device_blas;
+
+pub use rstsr_refblas::RefBLAS as BackendType;
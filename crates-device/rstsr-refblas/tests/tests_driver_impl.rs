@@ -0,0 +1 @@
+mod driver_impl;
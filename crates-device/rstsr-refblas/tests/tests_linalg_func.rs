@@ -0,0 +1,2 @@
+#[cfg(feature = "linalg")]
+mod linalg_func;
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "linalg", feature = "sci"))]
+mod sci_func;
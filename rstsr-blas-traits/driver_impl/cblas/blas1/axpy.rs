@@ -0,0 +1,34 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas1::axpy::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_saxpy];
+   [f64] [cblas_daxpy];
+)]
+impl AXPYDriverAPI<T> for BlasBackend {
+    unsafe fn driver_axpy(n: usize, alpha: T, x: *const T, incx: usize, y: *mut T, incy: usize) {
+        lapack_ffi::cblas::cblas_func(n as _, alpha, x, incx as _, y, incy as _);
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [Complex<f32>] [cblas_caxpy];
+   [Complex<f64>] [cblas_zaxpy];
+)]
+impl AXPYDriverAPI<T> for BlasBackend {
+    unsafe fn driver_axpy(n: usize, alpha: T, x: *const T, incx: usize, y: *mut T, incy: usize) {
+        lapack_ffi::cblas::cblas_func(
+            n as _,
+            &alpha as *const _ as *const _,
+            x as *const _,
+            incx as _,
+            y as *mut _,
+            incy as _,
+        );
+    }
+}
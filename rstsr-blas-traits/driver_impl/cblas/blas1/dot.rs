@@ -0,0 +1,38 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas1::dot::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_sdot];
+   [f64] [cblas_ddot];
+)]
+impl<const CONJ: bool> DOTDriverAPI<T, CONJ> for BlasBackend {
+    unsafe fn driver_dot(n: usize, x: *const T, incx: usize, y: *const T, incy: usize) -> T {
+        lapack_ffi::cblas::cblas_func(n as _, x, incx as _, y, incy as _)
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func        CONJ ;
+   [Complex<f32>] [cblas_cdotu_sub] [false];
+   [Complex<f32>] [cblas_cdotc_sub] [true ];
+   [Complex<f64>] [cblas_zdotu_sub] [false];
+   [Complex<f64>] [cblas_zdotc_sub] [true ];
+)]
+impl DOTDriverAPI<T, CONJ> for BlasBackend {
+    unsafe fn driver_dot(n: usize, x: *const T, incx: usize, y: *const T, incy: usize) -> T {
+        let mut res = Complex::new(0.0, 0.0);
+        lapack_ffi::cblas::cblas_func(
+            n as _,
+            x as *const _,
+            incx as _,
+            y as *const _,
+            incy as _,
+            &mut res as *mut _ as *mut _,
+        );
+        res
+    }
+}
@@ -0,0 +1,19 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas1::iamax::*;
+
+#[duplicate_item(
+    T              cblas_func   ;
+   [f32]          [cblas_isamax];
+   [f64]          [cblas_idamax];
+   [Complex<f32>] [cblas_icamax];
+   [Complex<f64>] [cblas_izamax];
+)]
+impl IAMAXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_iamax(n: usize, x: *const T, incx: usize) -> usize {
+        // CBLAS returns 0-based index
+        lapack_ffi::cblas::cblas_func(n as _, x as *const _, incx as _) as usize
+    }
+}
@@ -0,0 +1,5 @@
+pub mod axpy;
+pub mod dot;
+pub mod iamax;
+pub mod nrm2;
+pub mod scal;
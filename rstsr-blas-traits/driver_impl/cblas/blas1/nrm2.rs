@@ -0,0 +1,18 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas1::nrm2::*;
+
+#[duplicate_item(
+    T              TReal  cblas_func   ;
+   [f32]          [f32]  [cblas_snrm2 ];
+   [f64]          [f64]  [cblas_dnrm2 ];
+   [Complex<f32>] [f32]  [cblas_scnrm2];
+   [Complex<f64>] [f64]  [cblas_dznrm2];
+)]
+impl NRM2DriverAPI<T> for BlasBackend {
+    unsafe fn driver_nrm2(n: usize, x: *const T, incx: usize) -> TReal {
+        lapack_ffi::cblas::cblas_func(n as _, x as *const _, incx as _)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas1::scal::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_sscal];
+   [f64] [cblas_dscal];
+)]
+impl SCALDriverAPI<T> for BlasBackend {
+    unsafe fn driver_scal(n: usize, alpha: T, x: *mut T, incx: usize) {
+        lapack_ffi::cblas::cblas_func(n as _, alpha, x, incx as _);
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [Complex<f32>] [cblas_cscal];
+   [Complex<f64>] [cblas_zscal];
+)]
+impl SCALDriverAPI<T> for BlasBackend {
+    unsafe fn driver_scal(n: usize, alpha: T, x: *mut T, incx: usize) {
+        lapack_ffi::cblas::cblas_func(n as _, &alpha as *const _ as *const _, x as *mut _, incx as _);
+    }
+}
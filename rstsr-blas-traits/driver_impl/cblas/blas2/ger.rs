@@ -0,0 +1,63 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas2::ger::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func ;
+   [f32] [cblas_sger];
+   [f64] [cblas_dger];
+)]
+impl<const CONJ: bool> GERDriverAPI<T, CONJ> for BlasBackend {
+    unsafe fn driver_ger(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: usize,
+        y: *const T,
+        incy: usize,
+        a: *mut T,
+        lda: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(order.into(), m as _, n as _, alpha, x, incx as _, y, incy as _, a, lda as _);
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func    CONJ ;
+   [Complex<f32>] [cblas_cgeru] [false];
+   [Complex<f32>] [cblas_cgerc] [true ];
+   [Complex<f64>] [cblas_zgeru] [false];
+   [Complex<f64>] [cblas_zgerc] [true ];
+)]
+impl GERDriverAPI<T, CONJ> for BlasBackend {
+    unsafe fn driver_ger(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: usize,
+        y: *const T,
+        incy: usize,
+        a: *mut T,
+        lda: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            m as _,
+            n as _,
+            &alpha as *const _ as *const _,
+            x as *const _,
+            incx as _,
+            y as *const _,
+            incy as _,
+            a as *mut _,
+            lda as _,
+        );
+    }
+}
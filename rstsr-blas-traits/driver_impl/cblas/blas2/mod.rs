@@ -0,0 +1,4 @@
+pub mod gemv;
+pub mod ger;
+pub mod syhemv;
+pub mod trmv;
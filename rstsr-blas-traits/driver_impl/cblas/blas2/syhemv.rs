@@ -0,0 +1,76 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas2::syhemv::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_ssymv];
+   [f64] [cblas_dsymv];
+)]
+impl<const HERMI: bool> SYHEMVDriverAPI<T, HERMI> for BlasBackend {
+    unsafe fn driver_syhemv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            n as _,
+            alpha,
+            a,
+            lda as _,
+            x,
+            incx as _,
+            beta,
+            y,
+            incy as _,
+        );
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [Complex<f32>] [cblas_chemv];
+   [Complex<f64>] [cblas_zhemv];
+)]
+impl SYHEMVDriverAPI<T, true> for BlasBackend {
+    unsafe fn driver_syhemv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            n as _,
+            &alpha as *const _ as *const _,
+            a as *const _,
+            lda as _,
+            x as *const _,
+            incx as _,
+            &beta as *const _ as *const _,
+            y as *mut _,
+            incy as _,
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas2::trmv::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [f32]          [cblas_strmv];
+   [f64]          [cblas_dtrmv];
+   [Complex<f32>] [cblas_ctrmv];
+   [Complex<f64>] [cblas_ztrmv];
+)]
+impl TRMVDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trmv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        diag: FlagDiag,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        x: *mut T,
+        incx: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            diag.into(),
+            n as _,
+            a as *const _,
+            lda as _,
+            x as *mut _,
+            incx as _,
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::blas3::gemm::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [cblas_sgemm];
    [f64] [cblas_dgemm];
 )]
-impl GEMMDriverAPI<T> for DeviceBLAS {
+impl GEMMDriverAPI<T> for BlasBackend {
     unsafe fn driver_gemm(
         order: FlagOrder,
         transa: FlagTrans,
@@ -51,7 +51,7 @@ impl GEMMDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cblas_cgemm];
    [Complex<f64>] [cblas_zgemm];
 )]
-impl GEMMDriverAPI<T> for DeviceBLAS {
+impl GEMMDriverAPI<T> for BlasBackend {
     unsafe fn driver_gemm(
         order: FlagOrder,
         transa: FlagTrans,
@@ -0,0 +1,84 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas3::her2k::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func   ;
+   [f32] [cblas_ssyr2k];
+   [f64] [cblas_dsyr2k];
+)]
+impl HER2KDriverAPI<T> for BlasBackend {
+    unsafe fn driver_her2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            alpha,
+            a,
+            lda as _,
+            b,
+            ldb as _,
+            beta,
+            c,
+            ldc as _,
+        );
+    }
+}
+
+#[duplicate_item(
+    T              TReal  cblas_func  ;
+   [Complex<f32>] [f32]  [cblas_cher2k];
+   [Complex<f64>] [f64]  [cblas_zher2k];
+)]
+impl HER2KDriverAPI<T> for BlasBackend {
+    unsafe fn driver_her2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: TReal,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            &alpha as *const _ as *const _,
+            a as *const _,
+            lda as _,
+            b as *const _,
+            ldb as _,
+            beta,
+            c as *mut _,
+            ldc as _,
+        );
+    }
+}
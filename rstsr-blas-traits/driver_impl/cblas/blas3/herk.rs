@@ -0,0 +1,76 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas3::herk::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_ssyrk];
+   [f64] [cblas_dsyrk];
+)]
+impl HERKDriverAPI<T> for BlasBackend {
+    unsafe fn driver_herk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            alpha,
+            a,
+            lda as _,
+            beta,
+            c,
+            ldc as _,
+        );
+    }
+}
+
+#[duplicate_item(
+    T              TReal  cblas_func  ;
+   [Complex<f32>] [f32]  [cblas_cherk];
+   [Complex<f64>] [f64]  [cblas_zherk];
+)]
+impl HERKDriverAPI<T> for BlasBackend {
+    unsafe fn driver_herk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: TReal,
+        a: *const T,
+        lda: usize,
+        beta: TReal,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            alpha,
+            a as *const _,
+            lda as _,
+            beta,
+            c as *mut _,
+            ldc as _,
+        );
+    }
+}
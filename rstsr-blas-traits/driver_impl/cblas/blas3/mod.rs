@@ -1,3 +1,8 @@
 pub mod gemm;
+pub mod her2k;
+pub mod herk;
 pub mod syhemm;
+pub mod syr2k;
+pub mod syrk;
+pub mod trmm;
 pub mod trsm;
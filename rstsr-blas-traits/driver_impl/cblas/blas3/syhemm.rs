@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::blas3::syhemm::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [cblas_ssymm];
    [f64] [cblas_dsymm];
 )]
-impl<const HERMI: bool> SYHEMMDriverAPI<T, HERMI> for DeviceBLAS {
+impl<const HERMI: bool> SYHEMMDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_syhemm(
         order: FlagOrder,
         side: FlagSide,
@@ -51,7 +51,7 @@ impl<const HERMI: bool> SYHEMMDriverAPI<T, HERMI> for DeviceBLAS {
    [Complex<f64>] [cblas_zsymm] [false];
    [Complex<f64>] [cblas_zhemm] [true ];
 )]
-impl SYHEMMDriverAPI<T, HERMI> for DeviceBLAS {
+impl SYHEMMDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_syhemm(
         order: FlagOrder,
         side: FlagSide,
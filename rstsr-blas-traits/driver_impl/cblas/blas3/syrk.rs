@@ -0,0 +1,76 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas3::syrk::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_ssyrk];
+   [f64] [cblas_dsyrk];
+)]
+impl SYRKDriverAPI<T> for BlasBackend {
+    unsafe fn driver_syrk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            alpha,
+            a,
+            lda as _,
+            beta,
+            c,
+            ldc as _,
+        );
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [Complex<f32>] [cblas_csyrk];
+   [Complex<f64>] [cblas_zsyrk];
+)]
+impl SYRKDriverAPI<T> for BlasBackend {
+    unsafe fn driver_syrk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            uplo.into(),
+            trans.into(),
+            n as _,
+            k as _,
+            &alpha as *const _ as *const _,
+            a as *const _,
+            lda as _,
+            &beta as *const _ as *const _,
+            c as *mut _,
+            ldc as _,
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::blas3::trmm::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     cblas_func  ;
+   [f32] [cblas_strmm];
+   [f64] [cblas_dtrmm];
+)]
+impl TRMMDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trmm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            side.into(),
+            uplo.into(),
+            transa.into(),
+            diag.into(),
+            m as _,
+            n as _,
+            alpha,
+            a,
+            lda as _,
+            b,
+            ldb as _,
+        );
+    }
+}
+
+#[duplicate_item(
+    T              cblas_func  ;
+   [Complex<f32>] [cblas_ctrmm];
+   [Complex<f64>] [cblas_ztrmm];
+)]
+impl TRMMDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trmm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        lapack_ffi::cblas::cblas_func(
+            order.into(),
+            side.into(),
+            uplo.into(),
+            transa.into(),
+            diag.into(),
+            m as _,
+            n as _,
+            &alpha as *const _ as *const _,
+            a as *const _,
+            lda as _,
+            b as *mut _,
+            ldb as _,
+        );
+    }
+}
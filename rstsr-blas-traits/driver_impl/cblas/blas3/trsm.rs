@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::blas3::trsm::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [cblas_strsm];
    [f64] [cblas_dtrsm];
 )]
-impl TRSMDriverAPI<T> for DeviceBLAS {
+impl TRSMDriverAPI<T> for BlasBackend {
     unsafe fn driver_trsm(
         order: FlagOrder,
         side: FlagSide,
@@ -47,7 +47,7 @@ impl TRSMDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cblas_ctrsm];
    [Complex<f64>] [cblas_ztrsm];
 )]
-impl TRSMDriverAPI<T> for DeviceBLAS {
+impl TRSMDriverAPI<T> for BlasBackend {
     unsafe fn driver_trsm(
         order: FlagOrder,
         side: FlagSide,
@@ -1 +1,3 @@
+pub mod blas1;
+pub mod blas2;
 pub mod blas3;
@@ -1,4 +1,7 @@
 pub mod syev;
 pub mod syevd;
+pub mod syevr;
+pub mod syevx;
 pub mod sygv;
 pub mod sygvd;
+pub mod sygvx;
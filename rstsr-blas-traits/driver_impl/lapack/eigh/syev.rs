@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [ssyev_];
    [f64] [dsyev_];
 )]
-impl SYEVDriverAPI<T> for DeviceBLAS {
+impl SYEVDriverAPI<T> for BlasBackend {
     unsafe fn driver_syev(
         order: FlagOrder,
         jobz: char,
@@ -94,7 +94,7 @@ impl SYEVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cheev_];
    [Complex<f64>] [zheev_];
 )]
-impl SYEVDriverAPI<T> for DeviceBLAS {
+impl SYEVDriverAPI<T> for BlasBackend {
     unsafe fn driver_syev(
         order: FlagOrder,
         jobz: char,
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [ssyevd_];
    [f64] [dsyevd_];
 )]
-impl SYEVDDriverAPI<T> for DeviceBLAS {
+impl SYEVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_syevd(
         order: FlagOrder,
         jobz: char,
@@ -117,7 +117,7 @@ impl SYEVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cheevd_];
    [Complex<f64>] [zheevd_];
 )]
-impl SYEVDDriverAPI<T> for DeviceBLAS {
+impl SYEVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_syevd(
         order: FlagOrder,
         jobz: char,
@@ -0,0 +1,345 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_    ;
+   [f32] [ssyevr_];
+   [f64] [dsyevr_];
+)]
+impl SYEVRDriverAPI<T> for BlasBackend {
+    unsafe fn driver_syevr(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T,
+        vu: T,
+        il: usize,
+        iu: usize,
+        abstol: T,
+        m: &mut usize,
+        w: *mut T,
+        z: *mut T,
+        ldz: usize,
+        isuppz: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldz_t) = match order {
+            ColMajor => (lda, ldz),
+            RowMajor => (n.max(1), n.max(1)),
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut m_ = 0;
+        let lwork = -1;
+        let liwork = -1;
+        let mut work_query = 0.0;
+        let mut iwork_query = 0;
+        func_(
+            &(jobz as _),
+            &(range as _),
+            &uplo.into(),
+            &(n as _),
+            a,
+            &(lda_t as _),
+            &vl,
+            &vu,
+            &(il as _),
+            &(iu as _),
+            &abstol,
+            &mut m_,
+            w,
+            z,
+            &(ldz_t as _),
+            isuppz,
+            &mut work_query,
+            &lwork,
+            &mut iwork_query,
+            &liwork,
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query as usize;
+        let liwork = iwork_query as usize;
+
+        // Allocate memory for work arrays
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+        let mut iwork: Vec<blas_int> = match uninitialized_vec(liwork) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobz as _),
+                &(range as _),
+                &uplo.into(),
+                &(n as _),
+                a,
+                &(lda as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &abstol,
+                &mut m_,
+                w,
+                z,
+                &(ldz as _),
+                isuppz,
+                work.as_mut_ptr(),
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &(liwork as _),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            let ncol = if range.eq_ignore_ascii_case(&'I') { (iu + 1).saturating_sub(il) } else { n };
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut z_t: Vec<T> = match uninitialized_vec(ldz_t * ncol.max(1)) {
+                Ok(z_t) => z_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([n, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobz as _),
+                &(range as _),
+                &uplo.into(),
+                &(n as _),
+                a_t.as_mut_ptr(),
+                &(lda_t as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &abstol,
+                &mut m_,
+                w,
+                z_t.as_mut_ptr(),
+                &(ldz_t as _),
+                isuppz,
+                work.as_mut_ptr(),
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &(liwork as _),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+            // Transpose output matrices
+            if jobz.eq_ignore_ascii_case(&'V') {
+                let z_slice = from_raw_parts_mut(z, n * ldz);
+                let lz = Layout::new_unchecked([n, m_ as usize], [ldz as isize, 1], 0);
+                let lz_t = Layout::new_unchecked([n, m_ as usize], [1, ldz_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(z_slice, &lz, &z_t, &lz_t).unwrap();
+            }
+        }
+        *m = m_ as usize;
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_    ;
+   [Complex<f32>] [cheevr_];
+   [Complex<f64>] [zheevr_];
+)]
+impl SYEVRDriverAPI<T> for BlasBackend {
+    unsafe fn driver_syevr(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: <T as ComplexFloat>::Real,
+        vu: <T as ComplexFloat>::Real,
+        il: usize,
+        iu: usize,
+        abstol: <T as ComplexFloat>::Real,
+        m: &mut usize,
+        w: *mut <T as ComplexFloat>::Real,
+        z: *mut T,
+        ldz: usize,
+        isuppz: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldz_t) = match order {
+            ColMajor => (lda, ldz),
+            RowMajor => (n.max(1), n.max(1)),
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut m_ = 0;
+        let lwork = -1;
+        let lrwork = -1;
+        let liwork = -1;
+        let mut work_query: T = Complex::new(0.0, 0.0);
+        let mut rwork_query = 0.0;
+        let mut iwork_query = 0;
+        func_(
+            &(jobz as _),
+            &(range as _),
+            &uplo.into(),
+            &(n as _),
+            a as *mut _,
+            &(lda_t as _),
+            &vl,
+            &vu,
+            &(il as _),
+            &(iu as _),
+            &abstol,
+            &mut m_,
+            w,
+            z as *mut _,
+            &(ldz_t as _),
+            isuppz,
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            &mut rwork_query,
+            &lrwork,
+            &mut iwork_query,
+            &liwork,
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query.re as usize;
+        let lrwork = rwork_query as usize;
+        let liwork = iwork_query as usize;
+
+        // Allocate memory for work arrays
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+        let mut rwork: Vec<<T as ComplexFloat>::Real> = match uninitialized_vec(lrwork) {
+            Ok(rwork) => rwork,
+            Err(_) => return -1010,
+        };
+        let mut iwork: Vec<blas_int> = match uninitialized_vec(liwork) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobz as _),
+                &(range as _),
+                &uplo.into(),
+                &(n as _),
+                a as *mut _,
+                &(lda as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &abstol,
+                &mut m_,
+                w,
+                z as *mut _,
+                &(ldz as _),
+                isuppz,
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                &(lrwork as _),
+                iwork.as_mut_ptr(),
+                &(liwork as _),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            let ncol = if range.eq_ignore_ascii_case(&'I') { (iu + 1).saturating_sub(il) } else { n };
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut z_t: Vec<T> = match uninitialized_vec(ldz_t * ncol.max(1)) {
+                Ok(z_t) => z_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([n, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobz as _),
+                &(range as _),
+                &uplo.into(),
+                &(n as _),
+                a_t.as_mut_ptr() as *mut _,
+                &(lda_t as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &abstol,
+                &mut m_,
+                w,
+                z_t.as_mut_ptr() as *mut _,
+                &(ldz_t as _),
+                isuppz,
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                &(lrwork as _),
+                iwork.as_mut_ptr(),
+                &(liwork as _),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+            // Transpose output matrices
+            if jobz.eq_ignore_ascii_case(&'V') {
+                let z_slice = from_raw_parts_mut(z, n * ldz);
+                let lz = Layout::new_unchecked([n, m_ as usize], [ldz as isize, 1], 0);
+                let lz_t = Layout::new_unchecked([n, m_ as usize], [1, ldz_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(z_slice, &lz, &z_t, &lz_t).unwrap();
+            }
+        }
+        *m = m_ as usize;
+        return info;
+    }
+}
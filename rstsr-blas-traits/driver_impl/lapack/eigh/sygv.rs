@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [ssygv_];
    [f64] [dsygv_];
 )]
-impl SYGVDriverAPI<T> for DeviceBLAS {
+impl SYGVDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygv(
         order: FlagOrder,
         itype: blas_int,
@@ -126,7 +126,7 @@ impl SYGVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [chegv_];
    [Complex<f64>] [zhegv_];
 )]
-impl SYGVDriverAPI<T> for DeviceBLAS {
+impl SYGVDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygv(
         order: FlagOrder,
         itype: blas_int,
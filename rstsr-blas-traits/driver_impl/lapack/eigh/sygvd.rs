@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [ssygvd_];
    [f64] [dsygvd_];
 )]
-impl SYGVDDriverAPI<T> for DeviceBLAS {
+impl SYGVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygvd(
         order: FlagOrder,
         itype: blas_int,
@@ -139,7 +139,7 @@ impl SYGVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [chegvd_];
    [Complex<f64>] [zhegvd_];
 )]
-impl SYGVDDriverAPI<T> for DeviceBLAS {
+impl SYGVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygvd(
         order: FlagOrder,
         itype: blas_int,
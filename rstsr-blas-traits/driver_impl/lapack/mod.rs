@@ -1,3 +1,4 @@
 pub mod eigh;
+pub mod schur;
 pub mod solve;
 pub mod svd;
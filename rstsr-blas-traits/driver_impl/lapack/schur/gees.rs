@@ -0,0 +1,255 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_  ;
+   [f32] [sgees_];
+   [f64] [dgees_];
+)]
+impl GEESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut T,
+        wi: *mut T,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let jobvs = jobvs.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nvs = if jobvs == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut vs_t: Vec<T> = match uninitialized_vec(nvs * nvs) {
+                Ok(vs_t) => vs_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+
+            // Call column-major driver
+            let info = Self::driver_gees(ColMajor, jobvs, n, a_t.as_mut_ptr(), ld_t, wr, wi, vs_t.as_mut_ptr(), ld_t);
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(a_slice, &la, &a_t, &la_t).unwrap();
+            if jobvs == 'V' {
+                let vs_slice = from_raw_parts_mut(vs, n * ldvs);
+                let lvs = Layout::new_unchecked([n, n], [ldvs as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vs_slice, &lvs, &vs_t, &la_t).unwrap();
+            }
+            return info;
+        }
+
+        // Allocate memory for temporary array(s)
+        let mut bwork: Vec<blas_int> = match uninitialized_vec(n.max(1)) {
+            Ok(bwork) => bwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut sdim = 0;
+        let lwork = -1;
+        let mut work_query = 0.0;
+        func_(
+            &(jobvs as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a,
+            &(lda as _),
+            &mut sdim,
+            wr,
+            wi,
+            vs,
+            &(ldvs as _),
+            &mut work_query,
+            &lwork,
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query as usize;
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &(jobvs as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a,
+            &(lda as _),
+            &mut sdim,
+            wr,
+            wi,
+            vs,
+            &(ldvs as _),
+            work.as_mut_ptr(),
+            &(lwork as _),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_  ;
+   [Complex<f32>] [cgees_];
+   [Complex<f64>] [zgees_];
+)]
+impl GEESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut <T as ComplexFloat>::Real,
+        wi: *mut <T as ComplexFloat>::Real,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let jobvs = jobvs.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nvs = if jobvs == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut vs_t: Vec<T> = match uninitialized_vec(nvs * nvs) {
+                Ok(vs_t) => vs_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+
+            // Call column-major driver
+            let info = Self::driver_gees(ColMajor, jobvs, n, a_t.as_mut_ptr(), ld_t, wr, wi, vs_t.as_mut_ptr(), ld_t);
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(a_slice, &la, &a_t, &la_t).unwrap();
+            if jobvs == 'V' {
+                let vs_slice = from_raw_parts_mut(vs, n * ldvs);
+                let lvs = Layout::new_unchecked([n, n], [ldvs as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vs_slice, &lvs, &vs_t, &la_t).unwrap();
+            }
+            return info;
+        }
+
+        // Allocate memory for temporary array(s)
+        let mut w: Vec<T> = match uninitialized_vec(n) {
+            Ok(w) => w,
+            Err(_) => return -1010,
+        };
+        let mut rwork: Vec<<T as ComplexFloat>::Real> = match uninitialized_vec(n.max(1)) {
+            Ok(rwork) => rwork,
+            Err(_) => return -1010,
+        };
+        let mut bwork: Vec<blas_int> = match uninitialized_vec(n.max(1)) {
+            Ok(bwork) => bwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut sdim = 0;
+        let lwork = -1;
+        let mut work_query = Complex::new(0.0, 0.0);
+        func_(
+            &(jobvs as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a as *mut _,
+            &(lda as _),
+            &mut sdim,
+            w.as_mut_ptr() as *mut _,
+            vs as *mut _,
+            &(ldvs as _),
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            rwork.as_mut_ptr(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query.re as usize;
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &(jobvs as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a as *mut _,
+            &(lda as _),
+            &mut sdim,
+            w.as_mut_ptr() as *mut _,
+            vs as *mut _,
+            &(ldvs as _),
+            work.as_mut_ptr() as *mut _,
+            &(lwork as _),
+            rwork.as_mut_ptr(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        for (i, w) in w.iter().enumerate() {
+            *wr.add(i) = w.re;
+            *wi.add(i) = w.im;
+        }
+        return info;
+    }
+}
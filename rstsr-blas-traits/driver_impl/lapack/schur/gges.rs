@@ -0,0 +1,361 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_  ;
+   [f32] [sgges_];
+   [f64] [dgges_];
+)]
+impl GGESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut T,
+        alphai: *mut T,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let jobvsl = jobvsl.to_ascii_uppercase();
+        let jobvsr = jobvsr.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nvsl = if jobvsl == 'V' { n } else { 1 };
+            let nvsr = if jobvsr == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut b_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(b_t) => b_t,
+                Err(_) => return -1011,
+            };
+            let mut vsl_t: Vec<T> = match uninitialized_vec(nvsl * nvsl) {
+                Ok(vsl_t) => vsl_t,
+                Err(_) => return -1011,
+            };
+            let mut vsr_t: Vec<T> = match uninitialized_vec(nvsr * nvsr) {
+                Ok(vsr_t) => vsr_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let b_slice = from_raw_parts_mut(b, n * ldb);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let lb = Layout::new_unchecked([n, n], [ldb as isize, 1], 0);
+            let l_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &l_t, a_slice, &la).unwrap();
+            orderchange_out_r2c_ix2_cpu_serial(&mut b_t, &l_t, b_slice, &lb).unwrap();
+
+            // Call column-major driver
+            let info = Self::driver_gges(
+                ColMajor,
+                jobvsl,
+                jobvsr,
+                n,
+                a_t.as_mut_ptr(),
+                ld_t,
+                b_t.as_mut_ptr(),
+                ld_t,
+                alphar,
+                alphai,
+                beta,
+                vsl_t.as_mut_ptr(),
+                ld_t,
+                vsr_t.as_mut_ptr(),
+                ld_t,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(a_slice, &la, &a_t, &l_t).unwrap();
+            orderchange_out_c2r_ix2_cpu_serial(b_slice, &lb, &b_t, &l_t).unwrap();
+            if jobvsl == 'V' {
+                let vsl_slice = from_raw_parts_mut(vsl, n * ldvsl);
+                let lvsl = Layout::new_unchecked([n, n], [ldvsl as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vsl_slice, &lvsl, &vsl_t, &l_t).unwrap();
+            }
+            if jobvsr == 'V' {
+                let vsr_slice = from_raw_parts_mut(vsr, n * ldvsr);
+                let lvsr = Layout::new_unchecked([n, n], [ldvsr as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vsr_slice, &lvsr, &vsr_t, &l_t).unwrap();
+            }
+            return info;
+        }
+
+        // Allocate memory for temporary array(s)
+        let mut bwork: Vec<blas_int> = match uninitialized_vec(n.max(1)) {
+            Ok(bwork) => bwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut sdim = 0;
+        let lwork = -1;
+        let mut work_query = 0.0;
+        func_(
+            &(jobvsl as _),
+            &(jobvsr as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a,
+            &(lda as _),
+            b,
+            &(ldb as _),
+            &mut sdim,
+            alphar,
+            alphai,
+            beta,
+            vsl,
+            &(ldvsl as _),
+            vsr,
+            &(ldvsr as _),
+            &mut work_query,
+            &lwork,
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query as usize;
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &(jobvsl as _),
+            &(jobvsr as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a,
+            &(lda as _),
+            b,
+            &(ldb as _),
+            &mut sdim,
+            alphar,
+            alphai,
+            beta,
+            vsl,
+            &(ldvsl as _),
+            vsr,
+            &(ldvsr as _),
+            work.as_mut_ptr(),
+            &(lwork as _),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_  ;
+   [Complex<f32>] [cgges_];
+   [Complex<f64>] [zgges_];
+)]
+impl GGESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut <T as ComplexFloat>::Real,
+        alphai: *mut <T as ComplexFloat>::Real,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let jobvsl = jobvsl.to_ascii_uppercase();
+        let jobvsr = jobvsr.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nvsl = if jobvsl == 'V' { n } else { 1 };
+            let nvsr = if jobvsr == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut b_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(b_t) => b_t,
+                Err(_) => return -1011,
+            };
+            let mut vsl_t: Vec<T> = match uninitialized_vec(nvsl * nvsl) {
+                Ok(vsl_t) => vsl_t,
+                Err(_) => return -1011,
+            };
+            let mut vsr_t: Vec<T> = match uninitialized_vec(nvsr * nvsr) {
+                Ok(vsr_t) => vsr_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let b_slice = from_raw_parts_mut(b, n * ldb);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let lb = Layout::new_unchecked([n, n], [ldb as isize, 1], 0);
+            let l_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &l_t, a_slice, &la).unwrap();
+            orderchange_out_r2c_ix2_cpu_serial(&mut b_t, &l_t, b_slice, &lb).unwrap();
+
+            // Call column-major driver
+            let info = Self::driver_gges(
+                ColMajor,
+                jobvsl,
+                jobvsr,
+                n,
+                a_t.as_mut_ptr(),
+                ld_t,
+                b_t.as_mut_ptr(),
+                ld_t,
+                alphar,
+                alphai,
+                beta,
+                vsl_t.as_mut_ptr(),
+                ld_t,
+                vsr_t.as_mut_ptr(),
+                ld_t,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(a_slice, &la, &a_t, &l_t).unwrap();
+            orderchange_out_c2r_ix2_cpu_serial(b_slice, &lb, &b_t, &l_t).unwrap();
+            if jobvsl == 'V' {
+                let vsl_slice = from_raw_parts_mut(vsl, n * ldvsl);
+                let lvsl = Layout::new_unchecked([n, n], [ldvsl as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vsl_slice, &lvsl, &vsl_t, &l_t).unwrap();
+            }
+            if jobvsr == 'V' {
+                let vsr_slice = from_raw_parts_mut(vsr, n * ldvsr);
+                let lvsr = Layout::new_unchecked([n, n], [ldvsr as isize, 1], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vsr_slice, &lvsr, &vsr_t, &l_t).unwrap();
+            }
+            return info;
+        }
+
+        // Allocate memory for temporary array(s)
+        let mut alpha: Vec<T> = match uninitialized_vec(n) {
+            Ok(alpha) => alpha,
+            Err(_) => return -1010,
+        };
+        let mut rwork: Vec<<T as ComplexFloat>::Real> = match uninitialized_vec(8 * n.max(1)) {
+            Ok(rwork) => rwork,
+            Err(_) => return -1010,
+        };
+        let mut bwork: Vec<blas_int> = match uninitialized_vec(n.max(1)) {
+            Ok(bwork) => bwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut sdim = 0;
+        let lwork = -1;
+        let mut work_query = Complex::new(0.0, 0.0);
+        func_(
+            &(jobvsl as _),
+            &(jobvsr as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a as *mut _,
+            &(lda as _),
+            b as *mut _,
+            &(ldb as _),
+            &mut sdim,
+            alpha.as_mut_ptr() as *mut _,
+            beta as *mut _,
+            vsl as *mut _,
+            &(ldvsl as _),
+            vsr as *mut _,
+            &(ldvsr as _),
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            rwork.as_mut_ptr(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query.re as usize;
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &(jobvsl as _),
+            &(jobvsr as _),
+            &('N' as _),
+            None,
+            &(n as _),
+            a as *mut _,
+            &(lda as _),
+            b as *mut _,
+            &(ldb as _),
+            &mut sdim,
+            alpha.as_mut_ptr() as *mut _,
+            beta as *mut _,
+            vsl as *mut _,
+            &(ldvsl as _),
+            vsr as *mut _,
+            &(ldvsr as _),
+            work.as_mut_ptr() as *mut _,
+            &(lwork as _),
+            rwork.as_mut_ptr(),
+            bwork.as_mut_ptr(),
+            &mut info,
+        );
+        for (i, alpha) in alpha.iter().enumerate() {
+            *alphar.add(i) = alpha.re;
+            *alphai.add(i) = alpha.im;
+        }
+        return info;
+    }
+}
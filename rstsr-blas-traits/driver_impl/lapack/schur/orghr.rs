@@ -0,0 +1,94 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T              func_   ;
+   [f32]          [sorghr_];
+   [f64]          [dorghr_];
+   [Complex<f32>] [cunghr_];
+   [Complex<f64>] [zunghr_];
+)]
+impl ORGHRDriverAPI<T> for BlasBackend {
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        if order == RowMajor {
+            let lda_t = n.max(1);
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, n * lda);
+            let la = Layout::new_unchecked([n, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([n, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+
+            // Call column-major driver
+            let info = Self::driver_orghr(ColMajor, n, ilo, ihi, a_t.as_mut_ptr(), lda_t, tau);
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(a_slice, &la, &a_t, &la_t).unwrap();
+            return info;
+        }
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let lwork = -1;
+        let mut work_query: T = num::zero();
+        func_(
+            &(n as _),
+            &(ilo as _),
+            &(ihi as _),
+            a as *mut _,
+            &(lda as _),
+            tau as *const _,
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query.re() as usize;
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork.max(1)) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &(n as _),
+            &(ilo as _),
+            &(ihi as _),
+            a as *mut _,
+            &(lda as _),
+            tau as *const _,
+            work.as_mut_ptr() as *mut _,
+            &(lwork.max(1) as _),
+            &mut info,
+        );
+        return info;
+    }
+}
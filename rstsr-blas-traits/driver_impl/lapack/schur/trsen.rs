@@ -0,0 +1,296 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_   ;
+   [f32] [strsen_];
+   [f64] [dtrsen_];
+)]
+impl TRSENDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut T,
+        wi: *mut T,
+        m: &mut usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let compq = compq.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nq = if compq == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut t_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(t_t) => t_t,
+                Err(_) => return -1011,
+            };
+            let mut q_t: Vec<T> = match uninitialized_vec(nq * nq) {
+                Ok(q_t) => q_t,
+                Err(_) => return -1011,
+            };
+            let t_slice = from_raw_parts_mut(t, n * ldt);
+            let lt = Layout::new_unchecked([n, n], [ldt as isize, 1], 0);
+            let l_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut t_t, &l_t, t_slice, &lt).unwrap();
+            let lq = Layout::new_unchecked([n, n], [ldq as isize, 1], 0);
+            if compq == 'V' {
+                let q_slice = from_raw_parts_mut(q, n * ldq);
+                orderchange_out_r2c_ix2_cpu_serial(&mut q_t, &l_t, q_slice, &lq).unwrap();
+            }
+
+            // Call column-major driver
+            let info = Self::driver_trsen(
+                ColMajor,
+                compq,
+                select,
+                n,
+                t_t.as_mut_ptr(),
+                ld_t,
+                q_t.as_mut_ptr(),
+                ld_t,
+                wr,
+                wi,
+                m,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(t_slice, &lt, &t_t, &l_t).unwrap();
+            if compq == 'V' {
+                let q_slice = from_raw_parts_mut(q, n * ldq);
+                orderchange_out_c2r_ix2_cpu_serial(q_slice, &lq, &q_t, &l_t).unwrap();
+            }
+            return info;
+        }
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut m_ = 0;
+        let mut s = 0.0;
+        let mut sep = 0.0;
+        let lwork = -1;
+        let liwork = -1;
+        let mut work_query = 0.0;
+        let mut iwork_query = 0;
+        func_(
+            &('N' as _),
+            &(compq as _),
+            select,
+            &(n as _),
+            t,
+            &(ldt as _),
+            q,
+            &(ldq as _),
+            wr,
+            wi,
+            &mut m_,
+            &mut s,
+            &mut sep,
+            &mut work_query,
+            &lwork,
+            &mut iwork_query,
+            &liwork,
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = (work_query as usize).max(1);
+        let liwork = (iwork_query as usize).max(1);
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+        let mut iwork: Vec<blas_int> = match uninitialized_vec(liwork) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &('N' as _),
+            &(compq as _),
+            select,
+            &(n as _),
+            t,
+            &(ldt as _),
+            q,
+            &(ldq as _),
+            wr,
+            wi,
+            &mut m_,
+            &mut s,
+            &mut sep,
+            work.as_mut_ptr(),
+            &(lwork as _),
+            iwork.as_mut_ptr(),
+            &(liwork as _),
+            &mut info,
+        );
+        *m = m_ as usize;
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_   ;
+   [Complex<f32>] [ctrsen_];
+   [Complex<f64>] [ztrsen_];
+)]
+impl TRSENDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut <T as ComplexFloat>::Real,
+        wi: *mut <T as ComplexFloat>::Real,
+        m: &mut usize,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        // the row-major branch below compares job flags, so normalize the case first
+        let compq = compq.to_ascii_uppercase();
+
+        if order == RowMajor {
+            let ld_t = n.max(1);
+            let nq = if compq == 'V' { n } else { 1 };
+
+            // Transpose input matrices
+            let mut t_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(t_t) => t_t,
+                Err(_) => return -1011,
+            };
+            let mut q_t: Vec<T> = match uninitialized_vec(nq * nq) {
+                Ok(q_t) => q_t,
+                Err(_) => return -1011,
+            };
+            let t_slice = from_raw_parts_mut(t, n * ldt);
+            let lt = Layout::new_unchecked([n, n], [ldt as isize, 1], 0);
+            let l_t = Layout::new_unchecked([n, n], [1, ld_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut t_t, &l_t, t_slice, &lt).unwrap();
+            let lq = Layout::new_unchecked([n, n], [ldq as isize, 1], 0);
+            if compq == 'V' {
+                let q_slice = from_raw_parts_mut(q, n * ldq);
+                orderchange_out_r2c_ix2_cpu_serial(&mut q_t, &l_t, q_slice, &lq).unwrap();
+            }
+
+            // Call column-major driver
+            let info = Self::driver_trsen(
+                ColMajor,
+                compq,
+                select,
+                n,
+                t_t.as_mut_ptr(),
+                ld_t,
+                q_t.as_mut_ptr(),
+                ld_t,
+                wr,
+                wi,
+                m,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(t_slice, &lt, &t_t, &l_t).unwrap();
+            if compq == 'V' {
+                let q_slice = from_raw_parts_mut(q, n * ldq);
+                orderchange_out_c2r_ix2_cpu_serial(q_slice, &lq, &q_t, &l_t).unwrap();
+            }
+            return info;
+        }
+
+        // Allocate memory for temporary array(s)
+        let mut w: Vec<T> = match uninitialized_vec(n) {
+            Ok(w) => w,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut m_ = 0;
+        let mut s = 0.0;
+        let mut sep = 0.0;
+        let lwork = -1;
+        let mut work_query = Complex::new(0.0, 0.0);
+        func_(
+            &('N' as _),
+            &(compq as _),
+            select,
+            &(n as _),
+            t as *mut _,
+            &(ldt as _),
+            q as *mut _,
+            &(ldq as _),
+            w.as_mut_ptr() as *mut _,
+            &mut m_,
+            &mut s,
+            &mut sep,
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = (work_query.re as usize).max(1);
+
+        // Allocate memory for temporary array(s)
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        // Call LAPACK function and adjust info
+        func_(
+            &('N' as _),
+            &(compq as _),
+            select,
+            &(n as _),
+            t as *mut _,
+            &(ldt as _),
+            q as *mut _,
+            &(ldq as _),
+            w.as_mut_ptr() as *mut _,
+            &mut m_,
+            &mut s,
+            &mut sep,
+            work.as_mut_ptr() as *mut _,
+            &(lwork as _),
+            &mut info,
+        );
+        *m = m_ as usize;
+        for (i, w) in w.iter().enumerate() {
+            *wr.add(i) = w.re;
+            *wi.add(i) = w.im;
+        }
+        return info;
+    }
+}
@@ -0,0 +1,113 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+#[duplicate_item(
+    T              func_   ;
+   [f32]          [strsyl_];
+   [f64]          [dtrsyl_];
+   [Complex<f32>] [ctrsyl_];
+   [Complex<f64>] [ztrsyl_];
+)]
+impl TRSYLDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut <T as ComplexFloat>::Real,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        let mut info = 0;
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &trana.into(),
+                &tranb.into(),
+                &(isgn as _),
+                &(m as _),
+                &(n as _),
+                a as *const _,
+                &(lda as _),
+                b as *const _,
+                &(ldb as _),
+                c as *mut _,
+                &(ldc as _),
+                scale,
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            let lda_t = m.max(1);
+            let ldb_t = n.max(1);
+            let ldc_t = m.max(1);
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(m * m) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let mut b_t: Vec<T> = match uninitialized_vec(n * n) {
+                Ok(b_t) => b_t,
+                Err(_) => return -1011,
+            };
+            let mut c_t: Vec<T> = match uninitialized_vec(m * n) {
+                Ok(c_t) => c_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts(a, m * lda);
+            let b_slice = from_raw_parts(b, n * ldb);
+            let c_slice = from_raw_parts_mut(c, m * ldc);
+            let la = Layout::new_unchecked([m, m], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([m, m], [1, lda_t as isize], 0);
+            let lb = Layout::new_unchecked([n, n], [ldb as isize, 1], 0);
+            let lb_t = Layout::new_unchecked([n, n], [1, ldb_t as isize], 0);
+            let lc = Layout::new_unchecked([m, n], [ldc as isize, 1], 0);
+            let lc_t = Layout::new_unchecked([m, n], [1, ldc_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            orderchange_out_r2c_ix2_cpu_serial(&mut b_t, &lb_t, b_slice, &lb).unwrap();
+            orderchange_out_r2c_ix2_cpu_serial(&mut c_t, &lc_t, c_slice, &lc).unwrap();
+
+            // Call LAPACK function and adjust info
+            func_(
+                &trana.into(),
+                &tranb.into(),
+                &(isgn as _),
+                &(m as _),
+                &(n as _),
+                a_t.as_ptr() as *const _,
+                &(lda_t as _),
+                b_t.as_ptr() as *const _,
+                &(ldb_t as _),
+                c_t.as_mut_ptr() as *mut _,
+                &(ldc_t as _),
+                scale,
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            orderchange_out_c2r_ix2_cpu_serial(c_slice, &lc, &c_t, &lc_t).unwrap();
+        }
+        return info;
+    }
+}
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
 use rstsr_common::prelude_dev::*;
@@ -11,7 +11,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [sgesv_];
    [f64] [dgesv_];
 )]
-impl GESVDriverAPI<T> for DeviceBLAS {
+impl GESVDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesv(
         order: FlagOrder,
         n: usize,
@@ -78,7 +78,7 @@ impl GESVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cgesv_];
    [Complex<f64>] [zgesv_];
 )]
-impl GESVDriverAPI<T> for DeviceBLAS {
+impl GESVDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesv(
         order: FlagOrder,
         n: usize,
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
 use rstsr_common::prelude_dev::*;
@@ -11,7 +11,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [sgetrf_];
    [f64] [dgetrf_];
 )]
-impl GETRFDriverAPI<T> for DeviceBLAS {
+impl GETRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_getrf(
         order: FlagOrder,
         m: usize,
@@ -58,7 +58,7 @@ impl GETRFDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cgetrf_];
    [Complex<f64>] [zgetrf_];
 )]
-impl GETRFDriverAPI<T> for DeviceBLAS {
+impl GETRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_getrf(
         order: FlagOrder,
         m: usize,
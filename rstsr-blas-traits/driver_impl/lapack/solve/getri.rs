@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
 use rstsr_common::prelude_dev::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [sgetri_];
    [f64] [dgetri_];
 )]
-impl GETRIDriverAPI<T> for DeviceBLAS {
+impl GETRIDriverAPI<T> for BlasBackend {
     unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
         use lapack_ffi::lapack::func_;
 
@@ -66,7 +66,7 @@ impl GETRIDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cgetri_];
    [Complex<f64>] [zgetri_];
 )]
-impl GETRIDriverAPI<T> for DeviceBLAS {
+impl GETRIDriverAPI<T> for BlasBackend {
     unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
         use lapack_ffi::lapack::func_;
 
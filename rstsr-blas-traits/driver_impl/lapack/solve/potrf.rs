@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
 use rstsr_common::prelude_dev::*;
@@ -11,7 +11,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [spotrf_];
    [f64] [dpotrf_];
 )]
-impl POTRFDriverAPI<T> for DeviceBLAS {
+impl POTRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
         use lapack_ffi::lapack::func_;
 
@@ -51,7 +51,7 @@ impl POTRFDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cpotrf_];
    [Complex<f64>] [zpotrf_];
 )]
-impl POTRFDriverAPI<T> for DeviceBLAS {
+impl POTRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
         use lapack_ffi::lapack::func_;
 
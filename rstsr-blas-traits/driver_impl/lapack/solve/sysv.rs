@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
 use rstsr_common::prelude_dev::*;
@@ -12,7 +12,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [ssysv_];
    [f64] [dsysv_];
 )]
-impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for DeviceBLAS {
+impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_sysv(
         order: FlagOrder,
         uplo: FlagUpLo,
@@ -124,7 +124,7 @@ impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for DeviceBLAS {
    [Complex<f64>] [zsysv_] [false];
    [Complex<f64>] [zhesv_] [true ];
 )]
-impl SYSVDriverAPI<T, HERMI> for DeviceBLAS {
+impl SYSVDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_sysv(
         order: FlagOrder,
         uplo: FlagUpLo,
@@ -0,0 +1,301 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_    ;
+   [f32] [sgejsv_];
+   [f64] [dgejsv_];
+)]
+impl GEJSVDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut T,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut T,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        let want_u = jobu.eq_ignore_ascii_case(&'U') || jobu.eq_ignore_ascii_case(&'F');
+        let want_v = jobv.eq_ignore_ascii_case(&'V') || jobv.eq_ignore_ascii_case(&'J');
+        let ncols_u = if jobu.eq_ignore_ascii_case(&'F') { m } else { n };
+
+        // Allocate memory for working array(s)
+        // sizes are the same to the minimal requirements documented by LAPACK
+        let lwork = 7.max(2 * m + n).max(6 * n + 2 * n * n + 6);
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+        let liwork = 4.max(m + 3 * n);
+        let mut iwork: Vec<blas_int> = match uninitialized_vec(liwork) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+
+        let mut info = 0;
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(joba as _),
+                &(jobu as _),
+                &(jobv as _),
+                &(jobr as _),
+                &(jobt as _),
+                &(jobp as _),
+                &(m as _),
+                &(n as _),
+                a as *mut _,
+                &(lda as _),
+                sva,
+                u as *mut _,
+                &(ldu as _),
+                v as *mut _,
+                &(ldv as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            let lda_t = m.max(1);
+            let ldu_t = if want_u { m.max(1) } else { 1 };
+            let ldv_t = if want_v { n.max(1) } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, m * lda);
+            let la = Layout::new_unchecked([m, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([m, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            let mut u_t: Vec<T> = match uninitialized_vec(if want_u { m * ncols_u } else { 1 }) {
+                Ok(u_t) => u_t,
+                Err(_) => return -1011,
+            };
+            let mut v_t: Vec<T> = match uninitialized_vec(if want_v { n * n } else { 1 }) {
+                Ok(v_t) => v_t,
+                Err(_) => return -1011,
+            };
+
+            // Call LAPACK function and adjust info
+            func_(
+                &(joba as _),
+                &(jobu as _),
+                &(jobv as _),
+                &(jobr as _),
+                &(jobt as _),
+                &(jobp as _),
+                &(m as _),
+                &(n as _),
+                a_t.as_mut_ptr() as *mut _,
+                &(lda_t as _),
+                sva,
+                u_t.as_mut_ptr() as *mut _,
+                &(ldu_t as _),
+                v_t.as_mut_ptr() as *mut _,
+                &(ldv_t as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            if want_u {
+                let u_slice = from_raw_parts_mut(u, m * ldu);
+                let lu = Layout::new_unchecked([m, ncols_u], [ldu as isize, 1], 0);
+                let lu_t = Layout::new_unchecked([m, ncols_u], [1, ldu_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(u_slice, &lu, &u_t, &lu_t).unwrap();
+            }
+            if want_v {
+                let v_slice = from_raw_parts_mut(v, n * ldv);
+                let lv = Layout::new_unchecked([n, n], [ldv as isize, 1], 0);
+                let lv_t = Layout::new_unchecked([n, n], [1, ldv_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(v_slice, &lv, &v_t, &lv_t).unwrap();
+            }
+        }
+        // scaling factors and statistics, same to LAPACKE
+        (0..7).for_each(|i| *stat.add(i) = work[i]);
+        (0..3).for_each(|i| *istat.add(i) = iwork[i]);
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_    ;
+   [Complex<f32>] [cgejsv_];
+   [Complex<f64>] [zgejsv_];
+)]
+impl GEJSVDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut <T as ComplexFloat>::Real,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut <T as ComplexFloat>::Real,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        let want_u = jobu.eq_ignore_ascii_case(&'U') || jobu.eq_ignore_ascii_case(&'F');
+        let want_v = jobv.eq_ignore_ascii_case(&'V') || jobv.eq_ignore_ascii_case(&'J');
+        let ncols_u = if jobu.eq_ignore_ascii_case(&'F') { m } else { n };
+
+        // Allocate memory for working array(s)
+        // sizes are the same to the minimal requirements documented by LAPACK
+        let lwork = 7.max(2 * m + n).max(6 * n + 2 * n * n + 6);
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+        let liwork = 4.max(m + 3 * n);
+        let mut iwork: Vec<blas_int> = match uninitialized_vec(liwork) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+        let lrwork = 7.max(2 * m + n);
+        let mut rwork: Vec<<T as ComplexFloat>::Real> = match uninitialized_vec(lrwork) {
+            Ok(rwork) => rwork,
+            Err(_) => return -1010,
+        };
+
+        let mut info = 0;
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(joba as _),
+                &(jobu as _),
+                &(jobv as _),
+                &(jobr as _),
+                &(jobt as _),
+                &(jobp as _),
+                &(m as _),
+                &(n as _),
+                a as *mut _,
+                &(lda as _),
+                sva,
+                u as *mut _,
+                &(ldu as _),
+                v as *mut _,
+                &(ldv as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                &(lrwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            let lda_t = m.max(1);
+            let ldu_t = if want_u { m.max(1) } else { 1 };
+            let ldv_t = if want_v { n.max(1) } else { 1 };
+
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, m * lda);
+            let la = Layout::new_unchecked([m, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([m, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            let mut u_t: Vec<T> = match uninitialized_vec(if want_u { m * ncols_u } else { 1 }) {
+                Ok(u_t) => u_t,
+                Err(_) => return -1011,
+            };
+            let mut v_t: Vec<T> = match uninitialized_vec(if want_v { n * n } else { 1 }) {
+                Ok(v_t) => v_t,
+                Err(_) => return -1011,
+            };
+
+            // Call LAPACK function and adjust info
+            func_(
+                &(joba as _),
+                &(jobu as _),
+                &(jobv as _),
+                &(jobr as _),
+                &(jobt as _),
+                &(jobp as _),
+                &(m as _),
+                &(n as _),
+                a_t.as_mut_ptr() as *mut _,
+                &(lda_t as _),
+                sva,
+                u_t.as_mut_ptr() as *mut _,
+                &(ldu_t as _),
+                v_t.as_mut_ptr() as *mut _,
+                &(ldv_t as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                &(lrwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            if want_u {
+                let u_slice = from_raw_parts_mut(u, m * ldu);
+                let lu = Layout::new_unchecked([m, ncols_u], [ldu as isize, 1], 0);
+                let lu_t = Layout::new_unchecked([m, ncols_u], [1, ldu_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(u_slice, &lu, &u_t, &lu_t).unwrap();
+            }
+            if want_v {
+                let v_slice = from_raw_parts_mut(v, n * ldv);
+                let lv = Layout::new_unchecked([n, n], [ldv as isize, 1], 0);
+                let lv_t = Layout::new_unchecked([n, n], [1, ldv_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(v_slice, &lv, &v_t, &lv_t).unwrap();
+            }
+        }
+        // scaling factors and statistics, same to LAPACKE
+        (0..7).for_each(|i| *stat.add(i) = rwork[i]);
+        (0..3).for_each(|i| *istat.add(i) = iwork[i]);
+        return info;
+    }
+}
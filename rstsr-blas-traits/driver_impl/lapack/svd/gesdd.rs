@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -13,7 +13,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [sgesdd_];
    [f64] [dgesdd_];
 )]
-impl GESDDDriverAPI<T> for DeviceBLAS {
+impl GESDDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesdd(
         order: FlagOrder,
         jobz: char,
@@ -183,7 +183,7 @@ impl GESDDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cgesdd_];
    [Complex<f64>] [zgesdd_];
 )]
-impl GESDDDriverAPI<T> for DeviceBLAS {
+impl GESDDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesdd(
         order: FlagOrder,
         jobz: char,
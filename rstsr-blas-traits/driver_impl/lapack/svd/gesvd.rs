@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use num::complex::ComplexFloat;
 use num::{Complex, Zero};
 use rstsr_blas_traits::prelude::*;
@@ -13,7 +13,7 @@ use std::slice::from_raw_parts_mut;
    [f32] [sgesvd_];
    [f64] [dgesvd_];
 )]
-impl GESVDDriverAPI<T> for DeviceBLAS {
+impl GESVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesvd(
         order: FlagOrder,
         jobu: char,
@@ -31,6 +31,16 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
     ) -> blas_int {
         use lapack_ffi::lapack::func_;
 
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldu_t, ldvt_t) = match order {
+            ColMajor => (lda, ldu, ldvt),
+            RowMajor => (m.max(1), if jobu == 'A' || jobu == 'S' { m.max(1) } else { 1 }, match jobvt {
+                'A' => n.max(1),
+                'S' => m.min(n).max(1),
+                _ => 1,
+            }),
+        };
+
         // Query optimal working array size
         let mut info = 0;
         let lwork = -1;
@@ -41,12 +51,12 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
             &(m as _),
             &(n as _),
             a,
-            &(lda as _),
+            &(lda_t as _),
             s,
             u,
-            &(ldu as _),
+            &(ldu_t as _),
             vt,
-            &(ldvt as _),
+            &(ldvt_t as _),
             &mut work_query,
             &lwork,
             &mut info,
@@ -84,7 +94,6 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
                 return info;
             }
         } else {
-            let lda_t = m.max(1);
             let nrows_u = if jobu == 'A' || jobu == 'S' { m } else { 1 };
             let ncols_u = if jobu == 'A' {
                 m
@@ -100,8 +109,6 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
             } else {
                 1
             };
-            let ldu_t = nrows_u.max(1);
-            let ldvt_t = nrows_vt.max(1);
 
             // Transpose input matrices
             let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
@@ -185,7 +192,7 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [cgesvd_];
    [Complex<f64>] [zgesvd_];
 )]
-impl GESVDDriverAPI<T> for DeviceBLAS {
+impl GESVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesvd(
         order: FlagOrder,
         jobu: char,
@@ -210,6 +217,16 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
             Err(_) => return -1010,
         };
 
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldu_t, ldvt_t) = match order {
+            ColMajor => (lda, ldu, ldvt),
+            RowMajor => (m.max(1), if jobu == 'A' || jobu == 'S' { m.max(1) } else { 1 }, match jobvt {
+                'A' => n.max(1),
+                'S' => m.min(n).max(1),
+                _ => 1,
+            }),
+        };
+
         // Query optimal working array size
         let mut info = 0;
         let lwork = -1;
@@ -220,12 +237,12 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
             &(m as _),
             &(n as _),
             a as *mut _,
-            &(lda as _),
+            &(lda_t as _),
             s as *mut _,
             u as *mut _,
-            &(ldu as _),
+            &(ldu_t as _),
             vt as *mut _,
-            &(ldvt as _),
+            &(ldvt_t as _),
             &mut work_query as *mut _ as *mut _,
             &lwork,
             rwork.as_mut_ptr() as *mut _,
@@ -265,7 +282,6 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
                 return info;
             }
         } else {
-            let lda_t = m.max(1);
             let nrows_u = if jobu == 'A' || jobu == 'S' { m } else { 1 };
             let ncols_u = if jobu == 'A' {
                 m
@@ -281,8 +297,6 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
             } else {
                 1
             };
-            let ldu_t = nrows_u.max(1);
-            let ldvt_t = nrows_vt.max(1);
 
             // Transpose input matrices
             let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
@@ -0,0 +1,379 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude_dev::*;
+use rstsr_native_impl::prelude_dev::*;
+use std::slice::from_raw_parts_mut;
+
+#[duplicate_item(
+    T     func_     ;
+   [f32] [sgesvdx_];
+   [f64] [dgesvdx_];
+)]
+impl GESVDXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T,
+        vu: T,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut T,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        let minmn = m.min(n);
+        let ncol = if range.eq_ignore_ascii_case(&'I') { (iu + 1).saturating_sub(il) } else { minmn };
+        let want_u = jobu.eq_ignore_ascii_case(&'V');
+        let want_vt = jobvt.eq_ignore_ascii_case(&'V');
+
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldu_t, ldvt_t) = match order {
+            ColMajor => (lda, ldu, ldvt),
+            RowMajor => (m.max(1), if want_u { m.max(1) } else { 1 }, if want_vt { ncol.max(1) } else { 1 }),
+        };
+
+        // Allocate memory for working array(s)
+        let mut iwork: Vec<blas_int> = match uninitialized_vec((12 * minmn).max(1)) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut ns_ = 0;
+        let lwork = -1;
+        let mut work_query = 0.0;
+        func_(
+            &(jobu as _),
+            &(jobvt as _),
+            &(range as _),
+            &(m as _),
+            &(n as _),
+            a,
+            &(lda_t as _),
+            &vl,
+            &vu,
+            &(il as _),
+            &(iu as _),
+            &mut ns_,
+            s,
+            u,
+            &(ldu_t as _),
+            vt,
+            &(ldvt_t as _),
+            &mut work_query,
+            &lwork,
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query as usize;
+
+        // Allocate memory for work arrays
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobu as _),
+                &(jobvt as _),
+                &(range as _),
+                &(m as _),
+                &(n as _),
+                a,
+                &(lda as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &mut ns_,
+                s,
+                u,
+                &(ldu as _),
+                vt,
+                &(ldvt as _),
+                work.as_mut_ptr(),
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, m * lda);
+            let la = Layout::new_unchecked([m, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([m, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            let mut u_t: Vec<T> = match uninitialized_vec(if want_u { m * ncol } else { 1 }) {
+                Ok(u_t) => u_t,
+                Err(_) => return -1011,
+            };
+            let mut vt_t: Vec<T> = match uninitialized_vec(if want_vt { ncol * n } else { 1 }) {
+                Ok(vt_t) => vt_t,
+                Err(_) => return -1011,
+            };
+
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobu as _),
+                &(jobvt as _),
+                &(range as _),
+                &(m as _),
+                &(n as _),
+                a_t.as_mut_ptr(),
+                &(lda_t as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &mut ns_,
+                s,
+                u_t.as_mut_ptr(),
+                &(ldu_t as _),
+                vt_t.as_mut_ptr(),
+                &(ldvt_t as _),
+                work.as_mut_ptr(),
+                &(lwork as _),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            let ns_ = ns_ as usize;
+            if want_u {
+                let u_slice = from_raw_parts_mut(u, m * ldu);
+                let lu = Layout::new_unchecked([m, ns_], [ldu as isize, 1], 0);
+                let lu_t = Layout::new_unchecked([m, ns_], [1, ldu_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(u_slice, &lu, &u_t, &lu_t).unwrap();
+            }
+            if want_vt {
+                let vt_slice = from_raw_parts_mut(vt, ncol * ldvt);
+                let lvt = Layout::new_unchecked([ns_, n], [ldvt as isize, 1], 0);
+                let lvt_t = Layout::new_unchecked([ns_, n], [1, ldvt_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vt_slice, &lvt, &vt_t, &lvt_t).unwrap();
+            }
+        }
+        // indices of eigenvectors that failed to converge, same to LAPACKE
+        (0..(12 * minmn).saturating_sub(1)).for_each(|i| *superb.add(i) = iwork[i + 1]);
+        *ns = ns_ as usize;
+        return info;
+    }
+}
+
+#[duplicate_item(
+    T              func_     ;
+   [Complex<f32>] [cgesvdx_];
+   [Complex<f64>] [zgesvdx_];
+)]
+impl GESVDXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: <T as ComplexFloat>::Real,
+        vu: <T as ComplexFloat>::Real,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut <T as ComplexFloat>::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        use lapack_ffi::lapack::func_;
+
+        let minmn = m.min(n);
+        let ncol = if range.eq_ignore_ascii_case(&'I') { (iu + 1).saturating_sub(il) } else { minmn };
+        let want_u = jobu.eq_ignore_ascii_case(&'V');
+        let want_vt = jobvt.eq_ignore_ascii_case(&'V');
+
+        // leading dimensions of col-major matrices passed to LAPACK
+        let (lda_t, ldu_t, ldvt_t) = match order {
+            ColMajor => (lda, ldu, ldvt),
+            RowMajor => (m.max(1), if want_u { m.max(1) } else { 1 }, if want_vt { ncol.max(1) } else { 1 }),
+        };
+
+        // Allocate memory for working array(s)
+        let mut iwork: Vec<blas_int> = match uninitialized_vec((12 * minmn).max(1)) {
+            Ok(iwork) => iwork,
+            Err(_) => return -1010,
+        };
+        let mut rwork: Vec<<T as ComplexFloat>::Real> = match uninitialized_vec((17 * minmn * minmn).max(1)) {
+            Ok(rwork) => rwork,
+            Err(_) => return -1010,
+        };
+
+        // Query optimal working array(s) size
+        let mut info = 0;
+        let mut ns_ = 0;
+        let lwork = -1;
+        let mut work_query = Complex::new(0.0, 0.0);
+        func_(
+            &(jobu as _),
+            &(jobvt as _),
+            &(range as _),
+            &(m as _),
+            &(n as _),
+            a as *mut _,
+            &(lda_t as _),
+            &vl,
+            &vu,
+            &(il as _),
+            &(iu as _),
+            &mut ns_,
+            s,
+            u as *mut _,
+            &(ldu_t as _),
+            vt as *mut _,
+            &(ldvt_t as _),
+            &mut work_query as *mut _ as *mut _,
+            &lwork,
+            rwork.as_mut_ptr(),
+            iwork.as_mut_ptr(),
+            &mut info,
+        );
+        if info != 0 {
+            return info;
+        }
+        let lwork = work_query.re as usize;
+
+        // Allocate memory for work arrays
+        let mut work: Vec<T> = match uninitialized_vec(lwork) {
+            Ok(work) => work,
+            Err(_) => return -1010,
+        };
+
+        if order == ColMajor {
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobu as _),
+                &(jobvt as _),
+                &(range as _),
+                &(m as _),
+                &(n as _),
+                a as *mut _,
+                &(lda as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &mut ns_,
+                s,
+                u as *mut _,
+                &(ldu as _),
+                vt as *mut _,
+                &(ldvt as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+        } else {
+            // Transpose input matrices
+            let mut a_t: Vec<T> = match uninitialized_vec(m * n) {
+                Ok(a_t) => a_t,
+                Err(_) => return -1011,
+            };
+            let a_slice = from_raw_parts_mut(a, m * lda);
+            let la = Layout::new_unchecked([m, n], [lda as isize, 1], 0);
+            let la_t = Layout::new_unchecked([m, n], [1, lda_t as isize], 0);
+            orderchange_out_r2c_ix2_cpu_serial(&mut a_t, &la_t, a_slice, &la).unwrap();
+            let mut u_t: Vec<T> = match uninitialized_vec(if want_u { m * ncol } else { 1 }) {
+                Ok(u_t) => u_t,
+                Err(_) => return -1011,
+            };
+            let mut vt_t: Vec<T> = match uninitialized_vec(if want_vt { ncol * n } else { 1 }) {
+                Ok(vt_t) => vt_t,
+                Err(_) => return -1011,
+            };
+
+            // Call LAPACK function and adjust info
+            func_(
+                &(jobu as _),
+                &(jobvt as _),
+                &(range as _),
+                &(m as _),
+                &(n as _),
+                a_t.as_mut_ptr() as *mut _,
+                &(lda_t as _),
+                &vl,
+                &vu,
+                &(il as _),
+                &(iu as _),
+                &mut ns_,
+                s,
+                u_t.as_mut_ptr() as *mut _,
+                &(ldu_t as _),
+                vt_t.as_mut_ptr() as *mut _,
+                &(ldvt_t as _),
+                work.as_mut_ptr() as *mut _,
+                &(lwork as _),
+                rwork.as_mut_ptr(),
+                iwork.as_mut_ptr(),
+                &mut info,
+            );
+            if info != 0 {
+                return info;
+            }
+
+            // Transpose output matrices
+            let ns_ = ns_ as usize;
+            if want_u {
+                let u_slice = from_raw_parts_mut(u, m * ldu);
+                let lu = Layout::new_unchecked([m, ns_], [ldu as isize, 1], 0);
+                let lu_t = Layout::new_unchecked([m, ns_], [1, ldu_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(u_slice, &lu, &u_t, &lu_t).unwrap();
+            }
+            if want_vt {
+                let vt_slice = from_raw_parts_mut(vt, ncol * ldvt);
+                let lvt = Layout::new_unchecked([ns_, n], [ldvt as isize, 1], 0);
+                let lvt_t = Layout::new_unchecked([ns_, n], [1, ldvt_t as isize], 0);
+                orderchange_out_c2r_ix2_cpu_serial(vt_slice, &lvt, &vt_t, &lvt_t).unwrap();
+            }
+        }
+        // indices of eigenvectors that failed to converge, same to LAPACKE
+        (0..(12 * minmn).saturating_sub(1)).for_each(|i| *superb.add(i) = iwork[i + 1]);
+        *ns = ns_ as usize;
+        return info;
+    }
+}
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_ssyev];
    [f64] [LAPACKE_dsyev];
 )]
-impl SYEVDriverAPI<T> for DeviceBLAS {
+impl SYEVDriverAPI<T> for BlasBackend {
     unsafe fn driver_syev(
         order: FlagOrder,
         jobz: char,
@@ -30,7 +30,7 @@ impl SYEVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cheev];
    [Complex<f64>] [LAPACKE_zheev];
 )]
-impl SYEVDriverAPI<T> for DeviceBLAS {
+impl SYEVDriverAPI<T> for BlasBackend {
     unsafe fn driver_syev(
         order: FlagOrder,
         jobz: char,
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_ssyevd];
    [f64] [LAPACKE_dsyevd];
 )]
-impl SYEVDDriverAPI<T> for DeviceBLAS {
+impl SYEVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_syevd(
         order: FlagOrder,
         jobz: char,
@@ -30,7 +30,7 @@ impl SYEVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cheevd];
    [Complex<f64>] [LAPACKE_zheevd];
 )]
-impl SYEVDDriverAPI<T> for DeviceBLAS {
+impl SYEVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_syevd(
         order: FlagOrder,
         jobz: char,
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_ssygv];
    [f64] [LAPACKE_dsygv];
 )]
-impl SYGVDriverAPI<T> for DeviceBLAS {
+impl SYGVDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygv(
         order: FlagOrder,
         itype: blas_int,
@@ -44,7 +44,7 @@ impl SYGVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_chegv];
    [Complex<f64>] [LAPACKE_zhegv];
 )]
-impl SYGVDriverAPI<T> for DeviceBLAS {
+impl SYGVDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygv(
         order: FlagOrder,
         itype: blas_int,
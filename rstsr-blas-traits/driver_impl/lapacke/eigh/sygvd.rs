@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_ssygvd];
    [f64] [LAPACKE_dsygvd];
 )]
-impl SYGVDDriverAPI<T> for DeviceBLAS {
+impl SYGVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygvd(
         order: FlagOrder,
         itype: blas_int,
@@ -44,7 +44,7 @@ impl SYGVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_chegvd];
    [Complex<f64>] [LAPACKE_zhegvd];
 )]
-impl SYGVDDriverAPI<T> for DeviceBLAS {
+impl SYGVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_sygvd(
         order: FlagOrder,
         itype: blas_int,
@@ -0,0 +1,119 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_ssygvx];
+   [f64] [LAPACKE_dsygvx];
+)]
+impl SYGVXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_sygvx(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        vl: T,
+        vu: T,
+        il: usize,
+        iu: usize,
+        abstol: T,
+        m: &mut usize,
+        w: *mut T,
+        z: *mut T,
+        ldz: usize,
+        ifail: *mut blas_int,
+    ) -> blas_int {
+        let mut m_ = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            itype as _,
+            jobz as _,
+            range as _,
+            uplo.into(),
+            n as _,
+            a,
+            lda as _,
+            b,
+            ldb as _,
+            vl,
+            vu,
+            il as _,
+            iu as _,
+            abstol,
+            &mut m_,
+            w,
+            z,
+            ldz as _,
+            ifail,
+        );
+        *m = m_ as usize;
+        info
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_chegvx];
+   [Complex<f64>] [LAPACKE_zhegvx];
+)]
+impl SYGVXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_sygvx(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        vl: <T as ComplexFloat>::Real,
+        vu: <T as ComplexFloat>::Real,
+        il: usize,
+        iu: usize,
+        abstol: <T as ComplexFloat>::Real,
+        m: &mut usize,
+        w: *mut <T as ComplexFloat>::Real,
+        z: *mut T,
+        ldz: usize,
+        ifail: *mut blas_int,
+    ) -> blas_int {
+        let mut m_ = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            itype as _,
+            jobz as _,
+            range as _,
+            uplo.into(),
+            n as _,
+            a as _,
+            lda as _,
+            b as _,
+            ldb as _,
+            vl,
+            vu,
+            il as _,
+            iu as _,
+            abstol,
+            &mut m_,
+            w,
+            z as _,
+            ldz as _,
+            ifail,
+        );
+        *m = m_ as usize;
+        info
+    }
+}
@@ -0,0 +1,75 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_sgees];
+   [f64] [LAPACKE_dgees];
+)]
+impl GEESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut T,
+        wi: *mut T,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        let mut sdim = 0;
+        lapack_ffi::lapacke::lapacke_func(
+            order as _, jobvs as _, 'N' as _, None, n as _, a, lda as _, &mut sdim, wr, wi, vs, ldvs as _,
+        )
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_cgees];
+   [Complex<f64>] [LAPACKE_zgees];
+)]
+impl GEESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut <T as ComplexFloat>::Real,
+        wi: *mut <T as ComplexFloat>::Real,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        let mut w: Vec<T> = match uninitialized_vec(n) {
+            Ok(w) => w,
+            Err(_) => return -1010,
+        };
+        let mut sdim = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            jobvs as _,
+            'N' as _,
+            None,
+            n as _,
+            a as *mut _,
+            lda as _,
+            &mut sdim,
+            w.as_mut_ptr() as *mut _,
+            vs as *mut _,
+            ldvs as _,
+        );
+        for (i, w) in w.iter().enumerate() {
+            *wr.add(i) = w.re;
+            *wi.add(i) = w.im;
+        }
+        info
+    }
+}
@@ -0,0 +1,44 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_sgehrd];
+   [f64] [LAPACKE_dgehrd];
+)]
+impl GEHRDDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gehrd(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *mut T,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(order as _, n as _, ilo as _, ihi as _, a, lda as _, tau)
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_cgehrd];
+   [Complex<f64>] [LAPACKE_zgehrd];
+)]
+impl GEHRDDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gehrd(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *mut T,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(order as _, n as _, ilo as _, ihi as _, a as *mut _, lda as _, tau as *mut _)
+    }
+}
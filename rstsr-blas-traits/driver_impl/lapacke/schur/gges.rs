@@ -0,0 +1,110 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_sgges];
+   [f64] [LAPACKE_dgges];
+)]
+impl GGESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut T,
+        alphai: *mut T,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        let mut sdim = 0;
+        lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            jobvsl as _,
+            jobvsr as _,
+            'N' as _,
+            None,
+            n as _,
+            a,
+            lda as _,
+            b,
+            ldb as _,
+            &mut sdim,
+            alphar,
+            alphai,
+            beta,
+            vsl,
+            ldvsl as _,
+            vsr,
+            ldvsr as _,
+        )
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_cgges];
+   [Complex<f64>] [LAPACKE_zgges];
+)]
+impl GGESDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut <T as ComplexFloat>::Real,
+        alphai: *mut <T as ComplexFloat>::Real,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        let mut alpha: Vec<T> = match uninitialized_vec(n) {
+            Ok(alpha) => alpha,
+            Err(_) => return -1010,
+        };
+        let mut sdim = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            jobvsl as _,
+            jobvsr as _,
+            'N' as _,
+            None,
+            n as _,
+            a as *mut _,
+            lda as _,
+            b as *mut _,
+            ldb as _,
+            &mut sdim,
+            alpha.as_mut_ptr() as *mut _,
+            beta as *mut _,
+            vsl as *mut _,
+            ldvsl as _,
+            vsr as *mut _,
+            ldvsr as _,
+        );
+        for (i, alpha) in alpha.iter().enumerate() {
+            *alphar.add(i) = alpha.re;
+            *alphai.add(i) = alpha.im;
+        }
+        info
+    }
+}
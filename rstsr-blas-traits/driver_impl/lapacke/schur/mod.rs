@@ -0,0 +1,6 @@
+pub mod gees;
+pub mod gehrd;
+pub mod gges;
+pub mod orghr;
+pub mod trsen;
+pub mod trsyl;
@@ -0,0 +1,52 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_sorghr];
+   [f64] [LAPACKE_dorghr];
+)]
+impl ORGHRDriverAPI<T> for BlasBackend {
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(order as _, n as _, ilo as _, ihi as _, a, lda as _, tau)
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_cunghr];
+   [Complex<f64>] [LAPACKE_zunghr];
+)]
+impl ORGHRDriverAPI<T> for BlasBackend {
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            n as _,
+            ilo as _,
+            ihi as _,
+            a as *mut _,
+            lda as _,
+            tau as *const _,
+        )
+    }
+}
@@ -0,0 +1,89 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+use rstsr_common::prelude_dev::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_strsen];
+   [f64] [LAPACKE_dtrsen];
+)]
+impl TRSENDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut T,
+        wi: *mut T,
+        m: &mut usize,
+    ) -> blas_int {
+        let mut m_ = 0;
+        let mut s = 0.0;
+        let mut sep = 0.0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _, 'N' as _, compq as _, select, n as _, t, ldt as _, q, ldq as _, wr, wi, &mut m_, &mut s,
+            &mut sep,
+        );
+        *m = m_ as usize;
+        info
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_ctrsen];
+   [Complex<f64>] [LAPACKE_ztrsen];
+)]
+impl TRSENDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut <T as ComplexFloat>::Real,
+        wi: *mut <T as ComplexFloat>::Real,
+        m: &mut usize,
+    ) -> blas_int {
+        let mut w: Vec<T> = match uninitialized_vec(n) {
+            Ok(w) => w,
+            Err(_) => return -1010,
+        };
+        let mut m_ = 0;
+        let mut s = 0.0;
+        let mut sep = 0.0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            'N' as _,
+            compq as _,
+            select,
+            n as _,
+            t as *mut _,
+            ldt as _,
+            q as *mut _,
+            ldq as _,
+            w.as_mut_ptr() as *mut _,
+            &mut m_,
+            &mut s,
+            &mut sep,
+        );
+        *m = m_ as usize;
+        for (i, w) in w.iter().enumerate() {
+            *wr.add(i) = w.re;
+            *wi.add(i) = w.im;
+        }
+        info
+    }
+}
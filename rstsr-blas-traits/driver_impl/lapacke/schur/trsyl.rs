@@ -0,0 +1,85 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_strsyl];
+   [f64] [LAPACKE_dtrsyl];
+)]
+impl TRSYLDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut T,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            trana.into(),
+            tranb.into(),
+            isgn as _,
+            m as _,
+            n as _,
+            a,
+            lda as _,
+            b,
+            ldb as _,
+            c,
+            ldc as _,
+            scale,
+        )
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_ctrsyl];
+   [Complex<f64>] [LAPACKE_ztrsyl];
+)]
+impl TRSYLDriverAPI<T> for BlasBackend {
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut <T as ComplexFloat>::Real,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            trana.into(),
+            tranb.into(),
+            isgn as _,
+            m as _,
+            n as _,
+            a as *const _,
+            lda as _,
+            b as *const _,
+            ldb as _,
+            c as *mut _,
+            ldc as _,
+            scale,
+        )
+    }
+}
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_sgesv];
    [f64] [LAPACKE_dgesv];
 )]
-impl GESVDriverAPI<T> for DeviceBLAS {
+impl GESVDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesv(
         order: FlagOrder,
         n: usize,
@@ -30,7 +30,7 @@ impl GESVDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cgesv];
    [Complex<f64>] [LAPACKE_zgesv];
 )]
-impl GESVDriverAPI<T> for DeviceBLAS {
+impl GESVDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesv(
         order: FlagOrder,
         n: usize,
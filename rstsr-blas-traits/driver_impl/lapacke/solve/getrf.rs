@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_sgetrf];
    [f64] [LAPACKE_dgetrf];
 )]
-impl GETRFDriverAPI<T> for DeviceBLAS {
+impl GETRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_getrf(
         order: FlagOrder,
         m: usize,
@@ -28,7 +28,7 @@ impl GETRFDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cgetrf];
    [Complex<f64>] [LAPACKE_zgetrf];
 )]
-impl GETRFDriverAPI<T> for DeviceBLAS {
+impl GETRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_getrf(
         order: FlagOrder,
         m: usize,
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::{blas_int, lapack_solve::getri::*};
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_sgetri];
    [f64] [LAPACKE_dgetri];
 )]
-impl GETRIDriverAPI<T> for DeviceBLAS {
+impl GETRIDriverAPI<T> for BlasBackend {
     unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
         lapack_ffi::lapacke::lapacke_func(order as _, n as _, a, lda as _, ipiv)
     }
@@ -21,7 +21,7 @@ impl GETRIDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cgetri];
    [Complex<f64>] [LAPACKE_zgetri];
 )]
-impl GETRIDriverAPI<T> for DeviceBLAS {
+impl GETRIDriverAPI<T> for BlasBackend {
     unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
         lapack_ffi::lapacke::lapacke_func(order as _, n as _, a as *mut _, lda as _, ipiv)
     }
@@ -1,5 +1,7 @@
+pub mod geqrf;
 pub mod gesv;
 pub mod getrf;
 pub mod getri;
+pub mod orgqr;
 pub mod potrf;
 pub mod sysv;
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_spotrf];
    [f64] [LAPACKE_dpotrf];
 )]
-impl POTRFDriverAPI<T> for DeviceBLAS {
+impl POTRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
         lapack_ffi::lapacke::lapacke_func(order as _, uplo.into(), n as _, a, lda as _)
     }
@@ -21,7 +21,7 @@ impl POTRFDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cpotrf];
    [Complex<f64>] [LAPACKE_zpotrf];
 )]
-impl POTRFDriverAPI<T> for DeviceBLAS {
+impl POTRFDriverAPI<T> for BlasBackend {
     unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
         lapack_ffi::lapacke::lapacke_func(order as _, uplo.into(), n as _, a as *mut _, lda as _)
     }
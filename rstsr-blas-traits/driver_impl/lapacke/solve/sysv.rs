@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::Complex;
 use rstsr_blas_traits::prelude::*;
@@ -10,7 +10,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_ssysv];
    [f64] [LAPACKE_dsysv];
 )]
-impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for DeviceBLAS {
+impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_sysv(
         order: FlagOrder,
         uplo: FlagUpLo,
@@ -33,7 +33,7 @@ impl<const HERMI: bool> SYSVDriverAPI<T, HERMI> for DeviceBLAS {
    [Complex<f64>] [LAPACKE_zsysv] [false];
    [Complex<f64>] [LAPACKE_zhesv] [true ];
 )]
-impl SYSVDriverAPI<T, HERMI> for DeviceBLAS {
+impl SYSVDriverAPI<T, HERMI> for BlasBackend {
     unsafe fn driver_sysv(
         order: FlagOrder,
         uplo: FlagUpLo,
@@ -0,0 +1,89 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func   ;
+   [f32] [LAPACKE_sgejsv];
+   [f64] [LAPACKE_dgejsv];
+)]
+impl GEJSVDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut T,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut T,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(
+            order as _, joba as _, jobu as _, jobv as _, jobr as _, jobt as _, jobp as _, m as _, n as _, a, lda as _,
+            sva, u, ldu as _, v, ldv as _, stat, istat,
+        )
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func   ;
+   [Complex<f32>] [LAPACKE_cgejsv];
+   [Complex<f64>] [LAPACKE_zgejsv];
+)]
+impl GEJSVDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut <T as ComplexFloat>::Real,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut <T as ComplexFloat>::Real,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            joba as _,
+            jobu as _,
+            jobv as _,
+            jobr as _,
+            jobt as _,
+            jobp as _,
+            m as _,
+            n as _,
+            a as *mut _,
+            lda as _,
+            sva,
+            u as *mut _,
+            ldu as _,
+            v as *mut _,
+            ldv as _,
+            stat,
+            istat,
+        )
+    }
+}
@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_sgesdd];
    [f64] [LAPACKE_dgesdd];
 )]
-impl GESDDDriverAPI<T> for DeviceBLAS {
+impl GESDDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesdd(
         order: FlagOrder,
         jobz: char,
@@ -36,7 +36,7 @@ impl GESDDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cgesdd];
    [Complex<f64>] [LAPACKE_zgesdd];
 )]
-impl GESDDDriverAPI<T> for DeviceBLAS {
+impl GESDDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesdd(
         order: FlagOrder,
         jobz: char,
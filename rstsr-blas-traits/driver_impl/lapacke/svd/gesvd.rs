@@ -1,5 +1,5 @@
 use crate::lapack_ffi;
-use crate::DeviceBLAS;
+use crate::BlasBackend;
 use duplicate::duplicate_item;
 use num::complex::ComplexFloat;
 use num::Complex;
@@ -11,7 +11,7 @@ use rstsr_common::prelude::*;
    [f32] [LAPACKE_sgesvd];
    [f64] [LAPACKE_dgesvd];
 )]
-impl GESVDDriverAPI<T> for DeviceBLAS {
+impl GESVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesvd(
         order: FlagOrder,
         jobu: char,
@@ -38,7 +38,7 @@ impl GESVDDriverAPI<T> for DeviceBLAS {
    [Complex<f32>] [LAPACKE_cgesvd];
    [Complex<f64>] [LAPACKE_zgesvd];
 )]
-impl GESVDDriverAPI<T> for DeviceBLAS {
+impl GESVDDriverAPI<T> for BlasBackend {
     unsafe fn driver_gesvd(
         order: FlagOrder,
         jobu: char,
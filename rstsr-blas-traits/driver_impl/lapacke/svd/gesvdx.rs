@@ -0,0 +1,98 @@
+use crate::lapack_ffi;
+use crate::BlasBackend;
+use duplicate::duplicate_item;
+use num::complex::ComplexFloat;
+use num::Complex;
+use rstsr_blas_traits::prelude::*;
+use rstsr_common::prelude::*;
+
+#[duplicate_item(
+    T     lapacke_func    ;
+   [f32] [LAPACKE_sgesvdx];
+   [f64] [LAPACKE_dgesvdx];
+)]
+impl GESVDXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T,
+        vu: T,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut T,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        let mut ns_ = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _, jobu as _, jobvt as _, range as _, m as _, n as _, a, lda as _, vl, vu, il as _, iu as _,
+            &mut ns_, s, u, ldu as _, vt, ldvt as _, superb,
+        );
+        *ns = ns_ as usize;
+        info
+    }
+}
+
+#[duplicate_item(
+    T              lapacke_func    ;
+   [Complex<f32>] [LAPACKE_cgesvdx];
+   [Complex<f64>] [LAPACKE_zgesvdx];
+)]
+impl GESVDXDriverAPI<T> for BlasBackend {
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: <T as ComplexFloat>::Real,
+        vu: <T as ComplexFloat>::Real,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut <T as ComplexFloat>::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        let mut ns_ = 0;
+        let info = lapack_ffi::lapacke::lapacke_func(
+            order as _,
+            jobu as _,
+            jobvt as _,
+            range as _,
+            m as _,
+            n as _,
+            a as *mut _,
+            lda as _,
+            vl,
+            vu,
+            il as _,
+            iu as _,
+            &mut ns_,
+            s,
+            u as *mut _,
+            ldu as _,
+            vt as *mut _,
+            ldvt as _,
+            superb,
+        );
+        *ns = ns_ as usize;
+        info
+    }
+}
@@ -1,2 +1,4 @@
+pub mod gejsv;
 pub mod gesdd;
 pub mod gesvd;
+pub mod gesvdx;
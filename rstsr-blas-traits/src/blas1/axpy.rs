@@ -0,0 +1,50 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait AXPYDriverAPI<T> {
+    unsafe fn driver_axpy(n: usize, alpha: T, x: *const T, incx: usize, y: *mut T, incy: usize);
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct AXPY_<'x, 'y, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub x: TensorView<'x, T, B, Ix1>,
+    #[builder(setter(into))]
+    pub y: TensorReference<'y, T, B, Ix1>,
+
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+}
+
+impl<'y, B, T> AXPY_<'_, 'y, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + AXPYDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable1<'y, T, B>> {
+        let Self { x, y, alpha } = self;
+
+        rstsr_assert!(x.device().same_device(y.device()), DeviceMismatch)?;
+        rstsr_assert_eq!(x.size(), y.size(), InvalidLayout)?;
+        let n = x.size();
+
+        let (x, incx) = positive_inc_convert(x)?;
+        let mut y = overwritable_convert(y)?;
+
+        let ptr_x = x.as_ptr();
+        let ptr_y = y.view_mut().as_mut_ptr();
+        unsafe { B::driver_axpy(n, alpha, ptr_x, incx, ptr_y, 1) };
+
+        Ok(y.clone_to_mut())
+    }
+}
+
+pub type AXPY<'x, 'y, B, T> = AXPY_Builder<'x, 'y, B, T>;
+pub type SAXPY<'x, 'y, B> = AXPY<'x, 'y, B, f32>;
+pub type DAXPY<'x, 'y, B> = AXPY<'x, 'y, B, f64>;
+pub type CAXPY<'x, 'y, B> = AXPY<'x, 'y, B, Complex<f32>>;
+pub type ZAXPY<'x, 'y, B> = AXPY<'x, 'y, B, Complex<f64>>;
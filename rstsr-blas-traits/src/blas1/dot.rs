@@ -0,0 +1,46 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+/// Dot product of vectors; `x` is conjugated if `CONJ` (`?dotc`), otherwise
+/// not (`?dotu`, or `?dot` for real types).
+pub trait DOTDriverAPI<T, const CONJ: bool> {
+    unsafe fn driver_dot(n: usize, x: *const T, incx: usize, y: *const T, incy: usize) -> T;
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct DOT_<'x, 'y, B, T, const CONJ: bool>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub x: TensorView<'x, T, B, Ix1>,
+    pub y: TensorView<'y, T, B, Ix1>,
+}
+
+impl<B, T, const CONJ: bool> DOT_<'_, '_, B, T, CONJ>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + DOTDriverAPI<T, CONJ>,
+{
+    pub fn run(self) -> Result<T> {
+        let Self { x, y } = self;
+
+        rstsr_assert!(x.device().same_device(y.device()), DeviceMismatch)?;
+        rstsr_assert_eq!(x.size(), y.size(), InvalidLayout)?;
+        let n = x.size();
+
+        let (x, incx) = positive_inc_convert(x)?;
+        let (y, incy) = positive_inc_convert(y)?;
+
+        Ok(unsafe { B::driver_dot(n, x.as_ptr(), incx, y.as_ptr(), incy) })
+    }
+}
+
+pub type DOT<'x, 'y, B, T, const CONJ: bool> = DOT_Builder<'x, 'y, B, T, CONJ>;
+pub type SDOT<'x, 'y, B> = DOT<'x, 'y, B, f32, false>;
+pub type DDOT<'x, 'y, B> = DOT<'x, 'y, B, f64, false>;
+pub type CDOTU<'x, 'y, B> = DOT<'x, 'y, B, Complex<f32>, false>;
+pub type ZDOTU<'x, 'y, B> = DOT<'x, 'y, B, Complex<f64>, false>;
+pub type CDOTC<'x, 'y, B> = DOT<'x, 'y, B, Complex<f32>, true>;
+pub type ZDOTC<'x, 'y, B> = DOT<'x, 'y, B, Complex<f64>, true>;
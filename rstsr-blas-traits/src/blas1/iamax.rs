@@ -0,0 +1,39 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+/// Index (0-based) of the first element with maximum `|re(x)| + |im(x)|`.
+pub trait IAMAXDriverAPI<T> {
+    unsafe fn driver_iamax(n: usize, x: *const T, incx: usize) -> usize;
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct IAMAX_<'x, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub x: TensorView<'x, T, B, Ix1>,
+}
+
+impl<B, T> IAMAX_<'_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + IAMAXDriverAPI<T>,
+{
+    pub fn run(self) -> Result<usize> {
+        let Self { x } = self;
+
+        let n = x.size();
+        rstsr_assert!(n > 0, InvalidValue, "IAMAX: empty vector has no maximum")?;
+        let (x, incx) = positive_inc_convert(x)?;
+
+        Ok(unsafe { B::driver_iamax(n, x.as_ptr(), incx) })
+    }
+}
+
+pub type IAMAX<'x, B, T> = IAMAX_Builder<'x, B, T>;
+pub type ISAMAX<'x, B> = IAMAX<'x, B, f32>;
+pub type IDAMAX<'x, B> = IAMAX<'x, B, f64>;
+pub type ICAMAX<'x, B> = IAMAX<'x, B, Complex<f32>>;
+pub type IZAMAX<'x, B> = IAMAX<'x, B, Complex<f64>>;
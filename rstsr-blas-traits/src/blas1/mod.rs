@@ -0,0 +1,11 @@
+pub mod axpy;
+pub mod dot;
+pub mod iamax;
+pub mod nrm2;
+pub mod scal;
+
+pub use axpy::*;
+pub use dot::*;
+pub use iamax::*;
+pub use nrm2::*;
+pub use scal::*;
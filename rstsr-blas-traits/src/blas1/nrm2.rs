@@ -0,0 +1,40 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait NRM2DriverAPI<T>
+where
+    T: BlasFloat,
+{
+    unsafe fn driver_nrm2(n: usize, x: *const T, incx: usize) -> T::Real;
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct NRM2_<'x, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub x: TensorView<'x, T, B, Ix1>,
+}
+
+impl<B, T> NRM2_<'_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + NRM2DriverAPI<T>,
+{
+    pub fn run(self) -> Result<T::Real> {
+        let Self { x } = self;
+
+        let n = x.size();
+        let (x, incx) = positive_inc_convert(x)?;
+
+        Ok(unsafe { B::driver_nrm2(n, x.as_ptr(), incx) })
+    }
+}
+
+pub type NRM2<'x, B, T> = NRM2_Builder<'x, B, T>;
+pub type SNRM2<'x, B> = NRM2<'x, B, f32>;
+pub type DNRM2<'x, B> = NRM2<'x, B, f64>;
+pub type SCNRM2<'x, B> = NRM2<'x, B, Complex<f32>>;
+pub type DZNRM2<'x, B> = NRM2<'x, B, Complex<f64>>;
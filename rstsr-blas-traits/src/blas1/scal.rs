@@ -0,0 +1,44 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait SCALDriverAPI<T> {
+    unsafe fn driver_scal(n: usize, alpha: T, x: *mut T, incx: usize);
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct SCAL_<'x, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub x: TensorReference<'x, T, B, Ix1>,
+
+    #[builder(setter(into))]
+    pub alpha: T,
+}
+
+impl<'x, B, T> SCAL_<'x, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + SCALDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable1<'x, T, B>> {
+        let Self { x, alpha } = self;
+
+        let n = x.size();
+        let mut x = overwritable_convert(x)?;
+
+        let ptr_x = x.view_mut().as_mut_ptr();
+        unsafe { B::driver_scal(n, alpha, ptr_x, 1) };
+
+        Ok(x.clone_to_mut())
+    }
+}
+
+pub type SCAL<'x, B, T> = SCAL_Builder<'x, B, T>;
+pub type SSCAL<'x, B> = SCAL<'x, B, f32>;
+pub type DSCAL<'x, B> = SCAL<'x, B, f64>;
+pub type CSCAL<'x, B> = SCAL<'x, B, Complex<f32>>;
+pub type ZSCAL<'x, B> = SCAL<'x, B, Complex<f64>>;
@@ -0,0 +1,104 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait GEMVDriverAPI<T> {
+    unsafe fn driver_gemv(
+        order: FlagOrder,
+        trans: FlagTrans,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GEMV_<'a, 'x, 'y, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub a: TensorView<'a, T, B, Ix2>,
+    pub x: TensorView<'x, T, B, Ix1>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub y: Option<TensorViewMut<'y, T, B, Ix1>>,
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+    #[builder(setter(into), default = "T::zero()")]
+    pub beta: T,
+    #[builder(setter(into), default = "NoTrans")]
+    pub trans: FlagTrans,
+}
+
+impl<'y, B, T> GEMV_<'_, '_, 'y, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GEMVDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable1<'y, T, B>> {
+        let Self { a, x, y, alpha, beta, trans } = self;
+
+        // device check
+        rstsr_assert!(a.device().same_device(x.device()), DeviceMismatch)?;
+
+        // CBLAS accepts both row-major and col-major matrix
+        let order = match (a.f_prefer(), a.c_prefer()) {
+            (true, false) => ColMajor,
+            (false, true) => RowMajor,
+            _ => a.device().default_order(),
+        };
+        let a = a.to_contig_f(order)?;
+
+        // initialize intent(hide)
+        let [m, n] = *a.shape();
+        let (len_x, len_y) = match trans {
+            FlagTrans::N => (n, m),
+            FlagTrans::T | FlagTrans::C => (m, n),
+            _ => rstsr_invalid!(trans)?,
+        };
+        let lda = a.view().ld(order).unwrap();
+
+        // perform check
+        rstsr_assert_eq!(x.size(), len_x, InvalidLayout)?;
+        let (x, incx) = positive_inc_convert(x)?;
+
+        // optional intent(out)
+        let mut y = if let Some(y) = y {
+            rstsr_assert!(a.device().same_device(y.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(y.size(), len_y, InvalidLayout)?;
+            if y.c_prefer() {
+                TensorMutable::Mut(y)
+            } else {
+                let y_buffer = y.to_contig_f(RowMajor)?.into_owned();
+                TensorMutable::ToBeCloned(y, y_buffer)
+            }
+        } else {
+            TensorMutable1::Owned(zeros_f(([len_y].c(), a.device()))?.into_dim())
+        };
+
+        // perform blas
+        let ptr_a = a.as_ptr();
+        let ptr_x = x.as_ptr();
+        let ptr_y = y.view_mut().as_mut_ptr();
+
+        unsafe {
+            B::driver_gemv(order, trans, m, n, alpha, ptr_a, lda, ptr_x, incx, beta, ptr_y, 1);
+        }
+
+        Ok(y.clone_to_mut())
+    }
+}
+
+pub type GEMV<'a, 'x, 'y, B, T> = GEMV_Builder<'a, 'x, 'y, B, T>;
+pub type SGEMV<'a, 'x, 'y, B> = GEMV<'a, 'x, 'y, B, f32>;
+pub type DGEMV<'a, 'x, 'y, B> = GEMV<'a, 'x, 'y, B, f64>;
+pub type CGEMV<'a, 'x, 'y, B> = GEMV<'a, 'x, 'y, B, Complex<f32>>;
+pub type ZGEMV<'a, 'x, 'y, B> = GEMV<'a, 'x, 'y, B, Complex<f64>>;
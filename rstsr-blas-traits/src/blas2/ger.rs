@@ -0,0 +1,93 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+/// Rank-1 update `A = alpha x y^T + A`; `y` is conjugated if `CONJ`
+/// (`?gerc`), otherwise not (`?geru`, or `?ger` for real types).
+pub trait GERDriverAPI<T, const CONJ: bool> {
+    unsafe fn driver_ger(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: usize,
+        y: *const T,
+        incy: usize,
+        a: *mut T,
+        lda: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GER_<'x, 'y, 'a, B, T, const CONJ: bool>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub x: TensorView<'x, T, B, Ix1>,
+    pub y: TensorView<'y, T, B, Ix1>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub a: Option<TensorViewMut<'a, T, B, Ix2>>,
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+}
+
+impl<'a, B, T, const CONJ: bool> GER_<'_, '_, 'a, B, T, CONJ>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GERDriverAPI<T, CONJ>,
+{
+    pub fn run(self) -> Result<TensorMutable2<'a, T, B>> {
+        let Self { x, y, a, alpha } = self;
+
+        // device check
+        rstsr_assert!(x.device().same_device(y.device()), DeviceMismatch)?;
+
+        // initialize intent(hide)
+        let m = x.size();
+        let n = y.size();
+
+        // optional intent(in, out); CBLAS accepts both row-major and col-major matrix
+        let mut a = if let Some(a) = a {
+            rstsr_assert!(x.device().same_device(a.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(a.shape(), &[m, n], InvalidLayout)?;
+            if a.f_prefer() || a.c_prefer() {
+                TensorMutable::Mut(a)
+            } else {
+                let order = a.device().default_order();
+                let a_buffer = a.to_contig_f(order)?.into_owned();
+                TensorMutable::ToBeCloned(a, a_buffer)
+            }
+        } else {
+            TensorMutable2::Owned(zeros_f(([m, n], x.device().default_order(), x.device()))?.into_dim())
+        };
+        let order = match (a.view().f_prefer(), a.view().c_prefer()) {
+            (true, false) => ColMajor,
+            (false, true) => RowMajor,
+            _ => x.device().default_order(),
+        };
+        let lda = a.view().ld(order).unwrap();
+
+        let (x, incx) = positive_inc_convert(x)?;
+        let (y, incy) = positive_inc_convert(y)?;
+
+        // perform blas
+        let ptr_x = x.as_ptr();
+        let ptr_y = y.as_ptr();
+        let ptr_a = a.view_mut().as_mut_ptr();
+
+        unsafe { B::driver_ger(order, m, n, alpha, ptr_x, incx, ptr_y, incy, ptr_a, lda) };
+
+        Ok(a.clone_to_mut())
+    }
+}
+
+pub type GER<'x, 'y, 'a, B, T, const CONJ: bool> = GER_Builder<'x, 'y, 'a, B, T, CONJ>;
+pub type SGER<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, f32, false>;
+pub type DGER<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, f64, false>;
+pub type CGERU<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, Complex<f32>, false>;
+pub type ZGERU<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, Complex<f64>, false>;
+pub type CGERC<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, Complex<f32>, true>;
+pub type ZGERC<'x, 'y, 'a, B> = GER<'x, 'y, 'a, B, Complex<f64>, true>;
@@ -0,0 +1,9 @@
+pub mod gemv;
+pub mod ger;
+pub mod syhemv;
+pub mod trmv;
+
+pub use gemv::*;
+pub use ger::*;
+pub use syhemv::*;
+pub use trmv::*;
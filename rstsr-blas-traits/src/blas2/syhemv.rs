@@ -0,0 +1,108 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+/// Symmetric (`HERMI = false`) or hermitian (`HERMI = true`) matrix-vector
+/// product.
+///
+/// CBLAS does not provide complex symmetric matrix-vector product, so backends
+/// only implement `HERMI = false` for real types.
+pub trait SYHEMVDriverAPI<T, const HERMI: bool> {
+    unsafe fn driver_syhemv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct SYHEMV_<'a, 'x, 'y, B, T, const HERMI: bool>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub a: TensorView<'a, T, B, Ix2>,
+    pub x: TensorView<'x, T, B, Ix1>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub y: Option<TensorViewMut<'y, T, B, Ix1>>,
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+    #[builder(setter(into), default = "T::zero()")]
+    pub beta: T,
+    #[builder(setter(into), default = "None")]
+    pub uplo: Option<FlagUpLo>,
+}
+
+impl<'y, B, T, const HERMI: bool> SYHEMV_<'_, '_, 'y, B, T, HERMI>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + SYHEMVDriverAPI<T, HERMI>,
+{
+    pub fn run(self) -> Result<TensorMutable1<'y, T, B>> {
+        let Self { a, x, y, alpha, beta, uplo } = self;
+
+        // device check
+        rstsr_assert!(a.device().same_device(x.device()), DeviceMismatch)?;
+        let uplo = uplo.unwrap_or_else(|| match a.device().default_order() {
+            RowMajor => Lower,
+            ColMajor => Upper,
+        });
+
+        // CBLAS accepts both row-major and col-major matrix
+        let order = match (a.f_prefer(), a.c_prefer()) {
+            (true, false) => ColMajor,
+            (false, true) => RowMajor,
+            _ => a.device().default_order(),
+        };
+        let a = a.to_contig_f(order)?;
+
+        // initialize intent(hide)
+        let n = a.view().nrow();
+        rstsr_assert_eq!(a.shape(), &[n, n], InvalidLayout)?;
+        let lda = a.view().ld(order).unwrap();
+
+        // perform check
+        rstsr_assert_eq!(x.size(), n, InvalidLayout)?;
+        let (x, incx) = positive_inc_convert(x)?;
+
+        // optional intent(out)
+        let mut y = if let Some(y) = y {
+            rstsr_assert!(a.device().same_device(y.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(y.size(), n, InvalidLayout)?;
+            if y.c_prefer() {
+                TensorMutable::Mut(y)
+            } else {
+                let y_buffer = y.to_contig_f(RowMajor)?.into_owned();
+                TensorMutable::ToBeCloned(y, y_buffer)
+            }
+        } else {
+            TensorMutable1::Owned(zeros_f(([n].c(), a.device()))?.into_dim())
+        };
+
+        // perform blas
+        let ptr_a = a.as_ptr();
+        let ptr_x = x.as_ptr();
+        let ptr_y = y.view_mut().as_mut_ptr();
+
+        unsafe {
+            B::driver_syhemv(order, uplo, n, alpha, ptr_a, lda, ptr_x, incx, beta, ptr_y, 1);
+        }
+
+        Ok(y.clone_to_mut())
+    }
+}
+
+pub type SYHEMV<'a, 'x, 'y, B, T, const HERMI: bool> = SYHEMV_Builder<'a, 'x, 'y, B, T, HERMI>;
+pub type SSYMV<'a, 'x, 'y, B> = SYHEMV<'a, 'x, 'y, B, f32, false>;
+pub type DSYMV<'a, 'x, 'y, B> = SYHEMV<'a, 'x, 'y, B, f64, false>;
+pub type CHEMV<'a, 'x, 'y, B> = SYHEMV<'a, 'x, 'y, B, Complex<f32>, true>;
+pub type ZHEMV<'a, 'x, 'y, B> = SYHEMV<'a, 'x, 'y, B, Complex<f64>, true>;
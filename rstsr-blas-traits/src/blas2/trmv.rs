@@ -0,0 +1,85 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait TRMVDriverAPI<T> {
+    unsafe fn driver_trmv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        diag: FlagDiag,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        x: *mut T,
+        incx: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct TRMV_<'a, 'x, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub a: TensorView<'a, T, B, Ix2>,
+    #[builder(setter(into))]
+    pub x: TensorReference<'x, T, B, Ix1>,
+
+    #[builder(setter(into), default = "None")]
+    pub uplo: Option<FlagUpLo>,
+    #[builder(setter(into), default = "NoTrans")]
+    pub trans: FlagTrans,
+    #[builder(setter(into), default = "NonUnit")]
+    pub diag: FlagDiag,
+}
+
+impl<'x, B, T> TRMV_<'_, 'x, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + TRMVDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable1<'x, T, B>> {
+        let Self { a, x, uplo, trans, diag } = self;
+
+        // device check
+        rstsr_assert!(a.device().same_device(x.device()), DeviceMismatch)?;
+        let uplo = uplo.unwrap_or_else(|| match a.device().default_order() {
+            RowMajor => Lower,
+            ColMajor => Upper,
+        });
+
+        // CBLAS accepts both row-major and col-major matrix
+        let order = match (a.f_prefer(), a.c_prefer()) {
+            (true, false) => ColMajor,
+            (false, true) => RowMajor,
+            _ => a.device().default_order(),
+        };
+        let a = a.to_contig_f(order)?;
+
+        // initialize intent(hide)
+        let n = a.view().nrow();
+        rstsr_assert_eq!(a.shape(), &[n, n], InvalidLayout)?;
+        let lda = a.view().ld(order).unwrap();
+
+        // perform check
+        rstsr_assert_eq!(x.size(), n, InvalidLayout)?;
+
+        // prepare output
+        let mut x = overwritable_convert(x)?;
+
+        // perform blas
+        let ptr_a = a.as_ptr();
+        let ptr_x = x.view_mut().as_mut_ptr();
+
+        unsafe { B::driver_trmv(order, uplo, trans, diag, n, ptr_a, lda, ptr_x, 1) };
+
+        Ok(x.clone_to_mut())
+    }
+}
+
+pub type TRMV<'a, 'x, B, T> = TRMV_Builder<'a, 'x, B, T>;
+pub type STRMV<'a, 'x, B> = TRMV<'a, 'x, B, f32>;
+pub type DTRMV<'a, 'x, B> = TRMV<'a, 'x, B, f64>;
+pub type CTRMV<'a, 'x, B> = TRMV<'a, 'x, B, Complex<f32>>;
+pub type ZTRMV<'a, 'x, B> = TRMV<'a, 'x, B, Complex<f64>>;
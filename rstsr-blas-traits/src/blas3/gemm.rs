@@ -40,6 +40,12 @@ where
     pub transa: FlagTrans,
     #[builder(setter(into), default = "NoTrans")]
     pub transb: FlagTrans,
+    /// Elementwise conjugate `a` before applying `transa`.
+    #[builder(setter(into), default = "false")]
+    pub conja: bool,
+    /// Elementwise conjugate `b` before applying `transb`.
+    #[builder(setter(into), default = "false")]
+    pub conjb: bool,
     #[builder(setter(into, strip_option), default = "None")]
     pub order: Option<FlagOrder>,
 }
@@ -50,7 +56,11 @@ where
     B: BlasDriverBaseAPI<T> + GEMMDriverAPI<T>,
 {
     pub fn run(self) -> Result<TensorMutable2<'c, T, B>> {
-        let Self { a, b, c, alpha, beta, transa, transb, order } = self;
+        let Self { a, b, c, alpha, beta, transa, transb, conja, conjb, order } = self;
+
+        // absorb conjugation into trans flags
+        let (transa, a) = resolve_conj_trans(transa, a, conja)?;
+        let (transb, b) = resolve_conj_trans(transb, b, conjb)?;
 
         // determine preferred layout
         let order_c = c.as_ref().map(|c| (c.c_prefer(), c.f_prefer()));
@@ -65,7 +75,18 @@ where
             // f-prefer: C = op(A) op(B)
             let (transa, a_cow) = flip_trans(order, transa, a, false)?;
             let (transb, b_cow) = flip_trans(order, transb, b, false)?;
-            let obj = GEMM_ { a: a_cow.view(), b: b_cow.view(), c, alpha, beta, transa, transb, order: Some(ColMajor) };
+            let obj = GEMM_ {
+                a: a_cow.view(),
+                b: b_cow.view(),
+                c,
+                alpha,
+                beta,
+                transa,
+                transb,
+                conja: false,
+                conjb: false,
+                order: Some(ColMajor),
+            };
             obj.internal_run()
         } else {
             // c-prefer: C' = op(B') op(A')
@@ -79,6 +100,8 @@ where
                 beta,
                 transa: transb,
                 transb: transa,
+                conja: false,
+                conjb: false,
                 order: Some(ColMajor),
             };
             Ok(obj.internal_run()?.into_reverse_axes())
@@ -86,10 +109,11 @@ where
     }
 
     pub fn internal_run(self) -> Result<TensorMutable2<'c, T, B>> {
-        let Self { a, b, c, alpha, beta, transa, transb, order } = self;
+        let Self { a, b, c, alpha, beta, transa, transb, conja, conjb, order } = self;
 
-        // this function only accepts column major
+        // this function only accepts column major, with conjugation absorbed
         rstsr_assert_eq!(order, Some(ColMajor), RuntimeError)?;
+        rstsr_assert!(!conja && !conjb, RuntimeError)?;
         rstsr_assert!(a.f_prefer(), RuntimeError)?;
         rstsr_assert!(b.f_prefer(), RuntimeError)?;
 
@@ -146,6 +170,45 @@ where
     }
 }
 
+/// Absorb elementwise conjugation of a matrix into its trans flag.
+///
+/// `conj(A)` is `(A^T)^H`, `conj(A)^T` is `A^H`, and `conj(A)^H` is `A^T`;
+/// none of these requires copying `A`.
+fn resolve_conj_trans<T, B>(
+    trans: FlagTrans,
+    view: TensorView<'_, T, B, Ix2>,
+    conj: bool,
+) -> Result<(FlagTrans, TensorView<'_, T, B, Ix2>)>
+where
+    B: DeviceAPI<T>,
+{
+    if !conj {
+        return Ok((trans, view));
+    }
+    match trans {
+        FlagTrans::N => Ok((FlagTrans::C, view.into_reverse_axes())),
+        FlagTrans::T => Ok((FlagTrans::C, view)),
+        FlagTrans::C => Ok((FlagTrans::T, view)),
+        _ => rstsr_invalid!(trans),
+    }
+}
+
+impl<'a, 'b, 'c, B, T> GEMM_Builder<'a, 'b, 'c, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    /// Set `a` from a lazily conjugated view, e.g. `c.h()` for `C^H`.
+    pub fn a_conj(self, a: ConjView<'a, T, B, Ix2>) -> Self {
+        self.a(a.into_inner()).conja(true)
+    }
+
+    /// Set `b` from a lazily conjugated view.
+    pub fn b_conj(self, b: ConjView<'b, T, B, Ix2>) -> Self {
+        self.b(b.into_inner()).conjb(true)
+    }
+}
+
 pub type GEMM<'a, 'b, 'c, B, T> = GEMM_Builder<'a, 'b, 'c, B, T>;
 pub type SGEMM<'a, 'b, 'c, B> = GEMM<'a, 'b, 'c, B, f32>;
 pub type DGEMM<'a, 'b, 'c, B> = GEMM<'a, 'b, 'c, B, f64>;
@@ -0,0 +1,168 @@
+use crate::prelude_dev::*;
+use num::Zero;
+use rstsr_core::prelude_dev::*;
+
+pub trait HER2KDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    unsafe fn driver_her2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T::Real,
+        c: *mut T,
+        ldc: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct HER2K_<'a, 'b, 'c, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub a: TensorView<'a, T, B, Ix2>,
+    pub b: TensorView<'b, T, B, Ix2>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<TensorViewMut<'c, T, B, Ix2>>,
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub beta: T::Real,
+    #[builder(setter(into), default = "None")]
+    pub uplo: Option<FlagUpLo>,
+    #[builder(setter(into), default = "NoTrans")]
+    pub trans: FlagTrans,
+    /// Whether to fill the other triangle, so that the output is a full hermitian matrix.
+    #[builder(setter(into), default = "false")]
+    pub symmetrize: bool,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub order: Option<FlagOrder>,
+}
+
+impl<'c, B, T> HER2K_<'_, '_, 'c, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + HER2KDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable2<'c, T, B>> {
+        let Self { a, b, c, alpha, beta, uplo, trans, symmetrize, order } = self;
+
+        let uplo = uplo.unwrap_or_else(|| match a.device().default_order() {
+            RowMajor => Lower,
+            ColMajor => Upper,
+        });
+
+        // determine preferred layout
+        let order_c = c.as_ref().map(|c| (c.c_prefer(), c.f_prefer()));
+        let order = order.map(|order| match order {
+            ColMajor => (true, false),
+            RowMajor => (false, true),
+        });
+
+        let default_order = a.device().default_order();
+        let order = get_output_order(&[order, order_c], &[], default_order);
+        if order == ColMajor {
+            // f-prefer: C = op(A) op(B)^H + op(B) op(A)^H (scaled by alpha and conj(alpha))
+            // both A and B are required to share the same trans flag
+            let (a_cow, b_cow) = (a.to_contig_f(ColMajor)?, b.to_contig_f(ColMajor)?);
+            let obj = HER2K_ {
+                a: a_cow.view(),
+                b: b_cow.view(),
+                c,
+                alpha,
+                beta,
+                uplo: Some(uplo),
+                trans,
+                symmetrize,
+                order: Some(ColMajor),
+            };
+            obj.internal_run()
+        } else {
+            // c-prefer: C' = C^* is hermitian, so compute C' with flipped trans and uplo
+            // A and B are swapped, so that alpha need not be conjugated
+            let (a_cow, b_cow) = (a.to_contig_f(RowMajor)?, b.to_contig_f(RowMajor)?);
+            let obj = HER2K_ {
+                a: b_cow.t(),
+                b: a_cow.t(),
+                c: c.map(|c| c.into_reverse_axes()),
+                alpha,
+                beta,
+                uplo: Some(uplo.flip()),
+                trans: trans.flip(true)?,
+                symmetrize,
+                order: Some(ColMajor),
+            };
+            Ok(obj.internal_run()?.into_reverse_axes())
+        }
+    }
+
+    pub fn internal_run(self) -> Result<TensorMutable2<'c, T, B>> {
+        let Self { a, b, c, alpha, beta, uplo, trans, symmetrize, order } = self;
+
+        // this function only accepts column major
+        rstsr_assert_eq!(order, Some(ColMajor), RuntimeError)?;
+        rstsr_assert!(a.f_prefer(), RuntimeError)?;
+        rstsr_assert!(b.f_prefer(), RuntimeError)?;
+        rstsr_assert!(uplo.is_some(), InvalidValue, "uplo in internal_run should not be None")?;
+        let uplo = uplo.unwrap();
+
+        // device check
+        rstsr_assert!(a.device().same_device(b.device()), DeviceMismatch)?;
+
+        // initialize intent(hide)
+        let (n, k) = match trans {
+            FlagTrans::N => (a.nrow(), a.ncol()),
+            FlagTrans::C => (a.ncol(), a.nrow()),
+            _ => rstsr_invalid!(trans)?,
+        };
+        let lda = a.ld_col().unwrap();
+        let ldb = b.ld_col().unwrap();
+
+        // perform check
+        rstsr_assert_eq!(a.shape(), b.shape(), InvalidLayout)?;
+
+        // optional intent(out)
+        let mut c = if let Some(c) = c {
+            rstsr_assert!(a.device().same_device(c.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(c.shape(), &[n, n], InvalidLayout)?;
+            if c.f_prefer() {
+                TensorMutable::Mut(c)
+            } else {
+                let c_buffer = c.to_contig_f(ColMajor)?.into_owned();
+                TensorMutable::ToBeCloned(c, c_buffer)
+            }
+        } else {
+            TensorMutable2::Owned(zeros_f(([n, n].f(), a.device()))?.into_dim())
+        };
+        let ldc = c.view().ld(ColMajor).unwrap();
+
+        // perform blas
+        let ptr_a = a.raw().as_ptr();
+        let ptr_b = b.raw().as_ptr();
+        let ptr_c = c.view_mut().raw_mut().as_mut_ptr();
+
+        unsafe {
+            B::driver_her2k(ColMajor, uplo, trans, n, k, alpha, ptr_a, lda, ptr_b, ldb, beta, ptr_c, ldc);
+            if symmetrize {
+                fill_other_triangle::<T, true>(ptr_c, n, ldc, uplo);
+            }
+        }
+
+        Ok(c.clone_to_mut())
+    }
+}
+
+pub type HER2K<'a, 'b, 'c, B, T> = HER2K_Builder<'a, 'b, 'c, B, T>;
+pub type CHER2K<'a, 'b, 'c, B> = HER2K<'a, 'b, 'c, B, Complex<f32>>;
+pub type ZHER2K<'a, 'b, 'c, B> = HER2K<'a, 'b, 'c, B, Complex<f64>>;
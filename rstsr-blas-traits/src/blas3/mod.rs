@@ -1,7 +1,17 @@
 pub mod gemm;
+pub mod her2k;
+pub mod herk;
 pub mod syhemm;
+pub mod syr2k;
+pub mod syrk;
+pub mod trmm;
 pub mod trsm;
 
 pub use gemm::*;
+pub use her2k::*;
+pub use herk::*;
 pub use syhemm::*;
+pub use syr2k::*;
+pub use syrk::*;
+pub use trmm::*;
 pub use trsm::*;
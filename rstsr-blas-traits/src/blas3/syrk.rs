@@ -0,0 +1,142 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait SYRKDriverAPI<T> {
+    unsafe fn driver_syrk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    );
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct SYRK_<'a, 'c, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    pub a: TensorView<'a, T, B, Ix2>,
+
+    #[builder(setter(into, strip_option), default = "None")]
+    pub c: Option<TensorViewMut<'c, T, B, Ix2>>,
+    #[builder(setter(into), default = "T::one()")]
+    pub alpha: T,
+    #[builder(setter(into), default = "T::zero()")]
+    pub beta: T,
+    #[builder(setter(into), default = "None")]
+    pub uplo: Option<FlagUpLo>,
+    #[builder(setter(into), default = "NoTrans")]
+    pub trans: FlagTrans,
+    /// Whether to fill the other triangle, so that the output is a full symmetric matrix.
+    #[builder(setter(into), default = "false")]
+    pub symmetrize: bool,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub order: Option<FlagOrder>,
+}
+
+impl<'c, B, T> SYRK_<'_, 'c, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + SYRKDriverAPI<T>,
+{
+    pub fn run(self) -> Result<TensorMutable2<'c, T, B>> {
+        let Self { a, c, alpha, beta, uplo, trans, symmetrize, order } = self;
+
+        let uplo = uplo.unwrap_or_else(|| match a.device().default_order() {
+            RowMajor => Lower,
+            ColMajor => Upper,
+        });
+
+        // determine preferred layout
+        let order_c = c.as_ref().map(|c| (c.c_prefer(), c.f_prefer()));
+        let order = order.map(|order| match order {
+            ColMajor => (true, false),
+            RowMajor => (false, true),
+        });
+
+        let default_order = a.device().default_order();
+        let order = get_output_order(&[order, order_c], &[], default_order);
+        if order == ColMajor {
+            // f-prefer: C = op(A) op(A)^T
+            let (trans, a_cow) = flip_trans(order, trans, a, false)?;
+            let obj =
+                SYRK_ { a: a_cow.view(), c, alpha, beta, uplo: Some(uplo), trans, symmetrize, order: Some(ColMajor) };
+            obj.internal_run()
+        } else {
+            // c-prefer: C' = C is symmetric, so compute C' with flipped trans and uplo
+            let (trans, a_cow) = flip_trans(order, trans, a, false)?;
+            let obj = SYRK_ {
+                a: a_cow.t(),
+                c: c.map(|c| c.into_reverse_axes()),
+                alpha,
+                beta,
+                uplo: Some(uplo.flip()),
+                trans: trans.flip(false)?,
+                symmetrize,
+                order: Some(ColMajor),
+            };
+            Ok(obj.internal_run()?.into_reverse_axes())
+        }
+    }
+
+    pub fn internal_run(self) -> Result<TensorMutable2<'c, T, B>> {
+        let Self { a, c, alpha, beta, uplo, trans, symmetrize, order } = self;
+
+        // this function only accepts column major
+        rstsr_assert_eq!(order, Some(ColMajor), RuntimeError)?;
+        rstsr_assert!(a.f_prefer(), RuntimeError)?;
+        rstsr_assert!(uplo.is_some(), InvalidValue, "uplo in internal_run should not be None")?;
+        let uplo = uplo.unwrap();
+
+        // initialize intent(hide)
+        let (n, k) = match trans {
+            FlagTrans::N => (a.nrow(), a.ncol()),
+            FlagTrans::T => (a.ncol(), a.nrow()),
+            _ => rstsr_invalid!(trans)?,
+        };
+        let lda = a.ld_col().unwrap();
+
+        // optional intent(out)
+        let mut c = if let Some(c) = c {
+            rstsr_assert!(a.device().same_device(c.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(c.shape(), &[n, n], InvalidLayout)?;
+            if c.f_prefer() {
+                TensorMutable::Mut(c)
+            } else {
+                let c_buffer = c.to_contig_f(ColMajor)?.into_owned();
+                TensorMutable::ToBeCloned(c, c_buffer)
+            }
+        } else {
+            TensorMutable2::Owned(zeros_f(([n, n].f(), a.device()))?.into_dim())
+        };
+        let ldc = c.view().ld(ColMajor).unwrap();
+
+        // perform blas
+        let ptr_a = a.raw().as_ptr();
+        let ptr_c = c.view_mut().raw_mut().as_mut_ptr();
+
+        unsafe {
+            B::driver_syrk(ColMajor, uplo, trans, n, k, alpha, ptr_a, lda, beta, ptr_c, ldc);
+            if symmetrize {
+                fill_other_triangle::<T, false>(ptr_c, n, ldc, uplo);
+            }
+        }
+
+        Ok(c.clone_to_mut())
+    }
+}
+
+pub type SYRK<'a, 'c, B, T> = SYRK_Builder<'a, 'c, B, T>;
+pub type SSYRK<'a, 'c, B> = SYRK<'a, 'c, B, f32>;
+pub type DSYRK<'a, 'c, B> = SYRK<'a, 'c, B, f64>;
+pub type CSYRK<'a, 'c, B> = SYRK<'a, 'c, B, Complex<f32>>;
+pub type ZSYRK<'a, 'c, B> = SYRK<'a, 'c, B, Complex<f64>>;
@@ -1,9 +1,10 @@
-use crate::prelude_dev::*;
+use crate::device_blas::prelude_dev::*;
 
 macro_rules! impl_change_device {
     ($DevA: ty, $DevB: ty) => {
-        impl<'a, R, T, D> DeviceChangeAPI<'a, $DevB, R, T, D> for $DevA
+        impl<'a, B, R, T, D> DeviceChangeAPI<'a, $DevB, R, T, D> for $DevA
         where
+            B: CblasBackendAPI,
             T: Clone + Send + Sync + 'a,
             D: DimAPI,
             R: DataCloneAPI<Data = Vec<T>>,
@@ -38,42 +39,10 @@ macro_rules! impl_change_device {
     };
 }
 
-impl_change_device!(DeviceCpuSerial, DeviceBLAS);
-impl_change_device!(DeviceBLAS, DeviceCpuSerial);
-impl_change_device!(DeviceBLAS, DeviceBLAS);
+impl_change_device!(DeviceCpuSerial, DeviceBLAS<B>);
+impl_change_device!(DeviceBLAS<B>, DeviceCpuSerial);
+impl_change_device!(DeviceBLAS<B>, DeviceBLAS<B>);
 #[cfg(feature = "faer")]
-impl_change_device!(DeviceFaer, DeviceBLAS);
+impl_change_device!(DeviceFaer, DeviceBLAS<B>);
 #[cfg(feature = "faer")]
-impl_change_device!(DeviceBLAS, DeviceFaer);
-
-#[cfg(test)]
-mod test {
-    use super::*;
-
-    #[test]
-    fn test_device_conversion_cpu_serial() {
-        let device_serial = DeviceCpuSerial::default();
-        let device = DeviceBLAS::new(0);
-        let a = linspace((1.0, 5.0, 5, &device));
-        let b = a.to_device(&device_serial);
-        println!("{b:?}");
-        let a = linspace((1.0, 5.0, 5, &device_serial));
-        let a_view = a.view();
-        let b = a_view.to_device(&device);
-        println!("{b:?}");
-    }
-
-    #[test]
-    #[cfg(feature = "faer")]
-    fn test_device_conversion_faer() {
-        let device_faer = DeviceFaer::new(0);
-        let device = DeviceBLAS::new(0);
-        let a = linspace((1.0, 5.0, 5, &device));
-        let b = a.to_device(&device_faer);
-        println!("{b:?}");
-        let a = linspace((1.0, 5.0, 5, &device_faer));
-        let a_view = a.view();
-        let b = a_view.to_device(&device);
-        println!("{b:?}");
-    }
-}
+impl_change_device!(DeviceBLAS<B>, DeviceFaer);
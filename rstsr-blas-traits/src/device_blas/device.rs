@@ -1,14 +1,20 @@
-use crate::prelude_dev::*;
+use crate::device_blas::prelude_dev::*;
 use num::{complex::ComplexFloat, Num};
 use rstsr_dtype_traits::DTypeIntoFloatAPI;
 
-impl DeviceBLAS {
+impl<B> DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     pub fn new(num_threads: usize) -> Self {
-        DeviceBLAS { base: DeviceCpuRayon::new(num_threads) }
+        DeviceBLAS { base: DeviceCpuRayon::new(num_threads), backend: PhantomData }
     }
 }
 
-impl DeviceRayonAPI for DeviceBLAS {
+impl<B> DeviceRayonAPI for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     #[inline]
     fn set_num_threads(&mut self, num_threads: usize) {
         self.base.set_num_threads(num_threads);
@@ -30,13 +36,19 @@ impl DeviceRayonAPI for DeviceBLAS {
     }
 }
 
-impl Default for DeviceBLAS {
+impl<B> Default for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     fn default() -> Self {
         DeviceBLAS::new(0)
     }
 }
 
-impl DeviceBaseAPI for DeviceBLAS {
+impl<B> DeviceBaseAPI for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     fn same_device(&self, other: &Self) -> bool {
         let same_num_threads = self.get_num_threads() == other.get_num_threads();
         let same_default_order = self.default_order() == other.default_order();
@@ -52,11 +64,17 @@ impl DeviceBaseAPI for DeviceBLAS {
     }
 }
 
-impl<T> DeviceRawAPI<T> for DeviceBLAS {
+impl<B, T> DeviceRawAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     type Raw = Vec<T>;
 }
 
-impl<T> DeviceStorageAPI<T> for DeviceBLAS {
+impl<B, T> DeviceStorageAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
     fn len<R>(storage: &Storage<R, T, Self>) -> usize
     where
         R: DataAPI<Data = Self::Raw>,
@@ -115,18 +133,20 @@ impl<T> DeviceStorageAPI<T> for DeviceBLAS {
     }
 }
 
-impl<T> DeviceAPI<T> for DeviceBLAS {}
+impl<B, T> DeviceAPI<T> for DeviceBLAS<B> where B: CblasBackendAPI {}
 
-impl<T, D> DeviceComplexFloatAPI<T, D> for DeviceBLAS
+impl<B, T, D> DeviceComplexFloatAPI<T, D> for DeviceBLAS<B>
 where
+    B: CblasBackendAPI,
     T: ComplexFloat + DTypeIntoFloatAPI<FloatType = T> + Send + Sync,
     T::Real: DTypeIntoFloatAPI<FloatType = T::Real> + Send + Sync,
     D: DimAPI,
 {
 }
 
-impl<T, D> DeviceNumAPI<T, D> for DeviceBLAS
+impl<B, T, D> DeviceNumAPI<T, D> for DeviceBLAS<B>
 where
+    B: CblasBackendAPI,
     T: Clone + Num + Send + Sync,
     D: DimAPI,
 {
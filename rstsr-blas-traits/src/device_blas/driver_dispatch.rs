@@ -0,0 +1,869 @@
+//! Delegation of driver traits of [`DeviceBLAS`] to its backend.
+//!
+//! Driver traits are implemented for [`DeviceBLAS<B>`] whenever backend `B`
+//! implements them.
+
+use crate::blas1::*;
+use crate::blas2::*;
+use crate::blas3::*;
+use crate::device_blas::prelude_dev::*;
+use crate::lapack_eigh::*;
+use crate::lapack_schur::*;
+use crate::lapack_solve::*;
+use crate::lapack_svd::*;
+use crate::prelude_dev::*;
+
+impl<B, T> AXPYDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + AXPYDriverAPI<T>,
+{
+    unsafe fn driver_axpy(n: usize, alpha: T, x: *const T, incx: usize, y: *mut T, incy: usize) {
+        <B as AXPYDriverAPI<T>>::driver_axpy(n, alpha, x, incx, y, incy)
+    }
+}
+
+impl<B, T, const CONJ: bool> DOTDriverAPI<T, CONJ> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + DOTDriverAPI<T, CONJ>,
+{
+    unsafe fn driver_dot(n: usize, x: *const T, incx: usize, y: *const T, incy: usize) -> T {
+        <B as DOTDriverAPI<T, CONJ>>::driver_dot(n, x, incx, y, incy)
+    }
+}
+
+impl<B, T> NRM2DriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + NRM2DriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_nrm2(n: usize, x: *const T, incx: usize) -> T::Real {
+        <B as NRM2DriverAPI<T>>::driver_nrm2(n, x, incx)
+    }
+}
+
+impl<B, T> SCALDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SCALDriverAPI<T>,
+{
+    unsafe fn driver_scal(n: usize, alpha: T, x: *mut T, incx: usize) {
+        <B as SCALDriverAPI<T>>::driver_scal(n, alpha, x, incx)
+    }
+}
+
+impl<B, T> IAMAXDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + IAMAXDriverAPI<T>,
+{
+    unsafe fn driver_iamax(n: usize, x: *const T, incx: usize) -> usize {
+        <B as IAMAXDriverAPI<T>>::driver_iamax(n, x, incx)
+    }
+}
+
+impl<B, T> GEMVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEMVDriverAPI<T>,
+{
+    unsafe fn driver_gemv(
+        order: FlagOrder,
+        trans: FlagTrans,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        <B as GEMVDriverAPI<T>>::driver_gemv(order, trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+}
+
+impl<B, T, const HERMI: bool> SYHEMVDriverAPI<T, HERMI> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYHEMVDriverAPI<T, HERMI>,
+{
+    unsafe fn driver_syhemv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        x: *const T,
+        incx: usize,
+        beta: T,
+        y: *mut T,
+        incy: usize,
+    ) {
+        <B as SYHEMVDriverAPI<T, HERMI>>::driver_syhemv(order, uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+    }
+}
+
+impl<B, T> TRMVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRMVDriverAPI<T>,
+{
+    unsafe fn driver_trmv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        diag: FlagDiag,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        x: *mut T,
+        incx: usize,
+    ) {
+        <B as TRMVDriverAPI<T>>::driver_trmv(order, uplo, trans, diag, n, a, lda, x, incx)
+    }
+}
+
+impl<B, T, const CONJ: bool> GERDriverAPI<T, CONJ> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GERDriverAPI<T, CONJ>,
+{
+    unsafe fn driver_ger(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        alpha: T,
+        x: *const T,
+        incx: usize,
+        y: *const T,
+        incy: usize,
+        a: *mut T,
+        lda: usize,
+    ) {
+        <B as GERDriverAPI<T, CONJ>>::driver_ger(order, m, n, alpha, x, incx, y, incy, a, lda)
+    }
+}
+
+impl<B, T> GEMMDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEMMDriverAPI<T>,
+{
+    unsafe fn driver_gemm(
+        order: FlagOrder,
+        transa: FlagTrans,
+        transb: FlagTrans,
+        m: usize,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as GEMMDriverAPI<T>>::driver_gemm(order, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+impl<B, T, const HERMI: bool> SYHEMMDriverAPI<T, HERMI> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYHEMMDriverAPI<T, HERMI>,
+{
+    unsafe fn driver_syhemm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as SYHEMMDriverAPI<T, HERMI>>::driver_syhemm(order, side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+impl<B, T> TRSMDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRSMDriverAPI<T>,
+{
+    unsafe fn driver_trsm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        <B as TRSMDriverAPI<T>>::driver_trsm(order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+}
+
+impl<B, T> SYRKDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYRKDriverAPI<T>,
+{
+    unsafe fn driver_syrk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as SYRKDriverAPI<T>>::driver_syrk(order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+    }
+}
+
+impl<B, T> HERKDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + HERKDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_herk(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T::Real,
+        a: *const T,
+        lda: usize,
+        beta: T::Real,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as HERKDriverAPI<T>>::driver_herk(order, uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+    }
+}
+
+impl<B, T> SYR2KDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYR2KDriverAPI<T>,
+{
+    unsafe fn driver_syr2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as SYR2KDriverAPI<T>>::driver_syr2k(order, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+impl<B, T> HER2KDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + HER2KDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_her2k(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        trans: FlagTrans,
+        n: usize,
+        k: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        beta: T::Real,
+        c: *mut T,
+        ldc: usize,
+    ) {
+        <B as HER2KDriverAPI<T>>::driver_her2k(order, uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+    }
+}
+
+impl<B, T> TRMMDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRMMDriverAPI<T>,
+{
+    unsafe fn driver_trmm(
+        order: FlagOrder,
+        side: FlagSide,
+        uplo: FlagUpLo,
+        transa: FlagTrans,
+        diag: FlagDiag,
+        m: usize,
+        n: usize,
+        alpha: T,
+        a: *const T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+    ) {
+        <B as TRMMDriverAPI<T>>::driver_trmm(order, side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+    }
+}
+
+impl<B, T> SYEVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYEVDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_syev(
+        order: FlagOrder,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        <B as SYEVDriverAPI<T>>::driver_syev(order, jobz, uplo, n, a, lda, w)
+    }
+}
+
+impl<B, T> SYEVDDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYEVDDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_syevd(
+        order: FlagOrder,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        <B as SYEVDDriverAPI<T>>::driver_syevd(order, jobz, uplo, n, a, lda, w)
+    }
+}
+
+impl<B, T> SYEVRDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYEVRDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_syevr(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        isuppz: *mut blas_int,
+    ) -> blas_int {
+        <B as SYEVRDriverAPI<T>>::driver_syevr(
+            order, jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, isuppz,
+        )
+    }
+}
+
+impl<B, T> SYEVXDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYEVXDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_syevx(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        ifail: *mut blas_int,
+    ) -> blas_int {
+        <B as SYEVXDriverAPI<T>>::driver_syevx(
+            order, jobz, range, uplo, n, a, lda, vl, vu, il, iu, abstol, m, w, z, ldz, ifail,
+        )
+    }
+}
+
+impl<B, T> SYGVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYGVDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_sygv(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        <B as SYGVDriverAPI<T>>::driver_sygv(order, itype, jobz, uplo, n, a, lda, b, ldb, w)
+    }
+}
+
+impl<B, T> SYGVDDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYGVDDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_sygvd(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        w: *mut T::Real,
+    ) -> blas_int {
+        <B as SYGVDDriverAPI<T>>::driver_sygvd(order, itype, jobz, uplo, n, a, lda, b, ldb, w)
+    }
+}
+
+impl<B, T> SYGVXDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYGVXDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_sygvx(
+        order: FlagOrder,
+        itype: blas_int,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        ifail: *mut blas_int,
+    ) -> blas_int {
+        <B as SYGVXDriverAPI<T>>::driver_sygvx(
+            order, itype, jobz, range, uplo, n, a, lda, b, ldb, vl, vu, il, iu, abstol, m, w, z, ldz, ifail,
+        )
+    }
+}
+
+impl<B, T> POTRFDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + POTRFDriverAPI<T>,
+{
+    unsafe fn driver_potrf(order: FlagOrder, uplo: FlagUpLo, n: usize, a: *mut T, lda: usize) -> blas_int {
+        <B as POTRFDriverAPI<T>>::driver_potrf(order, uplo, n, a, lda)
+    }
+}
+
+impl<B, T> GESVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GESVDriverAPI<T>,
+{
+    unsafe fn driver_gesv(
+        order: FlagOrder,
+        n: usize,
+        nrhs: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+        b: *mut T,
+        ldb: usize,
+    ) -> blas_int {
+        <B as GESVDriverAPI<T>>::driver_gesv(order, n, nrhs, a, lda, ipiv, b, ldb)
+    }
+}
+
+impl<B, T> GETRFDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GETRFDriverAPI<T>,
+{
+    unsafe fn driver_getrf(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+    ) -> blas_int {
+        <B as GETRFDriverAPI<T>>::driver_getrf(order, m, n, a, lda, ipiv)
+    }
+}
+
+impl<B, T> GETRIDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GETRIDriverAPI<T>,
+{
+    unsafe fn driver_getri(order: FlagOrder, n: usize, a: *mut T, lda: usize, ipiv: *mut blas_int) -> blas_int {
+        <B as GETRIDriverAPI<T>>::driver_getri(order, n, a, lda, ipiv)
+    }
+}
+
+impl<B, T> GEQRFDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEQRFDriverAPI<T>,
+{
+    unsafe fn driver_geqrf(order: FlagOrder, m: usize, n: usize, a: *mut T, lda: usize, tau: *mut T) -> blas_int {
+        <B as GEQRFDriverAPI<T>>::driver_geqrf(order, m, n, a, lda, tau)
+    }
+}
+
+impl<B, T> ORGQRDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + ORGQRDriverAPI<T>,
+{
+    unsafe fn driver_orgqr(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        k: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        <B as ORGQRDriverAPI<T>>::driver_orgqr(order, m, n, k, a, lda, tau)
+    }
+}
+
+impl<B, T, const HERMI: bool> SYSVDriverAPI<T, HERMI> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYSVDriverAPI<T, HERMI>,
+{
+    unsafe fn driver_sysv(
+        order: FlagOrder,
+        uplo: FlagUpLo,
+        n: usize,
+        nrhs: usize,
+        a: *mut T,
+        lda: usize,
+        ipiv: *mut blas_int,
+        b: *mut T,
+        ldb: usize,
+    ) -> blas_int {
+        <B as SYSVDriverAPI<T, HERMI>>::driver_sysv(order, uplo, n, nrhs, a, lda, ipiv, b, ldb)
+    }
+}
+
+impl<B, T> GESVDDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GESVDDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gesvd(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut T::Real,
+    ) -> blas_int {
+        <B as GESVDDriverAPI<T>>::driver_gesvd(order, jobu, jobvt, m, n, a, lda, s, u, ldu, vt, ldvt, superb)
+    }
+}
+
+impl<B, T> GESDDDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GESDDDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gesdd(
+        order: FlagOrder,
+        jobz: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+    ) -> blas_int {
+        <B as GESDDDriverAPI<T>>::driver_gesdd(order, jobz, m, n, a, lda, s, u, ldu, vt, ldvt)
+    }
+}
+
+impl<B, T> GESVDXDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GESVDXDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int {
+        <B as GESVDXDriverAPI<T>>::driver_gesvdx(
+            order, jobu, jobvt, range, m, n, a, lda, vl, vu, il, iu, ns, s, u, ldu, vt, ldvt, superb,
+        )
+    }
+}
+
+impl<B, T> GEJSVDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEJSVDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut T::Real,
+        istat: *mut blas_int,
+    ) -> blas_int {
+        <B as GEJSVDriverAPI<T>>::driver_gejsv(
+            order, joba, jobu, jobv, jobr, jobt, jobp, m, n, a, lda, sva, u, ldu, v, ldv, stat, istat,
+        )
+    }
+}
+
+impl<B, T> GEESDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEESDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int {
+        <B as GEESDriverAPI<T>>::driver_gees(order, jobvs, n, a, lda, wr, wi, vs, ldvs)
+    }
+}
+
+impl<B, T> GEHRDDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GEHRDDriverAPI<T>,
+{
+    unsafe fn driver_gehrd(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *mut T,
+    ) -> blas_int {
+        <B as GEHRDDriverAPI<T>>::driver_gehrd(order, n, ilo, ihi, a, lda, tau)
+    }
+}
+
+impl<B, T> ORGHRDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + ORGHRDriverAPI<T>,
+{
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int {
+        <B as ORGHRDriverAPI<T>>::driver_orghr(order, n, ilo, ihi, a, lda, tau)
+    }
+}
+
+impl<B, T> GGESDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + GGESDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut T::Real,
+        alphai: *mut T::Real,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int {
+        <B as GGESDriverAPI<T>>::driver_gges(
+            order, jobvsl, jobvsr, n, a, lda, b, ldb, alphar, alphai, beta, vsl, ldvsl, vsr, ldvsr,
+        )
+    }
+}
+
+impl<B, T> TRSENDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRSENDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        m: &mut usize,
+    ) -> blas_int {
+        <B as TRSENDriverAPI<T>>::driver_trsen(order, compq, select, n, t, ldt, q, ldq, wr, wi, m)
+    }
+}
+
+impl<B, T> TRSYLDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRSYLDriverAPI<T>,
+    T: BlasFloat,
+{
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut T::Real,
+    ) -> blas_int {
+        <B as TRSYLDriverAPI<T>>::driver_trsyl(order, trana, tranb, isgn, m, n, a, lda, b, ldb, c, ldc, scale)
+    }
+}
+
+impl<B, T> BlasDriverBaseAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+    T: BlasFloat<Real: BlasFloat>,
+{
+}
+
+impl<B, T> BlasDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRSMDriverAPI<T> + SYHEMMDriverAPI<T, false> + SYHEMMDriverAPI<T, true>,
+    B: GEMMDriverAPI<T>,
+    B: AXPYDriverAPI<T> + DOTDriverAPI<T, false> + DOTDriverAPI<T, true> + NRM2DriverAPI<T>,
+    B: SCALDriverAPI<T> + IAMAXDriverAPI<T>,
+    B: GEMVDriverAPI<T> + SYHEMVDriverAPI<T, true> + TRMVDriverAPI<T>,
+    B: GERDriverAPI<T, false> + GERDriverAPI<T, true>,
+    B: SYRKDriverAPI<T> + HERKDriverAPI<T> + SYR2KDriverAPI<T> + HER2KDriverAPI<T> + TRMMDriverAPI<T>,
+    T: BlasFloat<Real: BlasFloat>,
+{
+}
+
+impl<B, T> LapackDriverAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + TRSMDriverAPI<T> + SYHEMMDriverAPI<T, false> + SYHEMMDriverAPI<T, true>,
+    B: GEMMDriverAPI<T>,
+    B: AXPYDriverAPI<T> + DOTDriverAPI<T, false> + DOTDriverAPI<T, true> + NRM2DriverAPI<T>,
+    B: SCALDriverAPI<T> + IAMAXDriverAPI<T>,
+    B: GEMVDriverAPI<T> + SYHEMVDriverAPI<T, true> + TRMVDriverAPI<T>,
+    B: GERDriverAPI<T, false> + GERDriverAPI<T, true>,
+    B: SYRKDriverAPI<T> + HERKDriverAPI<T> + SYR2KDriverAPI<T> + HER2KDriverAPI<T> + TRMMDriverAPI<T>,
+    B: SYEVDriverAPI<T> + SYEVDDriverAPI<T> + SYGVDriverAPI<T> + SYGVDDriverAPI<T>,
+    B: SYEVRDriverAPI<T> + SYEVXDriverAPI<T> + SYGVXDriverAPI<T>,
+    B: POTRFDriverAPI<T> + GESVDriverAPI<T> + GETRFDriverAPI<T> + GETRIDriverAPI<T>,
+    B: SYSVDriverAPI<T, false> + SYSVDriverAPI<T, true>,
+    B: GEQRFDriverAPI<T> + ORGQRDriverAPI<T>,
+    B: GESVDDriverAPI<T> + GESDDDriverAPI<T> + GESVDXDriverAPI<T> + GEJSVDriverAPI<T>,
+    B: GEESDriverAPI<T> + GEHRDDriverAPI<T> + ORGHRDriverAPI<T> + GGESDriverAPI<T>,
+    B: TRSENDriverAPI<T> + TRSYLDriverAPI<T>,
+    T: BlasFloat<Real: BlasFloat>,
+{
+}
@@ -0,0 +1,574 @@
+use crate::blas3::{HERKDriverAPI, SYRKDriverAPI, HERK, SYRK};
+use crate::blas_scalar::BlasFloat;
+use crate::device_blas::matmul_impl::*;
+use crate::device_blas::prelude_dev::*;
+use crate::threading::blas_num_threads_budget;
+use core::any::TypeId;
+use core::ops::{Add, Mul};
+use core::slice::{from_raw_parts, from_raw_parts_mut};
+use half::{bf16, f16};
+use num::complex::ComplexFloat;
+use num::{Complex, Zero};
+use rayon::prelude::*;
+
+// code from ndarray
+fn same_type<A: 'static, B: 'static>() -> bool {
+    TypeId::of::<A>() == TypeId::of::<B>()
+}
+
+fn is_half<T: 'static>() -> bool {
+    same_type::<T, f16>() || same_type::<T, bf16>()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_blas_ix2_no_conj_dispatch<B, TA, TB, TC>(
+    c: &mut [TC],
+    lc: &Layout<Ix2>,
+    a: &[TA],
+    la: &Layout<Ix2>,
+    b: &[TB],
+    lb: &Layout<Ix2>,
+    alpha: TC,
+    beta: TC,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    // check if syrk could be applicable
+    let able_syrk = beta == TC::zero()
+        && same_type::<TB, TC>()
+        && same_type::<TA, TC>()
+        && unsafe {
+            let a_ptr = a.as_ptr().add(la.offset()) as *const TC;
+            let b_ptr = b.as_ptr().add(lb.offset()) as *const TC;
+            let equal_ptr = core::ptr::eq(a_ptr, b_ptr);
+            let equal_shape = la.shape() == lb.reverse_axes().shape();
+            let equal_stride = la.stride() == lb.reverse_axes().stride();
+            equal_ptr && equal_shape && equal_stride
+        };
+
+    // type check and dispatch
+    macro_rules! impl_gemm_dispatch {
+        ($ty: ty, $fn_gemm_name: ident, $fn_syrk_name: ident) => {
+            if (same_type::<TA, $ty>() && same_type::<TB, $ty>() && same_type::<TC, $ty>()) {
+                let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                let alpha = unsafe { *(&alpha as *const TC as *const $ty) };
+                let beta = unsafe { *(&beta as *const TC as *const $ty) };
+                if able_syrk {
+                    $fn_syrk_name::<B>(c_slice, lc, a_slice, la, alpha, pool)?;
+                } else {
+                    $fn_gemm_name::<B>(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool)?;
+                }
+                return Ok(());
+            }
+        };
+    }
+
+    impl_gemm_dispatch!(f32, gemm_blas_no_conj_f32, syrk_blas_no_conj_f32);
+    impl_gemm_dispatch!(f64, gemm_blas_no_conj_f64, syrk_blas_no_conj_f64);
+    impl_gemm_dispatch!(Complex<f32>, gemm_blas_no_conj_c32, syrk_blas_no_conj_c32);
+    impl_gemm_dispatch!(Complex<f64>, gemm_blas_no_conj_c64, syrk_blas_no_conj_c64);
+
+    // half-precision types are packed to f32, and accumulated in f32 by sgemm
+    macro_rules! impl_gemm_half_dispatch {
+        ($ty: ty) => {
+            if (same_type::<TA, $ty>() && same_type::<TB, $ty>() && same_type::<TC, $ty>()) {
+                let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                let alpha = unsafe { *(&alpha as *const TC as *const $ty) };
+                let beta = unsafe { *(&beta as *const TC as *const $ty) };
+                let f_gemm =
+                    |c: &mut [f32],
+                     lc: &Layout<Ix2>,
+                     a: &[f32],
+                     la: &Layout<Ix2>,
+                     b: &[f32],
+                     lb: &Layout<Ix2>,
+                     alpha,
+                     beta| { gemm_blas_no_conj_f32::<B>(c, lc, a, la, b, lb, alpha, beta, pool) };
+                return gemm_ix2_promoted_cpu_rayon(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool, f_gemm);
+            }
+        };
+    }
+
+    impl_gemm_half_dispatch!(f16);
+    impl_gemm_half_dispatch!(bf16);
+
+    // not able to be accelarated by blas_no_conj
+    // fallback to generic cache-blocked implementation
+    let c_slice = c;
+    let a_slice = a;
+    let b_slice = b;
+    return gemm_blocked_cpu_rayon(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gemv_blas_ix1_no_conj_dispatch<B, TA, TB, TC>(
+    c: &mut [TC],
+    lc: &Layout<Ix1>,
+    a: &[TA],
+    la: &Layout<Ix2>,
+    b: &[TB],
+    lb: &Layout<Ix1>,
+    alpha: TC,
+    beta: TC,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    // type check and dispatch
+    macro_rules! impl_gemv_dispatch {
+        ($ty: ty, $fn_gemv_name: ident) => {
+            if (same_type::<TA, $ty>() && same_type::<TB, $ty>() && same_type::<TC, $ty>()) {
+                let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                let alpha = unsafe { *(&alpha as *const TC as *const $ty) };
+                let beta = unsafe { *(&beta as *const TC as *const $ty) };
+                $fn_gemv_name::<B>(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool)?;
+                return Ok(());
+            }
+        };
+    }
+
+    impl_gemv_dispatch!(f32, gemv_blas_no_conj_f32);
+    impl_gemv_dispatch!(f64, gemv_blas_no_conj_f64);
+    impl_gemv_dispatch!(Complex<f32>, gemv_blas_no_conj_c32);
+    impl_gemv_dispatch!(Complex<f64>, gemv_blas_no_conj_c64);
+
+    // not able to be accelarated by gemv
+    // fallback to gemm dispatch, with vectors regarded as column matrices
+    let lb = unsafe { Layout::new_unchecked([lb.shape()[0], 1], [lb.stride()[0], 1], lb.offset()) };
+    let lc = unsafe { Layout::new_unchecked([lc.shape()[0], 1], [lc.stride()[0], 1], lc.offset()) };
+    return gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, &lc, a, la, b, &lb, alpha, beta, pool);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gevm_blas_ix1_no_conj_dispatch<B, TA, TB, TC>(
+    c: &mut [TC],
+    lc: &Layout<Ix1>,
+    a: &[TA],
+    la: &Layout<Ix1>,
+    b: &[TB],
+    lb: &Layout<Ix2>,
+    alpha: TC,
+    beta: TC,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    // type check and dispatch
+    // c = a B  =>  c = B^T a
+    macro_rules! impl_gevm_dispatch {
+        ($ty: ty, $fn_gemv_name: ident) => {
+            if (same_type::<TA, $ty>() && same_type::<TB, $ty>() && same_type::<TC, $ty>()) {
+                let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                let alpha = unsafe { *(&alpha as *const TC as *const $ty) };
+                let beta = unsafe { *(&beta as *const TC as *const $ty) };
+                $fn_gemv_name::<B>(c_slice, lc, b_slice, &lb.reverse_axes(), a_slice, la, alpha, beta, pool)?;
+                return Ok(());
+            }
+        };
+    }
+
+    impl_gevm_dispatch!(f32, gemv_blas_no_conj_f32);
+    impl_gevm_dispatch!(f64, gemv_blas_no_conj_f64);
+    impl_gevm_dispatch!(Complex<f32>, gemv_blas_no_conj_c32);
+    impl_gevm_dispatch!(Complex<f64>, gemv_blas_no_conj_c64);
+
+    // not able to be accelarated by gemv
+    // fallback to gemm dispatch, with vectors regarded as row matrices
+    let la = unsafe { Layout::new_unchecked([1, la.shape()[0]], [1, la.stride()[0]], la.offset()) };
+    let lc = unsafe { Layout::new_unchecked([1, lc.shape()[0]], [1, lc.stride()[0]], lc.offset()) };
+    return gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, &lc, a, &la, b, lb, alpha, beta, pool);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn matmul_row_major_blas<B, TA, TB, TC, DA, DB, DC>(
+    c: &mut [TC],
+    lc: &Layout<DC>,
+    a: &[TA],
+    la: &Layout<DA>,
+    b: &[TB],
+    lb: &Layout<DB>,
+    alpha: TC,
+    beta: TC,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    // NOTE: this only works for row-major layout
+    // for column-major layout, we need to transpose the input:
+    // C = A * B  =>  C^T = B^T * A^T
+
+    // quick return for empty matrix
+    // in this case, we do not check the shape of a, b, c
+    if lc.size() == 0 {
+        return Ok(());
+    }
+
+    let nthreads = blas_num_threads_budget(pool);
+
+    // handle special cases
+    match (la.ndim(), lb.ndim(), lc.ndim()) {
+        (1, 1, 0) if is_half::<TC>() => {
+            // rule 1 of half-precision: regarded as (1, k) x (k, 1) matrix multiplication, to accumulate in f32
+            let la = &la.clone().into_dim::<Ix1>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix1>().unwrap();
+            let la = &unsafe { Layout::new_unchecked([1, la.shape()[0]], [1, la.stride()[0]], la.offset()) };
+            let lb = &unsafe { Layout::new_unchecked([lb.shape()[0], 1], [lb.stride()[0], 1], lb.offset()) };
+            let lc = &unsafe { Layout::new_unchecked([1, 1], [1, 1], lc.offset()) };
+            return B::with_num_threads(nthreads, || {
+                gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+            });
+        },
+        (1, 1, 0) => {
+            // rule 1: vector inner dot
+            let la = &la.clone().into_dim::<Ix1>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix1>().unwrap();
+            let lc = &lc.clone().into_dim::<Ix0>().unwrap();
+            let c_num = &mut c[lc.offset()];
+            return B::with_num_threads(nthreads, || inner_dot_naive_cpu_rayon(c_num, a, la, b, lb, alpha, beta, pool));
+        },
+        (2, 1, 1) => {
+            // rule 4 without broadcast: matrix-vector multiplication
+            let la = &la.clone().into_dim::<Ix2>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix1>().unwrap();
+            let lc = &lc.clone().into_dim::<Ix1>().unwrap();
+            return B::with_num_threads(nthreads, || {
+                gemv_blas_ix1_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+            });
+        },
+        (1, 2, 1) => {
+            // rule 3 without broadcast: vector-matrix multiplication
+            let la = &la.clone().into_dim::<Ix1>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix2>().unwrap();
+            let lc = &lc.clone().into_dim::<Ix1>().unwrap();
+            return B::with_num_threads(nthreads, || {
+                gevm_blas_ix1_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+            });
+        },
+        (2, 2, 2) => {
+            // rule 2: matrix multiplication
+            let la = &la.clone().into_dim::<Ix2>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix2>().unwrap();
+            let lc = &lc.clone().into_dim::<Ix2>().unwrap();
+            return B::with_num_threads(nthreads, || {
+                gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+            });
+        },
+        _ => (),
+    };
+
+    // handle broadcasted cases
+    let cfg = layout_matmul_dyn_row_major_with_lc(&la.to_dim()?, &lb.to_dim()?, &lc.to_dim()?)?;
+    // rules 1, 2 and non-broadcasted rules 3, 4 are handled above as fast
+    // paths; only the broadcasted rules reach here.
+    let la_matmul = cfg.la_matmul.into_dim::<Ix2>()?;
+    let lb_matmul = cfg.lb_matmul.into_dim::<Ix2>()?;
+    let lc_matmul = cfg.lc_matmul.into_dim::<Ix2>()?;
+    let la_rest = cfg.la_rest.unwrap();
+    let lb_rest = cfg.lb_rest.unwrap();
+    let lc_rest = cfg.lc_rest.unwrap();
+    // now, lx_rest should have the same shape, while lx_matmul
+    // should be matmulable
+    // only parallel matmul when lx_rest is small (larger than
+    // 2*nthreads), otherwise parallel matmul anyway
+    let n_task = la_rest.size();
+    let ita_rest = IterLayoutColMajor::new(&la_rest)?;
+    let itb_rest = IterLayoutColMajor::new(&lb_rest)?;
+    let itc_rest = IterLayoutColMajor::new(&lc_rest)?;
+    if n_task >= 4 * nthreads {
+        // parallel outer, sequential matmul
+        let task = || {
+            ita_rest.into_par_iter().zip(itb_rest).zip(itc_rest).try_for_each(
+                |((ia_rest, ib_rest), ic_rest)| -> Result<()> {
+                    // prepare layout
+                    let mut la_m = la_matmul.clone();
+                    let mut lb_m = lb_matmul.clone();
+                    let mut lc_m = lc_matmul.clone();
+                    unsafe {
+                        la_m.set_offset(ia_rest);
+                        lb_m.set_offset(ib_rest);
+                        lc_m.set_offset(ic_rest);
+                    }
+                    // move mutable reference into parallel closure
+                    let c = unsafe {
+                        let c_ptr = c.as_ptr() as *mut TC;
+                        let c_len = c.len();
+                        from_raw_parts_mut(c_ptr, c_len)
+                    };
+                    // clone alpha and beta
+                    let alpha = alpha.clone();
+                    let beta = beta.clone();
+                    // inside rayon workers, so nested policy applies
+                    B::with_num_threads(blas_num_threads_budget(None), || {
+                        gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, &lc_m, a, &la_m, b, &lb_m, alpha, beta, None)
+                    })
+                },
+            )
+        };
+        match pool {
+            Some(pool) => pool.install(task),
+            None => task(),
+        }
+    } else {
+        // sequential outer, parallel matmul
+        B::with_num_threads(nthreads, || -> Result<()> {
+            izip!(ita_rest, itb_rest, itc_rest).try_for_each(|(ia_rest, ib_rest, ic_rest)| {
+                // prepare layout
+                let mut la_m = la_matmul.clone();
+                let mut lb_m = lb_matmul.clone();
+                let mut lc_m = lc_matmul.clone();
+                unsafe {
+                    la_m.set_offset(ia_rest);
+                    lb_m.set_offset(ib_rest);
+                    lc_m.set_offset(ic_rest);
+                }
+                // clone alpha and beta
+                let alpha = alpha.clone();
+                let beta = beta.clone();
+                gemm_blas_ix2_no_conj_dispatch::<B, _, _, _>(c, &lc_m, a, &la_m, b, &lb_m, alpha, beta, pool)
+            })
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<B, TA, TB, TC, DA, DB, DC> DeviceMatMulAPI<TA, TB, TC, DA, DB, DC> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    TA: Mul<TB, Output = TC>,
+    TB: Mul<TA, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    fn matmul(
+        &self,
+        c: &mut Vec<TC>,
+        lc: &Layout<DC>,
+        a: &Vec<TA>,
+        la: &Layout<DA>,
+        b: &Vec<TB>,
+        lb: &Layout<DB>,
+        alpha: TC,
+        beta: TC,
+    ) -> Result<()> {
+        let default_order = self.default_order();
+        let pool = self.get_current_pool();
+        match default_order {
+            RowMajor => matmul_row_major_blas::<B, _, _, _, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool),
+            ColMajor => {
+                let la = la.reverse_axes();
+                let lb = lb.reverse_axes();
+                let lc = lc.reverse_axes();
+                matmul_row_major_blas::<B, _, _, _, _, _, _>(c, &lc, b, &lb, a, &la, alpha, beta, pool)
+            },
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<B, T, DA, DB, DC> DeviceMatMulConjAPI<T, DA, DB, DC> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+    T: ComplexFloat + Send + Sync + 'static,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+{
+    fn matmul_conj(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<DC>,
+        a: &Vec<T>,
+        la: &Layout<DA>,
+        conja: bool,
+        b: &Vec<T>,
+        lb: &Layout<DB>,
+        conjb: bool,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        let default_order = self.default_order();
+        let pool = self.get_current_pool();
+        let nthreads = blas_num_threads_budget(pool);
+
+        // conjugation is trivial for real types
+        let is_real = same_type::<T, f32>() || same_type::<T, f64>();
+        let is_complex = same_type::<T, Complex<f32>>() || same_type::<T, Complex<f64>>();
+        if !(conja || conjb) || is_real {
+            return self.matmul(c, lc, a, la, b, lb, alpha, beta);
+        }
+
+        // matrix multiplication of complex types: conjugation is passed to BLAS
+        // for 2-D matmul, row-major and col-major give the same result
+        if is_complex && la.ndim() == 2 && lb.ndim() == 2 && lc.ndim() == 2 {
+            let la = &la.to_dim::<Ix2>()?;
+            let lb = &lb.to_dim::<Ix2>()?;
+            let lc = &lc.to_dim::<Ix2>()?;
+            macro_rules! impl_gemm_conj_dispatch {
+                ($ty: ty, $fn_name: ident) => {
+                    if same_type::<T, $ty>() {
+                        let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                        let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                        let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                        let alpha = unsafe { *(&alpha as *const T as *const $ty) };
+                        let beta = unsafe { *(&beta as *const T as *const $ty) };
+                        return B::with_num_threads(nthreads, || {
+                            $fn_name::<B>(c_slice, lc, a_slice, la, conja, b_slice, lb, conjb, alpha, beta, pool)
+                        });
+                    }
+                };
+            }
+            impl_gemm_conj_dispatch!(Complex<f32>, gemm_blas_conj_c32);
+            impl_gemm_conj_dispatch!(Complex<f64>, gemm_blas_conj_c64);
+        }
+
+        // other cases: conjugated operands are copied
+        let a_conj = if conja { Some(conj_to_contig_cpu_rayon(a, la, default_order, pool)?) } else { None };
+        let b_conj = if conjb { Some(conj_to_contig_cpu_rayon(b, lb, default_order, pool)?) } else { None };
+        let (a, la) = a_conj.as_ref().map_or((a, la), |(a, la)| (a, la));
+        let (b, lb) = b_conj.as_ref().map_or((b, lb), |(b, lb)| (b, lb));
+        self.matmul(c, lc, a, la, b, lb, alpha, beta)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<B, TA, TB, TC> DeviceGEMVAPI<TA, TB, TC> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+    TA: Clone + Send + Sync + 'static,
+    TB: Clone + Send + Sync + 'static,
+    TC: Clone + Send + Sync + 'static,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero + PartialEq,
+{
+    fn gemv(
+        &self,
+        c: &mut Vec<TC>,
+        lc: &Layout<Ix1>,
+        a: &Vec<TA>,
+        la: &Layout<Ix2>,
+        b: &Vec<TB>,
+        lb: &Layout<Ix1>,
+        alpha: TC,
+        beta: TC,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        let nthreads = blas_num_threads_budget(pool);
+        B::with_num_threads(nthreads, || {
+            gemv_blas_ix1_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+        })
+    }
+
+    fn gevm(
+        &self,
+        c: &mut Vec<TC>,
+        lc: &Layout<Ix1>,
+        a: &Vec<TA>,
+        la: &Layout<Ix1>,
+        b: &Vec<TB>,
+        lb: &Layout<Ix2>,
+        alpha: TC,
+        beta: TC,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        let nthreads = blas_num_threads_budget(pool);
+        B::with_num_threads(nthreads, || {
+            gevm_blas_ix1_no_conj_dispatch::<B, _, _, _>(c, lc, a, la, b, lb, alpha, beta, pool)
+        })
+    }
+}
+
+impl<B, T> DeviceSYRKAPI<T, T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + SYRKDriverAPI<T>,
+    T: BlasFloat<Real: BlasFloat>,
+{
+    fn syrk(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<Ix2>,
+        a: &Vec<T>,
+        la: &Layout<Ix2>,
+        uplo: FlagUpLo,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        let a = TensorView::new(Storage::new(a.into(), self.clone()), la.clone());
+        let c = TensorViewMut::new(Storage::new(c.into(), self.clone()), lc.clone());
+        let nthreads = blas_num_threads_budget(self.get_current_pool());
+        B::with_num_threads(nthreads, || {
+            SYRK::default().a(a).c(c).uplo(uplo).alpha(alpha).beta(beta).build()?.run()?;
+            Ok(())
+        })
+    }
+}
+
+impl<B, T> DeviceHERKAPI<T, T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI + HERKDriverAPI<T>,
+    T: BlasFloat<Real: BlasFloat>,
+{
+    fn herk(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<Ix2>,
+        a: &Vec<T>,
+        la: &Layout<Ix2>,
+        uplo: FlagUpLo,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        rstsr_assert!(
+            alpha.im() == T::Real::zero() && beta.im() == T::Real::zero(),
+            InvalidValue,
+            "HERK requires real alpha and beta."
+        )?;
+        let a = TensorView::new(Storage::new(a.into(), self.clone()), la.clone());
+        let c = TensorViewMut::new(Storage::new(c.into(), self.clone()), lc.clone());
+        let nthreads = blas_num_threads_budget(self.get_current_pool());
+        B::with_num_threads(nthreads, || {
+            HERK::default().a(a).c(c).uplo(uplo).alpha(alpha.re()).beta(beta.re()).build()?.run()?;
+            Ok(())
+        })
+    }
+}
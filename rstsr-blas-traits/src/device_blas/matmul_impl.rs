@@ -0,0 +1,545 @@
+#![allow(non_camel_case_types)]
+
+use crate::blas2::GEMVDriverAPI;
+use crate::blas3::{GEMMDriverAPI, SYRKDriverAPI};
+use crate::device_blas::prelude_dev::*;
+use num::complex::Complex;
+use num::traits::ConstZero;
+use rayon::prelude::*;
+
+type c32 = Complex<f32>;
+type c64 = Complex<f64>;
+
+/* #region gemm */
+
+#[duplicate_item(
+     ty    fn_name               ;
+    [f32] [gemm_blas_no_conj_f32];
+    [f64] [gemm_blas_no_conj_f64];
+    [c32] [gemm_blas_no_conj_c32];
+    [c64] [gemm_blas_no_conj_c64];
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn fn_name<B>(
+    c: &mut [ty],
+    lc: &Layout<Ix2>,
+    a: &[ty],
+    la: &Layout<Ix2>,
+    b: &[ty],
+    lb: &Layout<Ix2>,
+    alpha: ty,
+    beta: ty,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+{
+    // nthreads is only used for `assign_cpu_rayon`.
+    // the threading of BLAS should be handled outside this function.
+
+    // check layout of output
+    if !lc.f_prefer() {
+        // change to f-contig anyway
+        // we do not handle conj, so this can be done easily
+        if lc.c_prefer() {
+            // c-prefer, transpose and run
+            return fn_name::<B>(
+                c,
+                &lc.reverse_axes(),
+                b,
+                &lb.reverse_axes(),
+                a,
+                &la.reverse_axes(),
+                alpha,
+                beta,
+                pool,
+            );
+        } else {
+            // not c-prefer, allocate new buffer and copy back
+            let lc_new = lc.shape().new_f_contig(None);
+            let mut c_new = unsafe { uninitialized_vec(lc_new.size())? };
+            if beta == <ty>::ZERO {
+                fill_cpu_rayon(&mut c_new, &lc_new, <ty>::ZERO, pool)?;
+            } else {
+                assign_cpu_rayon(&mut c_new, &lc_new, c, lc, pool)?;
+            }
+            fn_name::<B>(&mut c_new, &lc_new, a, la, b, lb, alpha, <ty>::ZERO, pool)?;
+            assign_cpu_rayon(c, lc, &c_new, &lc_new, pool)?;
+            return Ok(());
+        }
+    }
+
+    // we assume that the layout is correct
+    let sc = lc.shape();
+    let sa = la.shape();
+    let sb = lb.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sa[1], sb[0], InvalidLayout)?;
+    rstsr_assert_eq!(sc[1], sb[1], InvalidLayout)?;
+
+    let m = sc[0];
+    let n = sc[1];
+    let k = sa[1];
+
+    // handle the special case that k is zero-dimensional
+    if k == 0 {
+        // if k is zero, the result is a zero matrix
+        return fill_cpu_rayon(c, lc, <ty>::ZERO, pool);
+    }
+
+    // handle the special case that n/m is zero-dimensional
+    if n == 0 || m == 0 {
+        // if n or m is zero, the result matrix size is zero, and nothing to do
+        return Ok(());
+    }
+
+    // determine trans/layout and clone data if necessary
+    let mut a_data: Option<Vec<ty>> = None;
+    let mut b_data: Option<Vec<ty>> = None;
+    let (a_trans, la) = if la.f_prefer() {
+        (FlagTrans::N, la.clone())
+    } else if la.c_prefer() {
+        (FlagTrans::T, la.reverse_axes())
+    } else {
+        let len = la.size();
+        a_data = unsafe { Some(uninitialized_vec(len)?) };
+        let la_data = la.shape().new_f_contig(None);
+        assign_cpu_rayon(a_data.as_mut().unwrap(), &la_data, a, la, pool)?;
+        (FlagTrans::N, la_data)
+    };
+    let (b_trans, lb) = if lb.f_prefer() {
+        (FlagTrans::N, lb.clone())
+    } else if lb.c_prefer() {
+        (FlagTrans::T, lb.reverse_axes())
+    } else {
+        let len = lb.size();
+        b_data = unsafe { Some(uninitialized_vec(len)?) };
+        let lb_data = lb.shape().new_f_contig(None);
+        assign_cpu_rayon(b_data.as_mut().unwrap(), &lb_data, b, lb, pool)?;
+        (FlagTrans::N, lb_data)
+    };
+
+    // final configuration
+    // shape may be broadcasted for one-dimension case, so make this check
+    let lda = if la.shape()[1] != 1 { la.stride()[1] as usize } else { la.shape()[0] };
+    let ldb = if lb.shape()[1] != 1 { lb.stride()[1] as usize } else { lb.shape()[0] };
+    let ldc = if lc.shape()[1] != 1 { lc.stride()[1] as usize } else { lc.shape()[0] };
+
+    let ptr_c = unsafe { c.as_mut_ptr().add(lc.offset()) };
+    let ptr_a =
+        if let Some(a_data) = a_data.as_ref() { a_data.as_ptr() } else { unsafe { a.as_ptr().add(la.offset()) } };
+    let ptr_b =
+        if let Some(b_data) = b_data.as_ref() { b_data.as_ptr() } else { unsafe { b.as_ptr().add(lb.offset()) } };
+
+    // actual computation
+    unsafe {
+        <B as GEMMDriverAPI<ty>>::driver_gemm(
+            FlagOrder::F,
+            a_trans,
+            b_trans,
+            m,
+            n,
+            k,
+            alpha,
+            ptr_a,
+            lda,
+            ptr_b,
+            ldb,
+            beta,
+            ptr_c,
+            ldc,
+        );
+    }
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region gemm with conjugation */
+
+#[duplicate_item(
+     ty    fn_name            fn_no_conj           ;
+    [c32] [gemm_blas_conj_c32] [gemm_blas_no_conj_c32];
+    [c64] [gemm_blas_conj_c64] [gemm_blas_no_conj_c64];
+)]
+#[allow(clippy::too_many_arguments)]
+/// GEMM with lazily conjugated operands.
+///
+/// Conjugated operands in c-prefer layout are passed to BLAS as
+/// `FlagTrans::C`; otherwise a conjugated f-contiguous copy is made.
+pub fn fn_name<B>(
+    c: &mut [ty],
+    lc: &Layout<Ix2>,
+    a: &[ty],
+    la: &Layout<Ix2>,
+    conja: bool,
+    b: &[ty],
+    lb: &Layout<Ix2>,
+    conjb: bool,
+    alpha: ty,
+    beta: ty,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+{
+    if !conja && !conjb {
+        return fn_no_conj::<B>(c, lc, a, la, b, lb, alpha, beta, pool);
+    }
+
+    // check layout of output
+    if !lc.f_prefer() {
+        if lc.c_prefer() {
+            // c-prefer, transpose and run; conjugation flags follow the operands
+            return fn_name::<B>(
+                c,
+                &lc.reverse_axes(),
+                b,
+                &lb.reverse_axes(),
+                conjb,
+                a,
+                &la.reverse_axes(),
+                conja,
+                alpha,
+                beta,
+                pool,
+            );
+        } else {
+            // not c-prefer, allocate new buffer and copy back
+            let lc_new = lc.shape().new_f_contig(None);
+            let mut c_new = unsafe { uninitialized_vec(lc_new.size())? };
+            if beta == <ty>::ZERO {
+                fill_cpu_rayon(&mut c_new, &lc_new, <ty>::ZERO, pool)?;
+            } else {
+                assign_cpu_rayon(&mut c_new, &lc_new, c, lc, pool)?;
+            }
+            fn_name::<B>(&mut c_new, &lc_new, a, la, conja, b, lb, conjb, alpha, <ty>::ZERO, pool)?;
+            assign_cpu_rayon(c, lc, &c_new, &lc_new, pool)?;
+            return Ok(());
+        }
+    }
+
+    let sc = lc.shape();
+    let sa = la.shape();
+    let sb = lb.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sa[1], sb[0], InvalidLayout)?;
+    rstsr_assert_eq!(sc[1], sb[1], InvalidLayout)?;
+
+    let m = sc[0];
+    let n = sc[1];
+    let k = sa[1];
+
+    if k == 0 {
+        return fill_cpu_rayon(c, lc, <ty>::ZERO, pool);
+    }
+    if n == 0 || m == 0 {
+        return Ok(());
+    }
+
+    // determine trans/layout and clone data if necessary
+    // conjugation without transpose is not portable in CBLAS, so conjugated
+    // operands that are not c-prefer are copied
+    let prepare = |x: &[ty], lx: &Layout<Ix2>, conj: bool| -> Result<(FlagTrans, Layout<Ix2>, Option<Vec<ty>>)> {
+        if conj && lx.c_prefer() {
+            Ok((FlagTrans::C, lx.reverse_axes(), None))
+        } else if conj {
+            let (x_data, lx_data) = conj_to_contig_cpu_rayon(x, lx, ColMajor, pool)?;
+            Ok((FlagTrans::N, lx_data, Some(x_data)))
+        } else if lx.f_prefer() {
+            Ok((FlagTrans::N, lx.clone(), None))
+        } else if lx.c_prefer() {
+            Ok((FlagTrans::T, lx.reverse_axes(), None))
+        } else {
+            let mut x_data = unsafe { uninitialized_vec(lx.size())? };
+            let lx_data = lx.shape().new_f_contig(None);
+            assign_cpu_rayon(&mut x_data, &lx_data, x, lx, pool)?;
+            Ok((FlagTrans::N, lx_data, Some(x_data)))
+        }
+    };
+    let (a_trans, la, a_data) = prepare(a, la, conja)?;
+    let (b_trans, lb, b_data) = prepare(b, lb, conjb)?;
+
+    // final configuration
+    // shape may be broadcasted for one-dimension case, so make this check
+    let lda = if la.shape()[1] != 1 { la.stride()[1] as usize } else { la.shape()[0] };
+    let ldb = if lb.shape()[1] != 1 { lb.stride()[1] as usize } else { lb.shape()[0] };
+    let ldc = if lc.shape()[1] != 1 { lc.stride()[1] as usize } else { lc.shape()[0] };
+
+    let ptr_c = unsafe { c.as_mut_ptr().add(lc.offset()) };
+    let ptr_a =
+        if let Some(a_data) = a_data.as_ref() { a_data.as_ptr() } else { unsafe { a.as_ptr().add(la.offset()) } };
+    let ptr_b =
+        if let Some(b_data) = b_data.as_ref() { b_data.as_ptr() } else { unsafe { b.as_ptr().add(lb.offset()) } };
+
+    // actual computation
+    unsafe {
+        <B as GEMMDriverAPI<ty>>::driver_gemm(
+            FlagOrder::F,
+            a_trans,
+            b_trans,
+            m,
+            n,
+            k,
+            alpha,
+            ptr_a,
+            lda,
+            ptr_b,
+            ldb,
+            beta,
+            ptr_c,
+            ldc,
+        );
+    }
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region syrk */
+
+#[duplicate_item(
+     ty    fn_name               ;
+    [f32] [syrk_blas_no_conj_f32];
+    [f64] [syrk_blas_no_conj_f64];
+    [c32] [syrk_blas_no_conj_c32];
+    [c64] [syrk_blas_no_conj_c64];
+)]
+pub fn fn_name<B>(
+    c: &mut [ty],
+    lc: &Layout<Ix2>,
+    a: &[ty],
+    la: &Layout<Ix2>,
+    alpha: ty,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+{
+    // beta is assumed to be zero, and not passed as argument.
+
+    // check layout of output
+    if !lc.f_prefer() {
+        // change to f-contig anyway
+        // we do not handle conj, so this can be done easily
+        if lc.c_prefer() {
+            // c-prefer, transpose and run
+            return fn_name::<B>(c, &lc.reverse_axes(), a, la, alpha, pool);
+        } else {
+            // not c-prefer, allocate new buffer and copy back
+            let lc_new = lc.shape().new_f_contig(None);
+            let mut c_new = unsafe { uninitialized_vec(lc_new.size())? };
+            fill_cpu_rayon(&mut c_new, &lc_new, <ty>::ZERO, pool)?;
+            fn_name::<B>(&mut c_new, &lc_new, a, la, alpha, pool)?;
+            assign_cpu_rayon(c, lc, &c_new, &lc_new, pool)?;
+            return Ok(());
+        }
+    }
+
+    // we assume that the layout is correct
+    let sc = lc.shape();
+    let sa = la.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sc[1], sc[0], InvalidLayout)?;
+
+    let n = sc[0];
+    let k = sa[1];
+
+    // handle the special case that k is zero-dimensional
+    if k == 0 {
+        // if k is zero, the result is a zero matrix
+        return fill_cpu_rayon(c, lc, <ty>::ZERO, pool);
+    }
+
+    // handle the special case that n is zero-dimensional
+    if n == 0 {
+        // if n is zero, the result matrix size is zero, and nothing to do
+        return Ok(());
+    }
+
+    // determine trans/layout and clone data if necessary
+    let mut a_data: Option<Vec<ty>> = None;
+    let (a_trans, la) = if la.f_prefer() {
+        (FlagTrans::N, la.clone())
+    } else if la.c_prefer() {
+        (FlagTrans::T, la.reverse_axes())
+    } else {
+        let len = la.size();
+        a_data = unsafe { Some(uninitialized_vec(len)?) };
+        let la_data = la.shape().new_f_contig(None);
+        assign_cpu_rayon(a_data.as_mut().unwrap(), &la_data, a, la, pool)?;
+        (FlagTrans::N, la_data)
+    };
+
+    // final configuration
+    // shape may be broadcasted for one-dimension case, so make this check
+    let lda = if la.shape()[1] != 1 { la.stride()[1] as usize } else { la.shape()[0] };
+    let ldc = if lc.shape()[1] != 1 { lc.stride()[1] as usize } else { lc.shape()[0] };
+
+    let ptr_c = unsafe { c.as_mut_ptr().add(lc.offset()) };
+    let ptr_a =
+        if let Some(a_data) = a_data.as_ref() { a_data.as_ptr() } else { unsafe { a.as_ptr().add(la.offset()) } };
+
+    // actual computation
+    unsafe {
+        <B as SYRKDriverAPI<ty>>::driver_syrk(
+            FlagOrder::F,
+            FlagUpLo::U,
+            a_trans,
+            n,
+            k,
+            alpha,
+            ptr_a,
+            lda,
+            <ty>::ZERO,
+            ptr_c,
+            ldc,
+        );
+    }
+
+    // write back to lower triangle
+    let n = sc[0];
+    let ldc = lc.stride()[1];
+    let offset = lc.offset() as isize;
+    let task = || {
+        (0..(n as isize)).into_par_iter().for_each(|j| {
+            ((j + 1)..(n as isize)).for_each(|i| unsafe {
+                let idx_ij = (offset + j * ldc + i) as usize;
+                let idx_ji = (offset + i * ldc + j) as usize;
+                let c_ptr_ij = c.as_ptr().add(idx_ij) as *mut ty;
+                *c_ptr_ij = c[idx_ji];
+            });
+        });
+    };
+    pool.map_or_else(task, |pool| pool.install(task));
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region gemv */
+
+#[duplicate_item(
+     ty    fn_name               ;
+    [f32] [gemv_blas_no_conj_f32];
+    [f64] [gemv_blas_no_conj_f64];
+    [c32] [gemv_blas_no_conj_c32];
+    [c64] [gemv_blas_no_conj_c64];
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn fn_name<B>(
+    c: &mut [ty],
+    lc: &Layout<Ix1>,
+    a: &[ty],
+    la: &Layout<Ix2>,
+    b: &[ty],
+    lb: &Layout<Ix1>,
+    alpha: ty,
+    beta: ty,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    B: CblasBackendAPI,
+{
+    // this function computes c = alpha * a b + beta * c, where a is matrix and
+    // b, c are vectors.
+
+    // check layout of output
+    // BLAS only accepts positive increment
+    if lc.size() > 1 && lc.stride()[0] <= 0 {
+        // allocate new buffer and copy back
+        let lc_new = lc.shape().new_f_contig(None);
+        let mut c_new = unsafe { uninitialized_vec(lc_new.size())? };
+        if beta == <ty>::ZERO {
+            fill_cpu_rayon(&mut c_new, &lc_new, <ty>::ZERO, pool)?;
+        } else {
+            assign_cpu_rayon(&mut c_new, &lc_new, c, lc, pool)?;
+        }
+        fn_name::<B>(&mut c_new, &lc_new, a, la, b, lb, alpha, beta, pool)?;
+        assign_cpu_rayon(c, lc, &c_new, &lc_new, pool)?;
+        return Ok(());
+    }
+
+    // we assume that the layout is correct
+    let sc = lc.shape();
+    let sa = la.shape();
+    let sb = lb.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sa[1], sb[0], InvalidLayout)?;
+
+    let m = sa[0];
+    let k = sa[1];
+
+    // handle the special case that k is zero-dimensional
+    if k == 0 {
+        // if k is zero, the result is a zero vector
+        return fill_cpu_rayon(c, lc, <ty>::ZERO, pool);
+    }
+
+    // handle the special case that m is zero-dimensional
+    if m == 0 {
+        return Ok(());
+    }
+
+    // determine trans/layout and clone data if necessary
+    let mut a_data: Option<Vec<ty>> = None;
+    let mut b_data: Option<Vec<ty>> = None;
+    let (a_trans, la) = if la.f_prefer() {
+        (FlagTrans::N, la.clone())
+    } else if la.c_prefer() {
+        (FlagTrans::T, la.reverse_axes())
+    } else {
+        let len = la.size();
+        a_data = unsafe { Some(uninitialized_vec(len)?) };
+        let la_data = la.shape().new_f_contig(None);
+        assign_cpu_rayon(a_data.as_mut().unwrap(), &la_data, a, la, pool)?;
+        (FlagTrans::N, la_data)
+    };
+    let lb = if k == 1 || lb.stride()[0] > 0 {
+        lb.clone()
+    } else {
+        let len = lb.size();
+        b_data = unsafe { Some(uninitialized_vec(len)?) };
+        let lb_data = lb.shape().new_f_contig(None);
+        assign_cpu_rayon(b_data.as_mut().unwrap(), &lb_data, b, lb, pool)?;
+        lb_data
+    };
+
+    // final configuration
+    // shape may be broadcasted for one-dimension case, so make this check
+    let lda = if la.shape()[1] != 1 { la.stride()[1] as usize } else { la.shape()[0] };
+    let incb = if k != 1 { lb.stride()[0] as usize } else { 1 };
+    let incc = if m != 1 { lc.stride()[0] as usize } else { 1 };
+    // dimensions of the column-major matrix passed to BLAS
+    let (nrow, ncol) = match a_trans {
+        FlagTrans::N => (m, k),
+        _ => (k, m),
+    };
+
+    let ptr_c = unsafe { c.as_mut_ptr().add(lc.offset()) };
+    let ptr_a =
+        if let Some(a_data) = a_data.as_ref() { a_data.as_ptr() } else { unsafe { a.as_ptr().add(la.offset()) } };
+    let ptr_b =
+        if let Some(b_data) = b_data.as_ref() { b_data.as_ptr() } else { unsafe { b.as_ptr().add(lb.offset()) } };
+
+    // actual computation
+    unsafe {
+        <B as GEMVDriverAPI<ty>>::driver_gemv(
+            FlagOrder::F,
+            a_trans,
+            nrow,
+            ncol,
+            alpha,
+            ptr_a,
+            lda,
+            ptr_b,
+            incb,
+            beta,
+            ptr_c,
+            incc,
+        );
+    }
+    Ok(())
+}
+
+/* #endregion */
@@ -0,0 +1,111 @@
+//! Generic BLAS device.
+//!
+//! [`DeviceBLAS<B>`] is a rayon-parallel CPU device whose BLAS/LAPACK
+//! routines are supplied by backend `B`. All the device, rayon, matmul and
+//! driver-dispatch functionality is implemented here once for every backend;
+//! linear algebra, scientific and sparse functionality are implemented for
+//! [`DeviceBLAS<B>`] in `rstsr-linalg-traits`, `rstsr-sci-traits` and
+//! `rstsr-sparse` (feature `blas`).
+//!
+//! A backend is a zero-sized marker type that
+//! - implements [`CblasBackendAPI`] for the threading hooks;
+//! - implements the driver traits (such as [`GEMMDriverAPI`], [`SYRKDriverAPI`], [`SYEVDriverAPI`])
+//!   by its FFI symbols. For backends exposing standard CBLAS and Fortran LAPACK symbols, the
+//!   templates in `driver_impl` of this crate are shared, and only the FFI crate (aliased as
+//!   `lapack_ffi`) needs to be given.
+//!
+//! Since the driver functions are called without device instance, a backend resolved at runtime is
+//! also possible, as long as its FFI symbols are loaded before the first call (for example, FFI
+//! crates with feature `dynamic_loading`).
+//!
+//! [`GEMMDriverAPI`]: crate::blas3::GEMMDriverAPI
+//! [`SYRKDriverAPI`]: crate::blas3::SYRKDriverAPI
+//! [`SYEVDriverAPI`]: crate::lapack_eigh::SYEVDriverAPI
+
+pub mod conversion;
+pub mod device;
+pub mod driver_dispatch;
+pub mod matmul;
+pub mod matmul_impl;
+pub mod prelude_dev;
+pub mod rayon_impl;
+pub mod threading;
+
+use crate::blas2::GEMVDriverAPI;
+use crate::blas3::{GEMMDriverAPI, SYRKDriverAPI};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use num::Complex;
+use rstsr_core::prelude_dev::DeviceCpuRayon;
+
+/// Backend of [`DeviceBLAS`].
+///
+/// Level 2 and 3 routines required by matmul are supertraits; other driver traits
+/// are optional, and functions requiring them are available only if the
+/// backend implements them.
+pub trait CblasBackendAPI:
+    Clone
+    + Debug
+    + Default
+    + Send
+    + Sync
+    + 'static
+    + GEMMDriverAPI<f32>
+    + GEMMDriverAPI<f64>
+    + GEMMDriverAPI<Complex<f32>>
+    + GEMMDriverAPI<Complex<f64>>
+    + SYRKDriverAPI<f32>
+    + SYRKDriverAPI<f64>
+    + SYRKDriverAPI<Complex<f32>>
+    + SYRKDriverAPI<Complex<f64>>
+    + GEMVDriverAPI<f32>
+    + GEMVDriverAPI<f64>
+    + GEMVDriverAPI<Complex<f32>>
+    + GEMVDriverAPI<Complex<f64>>
+{
+    /// Whether only eigenvalues are requested (`jobz = 'N'`) when calling
+    /// eigen drivers for eigenvalues only.
+    ///
+    /// Backends that misbehave on `jobz = 'N'` should set this to `false`, so
+    /// eigenvectors are always computed and then discarded.
+    const EIGVALS_ONLY: bool = true;
+
+    /// Set the number of threads used by the BLAS library.
+    fn set_num_threads(nthreads: usize);
+
+    /// Get the number of threads used by the BLAS library.
+    fn get_num_threads() -> usize;
+
+    /// Whether [`set_num_threads`](Self::set_num_threads) only affects the
+    /// calling thread.
+    ///
+    /// Backends with process-global setter should keep this `false`, so that
+    /// nested calls from rayon workers do not restore the number of threads
+    /// while other workers are running BLAS.
+    fn is_num_threads_local() -> bool {
+        false
+    }
+
+    /// Set the number of threads used by the BLAS library in closure.
+    ///
+    /// See [`with_num_threads_scoped`](crate::threading::with_num_threads_scoped).
+    fn with_num_threads<R>(nthreads: usize, f: impl FnOnce() -> R) -> R {
+        crate::threading::with_num_threads_scoped(
+            nthreads,
+            Self::is_num_threads_local(),
+            Self::get_num_threads,
+            Self::set_num_threads,
+            f,
+        )
+    }
+}
+
+/// Rayon-parallel CPU device with BLAS/LAPACK backend `B`.
+#[derive(Clone, Debug)]
+pub struct DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
+    base: DeviceCpuRayon,
+    backend: PhantomData<B>,
+}
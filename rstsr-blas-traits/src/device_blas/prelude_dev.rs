@@ -0,0 +1,2 @@
+pub use super::{CblasBackendAPI, DeviceBLAS};
+pub use rstsr_core::prelude_dev::*;
@@ -0,0 +1,7 @@
+//! Rayon-parallel tensor operations of [`DeviceBLAS`](super::DeviceBLAS).
+//!
+//! Implemented by the `auto_impl` macros of `rstsr-core`, shared with `DeviceFaer`.
+
+use crate::device_blas::prelude_dev::*;
+
+rstsr_core::impl_rayon_auto!([B] DeviceBLAS<B> where [B: CblasBackendAPI,]);
@@ -0,0 +1,21 @@
+//! BLAS threading of [`DeviceBLAS`], delegated to backend.
+
+use crate::device_blas::prelude_dev::*;
+use crate::prelude_dev::BlasThreadAPI;
+
+impl<B> BlasThreadAPI for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+{
+    fn get_blas_num_threads(&self) -> usize {
+        B::get_num_threads()
+    }
+
+    fn set_blas_num_threads(&self, nthreads: usize) {
+        B::set_num_threads(nthreads);
+    }
+
+    fn with_blas_num_threads<T>(&self, nthreads: usize, f: impl FnOnce() -> T) -> T {
+        B::with_num_threads(nthreads, f)
+    }
+}
@@ -1,9 +1,15 @@
 pub mod syev;
 pub mod syevd;
+pub mod syevr;
+pub mod syevx;
 pub mod sygv;
 pub mod sygvd;
+pub mod sygvx;
 
 pub use syev::*;
 pub use syevd::*;
+pub use syevr::*;
+pub use syevx::*;
 pub use sygv::*;
 pub use sygvd::*;
+pub use sygvx::*;
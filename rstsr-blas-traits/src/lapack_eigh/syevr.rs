@@ -0,0 +1,143 @@
+use crate::prelude_dev::*;
+use num::Zero;
+use rstsr_core::prelude_dev::*;
+
+pub trait SYEVRDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    unsafe fn driver_syevr(
+        order: FlagOrder,
+        jobz: char,
+        range: char,
+        uplo: FlagUpLo,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        abstol: T::Real,
+        m: &mut usize,
+        w: *mut T::Real,
+        z: *mut T,
+        ldz: usize,
+        isuppz: *mut blas_int,
+    ) -> blas_int;
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct SYEVR_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+
+    #[builder(setter(into), default = "'V'")]
+    pub jobz: char,
+    #[builder(setter(into), default = "'A'")]
+    pub range: char,
+    #[builder(setter(into), default = "None")]
+    pub uplo: Option<FlagUpLo>,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub vl: T::Real,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub vu: T::Real,
+    /// Lower index of eigenvalues (1-based, inclusive), only used when `range = 'I'`.
+    #[builder(default = "0")]
+    pub il: usize,
+    /// Upper index of eigenvalues (1-based, inclusive), only used when `range = 'I'`.
+    #[builder(default = "0")]
+    pub iu: usize,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub abstol: T::Real,
+}
+
+impl<B, T> SYEVR_<'_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + SYEVRDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>)> {
+        let Self { a, jobz, range, uplo, vl, vu, il, iu, abstol } = self;
+
+        let device = a.device().clone();
+        let uplo = uplo.unwrap_or_else(|| match device.default_order() {
+            RowMajor => Lower,
+            ColMajor => Upper,
+        });
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+
+        let [n, n_] = *a.view().shape();
+        rstsr_assert_eq!(n, n_, InvalidLayout)?;
+
+        // number of columns of eigenvectors to be allocated
+        let ncol = match range.to_ascii_uppercase() {
+            'A' | 'V' => n,
+            'I' => {
+                rstsr_assert!(1 <= il && il <= iu && iu <= n, InvalidValue, "SYEVR: requires 1 <= il <= iu <= n")?;
+                iu - il + 1
+            },
+            _ => rstsr_invalid!(range)?,
+        };
+        let compute_v = jobz.eq_ignore_ascii_case(&'V');
+
+        let lda = a.view().ld(order).unwrap();
+        let mut w = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut z = match compute_v {
+            true => unsafe { empty_f(([n, ncol], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let mut isuppz = unsafe { empty_f(([2 * ncol.max(1)].c(), &device))?.into_dim::<Ix1>() };
+        let ldz = z.view().ld(order).unwrap();
+        let mut m = 0;
+
+        // run driver
+        let info = unsafe {
+            B::driver_syevr(
+                order,
+                jobz,
+                range,
+                uplo,
+                n,
+                a.view_mut().as_mut_ptr(),
+                lda,
+                vl,
+                vu,
+                il,
+                iu,
+                abstol,
+                &mut m,
+                w.as_mut_ptr(),
+                z.as_mut_ptr(),
+                ldz,
+                isuppz.as_mut_ptr(),
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack SYEVR")?;
+        }
+
+        let w = w.into_slice(..m).into_dim::<Ix1>();
+        match compute_v {
+            true => Ok((w, Some(z.into_slice((.., ..m)).into_dim::<Ix2>()))),
+            false => Ok((w, None)),
+        }
+    }
+
+    pub fn run(self) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>)> {
+        self.internal_run()
+    }
+}
+
+pub type SYEVR<'a, B, T> = SYEVR_Builder<'a, B, T>;
+pub type SSYEVR<'a, B> = SYEVR<'a, B, f32>;
+pub type DSYEVR<'a, B> = SYEVR<'a, B, f64>;
+pub type CHEEVR<'a, B> = SYEVR<'a, B, Complex<f32>>;
+pub type ZHEEVR<'a, B> = SYEVR<'a, B, Complex<f64>>;
@@ -0,0 +1,107 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait GEESDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    /// Schur decomposition without sorting of eigenvalues.
+    ///
+    /// Eigenvalues are split into real and imaginary parts for both real and
+    /// complex types.
+    unsafe fn driver_gees(
+        order: FlagOrder,
+        jobvs: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        vs: *mut T,
+        ldvs: usize,
+    ) -> blas_int;
+}
+
+/// Schur decomposition `A = Z T Z^H` (`xGEES`).
+///
+/// `T` is upper triangular for complex types, and upper quasi-triangular
+/// (real Schur form, with 2x2 blocks for complex conjugate pairs) for real
+/// types. Eigenvalues are returned as `(wr, wi)`.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GEES_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+
+    #[builder(setter(into), default = "'V'")]
+    pub jobvs: char,
+}
+
+impl<'a, B, T> GEES_<'a, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GEESDriverAPI<T>,
+{
+    pub fn internal_run(
+        self,
+    ) -> Result<(TensorMutable2<'a, T, B>, Tensor<T::Real, B, Ix1>, Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>)>
+    {
+        let Self { a, jobvs } = self;
+
+        let device = a.device().clone();
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+
+        let [n, m] = *a.view().shape();
+        rstsr_assert_eq!(n, m, InvalidLayout, "GEES: A must be square")?;
+        let compute_vs = jobvs.eq_ignore_ascii_case(&'V');
+
+        let lda = a.view().ld(order).unwrap();
+        let mut wr = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut wi = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut vs = match compute_vs {
+            true => unsafe { empty_f(([n, n], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let ldvs = vs.view().ld(order).unwrap();
+
+        // run driver
+        let info = unsafe {
+            B::driver_gees(
+                order,
+                jobvs,
+                n,
+                a.view_mut().as_mut_ptr(),
+                lda,
+                wr.as_mut_ptr(),
+                wi.as_mut_ptr(),
+                vs.as_mut_ptr(),
+                ldvs,
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GEES")?;
+        }
+
+        let vs = compute_vs.then_some(vs);
+        Ok((a.clone_to_mut(), wr, wi, vs))
+    }
+
+    pub fn run(
+        self,
+    ) -> Result<(TensorMutable2<'a, T, B>, Tensor<T::Real, B, Ix1>, Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>)>
+    {
+        self.internal_run()
+    }
+}
+
+pub type GEES<'a, B, T> = GEES_Builder<'a, B, T>;
+pub type SGEES<'a, B> = GEES<'a, B, f32>;
+pub type DGEES<'a, B> = GEES<'a, B, f64>;
+pub type CGEES<'a, B> = GEES<'a, B, Complex<f32>>;
+pub type ZGEES<'a, B> = GEES<'a, B, Complex<f64>>;
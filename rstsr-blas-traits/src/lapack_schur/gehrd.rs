@@ -0,0 +1,71 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait GEHRDDriverAPI<T> {
+    unsafe fn driver_gehrd(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *mut T,
+    ) -> blas_int;
+}
+
+/// Reduction to upper Hessenberg form `A = Q H Q^H` (`xGEHRD`).
+///
+/// On exit, `H` is stored in the upper triangle and the first subdiagonal of
+/// `A`, and `Q` is represented by the elementary reflectors below the
+/// subdiagonal and `tau`. Use [`ORGHR`](crate::lapack_schur::ORGHR) to form
+/// `Q` explicitly.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GEHRD_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+}
+
+impl<'a, B, T> GEHRD_<'a, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GEHRDDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<(TensorMutable2<'a, T, B>, Tensor<T, B, Ix1>)> {
+        let Self { a } = self;
+
+        let device = a.device().clone();
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+
+        let [n, m] = *a.view().shape();
+        rstsr_assert_eq!(n, m, InvalidLayout, "GEHRD: A must be square")?;
+
+        let lda = a.view().ld(order).unwrap();
+        let mut tau = unsafe { empty_f(([n.saturating_sub(1).max(1)].c(), &device))?.into_dim::<Ix1>() };
+
+        // run driver
+        let info = unsafe { B::driver_gehrd(order, n, 1, n, a.view_mut().as_mut_ptr(), lda, tau.as_mut_ptr()) };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GEHRD")?;
+        }
+
+        let tau = tau.into_slice(..n.saturating_sub(1)).into_dim::<Ix1>();
+        Ok((a.clone_to_mut(), tau))
+    }
+
+    pub fn run(self) -> Result<(TensorMutable2<'a, T, B>, Tensor<T, B, Ix1>)> {
+        self.internal_run()
+    }
+}
+
+pub type GEHRD<'a, B, T> = GEHRD_Builder<'a, B, T>;
+pub type SGEHRD<'a, B> = GEHRD<'a, B, f32>;
+pub type DGEHRD<'a, B> = GEHRD<'a, B, f64>;
+pub type CGEHRD<'a, B> = GEHRD<'a, B, Complex<f32>>;
+pub type ZGEHRD<'a, B> = GEHRD<'a, B, Complex<f64>>;
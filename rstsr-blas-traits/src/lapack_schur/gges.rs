@@ -0,0 +1,141 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait GGESDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    /// Generalized Schur decomposition without sorting of eigenvalues.
+    ///
+    /// Generalized eigenvalues are `(alphar + i alphai) / beta`; for complex
+    /// types `alpha` is split into real and imaginary parts.
+    unsafe fn driver_gges(
+        order: FlagOrder,
+        jobvsl: char,
+        jobvsr: char,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        b: *mut T,
+        ldb: usize,
+        alphar: *mut T::Real,
+        alphai: *mut T::Real,
+        beta: *mut T,
+        vsl: *mut T,
+        ldvsl: usize,
+        vsr: *mut T,
+        ldvsr: usize,
+    ) -> blas_int;
+}
+
+/// Generalized Schur (QZ) decomposition `A = Q S Z^H`, `B = Q T Z^H`
+/// (`xGGES`).
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GGES_<'a, 'b, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+    #[builder(setter(into))]
+    pub b: TensorReference<'b, T, B, Ix2>,
+
+    #[builder(setter(into), default = "'V'")]
+    pub jobvsl: char,
+    #[builder(setter(into), default = "'V'")]
+    pub jobvsr: char,
+}
+
+#[allow(clippy::type_complexity)]
+impl<'a, 'b, B, T> GGES_<'a, 'b, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GGESDriverAPI<T>,
+{
+    pub fn internal_run(
+        self,
+    ) -> Result<(
+        TensorMutable2<'a, T, B>,
+        TensorMutable2<'b, T, B>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T, B, Ix1>,
+        Option<Tensor<T, B, Ix2>>,
+        Option<Tensor<T, B, Ix2>>,
+    )> {
+        let Self { a, b, jobvsl, jobvsr } = self;
+
+        let device = a.device().clone();
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+        let mut b = overwritable_convert_with_order(b, order)?;
+
+        let [n, m] = *a.view().shape();
+        rstsr_assert_eq!(n, m, InvalidLayout, "GGES: A must be square")?;
+        rstsr_assert_eq!(b.view().shape(), &[n, n], InvalidLayout, "GGES: B must be of the same shape as A")?;
+        let compute_vsl = jobvsl.eq_ignore_ascii_case(&'V');
+        let compute_vsr = jobvsr.eq_ignore_ascii_case(&'V');
+
+        let lda = a.view().ld(order).unwrap();
+        let ldb = b.view().ld(order).unwrap();
+        let mut alphar = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut alphai = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut beta = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let shape_vs = |compute: bool| if compute { [n, n] } else { [1, 1] };
+        let mut vsl = unsafe { empty_f((shape_vs(compute_vsl), order, &device))?.into_dim::<Ix2>() };
+        let mut vsr = unsafe { empty_f((shape_vs(compute_vsr), order, &device))?.into_dim::<Ix2>() };
+        let ldvsl = vsl.view().ld(order).unwrap();
+        let ldvsr = vsr.view().ld(order).unwrap();
+
+        // run driver
+        let info = unsafe {
+            B::driver_gges(
+                order,
+                jobvsl,
+                jobvsr,
+                n,
+                a.view_mut().as_mut_ptr(),
+                lda,
+                b.view_mut().as_mut_ptr(),
+                ldb,
+                alphar.as_mut_ptr(),
+                alphai.as_mut_ptr(),
+                beta.as_mut_ptr(),
+                vsl.as_mut_ptr(),
+                ldvsl,
+                vsr.as_mut_ptr(),
+                ldvsr,
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GGES")?;
+        }
+
+        let vsl = compute_vsl.then_some(vsl);
+        let vsr = compute_vsr.then_some(vsr);
+        Ok((a.clone_to_mut(), b.clone_to_mut(), alphar, alphai, beta, vsl, vsr))
+    }
+
+    pub fn run(
+        self,
+    ) -> Result<(
+        TensorMutable2<'a, T, B>,
+        TensorMutable2<'b, T, B>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T, B, Ix1>,
+        Option<Tensor<T, B, Ix2>>,
+        Option<Tensor<T, B, Ix2>>,
+    )> {
+        self.internal_run()
+    }
+}
+
+pub type GGES<'a, 'b, B, T> = GGES_Builder<'a, 'b, B, T>;
+pub type SGGES<'a, 'b, B> = GGES<'a, 'b, B, f32>;
+pub type DGGES<'a, 'b, B> = GGES<'a, 'b, B, f64>;
+pub type CGGES<'a, 'b, B> = GGES<'a, 'b, B, Complex<f32>>;
+pub type ZGGES<'a, 'b, B> = GGES<'a, 'b, B, Complex<f64>>;
@@ -0,0 +1,13 @@
+pub mod gees;
+pub mod gehrd;
+pub mod gges;
+pub mod orghr;
+pub mod trsen;
+pub mod trsyl;
+
+pub use gees::*;
+pub use gehrd::*;
+pub use gges::*;
+pub use orghr::*;
+pub use trsen::*;
+pub use trsyl::*;
@@ -0,0 +1,67 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait ORGHRDriverAPI<T> {
+    unsafe fn driver_orghr(
+        order: FlagOrder,
+        n: usize,
+        ilo: usize,
+        ihi: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int;
+}
+
+/// Unitary matrix `Q` from the elementary reflectors of
+/// [`GEHRD`](crate::lapack_schur::GEHRD) (`xORGHR` or `xUNGHR`).
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct ORGHR_<'a, 'tau, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+    pub tau: TensorView<'tau, T, B, Ix1>,
+}
+
+impl<'a, B, T> ORGHR_<'a, '_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + ORGHRDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<TensorMutable2<'a, T, B>> {
+        let Self { a, tau } = self;
+
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+        let tau = tau.into_contig_f(ColMajor)?;
+
+        let [n, m] = *a.view().shape();
+        rstsr_assert_eq!(n, m, InvalidLayout, "ORGHR: A must be square")?;
+        rstsr_assert_eq!(tau.size(), n.saturating_sub(1), InvalidLayout, "ORGHR: tau must be of length n - 1")?;
+
+        let lda = a.view().ld(order).unwrap();
+
+        // run driver
+        let info = unsafe { B::driver_orghr(order, n, 1, n, a.view_mut().as_mut_ptr(), lda, tau.as_ptr()) };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack ORGHR")?;
+        }
+
+        Ok(a.clone_to_mut())
+    }
+
+    pub fn run(self) -> Result<TensorMutable2<'a, T, B>> {
+        self.internal_run()
+    }
+}
+
+pub type ORGHR<'a, 'tau, B, T> = ORGHR_Builder<'a, 'tau, B, T>;
+pub type SORGHR<'a, 'tau, B> = ORGHR<'a, 'tau, B, f32>;
+pub type DORGHR<'a, 'tau, B> = ORGHR<'a, 'tau, B, f64>;
+pub type CUNGHR<'a, 'tau, B> = ORGHR<'a, 'tau, B, Complex<f32>>;
+pub type ZUNGHR<'a, 'tau, B> = ORGHR<'a, 'tau, B, Complex<f64>>;
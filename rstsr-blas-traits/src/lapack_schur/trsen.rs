@@ -0,0 +1,126 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait TRSENDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    /// Reorder the Schur factorization so that the selected eigenvalues
+    /// appear in the leading block. Condition numbers are not computed.
+    unsafe fn driver_trsen(
+        order: FlagOrder,
+        compq: char,
+        select: *const blas_int,
+        n: usize,
+        t: *mut T,
+        ldt: usize,
+        q: *mut T,
+        ldq: usize,
+        wr: *mut T::Real,
+        wi: *mut T::Real,
+        m: &mut usize,
+    ) -> blas_int;
+}
+
+/// Reordering of a Schur factorization (`xTRSEN`).
+///
+/// `select[i]` marks the eigenvalue `T[i, i]` to be moved to the leading
+/// block. For real types, a complex conjugate pair is selected if either of
+/// its two entries is selected.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct TRSEN_<'t, 'q, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub t: TensorReference<'t, T, B, Ix2>,
+    #[builder(setter(into, strip_option), default = "None")]
+    pub q: Option<TensorReference<'q, T, B, Ix2>>,
+    #[builder(setter(into))]
+    pub select: Vec<bool>,
+}
+
+#[allow(clippy::type_complexity)]
+impl<'t, 'q, B, T> TRSEN_<'t, 'q, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + TRSENDriverAPI<T>,
+{
+    pub fn internal_run(
+        self,
+    ) -> Result<(
+        TensorMutable2<'t, T, B>,
+        Option<TensorMutable2<'q, T, B>>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T::Real, B, Ix1>,
+        usize,
+    )> {
+        let Self { t, q, select } = self;
+
+        let device = t.device().clone();
+        let mut t = overwritable_convert(t)?;
+        let order = if t.f_prefer() && !t.c_prefer() { ColMajor } else { RowMajor };
+        let mut q = q.map(|q| overwritable_convert_with_order(q, order)).transpose()?;
+
+        let [n, m] = *t.view().shape();
+        rstsr_assert_eq!(n, m, InvalidLayout, "TRSEN: T must be square")?;
+        rstsr_assert_eq!(select.len(), n, InvalidLayout, "TRSEN: select must be of length n")?;
+        if let Some(q) = q.as_ref() {
+            rstsr_assert_eq!(q.view().shape(), &[n, n], InvalidLayout, "TRSEN: Q must be of the same shape as T")?;
+        }
+
+        let select = select.iter().map(|&s| s as blas_int).collect::<Vec<blas_int>>();
+        let ldt = t.view().ld(order).unwrap();
+        let (compq, ptr_q, ldq) = match q.as_mut() {
+            Some(q) => ('V', q.view_mut().as_mut_ptr(), q.view().ld(order).unwrap()),
+            None => ('N', core::ptr::null_mut(), n.max(1)),
+        };
+        let mut wr = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut wi = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut m = 0;
+
+        // run driver
+        let info = unsafe {
+            B::driver_trsen(
+                order,
+                compq,
+                select.as_ptr(),
+                n,
+                t.view_mut().as_mut_ptr(),
+                ldt,
+                ptr_q,
+                ldq,
+                wr.as_mut_ptr(),
+                wi.as_mut_ptr(),
+                &mut m,
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack TRSEN")?;
+        }
+
+        let q = q.map(|q| q.clone_to_mut());
+        Ok((t.clone_to_mut(), q, wr, wi, m))
+    }
+
+    pub fn run(
+        self,
+    ) -> Result<(
+        TensorMutable2<'t, T, B>,
+        Option<TensorMutable2<'q, T, B>>,
+        Tensor<T::Real, B, Ix1>,
+        Tensor<T::Real, B, Ix1>,
+        usize,
+    )> {
+        self.internal_run()
+    }
+}
+
+pub type TRSEN<'t, 'q, B, T> = TRSEN_Builder<'t, 'q, B, T>;
+pub type STRSEN<'t, 'q, B> = TRSEN<'t, 'q, B, f32>;
+pub type DTRSEN<'t, 'q, B> = TRSEN<'t, 'q, B, f64>;
+pub type CTRSEN<'t, 'q, B> = TRSEN<'t, 'q, B, Complex<f32>>;
+pub type ZTRSEN<'t, 'q, B> = TRSEN<'t, 'q, B, Complex<f64>>;
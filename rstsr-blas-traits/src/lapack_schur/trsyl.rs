@@ -0,0 +1,110 @@
+use crate::prelude_dev::*;
+use num::Zero;
+use rstsr_core::prelude_dev::*;
+
+pub trait TRSYLDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    unsafe fn driver_trsyl(
+        order: FlagOrder,
+        trana: FlagTrans,
+        tranb: FlagTrans,
+        isgn: i32,
+        m: usize,
+        n: usize,
+        a: *const T,
+        lda: usize,
+        b: *const T,
+        ldb: usize,
+        c: *mut T,
+        ldc: usize,
+        scale: &mut T::Real,
+    ) -> blas_int;
+}
+
+/// Sylvester equation `op(A) X + isgn X op(B) = scale C` with `A` and `B` in
+/// (quasi-)triangular Schur form (`xTRSYL`).
+///
+/// `C` is overwritten by `X`; `scale <= 1` is chosen to avoid overflow.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct TRSYL_<'a, 'b, 'c, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorView<'a, T, B, Ix2>,
+    #[builder(setter(into))]
+    pub b: TensorView<'b, T, B, Ix2>,
+    #[builder(setter(into))]
+    pub c: TensorReference<'c, T, B, Ix2>,
+
+    #[builder(setter(into), default = "NoTrans")]
+    pub trana: FlagTrans,
+    #[builder(setter(into), default = "NoTrans")]
+    pub tranb: FlagTrans,
+    #[builder(setter(into), default = "1")]
+    pub isgn: i32,
+}
+
+impl<'c, B, T> TRSYL_<'_, '_, 'c, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + TRSYLDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<(TensorMutable2<'c, T, B>, T::Real)> {
+        let Self { a, b, c, trana, tranb, isgn } = self;
+
+        let mut c = overwritable_convert(c)?;
+        let order = if c.f_prefer() && !c.c_prefer() { ColMajor } else { RowMajor };
+        let a = a.into_contig_f(order)?;
+        let b = b.into_contig_f(order)?;
+
+        let [m, n] = *c.view().shape();
+        rstsr_assert_eq!(a.shape(), &[m, m], InvalidLayout, "TRSYL: A must be of shape (m, m)")?;
+        rstsr_assert_eq!(b.shape(), &[n, n], InvalidLayout, "TRSYL: B must be of shape (n, n)")?;
+        rstsr_assert!(isgn == 1 || isgn == -1, InvalidValue, "TRSYL: isgn must be 1 or -1")?;
+
+        let lda = a.view().ld(order).unwrap();
+        let ldb = b.view().ld(order).unwrap();
+        let ldc = c.view().ld(order).unwrap();
+        let mut scale = T::Real::zero();
+
+        // run driver
+        let info = unsafe {
+            B::driver_trsyl(
+                order,
+                trana,
+                tranb,
+                isgn,
+                m,
+                n,
+                a.as_ptr(),
+                lda,
+                b.as_ptr(),
+                ldb,
+                c.view_mut().as_mut_ptr(),
+                ldc,
+                &mut scale,
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack TRSYL")?;
+        }
+
+        Ok((c.clone_to_mut(), scale))
+    }
+
+    pub fn run(self) -> Result<(TensorMutable2<'c, T, B>, T::Real)> {
+        self.internal_run()
+    }
+}
+
+pub type TRSYL<'a, 'b, 'c, B, T> = TRSYL_Builder<'a, 'b, 'c, B, T>;
+pub type STRSYL<'a, 'b, 'c, B> = TRSYL<'a, 'b, 'c, B, f32>;
+pub type DTRSYL<'a, 'b, 'c, B> = TRSYL<'a, 'b, 'c, B, f64>;
+pub type CTRSYL<'a, 'b, 'c, B> = TRSYL<'a, 'b, 'c, B, Complex<f32>>;
+pub type ZTRSYL<'a, 'b, 'c, B> = TRSYL<'a, 'b, 'c, B, Complex<f64>>;
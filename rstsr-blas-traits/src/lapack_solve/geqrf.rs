@@ -0,0 +1,60 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait GEQRFDriverAPI<T> {
+    unsafe fn driver_geqrf(order: FlagOrder, m: usize, n: usize, a: *mut T, lda: usize, tau: *mut T) -> blas_int;
+}
+
+/// QR factorization `A = Q R` (`xGEQRF`).
+///
+/// On exit, `R` is stored in the upper triangle (trapezoid) of `A`, and `Q`
+/// is represented by the elementary reflectors below the diagonal and `tau`.
+/// Use [`ORGQR`](crate::lapack_solve::ORGQR) to form `Q` explicitly.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GEQRF_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+}
+
+impl<'a, B, T> GEQRF_<'a, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GEQRFDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<(TensorMutable2<'a, T, B>, Tensor<T, B, Ix1>)> {
+        let Self { a } = self;
+
+        let device = a.device().clone();
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+
+        let [m, n] = *a.view().shape();
+        let lda = a.view().ld(order).unwrap();
+        let mut tau = unsafe { empty_f(([m.min(n).max(1)].c(), &device))?.into_dim::<Ix1>() };
+
+        // run driver
+        let info = unsafe { B::driver_geqrf(order, m, n, a.view_mut().as_mut_ptr(), lda, tau.as_mut_ptr()) };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GEQRF")?;
+        }
+
+        let tau = tau.into_slice(..m.min(n)).into_dim::<Ix1>();
+        Ok((a.clone_to_mut(), tau))
+    }
+
+    pub fn run(self) -> Result<(TensorMutable2<'a, T, B>, Tensor<T, B, Ix1>)> {
+        self.internal_run()
+    }
+}
+
+pub type GEQRF<'a, B, T> = GEQRF_Builder<'a, B, T>;
+pub type SGEQRF<'a, B> = GEQRF<'a, B, f32>;
+pub type DGEQRF<'a, B> = GEQRF<'a, B, f64>;
+pub type CGEQRF<'a, B> = GEQRF<'a, B, Complex<f32>>;
+pub type ZGEQRF<'a, B> = GEQRF<'a, B, Complex<f64>>;
@@ -1,11 +1,15 @@
+pub mod geqrf;
 pub mod gesv;
 pub mod getrf;
 pub mod getri;
+pub mod orgqr;
 pub mod potrf;
 pub mod sysv;
 
+pub use geqrf::*;
 pub use gesv::*;
 pub use getrf::*;
 pub use getri::*;
+pub use orgqr::*;
 pub use potrf::*;
 pub use sysv::*;
@@ -0,0 +1,71 @@
+use crate::prelude_dev::*;
+use rstsr_core::prelude_dev::*;
+
+pub trait ORGQRDriverAPI<T> {
+    unsafe fn driver_orgqr(
+        order: FlagOrder,
+        m: usize,
+        n: usize,
+        k: usize,
+        a: *mut T,
+        lda: usize,
+        tau: *const T,
+    ) -> blas_int;
+}
+
+/// First `n` columns of the unitary matrix `Q` from the elementary reflectors
+/// of [`GEQRF`](crate::lapack_solve::GEQRF) (`xORGQR` or `xUNGQR`).
+///
+/// `A` is of shape `(m, n)` with `m >= n`, and the number of reflectors is
+/// the length of `tau`, which should not exceed `n`.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct ORGQR_<'a, 'tau, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+    pub tau: TensorView<'tau, T, B, Ix1>,
+}
+
+impl<'a, B, T> ORGQR_<'a, '_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + ORGQRDriverAPI<T>,
+{
+    pub fn internal_run(self) -> Result<TensorMutable2<'a, T, B>> {
+        let Self { a, tau } = self;
+
+        let mut a = overwritable_convert(a)?;
+        let order = if a.f_prefer() && !a.c_prefer() { ColMajor } else { RowMajor };
+        let tau = tau.into_contig_f(ColMajor)?;
+
+        let [m, n] = *a.view().shape();
+        let k = tau.size();
+        rstsr_assert!(m >= n, InvalidLayout, "ORGQR: A must not have more columns than rows")?;
+        rstsr_assert!(n >= k, InvalidLayout, "ORGQR: tau must not be longer than columns of A")?;
+
+        let lda = a.view().ld(order).unwrap();
+
+        // run driver
+        let info = unsafe { B::driver_orgqr(order, m, n, k, a.view_mut().as_mut_ptr(), lda, tau.as_ptr()) };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack ORGQR")?;
+        }
+
+        Ok(a.clone_to_mut())
+    }
+
+    pub fn run(self) -> Result<TensorMutable2<'a, T, B>> {
+        self.internal_run()
+    }
+}
+
+pub type ORGQR<'a, 'tau, B, T> = ORGQR_Builder<'a, 'tau, B, T>;
+pub type SORGQR<'a, 'tau, B> = ORGQR<'a, 'tau, B, f32>;
+pub type DORGQR<'a, 'tau, B> = ORGQR<'a, 'tau, B, f64>;
+pub type CUNGQR<'a, 'tau, B> = ORGQR<'a, 'tau, B, Complex<f32>>;
+pub type ZUNGQR<'a, 'tau, B> = ORGQR<'a, 'tau, B, Complex<f64>>;
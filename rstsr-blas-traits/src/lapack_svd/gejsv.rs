@@ -0,0 +1,164 @@
+use crate::prelude_dev::*;
+use num::One;
+use rstsr_core::prelude_dev::*;
+
+pub trait GEJSVDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    /// Jacobi SVD `A = U S V^H` of `m x n` matrix with `m >= n`.
+    ///
+    /// Note that `V` (not `V^H`) is returned, and the singular values are
+    /// `sva * stat[1] / stat[0]`, same to LAPACKE.
+    unsafe fn driver_gejsv(
+        order: FlagOrder,
+        joba: char,
+        jobu: char,
+        jobv: char,
+        jobr: char,
+        jobt: char,
+        jobp: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        sva: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        v: *mut T,
+        ldv: usize,
+        stat: *mut T::Real,
+        istat: *mut blas_int,
+    ) -> blas_int;
+}
+
+/// High relative accuracy SVD by preconditioned Jacobi method (`xGEJSV`).
+///
+/// Matrices with `m < n` are handled by decomposing `A^H`. Unlike the LAPACK
+/// routine, `VT = V^H` is returned, and singular values are scaled back.
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GEJSV_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+
+    /// Accuracy level of LAPACK (`'C'`, `'E'`, `'F'`, `'G'`, `'A'`, `'R'`).
+    #[builder(setter(into), default = "'C'")]
+    pub joba: char,
+    #[builder(default = "true")]
+    pub full_matrices: bool,
+    #[builder(default = "true")]
+    pub compute_u: bool,
+    #[builder(default = "true")]
+    pub compute_vt: bool,
+}
+
+impl<B, T> GEJSV_<'_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GEJSVDriverAPI<T>,
+{
+    pub fn internal_run(
+        self,
+    ) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>, Option<Tensor<T, B, Ix2>>)> {
+        let Self { a, joba, full_matrices, compute_u, compute_vt } = self;
+
+        let device = a.device().clone();
+        let order = match (a.c_prefer(), a.f_prefer()) {
+            (true, false) => RowMajor,
+            (false, true) => ColMajor,
+            (false, false) | (true, true) => a.device().default_order(),
+        };
+        let [m, n] = *a.view().shape();
+
+        // GEJSV requires m >= n; otherwise decompose A^H = (V S U^H)
+        let trans = m < n;
+        let (mut a, m, n, compute_left, compute_right) = match trans {
+            false => (overwritable_convert_with_order(a, order)?, m, n, compute_u, compute_vt),
+            true => {
+                let a_h = conj_f(a.view().into_reverse_axes())?.into_contig_f(order)?;
+                (TensorMutable::Owned(a_h), n, m, compute_vt, compute_u)
+            },
+        };
+        let lda = a.view().ld(order).unwrap();
+
+        let jobu = match (compute_left, full_matrices) {
+            (false, _) => 'N',
+            (true, false) => 'U',
+            (true, true) => 'F',
+        };
+        let jobv = if compute_right { 'V' } else { 'N' };
+
+        let ucol = if full_matrices { m } else { n };
+        let mut sva = unsafe { empty_f(([n].c(), &device))?.into_dim::<Ix1>() };
+        let mut u = match compute_left {
+            true => unsafe { empty_f(([m, ucol], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let mut v = match compute_right {
+            true => unsafe { empty_f(([n, n], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let mut stat = unsafe { empty_f(([7].c(), &device))?.into_dim::<Ix1>() };
+        let mut istat = unsafe { empty_f(([3].c(), &device))?.into_dim::<Ix1>() };
+        let ldu = u.view().ld(order).unwrap();
+        let ldv = v.view().ld(order).unwrap();
+
+        // run driver
+        let info = unsafe {
+            B::driver_gejsv(
+                order,
+                joba,
+                jobu,
+                jobv,
+                'R',
+                'N',
+                'N',
+                m,
+                n,
+                a.view_mut().as_mut_ptr(),
+                lda,
+                sva.as_mut_ptr(),
+                u.as_mut_ptr(),
+                ldu,
+                v.as_mut_ptr(),
+                ldv,
+                stat.as_mut_ptr(),
+                istat.as_mut_ptr(),
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GEJSV")?;
+        }
+
+        // singular values may be scaled to avoid overflow
+        let scale = stat.raw()[1] / stat.raw()[0];
+        if scale != T::Real::one() {
+            sva.raw_mut().iter_mut().for_each(|x| *x = *x * scale);
+        }
+
+        // u: left vectors of the tall matrix; v: right vectors of the tall matrix
+        let u = compute_left.then_some(u);
+        let v = compute_right.then_some(v);
+        let to_h = |x: Tensor<T, B, Ix2>| conj_f(x.into_reverse_axes())?.into_contig_f(order);
+        match trans {
+            false => Ok((sva, u, v.map(to_h).transpose()?)),
+            true => Ok((sva, v, u.map(to_h).transpose()?)),
+        }
+    }
+
+    pub fn run(self) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>, Option<Tensor<T, B, Ix2>>)> {
+        self.internal_run()
+    }
+}
+
+pub type GEJSV<'a, B, T> = GEJSV_Builder<'a, B, T>;
+pub type SGEJSV<'a, B> = GEJSV<'a, B, f32>;
+pub type DGEJSV<'a, B> = GEJSV<'a, B, f64>;
+pub type CGEJSV<'a, B> = GEJSV<'a, B, Complex<f32>>;
+pub type ZGEJSV<'a, B> = GEJSV<'a, B, Complex<f64>>;
@@ -35,6 +35,9 @@ where
     pub full_matrices: bool,
     #[builder(default = "true")]
     pub compute_uv: bool,
+    /// Whether to compute VT when `compute_uv` is set; U only is computed if false.
+    #[builder(default = "true")]
+    pub compute_vt: bool,
 }
 
 impl<'a, B, T> GESVD_<'a, B, T>
@@ -46,7 +49,7 @@ where
         self,
     ) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>, Option<Tensor<T, B, Ix2>>, Tensor<T::Real, B, Ix1>)>
     {
-        let Self { a, full_matrices, compute_uv } = self;
+        let Self { a, full_matrices, compute_uv, compute_vt } = self;
 
         let device = a.device().clone();
         let order = match (a.c_prefer(), a.f_prefer()) {
@@ -67,10 +70,18 @@ where
         };
         let minmn = m.min(n);
 
-        let ([u0, u1], [vt0, vt1]) = match jobz {
-            'N' => ([1, 1], [1, 1]),
-            'S' => ([m, minmn], [minmn, n]),
-            'A' => ([m, m], [n, n]),
+        let jobvt = if compute_vt { jobz } else { 'N' };
+
+        let [u0, u1] = match jobz {
+            'N' => [1, 1],
+            'S' => [m, minmn],
+            'A' => [m, m],
+            _ => unreachable!(),
+        };
+        let [vt0, vt1] = match jobvt {
+            'N' => [1, 1],
+            'S' => [minmn, n],
+            'A' => [n, n],
             _ => unreachable!(),
         };
 
@@ -87,7 +98,7 @@ where
             B::driver_gesvd(
                 order,
                 jobz,
-                jobz,
+                jobvt,
                 m,
                 n,
                 a.view_mut().as_mut_ptr(),
@@ -105,9 +116,10 @@ where
             rstsr_errcode!(info, "Lapack GESVD")?;
         }
 
-        match compute_uv {
-            false => Ok((s, None, None, superb)),
-            true => Ok((s, Some(u), Some(vt), superb)),
+        match (compute_uv, compute_vt) {
+            (false, _) => Ok((s, None, None, superb)),
+            (true, false) => Ok((s, Some(u), None, superb)),
+            (true, true) => Ok((s, Some(u), Some(vt), superb)),
         }
     }
 
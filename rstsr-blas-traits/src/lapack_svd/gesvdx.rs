@@ -0,0 +1,157 @@
+use crate::prelude_dev::*;
+use num::Zero;
+use rstsr_core::prelude_dev::*;
+
+pub trait GESVDXDriverAPI<T>
+where
+    T: BlasFloat,
+{
+    unsafe fn driver_gesvdx(
+        order: FlagOrder,
+        jobu: char,
+        jobvt: char,
+        range: char,
+        m: usize,
+        n: usize,
+        a: *mut T,
+        lda: usize,
+        vl: T::Real,
+        vu: T::Real,
+        il: usize,
+        iu: usize,
+        ns: &mut usize,
+        s: *mut T::Real,
+        u: *mut T,
+        ldu: usize,
+        vt: *mut T,
+        ldvt: usize,
+        superb: *mut blas_int,
+    ) -> blas_int;
+}
+
+#[derive(Builder)]
+#[builder(pattern = "owned", no_std, build_fn(error = "Error"))]
+pub struct GESVDX_<'a, B, T>
+where
+    T: BlasFloat,
+    B: DeviceAPI<T>,
+{
+    #[builder(setter(into))]
+    pub a: TensorReference<'a, T, B, Ix2>,
+
+    #[builder(setter(into), default = "'V'")]
+    pub jobu: char,
+    #[builder(setter(into), default = "'V'")]
+    pub jobvt: char,
+    #[builder(setter(into), default = "'A'")]
+    pub range: char,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub vl: T::Real,
+    #[builder(setter(into), default = "T::Real::zero()")]
+    pub vu: T::Real,
+    /// Lower index of singular values (1-based, inclusive, in descending order), only used when
+    /// `range = 'I'`.
+    #[builder(default = "0")]
+    pub il: usize,
+    /// Upper index of singular values (1-based, inclusive, in descending order), only used when
+    /// `range = 'I'`.
+    #[builder(default = "0")]
+    pub iu: usize,
+}
+
+impl<B, T> GESVDX_<'_, B, T>
+where
+    T: BlasFloat,
+    B: BlasDriverBaseAPI<T> + GESVDXDriverAPI<T>,
+{
+    pub fn internal_run(
+        self,
+    ) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>, Option<Tensor<T, B, Ix2>>)> {
+        let Self { a, jobu, jobvt, range, vl, vu, il, iu } = self;
+
+        let device = a.device().clone();
+        let order = match (a.c_prefer(), a.f_prefer()) {
+            (true, false) => RowMajor,
+            (false, true) => ColMajor,
+            (false, false) | (true, true) => a.device().default_order(),
+        };
+        let mut a = overwritable_convert_with_order(a, order)?;
+        let [m, n] = *a.view().shape();
+        let lda = a.view().ld(order).unwrap();
+        let minmn = m.min(n);
+
+        // number of singular vectors to be allocated
+        let ncol = match range.to_ascii_uppercase() {
+            'A' | 'V' => minmn,
+            'I' => {
+                rstsr_assert!(
+                    1 <= il && il <= iu && iu <= minmn,
+                    InvalidValue,
+                    "GESVDX: requires 1 <= il <= iu <= min(m, n)"
+                )?;
+                iu - il + 1
+            },
+            _ => rstsr_invalid!(range)?,
+        };
+        let compute_u = jobu.eq_ignore_ascii_case(&'V');
+        let compute_vt = jobvt.eq_ignore_ascii_case(&'V');
+
+        let mut s = unsafe { empty_f(([minmn].c(), &device))?.into_dim::<Ix1>() };
+        let mut u = match compute_u {
+            true => unsafe { empty_f(([m, ncol], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let mut vt = match compute_vt {
+            true => unsafe { empty_f(([ncol, n], order, &device))?.into_dim::<Ix2>() },
+            false => unsafe { empty_f(([1, 1], order, &device))?.into_dim::<Ix2>() },
+        };
+        let mut superb = unsafe { empty_f(([(12 * minmn).max(1)].c(), &device))?.into_dim::<Ix1>() };
+        let ldu = u.view().ld(order).unwrap();
+        let ldvt = vt.view().ld(order).unwrap();
+        let mut ns = 0;
+
+        // run driver
+        let info = unsafe {
+            B::driver_gesvdx(
+                order,
+                jobu,
+                jobvt,
+                range,
+                m,
+                n,
+                a.view_mut().as_mut_ptr(),
+                lda,
+                vl,
+                vu,
+                il,
+                iu,
+                &mut ns,
+                s.as_mut_ptr(),
+                u.as_mut_ptr(),
+                ldu,
+                vt.as_mut_ptr(),
+                ldvt,
+                superb.as_mut_ptr(),
+            )
+        };
+        let info = info as i32;
+        if info != 0 {
+            rstsr_errcode!(info, "Lapack GESVDX")?;
+        }
+
+        let s = s.into_slice(..ns).into_dim::<Ix1>();
+        let u = compute_u.then(|| u.into_slice((.., ..ns)).into_dim::<Ix2>());
+        let vt = compute_vt.then(|| vt.into_slice((..ns, ..)).into_dim::<Ix2>());
+        Ok((s, u, vt))
+    }
+
+    pub fn run(self) -> Result<(Tensor<T::Real, B, Ix1>, Option<Tensor<T, B, Ix2>>, Option<Tensor<T, B, Ix2>>)> {
+        self.internal_run()
+    }
+}
+
+pub type GESVDX<'a, B, T> = GESVDX_Builder<'a, B, T>;
+pub type SGESVDX<'a, B> = GESVDX<'a, B, f32>;
+pub type DGESVDX<'a, B> = GESVDX<'a, B, f64>;
+pub type CGESVDX<'a, B> = GESVDX<'a, B, Complex<f32>>;
+pub type ZGESVDX<'a, B> = GESVDX<'a, B, Complex<f64>>;
@@ -1,5 +1,9 @@
+pub mod gejsv;
 pub mod gesdd;
 pub mod gesvd;
+pub mod gesvdx;
 
+pub use gejsv::*;
 pub use gesdd::*;
 pub use gesvd::*;
+pub use gesvdx::*;
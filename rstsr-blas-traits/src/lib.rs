@@ -1,3 +1,4 @@
+#![allow(clippy::needless_return)]
 #![allow(non_camel_case_types)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::missing_safety_doc)]
@@ -16,8 +17,13 @@ pub mod threading;
 pub mod trait_def;
 pub mod util;
 
+pub mod blas1;
+pub mod blas2;
 pub mod blas3;
 
 pub mod lapack_eigh;
+pub mod lapack_schur;
 pub mod lapack_solve;
 pub mod lapack_svd;
+
+pub mod device_blas;
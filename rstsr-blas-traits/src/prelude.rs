@@ -2,8 +2,11 @@ pub use crate::blas_int;
 pub use crate::blas_scalar::BlasFloat;
 pub use crate::threading::*;
 
+pub use crate::blas1::*;
+pub use crate::blas2::*;
 pub use crate::blas3::*;
 pub use crate::lapack_eigh::*;
+pub use crate::lapack_schur::*;
 pub use crate::lapack_solve::*;
 pub use crate::lapack_svd::*;
 pub use crate::trait_def::*;
@@ -3,6 +3,32 @@
 //! Most BLAS distributions have a way to control the number of threads used by
 //! the library. This module provides trait to set the number of threads used by
 //! the BLAS library.
+//!
+//! # Thread budget with rayon nesting
+//!
+//! BLAS calls of RSTSR (matmul and linalg functions) are made with the number
+//! of threads given by [`blas_num_threads_budget`]:
+//!
+//! - thread-local override, if set by [`BlasNumThreadsGuard`] or [`with_local_blas_num_threads`];
+//! - outside rayon workers, the number of threads of device's thread pool;
+//! - inside rayon workers, by [`BlasNestedPolicy`]: 1 thread ([`Serial`](BlasNestedPolicy::Serial),
+//!   default), or a per-worker share of the available cores ([`Share`](BlasNestedPolicy::Share)).
+//!
+//! So nested parallel loops that call BLAS do not oversubscribe cores.
+//!
+//! Some BLAS libraries only have a process-global setter (OpenBLAS with
+//! pthreads, BLIS). Setting and restoring it independently in each rayon worker
+//! would let one worker restore the outer number of threads while another
+//! worker is still running BLAS. [`with_num_threads_scoped`] handles this: all
+//! scopes with such a setter, in rayon workers or not, share one state; the
+//! first scope entering saves the outer value, and the last one leaving
+//! restores it.
+
+use core::cell::Cell;
+use core::sync::atomic::{AtomicU8, Ordering};
+use rayon::ThreadPool;
+use rstsr_core::prelude_dev::DeviceRayonAPI;
+use std::sync::Mutex;
 
 pub trait BlasThreadAPI {
     /// Set the number of threads used by the BLAS library.
@@ -17,4 +43,181 @@ pub trait BlasThreadAPI {
         self.set_blas_num_threads(n);
         result
     }
+    /// Number of threads for a BLAS call on this device in the current context.
+    ///
+    /// See [`blas_num_threads_budget`].
+    fn get_blas_num_threads_budget(&self) -> usize
+    where
+        Self: DeviceRayonAPI,
+    {
+        blas_num_threads_budget(self.get_current_pool())
+    }
+}
+
+/* #region nested policy */
+
+/// Number of BLAS threads when BLAS is called inside rayon workers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlasNestedPolicy {
+    /// BLAS runs with 1 thread.
+    #[default]
+    Serial,
+    /// BLAS runs with `available cores / rayon workers` threads (at least 1).
+    Share,
+}
+
+static NESTED_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Set the policy of BLAS threads inside rayon workers.
+///
+/// This function should be safe to call from multiple threads.
+pub fn set_blas_nested_policy(policy: BlasNestedPolicy) {
+    NESTED_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+pub fn get_blas_nested_policy() -> BlasNestedPolicy {
+    match NESTED_POLICY.load(Ordering::Relaxed) {
+        0 => BlasNestedPolicy::Serial,
+        _ => BlasNestedPolicy::Share,
+    }
+}
+
+/* #endregion */
+
+/* #region thread-local override */
+
+std::thread_local! {
+    static LOCAL_NUM_THREADS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Thread-local override of BLAS threads of the current thread.
+pub fn get_local_blas_num_threads() -> Option<usize> {
+    LOCAL_NUM_THREADS.with(|n| n.get())
+}
+
+/// Set (or clear by `None`) thread-local override of BLAS threads of the
+/// current thread.
+///
+/// The override is not inherited by rayon workers spawned from this thread.
+pub fn set_local_blas_num_threads(nthreads: Option<usize>) {
+    LOCAL_NUM_THREADS.with(|n| n.set(nthreads.map(|n| n.max(1))));
+}
+
+/// Scoped thread-local override of BLAS threads.
+///
+/// The previous override is restored when the guard is dropped. The guard is
+/// not [`Send`], since the override belongs to the thread creating it.
+#[must_use = "the override is reverted when the guard is dropped"]
+pub struct BlasNumThreadsGuard {
+    prev: Option<usize>,
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+impl BlasNumThreadsGuard {
+    pub fn new(nthreads: usize) -> Self {
+        let prev = get_local_blas_num_threads();
+        set_local_blas_num_threads(Some(nthreads));
+        Self { prev, _not_send: core::marker::PhantomData }
+    }
+}
+
+impl Drop for BlasNumThreadsGuard {
+    fn drop(&mut self) {
+        set_local_blas_num_threads(self.prev);
+    }
 }
+
+/// Run closure with thread-local override of BLAS threads.
+pub fn with_local_blas_num_threads<R>(nthreads: usize, f: impl FnOnce() -> R) -> R {
+    let _guard = BlasNumThreadsGuard::new(nthreads);
+    f()
+}
+
+/* #endregion */
+
+/* #region budget and scoped setter */
+
+/// Number of threads for a BLAS call.
+///
+/// `pool` is the thread pool from
+/// [`DeviceRayonAPI::get_current_pool`], which is `None` inside rayon workers.
+pub fn blas_num_threads_budget(pool: Option<&ThreadPool>) -> usize {
+    if let Some(n) = get_local_blas_num_threads() {
+        return n;
+    }
+    match pool {
+        Some(pool) => pool.current_num_threads(),
+        None => match get_blas_nested_policy() {
+            BlasNestedPolicy::Serial => 1,
+            BlasNestedPolicy::Share => {
+                let ncores = std::thread::available_parallelism().map_or(1, |n| n.get());
+                (ncores / rayon::current_num_threads()).max(1)
+            },
+        },
+    }
+}
+
+/// Scopes of [`with_num_threads_scoped`] with process-global setter.
+///
+/// Active scopes as `(id, nthreads)` in order of entering, the next id, and the
+/// number of threads to be restored when all of them have left.
+static NESTED_STATE: Mutex<(Vec<(u64, usize)>, u64, usize)> = Mutex::new((Vec::new(), 0, 0));
+
+struct RestoreOnDrop<F: FnMut()>(F);
+
+impl<F: FnMut()> Drop for RestoreOnDrop<F> {
+    fn drop(&mut self) {
+        (self.0)()
+    }
+}
+
+/// Set the number of BLAS threads in closure, safe for rayon nesting.
+///
+/// - `local`: whether `set` only affects the calling thread (MKL's local setter, OpenMP ICV). In
+///   this case, the value is simply set and restored.
+/// - Otherwise, every scope (inside rayon workers or not) is registered in one process-wide state.
+///   The outer value is saved by the first scope entering and restored by the last one leaving; a
+///   scope leaving earlier sets the value of the latest scope still active, so that threads still
+///   running BLAS are not affected.
+///
+/// The number of threads is restored also if `f` panics.
+pub fn with_num_threads_scoped<R>(
+    nthreads: usize,
+    local: bool,
+    get: impl Fn() -> usize,
+    set: impl Fn(usize),
+    f: impl FnOnce() -> R,
+) -> R {
+    if local {
+        let n = get();
+        if n == nthreads {
+            return f();
+        }
+        set(nthreads);
+        let _restore = RestoreOnDrop(|| set(n));
+        return f();
+    }
+
+    let id = {
+        let mut state = NESTED_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        if state.0.is_empty() {
+            state.2 = get();
+        }
+        let id = state.1;
+        state.1 += 1;
+        state.0.push((id, nthreads));
+        set(nthreads);
+        id
+    };
+    let _restore = RestoreOnDrop(|| {
+        let mut state = NESTED_STATE.lock().unwrap_or_else(|e| e.into_inner());
+        state.0.retain(|&(i, _)| i != id);
+        match state.0.last() {
+            Some(&(_, n)) => set(n),
+            None => set(state.2),
+        }
+    });
+    f()
+}
+
+/* #endregion */
@@ -1,7 +1,10 @@
 //! Convenient traits for BLAS operations.
 
+use crate::blas1::*;
+use crate::blas2::*;
 use crate::blas3::*;
 use crate::lapack_eigh::*;
+use crate::lapack_schur::*;
 use crate::lapack_solve::*;
 use crate::lapack_svd::*;
 use crate::prelude_dev::*;
@@ -13,6 +16,8 @@ pub trait BlasDriverBaseAPI<T>:
     + DeviceAPI<blas_int, Raw = Vec<blas_int>>
     + BlasThreadAPI
     + DeviceRayonAPI
+    // blas1 and blas2 functionality requirements
+    + DeviceComplexFloatAPI<T, Ix1>
     // lapacke functionality requirements
     + DeviceComplexFloatAPI<T, Ix2>
     + DeviceNumAPI<blas_int, Ix1>
@@ -32,7 +37,30 @@ where
 }
 
 pub trait BlasDriverAPI<T>:
-    BlasDriverBaseAPI<T> + GEMMDriverAPI<T> + TRSMDriverAPI<T> + SYHEMMDriverAPI<T, false> + SYHEMMDriverAPI<T, true>
+    BlasDriverBaseAPI<T>
+    // blas1
+    + AXPYDriverAPI<T>
+    + DOTDriverAPI<T, false>
+    + DOTDriverAPI<T, true>
+    + NRM2DriverAPI<T>
+    + SCALDriverAPI<T>
+    + IAMAXDriverAPI<T>
+    // blas2
+    + GEMVDriverAPI<T>
+    + SYHEMVDriverAPI<T, true>
+    + TRMVDriverAPI<T>
+    + GERDriverAPI<T, false>
+    + GERDriverAPI<T, true>
+    // blas3
+    + GEMMDriverAPI<T>
+    + SYHEMMDriverAPI<T, false>
+    + SYHEMMDriverAPI<T, true>
+    + SYRKDriverAPI<T>
+    + HERKDriverAPI<T>
+    + SYR2KDriverAPI<T>
+    + HER2KDriverAPI<T>
+    + TRMMDriverAPI<T>
+    + TRSMDriverAPI<T>
 where
     T: BlasFloat,
 {
@@ -44,18 +72,32 @@ pub trait LapackDriverAPI<T>:
     // lapack_eigh
     + SYEVDriverAPI<T>
     + SYEVDDriverAPI<T>
+    + SYEVRDriverAPI<T>
+    + SYEVXDriverAPI<T>
     + SYGVDriverAPI<T>
     + SYGVDDriverAPI<T>
+    + SYGVXDriverAPI<T>
     // lapack_solve
     + POTRFDriverAPI<T>
     + GESVDriverAPI<T>
     + GETRFDriverAPI<T>
     + GETRIDriverAPI<T>
+    + GEQRFDriverAPI<T>
+    + ORGQRDriverAPI<T>
     + SYSVDriverAPI<T, false>
     + SYSVDriverAPI<T, true>
     // lapack_svd
     + GESVDDriverAPI<T>
     + GESDDDriverAPI<T>
+    + GESVDXDriverAPI<T>
+    + GEJSVDriverAPI<T>
+    // lapack_schur
+    + GEESDriverAPI<T>
+    + GEHRDDriverAPI<T>
+    + ORGHRDriverAPI<T>
+    + GGESDriverAPI<T>
+    + TRSENDriverAPI<T>
+    + TRSYLDriverAPI<T>
 where
     T: BlasFloat,
 {
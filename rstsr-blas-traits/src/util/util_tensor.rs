@@ -1,3 +1,4 @@
+use crate::prelude_dev::ComplexFloat;
 use rstsr_core::prelude::*;
 use rstsr_core::prelude_dev::*;
 
@@ -56,6 +57,30 @@ where
 
 /* #endregion */
 
+/* #region vector increment */
+
+/// Convert vector to have positive increment, which is required by BLAS
+/// level-1 and level-2 functions.
+///
+/// Vector with positive stride is passed by view; otherwise a contiguous copy
+/// is made. Returns the (possibly copied) vector and its increment.
+pub fn positive_inc_convert<T, B>(x: TensorView<'_, T, B, Ix1>) -> Result<(TensorCow<'_, T, B, Ix1>, usize)>
+where
+    T: Clone,
+    B: DeviceAPI<T> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, Ix1, Ix1>,
+{
+    let inc = x.stride()[0];
+    if inc > 0 {
+        Ok((x.into_cow(), inc as usize))
+    } else if x.size() <= 1 {
+        Ok((x.into_cow(), 1))
+    } else {
+        Ok((change_contig_f(x, RowMajor)?, 1))
+    }
+}
+
+/* #endregion */
+
 /* #region flip */
 
 pub fn flip_trans<T, B>(
@@ -92,3 +117,31 @@ where
 }
 
 /* #endregion */
+
+/* #region symmetrize */
+
+/// Fill the triangle of a column-major square matrix that is opposite to
+/// `uplo`, making it a full symmetric (or hermitian if `HERMI`) matrix.
+///
+/// # Safety
+///
+/// `c` must point to a valid column-major `n x n` matrix with leading
+/// dimension `ldc`.
+pub unsafe fn fill_other_triangle<T, const HERMI: bool>(c: *mut T, n: usize, ldc: usize, uplo: FlagUpLo)
+where
+    T: ComplexFloat,
+{
+    for j in 0..n {
+        for i in (j + 1)..n {
+            // (i, j) is in lower triangle, (j, i) is in upper triangle
+            let (dst, src) = match uplo {
+                FlagUpLo::U => (i + j * ldc, j + i * ldc),
+                FlagUpLo::L => (j + i * ldc, i + j * ldc),
+            };
+            let val = *c.add(src);
+            *c.add(dst) = if HERMI { val.conj() } else { val };
+        }
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,25 @@
+use super::*;
+use rstsr_blas_traits::lapack_schur::*;
+
+#[test]
+fn test_gees_gges_lowercase_job_row_major() {
+    let device = DeviceType::default();
+    let n = 5;
+    let a =
+        linspace((-1.0, 2.0, n * n, &device)).mapv(|x: f64| (3.0 * x * x).sin()).into_shape([n, n]).into_dim::<Ix2>();
+    let b =
+        linspace((1.0, 3.0, n * n, &device)).mapv(|x: f64| (2.0 * x * x).cos()).into_shape([n, n]).into_dim::<Ix2>();
+    assert!(a.c_prefer() && b.c_prefer());
+
+    // job flags are accepted in either case; lowercase must also compute the Schur vectors
+    let (t, _, _, z) = DGEES::default().a(a.view()).jobvs('v').build().unwrap().run().unwrap();
+    let (t, z) = (t.into_owned(), z.unwrap());
+    assert!((z.t() % &z - eye((n, &device))).abs().max_all() < 1e-10);
+    assert!((&z % &t % z.t() - &a).abs().max_all() < 1e-10);
+
+    let (s, t, _, _, _, q, z) =
+        DGGES::default().a(a.view()).b(b.view()).jobvsl('v').jobvsr('v').build().unwrap().run().unwrap();
+    let (s, t, q, z) = (s.into_owned(), t.into_owned(), q.unwrap(), z.unwrap());
+    assert!((&q % &s % z.t() - &a).abs().max_all() < 1e-10);
+    assert!((&q % &t % z.t() - &b).abs().max_all() < 1e-10);
+}
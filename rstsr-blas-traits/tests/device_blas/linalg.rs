@@ -0,0 +1,30 @@
+use super::*;
+use rstsr_linalg_traits::prelude::rstsr_funcs::*;
+use rstsr_linalg_traits::prelude::rstsr_structs::*;
+
+#[test]
+fn test_svd_partial_u_only() {
+    let device = DeviceType::default();
+    let a = linspace((-1.0, 2.0, 40, &device)).mapv(|x: f64| (3.0 * x * x).sin()).into_shape([8, 5]).into_dim::<Ix2>();
+    let s_ref = svdvals(a.view());
+
+    // GESVD with VT skipped, in both shapes of U
+    for full_matrices in [true, false] {
+        let args = SVDArgs::default().a(a.view()).full_matrices(full_matrices).compute_u_only(true);
+        let (u, s, vt) = svd_partial(args).into();
+        let u = u.unwrap();
+        assert!(vt.is_none());
+        assert_eq!(u.shape(), &[8, if full_matrices { 8 } else { 5 }]);
+        assert!((&s - &s_ref).abs().max_all() < 1e-10);
+        let u = u.i((.., ..5));
+        assert!((&u % u.t() % &a - &a).abs().max_all() < 1e-10);
+    }
+
+    // GEJSV on request
+    let args = SVDArgs::default().a(a.view()).full_matrices(false).compute_u_only(true).driver("gejsv");
+    let (u, s, vt) = svd_partial(args).into();
+    let u = u.unwrap();
+    assert!(vt.is_none());
+    assert!((&s - &s_ref).abs().max_all() < 1e-10);
+    assert!((&u % u.t() % &a - &a).abs().max_all() < 1e-10);
+}
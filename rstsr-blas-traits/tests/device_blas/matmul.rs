@@ -0,0 +1,320 @@
+use super::*;
+use num::complex::Complex;
+
+#[test]
+fn test_matmul() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 14.0, 15, &device)).into_shape([3, 5]);
+    let b = linspace((0.0, 14.0, 15, &device)).into_shape([5, 3]);
+    println!("{:}", &a % &b);
+
+    let a = linspace((0.0, 14.0, 15, &device));
+    let b = linspace((0.0, 14.0, 15, &device));
+    println!("{:}", &a % &b);
+
+    let a = linspace((0.0, 2.0, 3, &device));
+    let b = linspace((0.0, 29.0, 30, &device)).into_shape([2, 3, 5]);
+    println!("{:}", &a % &b);
+
+    let a = linspace((0.0, 29.0, 30, &device)).into_shape([2, 3, 5]);
+    let b = linspace((0.0, 4.0, 5, &device));
+    println!("{:}", &a % &b);
+
+    let a = linspace((0.0, 14.0, 15, &device)).into_shape([5, 3]);
+    let b = linspace((0.0, 29.0, 30, &device)).into_shape([2, 3, 5]);
+    println!("{:}", &a % &b);
+
+    let a = linspace((0.0, 29.0, 30, &device)).into_shape([2, 3, 5]);
+    let b = linspace((0.0, 14.0, 15, &device)).into_shape([5, 3]);
+    println!("{:}", &a % &b);
+}
+
+#[test]
+#[ignore]
+fn parallel_test_full() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([8192, 8192]);
+    let b = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([8192, 8192]);
+    for _ in 0..10 {
+        let start = std::time::Instant::now();
+        let _ = &a % &b;
+        println!("time: {:?}", start.elapsed());
+    }
+}
+
+#[test]
+#[ignore]
+fn parallel_test_full_512() {
+    let device = DeviceType::new(1);
+    let a = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    let b = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    for _ in 0..1000 {
+        let start = std::time::Instant::now();
+        let c = &a % &b;
+        println!("{:?}", c.device());
+        println!("time: {:?}", start.elapsed());
+    }
+}
+
+#[test]
+#[ignore]
+fn parallel_test_par_rule7() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([256, 512, 512]);
+    let b = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([256, 512, 512]);
+    for i in 0..10 {
+        let start = std::time::Instant::now();
+        let c = &a % &b;
+        println!("{:?}", c.layout());
+        println!("time: {:?}", start.elapsed());
+        if i == 0 {
+            println!("{c:?}");
+        }
+    }
+}
+
+#[test]
+#[ignore]
+fn parallel_test_par_rule6() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([256, 512, 512]);
+    let b = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    for i in 0..10 {
+        let start = std::time::Instant::now();
+        let c = &a % &b;
+        println!("{:?}", c.layout());
+        println!("time: {:?}", start.elapsed());
+        if i == 0 {
+            println!("{c:?}");
+        }
+    }
+}
+
+#[test]
+#[ignore]
+fn parallel_test_par_rule6_fprefer() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([512, 512, 256]).into_reverse_axes();
+    let b = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    for i in 0..10 {
+        let start = std::time::Instant::now();
+        let c = &a % &b;
+        println!("{:?}", c.layout());
+        println!("time: {:?}", start.elapsed());
+        if i == 0 {
+            println!("{c:?}");
+        }
+    }
+}
+
+#[test]
+fn syrk_correctness() {
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    let b = linspace((0.0, 1.0, 512 * 512, &device)).into_shape([512, 512]);
+    let c = &a % &a.t();
+    let d = &a % &b.t();
+    assert!(allclose_f64(&c, &d));
+
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 1024 * 1024, &device)).into_shape([4, 512, 512]);
+    let b = linspace((0.0, 1.0, 1024 * 1024, &device)).into_shape([4, 512, 512]);
+    let c = &a % &a.swapaxes(-1, -2);
+    let d = &a % &b.swapaxes(-1, -2);
+    assert!(allclose_f64(&c, &d));
+}
+
+#[test]
+fn test_matmul_rule7_broadcast() {
+    // rule 7 with batch broadcasting: the batch (`rest`) dims of A and B
+    // must broadcast against C's batch dims. Previously A/B batch layouts
+    // were not broadcast, so e.g. `[1, M, K] @ [B, K, N]` panicked instead
+    // of producing `[B, M, N]`.
+    let device = DeviceType::default();
+
+    // A broadcasts on the batch axis: [1, M, K] @ [B, K, N] -> [B, M, N]
+    let a = linspace((0.0, 14.0, 15, &device)).into_shape([1, 3, 5]);
+    let b = linspace((0.0, 29.0, 30, &device)).into_shape([2, 5, 3]);
+    let c = &a % &b;
+    assert_eq!(c.shape(), &[2, 3, 3]);
+    let a_big = a.to_broadcast(vec![2, 3, 5]);
+    let c_ref = &a_big % &b;
+    assert!(allclose_f64(&c, &c_ref));
+
+    // B broadcasts on the batch axis: [B, M, K] @ [1, K, N] -> [B, M, N]
+    let a = linspace((0.0, 29.0, 30, &device)).into_shape([2, 3, 5]);
+    let b = linspace((0.0, 14.0, 15, &device)).into_shape([1, 5, 3]);
+    let c = &a % &b;
+    assert_eq!(c.shape(), &[2, 3, 3]);
+    let b_big = b.to_broadcast(vec![2, 5, 3]);
+    let c_ref = &a % &b_big;
+    assert!(allclose_f64(&c, &c_ref));
+}
+
+#[test]
+fn test_matmul_gemv() {
+    // matrix-vector products are dispatched to GEMV; compare with matrix
+    // products where the vector is regarded as a column/row matrix.
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 35, &device)).into_shape([7, 5]);
+    let x = linspace((-1.0, 1.0, 5, &device));
+    let z = linspace((-1.0, 1.0, 7, &device));
+
+    // c-prefer, f-prefer and strided matrix
+    let c_ref = (&a % x.i((.., None))).into_shape([7]);
+    assert!(allclose_f64(&(&a % &x), &c_ref));
+    let a_f = a.to_contig(ColMajor);
+    assert!(allclose_f64(&(&a_f % &x), &c_ref));
+    let a_big = linspace((0.0, 1.0, 140, &device)).into_shape([14, 10]);
+    let a_s = a_big.slice((slice!(None, None, 2), slice!(None, None, 2)));
+    let c_ref_s = (&a_s % x.i((.., None))).into_shape([7]);
+    assert!(allclose_f64(&(&a_s % &x), &c_ref_s));
+
+    // vector-matrix, and negative stride of vector
+    let c_ref = (z.i((None, ..)) % &a).into_shape([5]);
+    assert!(allclose_f64(&(&z % &a), &c_ref));
+    let x_rev = x.flip(0);
+    let c_ref = (&a % x_rev.i((.., None))).into_shape([7]);
+    assert!(allclose_f64(&(&a % &x_rev), &c_ref));
+
+    // non-BLAS type falls back to naive implementation
+    let a = arange((35, &device)).into_shape([7, 5]);
+    let x = arange((5, &device));
+    let c_ref = (&a % x.i((.., None))).into_shape([7]);
+    assert_eq!((&a % &x).raw(), c_ref.raw());
+}
+
+#[test]
+fn test_matmul_conj() {
+    // lazily conjugated operands should give the same result as materialized
+    // conjugates, for all layouts that BLAS handles with or without copy.
+    use num::complex::Complex;
+    let device = DeviceType::default();
+    let c = linspace((Complex::new(0.0, 1.0), Complex::new(1.0, -2.0), 35, &device)).into_shape([7, 5]);
+    let s = linspace((Complex::new(1.0, 0.5), Complex::new(-1.0, 2.0), 49, &device)).into_shape([7, 7]);
+    let c_conj = c.view().conj();
+    let close = |x: &Tensor<Complex<f64>, DeviceType, _>, y: &Tensor<Complex<f64>, DeviceType, _>| {
+        (x - y).abs().max_all() < 1e-10
+    };
+
+    // C^H S C, with c-prefer and f-prefer C
+    let c_ref = &c_conj.t() % &s % &c;
+    assert!(close(&(c.h() % &s % &c), &c_ref));
+    let c_f = c.to_contig(ColMajor);
+    assert!(close(&(c_f.h() % &s % &c_f), &c_ref));
+
+    // conj(C) without transpose, and strided C
+    let c_ref = &s % &c_conj;
+    assert!(close(&(&s % c.conj_view()), &c_ref));
+    assert!(close(&(&s % c_f.conj_view()), &c_ref));
+    let c_big = linspace((Complex::new(0.0, 1.0), Complex::new(1.0, -2.0), 140, &device)).into_shape([14, 10]);
+    let c_s = c_big.slice((slice!(None, None, 2), slice!(None, None, 2)));
+    let c_ref = c_s.view().conj().t() % c_s.view().conj();
+    assert!(close(&(c_s.h() % c_s.conj_view()), &c_ref));
+
+    // broadcasted and vector cases fall back to copy
+    let c3 = c.to_shape([1, 7, 5]);
+    let c_ref = c3.view().conj().swapaxes(-1, -2) % &s;
+    assert!(close(&(c3.conj_view().swapaxes(-1, -2) % &s), &c_ref));
+    let v = c.i(0);
+    let c_ref = v.view().conj() % &v;
+    assert!(close(&(v.conj_view() % &v), &c_ref));
+}
+
+#[test]
+fn test_matmul_half() {
+    // half-precision operands are accumulated in f32; accumulation in f16 would stall at 256, where the
+    // spacing of f16 numbers (0.25) exceeds 0.1.
+    use rstsr_blas_traits::prelude_dev::f16;
+    let device = DeviceType::default();
+    let a = full(([3, 4096], f16::from_f64(0.1), &device));
+    let b = full(([4096, 2], f16::from_f64(1.0), &device));
+    let c_ref = 4096.0 * f16::from_f64(0.1).to_f64();
+    let c = &a % &b;
+    assert!(c.iter().all(|x| (x.to_f64() - c_ref).abs() < 0.5));
+
+    // inner dot, gemv and gevm
+    let c = a.i(0) % b.i((.., 0));
+    assert!((c.to_scalar().to_f64() - c_ref).abs() < 0.5);
+    let c = &a % b.i((.., 0));
+    assert!(c.iter().all(|x| (x.to_f64() - c_ref).abs() < 0.5));
+    let c = a.i(0) % &b;
+    assert!(c.iter().all(|x| (x.to_f64() - c_ref).abs() < 0.5));
+
+    // general layouts, compared to f64 matmul of the same (rounded) inputs
+    let a = linspace((-1.0, 1.0, 35, &device)).into_shape([7, 5]).mapv(f16::from_f64);
+    let b = linspace((-1.0, 1.0, 30, &device)).into_shape([6, 5]).mapv(f16::from_f64);
+    let c = &a % b.t();
+    let c_ref = a.mapv(|x| x.to_f64()) % b.mapv(|x| x.to_f64()).t();
+    assert!(c.iter().zip(c_ref.iter()).all(|(x, y)| (x.to_f64() - y).abs() < 1e-2));
+}
+
+#[test]
+#[ignore]
+fn syrk_efficiency() {
+    use std::hint::black_box;
+    let device = DeviceType::default();
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([256, 512, 512]);
+    let b = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([256, 512, 512]);
+    for _ in 0..10 {
+        let start = std::time::Instant::now();
+        black_box(&a % &a.swapaxes(-1, -2));
+        println!("syrk time: {:?}", start.elapsed());
+        let start = std::time::Instant::now();
+        black_box(&a % &b.swapaxes(-1, -2));
+        println!("gemm time: {:?}", start.elapsed());
+    }
+
+    println!("---------------------");
+    let a = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([8192, 8192]);
+    let b = linspace((0.0, 1.0, 8192 * 8192, &device)).into_shape([8192, 8192]);
+    for _ in 0..10 {
+        let start = std::time::Instant::now();
+        black_box(&a % &a.swapaxes(-1, -2));
+        println!("syrk time: {:?}", start.elapsed());
+        let start = std::time::Instant::now();
+        black_box(&a % &b.swapaxes(-1, -2));
+        println!("gemm time: {:?}", start.elapsed());
+    }
+}
+
+#[test]
+fn test_syrk_herk() {
+    let device = DeviceType::default();
+    let n = 5;
+    // `a` is transposed, so that it is not c-contiguous
+    let a = linspace((-1.0, 1.0, 15, &device)).into_shape([3, n]).into_dim::<Ix2>().into_reverse_axes();
+    let c0 = linspace((0.0, 2.0, n * n, &device)).into_shape([n, n]).into_dim::<Ix2>();
+    // only the `uplo` triangle is updated
+    let in_triangle = |uplo: FlagUpLo, i: usize, j: usize| if uplo == Lower { i >= j } else { i <= j };
+
+    for uplo in [Lower, Upper] {
+        let mut c = c0.to_owned();
+        let lc = c.layout().clone();
+        device.syrk(c.raw_mut(), &lc, a.raw(), a.layout(), uplo, 2.0, 0.5).unwrap();
+        let c_ref: Tensor<f64, DeviceType, Ix2> = 2.0 * (&a % a.t()) + 0.5 * &c0;
+        for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            let expect = if in_triangle(uplo, i, j) { c_ref[[i, j]] } else { c0[[i, j]] };
+            assert!((c[[i, j]] - expect).abs() < 1e-12);
+        }
+    }
+
+    let z = a.mapv(|x| Complex::new(x, 1.0 - x));
+    let w0 = c0.mapv(|x| Complex::new(x, 0.0));
+    let (alpha, beta) = (Complex::new(2.0, 0.0), Complex::new(0.5, 0.0));
+    for uplo in [Lower, Upper] {
+        let mut w = w0.to_owned();
+        let lw = w.layout().clone();
+        device.herk(w.raw_mut(), &lw, z.raw(), z.layout(), uplo, alpha, beta).unwrap();
+        let w_ref: Tensor<Complex<f64>, DeviceType, Ix2> = alpha * (&z % z.t().mapv(|x| x.conj())) + beta * &w0;
+        for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            let expect = if in_triangle(uplo, i, j) { w_ref[[i, j]] } else { w0[[i, j]] };
+            assert!((w[[i, j]] - expect).norm() < 1e-12);
+        }
+    }
+
+    // HERK with complex scalars is rejected
+    let mut w = w0.to_owned();
+    let lw = w.layout().clone();
+    assert!(device.herk(w.raw_mut(), &lw, z.raw(), z.layout(), Lower, Complex::new(1.0, 1.0), beta).is_err());
+}
@@ -0,0 +1,72 @@
+use super::*;
+use num::complex::Complex;
+use rstsr_blas_traits::device_blas::matmul_impl::*;
+
+#[allow(non_camel_case_types)]
+type c32 = Complex<f32>;
+
+#[test]
+fn test_f32() {
+    let a = vec![1., 2., 3., 4., 5., 6.];
+    let b = vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.];
+    let mut c = vec![0.0; 16];
+
+    let la = [2, 3].c();
+    let lb = [3, 4].c();
+    let lc = [2, 4].c();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(16).build().unwrap();
+    let pool = Some(&pool);
+    gemm_blas_no_conj_f32::<BackendType>(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+    let c_tsr = TensorView::new(asarray(&c).into_raw_parts().0, lc);
+    println!("{c_tsr:}");
+    println!("{:}", c_tsr.reshape([8]));
+    let c_ref = asarray(vec![38., 44., 50., 56., 83., 98., 113., 128.]);
+    assert!(allclose_f64(&c_tsr.map(|v| *v as f64), &c_ref));
+
+    let la = [2, 3].c();
+    let lb = [3, 4].c();
+    let lc = [2, 4].f();
+    gemm_blas_no_conj_f32::<BackendType>(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+    let c_tsr = TensorView::new(asarray(&c).into_raw_parts().0, lc);
+    println!("{c_tsr:}");
+    println!("{:}", c_tsr.reshape([8]));
+    let c_ref = asarray(vec![38., 44., 50., 56., 83., 98., 113., 128.]);
+    assert!(allclose_f64(&c_tsr.map(|v| *v as f64), &c_ref));
+
+    let la = [2, 3].f();
+    let lb = [3, 4].c();
+    let lc = [2, 4].c();
+    gemm_blas_no_conj_f32::<BackendType>(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+    let c_tsr = TensorView::new(asarray(&c).into_raw_parts().0, lc);
+    println!("{c_tsr:}");
+    println!("{:}", c_tsr.reshape([8]));
+    let c_ref = asarray(vec![61., 70., 79., 88., 76., 88., 100., 112.]);
+    assert!(allclose_f64(&c_tsr.map(|v| *v as f64), &c_ref));
+
+    let la = [2, 3].f();
+    let lb = [3, 4].c();
+    let lc = [2, 4].f();
+    gemm_blas_no_conj_f32::<BackendType>(&mut c, &lc, &a, &la, &b, &lb, 2.0, 0.0, pool).unwrap();
+    let c_tsr = TensorView::new(asarray(&c).into_raw_parts().0, lc);
+    println!("{c_tsr:}");
+    println!("{:}", c_tsr.reshape([8]));
+    let c_ref = 2 * asarray(vec![61., 70., 79., 88., 76., 88., 100., 112.]);
+    assert!(allclose_f64(&c_tsr.map(|v| *v as f64), &c_ref));
+}
+
+#[test]
+fn test_c32() {
+    let a = linspace((c32::new(1., 1.), c32::new(6., 6.), 6)).into_vec();
+    let b = linspace((c32::new(1., 1.), c32::new(12., 12.), 12)).into_vec();
+    let mut c = vec![c32::ZERO; 16];
+
+    let la = [2, 3].c();
+    let lb = [3, 4].c();
+    let lc = [2, 4].c();
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(16).build().unwrap();
+    let pool = Some(&pool);
+    gemm_blas_no_conj_c32::<BackendType>(&mut c, &lc, &a, &la, &b, &lb, c32::ONE, c32::ZERO, pool).unwrap();
+    let c_tsr = TensorView::new(asarray(&c).into_raw_parts().0, lc);
+    println!("{c_tsr:}");
+    println!("{:}", c_tsr.reshape([8]));
+}
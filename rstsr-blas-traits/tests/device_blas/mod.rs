@@ -0,0 +1,18 @@
+//! Tests of [`DeviceBLAS`] shared by all BLAS backends.
+//!
+//! The test entry of each backend crate should define `BackendType` (the
+//! backend marker type) before declaring this module.
+
+pub mod conversion;
+pub mod lapack_schur;
+#[cfg(feature = "linalg")]
+pub mod linalg;
+pub mod matmul;
+pub mod matmul_impl;
+pub mod threading;
+
+pub use crate::BackendType;
+pub use rstsr_blas_traits::device_blas::DeviceBLAS;
+pub use rstsr_core::prelude_dev::*;
+
+pub type DeviceType = DeviceBLAS<BackendType>;
@@ -0,0 +1,146 @@
+//! BLAS threads with rayon nesting.
+//!
+//! For backends with per-thread setting (MKL, OpenMP-built OpenBLAS), and with
+//! process-global setting (pthread-built OpenBLAS, BLIS), BLAS called inside
+//! rayon workers should run with the nested budget during the whole call, and
+//! the number of threads outside should be restored afterwards.
+
+use super::*;
+use rayon::prelude::*;
+use rstsr_blas_traits::threading::*;
+use std::sync::{Barrier, Mutex};
+
+/// Tests here change process-wide BLAS threading, so they are serialized.
+static LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn test_nested_no_oversubscription() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let device = DeviceType::new(4);
+    assert_eq!(device.get_blas_num_threads_budget(), 4);
+
+    let n_orig = device.get_blas_num_threads();
+    device.set_blas_num_threads(3);
+    let n_outer = device.get_blas_num_threads();
+
+    // worker 0 enters BLAS scope after, and leaves after the others; the others
+    // leaving should not restore the outer number of threads
+    let (enter, leave, left) = (Barrier::new(4), Barrier::new(4), Barrier::new(4));
+    device.get_pool().broadcast(|ctx| {
+        let observer = ctx.index() == 0;
+        if observer {
+            enter.wait();
+        }
+        device.with_blas_num_threads(device.get_blas_num_threads_budget(), || {
+            if !observer {
+                enter.wait();
+            }
+            leave.wait();
+            if observer {
+                left.wait();
+                assert_eq!(device.get_blas_num_threads(), 1);
+            }
+        });
+        if !observer {
+            left.wait();
+        }
+    });
+    assert_eq!(device.get_blas_num_threads(), n_outer);
+
+    let a = asarray((vec![1.0; 64 * 64], [64, 64].c(), &device));
+    let c_ref = asarray((vec![64.0; 64 * 64], [64, 64].c(), &device));
+    device.get_pool().install(|| {
+        (0..64).into_par_iter().for_each(|_| {
+            let nthreads = device.get_blas_num_threads_budget();
+            assert_eq!(nthreads, 1);
+            device.with_blas_num_threads(nthreads, || {
+                for _ in 0..8 {
+                    assert_eq!(device.get_blas_num_threads(), 1);
+                    let c = &a % &a;
+                    assert!((c - &c_ref).abs().max_all() < 1e-10);
+                }
+            });
+        })
+    });
+    assert_eq!(device.get_blas_num_threads(), n_outer);
+    device.set_blas_num_threads(n_orig);
+}
+
+#[test]
+fn test_local_override() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let device = DeviceType::new(4);
+    {
+        let _guard = BlasNumThreadsGuard::new(2);
+        assert_eq!(device.get_blas_num_threads_budget(), 2);
+        with_local_blas_num_threads(1, || assert_eq!(device.get_blas_num_threads_budget(), 1));
+        assert_eq!(device.get_blas_num_threads_budget(), 2);
+        // override is not inherited by rayon workers
+        device.get_pool().install(|| {
+            (0..16).into_par_iter().for_each(|_| assert_eq!(device.get_blas_num_threads_budget(), 1));
+        });
+    }
+    assert_eq!(get_local_blas_num_threads(), None);
+    assert_eq!(device.get_blas_num_threads_budget(), 4);
+}
+
+#[test]
+fn test_nested_policy_share() {
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let device = DeviceType::new(2);
+    let ncores = std::thread::available_parallelism().map_or(1, |n| n.get());
+    set_blas_nested_policy(BlasNestedPolicy::Share);
+    device.get_pool().install(|| {
+        (0..16).into_par_iter().for_each(|_| assert_eq!(device.get_blas_num_threads_budget(), (ncores / 2).max(1)));
+    });
+    set_blas_nested_policy(BlasNestedPolicy::Serial);
+    assert_eq!(get_blas_nested_policy(), BlasNestedPolicy::Serial);
+}
+
+#[test]
+fn test_scoped_global_setter_contention() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let _lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    // process-global setter, as pthread-built OpenBLAS or BLIS
+    static GLOBAL: AtomicUsize = AtomicUsize::new(8);
+    let scoped = |nthreads: usize, f: &dyn Fn()| {
+        with_num_threads_scoped(
+            nthreads,
+            false,
+            || GLOBAL.load(Ordering::SeqCst),
+            |n| GLOBAL.store(n, Ordering::SeqCst),
+            f,
+        )
+    };
+
+    // sequential nesting on one thread
+    scoped(4, &|| {
+        scoped(2, &|| assert_eq!(GLOBAL.load(Ordering::SeqCst), 2));
+        assert_eq!(GLOBAL.load(Ordering::SeqCst), 4);
+    });
+    assert_eq!(GLOBAL.load(Ordering::SeqCst), 8);
+
+    // a thread outside rayon enters before the workers, and leaves while they
+    // are still running; it should not restore the outer number of threads
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(3).build().unwrap();
+    let (outside_in, workers_in, outside_out) = (Barrier::new(4), Barrier::new(4), Barrier::new(4));
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            scoped(2, &|| {
+                outside_in.wait();
+                workers_in.wait();
+            });
+            outside_out.wait();
+        });
+        pool.broadcast(|_| {
+            outside_in.wait();
+            scoped(1, &|| {
+                workers_in.wait();
+                outside_out.wait();
+                assert_eq!(GLOBAL.load(Ordering::SeqCst), 1);
+            });
+        });
+    });
+    assert_eq!(GLOBAL.load(Ordering::SeqCst), 8);
+}
@@ -496,6 +496,25 @@ where
         stride.as_mut().swap(axis1, axis2);
         return unsafe { Ok(Layout::new_unchecked(shape, stride, self.offset)) };
     }
+
+    /// Layout of the `index`-th component, when each element is reinterpreted as `n` contiguous
+    /// sub-elements (e.g. `Complex<T>` as `[T; 2]`).
+    ///
+    /// Strides and offset are scaled by `n`, so this works for any memory order.
+    pub fn reinterpret_elem(&self, n: usize, index: usize) -> Result<Self> {
+        rstsr_assert!(index < n, InvalidValue, "index {index} should be smaller than number of sub-elements {n}.")?;
+        let n_isize = n as isize;
+        let mut stride = self.stride().clone();
+        for s in stride.as_mut().iter_mut() {
+            *s = s.checked_mul(n_isize).ok_or_else(|| rstsr_error!(InvalidLayout, "stride overflow."))?;
+        }
+        let offset = self
+            .offset
+            .checked_mul(n)
+            .and_then(|o| o.checked_add(index))
+            .ok_or_else(|| rstsr_error!(InvalidLayout, "offset overflow."))?;
+        return unsafe { Ok(Layout::new_unchecked(self.shape().clone(), stride, offset)) };
+    }
 }
 
 /// Fast indexing and utilities of layout.
@@ -1004,4 +1023,15 @@ mod test {
         assert_eq!(indexed.shape(), &[10, 3, 12]);
         assert_eq!(indexed.stride(), &[132, -48, 1]);
     }
+
+    #[test]
+    fn test_reinterpret_elem() {
+        let layout = Layout::new([3, 2], [1, -6], 7).unwrap();
+        let re = layout.reinterpret_elem(2, 0).unwrap();
+        let im = layout.reinterpret_elem(2, 1).unwrap();
+        assert_eq!(re.stride(), &[2, -12]);
+        assert_eq!((re.offset(), im.offset()), (14, 15));
+        assert_eq!(im.bounds_index().unwrap(), (3, 20));
+        assert!(layout.reinterpret_elem(2, 2).is_err());
+    }
 }
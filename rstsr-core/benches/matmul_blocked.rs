@@ -0,0 +1,51 @@
+//! Benchmarks of cache-blocked generic matmul, compared to the naive triple
+//! loop.
+//!
+//! Run with `cargo bench -p rstsr-core --bench matmul_blocked`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rstsr_core::prelude_dev::*;
+
+fn bench_gemm<T>(c: &mut Criterion, name: &str, n: usize, f: impl Fn(usize) -> T)
+where
+    T: Clone + Send + Sync + core::ops::Mul<Output = T> + core::ops::Add<Output = T> + num::Zero + num::One,
+{
+    let l = Layout::new([n, n], [n as isize, 1], 0).unwrap();
+    let a = (0..n * n).map(&f).collect::<Vec<_>>();
+    let b = (0..n * n).map(|i| f(i + 7)).collect::<Vec<_>>();
+    let mut out = vec![T::zero(); n * n];
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+    group.bench_function(BenchmarkId::new("naive (serial)", n), |bench| {
+        bench.iter(|| gemm_naive_cpu_serial(&mut out, &l, &a, &l, &b, &l, T::one(), T::zero()).unwrap())
+    });
+    group.bench_function(BenchmarkId::new("blocked (serial)", n), |bench| {
+        bench.iter(|| gemm_blocked_cpu_serial(&mut out, &l, &a, &l, &b, &l, T::one(), T::zero()).unwrap())
+    });
+    #[cfg(feature = "rayon")]
+    {
+        let pool = rayon::ThreadPoolBuilder::new().build().unwrap();
+        group.bench_function(BenchmarkId::new("naive (rayon)", n), |bench| {
+            bench.iter(|| {
+                gemm_ix2_naive_cpu_rayon(&mut out, &l, &a, &l, &b, &l, T::one(), T::zero(), Some(&pool)).unwrap()
+            })
+        });
+        group.bench_function(BenchmarkId::new("blocked (rayon)", n), |bench| {
+            bench.iter(|| {
+                gemm_blocked_cpu_rayon(&mut out, &l, &a, &l, &b, &l, T::one(), T::zero(), Some(&pool)).unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_matmul_blocked(c: &mut Criterion) {
+    for n in [512, 1024] {
+        bench_gemm::<i64>(c, "i64", n, |i| (i % 17) as i64 - 8);
+    }
+    bench_gemm::<num::Complex<i64>>(c, "Complex<i64>", 512, |i| num::Complex::new((i % 17) as i64 - 8, (i % 5) as i64));
+}
+
+criterion_group!(benches, bench_matmul_blocked);
+criterion_main!(benches);
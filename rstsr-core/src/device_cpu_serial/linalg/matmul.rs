@@ -3,6 +3,8 @@
 //! **This implementation is not optimized!**
 
 use core::ops::{Add, Mul};
+use num::complex::ComplexFloat;
+use num::Zero;
 
 use crate::prelude_dev::*;
 
@@ -16,7 +18,7 @@ where
     DC: DimAPI,
     TA: Mul<TB, Output = TC>,
     TB: Mul<TA, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
     Self: DeviceAPI<TA, Raw = Vec<TA>> + DeviceAPI<TB, Raw = Vec<TB>> + DeviceAPI<TC, Raw = Vec<TC>>,
 {
     fn matmul(
@@ -43,6 +45,37 @@ where
     }
 }
 
+impl<T, DA, DB, DC> DeviceMatMulConjAPI<T, DA, DB, DC> for DeviceCpuSerial
+where
+    T: ComplexFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    Self: DeviceAPI<T, Raw = Vec<T>>,
+{
+    fn matmul_conj(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<DC>,
+        a: &Vec<T>,
+        la: &Layout<DA>,
+        conja: bool,
+        b: &Vec<T>,
+        lb: &Layout<DB>,
+        conjb: bool,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        // naive kernels do not handle conjugation, so conjugated operands are copied
+        let default_order = self.default_order();
+        let a_conj = if conja { Some(conj_to_contig_cpu_serial(a, la, default_order)?) } else { None };
+        let b_conj = if conjb { Some(conj_to_contig_cpu_serial(b, lb, default_order)?) } else { None };
+        let (a, la) = a_conj.as_ref().map_or((a, la), |(a, la)| (a, la));
+        let (b, lb) = b_conj.as_ref().map_or((b, lb), |(b, lb)| (b, lb));
+        self.matmul(c, lc, a, la, b, lb, alpha, beta)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -103,4 +136,59 @@ mod test {
         ));
         assert!((&c.reshape(-1) - c_ref).l2_norm() < 1e-10);
     }
+
+    #[test]
+    fn test_beta_zero_ignores_output() {
+        // output is not read when beta is zero (may be uninitialized)
+        let device = DeviceCpuSerial::default();
+        let a = rt::linspace((1.0, 24.0, 24, &device)).into_shape((2, 3, 4));
+        let b = rt::linspace((1.0, 20.0, 20, &device)).into_shape((4, 5));
+        let mut c = rt::full(([2, 3, 5], f64::NAN, &device));
+        let la = a.layout();
+        let lb = b.layout();
+        let lc = c.layout().clone();
+        device.matmul(c.raw_mut(), &lc, a.raw(), la, b.raw(), lb, 1.0, 0.0).unwrap();
+        assert!((&c - (&a % &b)).l2_norm() < 1e-10);
+
+        let x = rt::linspace((1.0, 4.0, 4, &device));
+        let mut c = rt::full(([], f64::NAN, &device));
+        let lc = c.layout().clone();
+        device.matmul(c.raw_mut(), &lc, x.raw(), x.layout(), x.raw(), x.layout(), 1.0, 0.0).unwrap();
+        assert_eq!(c.to_scalar(), 30.0);
+    }
+
+    #[test]
+    fn test_matmul_blocked() {
+        // sizes cross the block sizes (MC = 64, KC = 256) and leave edge micro-tiles
+        #[cfg(not(feature = "col_major"))]
+        {
+            let device = DeviceCpuSerial::default();
+            let (m, n, k) = (131, 70, 300);
+            let a = rt::arange(((m * k) as i64, &device)).into_shape([k, m]).into_reverse_axes();
+            let a = a.mapv(|x| x % 17 - 8).into_dim::<Ix2>();
+            let b = rt::arange(((2 * k * n) as i64, &device)).into_shape([2, k, n]).mapv(|x| x % 13 - 6);
+            let b = b.slice((.., .., slice!(None, None, -1)));
+
+            // reference by naive implementation, for each batch
+            let c = &a % &b;
+            for i in 0..2 {
+                let b_i = b.i(i).into_dim::<Ix2>();
+                let mut c_ref = rt::zeros(([m, n], &device)).into_dim::<Ix2>();
+                let lc = c_ref.layout().clone();
+                gemm_naive_cpu_serial(c_ref.raw_mut(), &lc, a.raw(), a.layout(), b_i.raw(), b_i.layout(), 1, 0)
+                    .unwrap();
+                assert_eq!(c.i(i).reshape(-1).to_vec(), c_ref.reshape(-1).to_vec());
+            }
+
+            // alpha and beta
+            let b_0 = b.i(0).into_dim::<Ix2>();
+            let mut c = rt::arange(((m * n) as i64, &device)).into_shape([m, n]).into_dim::<Ix2>();
+            let mut c_ref = c.to_owned();
+            let (lc, lc_ref) = (c.layout().clone(), c_ref.layout().clone());
+            device.matmul(c.raw_mut(), &lc, a.raw(), a.layout(), b_0.raw(), b_0.layout(), 3, -2).unwrap();
+            gemm_naive_cpu_serial(c_ref.raw_mut(), &lc_ref, a.raw(), a.layout(), b_0.raw(), b_0.layout(), 3, -2)
+                .unwrap();
+            assert_eq!(c.reshape(-1).to_vec(), c_ref.reshape(-1).to_vec());
+        }
+    }
 }
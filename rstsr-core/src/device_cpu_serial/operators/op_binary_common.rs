@@ -1,39 +1,49 @@
 use crate::prelude_dev::*;
 use num::complex::ComplexFloat;
-use num::{Float, Signed};
-use rstsr_dtype_traits::{DTypeIntoFloatAPI, ExtNum};
+use num::{Float, Signed, Zero};
+use rstsr_dtype_traits::{DTypeIntoFloatAPI, ExtFloat, ExtNum};
 
 // TODO: log1p
 
 /* #region same type */
 
 #[duplicate_item(
-     OpAPI             NumTrait       func_inner;
-    [OpAcosAPI      ] [ComplexFloat] [b.acos()  ];
-    [OpAcoshAPI     ] [ComplexFloat] [b.acosh() ];
-    [OpAsinAPI      ] [ComplexFloat] [b.asin()  ];
-    [OpAsinhAPI     ] [ComplexFloat] [b.asinh() ];
-    [OpAtanAPI      ] [ComplexFloat] [b.atan()  ];
-    [OpAtanhAPI     ] [ComplexFloat] [b.atanh() ];
-    [OpCeilAPI      ] [Float       ] [b.ceil()  ];
-    [OpConjAPI      ] [ComplexFloat] [b.conj()  ];
-    [OpCosAPI       ] [ComplexFloat] [b.cos()   ];
-    [OpCoshAPI      ] [ComplexFloat] [b.cosh()  ];
-    [OpExpAPI       ] [ComplexFloat] [b.exp()   ];
-    [OpExpm1API     ] [Float       ] [b.exp_m1()];
-    [OpFloorAPI     ] [Float       ] [b.floor() ];
-    [OpInvAPI       ] [ComplexFloat] [b.recip() ];
-    [OpLogAPI       ] [ComplexFloat] [b.ln()    ];
-    [OpLog2API      ] [ComplexFloat] [b.log2()  ];
-    [OpLog10API     ] [ComplexFloat] [b.log10() ];
-    [OpReciprocalAPI] [ComplexFloat] [b.recip() ];
-    [OpRoundAPI     ] [Float       ] [b.round() ];
-    [OpSinAPI       ] [ComplexFloat] [b.sin()   ];
-    [OpSinhAPI      ] [ComplexFloat] [b.sinh()  ];
-    [OpSqrtAPI      ] [ComplexFloat] [b.sqrt()  ];
-    [OpTanAPI       ] [ComplexFloat] [b.tan()   ];
-    [OpTanhAPI      ] [ComplexFloat] [b.tanh()  ];
-    [OpTruncAPI     ] [Float       ] [b.trunc() ];
+     OpAPI             NumTrait       func_inner      ;
+    [OpAcosAPI      ] [ComplexFloat] [b.acos()       ];
+    [OpAcoshAPI     ] [ComplexFloat] [b.acosh()      ];
+    [OpAsinAPI      ] [ComplexFloat] [b.asin()       ];
+    [OpAsinhAPI     ] [ComplexFloat] [b.asinh()      ];
+    [OpAtanAPI      ] [ComplexFloat] [b.atan()       ];
+    [OpAtanhAPI     ] [ComplexFloat] [b.atanh()      ];
+    [OpCbrtAPI      ] [ExtFloat    ] [b.ext_cbrt()   ];
+    [OpCeilAPI      ] [Float       ] [b.ceil()       ];
+    [OpConjAPI      ] [ComplexFloat] [b.conj()       ];
+    [OpCosAPI       ] [ComplexFloat] [b.cos()        ];
+    [OpCoshAPI      ] [ComplexFloat] [b.cosh()       ];
+    [OpDeg2RadAPI   ] [ExtFloat    ] [b.ext_deg2rad()];
+    [OpErfAPI       ] [ExtFloat    ] [b.ext_erf()    ];
+    [OpErfcAPI      ] [ExtFloat    ] [b.ext_erfc()   ];
+    [OpErfcxAPI     ] [ExtFloat    ] [b.ext_erfcx()  ];
+    [OpExpAPI       ] [ComplexFloat] [b.exp()        ];
+    [OpExp2API      ] [ExtFloat    ] [b.ext_exp2()   ];
+    [OpExpm1API     ] [Float       ] [b.exp_m1()     ];
+    [OpFloorAPI     ] [Float       ] [b.floor()      ];
+    [OpGammaAPI     ] [ExtFloat    ] [b.ext_gamma()  ];
+    [OpInvAPI       ] [ComplexFloat] [b.recip()      ];
+    [OpLGammaAPI    ] [ExtFloat    ] [b.ext_lgamma() ];
+    [OpLogAPI       ] [ComplexFloat] [b.ln()         ];
+    [OpLog2API      ] [ComplexFloat] [b.log2()       ];
+    [OpLog10API     ] [ComplexFloat] [b.log10()      ];
+    [OpRad2DegAPI   ] [ExtFloat    ] [b.ext_rad2deg()];
+    [OpReciprocalAPI] [ComplexFloat] [b.recip()      ];
+    [OpRoundAPI     ] [Float       ] [b.round()      ];
+    [OpSinAPI       ] [ComplexFloat] [b.sin()        ];
+    [OpSincAPI      ] [ExtFloat    ] [b.ext_sinc()   ];
+    [OpSinhAPI      ] [ComplexFloat] [b.sinh()       ];
+    [OpSqrtAPI      ] [ComplexFloat] [b.sqrt()       ];
+    [OpTanAPI       ] [ComplexFloat] [b.tan()        ];
+    [OpTanhAPI      ] [ComplexFloat] [b.tanh()       ];
+    [OpTruncAPI     ] [Float       ] [b.trunc()      ];
 )]
 impl<T, D> OpAPI<T, D> for DeviceCpuSerial
 where
@@ -213,6 +223,33 @@ where
     }
 }
 
+impl<T, D> OpAngleAPI<T, D> for DeviceCpuSerial
+where
+    T: ComplexFloat,
+    D: DimAPI,
+{
+    type TOut = T::Real;
+
+    fn op_muta_refb(
+        &self,
+        a: &mut Vec<MaybeUninit<T::Real>>,
+        la: &Layout<D>,
+        b: &Vec<T>,
+        lb: &Layout<D>,
+    ) -> Result<()> {
+        self.op_muta_refb_func(a, la, b, lb, &mut |a, b| {
+            a.write(b.arg());
+        })
+    }
+
+    fn op_muta(&self, a: &mut Vec<MaybeUninit<T::Real>>, la: &Layout<D>) -> Result<()> {
+        // only reachable for real types (`T::Real = T`), where angle is `atan2(0, x)`
+        self.op_muta_func(a, la, &mut |a| unsafe {
+            a.write(Float::atan2(T::Real::zero(), a.assume_init_read()));
+        })
+    }
+}
+
 impl<T, D> OpSignAPI<T, D> for DeviceCpuSerial
 where
     T: ExtNum,
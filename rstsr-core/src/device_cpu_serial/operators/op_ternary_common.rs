@@ -1,7 +1,7 @@
 use crate::prelude_dev::*;
 use num::complex::ComplexFloat;
-use num::{pow::Pow, Float};
-use rstsr_dtype_traits::{DTypeIntoFloatAPI, DTypePromoteAPI, ExtFloat, ExtReal};
+use num::{pow::Pow, Complex, Float};
+use rstsr_dtype_traits::{DTypeCastAPI, DTypeIntoFloatAPI, DTypePromoteAPI, ExtFloat, ExtReal};
 
 // output with special promotion
 #[duplicate_item(
@@ -10,6 +10,8 @@ use rstsr_dtype_traits::{DTypeIntoFloatAPI, DTypePromoteAPI, ExtFloat, ExtReal};
     [OpCopySignAPI    ] [Float         ] [Float::copysign(a, b)         ];
     [OpHypotAPI       ] [Float         ] [Float::hypot(a, b)            ];
     [OpNextAfterAPI   ] [ExtFloat      ] [ExtFloat::ext_nextafter(a, b) ];
+    [OpFmodAPI        ] [ExtFloat      ] [ExtFloat::ext_fmod(a, b)      ];
+    [OpRemainderAPI   ] [ExtFloat      ] [ExtFloat::ext_remainder(a, b) ];
     [OpLogAddExpAPI   ] [ComplexFloat  ] [(a.exp() + b.exp()).ln()      ];
 )]
 impl<TA, TB, D> OpAPI<TA, TB, D> for DeviceCpuSerial
@@ -70,6 +72,70 @@ where
     }
 }
 
+// complex construction from two real values
+#[duplicate_item(
+     OpAPI             func_inner               ;
+    [OpComplexAPI  ] [Complex::new(a, b)       ];
+    [OpFromPolarAPI] [Complex::from_polar(a, b)];
+)]
+impl<TA, TB, D> OpAPI<TA, TB, D> for DeviceCpuSerial
+where
+    TA: Clone + DTypePromoteAPI<TB, Res: DTypeIntoFloatAPI<FloatType: Float>>,
+    TB: Clone,
+    D: DimAPI,
+{
+    type TOut = Complex<<TA::Res as DTypeIntoFloatAPI>::FloatType>;
+
+    fn op_mutc_refa_refb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: &Vec<TA>,
+        la: &Layout<D>,
+        b: &Vec<TB>,
+        lb: &Layout<D>,
+    ) -> Result<()> {
+        let mut func = |c: &mut MaybeUninit<Self::TOut>, a: &TA, b: &TB| {
+            let (a, b) = TA::promote_pair(a.clone(), b.clone());
+            let (a, b) = (a.into_float(), b.into_float());
+            c.write(func_inner);
+        };
+        self.op_mutc_refa_refb_func(c, lc, a, la, b, lb, &mut func)
+    }
+
+    fn op_mutc_refa_numb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: &Vec<TA>,
+        la: &Layout<D>,
+        b: TB,
+    ) -> Result<()> {
+        let mut func = |c: &mut MaybeUninit<Self::TOut>, a: &TA, b: &TB| {
+            let (a, b) = TA::promote_pair(a.clone(), b.clone());
+            let (a, b) = (a.into_float(), b.into_float());
+            c.write(func_inner);
+        };
+        self.op_mutc_refa_numb_func(c, lc, a, la, b, &mut func)
+    }
+
+    fn op_mutc_numa_refb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: TA,
+        b: &Vec<TB>,
+        lb: &Layout<D>,
+    ) -> Result<()> {
+        let mut func = |c: &mut MaybeUninit<Self::TOut>, a: &TA, b: &TB| {
+            let (a, b) = TA::promote_pair(a.clone(), b.clone());
+            let (a, b) = (a.into_float(), b.into_float());
+            c.write(func_inner);
+        };
+        self.op_mutc_numa_refb_func(c, lc, a, b, lb, &mut func)
+    }
+}
+
 // general promotion
 #[duplicate_item(
      OpAPI               TO        TraitT           func_inner;
@@ -190,6 +256,82 @@ where
     }
 }
 
+// Special case for ldexp
+impl<TA, TB, D> OpLdexpAPI<TA, TB, D> for DeviceCpuSerial
+where
+    TA: Clone + DTypeIntoFloatAPI<FloatType: ExtFloat>,
+    TB: Clone + DTypeCastAPI<i32>,
+    D: DimAPI,
+{
+    type TOut = TA::FloatType;
+
+    fn op_mutc_refa_refb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: &Vec<TA>,
+        la: &Layout<D>,
+        b: &Vec<TB>,
+        lb: &Layout<D>,
+    ) -> Result<()> {
+        self.op_mutc_refa_refb_func(c, lc, a, la, b, lb, &mut |c, a, b| {
+            c.write(a.clone().into_float().ext_ldexp(b.clone().into_cast()));
+        })
+    }
+
+    fn op_mutc_refa_numb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: &Vec<TA>,
+        la: &Layout<D>,
+        b: TB,
+    ) -> Result<()> {
+        self.op_mutc_refa_numb_func(c, lc, a, la, b, &mut |c, a, b| {
+            c.write(a.clone().into_float().ext_ldexp(b.clone().into_cast()));
+        })
+    }
+
+    fn op_mutc_numa_refb(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        a: TA,
+        b: &Vec<TB>,
+        lb: &Layout<D>,
+    ) -> Result<()> {
+        self.op_mutc_numa_refb_func(c, lc, a, b, lb, &mut |c, a, b| {
+            c.write(a.clone().into_float().ext_ldexp(b.clone().into_cast()));
+        })
+    }
+}
+
+// Special case for frexp
+impl<T, D> OpFrexpAPI<T, D> for DeviceCpuSerial
+where
+    T: Clone + DTypeIntoFloatAPI<FloatType: ExtFloat>,
+    D: DimAPI,
+{
+    type TOut = T::FloatType;
+
+    fn op_mutc_mutd_refa(
+        &self,
+        c: &mut Vec<MaybeUninit<Self::TOut>>,
+        lc: &Layout<D>,
+        d: &mut Vec<MaybeUninit<i32>>,
+        ld: &Layout<D>,
+        a: &Vec<T>,
+        la: &Layout<D>,
+    ) -> Result<()> {
+        self.op_muta_refb_func(c, lc, a, la, &mut |c, a| {
+            c.write(a.clone().into_float().ext_frexp().0);
+        })?;
+        self.op_muta_refb_func(d, ld, a, la, &mut |d, a| {
+            d.write(a.clone().into_float().ext_frexp().1);
+        })
+    }
+}
+
 // Special case for isclose
 use rstsr_dtype_traits::*;
 
@@ -320,6 +320,72 @@ where
     }
 }
 
+impl<T, D> OpNormAPI<T, D> for DeviceCpuSerial
+where
+    T: ComplexFloat,
+    D: DimAPI,
+{
+    type TOut = T::Real;
+
+    fn norm_all(&self, a: &Vec<T>, la: &Layout<D>) -> Result<T::Real> {
+        norm_all_cpu_serial(a, la)
+    }
+
+    fn norm_axes(
+        &self,
+        a: &Vec<T>,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<(Storage<DataOwned<Vec<T::Real>>, T::Real, Self>, Layout<IxD>)> {
+        let (out, layout_out) = norm_axes_cpu_serial(a, &la.to_dim()?, axes)?;
+        Ok((Storage::new(out.into(), self.clone()), layout_out))
+    }
+}
+
+impl<T, D> OpLogSumExpAPI<T, D> for DeviceCpuSerial
+where
+    T: num::Float,
+    D: DimAPI,
+{
+    type TOut = T;
+
+    fn logsumexp_all(&self, a: &Vec<T>, la: &Layout<D>) -> Result<T> {
+        logsumexp_all_cpu_serial(a, la)
+    }
+
+    fn logsumexp_axes(
+        &self,
+        a: &Vec<T>,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<(Storage<DataOwned<Vec<T>>, T, Self>, Layout<IxD>)> {
+        let (out, layout_out) = logsumexp_axes_cpu_serial(a, &la.to_dim()?, axes)?;
+        Ok((Storage::new(out.into(), self.clone()), layout_out))
+    }
+}
+
+#[duplicate_item(
+    OpSoftmaxAPI       func          log  ;
+   [OpSoftmaxAPI   ] [softmax    ] [false];
+   [OpLogSoftmaxAPI] [log_softmax] [true ];
+)]
+impl<T, D> OpSoftmaxAPI<T, D> for DeviceCpuSerial
+where
+    T: num::Float,
+    D: DimAPI,
+{
+    fn func(
+        &self,
+        c: &mut Vec<MaybeUninit<T>>,
+        lc: &Layout<D>,
+        a: &Vec<T>,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<()> {
+        softmax_cpu_serial(c, lc, a, la, axes, log)
+    }
+}
+
 impl<T, D> OpArgMinAPI<T, D> for DeviceCpuSerial
 where
     T: Clone + PartialOrd,
@@ -649,6 +715,53 @@ where
     }
 }
 
+impl<T, TAcc, D> OpSumAccAPI<T, TAcc, D> for DeviceCpuSerial
+where
+    T: Clone + DTypePromoteAPI<TAcc, Res = TAcc>,
+    TAcc: Zero + Add<Output = TAcc> + Clone,
+    D: DimAPI,
+{
+    fn sum_acc_all(&self, a: &Vec<T>, la: &Layout<D>) -> Result<TAcc> {
+        let f_init = TAcc::zero;
+        let f = |acc: TAcc, x: T| acc + x.promote_self();
+        let f_sum = |acc1, acc2| acc1 + acc2;
+        let f_out = |acc| acc;
+
+        reduce_all_cpu_serial(a, la, f_init, f, f_sum, f_out)
+    }
+
+    fn sum_acc_axes(
+        &self,
+        a: &Vec<T>,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<(Storage<DataOwned<Vec<TAcc>>, TAcc, Self>, Layout<IxD>)> {
+        let f_init = TAcc::zero;
+        let f = |acc: TAcc, x: T| acc + x.promote_self();
+        let f_sum = |acc1, acc2| acc1 + acc2;
+        let f_out = |acc| acc;
+
+        let (out, layout_out) = reduce_axes_cpu_serial(a, &la.to_dim()?, axes, f_init, f, f_sum, f_out)?;
+        Ok((Storage::new(out.into(), self.clone()), layout_out))
+    }
+
+    fn sum_acc_axes_to(
+        &self,
+        c: &mut Vec<TAcc>,
+        lc: &Layout<IxD>,
+        a: &Vec<T>,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<()> {
+        let f_init = TAcc::zero;
+        let f = |acc: TAcc, x: T| acc + x.promote_self();
+        let f_sum = |acc1, acc2| acc1 + acc2;
+        let f_out = |acc| acc;
+
+        reduce_axes_to_cpu_serial(c, lc, a, &la.to_dim()?, axes, f_init, f, f_sum, f_out)
+    }
+}
+
 impl<TA, TB, TE, D> OpAllCloseAPI<TA, TB, TE, D> for DeviceCpuSerial
 where
     TA: Clone + DTypePromoteAPI<TB>,
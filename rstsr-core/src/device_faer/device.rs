@@ -7,8 +7,6 @@ pub struct DeviceFaer {
     base: DeviceCpuRayon,
 }
 
-pub(crate) use self::DeviceFaer as DeviceRayonAutoImpl;
-
 impl DeviceFaer {
     pub fn new(num_threads: usize) -> Self {
         DeviceFaer { base: DeviceCpuRayon::new(num_threads) }
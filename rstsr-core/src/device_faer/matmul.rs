@@ -7,7 +7,10 @@ use crate::prelude_dev::*;
 use core::any::TypeId;
 use core::ops::{Add, Mul};
 use core::slice::{from_raw_parts, from_raw_parts_mut};
-use num::{Complex, Zero};
+use faer::traits::ComplexField;
+use half::{bf16, f16};
+use num::complex::ComplexFloat;
+use num::{Complex, Num, Zero};
 use rayon::prelude::*;
 
 // code from ndarray
@@ -15,14 +18,20 @@ fn same_type<A: 'static, B: 'static>() -> bool {
     TypeId::of::<A>() == TypeId::of::<B>()
 }
 
+fn is_half<T: 'static>() -> bool {
+    same_type::<T, f16>() || same_type::<T, bf16>()
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn gemm_faer_ix2_dispatch<TA, TB, TC>(
     c: &mut [TC],
     lc: &Layout<Ix2>,
     a: &[TA],
     la: &Layout<Ix2>,
+    conja: bool,
     b: &[TB],
     lb: &Layout<Ix2>,
+    conjb: bool,
     alpha: TC,
     beta: TC,
     pool: Option<&ThreadPool>,
@@ -36,6 +45,8 @@ where
 {
     // check if syrk could be applicable
     let able_syrk = beta == TC::zero()
+        && !conja
+        && !conjb
         && same_type::<TA, TC>()
         && same_type::<TB, TC>()
         && unsafe {
@@ -59,7 +70,7 @@ where
                 if able_syrk {
                     gemm_with_syrk_faer(c_slice, lc, a_slice, la, alpha, beta, pool)?;
                 } else {
-                    gemm_faer(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool)?;
+                    gemm_faer(c_slice, lc, a_slice, la, conja, b_slice, lb, conjb, alpha, beta, pool)?;
                 }
                 return Ok(());
             }
@@ -71,12 +82,39 @@ where
     impl_gemm_dispatch!(Complex<f32>);
     impl_gemm_dispatch!(Complex<f64>);
 
+    // half-precision types are packed to f32, and accumulated in f32 by faer
+    macro_rules! impl_gemm_half_dispatch {
+        ($ty: ty) => {
+            if (same_type::<TA, $ty>() && same_type::<TB, $ty>() && same_type::<TC, $ty>()) {
+                let a_slice = unsafe { from_raw_parts(a.as_ptr() as *const $ty, a.len()) };
+                let b_slice = unsafe { from_raw_parts(b.as_ptr() as *const $ty, b.len()) };
+                let c_slice = unsafe { from_raw_parts_mut(c.as_mut_ptr() as *mut $ty, c.len()) };
+                let alpha = unsafe { *(&alpha as *const TC as *const $ty) };
+                let beta = unsafe { *(&beta as *const TC as *const $ty) };
+                let f_gemm =
+                    |c: &mut [f32],
+                     lc: &Layout<Ix2>,
+                     a: &[f32],
+                     la: &Layout<Ix2>,
+                     b: &[f32],
+                     lb: &Layout<Ix2>,
+                     alpha,
+                     beta| { gemm_faer(c, lc, a, la, false, b, lb, false, alpha, beta, pool) };
+                return gemm_ix2_promoted_cpu_rayon(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool, f_gemm);
+            }
+        };
+    }
+
+    impl_gemm_half_dispatch!(f16);
+    impl_gemm_half_dispatch!(bf16);
+
     // not able to be accelarated by faer
-    // fallback to naive implementation
+    // fallback to generic cache-blocked implementation, which does not handle conjugation
+    rstsr_assert!(!conja && !conjb, RuntimeError, "Conjugation is not supported in generic matmul.")?;
     let c_slice = c;
     let a_slice = a;
     let b_slice = b;
-    return gemm_ix2_naive_cpu_rayon(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool);
+    return gemm_blocked_cpu_rayon(c_slice, lc, a_slice, la, b_slice, lb, alpha, beta, pool);
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -85,8 +123,10 @@ pub fn matmul_row_major_faer<TA, TB, TC, DA, DB, DC>(
     lc: &Layout<DC>,
     a: &[TA],
     la: &Layout<DA>,
+    conja: bool,
     b: &[TB],
     lb: &Layout<DB>,
+    conjb: bool,
     alpha: TC,
     beta: TC,
     pool: Option<&ThreadPool>,
@@ -112,7 +152,7 @@ where
 
     // handle special cases
     match (la.ndim(), lb.ndim(), lc.ndim()) {
-        (1, 1, 0) => {
+        (1, 1, 0) if !(conja || conjb || is_half::<TC>()) => {
             // rule 1: vector inner dot
             let la = &la.clone().into_dim::<Ix1>().unwrap();
             let lb = &lb.clone().into_dim::<Ix1>().unwrap();
@@ -120,12 +160,21 @@ where
             let c_num = &mut c[lc.offset()];
             return inner_dot_naive_cpu_rayon(c_num, a, la, b, lb, alpha, beta, pool);
         },
+        (1, 1, 0) => {
+            // rule 1 with conjugation or of half-precision: regarded as (1, k) x (k, 1) matrix multiplication
+            let la = &la.clone().into_dim::<Ix1>().unwrap();
+            let lb = &lb.clone().into_dim::<Ix1>().unwrap();
+            let la = &unsafe { Layout::new_unchecked([1, la.shape()[0]], [1, la.stride()[0]], la.offset()) };
+            let lb = &unsafe { Layout::new_unchecked([lb.shape()[0], 1], [lb.stride()[0], 1], lb.offset()) };
+            let lc = &unsafe { Layout::new_unchecked([1, 1], [1, 1], lc.offset()) };
+            return gemm_faer_ix2_dispatch(c, lc, a, la, conja, b, lb, conjb, alpha, beta, pool);
+        },
         (2, 2, 2) => {
             // rule 2: matrix multiplication
             let la = &la.clone().into_dim::<Ix2>().unwrap();
             let lb = &lb.clone().into_dim::<Ix2>().unwrap();
             let lc = &lc.clone().into_dim::<Ix2>().unwrap();
-            return gemm_faer_ix2_dispatch(c, lc, a, la, b, lb, alpha, beta, pool);
+            return gemm_faer_ix2_dispatch(c, lc, a, la, conja, b, lb, conjb, alpha, beta, pool);
         },
         _ => (),
     }
@@ -171,7 +220,7 @@ where
                     // clone alpha and beta
                     let alpha = alpha.clone();
                     let beta = beta.clone();
-                    gemm_faer_ix2_dispatch(c, &lc_m, a, &la_m, b, &lb_m, alpha, beta, None)
+                    gemm_faer_ix2_dispatch(c, &lc_m, a, &la_m, conja, b, &lb_m, conjb, alpha, beta, None)
                 },
             )
         };
@@ -194,7 +243,7 @@ where
             // clone alpha and beta
             let alpha = alpha.clone();
             let beta = beta.clone();
-            gemm_faer_ix2_dispatch(c, &lc_m, a, &la_m, b, &lb_m, alpha, beta, pool)?;
+            gemm_faer_ix2_dispatch(c, &lc_m, a, &la_m, conja, b, &lb_m, conjb, alpha, beta, pool)?;
         }
     }
     return Ok(());
@@ -227,21 +276,177 @@ where
         let default_order = self.default_order();
         let pool = self.get_current_pool();
         match default_order {
-            RowMajor => matmul_row_major_faer(c, lc, a, la, b, lb, alpha, beta, pool),
+            RowMajor => matmul_row_major_faer(c, lc, a, la, false, b, lb, false, alpha, beta, pool),
+            ColMajor => {
+                let la = la.reverse_axes();
+                let lb = lb.reverse_axes();
+                let lc = lc.reverse_axes();
+                matmul_row_major_faer(c, &lc, b, &lb, false, a, &la, false, alpha, beta, pool)
+            },
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<T, DA, DB, DC> DeviceMatMulConjAPI<T, DA, DB, DC> for DeviceFaer
+where
+    T: ComplexFloat + Send + Sync + 'static,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+{
+    fn matmul_conj(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<DC>,
+        a: &Vec<T>,
+        la: &Layout<DA>,
+        conja: bool,
+        b: &Vec<T>,
+        lb: &Layout<DB>,
+        conjb: bool,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        let default_order = self.default_order();
+        let pool = self.get_current_pool();
+
+        // types other than faer-supported ones are conjugated by copy
+        let faer_type = same_type::<T, f32>()
+            || same_type::<T, f64>()
+            || same_type::<T, Complex<f32>>()
+            || same_type::<T, Complex<f64>>();
+        let a_conj =
+            if conja && !faer_type { Some(conj_to_contig_cpu_rayon(a, la, default_order, pool)?) } else { None };
+        let b_conj =
+            if conjb && !faer_type { Some(conj_to_contig_cpu_rayon(b, lb, default_order, pool)?) } else { None };
+        let (a, la, conja) = a_conj.as_ref().map_or((a, la, conja), |(a, la)| (a, la, false));
+        let (b, lb, conjb) = b_conj.as_ref().map_or((b, lb, conjb), |(b, lb)| (b, lb, false));
+
+        match default_order {
+            RowMajor => matmul_row_major_faer(c, lc, a, la, conja, b, lb, conjb, alpha, beta, pool),
             ColMajor => {
                 let la = la.reverse_axes();
                 let lb = lb.reverse_axes();
                 let lc = lc.reverse_axes();
-                matmul_row_major_faer(c, &lc, b, &lb, a, &la, alpha, beta, pool)
+                matmul_row_major_faer(c, &lc, b, &lb, conjb, a, &la, conja, alpha, beta, pool)
             },
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+impl<T> DeviceSYRKAPI<T, T> for DeviceFaer
+where
+    T: ComplexField + Num + MulAssign<T> + Send + Sync,
+{
+    fn syrk(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<Ix2>,
+        a: &Vec<T>,
+        la: &Layout<Ix2>,
+        uplo: FlagUpLo,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        syrk_faer(c, lc, a, la, uplo, alpha, beta, self.get_current_pool())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+impl<T> DeviceHERKAPI<T, T> for DeviceFaer
+where
+    T: ComplexField + Num + MulAssign<T> + Send + Sync,
+{
+    fn herk(
+        &self,
+        c: &mut Vec<T>,
+        lc: &Layout<Ix2>,
+        a: &Vec<T>,
+        la: &Layout<Ix2>,
+        uplo: FlagUpLo,
+        alpha: T,
+        beta: T,
+    ) -> Result<()> {
+        let is_real = |x: &T| T::conj_impl(x) == *x;
+        rstsr_assert!(is_real(&alpha) && is_real(&beta), InvalidValue, "HERK requires real alpha and beta.")?;
+        herk_faer(c, lc, a, la, uplo, alpha, beta, self.get_current_pool())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn test_matmul_half() {
+        // sum of 4096 values of 0.1 is 409.6; accumulation in f16 stalls at 256, where the spacing of f16
+        // numbers (0.25) exceeds 0.1.
+        let device = DeviceFaer::default();
+        let a = full(([3, 4096], f16::from_f64(0.1), &device));
+        let b = full(([4096, 2], f16::from_f64(1.0), &device));
+        let c_ref = 4096.0 * f16::from_f64(0.1).to_f64();
+        let c = &a % &b;
+        assert!(c.iter().all(|x| (x.to_f64() - c_ref).abs() < 0.5));
+        let c = a.i(0) % b.i((.., 0));
+        assert!((c.to_scalar().to_f64() - c_ref).abs() < 0.5);
+
+        let a = full(([3, 4096], bf16::from_f64(0.1), &device));
+        let b = full(([4096, 2], bf16::from_f64(1.0), &device));
+        let c_ref = 4096.0 * bf16::from_f64(0.1).to_f64();
+        let c = &a % &b;
+        assert!(c.iter().all(|x| (x.to_f64() - c_ref).abs() < 2.0));
+
+        // general layouts, compared to f64 matmul of the same (rounded) inputs
+        let a = linspace((-1.0, 1.0, 35, &device)).into_shape([7, 5]).mapv(f16::from_f64);
+        let b = linspace((-1.0, 1.0, 30, &device)).into_shape([6, 5]).mapv(f16::from_f64);
+        let c = &a % b.t();
+        let c_ref = a.mapv(|x| x.to_f64()) % b.mapv(|x| x.to_f64()).t();
+        assert!(c.iter().zip(c_ref.iter()).all(|(x, y)| (x.to_f64() - y).abs() < 1e-2));
+    }
+
+    #[test]
+    fn test_syrk_herk() {
+        let device = DeviceFaer::default();
+        let n = 5;
+        // `a` is transposed, so that it is not c-contiguous
+        let a = linspace((-1.0, 1.0, 15, &device)).into_shape([3, n]).into_dim::<Ix2>().into_reverse_axes();
+        let c0 = linspace((0.0, 2.0, n * n, &device)).into_shape([n, n]).into_dim::<Ix2>();
+        // only the `uplo` triangle is updated
+        let in_triangle = |uplo: FlagUpLo, i: usize, j: usize| if uplo == Lower { i >= j } else { i <= j };
+
+        for uplo in [Lower, Upper] {
+            let mut c = c0.to_owned();
+            let lc = c.layout().clone();
+            device.syrk(c.raw_mut(), &lc, a.raw(), a.layout(), uplo, 2.0, 0.5).unwrap();
+            let c_ref: Tensor<f64, DeviceFaer, Ix2> = 2.0 * (&a % a.t()) + 0.5 * &c0;
+            for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+                let expect = if in_triangle(uplo, i, j) { c_ref[[i, j]] } else { c0[[i, j]] };
+                assert!((c[[i, j]] - expect).abs() < 1e-12);
+            }
+        }
+
+        let z = a.mapv(|x| Complex::new(x, 1.0 - x));
+        let w0 = c0.mapv(|x| Complex::new(x, 0.0));
+        let (alpha, beta) = (Complex::new(2.0, 0.0), Complex::new(0.5, 0.0));
+        for uplo in [Lower, Upper] {
+            let mut w = w0.to_owned();
+            let lw = w.layout().clone();
+            device.herk(w.raw_mut(), &lw, z.raw(), z.layout(), uplo, alpha, beta).unwrap();
+            let w_ref: Tensor<Complex<f64>, DeviceFaer, Ix2> = alpha * (&z % z.t().mapv(|x| x.conj())) + beta * &w0;
+            for (i, j) in (0..n).flat_map(|i| (0..n).map(move |j| (i, j))) {
+                let expect = if in_triangle(uplo, i, j) { w_ref[[i, j]] } else { w0[[i, j]] };
+                assert!((w[[i, j]] - expect).norm() < 1e-12);
+            }
+        }
+
+        // HERK with complex scalars is rejected
+        let mut w = w0.to_owned();
+        let lw = w.layout().clone();
+        assert!(device.herk(w.raw_mut(), &lw, z.raw(), z.layout(), Lower, Complex::new(1.0, 1.0), beta).is_err());
+    }
+
     #[test]
     fn test_matmul() {
         let mut device = DeviceFaer::default();
@@ -349,4 +554,25 @@ mod test {
         let c = &a % &b;
         assert_eq!(c.shape(), &[1, 3, 3]);
     }
+
+    #[test]
+    fn test_matmul_blocked() {
+        // types not supported by faer go to the parallel cache-blocked kernel
+        use num::Complex;
+
+        let device = DeviceFaer::default();
+        let (m, n, k) = (131, 70, 300);
+        let a = arange(((m * k) as i64, &device)).into_shape([m, k]).mapv(|x| Complex::new(x % 17 - 8, x % 5 - 2));
+        let b = arange(((k * n) as i64, &device)).into_shape([n, k]).mapv(|x| Complex::new(x % 13 - 6, x % 7 - 3));
+        let (a, b) = (a.into_dim::<Ix2>(), b.into_dim::<Ix2>().into_reverse_axes());
+        let c = arange(((m * n) as i64, &device)).into_shape([m, n]).mapv(|x| Complex::new(x, -x));
+        let mut c = c.into_dim::<Ix2>();
+        let mut c_ref = c.to_owned();
+
+        let (alpha, beta) = (Complex::new(2, 1), Complex::new(-1, 3));
+        let (lc, lc_ref) = (c.layout().clone(), c_ref.layout().clone());
+        device.matmul(c.raw_mut(), &lc, a.raw(), a.layout(), b.raw(), b.layout(), alpha, beta).unwrap();
+        gemm_naive_cpu_serial(c_ref.raw_mut(), &lc_ref, a.raw(), a.layout(), b.raw(), b.layout(), alpha, beta).unwrap();
+        assert_eq!(c.reshape(-1).to_vec(), c_ref.reshape(-1).to_vec());
+    }
 }
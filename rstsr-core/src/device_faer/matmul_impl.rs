@@ -16,13 +16,17 @@ const PARALLEL_SWITCH: usize = 64;
 
 /* #region gemm */
 
+/// Perform `C = alpha * op(A) @ op(B) + beta * C` by faer, where `op(X)` is
+/// conjugate of `X` if the corresponding flag is set.
 pub fn gemm_faer<T>(
     c: &mut [T],
     lc: &Layout<Ix2>,
     a: &[T],
     la: &Layout<Ix2>,
+    conja: bool,
     b: &[T],
     lb: &Layout<Ix2>,
+    conjb: bool,
     alpha: T,
     beta: T,
     pool: Option<&ThreadPool>,
@@ -68,15 +72,8 @@ where
         )
     };
 
-    if beta == T::zero() {
-        faer::linalg::matmul::matmul(
-            faer_c,
-            faer::Accum::Replace,
-            faer_a,
-            faer_b,
-            alpha,
-            faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap()),
-        );
+    let accum = if beta == T::zero() {
+        faer::Accum::Replace
     } else {
         if beta != T::one() {
             // perform inplace multiplication
@@ -89,14 +86,17 @@ where
                 pool,
             )?;
         }
-        faer::linalg::matmul::matmul(
-            faer_c,
-            faer::Accum::Add,
-            faer_a,
-            faer_b,
-            alpha,
-            faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap()),
-        );
+        faer::Accum::Add
+    };
+    let par = faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap());
+
+    // conjugation in faer changes the element type of view, so dispatch by flags
+    use faer::linalg::matmul::matmul;
+    match (conja, conjb) {
+        (false, false) => matmul(faer_c, accum, faer_a, faer_b, alpha, par),
+        (true, false) => matmul(faer_c, accum, faer_a.conjugate(), faer_b, alpha, par),
+        (false, true) => matmul(faer_c, accum, faer_a, faer_b.conjugate(), alpha, par),
+        (true, true) => matmul(faer_c, accum, faer_a.conjugate(), faer_b.conjugate(), alpha, par),
     }
     return Ok(());
 }
@@ -105,6 +105,8 @@ where
 
 /* #region syrk */
 
+/// Perform `C = alpha * A @ A^T + beta * C` by faer, where only the `uplo`
+/// triangle of `C` is referenced and updated.
 pub fn syrk_faer<T>(
     c: &mut [T],
     lc: &Layout<Ix2>,
@@ -115,6 +117,42 @@ pub fn syrk_faer<T>(
     beta: T,
     pool: Option<&ThreadPool>,
 ) -> Result<()>
+where
+    T: ComplexField + Num + MulAssign<T>,
+{
+    syhe_rk_faer::<T, false>(c, lc, a, la, uplo, alpha, beta, pool)
+}
+
+/// Perform `C = alpha * A @ A^H + beta * C` by faer, where only the `uplo`
+/// triangle of `C` is referenced and updated.
+///
+/// `alpha` and `beta` should be real, so that `C` stays hermitian.
+pub fn herk_faer<T>(
+    c: &mut [T],
+    lc: &Layout<Ix2>,
+    a: &[T],
+    la: &Layout<Ix2>,
+    uplo: FlagUpLo,
+    alpha: T,
+    beta: T,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    T: ComplexField + Num + MulAssign<T>,
+{
+    syhe_rk_faer::<T, true>(c, lc, a, la, uplo, alpha, beta, pool)
+}
+
+fn syhe_rk_faer<T, const HERMI: bool>(
+    c: &mut [T],
+    lc: &Layout<Ix2>,
+    a: &[T],
+    la: &Layout<Ix2>,
+    uplo: FlagUpLo,
+    alpha: T,
+    beta: T,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
 where
     T: ComplexField + Num + MulAssign<T>,
 {
@@ -144,6 +182,22 @@ where
             la.stride()[0],
         )
     };
+
+    // scale the referenced triangle only, the other one is left untouched
+    let n = sc[0];
+    if beta != T::zero() && beta != T::one() {
+        for i in 0..n {
+            let (lo, hi) = match uplo {
+                FlagUpLo::U => (i, n),
+                FlagUpLo::L => (0, i + 1),
+            };
+            for j in lo..hi {
+                let idx = unsafe { lc.index_uncheck(&[i, j]) as usize };
+                c[idx] *= beta.clone();
+            }
+        }
+    }
+
     let faer_c = unsafe {
         MatMut::from_raw_parts_mut(
             c.as_mut_ptr().add(lc.offset()) as *mut T,
@@ -154,46 +208,18 @@ where
         )
     };
 
-    use faer::linalg::matmul::triangular::BlockStructure;
+    use faer::linalg::matmul::triangular::{matmul, BlockStructure};
     let block_structure = match uplo {
         FlagUpLo::U => BlockStructure::TriangularUpper,
         FlagUpLo::L => BlockStructure::TriangularLower,
     };
-    if beta == T::zero() {
-        faer::linalg::matmul::triangular::matmul(
-            faer_c,
-            block_structure,
-            faer::Accum::Replace,
-            faer_a,
-            BlockStructure::Rectangular,
-            faer_at,
-            BlockStructure::Rectangular,
-            alpha,
-            faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap()),
-        );
-    } else {
-        if beta != T::one() {
-            // perform inplace multiplication
-            let c = unsafe { transmute::<&mut [T], &mut [MaybeUninit<T>]>(c) };
-            op_muta_numb_func_cpu_rayon(
-                c,
-                lc,
-                beta,
-                &mut |vc, vb| unsafe { *vc.assume_init_mut() *= vb.clone() },
-                pool,
-            )?;
-        }
-        faer::linalg::matmul::triangular::matmul(
-            faer_c,
-            block_structure,
-            faer::Accum::Add,
-            faer_a,
-            BlockStructure::Rectangular,
-            faer_at,
-            BlockStructure::Rectangular,
-            alpha,
-            faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap()),
-        );
+    let accum = if beta == T::zero() { faer::Accum::Replace } else { faer::Accum::Add };
+    let par = faer::Par::Rayon(NonZeroUsize::new(nthreads).unwrap());
+    let rect = BlockStructure::Rectangular;
+    // conjugation in faer changes the element type of view, so dispatch by flag
+    match HERMI {
+        false => matmul(faer_c, block_structure, accum, faer_a, rect, faer_at, rect, alpha, par),
+        true => matmul(faer_c, block_structure, accum, faer_a, rect, faer_at.conjugate(), rect, alpha, par),
     }
 
     return Ok(());
@@ -219,7 +245,7 @@ where
     // beta is usually zero, in that normal use case of tensor multiplication
     // usually do not involve output matrix c
     if beta != T::zero() {
-        gemm_faer(c, lc, a, la, a, &la.reverse_axes(), alpha, beta, pool)?;
+        gemm_faer(c, lc, a, la, false, a, &la.reverse_axes(), false, alpha, beta, pool)?;
     } else {
         syrk_faer(c, lc, a, la, FlagUpLo::L, alpha, beta, pool)?;
         // symmetrize
@@ -273,13 +299,13 @@ mod test {
         let pool = Some(&pool);
 
         let start = Instant::now();
-        gemm_faer(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+        gemm_faer(&mut c, &lc, &a, &la, false, &b, &lb, false, 1.0, 0.0, pool).unwrap();
         println!("time: {:?}", start.elapsed());
         let start = Instant::now();
-        gemm_faer(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+        gemm_faer(&mut c, &lc, &a, &la, false, &b, &lb, false, 1.0, 0.0, pool).unwrap();
         println!("time: {:?}", start.elapsed());
         let start = Instant::now();
-        gemm_faer(&mut c, &lc, &a, &la, &b, &lb, 1.0, 0.0, pool).unwrap();
+        gemm_faer(&mut c, &lc, &a, &la, false, &b, &lb, false, 1.0, 0.0, pool).unwrap();
         println!("time: {:?}", start.elapsed());
     }
 
@@ -0,0 +1,15 @@
+use crate::prelude_dev::*;
+
+crate::impl_rayon_auto!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_linspace() {
+        let device = DeviceFaer::default();
+        let a = linspace((1.0, 5.0, 5, &device));
+        assert_eq!(a.raw(), &vec![1., 2., 3., 4., 5.]);
+    }
+}
@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/adv_indexing.rs
\ No newline at end of file
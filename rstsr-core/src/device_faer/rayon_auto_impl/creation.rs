@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/creation.rs
\ No newline at end of file
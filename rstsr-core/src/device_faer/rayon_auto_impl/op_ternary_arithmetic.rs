@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/op_ternary_arithmetic.rs
\ No newline at end of file
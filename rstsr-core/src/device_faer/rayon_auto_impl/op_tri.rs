@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/op_tri.rs
\ No newline at end of file
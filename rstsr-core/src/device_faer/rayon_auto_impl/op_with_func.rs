@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/op_with_func.rs
\ No newline at end of file
@@ -1 +0,0 @@
-../../feature_rayon/auto_impl/vecdot.rs
\ No newline at end of file
@@ -1,21 +1,34 @@
-use crate::prelude_dev::*;
+/// Rayon-parallel `adv_indexing` operations for CPU device `$Device` with generic
+/// parameters `$G` and their bounds.
+///
+/// See [`impl_rayon_auto`](crate::impl_rayon_auto).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_rayon_auto_adv_indexing {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use $crate::prelude_dev::*;
 
-impl<T, D> DeviceIndexSelectAPI<T, D> for DeviceRayonAutoImpl
-where
-    T: Clone + Send + Sync,
-    D: DimAPI + DimSmallerOneAPI,
-    D::SmallerOne: DimAPI,
-{
-    fn index_select(
-        &self,
-        c: &mut Vec<MaybeUninit<T>>,
-        lc: &Layout<D>,
-        a: &Vec<T>,
-        la: &Layout<D>,
-        axis: usize,
-        indices: &[usize],
-    ) -> Result<()> {
-        let pool = self.get_current_pool();
-        index_select_cpu_rayon(c, lc, a, la, axis, indices, pool)
-    }
+            impl<$($G,)* T, D> DeviceIndexSelectAPI<T, D> for $Device
+            where
+                $($bound)*
+                T: Clone + Send + Sync,
+                D: DimAPI + DimSmallerOneAPI,
+                D::SmallerOne: DimAPI,
+            {
+                fn index_select(
+                    &self,
+                    c: &mut Vec<MaybeUninit<T>>,
+                    lc: &Layout<D>,
+                    a: &Vec<T>,
+                    la: &Layout<D>,
+                    axis: usize,
+                    indices: &[usize],
+                ) -> Result<()> {
+                    let pool = self.get_current_pool();
+                    index_select_cpu_rayon(c, lc, a, la, axis, indices, pool)
+                }
+            }
+        };
+    };
 }
@@ -1,3 +1,9 @@
+//! Rayon-parallel tensor operations for CPU devices with `Vec<T>` storage.
+//!
+//! These are macros, so that devices with generic parameters (such as
+//! `DeviceBLAS<B>` of `rstsr-blas-traits`) can be implemented in their own
+//! crates without copying the code.
+
 pub mod adv_indexing;
 pub mod assignment;
 pub mod creation;
@@ -9,3 +15,30 @@ pub mod op_tri;
 pub mod op_with_func;
 pub mod reduction;
 pub mod vecdot;
+
+/// Implement rayon-parallel tensor operations for CPU device `$Device`.
+///
+/// The device should implement [`DeviceRayonAPI`](crate::prelude_dev::DeviceRayonAPI) with
+/// `Vec<T>` as raw data. Generic parameters of the device and their bounds are given in
+/// brackets, e.g.
+///
+/// ```ignore
+/// impl_rayon_auto!([] DeviceFaer where []);
+/// impl_rayon_auto!([B] DeviceBLAS<B> where [B: CblasBackendAPI,]);
+/// ```
+#[macro_export]
+macro_rules! impl_rayon_auto {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        $crate::impl_rayon_auto_adv_indexing!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_assignment!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_creation!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_binary_arithmetic!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_binary_common!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_ternary_arithmetic!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_ternary_common!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_tri!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_op_with_func!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_reduction!([$($G),*] $Device where [$($bound)*]);
+        $crate::impl_rayon_auto_vecdot!([$($G),*] $Device where [$($bound)*]);
+    };
+}
@@ -3,6 +3,7 @@
 //! - Layout parallel iterator
 //! - Tensor parallel iterator
 
+pub mod auto_impl;
 pub mod device;
 pub mod par_iter;
 
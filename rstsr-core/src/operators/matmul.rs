@@ -24,6 +24,35 @@ where
     ) -> Result<()>;
 }
 
+/// Matrix multiplication with lazily conjugated operands.
+///
+/// Computes `C = alpha * op(A) @ op(B) + beta * C`, where `op(X)` is the
+/// elementwise complex conjugate of `X` if the corresponding flag is set, and
+/// `X` itself otherwise. Devices are expected to pass the conjugation to their
+/// kernels (e.g. `CblasConjTrans` for BLAS) where possible, instead of
+/// materializing the conjugated operand.
+pub trait DeviceMatMulConjAPI<T, DA, DB, DC>
+where
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    Self: DeviceAPI<T>,
+{
+    fn matmul_conj(
+        &self,
+        c: &mut <Self as DeviceRawAPI<T>>::Raw,
+        lc: &Layout<DC>,
+        a: &<Self as DeviceRawAPI<T>>::Raw,
+        la: &Layout<DA>,
+        conja: bool,
+        b: &<Self as DeviceRawAPI<T>>::Raw,
+        lb: &Layout<DB>,
+        conjb: bool,
+        alpha: T,
+        beta: T,
+    ) -> Result<()>;
+}
+
 pub trait DeviceGEMMAPI<TA, TB, TC>
 where
     Self: DeviceAPI<TA> + DeviceAPI<TB> + DeviceAPI<TC>,
@@ -60,6 +89,9 @@ where
     ) -> Result<()>;
 }
 
+/// Symmetric rank-k update `C = alpha * A @ A^T + beta * C`.
+///
+/// Only the `uplo` triangle of `C` is referenced and updated.
 pub trait DeviceSYRKAPI<TA, TC>
 where
     Self: DeviceAPI<TA> + DeviceAPI<TC>,
@@ -76,6 +108,10 @@ where
     ) -> Result<()>;
 }
 
+/// Hermitian rank-k update `C = alpha * A @ A^H + beta * C`.
+///
+/// Only the `uplo` triangle of `C` is referenced and updated; `alpha` and
+/// `beta` should be real.
 pub trait DeviceHERKAPI<TA, TC>
 where
     Self: DeviceAPI<TA> + DeviceAPI<TC>,
@@ -8,25 +8,35 @@ use crate::prelude_dev::*;
    [OpAsinhAPI     ];
    [OpAtanAPI      ];
    [OpAtanhAPI     ];
+   [OpCbrtAPI      ];
    [OpCeilAPI      ];
    [OpConjAPI      ];
    [OpCosAPI       ];
    [OpCoshAPI      ];
+   [OpDeg2RadAPI   ];
+   [OpErfAPI       ];
+   [OpErfcAPI      ];
+   [OpErfcxAPI     ];
    [OpExpAPI       ];
+   [OpExp2API      ];
    [OpExpm1API     ];
    [OpFloorAPI     ];
+   [OpGammaAPI     ];
    [OpInvAPI       ];
    [OpIsFiniteAPI  ];
    [OpIsInfAPI     ];
    [OpIsNanAPI     ];
+   [OpLGammaAPI    ];
    [OpLogAPI       ];
    [OpLog1pAPI     ];
    [OpLog2API      ];
    [OpLog10API     ];
+   [OpRad2DegAPI   ];
    [OpReciprocalAPI];
    [OpRoundAPI     ];
    [OpSignBitAPI   ];
    [OpSinAPI       ];
+   [OpSincAPI      ];
    [OpSinhAPI      ];
    [OpSquareAPI    ];
    [OpSqrtAPI      ];
@@ -34,6 +44,7 @@ use crate::prelude_dev::*;
    [OpTanhAPI      ];
    [OpTruncAPI     ];
    [OpAbsAPI       ];
+   [OpAngleAPI     ];
    [OpImagAPI      ];
    [OpRealAPI      ];
    [OpSignAPI      ];
@@ -17,6 +17,11 @@ use crate::prelude_dev::*;
    [OpNotEqualAPI    ];
    [OpPowAPI         ];
    [OpNextAfterAPI   ];
+   [OpFmodAPI        ];
+   [OpRemainderAPI   ];
+   [OpLdexpAPI       ];
+   [OpComplexAPI     ];
+   [OpFromPolarAPI   ];
 )]
 pub trait OpAPI<TA, TB, D>
 where
@@ -62,6 +67,25 @@ where
 
 // other common ternary operations
 
+/// Decomposes elements of `a` into mantissa `c` and exponent of two `d`.
+pub trait OpFrexpAPI<T, D>
+where
+    D: DimAPI,
+    Self: DeviceAPI<T> + DeviceAPI<MaybeUninit<Self::TOut>> + DeviceAPI<MaybeUninit<i32>>,
+{
+    type TOut;
+
+    fn op_mutc_mutd_refa(
+        &self,
+        c: &mut <Self as DeviceRawAPI<MaybeUninit<Self::TOut>>>::Raw,
+        lc: &Layout<D>,
+        d: &mut <Self as DeviceRawAPI<MaybeUninit<i32>>>::Raw,
+        ld: &Layout<D>,
+        a: &<Self as DeviceRawAPI<T>>::Raw,
+        la: &Layout<D>,
+    ) -> Result<()>;
+}
+
 use rstsr_dtype_traits::IsCloseArgs;
 
 pub trait OpIsCloseAPI<TA, TB, D, TE>
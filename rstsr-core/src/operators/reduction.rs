@@ -11,6 +11,8 @@ use crate::prelude_dev::*;
    [OpVarAPI   ] [var_axes    ] [var_all    ];
    [OpStdAPI   ] [std_axes    ] [std_all    ];
    [OpL2NormAPI] [l2_norm_axes] [l2_norm_all];
+   [OpNormAPI  ] [norm_axes   ] [norm_all   ];
+   [OpLogSumExpAPI] [logsumexp_axes] [logsumexp_all];
    [OpArgMinAPI] [argmin_axes ] [argmin_all ];
    [OpArgMaxAPI] [argmax_axes ] [argmax_all ];
    [OpAllAPI   ] [all_axes    ] [all_all    ];
@@ -54,6 +56,26 @@ where
         Self: DeviceAPI<IxD>;
 }
 
+#[duplicate_item(
+    OpSoftmaxAPI       func       ;
+   [OpSoftmaxAPI   ] [softmax    ];
+   [OpLogSoftmaxAPI] [log_softmax];
+)]
+pub trait OpSoftmaxAPI<T, D>
+where
+    D: DimAPI,
+    Self: DeviceAPI<T> + DeviceAPI<MaybeUninit<T>>,
+{
+    fn func(
+        &self,
+        c: &mut <Self as DeviceRawAPI<MaybeUninit<T>>>::Raw,
+        lc: &Layout<D>,
+        a: &<Self as DeviceRawAPI<T>>::Raw,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<()>;
+}
+
 #[allow(clippy::type_complexity)]
 pub trait OpSumBoolAPI<D>
 where
@@ -69,6 +91,36 @@ where
     ) -> Result<(Storage<DataOwned<<Self as DeviceRawAPI<usize>>::Raw>, usize, Self>, Layout<IxD>)>;
 }
 
+/// Summation with accumulator type `TAcc`, which is also the output type.
+///
+/// Elements are promoted to `TAcc` by [`DTypePromoteAPI`] before accumulation (e.g. `f16` summed in
+/// `f32`, `i32` summed in `i64`).
+#[allow(clippy::type_complexity)]
+pub trait OpSumAccAPI<T, TAcc, D>
+where
+    D: DimAPI,
+    Self: DeviceAPI<T> + DeviceAPI<TAcc>,
+{
+    fn sum_acc_all(&self, a: &<Self as DeviceRawAPI<T>>::Raw, la: &Layout<D>) -> Result<TAcc>;
+    fn sum_acc_axes(
+        &self,
+        a: &<Self as DeviceRawAPI<T>>::Raw,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<(Storage<DataOwned<<Self as DeviceRawAPI<TAcc>>::Raw>, TAcc, Self>, Layout<IxD>)>;
+    /// Sum over `axes` and write to the preallocated output `c`.
+    ///
+    /// Shape of `lc` must be the shape of `la` with `axes` removed.
+    fn sum_acc_axes_to(
+        &self,
+        c: &mut <Self as DeviceRawAPI<TAcc>>::Raw,
+        lc: &Layout<IxD>,
+        a: &<Self as DeviceRawAPI<T>>::Raw,
+        la: &Layout<D>,
+        axes: &[isize],
+    ) -> Result<()>;
+}
+
 #[allow(clippy::type_complexity)]
 pub trait OpAllCloseAPI<TA, TB, TE, D>
 where
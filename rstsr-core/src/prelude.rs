@@ -14,16 +14,18 @@ pub mod rstsr_traits {
     };
     pub use crate::tensor::device_conversion::{TensorChangeFromDevice, TensorDeviceChangeAPI};
     pub use crate::tensor::operators::op_binary_common::{
-        TensorATan2API, TensorCopySignAPI, TensorEqualAPI, TensorFloorDivideAPI, TensorGreaterAPI,
-        TensorGreaterEqualAPI, TensorHypotAPI, TensorLessAPI, TensorLessEqualAPI, TensorLogAddExpAPI, TensorMaximumAPI,
-        TensorMinimumAPI, TensorNotEqualAPI, TensorPowAPI,
+        TensorATan2API, TensorCopySignAPI, TensorEqualAPI, TensorFloorDivideAPI, TensorFmodAPI, TensorGreaterAPI,
+        TensorGreaterEqualAPI, TensorHypotAPI, TensorLdexpAPI, TensorLessAPI, TensorLessEqualAPI, TensorLogAddExpAPI,
+        TensorMaximumAPI, TensorMinimumAPI, TensorNotEqualAPI, TensorPowAPI, TensorRemainderAPI,
     };
     pub use crate::tensor::operators::op_unary_common::{
         TensorAbsAPI, TensorAcosAPI, TensorAcoshAPI, TensorAsinAPI, TensorAsinhAPI, TensorAtanAPI, TensorAtanhAPI,
-        TensorCeilAPI, TensorConjAPI, TensorCosAPI, TensorCoshAPI, TensorExpAPI, TensorExpm1API, TensorFloorAPI,
-        TensorImagAPI, TensorInvAPI, TensorIsFiniteAPI, TensorIsInfAPI, TensorIsNanAPI, TensorLog10API, TensorLog2API,
-        TensorLogAPI, TensorRealAPI, TensorRoundAPI, TensorSignAPI, TensorSignBitAPI, TensorSinAPI, TensorSinhAPI,
-        TensorSqrtAPI, TensorSquareAPI, TensorTanAPI, TensorTanhAPI, TensorTruncAPI,
+        TensorCbrtAPI, TensorCeilAPI, TensorConjAPI, TensorCosAPI, TensorCoshAPI, TensorDeg2RadAPI, TensorErfAPI,
+        TensorErfcAPI, TensorErfcxAPI, TensorExp2API, TensorExpAPI, TensorExpm1API, TensorFloorAPI, TensorFrexpAPI,
+        TensorGammaAPI, TensorImagAPI, TensorInvAPI, TensorIsFiniteAPI, TensorIsInfAPI, TensorIsNanAPI,
+        TensorLGammaAPI, TensorLog10API, TensorLog2API, TensorLogAPI, TensorRad2DegAPI, TensorRealAPI, TensorRoundAPI,
+        TensorSignAPI, TensorSignBitAPI, TensorSinAPI, TensorSincAPI, TensorSinhAPI, TensorSqrtAPI, TensorSquareAPI,
+        TensorTanAPI, TensorTanhAPI, TensorTruncAPI,
     };
     pub use crate::tensor::ownership_conversion::{TensorIntoOwnedAPI, TensorViewAPI, TensorViewMutAPI};
     pub use crate::tensor::reduction::TensorSumBoolAPI;
@@ -40,6 +42,7 @@ pub mod rstsr_structs {
     pub use crate::device_faer::device::DeviceFaer;
     pub use crate::DeviceCpu;
 
+    pub use crate::tensor::linalg::conj_view::ConjView;
     pub use crate::tensor::tensor_mutable::TensorMutable;
     pub use crate::{
         Tensor, TensorAny, TensorArc, TensorBase, TensorCow, TensorMut, TensorRef, TensorReference, TensorView,
@@ -110,40 +113,54 @@ pub mod rstsr_funcs {
     pub use crate::tensor::operators::exports::{neg, neg_f, not, not_f};
     // unary common functions
     pub use crate::tensor::operators::exports::{
-        abs, abs_f, acos, acos_f, acosh, acosh_f, asin, asin_f, asinh, asinh_f, atan, atan_f, atanh, atanh_f, ceil,
-        ceil_f, conj, conj_f, cos, cos_f, cosh, cosh_f, exp, exp_f, expm1, expm1_f, floor, floor_f, imag, imag_f, inv,
-        inv_f, is_finite, is_finite_f, is_inf, is_inf_f, is_nan, is_nan_f, log, log10, log10_f, log2, log2_f, log_f,
-        real, real_f, reciprocal, reciprocal_f, round, round_f, sign, sign_f, signbit, signbit_f, sin, sin_f, sinh,
-        sinh_f, sqrt, sqrt_f, square, square_f, tan, tan_f, tanh, tanh_f, trunc, trunc_f,
+        abs, abs_f, acos, acos_f, acosh, acosh_f, angle, angle_f, asin, asin_f, asinh, asinh_f, atan, atan_f, atanh,
+        atanh_f, cbrt, cbrt_f, ceil, ceil_f, conj, conj_f, cos, cos_f, cosh, cosh_f, deg2rad, deg2rad_f, erf, erf_f,
+        erfc, erfc_f, erfcx, erfcx_f, exp, exp2, exp2_f, exp_f, expm1, expm1_f, floor, floor_f, frexp, frexp_f, gamma,
+        gamma_f, imag, imag_f, inv, inv_f, is_finite, is_finite_f, is_inf, is_inf_f, is_nan, is_nan_f, lgamma,
+        lgamma_f, log, log10, log10_f, log2, log2_f, log_f, rad2deg, rad2deg_f, real, real_f, reciprocal, reciprocal_f,
+        round, round_f, sign, sign_f, signbit, signbit_f, sin, sin_f, sinc, sinc_f, sinh, sinh_f, sqrt, sqrt_f, square,
+        square_f, tan, tan_f, tanh, tanh_f, trunc, trunc_f,
     };
     // binary common functions
     pub use crate::tensor::operators::exports::{
-        atan2, atan2_f, copysign, copysign_f, eq, eq_f, equal, equal_f, equal_than, equal_than_f, floor_divide,
-        floor_divide_f, ge, ge_f, greater, greater_equal, greater_equal_f, greater_equal_to, greater_equal_to_f,
-        greater_f, greater_than, greater_than_f, gt, gt_f, hypot, hypot_f, le, le_f, less, less_equal, less_equal_f,
-        less_equal_to, less_equal_to_f, less_f, less_than, less_than_f, log_add_exp, log_add_exp_f, lt, lt_f, maximum,
-        maximum_f, minimum, minimum_f, ne, ne_f, nextafter, nextafter_f, not_equal, not_equal_f, not_equal_to,
-        not_equal_to_f, pow, pow_f,
+        atan2, atan2_f, complex, complex_f, copysign, copysign_f, eq, eq_f, equal, equal_f, equal_than, equal_than_f,
+        floor_divide, floor_divide_f, fmod, fmod_f, from_polar, from_polar_f, ge, ge_f, greater, greater_equal,
+        greater_equal_f, greater_equal_to, greater_equal_to_f, greater_f, greater_than, greater_than_f, gt, gt_f,
+        hypot, hypot_f, ldexp, ldexp_f, le, le_f, less, less_equal, less_equal_f, less_equal_to, less_equal_to_f,
+        less_f, less_than, less_than_f, log_add_exp, log_add_exp_f, lt, lt_f, maximum, maximum_f, minimum, minimum_f,
+        ne, ne_f, nextafter, nextafter_f, not_equal, not_equal_f, not_equal_to, not_equal_to_f, pow, pow_f, remainder,
+        remainder_f,
     };
     // reduction
     pub use crate::tensor::reduction::{
-        all, all_all, all_all_f, all_axes, all_axes_f, all_f, allclose, allclose_f, any, any_all, any_all_f, any_axes,
-        any_axes_f, any_f, argmax, argmax_all, argmax_all_f, argmax_axes, argmax_axes_f, argmax_f, argmin, argmin_all,
-        argmin_all_f, argmin_axes, argmin_axes_f, argmin_f, count_nonzero, count_nonzero_all, count_nonzero_all_f,
-        count_nonzero_axes, count_nonzero_axes_f, count_nonzero_f, l2_norm, l2_norm_all, l2_norm_all_f, l2_norm_axes,
-        l2_norm_axes_f, l2_norm_f, max, max_all, max_all_f, max_axes, max_axes_f, max_f, mean, mean_all, mean_all_f,
-        mean_axes, mean_axes_f, mean_f, min, min_all, min_all_f, min_axes, min_axes_f, min_f, prod, prod_all,
-        prod_all_f, prod_axes, prod_axes_f, prod_f, std, std_all, std_all_f, std_axes, std_axes_f, std_f, sum, sum_all,
-        sum_all_f, sum_axes, sum_axes_f, sum_f, unraveled_argmax, unraveled_argmax_all, unraveled_argmax_all_f,
-        unraveled_argmax_axes, unraveled_argmax_axes_f, unraveled_argmax_f, unraveled_argmin, unraveled_argmin_all,
-        unraveled_argmin_all_f, unraveled_argmin_axes, unraveled_argmin_axes_f, unraveled_argmin_f, var, var_all,
-        var_all_f, var_axes, var_axes_f, var_f,
+        all, all_all, all_all_f, all_axes, all_axes_f, all_axes_keepdims, all_axes_keepdims_f, all_f, allclose,
+        allclose_f, any, any_all, any_all_f, any_axes, any_axes_f, any_axes_keepdims, any_axes_keepdims_f, any_f,
+        argmax, argmax_all, argmax_all_f, argmax_axes, argmax_axes_f, argmax_axes_keepdims, argmax_axes_keepdims_f,
+        argmax_f, argmin, argmin_all, argmin_all_f, argmin_axes, argmin_axes_f, argmin_axes_keepdims,
+        argmin_axes_keepdims_f, argmin_f, count_nonzero, count_nonzero_all, count_nonzero_all_f, count_nonzero_axes,
+        count_nonzero_axes_f, count_nonzero_axes_keepdims, count_nonzero_axes_keepdims_f, count_nonzero_f, l2_norm,
+        l2_norm_all, l2_norm_all_f, l2_norm_axes, l2_norm_axes_f, l2_norm_axes_keepdims, l2_norm_axes_keepdims_f,
+        l2_norm_f, log_softmax, log_softmax_f, logsumexp, logsumexp_all, logsumexp_all_f, logsumexp_axes,
+        logsumexp_axes_f, logsumexp_axes_keepdims, logsumexp_axes_keepdims_f, logsumexp_f, max, max_all, max_all_f,
+        max_axes, max_axes_f, max_axes_keepdims, max_axes_keepdims_f, max_f, mean, mean_all, mean_all_f, mean_axes,
+        mean_axes_f, mean_axes_keepdims, mean_axes_keepdims_f, mean_f, min, min_all, min_all_f, min_axes, min_axes_f,
+        min_axes_keepdims, min_axes_keepdims_f, min_f, norm, norm_all, norm_all_f, norm_axes, norm_axes_f,
+        norm_axes_keepdims, norm_axes_keepdims_f, norm_f, prod, prod_all, prod_all_f, prod_axes, prod_axes_f,
+        prod_axes_keepdims, prod_axes_keepdims_f, prod_f, softmax, softmax_f, std, std_all, std_all_f, std_axes,
+        std_axes_f, std_axes_keepdims, std_axes_keepdims_f, std_f, sum, sum_acc, sum_acc_all, sum_acc_all_f,
+        sum_acc_axes, sum_acc_axes_f, sum_acc_f, sum_all, sum_all_f, sum_axes, sum_axes_f, sum_axes_keepdims,
+        sum_axes_keepdims_f, sum_f, sum_with_output, sum_with_output_f, unraveled_argmax, unraveled_argmax_all,
+        unraveled_argmax_all_f, unraveled_argmax_axes, unraveled_argmax_axes_f, unraveled_argmax_axes_keepdims,
+        unraveled_argmax_axes_keepdims_f, unraveled_argmax_f, unraveled_argmin, unraveled_argmin_all,
+        unraveled_argmin_all_f, unraveled_argmin_axes, unraveled_argmin_axes_f, unraveled_argmin_axes_keepdims,
+        unraveled_argmin_axes_keepdims_f, unraveled_argmin_f, var, var_all, var_all_f, var_axes, var_axes_f,
+        var_axes_keepdims, var_axes_keepdims_f, var_f,
     };
     // linalg (array-api's basic linalg operations, not the rstsr-linalg-traits)
     pub use crate::tensor::linalg::exports::{
-        into_matrix_transpose, into_matrix_transpose_f, matmul, matmul_f, matmul_from, matmul_from_f,
-        matmul_with_output, matmul_with_output_f, matrix_transpose, matrix_transpose_f, vecdot, vecdot_f, vecdot_from,
-        vecdot_from_f,
+        into_matrix_transpose, into_matrix_transpose_f, matmul, matmul_f, matmul_from, matmul_from_f, matmul_promote,
+        matmul_promote_f, matmul_with_output, matmul_with_output_f, matrix_transpose, matrix_transpose_f, vecdot,
+        vecdot_f, vecdot_from, vecdot_from_f,
     };
 }
 
@@ -0,0 +1,169 @@
+//! Zero-copy views and in-place operations on complex tensors.
+
+use crate::prelude_dev::*;
+use core::mem::{transmute, ManuallyDrop};
+use num::Complex;
+
+/* #region real/imag views */
+
+impl<R, T, B, D> TensorAny<R, Complex<T>, B, D>
+where
+    R: DataAPI<Data = Vec<Complex<T>>>,
+    B: DeviceAPI<Complex<T>, Raw = Vec<Complex<T>>> + DeviceAPI<T, Raw = Vec<T>>,
+    D: DimAPI,
+{
+    /// View of the `index`-th component (0 for real, 1 for imaginary) of complex tensor.
+    fn complex_part_view_f(&self, index: usize) -> Result<TensorView<'_, T, B, D>> {
+        let layout = self.layout().reinterpret_elem(2, index)?;
+        let raw = self.raw();
+        let len = 2 * raw.len();
+        let vec = unsafe { Vec::from_raw_parts(raw.as_ptr() as *mut T, len, len) };
+        let storage = Storage::new(DataRef::from_manually_drop(ManuallyDrop::new(vec)), self.device().clone());
+        Ok(unsafe { TensorBase::new_unchecked(storage, layout) })
+    }
+
+    /// Real part of complex tensor, as view of the original storage.
+    ///
+    /// Strides of the view are doubled, so this works for any memory layout without copy.
+    ///
+    /// # See also
+    ///
+    /// - [`real`](crate::real) allocates new tensor.
+    pub fn real_view_f(&self) -> Result<TensorView<'_, T, B, D>> {
+        self.complex_part_view_f(0)
+    }
+
+    /// Real part of complex tensor, as view of the original storage.
+    pub fn real_view(&self) -> TensorView<'_, T, B, D> {
+        self.real_view_f().rstsr_unwrap()
+    }
+
+    /// Imaginary part of complex tensor, as view of the original storage.
+    ///
+    /// # See also
+    ///
+    /// - [`imag`](crate::imag) allocates new tensor.
+    pub fn imag_view_f(&self) -> Result<TensorView<'_, T, B, D>> {
+        self.complex_part_view_f(1)
+    }
+
+    /// Imaginary part of complex tensor, as view of the original storage.
+    pub fn imag_view(&self) -> TensorView<'_, T, B, D> {
+        self.imag_view_f().rstsr_unwrap()
+    }
+}
+
+impl<R, T, B, D> TensorAny<R, Complex<T>, B, D>
+where
+    R: DataMutAPI<Data = Vec<Complex<T>>>,
+    B: DeviceAPI<Complex<T>, Raw = Vec<Complex<T>>> + DeviceAPI<T, Raw = Vec<T>>,
+    D: DimAPI,
+{
+    /// Mutable view of the `index`-th component (0 for real, 1 for imaginary) of complex tensor.
+    fn complex_part_view_mut_f(&mut self, index: usize) -> Result<TensorMut<'_, T, B, D>> {
+        let layout = self.layout().reinterpret_elem(2, index)?;
+        let device = self.device().clone();
+        let raw = self.raw_mut();
+        let len = 2 * raw.len();
+        let vec = unsafe { Vec::from_raw_parts(raw.as_mut_ptr() as *mut T, len, len) };
+        let storage = Storage::new(DataMut::from_manually_drop(ManuallyDrop::new(vec)), device);
+        Ok(unsafe { TensorBase::new_unchecked(storage, layout) })
+    }
+
+    /// Mutable view of real part of complex tensor.
+    ///
+    /// Only one mutable view (real or imaginary) can be borrowed at a time. To update real part by
+    /// imaginary part, copy one of them first.
+    pub fn real_view_mut_f(&mut self) -> Result<TensorMut<'_, T, B, D>> {
+        self.complex_part_view_mut_f(0)
+    }
+
+    /// Mutable view of real part of complex tensor.
+    pub fn real_view_mut(&mut self) -> TensorMut<'_, T, B, D> {
+        self.real_view_mut_f().rstsr_unwrap()
+    }
+
+    /// Mutable view of imaginary part of complex tensor.
+    pub fn imag_view_mut_f(&mut self) -> Result<TensorMut<'_, T, B, D>> {
+        self.complex_part_view_mut_f(1)
+    }
+
+    /// Mutable view of imaginary part of complex tensor.
+    pub fn imag_view_mut(&mut self) -> TensorMut<'_, T, B, D> {
+        self.imag_view_mut_f().rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+/* #region conj_inplace */
+
+impl<R, T, B, D> TensorAny<R, T, B, D>
+where
+    R: DataMutAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    B: OpConjAPI<T, D, TOut = T>,
+    D: DimAPI,
+{
+    /// Complex conjugate in-place. For real types, this does nothing.
+    pub fn conj_inplace_f(&mut self) -> Result<()> {
+        let layout = self.layout().clone();
+        let device = self.device().clone();
+        let raw = unsafe {
+            transmute::<&mut <B as DeviceRawAPI<T>>::Raw, &mut <B as DeviceRawAPI<MaybeUninit<T>>>::Raw>(self.raw_mut())
+        };
+        device.op_muta(raw, &layout)
+    }
+
+    /// Complex conjugate in-place. For real types, this does nothing.
+    pub fn conj_inplace(&mut self) {
+        self.conj_inplace_f().rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num::complex::c64;
+
+    #[test]
+    fn test_real_imag_view() {
+        let mut a = linspace((c64(1.0, 2.0), c64(12.0, 24.0), 12)).into_shape([3, 4]);
+        let b = a.t();
+        let b = b.i((.., slice!(None, None, 2)));
+        assert!(allclose_f64(&b.real_view(), &real(&b)));
+        assert!(allclose_f64(&b.imag_view(), &imag(&b)));
+        assert_eq!(b.real_view().raw().as_ptr(), a.raw().as_ptr() as *const f64);
+
+        let im = a.imag_view().to_owned();
+        let mut re = a.real_view_mut();
+        re += &im;
+        a.imag_view_mut().fill(0.0);
+        assert!(allclose_f64(&real(&a), &(linspace((3.0, 36.0, 12)).into_shape([3, 4]))));
+        assert!(allclose_f64(&a.imag_view(), &zeros([3, 4])));
+    }
+
+    #[test]
+    fn test_conj_inplace() {
+        let mut a = linspace((c64(1.0, 2.0), c64(6.0, 12.0), 6)).into_shape([2, 3]);
+        let b = conj(&a);
+        a.i_mut(0).conj_inplace();
+        assert_eq!(a.i(0).to_vec(), b.i(0).to_vec());
+        assert_eq!(a.i(1).to_vec(), conj(b.i(1)).to_vec());
+    }
+
+    #[test]
+    fn test_complex_construct() {
+        let r = asarray(vec![1.0, 2.0]);
+        let theta = asarray(vec![0.0, core::f64::consts::FRAC_PI_2, core::f64::consts::PI]).into_shape([3, 1]);
+        let z = from_polar(&r, &theta);
+        assert_eq!(z.shape(), &[3, 2]);
+        assert!(allclose_f64(&abs(&z), &asarray(vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]).into_shape([3, 2])));
+        assert!(allclose_f64(&angle(&z).i((.., 0)), &theta.i((.., 0))));
+
+        let z = complex(&r, 3.0_f32);
+        assert_eq!(z.to_vec(), vec![c64(1.0, 3.0), c64(2.0, 3.0)]);
+        assert!(allclose_f64(&angle(asarray(vec![-1.0, 1.0])), &asarray(vec![core::f64::consts::PI, 0.0])));
+    }
+}
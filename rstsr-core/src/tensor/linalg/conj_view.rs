@@ -0,0 +1,304 @@
+//! Lazily conjugated tensor view.
+//!
+//! [`ConjView`] marks a tensor view as complex-conjugated without touching its
+//! data. Matrix multiplication recognizes this marker and passes conjugation to
+//! the device kernels (e.g. `CblasConjTrans` for BLAS, `conjugate()` for faer),
+//! so that products such as `C† S C` do not allocate the conjugated operand.
+
+use crate::prelude_dev::*;
+use core::ops::Rem;
+use num::{One, Zero};
+
+/* #region struct definition */
+
+/// Tensor view whose elements are regarded as complex-conjugated.
+///
+/// Conjugation is not performed until the view is materialized by
+/// [`ConjView::to_owned`], or consumed by matmul (`%`), where the device
+/// decides whether conjugation could be done without copy.
+pub struct ConjView<'a, T, B, D>
+where
+    B: DeviceAPI<T>,
+    D: DimAPI,
+{
+    view: TensorView<'a, T, B, D>,
+}
+
+impl<'a, T, B, D> ConjView<'a, T, B, D>
+where
+    B: DeviceAPI<T>,
+    D: DimAPI,
+{
+    /// Mark a tensor view as conjugated.
+    pub fn new(view: TensorView<'a, T, B, D>) -> Self {
+        Self { view }
+    }
+
+    /// The underlying (not conjugated) tensor view.
+    pub fn view(&self) -> TensorView<'_, T, B, D> {
+        self.view.view()
+    }
+
+    /// The underlying (not conjugated) tensor view.
+    pub fn into_inner(self) -> TensorView<'a, T, B, D> {
+        self.view
+    }
+
+    pub fn layout(&self) -> &Layout<D> {
+        self.view.layout()
+    }
+
+    pub fn shape(&self) -> &D {
+        self.view.shape()
+    }
+
+    pub fn device(&self) -> &B {
+        self.view.device()
+    }
+
+    /// Reverse the order of the axes, keeping conjugation.
+    pub fn t(self) -> Self {
+        Self { view: self.view.into_reverse_axes() }
+    }
+
+    /// Reverse the order of the axes, keeping conjugation.
+    pub fn reverse_axes(self) -> Self {
+        self.t()
+    }
+
+    /// Interchange two axes, keeping conjugation.
+    pub fn swapaxes_f<I>(self, axis1: I, axis2: I) -> Result<Self>
+    where
+        I: TryInto<isize>,
+    {
+        Ok(Self { view: into_swapaxes_f(self.view, axis1, axis2)? })
+    }
+
+    /// Interchange two axes, keeping conjugation.
+    pub fn swapaxes<I>(self, axis1: I, axis2: I) -> Self
+    where
+        I: TryInto<isize>,
+    {
+        self.swapaxes_f(axis1, axis2).rstsr_unwrap()
+    }
+
+    /// Materialize the conjugated tensor.
+    pub fn to_owned_f(&self) -> Result<Tensor<T, B, D>>
+    where
+        B: OpConjAPI<T, D, TOut = T> + DeviceCreationAnyAPI<T>,
+    {
+        conj_f(&self.view)
+    }
+
+    /// Materialize the conjugated tensor.
+    pub fn to_owned(&self) -> Tensor<T, B, D>
+    where
+        B: OpConjAPI<T, D, TOut = T> + DeviceCreationAnyAPI<T>,
+    {
+        self.to_owned_f().rstsr_unwrap()
+    }
+}
+
+impl<R, T, B, D> TensorAny<R, T, B, D>
+where
+    R: DataAPI<Data = B::Raw>,
+    B: DeviceAPI<T>,
+    D: DimAPI,
+{
+    /// Lazily conjugated view of the tensor.
+    ///
+    /// No data is copied. This is mostly useful as operand of matmul, e.g.
+    /// `c.conj_view().t() % &s % &c` evaluates `C† S C` without allocating
+    /// the conjugate of `C`.
+    pub fn conj_view(&self) -> ConjView<'_, T, B, D> {
+        ConjView::new(self.view())
+    }
+
+    /// Lazily conjugate-transposed (hermitian adjoint) view of the tensor.
+    ///
+    /// Equivalent to `self.conj_view().t()`.
+    pub fn h(&self) -> ConjView<'_, T, B, D> {
+        self.conj_view().t()
+    }
+}
+
+/* #endregion */
+
+/* #region matmul with conjugation */
+
+#[allow(clippy::too_many_arguments)]
+pub fn op_mutc_refa_refb_matmul_conj<T, DA, DB, DC, B>(
+    mut c: impl TensorViewMutAPI<Type = T, Backend = B, Dim = DC>,
+    a: impl TensorViewAPI<Type = T, Backend = B, Dim = DA>,
+    conja: bool,
+    b: impl TensorViewAPI<Type = T, Backend = B, Dim = DB>,
+    conjb: bool,
+    alpha: T,
+    beta: T,
+) -> Result<()>
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    B: DeviceMatMulConjAPI<T, DA, DB, DC>,
+{
+    let (a, b, mut c) = (a.view(), b.view(), c.view_mut());
+    rstsr_assert!(c.device().same_device(a.device()), DeviceMismatch)?;
+    rstsr_assert!(c.device().same_device(b.device()), DeviceMismatch)?;
+    let device = c.device().clone();
+    let la = a.layout();
+    let lb = b.layout();
+    let lc = c.layout().clone();
+    let sa = a.raw();
+    let sb = b.raw();
+    let sc = c.raw_mut();
+    device.matmul_conj(sc, &lc, sa, la, conja, sb, lb, conjb, alpha, beta)
+}
+
+pub fn op_refa_refb_matmul_conj<T, DA, DB, DC, B>(
+    a: impl TensorViewAPI<Type = T, Backend = B, Dim = DA>,
+    conja: bool,
+    b: impl TensorViewAPI<Type = T, Backend = B, Dim = DB>,
+    conjb: bool,
+    alpha: T,
+) -> Result<Tensor<T, B, DC>>
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    T: Zero,
+    B: DeviceCreationAnyAPI<T>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulConjAPI<T, DA, DB, DC>,
+{
+    let (a, b) = (a.view(), b.view());
+    rstsr_assert!(a.device().same_device(b.device()), DeviceMismatch)?;
+    let default_order = a.device().default_order();
+    let cfg = LayoutMatMulConfig::<DA, DB>::layout_matmul(a.layout(), b.layout(), default_order)?;
+    let lc = cfg.lc;
+    let mut c: Tensor<T, B, _> = unsafe { empty((lc, a.device())) }.into_dim_f()?;
+    op_mutc_refa_refb_matmul_conj(&mut c, &a, conja, &b, conjb, alpha, T::zero())?;
+    return Ok(c);
+}
+
+#[duplicate_item(
+     TrA                         TrB                         conja   conjb  ;
+    [ ConjView<'_, T, B, DA>  ] [ TensorAny<R, T, B, DB>  ] [true ] [false];
+    [&ConjView<'_, T, B, DA>  ] [ TensorAny<R, T, B, DB>  ] [true ] [false];
+    [ ConjView<'_, T, B, DA>  ] [&TensorAny<R, T, B, DB>  ] [true ] [false];
+    [&ConjView<'_, T, B, DA>  ] [&TensorAny<R, T, B, DB>  ] [true ] [false];
+    [ TensorAny<R, T, B, DA>  ] [ ConjView<'_, T, B, DB>  ] [false] [true ];
+    [&TensorAny<R, T, B, DA>  ] [ ConjView<'_, T, B, DB>  ] [false] [true ];
+    [ TensorAny<R, T, B, DA>  ] [&ConjView<'_, T, B, DB>  ] [false] [true ];
+    [&TensorAny<R, T, B, DA>  ] [&ConjView<'_, T, B, DB>  ] [false] [true ];
+)]
+impl<R, T, B, DA, DB, DC> Rem<TrB> for TrA
+where
+    // storage
+    R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    T: Zero + One,
+    B: DeviceCreationAnyAPI<T>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulConjAPI<T, DA, DB, DC>,
+{
+    type Output = Tensor<T, B, DC>;
+    fn rem(self, rhs: TrB) -> Self::Output {
+        op_refa_refb_matmul_conj(self.view(), conja, rhs.view(), conjb, T::one()).rstsr_unwrap()
+    }
+}
+
+#[duplicate_item(
+     TrA                         TrB                       ;
+    [ ConjView<'_, T, B, DA>  ] [ ConjView<'_, T, B, DB>  ];
+    [&ConjView<'_, T, B, DA>  ] [ ConjView<'_, T, B, DB>  ];
+    [ ConjView<'_, T, B, DA>  ] [&ConjView<'_, T, B, DB>  ];
+    [&ConjView<'_, T, B, DA>  ] [&ConjView<'_, T, B, DB>  ];
+)]
+impl<T, B, DA, DB, DC> Rem<TrB> for TrA
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    T: Zero + One,
+    B: DeviceCreationAnyAPI<T>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulConjAPI<T, DA, DB, DC>,
+{
+    type Output = Tensor<T, B, DC>;
+    fn rem(self, rhs: TrB) -> Self::Output {
+        op_refa_refb_matmul_conj(self.view(), true, rhs.view(), true, T::one()).rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num::Complex;
+
+    #[test]
+    fn test_conj_view_matmul() {
+        #[cfg(not(feature = "col_major"))]
+        {
+            let device = DeviceCpuSerial::default();
+            let a: Tensor<Complex<f64>, _> =
+                linspace((Complex::new(0.0, 1.0), Complex::new(14.0, -2.0), 15, &device)).into_shape([5, 3]);
+            let s: Tensor<Complex<f64>, _> =
+                linspace((Complex::new(1.0, 0.5), Complex::new(3.0, 2.0), 25, &device)).into_shape([5, 5]);
+
+            // C† S C
+            let c_ref = &a.view().conj().t() % &s % &a;
+            let c = a.h() % &s % &a;
+            assert!((&c - &c_ref).abs().sum() < 1e-10);
+
+            // S C*
+            let c_ref = &s % a.view().conj();
+            let c = &s % a.conj_view();
+            assert!((&c - &c_ref).abs().sum() < 1e-10);
+
+            // C† C*
+            let c_ref = a.view().conj().t() % a.view().conj();
+            let c = a.h() % a.conj_view();
+            assert!((&c - &c_ref).abs().sum() < 1e-10);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "faer")]
+    fn test_conj_view_matmul_faer() {
+        let device = DeviceFaer::default();
+        let a: Tensor<Complex<f64>, _> =
+            linspace((Complex::new(0.0, 1.0), Complex::new(14.0, -2.0), 15, &device)).into_shape([5, 3]);
+        let s: Tensor<Complex<f64>, _> =
+            linspace((Complex::new(1.0, 0.5), Complex::new(3.0, 2.0), 25, &device)).into_shape([5, 5]);
+
+        // C† S C
+        let c_ref = &a.view().conj().t() % &s % &a;
+        let c = a.h() % &s % &a;
+        assert!((&c - &c_ref).abs().sum() < 1e-10);
+
+        // stacked C*, broadcasted
+        let b = a.conj_view().into_inner().into_shape([3, 1, 5]);
+        let c_ref = b.view().conj() % &s;
+        let c = b.conj_view() % &s;
+        assert!((&c - &c_ref).abs().sum() < 1e-10);
+
+        // vector inner product
+        let v = a.reshape(-1);
+        let c_ref = v.view().conj() % &v;
+        let c = v.conj_view() % &v;
+        assert!((&c - &c_ref).abs().sum() < 1e-10);
+    }
+}
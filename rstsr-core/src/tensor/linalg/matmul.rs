@@ -1,6 +1,8 @@
 //! Matrix-multiplication for tensor.
 
 use crate::prelude_dev::*;
+use core::any::TypeId;
+use core::mem::{transmute, transmute_copy, ManuallyDrop};
 use core::ops::{Mul, Rem};
 use num::{One, Zero};
 
@@ -168,6 +170,114 @@ where
 
 /* #endregion */
 
+/* #region matmul with type promotion */
+
+/// Cast tensor view to type `TC`; no copy is performed if `T` is already `TC`.
+fn into_promoted_cow<'a, T, TC, B, D>(a: TensorView<'a, T, B, D>) -> Result<TensorCow<'a, TC, B, D>>
+where
+    T: 'static,
+    TC: 'static,
+    D: DimAPI,
+    B: DeviceAPI<T> + DeviceAPI<TC> + DeviceCreationAnyAPI<TC> + OpAssignAPI<TC, D, T>,
+{
+    if TypeId::of::<T>() == TypeId::of::<TC>() {
+        // same type, only reinterpret the view
+        let a = ManuallyDrop::new(a);
+        let a = unsafe { transmute_copy::<TensorView<'a, T, B, D>, TensorView<'a, TC, B, D>>(&a) };
+        return Ok(a.into_cow());
+    }
+    let device = a.device().clone();
+    let lc = layout_for_array_copy(a.layout(), TensorIterOrder::K)?;
+    let mut storage_c = device.uninit_impl(lc.bounds_index()?.1)?;
+    // assignment writes all elements, so reading uninitialized values does not happen
+    let raw_c = unsafe {
+        transmute::<&mut <B as DeviceRawAPI<MaybeUninit<TC>>>::Raw, &mut <B as DeviceRawAPI<TC>>::Raw>(
+            storage_c.raw_mut(),
+        )
+    };
+    device.assign(raw_c, &lc, a.raw(), a.layout())?;
+    let storage_c = unsafe { B::assume_init_impl(storage_c) }?;
+    Ok(Tensor::new_f(storage_c, lc)?.into_cow())
+}
+
+/// Matrix multiplication of operands of different types.
+///
+/// Both operands are promoted to `<TA as DTypePromoteAPI<TB>>::Res` (e.g. `f32 × f64 -> f64`) by
+/// [`DTypePromoteAPI`] before multiplication. Operand that is already of the promoted type is not
+/// copied.
+pub fn op_refa_refb_matmul_promote<TA, TB, TC, DA, DB, DC, B>(
+    a: impl TensorViewAPI<Type = TA, Backend = B, Dim = DA>,
+    b: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
+    alpha: TC,
+) -> Result<Tensor<TC, B, DC>>
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    TA: DTypePromoteAPI<TB, Res = TC> + 'static,
+    TB: 'static,
+    TC: Zero + 'static,
+    B: DeviceAPI<TA> + DeviceAPI<TB> + DeviceCreationAnyAPI<TC>,
+    B: OpAssignAPI<TC, DA, TA> + OpAssignAPI<TC, DB, TB>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulAPI<TC, TC, TC, DA, DB, DC>,
+{
+    let (a, b) = (a.view(), b.view());
+    rstsr_assert!(a.device().same_device(b.device()), DeviceMismatch)?;
+    let a = into_promoted_cow::<TA, TC, B, DA>(a)?;
+    let b = into_promoted_cow::<TB, TC, B, DB>(b)?;
+    op_refa_refb_matmul(a.view(), b.view(), alpha)
+}
+
+pub fn matmul_promote_f<TA, TB, TC, DA, DB, DC, B>(
+    a: impl TensorViewAPI<Type = TA, Backend = B, Dim = DA>,
+    b: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
+) -> Result<Tensor<TC, B, DC>>
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    TA: DTypePromoteAPI<TB, Res = TC> + 'static,
+    TB: 'static,
+    TC: Zero + One + 'static,
+    B: DeviceAPI<TA> + DeviceAPI<TB> + DeviceCreationAnyAPI<TC>,
+    B: OpAssignAPI<TC, DA, TA> + OpAssignAPI<TC, DB, TB>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulAPI<TC, TC, TC, DA, DB, DC>,
+{
+    op_refa_refb_matmul_promote(a, b, TC::one())
+}
+
+/// Matrix multiplication of operands of different types.
+///
+/// See also [`op_refa_refb_matmul_promote`].
+pub fn matmul_promote<TA, TB, TC, DA, DB, DC, B>(
+    a: impl TensorViewAPI<Type = TA, Backend = B, Dim = DA>,
+    b: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
+) -> Tensor<TC, B, DC>
+where
+    // dimension
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    // operation specific
+    TA: DTypePromoteAPI<TB, Res = TC> + 'static,
+    TB: 'static,
+    TC: Zero + One + 'static,
+    B: DeviceAPI<TA> + DeviceAPI<TB> + DeviceCreationAnyAPI<TC>,
+    B: OpAssignAPI<TC, DA, TA> + OpAssignAPI<TC, DB, TB>,
+    LayoutMatMulConfig<DA, DB>: LayoutMatMulAPI<DA, DB, DC = DC>,
+    B: DeviceMatMulAPI<TC, TC, TC, DA, DB, DC>,
+{
+    op_refa_refb_matmul_promote(a, b, TC::one()).rstsr_unwrap()
+}
+
+/* #endregion */
+
 /* #region matmul implementation to core ops */
 
 #[duplicate_item(
@@ -242,6 +352,46 @@ where
         op_refa_refb_matmul(self.view(), rhs, TC::one()).rstsr_unwrap()
     }
 
+    pub fn matmul_promote_f<TB, TC, DB, DC>(
+        &self,
+        rhs: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
+    ) -> Result<Tensor<TC, B, DC>>
+    where
+        // dimension
+        DB: DimAPI,
+        DC: DimAPI,
+        // operation specific
+        T: DTypePromoteAPI<TB, Res = TC> + 'static,
+        TB: 'static,
+        TC: Zero + One + 'static,
+        B: DeviceAPI<TB> + DeviceCreationAnyAPI<TC>,
+        B: OpAssignAPI<TC, D, T> + OpAssignAPI<TC, DB, TB>,
+        LayoutMatMulConfig<D, DB>: LayoutMatMulAPI<D, DB, DC = DC>,
+        B: DeviceMatMulAPI<TC, TC, TC, D, DB, DC>,
+    {
+        op_refa_refb_matmul_promote(self.view(), rhs, TC::one())
+    }
+
+    pub fn matmul_promote<TB, TC, DB, DC>(
+        &self,
+        rhs: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
+    ) -> Tensor<TC, B, DC>
+    where
+        // dimension
+        DB: DimAPI,
+        DC: DimAPI,
+        // operation specific
+        T: DTypePromoteAPI<TB, Res = TC> + 'static,
+        TB: 'static,
+        TC: Zero + One + 'static,
+        B: DeviceAPI<TB> + DeviceCreationAnyAPI<TC>,
+        B: OpAssignAPI<TC, D, T> + OpAssignAPI<TC, DB, TB>,
+        LayoutMatMulConfig<D, DB>: LayoutMatMulAPI<D, DB, DC = DC>,
+        B: DeviceMatMulAPI<TC, TC, TC, D, DB, DC>,
+    {
+        op_refa_refb_matmul_promote(self.view(), rhs, TC::one()).rstsr_unwrap()
+    }
+
     pub fn matmul_with_output_f<TB, TC, DB, DC>(
         &self,
         rhs: impl TensorViewAPI<Type = TB, Backend = B, Dim = DB>,
@@ -378,4 +528,28 @@ mod test {
             assert!(allclose_f64(&c.raw().into(), &c_ref.into()));
         }
     }
+
+    #[test]
+    fn test_matmul_promote() {
+        let a = linspace((0.0, 14.0, 15)).into_shape([3, 5]);
+        let b = linspace((0.0, 19.0, 20)).into_shape([5, 4]);
+        let c_ref = &a % &b;
+
+        // f32 x f64 -> f64, and f64 x f32 -> f64
+        let a_f32 = a.mapv(|x| x as f32);
+        let b_f32 = b.mapv(|x| x as f32);
+        let c: Tensor<f64, _, _> = matmul_promote(&a_f32, &b);
+        assert!(allclose_f64(&c, &c_ref));
+        let c = a.matmul_promote(&b_f32);
+        assert!(allclose_f64(&c, &c_ref));
+
+        // same type is not copied, and gives the same result as matmul
+        let a_cow = into_promoted_cow::<f64, f64, _, _>(a.view()).unwrap();
+        assert!(a_cow.is_ref());
+        assert_eq!(a_cow.raw().as_ptr(), a.raw().as_ptr());
+        let a_cow = into_promoted_cow::<f32, f64, _, _>(a_f32.view()).unwrap();
+        assert!(a_cow.is_owned());
+        let c = a.matmul_promote(&b);
+        assert!(allclose_f64(&c, &c_ref));
+    }
 }
@@ -1,3 +1,4 @@
+pub mod conj_view;
 pub mod matmul;
 pub mod matrix_transpose;
 pub mod vecdot;
@@ -5,6 +6,7 @@ pub mod vecdot;
 pub mod exports {
     use super::*;
 
+    pub use conj_view::*;
     pub use matmul::*;
     pub use matrix_transpose::*;
     pub use vecdot::*;
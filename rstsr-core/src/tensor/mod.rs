@@ -1,6 +1,7 @@
 pub mod adv_indexing;
 pub mod asarray;
 pub mod assignment;
+pub mod complex;
 pub mod creation;
 pub mod creation_from_tensor;
 pub mod device_conversion;
@@ -26,6 +27,7 @@ pub mod exports {
     pub use adv_indexing::*;
     pub use asarray::*;
     pub use assignment::*;
+    pub use complex::*;
     pub use creation::*;
     pub use creation_from_tensor::*;
     pub use device_conversion::*;
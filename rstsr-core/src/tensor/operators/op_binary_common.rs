@@ -5,6 +5,8 @@ use crate::prelude_dev::*;
 Exception functions:
 - floor_divide: integer and float are different, so we need to implement two functions
 - pow: different input types occurs
+- ldexp: exponent is casted to `i32`
+- complex, from_polar: output is complex of promoted float type
 
 */
 
@@ -27,7 +29,13 @@ Exception functions:
    [not_equal    ] [not_equal_f    ] [TensorNotEqualAPI    ];
    [pow          ] [pow_f          ] [TensorPowAPI         ];
    [nextafter    ] [nextafter_f    ] [TensorNextAfterAPI   ];
+   [fmod         ] [fmod_f         ] [TensorFmodAPI        ];
+   [remainder    ] [remainder_f    ] [TensorRemainderAPI   ];
+   [ldexp        ] [ldexp_f        ] [TensorLdexpAPI       ];
+   [complex      ] [complex_f      ] [TensorComplexAPI     ];
+   [from_polar   ] [from_polar_f   ] [TensorFromPolarAPI   ];
 )]
+#[allow(clippy::wrong_self_convention)]
 pub trait TensorOpAPI<TRB> {
     type Output;
     fn op_f(self, b: TRB) -> Result<Self::Output>;
@@ -56,6 +64,11 @@ pub trait TensorOpAPI<TRB> {
    [not_equal_f    ] [TensorNotEqualAPI    ] [OpNotEqualAPI    ];
    [pow_f          ] [TensorPowAPI         ] [OpPowAPI         ];
    [nextafter_f    ] [TensorNextAfterAPI   ] [OpNextAfterAPI   ];
+   [fmod_f         ] [TensorFmodAPI        ] [OpFmodAPI        ];
+   [remainder_f    ] [TensorRemainderAPI   ] [OpRemainderAPI   ];
+   [ldexp_f        ] [TensorLdexpAPI       ] [OpLdexpAPI       ];
+   [complex_f      ] [TensorComplexAPI     ] [OpComplexAPI     ];
+   [from_polar_f   ] [TensorFromPolarAPI   ] [OpFromPolarAPI   ];
 )]
 mod impl_trait_binary {
     use super::*;
@@ -255,6 +268,11 @@ mod func_binary {
     func_binary!(greater_equal , greater_equal_f   , TensorGreaterEqualAPI     , DeviceGreaterEqualAPI     , ge, ge_f, greater_equal_to, greater_equal_to_f);
     func_binary!(not_equal     , not_equal_f       , TensorNotEqualAPI         , DeviceNotEqualAPI         , ne, ne_f, not_equal_to    , not_equal_to_f    );
     func_binary!(nextafter     , nextafter_f       , TensorNextAfterAPI        , DeviceNextAfterAPI        ,);
+    func_binary!(fmod          , fmod_f            , TensorFmodAPI             , DeviceFmodAPI             ,);
+    func_binary!(remainder     , remainder_f       , TensorRemainderAPI        , DeviceRemainderAPI        ,);
+    func_binary!(ldexp         , ldexp_f           , TensorLdexpAPI            , DeviceLdexpAPI            ,);
+    func_binary!(complex       , complex_f         , TensorComplexAPI          , DeviceComplexAPI          ,);
+    func_binary!(from_polar    , from_polar_f      , TensorFromPolarAPI        , DeviceFromPolarAPI        ,);
 }
 
 pub use func_binary::*;
@@ -376,4 +394,18 @@ mod test {
         let b = 2.0.pow(a.view());
         assert_eq!(b.raw(), &[2.0, 8.0, 4.0, 32.0, 32.0, 4.0]);
     }
+
+    #[test]
+    fn test_fmod_remainder() {
+        let a = asarray(vec![5.0, -5.0, 5.0, -5.0, 6.0]);
+        let b = asarray(vec![3.0, 3.0, -3.0, -3.0, -3.0]);
+        let c = fmod(&a, &b);
+        assert_eq!(c.raw(), &[2.0, -2.0, 2.0, -2.0, 0.0]);
+        let c = remainder(&a, &b);
+        assert_eq!(c.raw(), &[2.0, 1.0, -1.0, -2.0, 0.0]);
+        let c = a.remainder(3);
+        assert_eq!(c.raw(), &[2.0, 1.0, 2.0, 1.0, 0.0]);
+        let c = ldexp(&a, 2);
+        assert_eq!(c.raw(), &[20.0, -20.0, 20.0, -20.0, 24.0]);
+    }
 }
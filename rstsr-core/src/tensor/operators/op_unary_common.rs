@@ -7,10 +7,11 @@ Most unary functions are of the same type. However, there are some exceptions, a
 
 - `same type`: Input and Output are of the same type. They can be implemented in an inplace manner.
 - `boolean output`: Output is boolean. Not able for inplace operation.
-- `Imag, Real, Abs`:
+- `Imag, Real, Abs, Angle`:
     - complex: generalized, not for inplace.
     - real: specialized, for inplace.
 - `Sign`: any `ExtNum` dtype (integers, floats, complex), same-type output, for inplace.
+- `Frexp`: two outputs (mantissa and exponent), not for inplace.
 
 */
 
@@ -55,22 +56,32 @@ mod trait_unary {
     trait_unary!(asinh     , asinh_f     , TensorAsinhAPI      );
     trait_unary!(atan      , atan_f      , TensorAtanAPI       );
     trait_unary!(atanh     , atanh_f     , TensorAtanhAPI      );
+    trait_unary!(cbrt      , cbrt_f      , TensorCbrtAPI       );
     trait_unary!(ceil      , ceil_f      , TensorCeilAPI       );
     trait_unary!(conj      , conj_f      , TensorConjAPI       );
     trait_unary!(cos       , cos_f       , TensorCosAPI        );
     trait_unary!(cosh      , cosh_f      , TensorCoshAPI       );
+    trait_unary!(deg2rad   , deg2rad_f   , TensorDeg2RadAPI    );
+    trait_unary!(erf       , erf_f       , TensorErfAPI        );
+    trait_unary!(erfc      , erfc_f      , TensorErfcAPI       );
+    trait_unary!(erfcx     , erfcx_f     , TensorErfcxAPI      );
     trait_unary!(exp       , exp_f       , TensorExpAPI        );
+    trait_unary!(exp2      , exp2_f      , TensorExp2API       );
     trait_unary!(expm1     , expm1_f     , TensorExpm1API      );
     trait_unary!(floor     , floor_f     , TensorFloorAPI      );
+    trait_unary!(gamma     , gamma_f     , TensorGammaAPI      );
     trait_unary!(inv       , inv_f       , TensorInvAPI        );
+    trait_unary!(lgamma    , lgamma_f    , TensorLGammaAPI     );
     trait_unary!(log       , log_f       , TensorLogAPI        );
     trait_unary!(log1p     , log1p_f     , TensorLog1pAPI      );
     trait_unary!(log2      , log2_f      , TensorLog2API       );
     trait_unary!(log10     , log10_f     , TensorLog10API      );
+    trait_unary!(rad2deg   , rad2deg_f   , TensorRad2DegAPI    );
     trait_unary!(reciprocal, reciprocal_f , TensorReciprocalAPI);
     trait_unary!(round     , round_f     , TensorRoundAPI      );
     trait_unary!(signbit   , signbit_f   , TensorSignBitAPI    );
     trait_unary!(sin       , sin_f       , TensorSinAPI        );
+    trait_unary!(sinc      , sinc_f      , TensorSincAPI       );
     trait_unary!(sinh      , sinh_f      , TensorSinhAPI       );
     trait_unary!(square    , square_f    , TensorSquareAPI     );
     trait_unary!(sqrt      , sqrt_f      , TensorSqrtAPI       );
@@ -82,9 +93,12 @@ mod trait_unary {
     trait_unary!(is_nan    , is_nan_f    , TensorIsNanAPI      );
 
     trait_unary!(abs  , abs_f  , TensorAbsAPI  );
+    trait_unary!(angle, angle_f, TensorAngleAPI);
     trait_unary!(real , real_f , TensorRealAPI );
     trait_unary!(imag , imag_f , TensorImagAPI );
     trait_unary!(sign , sign_f , TensorSignAPI );
+
+    trait_unary!(frexp, frexp_f, TensorFrexpAPI);
 }
 
 pub use trait_unary::*;
@@ -101,25 +115,35 @@ pub use trait_unary::*;
    [asinh_f     ] [TensorAsinhAPI     ] [OpAsinhAPI     ];
    [atan_f      ] [TensorAtanAPI      ] [OpAtanAPI      ];
    [atanh_f     ] [TensorAtanhAPI     ] [OpAtanhAPI     ];
+   [cbrt_f      ] [TensorCbrtAPI      ] [OpCbrtAPI      ];
    [ceil_f      ] [TensorCeilAPI      ] [OpCeilAPI      ];
    [conj_f      ] [TensorConjAPI      ] [OpConjAPI      ];
    [cos_f       ] [TensorCosAPI       ] [OpCosAPI       ];
    [cosh_f      ] [TensorCoshAPI      ] [OpCoshAPI      ];
+   [deg2rad_f   ] [TensorDeg2RadAPI   ] [OpDeg2RadAPI   ];
+   [erf_f       ] [TensorErfAPI       ] [OpErfAPI       ];
+   [erfc_f      ] [TensorErfcAPI      ] [OpErfcAPI      ];
+   [erfcx_f     ] [TensorErfcxAPI     ] [OpErfcxAPI     ];
    [exp_f       ] [TensorExpAPI       ] [OpExpAPI       ];
+   [exp2_f      ] [TensorExp2API      ] [OpExp2API      ];
    [expm1_f     ] [TensorExpm1API     ] [OpExpm1API     ];
    [floor_f     ] [TensorFloorAPI     ] [OpFloorAPI     ];
+   [gamma_f     ] [TensorGammaAPI     ] [OpGammaAPI     ];
    [inv_f       ] [TensorInvAPI       ] [OpInvAPI       ];
    [is_finite_f ] [TensorIsFiniteAPI  ] [OpIsFiniteAPI  ];
    [is_inf_f    ] [TensorIsInfAPI     ] [OpIsInfAPI     ];
    [is_nan_f    ] [TensorIsNanAPI     ] [OpIsNanAPI     ];
+   [lgamma_f    ] [TensorLGammaAPI    ] [OpLGammaAPI    ];
    [log_f       ] [TensorLogAPI       ] [OpLogAPI       ];
    [log1p_f     ] [TensorLog1pAPI     ] [OpLog1pAPI     ];
    [log2_f      ] [TensorLog2API      ] [OpLog2API      ];
    [log10_f     ] [TensorLog10API     ] [OpLog10API     ];
+   [rad2deg_f   ] [TensorRad2DegAPI   ] [OpRad2DegAPI   ];
    [reciprocal_f] [TensorReciprocalAPI] [OpReciprocalAPI];
    [round_f     ] [TensorRoundAPI     ] [OpRoundAPI     ];
    [signbit_f   ] [TensorSignBitAPI   ] [OpSignBitAPI   ];
    [sin_f       ] [TensorSinAPI       ] [OpSinAPI       ];
+   [sinc_f      ] [TensorSincAPI      ] [OpSincAPI      ];
    [sinh_f      ] [TensorSinhAPI      ] [OpSinhAPI      ];
    [square_f    ] [TensorSquareAPI    ] [OpSquareAPI    ];
    [sqrt_f      ] [TensorSqrtAPI      ] [OpSqrtAPI      ];
@@ -127,6 +151,7 @@ pub use trait_unary::*;
    [tanh_f      ] [TensorTanhAPI      ] [OpTanhAPI      ];
    [trunc_f     ] [TensorTruncAPI     ] [OpTruncAPI     ];
    [abs_f       ] [TensorAbsAPI       ] [OpAbsAPI       ];
+   [angle_f     ] [TensorAngleAPI     ] [OpAngleAPI     ];
    [imag_f      ] [TensorImagAPI      ] [OpImagAPI      ];
    [real_f      ] [TensorRealAPI      ] [OpRealAPI      ];
    [sign_f      ] [TensorSignAPI      ] [OpSignAPI      ];
@@ -194,6 +219,44 @@ mod impl_tensor_unary_common {
 
 /* #endregion */
 
+/* #region impl tensor frexp */
+
+impl<R, T, B, D> TensorFrexpAPI for &TensorAny<R, T, B, D>
+where
+    D: DimAPI,
+    R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    B: DeviceAPI<T> + DeviceAPI<B::TOut> + DeviceAPI<i32>,
+    B: OpFrexpAPI<T, D> + DeviceCreationAnyAPI<B::TOut> + DeviceCreationAnyAPI<i32>,
+{
+    type Output = (Tensor<B::TOut, B, D>, Tensor<i32, B, D>);
+    fn frexp_f(self) -> Result<Self::Output> {
+        let la = self.layout();
+        let device = self.device();
+        let lc = layout_for_array_copy(la, TensorIterOrder::K)?;
+        let size = lc.bounds_index()?.1;
+        let mut storage_c = device.uninit_impl(size)?;
+        let mut storage_d = device.uninit_impl(size)?;
+        device.op_mutc_mutd_refa(storage_c.raw_mut(), &lc, storage_d.raw_mut(), &lc, self.raw(), la)?;
+        let storage_c = unsafe { B::assume_init_impl(storage_c) }?;
+        let storage_d = unsafe { B::assume_init_impl(storage_d) }?;
+        Ok((Tensor::new_f(storage_c, lc.clone())?, Tensor::new_f(storage_d, lc)?))
+    }
+}
+
+impl<T, B, D> TensorFrexpAPI for TensorView<'_, T, B, D>
+where
+    D: DimAPI,
+    B: DeviceAPI<T> + DeviceAPI<B::TOut> + DeviceAPI<i32>,
+    B: OpFrexpAPI<T, D> + DeviceCreationAnyAPI<B::TOut> + DeviceCreationAnyAPI<i32>,
+{
+    type Output = (Tensor<B::TOut, B, D>, Tensor<i32, B, D>);
+    fn frexp_f(self) -> Result<Self::Output> {
+        TensorFrexpAPI::frexp_f(&self)
+    }
+}
+
+/* #endregion */
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -254,6 +317,27 @@ mod test {
         println!("{b:}");
     }
 
+    #[test]
+    fn test_special_functions() {
+        let a = linspace((0.5, 3.0, 6));
+        let b = gamma(&a);
+        assert!(allclose_f64(&b, &lgamma(&a).exp()));
+        assert!(allclose_f64(&(erf(&a) + erfc(&a)), &ones_like(&a)));
+        assert!(allclose_f64(&erfcx(&a), &(erfc(&a) * exp(a.view() * &a))));
+        let c = cbrt(&a);
+        assert!(allclose_f64(&(&c * &c * &c), &a));
+        assert!(allclose_f64(&exp2(&a), &exp(a.view() * core::f64::consts::LN_2)));
+        assert!(allclose_f64(&rad2deg(deg2rad(&a)), &a));
+        let b = sinc(asarray(vec![0.0, 0.5, 1.0]));
+        assert!(allclose_f64(&b, &asarray(vec![1.0, 2.0 / core::f64::consts::PI, 0.0])));
+
+        let a = asarray(vec![3.0, -0.5, 0.0, 1024.0]);
+        let (m, e) = frexp(&a);
+        assert_eq!(m.to_vec(), vec![0.75, -0.5, 0.0, 0.5]);
+        assert_eq!(e.to_vec(), vec![2, 0, 0, 11]);
+        assert!(allclose_f64(&ldexp(&m, &e), &a));
+    }
+
     #[test]
     fn test_cpuserial() {
         let a = linspace((1.0, 5.0, 5, &DeviceCpuSerial::default()));
@@ -1,7 +1,26 @@
 use crate::prelude_dev::*;
 
+/// Insert reduced axes back to the reduced layout as size-one dimensions.
+///
+/// This is used by the `keepdims` variants of reductions, so that the result can be broadcasted
+/// against the input tensor, and keeps the dimension type of input tensor.
+fn layout_reduce_keepdims<D>(layout: Layout<IxD>, ndim: usize, axes: AxesIndex<isize>) -> Result<Layout<D>>
+where
+    D: DimAPI,
+{
+    let axes = match axes {
+        AxesIndex::None => (0..ndim as isize).collect(),
+        _ => normalize_axes_index(axes, ndim, false, true)?,
+    };
+    let mut layout = layout;
+    for axis in axes {
+        layout = layout.dim_insert(axis)?;
+    }
+    layout.into_dim::<D>()
+}
+
 macro_rules! trait_reduction {
-    ($OpReduceAPI: ident, $fn: ident, $fn_f: ident, $fn_axes: ident, $fn_axes_f: ident, $fn_all: ident, $fn_all_f: ident) => {
+    ($OpReduceAPI: ident, $fn: ident, $fn_f: ident, $fn_axes: ident, $fn_axes_f: ident, $fn_axes_keepdims: ident, $fn_axes_keepdims_f: ident, $fn_all: ident, $fn_all_f: ident) => {
         pub fn $fn_all_f<T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<B::TOut>
         where
             D: DimAPI,
@@ -55,6 +74,33 @@ macro_rules! trait_reduction {
             $fn_axes_f(tensor, axes).rstsr_unwrap()
         }
 
+        pub fn $fn_axes_keepdims_f<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Result<Tensor<B::TOut, B, D>>
+        where
+            D: DimAPI,
+            B: $OpReduceAPI<T, D> + DeviceCreationAnyAPI<B::TOut>,
+        {
+            let axes = axes.try_into().map_err(Into::into)?;
+            let tensor = tensor.view();
+            let ndim = tensor.ndim();
+            let (storage, layout) = $fn_axes_f(&tensor, axes.clone())?.into_raw_parts();
+            let layout = layout_reduce_keepdims(layout, ndim, axes)?;
+            Tensor::new_f(storage, layout)
+        }
+
+        pub fn $fn_axes_keepdims<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Tensor<B::TOut, B, D>
+        where
+            D: DimAPI,
+            B: $OpReduceAPI<T, D> + DeviceCreationAnyAPI<B::TOut>,
+        {
+            $fn_axes_keepdims_f(tensor, axes).rstsr_unwrap()
+        }
+
         pub fn $fn_f<T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<B::TOut>
         where
             D: DimAPI,
@@ -102,6 +148,26 @@ macro_rules! trait_reduction {
                 $fn_axes(self, axes)
             }
 
+            pub fn $fn_axes_keepdims_f(
+                &self,
+                axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+            ) -> Result<Tensor<B::TOut, B, D>>
+            where
+                B: DeviceCreationAnyAPI<B::TOut>,
+            {
+                $fn_axes_keepdims_f(self, axes)
+            }
+
+            pub fn $fn_axes_keepdims(
+                &self,
+                axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+            ) -> Tensor<B::TOut, B, D>
+            where
+                B: DeviceCreationAnyAPI<B::TOut>,
+            {
+                $fn_axes_keepdims(self, axes)
+            }
+
             pub fn $fn_f(&self) -> Result<B::TOut> {
                 $fn_f(self)
             }
@@ -116,24 +182,26 @@ macro_rules! trait_reduction {
 #[rustfmt::skip]
 mod impl_trait_reduction {
     use super::*;
-    trait_reduction!(OpSumAPI, sum, sum_f, sum_axes, sum_axes_f, sum_all, sum_all_f);
-    trait_reduction!(OpMinAPI, min, min_f, min_axes, min_axes_f, min_all, min_all_f);
-    trait_reduction!(OpMaxAPI, max, max_f, max_axes, max_axes_f, max_all, max_all_f);
-    trait_reduction!(OpProdAPI, prod, prod_f, prod_axes, prod_axes_f, prod_all, prod_all_f);
-    trait_reduction!(OpMeanAPI, mean, mean_f, mean_axes, mean_axes_f, mean_all, mean_all_f);
-    trait_reduction!(OpVarAPI, var, var_f, var_axes, var_axes_f, var_all, var_all_f);
-    trait_reduction!(OpStdAPI, std, std_f, std_axes, std_axes_f, std_all, std_all_f);
-    trait_reduction!(OpL2NormAPI, l2_norm, l2_norm_f, l2_norm_axes, l2_norm_axes_f, l2_norm_all, l2_norm_all_f);
-    trait_reduction!(OpArgMinAPI, argmin, argmin_f, argmin_axes, argmin_axes_f, argmin_all, argmin_all_f);
-    trait_reduction!(OpArgMaxAPI, argmax, argmax_f, argmax_axes, argmax_axes_f, argmax_all, argmax_all_f);
-    trait_reduction!(OpAllAPI, all, all_f, all_axes, all_axes_f, all_all, all_all_f);
-    trait_reduction!(OpAnyAPI, any, any_f, any_axes, any_axes_f, any_all, any_all_f);
-    trait_reduction!(OpCountNonZeroAPI, count_nonzero, count_nonzero_f, count_nonzero_axes, count_nonzero_axes_f, count_nonzero_all, count_nonzero_all_f);
+    trait_reduction!(OpSumAPI, sum, sum_f, sum_axes, sum_axes_f, sum_axes_keepdims, sum_axes_keepdims_f, sum_all, sum_all_f);
+    trait_reduction!(OpMinAPI, min, min_f, min_axes, min_axes_f, min_axes_keepdims, min_axes_keepdims_f, min_all, min_all_f);
+    trait_reduction!(OpMaxAPI, max, max_f, max_axes, max_axes_f, max_axes_keepdims, max_axes_keepdims_f, max_all, max_all_f);
+    trait_reduction!(OpProdAPI, prod, prod_f, prod_axes, prod_axes_f, prod_axes_keepdims, prod_axes_keepdims_f, prod_all, prod_all_f);
+    trait_reduction!(OpMeanAPI, mean, mean_f, mean_axes, mean_axes_f, mean_axes_keepdims, mean_axes_keepdims_f, mean_all, mean_all_f);
+    trait_reduction!(OpVarAPI, var, var_f, var_axes, var_axes_f, var_axes_keepdims, var_axes_keepdims_f, var_all, var_all_f);
+    trait_reduction!(OpStdAPI, std, std_f, std_axes, std_axes_f, std_axes_keepdims, std_axes_keepdims_f, std_all, std_all_f);
+    trait_reduction!(OpL2NormAPI, l2_norm, l2_norm_f, l2_norm_axes, l2_norm_axes_f, l2_norm_axes_keepdims, l2_norm_axes_keepdims_f, l2_norm_all, l2_norm_all_f);
+    trait_reduction!(OpNormAPI, norm, norm_f, norm_axes, norm_axes_f, norm_axes_keepdims, norm_axes_keepdims_f, norm_all, norm_all_f);
+    trait_reduction!(OpLogSumExpAPI, logsumexp, logsumexp_f, logsumexp_axes, logsumexp_axes_f, logsumexp_axes_keepdims, logsumexp_axes_keepdims_f, logsumexp_all, logsumexp_all_f);
+    trait_reduction!(OpArgMinAPI, argmin, argmin_f, argmin_axes, argmin_axes_f, argmin_axes_keepdims, argmin_axes_keepdims_f, argmin_all, argmin_all_f);
+    trait_reduction!(OpArgMaxAPI, argmax, argmax_f, argmax_axes, argmax_axes_f, argmax_axes_keepdims, argmax_axes_keepdims_f, argmax_all, argmax_all_f);
+    trait_reduction!(OpAllAPI, all, all_f, all_axes, all_axes_f, all_axes_keepdims, all_axes_keepdims_f, all_all, all_all_f);
+    trait_reduction!(OpAnyAPI, any, any_f, any_axes, any_axes_f, any_axes_keepdims, any_axes_keepdims_f, any_all, any_all_f);
+    trait_reduction!(OpCountNonZeroAPI, count_nonzero, count_nonzero_f, count_nonzero_axes, count_nonzero_axes_f, count_nonzero_axes_keepdims, count_nonzero_axes_keepdims_f, count_nonzero_all, count_nonzero_all_f);
 }
 pub use impl_trait_reduction::*;
 
 macro_rules! trait_reduction_arg {
-    ($OpReduceAPI: ident, $fn: ident, $fn_f: ident, $fn_axes: ident, $fn_axes_f: ident, $fn_all: ident, $fn_all_f: ident) => {
+    ($OpReduceAPI: ident, $fn: ident, $fn_f: ident, $fn_axes: ident, $fn_axes_f: ident, $fn_axes_keepdims: ident, $fn_axes_keepdims_f: ident, $fn_all: ident, $fn_all_f: ident) => {
         pub fn $fn_all_f<T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<D>
         where
             D: DimAPI,
@@ -188,6 +256,33 @@ macro_rules! trait_reduction_arg {
             $fn_axes_f(tensor, axes).rstsr_unwrap()
         }
 
+        pub fn $fn_axes_keepdims_f<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Result<Tensor<IxD, B, D>>
+        where
+            D: DimAPI,
+            B: $OpReduceAPI<T, D> + DeviceAPI<IxD> + DeviceCreationAnyAPI<IxD>,
+        {
+            let axes = axes.try_into().map_err(Into::into)?;
+            let tensor = tensor.view();
+            let ndim = tensor.ndim();
+            let (storage, layout) = $fn_axes_f(&tensor, axes.clone())?.into_raw_parts();
+            let layout = layout_reduce_keepdims(layout, ndim, axes)?;
+            Tensor::new_f(storage, layout)
+        }
+
+        pub fn $fn_axes_keepdims<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Tensor<IxD, B, D>
+        where
+            D: DimAPI,
+            B: $OpReduceAPI<T, D> + DeviceAPI<IxD> + DeviceCreationAnyAPI<IxD>,
+        {
+            $fn_axes_keepdims_f(tensor, axes).rstsr_unwrap()
+        }
+
         pub fn $fn_f<T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<D>
         where
             D: DimAPI,
@@ -235,6 +330,26 @@ macro_rules! trait_reduction_arg {
                 $fn_axes(self, axes)
             }
 
+            pub fn $fn_axes_keepdims_f(
+                &self,
+                axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+            ) -> Result<Tensor<IxD, B, D>>
+            where
+                B: DeviceAPI<IxD> + DeviceCreationAnyAPI<IxD>,
+            {
+                $fn_axes_keepdims_f(self, axes)
+            }
+
+            pub fn $fn_axes_keepdims(
+                &self,
+                axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+            ) -> Tensor<IxD, B, D>
+            where
+                B: DeviceAPI<IxD> + DeviceCreationAnyAPI<IxD>,
+            {
+                $fn_axes_keepdims(self, axes)
+            }
+
             pub fn $fn_f(&self) -> Result<D> {
                 $fn_f(self)
             }
@@ -252,6 +367,8 @@ trait_reduction_arg!(
     unraveled_argmin_f,
     unraveled_argmin_axes,
     unraveled_argmin_axes_f,
+    unraveled_argmin_axes_keepdims,
+    unraveled_argmin_axes_keepdims_f,
     unraveled_argmin_all,
     unraveled_argmin_all_f
 );
@@ -261,10 +378,72 @@ trait_reduction_arg!(
     unraveled_argmax_f,
     unraveled_argmax_axes,
     unraveled_argmax_axes_f,
+    unraveled_argmax_axes_keepdims,
+    unraveled_argmax_axes_keepdims_f,
     unraveled_argmax_all,
     unraveled_argmax_all_f
 );
 
+/* #region softmax */
+
+macro_rules! trait_softmax {
+    ($OpSoftmaxAPI: ident, $fn: ident, $fn_f: ident) => {
+        pub fn $fn_f<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Result<Tensor<T, B, D>>
+        where
+            D: DimAPI,
+            B: $OpSoftmaxAPI<T, D> + DeviceCreationAnyAPI<T>,
+        {
+            let axes = axes.try_into().map_err(Into::into)?;
+            let tensor = tensor.view();
+            let la = tensor.layout();
+            let axes = match axes {
+                AxesIndex::None => (0..la.ndim() as isize).collect_vec(),
+                _ => axes.as_ref().to_vec(),
+            };
+            let lc = layout_for_array_copy(la, TensorIterOrder::default())?;
+            let device = tensor.device();
+            let mut storage_c = device.uninit_impl(lc.bounds_index()?.1)?;
+            device.$fn(storage_c.raw_mut(), &lc, tensor.raw(), la, &axes)?;
+            let storage_c = unsafe { B::assume_init_impl(storage_c) }?;
+            Tensor::new_f(storage_c, lc)
+        }
+
+        pub fn $fn<T, B, D>(
+            tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+            axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        ) -> Tensor<T, B, D>
+        where
+            D: DimAPI,
+            B: $OpSoftmaxAPI<T, D> + DeviceCreationAnyAPI<T>,
+        {
+            $fn_f(tensor, axes).rstsr_unwrap()
+        }
+
+        impl<R, T, B, D> TensorAny<R, T, B, D>
+        where
+            R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+            D: DimAPI,
+            B: $OpSoftmaxAPI<T, D> + DeviceCreationAnyAPI<T>,
+        {
+            pub fn $fn_f(&self, axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>) -> Result<Tensor<T, B, D>> {
+                $fn_f(self, axes)
+            }
+
+            pub fn $fn(&self, axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>) -> Tensor<T, B, D> {
+                $fn(self, axes)
+            }
+        }
+    };
+}
+
+trait_softmax!(OpSoftmaxAPI, softmax, softmax_f);
+trait_softmax!(OpLogSoftmaxAPI, log_softmax, log_softmax_f);
+
+/* #endregion */
+
 /* #region sum (bool) */
 
 pub trait TensorSumBoolAPI<B, D>
@@ -286,6 +465,13 @@ where
     fn sum_axes(&self, axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>) -> Tensor<usize, B, IxD> {
         self.sum_axes_f(axes).rstsr_unwrap()
     }
+    fn sum_axes_keepdims_f(
+        &self,
+        axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+    ) -> Result<Tensor<usize, B, D>>;
+    fn sum_axes_keepdims(&self, axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>) -> Tensor<usize, B, D> {
+        self.sum_axes_keepdims_f(axes).rstsr_unwrap()
+    }
 }
 
 impl<R, B, D> TensorSumBoolAPI<B, D> for TensorAny<R, bool, B, D>
@@ -315,6 +501,217 @@ where
             },
         }
     }
+    fn sum_axes_keepdims_f(
+        &self,
+        axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+    ) -> Result<Tensor<usize, B, D>> {
+        let axes = axes.try_into().map_err(Into::into)?;
+        let (storage, layout) = self.sum_axes_f(axes.clone())?.into_raw_parts();
+        let layout = layout_reduce_keepdims(layout, self.ndim(), axes)?;
+        Tensor::new_f(storage, layout)
+    }
+}
+
+/* #endregion */
+
+/* #region sum (accumulator type and output) */
+
+pub fn sum_acc_all_f<TAcc, T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<TAcc>
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    let tensor = tensor.view();
+    tensor.device().sum_acc_all(tensor.raw(), tensor.layout())
+}
+
+pub fn sum_acc_axes_f<TAcc, T, B, D>(
+    tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+    axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+) -> Result<Tensor<TAcc, B, IxD>>
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D> + DeviceCreationAnyAPI<TAcc>,
+{
+    let axes = axes.try_into().map_err(Into::into)?;
+    let tensor = tensor.view();
+
+    match axes {
+        AxesIndex::None => {
+            let sum = tensor.device().sum_acc_all(tensor.raw(), tensor.layout())?;
+            let storage = tensor.device().outof_cpu_vec(vec![sum])?;
+            let layout = Layout::new(vec![], vec![], 0)?;
+            Tensor::new_f(storage, layout)
+        },
+        _ => {
+            let (storage, layout) = tensor.device().sum_acc_axes(tensor.raw(), tensor.layout(), axes.as_ref())?;
+            Tensor::new_f(storage, layout)
+        },
+    }
+}
+
+pub fn sum_acc_all<TAcc, T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> TAcc
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    sum_acc_all_f(tensor).rstsr_unwrap()
+}
+
+pub fn sum_acc_axes<TAcc, T, B, D>(
+    tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+    axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+) -> Tensor<TAcc, B, IxD>
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D> + DeviceCreationAnyAPI<TAcc>,
+{
+    sum_acc_axes_f(tensor, axes).rstsr_unwrap()
+}
+
+pub fn sum_acc_f<TAcc, T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> Result<TAcc>
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    sum_acc_all_f(tensor)
+}
+
+pub fn sum_acc<TAcc, T, B, D>(tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>) -> TAcc
+where
+    D: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    sum_acc_all(tensor)
+}
+
+/// Sum over `axes` and write to the preallocated output `out`.
+///
+/// Accumulation is performed in the element type of `out`. The shape of `out` can be either the
+/// reduced shape (reduced axes removed), or the keepdims shape (reduced axes kept as size one).
+pub fn sum_with_output_f<TAcc, T, B, D, DC>(
+    tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+    axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+    mut out: impl TensorViewMutAPI<Type = TAcc, Backend = B, Dim = DC>,
+) -> Result<()>
+where
+    D: DimAPI,
+    DC: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    let axes = axes.try_into().map_err(Into::into)?;
+    let (tensor, mut out) = (tensor.view(), out.view_mut());
+    rstsr_assert!(out.device().same_device(tensor.device()), DeviceMismatch)?;
+
+    let ndim = tensor.ndim();
+    let axes = match axes {
+        AxesIndex::None => (0..ndim as isize).collect_vec(),
+        _ => normalize_axes_index(axes, ndim, false, true)?,
+    };
+
+    let lc = out.layout().to_dim::<IxD>()?;
+    let lc = if lc.ndim() == ndim && !axes.is_empty() {
+        // keepdims shape of output, remove the size-one reduced axes
+        let (ls, lm) = lc.dim_split_axes(&axes)?;
+        rstsr_assert!(
+            ls.shape().iter().all(|&n| n == 1),
+            InvalidLayout,
+            "reduced axes of output in keepdims shape should be of size one"
+        )?;
+        lm
+    } else {
+        lc
+    };
+
+    let device = tensor.device().clone();
+    device.sum_acc_axes_to(out.raw_mut(), &lc, tensor.raw(), tensor.layout(), &axes)
+}
+
+pub fn sum_with_output<TAcc, T, B, D, DC>(
+    tensor: impl TensorViewAPI<Type = T, Backend = B, Dim = D>,
+    axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+    out: impl TensorViewMutAPI<Type = TAcc, Backend = B, Dim = DC>,
+) where
+    D: DimAPI,
+    DC: DimAPI,
+    B: OpSumAccAPI<T, TAcc, D>,
+{
+    sum_with_output_f(tensor, axes, out).rstsr_unwrap()
+}
+
+impl<R, T, B, D> TensorAny<R, T, B, D>
+where
+    R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    D: DimAPI,
+    B: DeviceAPI<T>,
+{
+    pub fn sum_acc_all_f<TAcc>(&self) -> Result<TAcc>
+    where
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_acc_all_f(self)
+    }
+
+    pub fn sum_acc_all<TAcc>(&self) -> TAcc
+    where
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_acc_all(self)
+    }
+
+    pub fn sum_acc_axes_f<TAcc>(
+        &self,
+        axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+    ) -> Result<Tensor<TAcc, B, IxD>>
+    where
+        B: OpSumAccAPI<T, TAcc, D> + DeviceCreationAnyAPI<TAcc>,
+    {
+        sum_acc_axes_f(self, axes)
+    }
+
+    pub fn sum_acc_axes<TAcc>(&self, axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>) -> Tensor<TAcc, B, IxD>
+    where
+        B: OpSumAccAPI<T, TAcc, D> + DeviceCreationAnyAPI<TAcc>,
+    {
+        sum_acc_axes(self, axes)
+    }
+
+    pub fn sum_acc_f<TAcc>(&self) -> Result<TAcc>
+    where
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_acc_f(self)
+    }
+
+    pub fn sum_acc<TAcc>(&self) -> TAcc
+    where
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_acc(self)
+    }
+
+    pub fn sum_with_output_f<TAcc, DC>(
+        &self,
+        axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        out: impl TensorViewMutAPI<Type = TAcc, Backend = B, Dim = DC>,
+    ) -> Result<()>
+    where
+        DC: DimAPI,
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_with_output_f(self, axes, out)
+    }
+
+    pub fn sum_with_output<TAcc, DC>(
+        &self,
+        axes: impl TryInto<AxesIndex<isize>, Error: Into<Error>>,
+        out: impl TensorViewMutAPI<Type = TAcc, Backend = B, Dim = DC>,
+    ) where
+        DC: DimAPI,
+        B: OpSumAccAPI<T, TAcc, D>,
+    {
+        sum_with_output(self, axes, out)
+    }
 }
 
 /* #endregion */
@@ -529,6 +926,101 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_axes_keepdims() {
+        // DeviceCpuSerial
+        let a = arange((24.0, &DeviceCpuSerial::default())).into_shape([2, 3, 4]).into_dim::<Ix3>();
+        let s: Tensor<f64, _, Ix3> = a.sum_axes_keepdims([0, -1]);
+        assert_eq!(s.shape(), &[1, 3, 1]);
+        assert!(allclose(s.reshape(-1), a.sum_axes([0, -1]), None));
+        let s = a.mean_axes_keepdims(None);
+        assert_eq!(s.shape(), &[1, 1, 1]);
+        assert_eq!(s.to_scalar(), 11.5);
+
+        // broadcast back to input without expand_dims
+        let b = &a - a.mean_axes_keepdims(-1);
+        assert!(b.sum_axes(-1).abs().max_all() < 1e-12);
+
+        // arg reductions
+        let m = a.argmax_axes_keepdims(1);
+        assert_eq!(m.shape(), &[2, 1, 4]);
+        assert!(m.raw().iter().all(|&x| x == 2));
+        let m = a.unraveled_argmin_axes_keepdims([0, 2]);
+        assert_eq!(m.shape(), &[1, 3, 1]);
+        assert_eq!(m.raw()[1], vec![0, 0]);
+
+        // boolean sum
+        let c = greater_equal(&a, 12.0);
+        let s = c.sum_axes_keepdims(0);
+        assert_eq!(s.shape(), &[1, 3, 4]);
+        assert_eq!(s.sum_all(), 12);
+
+        // DeviceFaer
+        let a: Tensor<f64, _, IxD> = arange(24.0).into_shape([2, 3, 4]).into_dyn();
+        let s = a.sum_axes_keepdims(1);
+        assert_eq!(s.shape(), &[2, 1, 4]);
+        assert!(allclose(s.reshape([2, 4]), a.sum_axes(1), None));
+    }
+
+    #[test]
+    fn test_sum_acc() {
+        // DeviceCpuSerial
+        let device = DeviceCpuSerial::default();
+
+        // i32 overflows, accumulate in i64
+        let a = full(([4, 3], i32::MAX, &device));
+        let s = a.sum_acc_all::<i64>();
+        assert_eq!(s, 12 * i32::MAX as i64);
+        let s = a.sum_acc_axes::<i64>(0);
+        assert_eq!(s.raw(), &vec![4 * i32::MAX as i64; 3]);
+        let mut out: Tensor<i64, _> = zeros(([3, 2], &device));
+        a.sum_with_output(0, out.i_mut((.., 1)));
+        assert_eq!(out.i((.., 0)).to_vec(), vec![0; 3]);
+        assert_eq!(out.i((.., 1)).to_vec(), vec![4 * i32::MAX as i64; 3]);
+
+        // f16 loses precision, accumulate in f32
+        let a = full((vec![4096], half::f16::from_f32(0.1), &device));
+        let exact = 4096.0 * half::f16::from_f32(0.1).to_f32();
+        assert!((a.sum_all().to_f32() - exact).abs() > 1.0);
+        assert!((a.sum_acc_all::<f32>() - exact).abs() < 1e-3);
+
+        // DeviceFaer
+        let a: Tensor<i32> = full(([4, 3], i32::MAX));
+        let mut out: Tensor<i64> = zeros(vec![3]);
+        a.sum_with_output(0, &mut out);
+        assert_eq!(out.raw(), &vec![4 * i32::MAX as i64; 3]);
+
+        // keepdims-shaped output, and output of strided view
+        let a: Tensor<f64> = arange(24.0).into_shape([2, 3, 4]);
+        let mut out: Tensor<f64> = zeros([2, 1, 2]);
+        sum_with_output(a.i((.., .., ..2)), 1, &mut out);
+        assert!(allclose(out.reshape([2, 2]), a.i((.., .., ..2)).sum_axes(1), None));
+        let mut out: Tensor<f64> = zeros([3, 2]);
+        a.sum_with_output([0, 2], out.i_mut((.., 1)));
+        assert_eq!(out.i((.., 0)).sum_all(), 0.0);
+        assert!(allclose(out.i((.., 1)), a.sum_axes([0, 2]), None));
+        let mut out: Tensor<f64> = zeros([]);
+        a.sum_with_output(None, &mut out);
+        assert_eq!(out.to_scalar(), 276.0);
+
+        // shape mismatch
+        let mut out: Tensor<f64> = zeros([2, 2]);
+        assert!(a.sum_with_output_f(1, &mut out).is_err());
+
+        // large enough for parallel reduction, transposed output and broadcasted input
+        let a: Tensor<f64> = arange(6000.0).into_shape([20, 30, 10]);
+        let mut out: Tensor<f64> = zeros([10, 20]);
+        a.sum_with_output(1, out.view_mut().into_reverse_axes());
+        assert!(allclose(out.t(), a.sum_axes(1), None));
+        let b = a.i((.., 0, None)).into_broadcast(vec![20, 40, 10]);
+        let mut out: Tensor<f64> = zeros([10]);
+        b.sum_with_output([0, 1], &mut out);
+        assert!(allclose(&out, b.to_owned().sum_axes([0, 1]), None));
+        let mut out: Tensor<f64> = zeros([40, 10]);
+        b.sum_with_output(0, &mut out);
+        assert!(allclose(&out, b.to_owned().sum_axes(0), None));
+    }
+
     #[test]
     fn test_min() {
         // DeviceCpuSerial
@@ -741,6 +1233,91 @@ mod test {
         assert!((m - 33.21144381083123).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_norm() {
+        // DeviceCpuSerial
+        let vr = [8, 4, 2, 9, 3, 7, 2, 8, 1, 6, 10, 5];
+        let vi = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let v = vr
+            .iter()
+            .zip(vi.iter())
+            .map(|(r, i)| num::Complex::new(r.to_f64().unwrap(), i.to_f64().unwrap()))
+            .collect::<Vec<_>>();
+        let a = asarray((&v, [4, 3].c(), &DeviceCpuSerial::default()));
+        let m = a.norm_all();
+        assert!((m - 33.21144381083123).abs() < 1e-10);
+        let m = a.norm_axes(0);
+        assert!(allclose_f64(&m, &a.l2_norm_axes(0)));
+
+        // overflow-safe: l2_norm overflows, while norm should not
+        let a = asarray((vec![1e200_f64, -3e200, 4e200], &DeviceCpuSerial::default()));
+        assert!(a.l2_norm_all().is_infinite());
+        let m = a.norm_all();
+        assert!((m / 26.0_f64.sqrt() - 1e200).abs() < 1e190);
+
+        // DeviceFaer
+        let a = asarray(vec![1e-200, -3e-200, 4e-200]);
+        let m = a.norm_all();
+        assert!((m / 26.0_f64.sqrt() - 1e-200).abs() < 1e-210);
+
+        let a: Tensor<f64> = linspace((-1.0, 1.0, 4096)).into_shape([16, 256]) * 1e300;
+        let m = a.norm_axes(-1);
+        let m_ref = (a / 1e300).l2_norm_axes(-1) * 1e300;
+        assert!(allclose_f64(&m, &m_ref));
+    }
+
+    #[test]
+    fn test_logsumexp() {
+        // DeviceCpuSerial
+        // a = np.array([[1000, 1001, 1002], [-2, -1, 0]])
+        // scipy.special.logsumexp(a, axis=-1)
+        let a = asarray((vec![1000.0, 1001.0, 1002.0, -2.0, -1.0, 0.0], [2, 3].c(), &DeviceCpuSerial::default()));
+        let m = a.logsumexp_axes(-1);
+        assert!(allclose_f64(&m, &asarray((vec![1002.40760596444, 0.40760596444437], &DeviceCpuSerial::default()))));
+        let m = a.logsumexp_all();
+        assert!((m - 1002.40760596444).abs() < 1e-10);
+
+        let a = asarray((vec![f64::NEG_INFINITY; 3], &DeviceCpuSerial::default()));
+        assert_eq!(a.logsumexp_all(), f64::NEG_INFINITY);
+
+        // DeviceFaer
+        // a = np.linspace(-500, 500, 65536).reshape(256, 256)
+        // scipy.special.logsumexp(a, axis=0)[[0, -1]]
+        let a: Tensor<f64> = linspace((-500.0, 500.0, 65536)).into_shape([256, 256]);
+        let m = a.logsumexp_axes(0);
+        let m_ref = (&a - 500.0).exp().sum_axes(0).log() + 500.0;
+        assert!(allclose_f64(&m, &m_ref));
+        let m = a.logsumexp_all();
+        let m_ref = (&a - 500.0).exp().sum_all().ln() + 500.0;
+        assert!((m - m_ref).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_softmax() {
+        // DeviceCpuSerial
+        // a = np.array([[1000, 1001, 1002], [-2, -1, 0]])
+        // scipy.special.softmax(a, axis=-1)
+        let a = asarray((vec![1000.0, 1001.0, 1002.0, -2.0, -1.0, 0.0], [2, 3].c(), &DeviceCpuSerial::default()));
+        let s = a.softmax(-1);
+        let s_ref = vec![0.09003057, 0.24472847, 0.66524096, 0.09003057, 0.24472847, 0.66524096];
+        assert_eq!(s.shape(), &[2, 3]);
+        assert!(allclose_f64(&s, &asarray((s_ref, [2, 3].c(), &DeviceCpuSerial::default()))));
+        let s = a.log_softmax(-1);
+        let s_ref = vec![-2.40760596, -1.40760596, -0.40760596, -2.40760596, -1.40760596, -0.40760596];
+        assert!(allclose_f64(&s, &asarray((s_ref, [2, 3].c(), &DeviceCpuSerial::default()))));
+
+        // DeviceFaer
+        let a: Tensor<f64> = linspace((-500.0, 500.0, 65536)).into_shape([256, 256]).into_swapaxes(0, 1);
+        let s = a.softmax(0);
+        let s_sum = s.sum_axes(0);
+        assert!(allclose_f64(&s_sum, &ones(vec![256])));
+        let s_ref = (&a - a.max_axes(0)).exp();
+        let s_ref = &s_ref / s_ref.sum_axes(0);
+        assert!(allclose_f64(&s, &s_ref));
+        let s = a.log_softmax((0, 1));
+        assert!((s.exp().sum_all() - 1.0).abs() < 1e-10);
+    }
+
     #[test]
     #[cfg(feature = "rayon")]
     fn test_large_std() {
@@ -0,0 +1,83 @@
+//! Cache-blocked generic matmul, compared to the naive triple loop.
+//!
+//! Shapes cross the block sizes of packing, and operands are of contiguous,
+//! transposed, negative and non-unit strides. Benchmarks are in
+//! `benches/matmul_blocked.rs`.
+
+use rstsr_core::prelude_dev::*;
+
+/// Layouts of `[m, n]` matrix: row-major, col-major, reversed rows, and
+/// strided with offset. Returns each layout with the buffer length it needs.
+fn layouts(m: usize, n: usize) -> Vec<(Layout<Ix2>, usize)> {
+    let (mi, ni) = (m as isize, n as isize);
+    vec![
+        (Layout::new([m, n], [ni, 1], 0).unwrap(), m * n),
+        (Layout::new([m, n], [1, mi], 0).unwrap(), m * n),
+        (Layout::new([m, n], [-ni, 1], m.saturating_sub(1) * n).unwrap(), m * n),
+        (Layout::new([m, n], [2, 2 * mi + 1], 3).unwrap(), 2 * m * n + n + 3),
+    ]
+}
+
+fn check_blocked<T>(m: usize, n: usize, k: usize, f: impl Fn(usize) -> T, alpha: T, beta: T)
+where
+    T: Clone + Send + Sync + core::ops::Mul<Output = T> + core::ops::Add<Output = T> + num::Zero,
+    T: PartialEq + core::fmt::Debug,
+{
+    for ((la, len_a), (lb, len_b), (lc, len_c)) in itertools::iproduct!(layouts(m, k), layouts(k, n), layouts(m, n)) {
+        let a = (0..len_a).map(&f).collect::<Vec<_>>();
+        let b = (0..len_b).map(|i| f(i + 7)).collect::<Vec<_>>();
+        let c = (0..len_c).map(|i| f(i + 13)).collect::<Vec<_>>();
+
+        let mut c_naive = c.clone();
+        gemm_naive_cpu_serial(&mut c_naive, &lc, &a, &la, &b, &lb, alpha.clone(), beta.clone()).unwrap();
+
+        let mut c_blocked = c.clone();
+        gemm_blocked_cpu_serial(&mut c_blocked, &lc, &a, &la, &b, &lb, alpha.clone(), beta.clone()).unwrap();
+        assert_eq!(c_naive, c_blocked);
+
+        #[cfg(feature = "rayon")]
+        {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+            let mut c_rayon = c.clone();
+            gemm_blocked_cpu_rayon(&mut c_rayon, &lc, &a, &la, &b, &lb, alpha.clone(), beta.clone(), Some(&pool))
+                .unwrap();
+            assert_eq!(c_naive, c_rayon);
+        }
+    }
+}
+
+#[test]
+fn test_matmul_blocked_i64() {
+    let f = |i: usize| (i % 17) as i64 - 8;
+    for (m, n, k) in [(1, 1, 1), (5, 7, 3), (67, 9, 300)] {
+        check_blocked::<i64>(m, n, k, f, 1, 0);
+        check_blocked::<i64>(m, n, k, f, 2, -3);
+    }
+    // empty contraction only scales C
+    check_blocked::<i64>(4, 5, 0, f, 2, -3);
+}
+
+#[test]
+fn test_matmul_blocked_complex_i64() {
+    let f = |i: usize| num::Complex::new((i % 17) as i64 - 8, (i % 5) as i64 - 2);
+    let (alpha, beta) = (num::Complex::new(2, -1), num::Complex::new(0, 3));
+    for (m, n, k) in [(3, 5, 2), (66, 5, 260)] {
+        check_blocked(m, n, k, f, alpha, beta);
+    }
+}
+
+#[test]
+fn test_matmul_blocked_beta_zero() {
+    // C is not read when beta is zero
+    let (m, n, k) = (9, 6, 5);
+    let la = Layout::new([m, k], [k as isize, 1], 0).unwrap();
+    let lb = Layout::new([k, n], [1, k as isize], 0).unwrap();
+    let lc = Layout::new([m, n], [n as isize, 1], 0).unwrap();
+    let a = (0..m * k).map(|i| i as f64).collect::<Vec<_>>();
+    let b = (0..k * n).map(|i| 1.0 - i as f64).collect::<Vec<_>>();
+    let mut c_naive = vec![0.0; m * n];
+    gemm_naive_cpu_serial(&mut c_naive, &lc, &a, &la, &b, &lb, 1.0, 0.0).unwrap();
+    let mut c_blocked = vec![f64::NAN; m * n];
+    gemm_blocked_cpu_serial(&mut c_blocked, &lc, &a, &la, &b, &lb, 1.0, 0.0).unwrap();
+    assert_eq!(c_naive, c_blocked);
+}
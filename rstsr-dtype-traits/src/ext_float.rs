@@ -1,33 +1,186 @@
+use duplicate::duplicate_item;
+
 /// Extension trait for float types ([`num::Float`]).
+///
+/// Functions here are mostly special functions that are not provided by [`num::Float`]. They are
+/// implemented by [`libm`], so no_std is still available.
 pub trait ExtFloat: Clone {
     /// Returns the next representable floating-point value after `self` in the direction of
     /// `other`.
     fn ext_nextafter(self, other: Self) -> Self;
+
+    /// Error function.
+    fn ext_erf(self) -> Self;
+
+    /// Complementary error function `1 - erf(x)`.
+    fn ext_erfc(self) -> Self;
+
+    /// Scaled complementary error function `exp(x^2) * erfc(x)`.
+    ///
+    /// This function does not overflow/underflow for large positive `x`.
+    fn ext_erfcx(self) -> Self;
+
+    /// Gamma function.
+    fn ext_gamma(self) -> Self;
+
+    /// Natural logarithm of the absolute value of gamma function.
+    fn ext_lgamma(self) -> Self;
+
+    /// Computes `2^x`.
+    fn ext_exp2(self) -> Self;
+
+    /// Cube root.
+    fn ext_cbrt(self) -> Self;
+
+    /// Normalized sinc function `sin(pi x) / (pi x)`.
+    fn ext_sinc(self) -> Self;
+
+    /// Converts angles from degrees to radians.
+    fn ext_deg2rad(self) -> Self;
+
+    /// Converts angles from radians to degrees.
+    fn ext_rad2deg(self) -> Self;
+
+    /// Remainder of division, with the same sign of dividend `self` (C `fmod`).
+    fn ext_fmod(self, other: Self) -> Self;
+
+    /// Remainder of division, with the same sign of divisor `other` (Python `%`, or
+    /// `numpy.remainder`).
+    ///
+    /// Note that this is not the IEEE 754 `remainder` function.
+    fn ext_remainder(self, other: Self) -> Self;
+
+    /// Computes `self * 2^n`.
+    fn ext_ldexp(self, n: i32) -> Self;
+
+    /// Decomposes into mantissa (in range `[0.5, 1)` by absolute value) and exponent of 2.
+    fn ext_frexp(self) -> (Self, i32);
 }
 
-impl ExtFloat for f32 {
+#[duplicate_item(
+     T     nextafter           erf           erfc           exp           gamma           lgamma           exp2           cbrt           sin           fmod           ldexp           frexp           sqrt           PI                          ;
+    [f32] [libm::nextafterf] [libm::erff] [libm::erfcf] [libm::expf] [libm::tgammaf] [libm::lgammaf] [libm::exp2f] [libm::cbrtf] [libm::sinf] [libm::fmodf] [libm::ldexpf] [libm::frexpf] [libm::sqrtf] [core::f32::consts::PI];
+    [f64] [libm::nextafter ] [libm::erf ] [libm::erfc ] [libm::exp ] [libm::tgamma ] [libm::lgamma ] [libm::exp2 ] [libm::cbrt ] [libm::sin ] [libm::fmod ] [libm::ldexp ] [libm::frexp ] [libm::sqrt ] [core::f64::consts::PI];
+)]
+impl ExtFloat for T {
     fn ext_nextafter(self, other: Self) -> Self {
-        libm::nextafterf(self, other)
+        nextafter(self, other)
     }
-}
 
-impl ExtFloat for f64 {
-    fn ext_nextafter(self, other: Self) -> Self {
-        libm::nextafter(self, other)
+    fn ext_erf(self) -> Self {
+        erf(self)
     }
-}
 
-#[cfg(feature = "half")]
-impl ExtFloat for half::bf16 {
-    fn ext_nextafter(self, other: Self) -> Self {
-        nextafter_bf16(self, other)
+    fn ext_erfc(self) -> Self {
+        erfc(self)
+    }
+
+    fn ext_erfcx(self) -> Self {
+        if self.is_nan() {
+            self
+        } else if self < 0.0 {
+            2.0 * exp(self * self) - (-self).ext_erfcx()
+        } else if self < 5.0 {
+            exp(self * self) * erfc(self)
+        } else {
+            // continued fraction of erfc, evaluated backwards
+            let mut t = self;
+            for k in (1..=60).rev() {
+                t = self + (k as T * 0.5) / t;
+            }
+            1.0 / (t * sqrt(PI))
+        }
+    }
+
+    fn ext_gamma(self) -> Self {
+        gamma(self)
+    }
+
+    fn ext_lgamma(self) -> Self {
+        lgamma(self)
+    }
+
+    fn ext_exp2(self) -> Self {
+        exp2(self)
+    }
+
+    fn ext_cbrt(self) -> Self {
+        cbrt(self)
+    }
+
+    fn ext_sinc(self) -> Self {
+        if self == 0.0 {
+            1.0
+        } else {
+            let x = PI * self;
+            sin(x) / x
+        }
+    }
+
+    fn ext_deg2rad(self) -> Self {
+        self * (PI / 180.0)
+    }
+
+    fn ext_rad2deg(self) -> Self {
+        self * (180.0 / PI)
+    }
+
+    fn ext_fmod(self, other: Self) -> Self {
+        fmod(self, other)
+    }
+
+    fn ext_remainder(self, other: Self) -> Self {
+        let r = fmod(self, other);
+        if r != 0.0 && ((r < 0.0) != (other < 0.0)) {
+            r + other
+        } else {
+            r
+        }
+    }
+
+    fn ext_ldexp(self, n: i32) -> Self {
+        ldexp(self, n)
+    }
+
+    fn ext_frexp(self) -> (Self, i32) {
+        frexp(self)
     }
 }
 
 #[cfg(feature = "half")]
-impl ExtFloat for half::f16 {
+#[duplicate_item(
+     T            nextafter       ;
+    [half::bf16] [nextafter_bf16];
+    [half::f16 ] [nextafter_f16 ];
+)]
+impl ExtFloat for T {
     fn ext_nextafter(self, other: Self) -> Self {
-        nextafter_f16(self, other)
+        nextafter(self, other)
+    }
+
+    /* functions below are evaluated in f32 */
+
+    #[duplicate_item(
+        func;
+        [ext_erf]; [ext_erfc]; [ext_erfcx]; [ext_gamma]; [ext_lgamma]; [ext_exp2]; [ext_cbrt];
+        [ext_sinc]; [ext_deg2rad]; [ext_rad2deg];
+    )]
+    fn func(self) -> Self {
+        T::from_f32(self.to_f32().func())
+    }
+
+    #[duplicate_item(func; [ext_fmod]; [ext_remainder];)]
+    fn func(self, other: Self) -> Self {
+        T::from_f32(self.to_f32().func(other.to_f32()))
+    }
+
+    fn ext_ldexp(self, n: i32) -> Self {
+        T::from_f32(self.to_f32().ext_ldexp(n))
+    }
+
+    fn ext_frexp(self) -> (Self, i32) {
+        let (m, e) = self.to_f32().ext_frexp();
+        (T::from_f32(m), e)
     }
 }
 
@@ -128,3 +281,34 @@ mod impl_half {
 pub use impl_half::*;
 
 /* #endregion */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfcx() {
+        let cases = [
+            (1.0_f64, 0.427583576155807),
+            (5.0, 0.11070463773306863),
+            (10.0, 0.05614099274382259),
+            (30.0, 0.01879588886141675),
+            (-1.0, 5.008980080762283),
+        ];
+        for (x, y) in cases {
+            assert!((x.ext_erfcx() - y).abs() < 1e-13 * y);
+        }
+        assert!((5.0_f32.ext_erfcx() - 0.11070464).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(5.0_f64.ext_fmod(-3.0), 2.0);
+        assert_eq!(5.0_f64.ext_remainder(-3.0), -1.0);
+        assert_eq!((-5.0_f64).ext_fmod(3.0), -2.0);
+        assert_eq!((-5.0_f64).ext_remainder(3.0), 1.0);
+        assert_eq!(6.0_f64.ext_remainder(-3.0), 0.0);
+        assert_eq!(3.0_f64.ext_frexp(), (0.75, 2));
+        assert_eq!(0.75_f64.ext_ldexp(2), 3.0);
+    }
+}
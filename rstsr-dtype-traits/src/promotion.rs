@@ -558,3 +558,94 @@ impl DTypeCastAPI<c64> for c32 {
 }
 
 /* #endregion */
+
+/* #region half to primitive float */
+
+#[cfg(feature = "half")]
+macro_rules! impl_promotion_half_float {
+    ($THalf:ty, $TFloat:ty, $to_float:ident, $from_float:ident) => {
+        impl DTypePromoteAPI<$TFloat> for $THalf {
+            type Res = $TFloat;
+            const CAN_CAST_SELF: bool = false;
+            const CAN_CAST_OTHER: bool = true;
+            #[inline]
+            fn promote_self(self) -> Self::Res {
+                self.$to_float()
+            }
+            #[inline]
+            fn promote_other(val: $TFloat) -> Self::Res {
+                val
+            }
+        }
+
+        impl DTypePromoteAPI<$THalf> for $TFloat {
+            type Res = $TFloat;
+            const CAN_CAST_SELF: bool = true;
+            const CAN_CAST_OTHER: bool = false;
+            #[inline]
+            fn promote_self(self) -> Self::Res {
+                self
+            }
+            #[inline]
+            fn promote_other(val: $THalf) -> Self::Res {
+                val.$to_float()
+            }
+        }
+
+        impl DTypeCastAPI<$TFloat> for $THalf {
+            #[inline]
+            fn into_cast(self) -> $TFloat {
+                self.$to_float()
+            }
+        }
+
+        impl DTypeCastAPI<$THalf> for $TFloat {
+            #[inline]
+            fn into_cast(self) -> $THalf {
+                <$THalf>::$from_float(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+impl_promotion_half_float!(half::f16, f32, to_f32, from_f32);
+#[cfg(feature = "half")]
+impl_promotion_half_float!(half::f16, f64, to_f64, from_f64);
+#[cfg(feature = "half")]
+impl_promotion_half_float!(half::bf16, f32, to_f32, from_f32);
+#[cfg(feature = "half")]
+impl_promotion_half_float!(half::bf16, f64, to_f64, from_f64);
+
+#[cfg(feature = "half")]
+#[duplicate_item(
+    THalf1        THalf2      ;
+   [half::f16 ] [half::bf16];
+   [half::bf16] [half::f16 ];
+)]
+impl DTypePromoteAPI<THalf2> for THalf1 {
+    type Res = f32;
+    #[inline]
+    fn promote_self(self) -> Self::Res {
+        self.to_f32()
+    }
+    #[inline]
+    fn promote_other(val: THalf2) -> Self::Res {
+        val.to_f32()
+    }
+}
+
+#[cfg(feature = "half")]
+#[duplicate_item(
+    THalf1        THalf2      ;
+   [half::f16 ] [half::bf16];
+   [half::bf16] [half::f16 ];
+)]
+impl DTypeCastAPI<THalf2> for THalf1 {
+    #[inline]
+    fn into_cast(self) -> THalf2 {
+        THalf2::from_f32(self.to_f32())
+    }
+}
+
+/* #endregion */
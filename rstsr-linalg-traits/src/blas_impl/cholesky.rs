@@ -1,22 +1,23 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 /* #region full-args */
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> CholeskyAPI<DeviceBLAS> for (Tr, Option<FlagUpLo>)
+impl<ImplType> CholeskyAPI<DeviceBLAS<B>> for (Tr, Option<FlagUpLo>)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T, DeviceBLAS, D>;
+    type Out = Tensor<T, DeviceBLAS<B>, D>;
     fn cholesky_f(self) -> Result<Self::Out> {
         let (a, uplo) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -27,15 +28,16 @@ where
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> CholeskyAPI<DeviceBLAS> for (Tr, Option<FlagUpLo>)
+impl<ImplType> CholeskyAPI<DeviceBLAS<B>> for (Tr, Option<FlagUpLo>)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = Tr;
     fn cholesky_f(self) -> Result<Self::Out> {
@@ -56,34 +58,36 @@ where
     ImplStruct        args_tuple  internal_tuple  ;
    [(Tr, FlagUpLo)] [(a, uplo)] [(a, Some(uplo))];
 )]
-impl<Tr> CholeskyAPI<DeviceBLAS> for ImplStruct
+impl<B, Tr> CholeskyAPI<DeviceBLAS<B>> for ImplStruct
 where
-    (Tr, Option<FlagUpLo>): CholeskyAPI<DeviceBLAS>,
+    B: CblasBackendAPI,
+    (Tr, Option<FlagUpLo>): CholeskyAPI<DeviceBLAS<B>>,
 {
-    type Out = <(Tr, Option<FlagUpLo>) as CholeskyAPI<DeviceBLAS>>::Out;
+    type Out = <(Tr, Option<FlagUpLo>) as CholeskyAPI<DeviceBLAS<B>>>::Out;
     fn cholesky_f(self) -> Result<Self::Out> {
         let args_tuple = self;
-        CholeskyAPI::<DeviceBLAS>::cholesky_f(internal_tuple)
+        CholeskyAPI::<DeviceBLAS<B>>::cholesky_f(internal_tuple)
     }
 }
 
 #[duplicate_item(
-    ImplType                              Tr;
-   ['a, T, D, R: DataAPI<Data = Vec<T>>] [&'a TensorAny<R, T, DeviceBLAS, D>];
-   ['a, T, D,                          ] [TensorView<'a, T, DeviceBLAS, D>  ];
-   [    T, D                           ] [Tensor<T, DeviceBLAS, D>          ];
-   ['a, T, D                           ] [TensorMut<'a, T, DeviceBLAS, D>   ];
+    ImplType                                 Tr                                    ;
+   ['a, B, T, D, R: DataAPI<Data = Vec<T>>] [&'a TensorAny<R, T, DeviceBLAS<B>, D>];
+   ['a, B, T, D,                          ] [TensorView<'a, T, DeviceBLAS<B>, D>  ];
+   [    B, T, D                           ] [Tensor<T, DeviceBLAS<B>, D>          ];
+   ['a, B, T, D                           ] [TensorMut<'a, T, DeviceBLAS<B>, D>   ];
 )]
-impl<ImplType> CholeskyAPI<DeviceBLAS> for Tr
+impl<ImplType> CholeskyAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    (Tr, Option<FlagUpLo>): CholeskyAPI<DeviceBLAS>,
+    (Tr, Option<FlagUpLo>): CholeskyAPI<DeviceBLAS<B>>,
 {
-    type Out = <(Tr, Option<FlagUpLo>) as CholeskyAPI<DeviceBLAS>>::Out;
+    type Out = <(Tr, Option<FlagUpLo>) as CholeskyAPI<DeviceBLAS<B>>>::Out;
     fn cholesky_f(self) -> Result<Self::Out> {
         let a = self;
-        CholeskyAPI::<DeviceBLAS>::cholesky_f((a, None))
+        CholeskyAPI::<DeviceBLAS<B>>::cholesky_f((a, None))
     }
 }
 
@@ -1,19 +1,20 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
 use num::complex::ComplexFloat;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> DetAPI<DeviceBLAS> for Tr
+impl<ImplType> DetAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = T;
     fn det_f(self) -> Result<Self::Out> {
@@ -26,15 +27,16 @@ where
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> DetAPI<DeviceBLAS> for Tr
+impl<ImplType> DetAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = T;
     fn det_f(self) -> Result<Self::Out> {
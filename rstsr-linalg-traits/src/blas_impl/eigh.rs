@@ -1,23 +1,24 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 /* #region simple eigh */
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for (Tr, FlagUpLo)
+impl<ImplType> EighAPI<DeviceBLAS<B>> for (Tr, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tensor<T, DeviceBLAS<B>, D>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let (a, uplo) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -31,41 +32,43 @@ where
 }
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for Tr
+impl<ImplType> EighAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tensor<T, DeviceBLAS<B>, D>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let a = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EighAPI::<DeviceBLAS>::eigh_f((a, uplo))
+        EighAPI::<DeviceBLAS<B>>::eigh_f((a, uplo))
     }
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for (Tr, FlagUpLo)
+impl<ImplType> EighAPI<DeviceBLAS<B>> for (Tr, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tr>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tr>;
     fn eigh_f(self) -> Result<Self::Out> {
         let (mut a, uplo) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -79,25 +82,26 @@ where
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for Tr
+impl<ImplType> EighAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tr>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tr>;
     fn eigh_f(self) -> Result<Self::Out> {
         let a = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EighAPI::<DeviceBLAS>::eigh_f((a, uplo))
+        EighAPI::<DeviceBLAS<B>>::eigh_f((a, uplo))
     }
 }
 
@@ -106,20 +110,21 @@ where
 /* #region general eigh */
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for (TrA, TrB, FlagUpLo, i32)
+impl<ImplType> EighAPI<DeviceBLAS<B>> for (TrA, TrB, FlagUpLo, i32)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tensor<T, DeviceBLAS<B>, D>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let (a, b, uplo, eig_type) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -136,48 +141,50 @@ where
 }
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for (TrA, TrB, FlagUpLo)
+impl<ImplType> EighAPI<DeviceBLAS<B>> for (TrA, TrB, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tensor<T, DeviceBLAS<B>, D>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let (a, b, uplo) = self;
-        EighAPI::<DeviceBLAS>::eigh_f((a, b, uplo, 1))
+        EighAPI::<DeviceBLAS<B>>::eigh_f((a, b, uplo, 1))
     }
 }
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EighAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> EighAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>, Tensor<T, DeviceBLAS<B>, D>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let (a, b) = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EighAPI::<DeviceBLAS>::eigh_f((a, b, uplo, 1))
+        EighAPI::<DeviceBLAS<B>>::eigh_f((a, b, uplo, 1))
     }
 }
 
@@ -185,12 +192,13 @@ where
 
 /* #region EighArgs implementation */
 
-impl<'a, 'b, T> EighAPI<DeviceBLAS> for EighArgs<'a, 'b, DeviceBLAS, T>
+impl<'a, 'b, B, T> EighAPI<DeviceBLAS<B>> for EighArgs<'a, 'b, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, Ix1>, TensorMutable<'a, T, DeviceBLAS, Ix2>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, Ix1>, TensorMutable<'a, T, DeviceBLAS<B>, Ix2>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let args = self.build()?;
         rstsr_assert!(!args.eigvals_only, InvalidValue, "Eigh only supports eigvals_only = false.")?;
@@ -199,12 +207,13 @@ where
     }
 }
 
-impl<'a, 'b, T> EighAPI<DeviceBLAS> for EighArgs_<'a, 'b, DeviceBLAS, T>
+impl<'a, 'b, B, T> EighAPI<DeviceBLAS<B>> for EighArgs_<'a, 'b, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = EighResult<Tensor<T::Real, DeviceBLAS, Ix1>, TensorMutable<'a, T, DeviceBLAS, Ix2>>;
+    type Out = EighResult<Tensor<T::Real, DeviceBLAS<B>, Ix1>, TensorMutable<'a, T, DeviceBLAS<B>, Ix2>>;
     fn eigh_f(self) -> Result<Self::Out> {
         let args = self;
         rstsr_assert!(!args.eigvals_only, InvalidValue, "Eigh only supports eigvals_only = false.")?;
@@ -1,28 +1,29 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 /* #region simple eigh */
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for (Tr, FlagUpLo)
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for (Tr, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let (a, uplo) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
         let a_view = a.view().into_dim::<Ix2>();
-        let eigh_args = EighArgs::default().a(a_view).uplo(uplo).eigvals_only(true).build()?;
+        let eigh_args = EighArgs::default().a(a_view).uplo(uplo).eigvals_only(B::EIGVALS_ONLY).build()?;
         let (vals, _) = ref_impl_eigh_simple_f(eigh_args)?;
         let vals = vals.into_dim::<IxD>().into_dim::<D::SmallerOne>();
         return Ok(vals);
@@ -30,46 +31,48 @@ where
 }
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for Tr
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let a = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EigvalshAPI::<DeviceBLAS>::eigvalsh_f((a, uplo))
+        EigvalshAPI::<DeviceBLAS<B>>::eigvalsh_f((a, uplo))
     }
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for (Tr, FlagUpLo)
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for (Tr, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let (mut a, uplo) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
         let a_view = a.view_mut().into_dim::<Ix2>();
-        let eigh_args = EighArgs::default().a(a_view).uplo(uplo).eigvals_only(true).build()?;
+        let eigh_args = EighArgs::default().a(a_view).uplo(uplo).eigvals_only(B::EIGVALS_ONLY).build()?;
         let (vals, _) = ref_impl_eigh_simple_f(eigh_args)?;
         let vals = vals.into_dim::<IxD>().into_dim::<D::SmallerOne>();
         return Ok(vals);
@@ -77,25 +80,26 @@ where
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for Tr
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let a = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EigvalshAPI::<DeviceBLAS>::eigvalsh_f((a, uplo))
+        EigvalshAPI::<DeviceBLAS<B>>::eigvalsh_f((a, uplo))
     }
 }
 
@@ -104,20 +108,21 @@ where
 /* #region general eigh */
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for (TrA, TrB, FlagUpLo, i32)
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for (TrA, TrB, FlagUpLo, i32)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let (a, b, uplo, eig_type) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -125,8 +130,13 @@ where
         rstsr_pattern!(eig_type, 1..=3, InvalidLayout, "Only eig_type = 1, 2, or 3 allowed.")?;
         let a_view = a.view().into_dim::<Ix2>();
         let b_view = b.view().into_dim::<Ix2>();
-        let eigh_args =
-            EighArgs::default().a(a_view).b(b_view).uplo(uplo).eig_type(eig_type).eigvals_only(true).build()?;
+        let eigh_args = EighArgs::default()
+            .a(a_view)
+            .b(b_view)
+            .uplo(uplo)
+            .eig_type(eig_type)
+            .eigvals_only(B::EIGVALS_ONLY)
+            .build()?;
         let (vals, _) = ref_impl_eigh_simple_f(eigh_args)?;
         let vals = vals.into_dim::<IxD>().into_dim::<D::SmallerOne>();
         return Ok(vals);
@@ -134,48 +144,50 @@ where
 }
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for (TrA, TrB, FlagUpLo)
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for (TrA, TrB, FlagUpLo)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let (a, b, uplo) = self;
-        EigvalshAPI::<DeviceBLAS>::eigvalsh_f((a, b, uplo, 1))
+        EigvalshAPI::<DeviceBLAS<B>>::eigvalsh_f((a, b, uplo, 1))
     }
 }
 
 #[duplicate_item(
-    ImplType                                                       TrA                                TrB                              ;
-   [T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, D>] [&TensorAny<Rb, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, D> ] [TensorView<'_, T, DeviceBLAS, D>];
-   [T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, D>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D,                                                       ] [TensorView<'_, T, DeviceBLAS, D>] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                                                          TrA                                   TrB                                 ;
+   [B, T, D, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, D>] [&TensorAny<Rb, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, D> ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, D>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, D>] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> EigvalshAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> EigvalshAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let (a, b) = self;
         let uplo = match a.device().default_order() {
             RowMajor => Lower,
             ColMajor => Upper,
         };
-        EigvalshAPI::<DeviceBLAS>::eigvalsh_f((a, b, uplo, 1))
+        EigvalshAPI::<DeviceBLAS<B>>::eigvalsh_f((a, b, uplo, 1))
     }
 }
 
@@ -183,12 +195,13 @@ where
 
 /* #region EighArgs implementation */
 
-impl<'a, 'b, T> EigvalshAPI<DeviceBLAS> for EighArgs<'a, 'b, DeviceBLAS, T>
+impl<'a, 'b, B, T> EigvalshAPI<DeviceBLAS<B>> for EighArgs<'a, 'b, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, Ix1>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, Ix1>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let args = self.build()?;
         rstsr_assert!(args.eigvals_only, InvalidValue, "Eigvalsh only supports eigvals_only = true.")?;
@@ -197,12 +210,13 @@ where
     }
 }
 
-impl<'a, 'b, T> EigvalshAPI<DeviceBLAS> for EighArgs_<'a, 'b, DeviceBLAS, T>
+impl<'a, 'b, B, T> EigvalshAPI<DeviceBLAS<B>> for EighArgs_<'a, 'b, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, Ix1>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, Ix1>;
     fn eigvalsh_f(self) -> Result<Self::Out> {
         let args = self;
         rstsr_assert!(args.eigvals_only, InvalidValue, "Eigvalsh only supports eigvals_only = true.")?;
@@ -1,20 +1,21 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 #[duplicate_item(
-    ImplType                          Tr                               ;
-   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, D> ];
-   [T, D                           ] [TensorView<'_, T, DeviceBLAS, D>];
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<ImplType> InvAPI<DeviceBLAS> for Tr
+impl<ImplType> InvAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T, DeviceBLAS, D>;
+    type Out = Tensor<T, DeviceBLAS<B>, D>;
     fn inv_f(self) -> Result<Self::Out> {
         rstsr_assert_eq!(self.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
         let a = self.view().into_dim::<Ix2>();
@@ -24,15 +25,16 @@ where
 }
 
 #[duplicate_item(
-    ImplType   Tr                              ;
-   ['a, T, D] [TensorMut<'a, T, DeviceBLAS, D>];
-   [    T, D] [Tensor<T, DeviceBLAS, D>       ];
+    ImplType      Tr                                 ;
+   ['a, B, T, D] [TensorMut<'a, T, DeviceBLAS<B>, D>];
+   [    B, T, D] [Tensor<T, DeviceBLAS<B>, D>       ];
 )]
-impl<ImplType> InvAPI<DeviceBLAS> for Tr
+impl<ImplType> InvAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = Tr;
     fn inv_f(self) -> Result<Self::Out> {
@@ -3,7 +3,10 @@ pub mod det;
 pub mod eigh;
 pub mod eigvalsh;
 pub mod inv;
+pub mod orth;
 pub mod pinv;
+pub mod rsvd;
+pub mod schur;
 pub mod slogdet;
 pub mod solve_general;
 pub mod solve_symmetric;
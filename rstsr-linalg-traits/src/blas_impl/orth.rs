@@ -0,0 +1,256 @@
+use crate::prelude_dev::*;
+use num::FromPrimitive;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
+use rstsr_blas_traits::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region polar */
+
+impl<B, T, D, R> PolarAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, FlagSide)
+where
+    B: CblasBackendAPI,
+    R: DataAPI<Data = Vec<T>>,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = PolarResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        let (a, side) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = a.view().into_dim::<Ix2>();
+        let (u, p) = ref_impl_polar_f(a, side)?.into();
+        let u = u.into_dim::<IxD>().into_dim::<D>();
+        let p = p.into_dim::<IxD>().into_dim::<D>();
+        return Ok(PolarResult { u, p });
+    }
+}
+
+impl<B, T, D, R> PolarAPI<DeviceBLAS<B>> for &TensorAny<R, T, DeviceBLAS<B>, D>
+where
+    B: CblasBackendAPI,
+    R: DataAPI<Data = Vec<T>>,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = PolarResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        PolarAPI::<DeviceBLAS<B>>::polar_f((self, FlagSide::R))
+    }
+}
+
+#[duplicate_item(
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
+)]
+impl<B, T, D> PolarAPI<DeviceBLAS<B>> for (Tr, FlagSide)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = PolarResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        let (a, side) = self;
+        PolarAPI::<DeviceBLAS<B>>::polar_f((&a, side))
+    }
+}
+
+#[duplicate_item(
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
+)]
+impl<B, T, D> PolarAPI<DeviceBLAS<B>> for Tr
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = PolarResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        let a = self;
+        PolarAPI::<DeviceBLAS<B>>::polar_f(&a)
+    }
+}
+
+/* #endregion */
+
+/* #region orth, null_space, lowdin_orth, canonical_orth */
+
+#[duplicate_item(
+    TraitAPI            func_f              ref_impl_func_f            ;
+   [OrthAPI         ] [orth_f          ] [ref_impl_orth_f          ];
+   [NullSpaceAPI    ] [null_space_f    ] [ref_impl_null_space_f    ];
+   [LowdinOrthAPI   ] [lowdin_orth_f   ] [ref_impl_lowdin_orth_f   ];
+   [CanonicalOrthAPI] [canonical_orth_f] [ref_impl_canonical_orth_f];
+)]
+mod impl_func {
+    use super::*;
+
+    impl<B, T, D, R> TraitAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, T::Real)
+    where
+        B: CblasBackendAPI,
+        R: DataAPI<Data = Vec<T>>,
+        T: BlasFloat,
+        T::Real: FromPrimitive,
+        D: DimAPI,
+        DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+    {
+        type Out = Tensor<T, DeviceBLAS<B>, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let (a, thresh) = self;
+            rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+            let a = a.view().into_dim::<Ix2>();
+            let result = ref_impl_func_f(a, Some(thresh))?;
+            return Ok(result.into_dim::<IxD>().into_dim::<D>());
+        }
+    }
+
+    impl<B, T, D, R> TraitAPI<DeviceBLAS<B>> for &TensorAny<R, T, DeviceBLAS<B>, D>
+    where
+        B: CblasBackendAPI,
+        R: DataAPI<Data = Vec<T>>,
+        T: BlasFloat,
+        T::Real: FromPrimitive,
+        D: DimAPI,
+        DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+    {
+        type Out = Tensor<T, DeviceBLAS<B>, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let a = self;
+            rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+            let a = a.view().into_dim::<Ix2>();
+            let result = ref_impl_func_f(a, None)?;
+            return Ok(result.into_dim::<IxD>().into_dim::<D>());
+        }
+    }
+
+    #[duplicate_item(
+        Tr                                  ;
+       [Tensor<T, DeviceBLAS<B>, D>        ];
+       [TensorView<'_, T, DeviceBLAS<B>, D>];
+    )]
+    impl<B, T, D> TraitAPI<DeviceBLAS<B>> for (Tr, T::Real)
+    where
+        B: CblasBackendAPI,
+        T: BlasFloat,
+        T::Real: FromPrimitive,
+        D: DimAPI,
+        DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+    {
+        type Out = Tensor<T, DeviceBLAS<B>, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let (a, thresh) = self;
+            TraitAPI::<DeviceBLAS<B>>::func_f((&a, thresh))
+        }
+    }
+
+    #[duplicate_item(
+        Tr                                  ;
+       [Tensor<T, DeviceBLAS<B>, D>        ];
+       [TensorView<'_, T, DeviceBLAS<B>, D>];
+    )]
+    impl<B, T, D> TraitAPI<DeviceBLAS<B>> for Tr
+    where
+        B: CblasBackendAPI,
+        T: BlasFloat,
+        T::Real: FromPrimitive,
+        D: DimAPI,
+        DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+    {
+        type Out = Tensor<T, DeviceBLAS<B>, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let a = self;
+            TraitAPI::<DeviceBLAS<B>>::func_f(&a)
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region gram_schmidt */
+
+impl<B, T, D, R> GramSchmidtAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, usize)
+where
+    B: CblasBackendAPI,
+    R: DataAPI<Data = Vec<T>>,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let (a, block_size) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = a.view().into_dim::<Ix2>();
+        let (q, r) = ref_impl_gram_schmidt_f(a, Some(block_size))?.into();
+        let q = q.into_dim::<IxD>().into_dim::<D>();
+        let r = r.into_dim::<IxD>().into_dim::<D>();
+        return Ok(GramSchmidtResult { q, r });
+    }
+}
+
+impl<B, T, D, R> GramSchmidtAPI<DeviceBLAS<B>> for &TensorAny<R, T, DeviceBLAS<B>, D>
+where
+    B: CblasBackendAPI,
+    R: DataAPI<Data = Vec<T>>,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let a = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = a.view().into_dim::<Ix2>();
+        let (q, r) = ref_impl_gram_schmidt_f(a, None)?.into();
+        let q = q.into_dim::<IxD>().into_dim::<D>();
+        let r = r.into_dim::<IxD>().into_dim::<D>();
+        return Ok(GramSchmidtResult { q, r });
+    }
+}
+
+#[duplicate_item(
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
+)]
+impl<B, T, D> GramSchmidtAPI<DeviceBLAS<B>> for (Tr, usize)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let (a, block_size) = self;
+        GramSchmidtAPI::<DeviceBLAS<B>>::gram_schmidt_f((&a, block_size))
+    }
+}
+
+#[duplicate_item(
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
+)]
+impl<B, T, D> GramSchmidtAPI<DeviceBLAS<B>> for Tr
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let a = self;
+        GramSchmidtAPI::<DeviceBLAS<B>>::gram_schmidt_f(&a)
+    }
+}
+
+/* #endregion */
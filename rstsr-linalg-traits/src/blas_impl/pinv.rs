@@ -1,19 +1,20 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
 use num::FromPrimitive;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
-impl<T, D, R> PinvAPI<DeviceBLAS> for (&TensorAny<R, T, DeviceBLAS, D>, T::Real, T::Real)
+impl<B, T, D, R> PinvAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, T::Real, T::Real)
 where
+    B: CblasBackendAPI,
     R: DataAPI<Data = Vec<T>>,
     T: BlasFloat,
     T::Real: FromPrimitive,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = PinvResult<Tensor<T, DeviceBLAS, D>>;
+    type Out = PinvResult<Tensor<T, DeviceBLAS<B>, D>>;
     fn pinv_f(self) -> Result<Self::Out> {
         let (a, atol, rtol) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -24,16 +25,17 @@ where
     }
 }
 
-impl<T, D, R> PinvAPI<DeviceBLAS> for &TensorAny<R, T, DeviceBLAS, D>
+impl<B, T, D, R> PinvAPI<DeviceBLAS<B>> for &TensorAny<R, T, DeviceBLAS<B>, D>
 where
+    B: CblasBackendAPI,
     R: DataAPI<Data = Vec<T>>,
     T: BlasFloat,
     T::Real: FromPrimitive,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = PinvResult<Tensor<T, DeviceBLAS, D>>;
+    type Out = PinvResult<Tensor<T, DeviceBLAS<B>, D>>;
     fn pinv_f(self) -> Result<Self::Out> {
         let a = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -45,41 +47,43 @@ where
 }
 
 #[duplicate_item(
-    Tr                               ;
-   [Tensor<T, DeviceBLAS, D>        ];
-   [TensorView<'_, T, DeviceBLAS, D>];
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<T, D> PinvAPI<DeviceBLAS> for (Tr, T::Real, T::Real)
+impl<B, T, D> PinvAPI<DeviceBLAS<B>> for (Tr, T::Real, T::Real)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     T::Real: FromPrimitive,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = PinvResult<Tensor<T, DeviceBLAS, D>>;
+    type Out = PinvResult<Tensor<T, DeviceBLAS<B>, D>>;
     fn pinv_f(self) -> Result<Self::Out> {
         let (a, atol, rtol) = self;
-        PinvAPI::<DeviceBLAS>::pinv_f((&a, atol, rtol))
+        PinvAPI::<DeviceBLAS<B>>::pinv_f((&a, atol, rtol))
     }
 }
 
 #[duplicate_item(
-    Tr                               ;
-   [Tensor<T, DeviceBLAS, D>        ];
-   [TensorView<'_, T, DeviceBLAS, D>];
+    Tr                                  ;
+   [Tensor<T, DeviceBLAS<B>, D>        ];
+   [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<T, D> PinvAPI<DeviceBLAS> for Tr
+impl<B, T, D> PinvAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     T::Real: FromPrimitive,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = PinvResult<Tensor<T, DeviceBLAS, D>>;
+    type Out = PinvResult<Tensor<T, DeviceBLAS<B>, D>>;
     fn pinv_f(self) -> Result<Self::Out> {
         let a = self;
-        PinvAPI::<DeviceBLAS>::pinv_f(&a)
+        PinvAPI::<DeviceBLAS<B>>::pinv_f(&a)
     }
 }
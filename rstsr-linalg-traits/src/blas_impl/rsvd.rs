@@ -0,0 +1,85 @@
+use crate::prelude_dev::*;
+use num::FromPrimitive;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
+use rstsr_blas_traits::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region full-args */
+
+impl<B, T, D, R> RSVDAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, usize, usize, usize)
+where
+    B: CblasBackendAPI,
+    R: DataAPI<Data = Vec<T>>,
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    D: DimAPI + DimSmallerOneAPI,
+    D::SmallerOne: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = SVDResult<
+        Tensor<T, DeviceBLAS<B>, D>,
+        Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>,
+        Tensor<T, DeviceBLAS<B>, D>,
+    >;
+    fn rsvd_f(self) -> Result<Self::Out> {
+        let (a, k, oversample, n_iter) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = a.view().into_dim::<Ix2>();
+        let (u, s, vt) = ref_impl_rsvd_f(a, k, oversample, n_iter)?.into();
+        // convert dimensions
+        let u = u.into_dim::<IxD>().into_dim::<D>();
+        let vt = vt.into_dim::<IxD>().into_dim::<D>();
+        let s = s.into_dim::<IxD>().into_dim::<D::SmallerOne>();
+        Ok(SVDResult { u, s, vt })
+    }
+}
+
+#[duplicate_item(
+    Tr; [Tensor<T, DeviceBLAS<B>, D>]; [TensorView<'_, T, DeviceBLAS<B>, D>];
+)]
+impl<B, T, D> RSVDAPI<DeviceBLAS<B>> for (Tr, usize, usize, usize)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    D: DimAPI + DimSmallerOneAPI,
+    D::SmallerOne: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = SVDResult<
+        Tensor<T, DeviceBLAS<B>, D>,
+        Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>,
+        Tensor<T, DeviceBLAS<B>, D>,
+    >;
+    fn rsvd_f(self) -> Result<Self::Out> {
+        let (a, k, oversample, n_iter) = self;
+        RSVDAPI::<DeviceBLAS<B>>::rsvd_f((&a, k, oversample, n_iter))
+    }
+}
+
+/* #endregion */
+
+/* #region sub-args */
+
+#[duplicate_item(
+    ImplType                                 Tr                                    ;
+   ['a, B, T, D, R: DataAPI<Data = Vec<T>>] [&'a TensorAny<R, T, DeviceBLAS<B>, D>];
+   ['a, B, T, D,                          ] [TensorView<'a, T, DeviceBLAS<B>, D>  ];
+   [    B, T, D                           ] [Tensor<T, DeviceBLAS<B>, D>          ];
+)]
+impl<ImplType> RSVDAPI<DeviceBLAS<B>> for (Tr, usize)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    (Tr, usize, usize, usize): RSVDAPI<DeviceBLAS<B>>,
+{
+    type Out = <(Tr, usize, usize, usize) as RSVDAPI<DeviceBLAS<B>>>::Out;
+    fn rsvd_f(self) -> Result<Self::Out> {
+        // same defaults to scikit-learn's `randomized_svd`
+        let (a, k) = self;
+        RSVDAPI::<DeviceBLAS<B>>::rsvd_f((a, k, 10, 4))
+    }
+}
+
+/* #endregion */
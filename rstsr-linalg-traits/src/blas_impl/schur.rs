@@ -0,0 +1,189 @@
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
+use rstsr_blas_traits::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region schur, hessenberg */
+
+#[duplicate_item(
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D                           ] [Tensor<T, DeviceBLAS<B>, D>        ];
+)]
+impl<ImplType> SchurAPI<DeviceBLAS<B>> for Tr
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = SchurResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn schur_f(self) -> Result<Self::Out> {
+        rstsr_assert_eq!(self.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = self;
+        let a_view = a.view().into_dim::<Ix2>();
+        let (t, z) = ref_impl_schur_f(a_view)?.into();
+        let t = t.into_dim::<IxD>().into_dim::<D>();
+        let z = z.into_dim::<IxD>().into_dim::<D>();
+        Ok(SchurResult { t, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                             Tr                                  ;
+   [B, T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, D> ];
+   [B, T, D                           ] [TensorView<'_, T, DeviceBLAS<B>, D>];
+   [B, T, D                           ] [Tensor<T, DeviceBLAS<B>, D>        ];
+)]
+impl<ImplType> HessenbergAPI<DeviceBLAS<B>> for Tr
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    D: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = HessenbergResult<Tensor<T, DeviceBLAS<B>, D>, Tensor<T, DeviceBLAS<B>, D>>;
+    fn hessenberg_f(self) -> Result<Self::Out> {
+        rstsr_assert_eq!(self.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = self;
+        let a_view = a.view().into_dim::<Ix2>();
+        let (h, q) = ref_impl_hessenberg_f(a_view)?.into();
+        let h = h.into_dim::<IxD>().into_dim::<D>();
+        let q = q.into_dim::<IxD>().into_dim::<D>();
+        Ok(HessenbergResult { h, q })
+    }
+}
+
+/* #endregion */
+
+/* #region qz, ordschur, solve_continuous_lyapunov */
+
+#[duplicate_item(
+    ImplType                                                               TrA                                    TrB                                  ;
+   [B, T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+)]
+impl<ImplType> QZAPI<DeviceBLAS<B>> for (TrA, TrB)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = QZResult<
+        Tensor<T, DeviceBLAS<B>, DA>,
+        Tensor<T, DeviceBLAS<B>, DA>,
+        Tensor<T, DeviceBLAS<B>, DA>,
+        Tensor<T, DeviceBLAS<B>, DA>,
+    >;
+    fn qz_f(self) -> Result<Self::Out> {
+        let (a, b) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(b.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let b_view = b.view().into_dim::<Ix2>();
+        let (aa, bb, q, z) = ref_impl_qz_f(a_view, b_view)?.into();
+        let aa = aa.into_dim::<IxD>().into_dim::<DA>();
+        let bb = bb.into_dim::<IxD>().into_dim::<DA>();
+        let q = q.into_dim::<IxD>().into_dim::<DA>();
+        let z = z.into_dim::<IxD>().into_dim::<DA>();
+        Ok(QZResult { aa, bb, q, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                               TrA                                    TrB                                  ;
+   [B, T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+)]
+impl<ImplType> OrdSchurAPI<DeviceBLAS<B>> for (TrA, TrB, &[bool])
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = SchurResult<Tensor<T, DeviceBLAS<B>, DA>, Tensor<T, DeviceBLAS<B>, DA>>;
+    fn ordschur_f(self) -> Result<Self::Out> {
+        let (t, z, select) = self;
+        rstsr_assert_eq!(t.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(z.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(select.len(), t.shape()[0], InvalidLayout, "Length of select should be order of T.")?;
+        let t_view = t.view().into_dim::<Ix2>();
+        let z_view = z.view().into_dim::<Ix2>();
+        let (t, z) = ref_impl_ordschur_f(t_view, z_view, select)?.into();
+        let t = t.into_dim::<IxD>().into_dim::<DA>();
+        let z = z.into_dim::<IxD>().into_dim::<DA>();
+        Ok(SchurResult { t, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                               TrA                                    TrB                                  ;
+   [B, T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+)]
+impl<ImplType> SolveContinuousLyapunovAPI<DeviceBLAS<B>> for (TrA, TrB)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = Tensor<T, DeviceBLAS<B>, DB>;
+    fn solve_continuous_lyapunov_f(self) -> Result<Self::Out> {
+        let (a, q) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(q.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let q_view = q.view().into_dim::<Ix2>();
+        let x = ref_impl_solve_continuous_lyapunov_f(a_view, q_view)?;
+        Ok(x.into_dim::<IxD>().into_dim::<DB>())
+    }
+}
+
+/* #endregion */
+
+/* #region solve_sylvester */
+
+#[duplicate_item(
+    ImplType                                                                                             TrA                                    TrB                                    TrC                                  ;
+   [B, T, DA, DB, DC, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>, Rc: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>] [&TensorAny<Rc, T, DeviceBLAS<B>, DC>];
+   [B, T, DA, DB, DC, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>                            ] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>] [TensorView<'_, T, DeviceBLAS<B>, DC>];
+   [B, T, DA, DB, DC, R: DataAPI<Data = Vec<T>>                                                         ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>] [&TensorAny<R, T, DeviceBLAS<B>, DC> ];
+   [B, T, DA, DB, DC                                                                                    ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>] [TensorView<'_, T, DeviceBLAS<B>, DC>];
+)]
+impl<ImplType> SolveSylvesterAPI<DeviceBLAS<B>> for (TrA, TrB, TrC)
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    DeviceBLAS<B>: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    type Out = Tensor<T, DeviceBLAS<B>, DC>;
+    fn solve_sylvester_f(self) -> Result<Self::Out> {
+        let (a, b, c) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(b.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(c.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let b_view = b.view().into_dim::<Ix2>();
+        let c_view = c.view().into_dim::<Ix2>();
+        let x = ref_impl_solve_sylvester_f(a_view, b_view, c_view)?;
+        Ok(x.into_dim::<IxD>().into_dim::<DC>())
+    }
+}
+
+/* #endregion */
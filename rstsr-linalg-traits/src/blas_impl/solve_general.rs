@@ -1,23 +1,24 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 #[duplicate_item(
-    ImplType                                                            TrA                                 TrB                               ;
-   [T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS, DA>] [&TensorAny<Rb, T, DeviceBLAS, DB>];
-   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS, DA> ] [TensorView<'_, T, DeviceBLAS, DB>];
-   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS, DA>] [&TensorAny<R, T, DeviceBLAS, DB> ];
-   [T, DA, DB,                                                       ] [TensorView<'_, T, DeviceBLAS, DA>] [TensorView<'_, T, DeviceBLAS, DB>];
+    ImplType                                                               TrA                                    TrB                                  ;
+   [B, T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceBLAS<B>, DA>] [&TensorAny<Rb, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                                                       ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>];
 )]
-impl<ImplType> SolveGeneralAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> SolveGeneralAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     DA: DimAPI,
     DB: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T, DeviceBLAS, DB>;
+    type Out = Tensor<T, DeviceBLAS<B>, DB>;
     fn solve_general_f(self) -> Result<Self::Out> {
         let (a, b) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -38,18 +39,19 @@ where
 }
 
 #[duplicate_item(
-    ImplType                                   TrA                                 TrB                              ;
-   ['b, T, DA, DB, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, DA> ] [TensorMut<'b, T, DeviceBLAS, DB>];
-   ['b, T, DA, DB,                          ] [TensorView<'_, T, DeviceBLAS, DA>] [TensorMut<'b, T, DeviceBLAS, DB>];
-   [    T, DA, DB, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS, DA> ] [Tensor<T, DeviceBLAS, DB>       ];
-   [    T, DA, DB,                          ] [TensorView<'_, T, DeviceBLAS, DA>] [Tensor<T, DeviceBLAS, DB>       ];
+    ImplType                                      TrA                                    TrB                                 ;
+   ['b, B, T, DA, DB, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [TensorMut<'b, T, DeviceBLAS<B>, DB>];
+   ['b, B, T, DA, DB,                          ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [TensorMut<'b, T, DeviceBLAS<B>, DB>];
+   [    B, T, DA, DB, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceBLAS<B>, DA> ] [Tensor<T, DeviceBLAS<B>, DB>       ];
+   [    B, T, DA, DB,                          ] [TensorView<'_, T, DeviceBLAS<B>, DA>] [Tensor<T, DeviceBLAS<B>, DB>       ];
 )]
-impl<ImplType> SolveGeneralAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> SolveGeneralAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     DA: DimAPI,
     DB: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = TrB;
     fn solve_general_f(self) -> Result<Self::Out> {
@@ -69,20 +71,21 @@ where
 }
 
 #[duplicate_item(
-    ImplType                               TrA                                TrB                               ;
-   [T, DA, DB, R: DataAPI<Data = Vec<T>>] [TensorMut<'_, T, DeviceBLAS, DA>] [&TensorAny<R, T, DeviceBLAS, DB> ];
-   [T, DA, DB,                          ] [TensorMut<'_, T, DeviceBLAS, DA>] [TensorView<'_, T, DeviceBLAS, DB>];
-   [T, DA, DB, R: DataAPI<Data = Vec<T>>] [Tensor<T, DeviceBLAS, DA>       ] [&TensorAny<R, T, DeviceBLAS, DB> ];
-   [T, DA, DB,                          ] [Tensor<T, DeviceBLAS, DA>       ] [TensorView<'_, T, DeviceBLAS, DB>];
+    ImplType                                  TrA                                   TrB                                  ;
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>] [TensorMut<'_, T, DeviceBLAS<B>, DA>] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                          ] [TensorMut<'_, T, DeviceBLAS<B>, DA>] [TensorView<'_, T, DeviceBLAS<B>, DB>];
+   [B, T, DA, DB, R: DataAPI<Data = Vec<T>>] [Tensor<T, DeviceBLAS<B>, DA>       ] [&TensorAny<R, T, DeviceBLAS<B>, DB> ];
+   [B, T, DA, DB,                          ] [Tensor<T, DeviceBLAS<B>, DA>       ] [TensorView<'_, T, DeviceBLAS<B>, DB>];
 )]
-impl<ImplType> SolveGeneralAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> SolveGeneralAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     DA: DimAPI,
     DB: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T, DeviceBLAS, DB>;
+    type Out = Tensor<T, DeviceBLAS<B>, DB>;
     fn solve_general_f(self) -> Result<Self::Out> {
         let (mut a, b) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -103,18 +106,19 @@ where
 }
 
 #[duplicate_item(
-    ImplType        TrA                                TrB                              ;
-   ['b, T, DA, DB] [TensorMut<'_, T, DeviceBLAS, DA>] [TensorMut<'b, T, DeviceBLAS, DB>];
-   [    T, DA, DB] [TensorMut<'_, T, DeviceBLAS, DA>] [Tensor<T, DeviceBLAS, DB>       ];
-   ['b, T, DA, DB] [Tensor<T, DeviceBLAS, DA>       ] [TensorMut<'b, T, DeviceBLAS, DB>];
-   [    T, DA, DB] [Tensor<T, DeviceBLAS, DA>       ] [Tensor<T, DeviceBLAS, DB>       ];
+    ImplType           TrA                                   TrB                                 ;
+   ['b, B, T, DA, DB] [TensorMut<'_, T, DeviceBLAS<B>, DA>] [TensorMut<'b, T, DeviceBLAS<B>, DB>];
+   [    B, T, DA, DB] [TensorMut<'_, T, DeviceBLAS<B>, DA>] [Tensor<T, DeviceBLAS<B>, DB>       ];
+   ['b, B, T, DA, DB] [Tensor<T, DeviceBLAS<B>, DA>       ] [TensorMut<'b, T, DeviceBLAS<B>, DB>];
+   [    B, T, DA, DB] [Tensor<T, DeviceBLAS<B>, DA>       ] [Tensor<T, DeviceBLAS<B>, DB>       ];
 )]
-impl<ImplType> SolveGeneralAPI<DeviceBLAS> for (TrA, TrB)
+impl<ImplType> SolveGeneralAPI<DeviceBLAS<B>> for (TrA, TrB)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     DA: DimAPI,
     DB: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
     type Out = TrB;
     fn solve_general_f(self) -> Result<Self::Out> {
@@ -1,20 +1,24 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 /* #region full-args */
 
-impl<T, D, R> SVDAPI<DeviceBLAS> for (&TensorAny<R, T, DeviceBLAS, D>, bool)
+impl<B, T, D, R> SVDAPI<DeviceBLAS<B>> for (&TensorAny<R, T, DeviceBLAS<B>, D>, bool)
 where
+    B: CblasBackendAPI,
     R: DataAPI<Data = Vec<T>>,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out =
-        SVDResult<Tensor<T, DeviceBLAS, D>, Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = SVDResult<
+        Tensor<T, DeviceBLAS<B>, D>,
+        Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>,
+        Tensor<T, DeviceBLAS<B>, D>,
+    >;
     fn svd_f(self) -> Result<Self::Out> {
         let (a, full_matrices) = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -30,20 +34,24 @@ where
 }
 
 #[duplicate_item(
-    Tr; [Tensor<T, DeviceBLAS, D>]; [TensorView<'_, T, DeviceBLAS, D>];
+    Tr; [Tensor<T, DeviceBLAS<B>, D>]; [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<T, D> SVDAPI<DeviceBLAS> for (Tr, bool)
+impl<B, T, D> SVDAPI<DeviceBLAS<B>> for (Tr, bool)
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out =
-        SVDResult<Tensor<T, DeviceBLAS, D>, Tensor<T::Real, DeviceBLAS, D::SmallerOne>, Tensor<T, DeviceBLAS, D>>;
+    type Out = SVDResult<
+        Tensor<T, DeviceBLAS<B>, D>,
+        Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>,
+        Tensor<T, DeviceBLAS<B>, D>,
+    >;
     fn svd_f(self) -> Result<Self::Out> {
         let (a, full_matrices) = self;
-        SVDAPI::<DeviceBLAS>::svd_f((&a, full_matrices))
+        SVDAPI::<DeviceBLAS<B>>::svd_f((&a, full_matrices))
     }
 }
 
@@ -52,21 +60,22 @@ where
 /* #region sub-args */
 
 #[duplicate_item(
-    ImplType                              Tr;
-   ['a, T, D, R: DataAPI<Data = Vec<T>>] [&'a TensorAny<R, T, DeviceBLAS, D>];
-   ['a, T, D,                          ] [TensorView<'a, T, DeviceBLAS, D>  ];
-   [    T, D                           ] [Tensor<T, DeviceBLAS, D>          ];
+    ImplType                                 Tr                                    ;
+   ['a, B, T, D, R: DataAPI<Data = Vec<T>>] [&'a TensorAny<R, T, DeviceBLAS<B>, D>];
+   ['a, B, T, D,                          ] [TensorView<'a, T, DeviceBLAS<B>, D>  ];
+   [    B, T, D                           ] [Tensor<T, DeviceBLAS<B>, D>          ];
 )]
-impl<ImplType> SVDAPI<DeviceBLAS> for Tr
+impl<ImplType> SVDAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI,
-    (Tr, bool): SVDAPI<DeviceBLAS>,
+    (Tr, bool): SVDAPI<DeviceBLAS<B>>,
 {
-    type Out = <(Tr, bool) as SVDAPI<DeviceBLAS>>::Out;
+    type Out = <(Tr, bool) as SVDAPI<DeviceBLAS<B>>>::Out;
     fn svd_f(self) -> Result<Self::Out> {
         let a = self;
-        SVDAPI::<DeviceBLAS>::svd_f((a, true))
+        SVDAPI::<DeviceBLAS<B>>::svd_f((a, true))
     }
 }
 
@@ -74,34 +83,75 @@ where
 
 /* #region SVDArgs implementation */
 
-impl<'a, T> SVDAPI<DeviceBLAS> for SVDArgs<'a, DeviceBLAS, T>
+impl<'a, B, T> SVDAPI<DeviceBLAS<B>> for SVDArgs<'a, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = SVDResult<Tensor<T, DeviceBLAS, Ix2>, Tensor<T::Real, DeviceBLAS, Ix1>, Tensor<T, DeviceBLAS, Ix2>>;
+    type Out =
+        SVDResult<Tensor<T, DeviceBLAS<B>, Ix2>, Tensor<T::Real, DeviceBLAS<B>, Ix1>, Tensor<T, DeviceBLAS<B>, Ix2>>;
     fn svd_f(self) -> Result<Self::Out> {
-        SVDAPI::<DeviceBLAS>::svd_f(self.build()?)
+        SVDAPI::<DeviceBLAS<B>>::svd_f(self.build()?)
     }
 }
 
-impl<'a, T> SVDAPI<DeviceBLAS> for SVDArgs_<'a, DeviceBLAS, T>
+impl<'a, B, T> SVDAPI<DeviceBLAS<B>> for SVDArgs_<'a, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = SVDResult<Tensor<T, DeviceBLAS, Ix2>, Tensor<T::Real, DeviceBLAS, Ix1>, Tensor<T, DeviceBLAS, Ix2>>;
+    type Out =
+        SVDResult<Tensor<T, DeviceBLAS<B>, Ix2>, Tensor<T::Real, DeviceBLAS<B>, Ix1>, Tensor<T, DeviceBLAS<B>, Ix2>>;
     fn svd_f(self) -> Result<Self::Out> {
         let args = self;
         rstsr_assert!(
-            args.full_matrices.is_some(),
+            args.full_matrices.is_some() && args.compute_uv,
             InvalidValue,
             "`svd` must compute UV. Refer to `svdvals` if UV is not required."
         )?;
+        rstsr_assert!(
+            !args.compute_u_only,
+            InvalidValue,
+            "`svd` must compute both U and VT. Refer to `svd_partial` if only U is required."
+        )?;
+        let (u, s, vt) = ref_impl_svd_simple_f(args)?;
+        Ok(SVDResult { u: u.unwrap(), s, vt: vt.unwrap() })
+    }
+}
+
+impl<'a, B, T> SVDPartialAPI<DeviceBLAS<B>> for SVDArgs<'a, DeviceBLAS<B>, T>
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = SVDResult<
+        Option<Tensor<T, DeviceBLAS<B>, Ix2>>,
+        Tensor<T::Real, DeviceBLAS<B>, Ix1>,
+        Option<Tensor<T, DeviceBLAS<B>, Ix2>>,
+    >;
+    fn svd_partial_f(self) -> Result<Self::Out> {
+        SVDPartialAPI::<DeviceBLAS<B>>::svd_partial_f(self.build()?)
+    }
+}
+
+impl<'a, B, T> SVDPartialAPI<DeviceBLAS<B>> for SVDArgs_<'a, DeviceBLAS<B>, T>
+where
+    B: CblasBackendAPI,
+    T: BlasFloat,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
+{
+    type Out = SVDResult<
+        Option<Tensor<T, DeviceBLAS<B>, Ix2>>,
+        Tensor<T::Real, DeviceBLAS<B>, Ix1>,
+        Option<Tensor<T, DeviceBLAS<B>, Ix2>>,
+    >;
+    fn svd_partial_f(self) -> Result<Self::Out> {
+        // factors not requested by `compute_uv` or `compute_u_only` are `None`
+        let args = self;
         let (u, s, vt) = ref_impl_svd_simple_f(args)?;
-        let u = u.unwrap().into_dim::<IxD>().into_dim::<Ix2>();
-        let vt = vt.unwrap().into_dim::<IxD>().into_dim::<Ix2>();
-        let s = s.into_dim::<IxD>().into_dim::<Ix1>();
         Ok(SVDResult { u, s, vt })
     }
 }
@@ -1,19 +1,20 @@
-use crate::DeviceBLAS;
+use crate::prelude_dev::*;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude_dev::*;
-use rstsr_linalg_traits::prelude_dev::*;
 
 /* #region full-args */
 
-impl<T, D, R> SVDvalsAPI<DeviceBLAS> for &TensorAny<R, T, DeviceBLAS, D>
+impl<B, T, D, R> SVDvalsAPI<DeviceBLAS<B>> for &TensorAny<R, T, DeviceBLAS<B>, D>
 where
+    B: CblasBackendAPI,
     R: DataAPI<Data = Vec<T>>,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn svdvals_f(self) -> Result<Self::Out> {
         let a = self;
         rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
@@ -27,19 +28,20 @@ where
 }
 
 #[duplicate_item(
-    Tr; [Tensor<T, DeviceBLAS, D>]; [TensorView<'_, T, DeviceBLAS, D>];
+    Tr; [Tensor<T, DeviceBLAS<B>, D>]; [TensorView<'_, T, DeviceBLAS<B>, D>];
 )]
-impl<T, D> SVDvalsAPI<DeviceBLAS> for Tr
+impl<B, T, D> SVDvalsAPI<DeviceBLAS<B>> for Tr
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
     D: DimAPI + DimSmallerOneAPI,
     D::SmallerOne: DimAPI,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, D::SmallerOne>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, D::SmallerOne>;
     fn svdvals_f(self) -> Result<Self::Out> {
         let a = self;
-        SVDvalsAPI::<DeviceBLAS>::svdvals_f(&a)
+        SVDvalsAPI::<DeviceBLAS<B>>::svdvals_f(&a)
     }
 }
 
@@ -47,27 +49,29 @@ where
 
 /* #region SVDArgs implementation */
 
-impl<'a, T> SVDvalsAPI<DeviceBLAS> for SVDArgs<'a, DeviceBLAS, T>
+impl<'a, B, T> SVDvalsAPI<DeviceBLAS<B>> for SVDArgs<'a, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, Ix1>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, Ix1>;
     fn svdvals_f(self) -> Result<Self::Out> {
-        SVDvalsAPI::<DeviceBLAS>::svdvals_f(self.build()?)
+        SVDvalsAPI::<DeviceBLAS<B>>::svdvals_f(self.build()?)
     }
 }
 
-impl<'a, T> SVDvalsAPI<DeviceBLAS> for SVDArgs_<'a, DeviceBLAS, T>
+impl<'a, B, T> SVDvalsAPI<DeviceBLAS<B>> for SVDArgs_<'a, DeviceBLAS<B>, T>
 where
+    B: CblasBackendAPI,
     T: BlasFloat,
-    DeviceBLAS: LapackDriverAPI<T>,
+    DeviceBLAS<B>: LapackDriverAPI<T>,
 {
-    type Out = Tensor<T::Real, DeviceBLAS, Ix1>;
+    type Out = Tensor<T::Real, DeviceBLAS<B>, Ix1>;
     fn svdvals_f(self) -> Result<Self::Out> {
         let args = self;
         rstsr_assert!(
-            args.full_matrices.is_none(),
+            args.full_matrices.is_none() || !args.compute_uv,
             InvalidValue,
             "`svdvals` must not compute UV. Refer to `svd` if UV is required."
         )?;
@@ -1,7 +1,9 @@
-use crate::traits_def::{EighAPI, EighResult};
+use crate::traits_def::{EighAPI, EighArgs, EighArgs_, EighResult};
 use faer::prelude::*;
 use faer::traits::ComplexField;
 use faer_ext::IntoFaer;
+use num::complex::ComplexFloat;
+use rstsr_blas_traits::prelude::BlasFloat;
 use rstsr_core::prelude_dev::*;
 use rstsr_dtype_traits::ExtNum;
 
@@ -323,3 +325,52 @@ where
 }
 
 /* #endregion */
+
+/* #region EighArgs implementation */
+
+/// Eigen decomposition by [`EighArgs_`].
+///
+/// Faer always solves the full spectrum, so requests of eigenvalue subsets or
+/// LAPACK drivers are rejected, instead of being silently ignored.
+pub fn faer_impl_eigh_args_f<T>(
+    args: EighArgs_<'_, '_, DeviceFaer, T>,
+) -> Result<(Tensor<<T as ComplexField>::Real, DeviceFaer, Ix1>, Tensor<T, DeviceFaer, Ix2>)>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+{
+    let EighArgs_ { a, b, uplo, eigvals_only: _, eig_type, subset_by_index, subset_by_value, driver } = args;
+    rstsr_assert!(
+        subset_by_index.is_none() && subset_by_value.is_none(),
+        InvalidValue,
+        "Faer eigh can not compute a subset of eigenvalues, use a BLAS device for subset_by_index or subset_by_value."
+    )?;
+    rstsr_assert!(driver.is_none(), InvalidValue, "Faer eigh does not accept lapack driver {driver:?}.")?;
+    match b {
+        Some(b) => faer_impl_generalized_eigh_f(a.view(), b.view(), uplo, eig_type),
+        None => faer_impl_standard_eigh_f(a.view(), uplo),
+    }
+}
+
+impl<'a, T> EighAPI<DeviceFaer> for EighArgs<'a, '_, DeviceFaer, T>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+{
+    type Out = EighResult<Tensor<<T as ComplexField>::Real, DeviceFaer, Ix1>, TensorMutable<'a, T, DeviceFaer, Ix2>>;
+    fn eigh_f(self) -> Result<Self::Out> {
+        EighAPI::<DeviceFaer>::eigh_f(self.build()?)
+    }
+}
+
+impl<'a, T> EighAPI<DeviceFaer> for EighArgs_<'a, '_, DeviceFaer, T>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+{
+    type Out = EighResult<Tensor<<T as ComplexField>::Real, DeviceFaer, Ix1>, TensorMutable<'a, T, DeviceFaer, Ix2>>;
+    fn eigh_f(self) -> Result<Self::Out> {
+        rstsr_assert!(!self.eigvals_only, InvalidValue, "Eigh only supports eigvals_only = false.")?;
+        let (vals, vecs) = faer_impl_eigh_args_f(self)?;
+        Ok(EighResult { eigenvalues: vals, eigenvectors: TensorMutable::Owned(vecs) })
+    }
+}
+
+/* #endregion */
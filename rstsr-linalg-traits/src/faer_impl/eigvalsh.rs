@@ -1,7 +1,10 @@
-use crate::traits_def::EigvalshAPI;
+use crate::faer_impl::eigh::faer_impl_eigh_args_f;
+use crate::traits_def::{EighArgs, EighArgs_, EigvalshAPI};
 use faer::prelude::*;
 use faer::traits::ComplexField;
 use faer_ext::IntoFaer;
+use num::complex::ComplexFloat;
+use rstsr_blas_traits::prelude::BlasFloat;
 use rstsr_core::prelude_dev::*;
 
 pub fn faer_impl_eigvalsh_f<T>(
@@ -106,3 +109,29 @@ where
         EigvalshAPI::<DeviceFaer>::eigvalsh_f((a, None))
     }
 }
+
+/* #region EighArgs implementation */
+
+impl<T> EigvalshAPI<DeviceFaer> for EighArgs<'_, '_, DeviceFaer, T>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+{
+    type Out = Tensor<<T as ComplexField>::Real, DeviceFaer, Ix1>;
+    fn eigvalsh_f(self) -> Result<Self::Out> {
+        EigvalshAPI::<DeviceFaer>::eigvalsh_f(self.build()?)
+    }
+}
+
+impl<T> EigvalshAPI<DeviceFaer> for EighArgs_<'_, '_, DeviceFaer, T>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+{
+    type Out = Tensor<<T as ComplexField>::Real, DeviceFaer, Ix1>;
+    fn eigvalsh_f(self) -> Result<Self::Out> {
+        rstsr_assert!(self.eigvals_only, InvalidValue, "Eigvalsh only supports eigvals_only = true.")?;
+        let (vals, _) = faer_impl_eigh_args_f(self)?;
+        Ok(vals)
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,81 @@
+use crate::traits_def::{HessenbergAPI, HessenbergResult};
+use faer::dyn_stack::{MemBuffer, MemStack};
+use faer::linalg::evd::hessenberg::{hessenberg_in_place, hessenberg_in_place_scratch};
+use faer::linalg::householder::{
+    apply_block_householder_sequence_on_the_right_in_place_scratch,
+    apply_block_householder_sequence_on_the_right_in_place_with_conj,
+};
+use faer::prelude::*;
+use faer::traits::ComplexField;
+use faer::Conj;
+use faer_ext::IntoFaer;
+use rstsr_core::prelude_dev::*;
+
+pub fn faer_impl_hessenberg_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+) -> Result<HessenbergResult<Tensor<T, DeviceFaer, Ix2>, Tensor<T, DeviceFaer, Ix2>>>
+where
+    T: ComplexField,
+{
+    // set parallel mode
+    let device = a.device().clone();
+    let pool = device.get_current_pool();
+    let faer_par = pool.map_or(Par::Seq, |pool| Par::rayon(pool.current_num_threads()));
+
+    let n = a.shape()[0];
+    rstsr_assert_eq!(a.shape()[1], n, InvalidLayout, "Hessenberg decomposition requires square matrix.")?;
+    let mut h = a.into_faer().to_owned();
+    let mut q = Mat::<T>::identity(n, n);
+
+    // hessenberg reduction, and Q from householder reflectors (same to faer's evd)
+    if n > 1 {
+        let bs = faer::linalg::qr::no_pivoting::factor::recommended_blocksize::<T>(n - 1, n - 1);
+        let mut householder = Mat::<T>::zeros(bs, n - 1);
+        let req = hessenberg_in_place_scratch::<T>(n, bs, faer_par, Default::default())
+            .or(apply_block_householder_sequence_on_the_right_in_place_scratch::<T>(n - 1, bs, n - 1));
+        let mut mem = MemBuffer::new(req);
+        let stack = MemStack::new(&mut mem);
+        hessenberg_in_place(h.as_mut(), householder.as_mut(), faer_par, stack, Default::default());
+        apply_block_householder_sequence_on_the_right_in_place_with_conj(
+            h.as_ref().submatrix(1, 0, n - 1, n - 1),
+            householder.as_ref(),
+            Conj::No,
+            q.as_mut().submatrix_mut(1, 1, n - 1, n - 1),
+            faer_par,
+            stack,
+        );
+        for j in 0..n {
+            for i in j + 2..n {
+                h[(i, j)] = T::zero_impl();
+            }
+        }
+    }
+
+    // convert to rstsr tensor with certain layout
+    let h = h.as_ref().into_rstsr().into_contig(device.default_order());
+    let q = q.as_ref().into_rstsr().into_contig(device.default_order());
+    Ok(HessenbergResult { h, q })
+}
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> HessenbergAPI<DeviceFaer> for Tr
+where
+    T: ComplexField,
+    D: DimAPI,
+{
+    type Out = HessenbergResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn hessenberg_f(self) -> Result<Self::Out> {
+        rstsr_assert_eq!(self.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = self;
+        let a_view = a.view().into_dim::<Ix2>();
+        let (h, q) = faer_impl_hessenberg_f(a_view)?.into();
+        let h = h.into_dim::<IxD>().into_dim::<D>();
+        let q = q.into_dim::<IxD>().into_dim::<D>();
+        Ok(HessenbergResult { h, q })
+    }
+}
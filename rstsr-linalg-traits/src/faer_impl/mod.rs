@@ -2,8 +2,12 @@ pub mod cholesky;
 pub mod det;
 pub mod eigh;
 pub mod eigvalsh;
+pub mod hessenberg;
 pub mod inv;
+pub mod orth;
 pub mod pinv;
+pub mod rsvd;
+pub mod schur;
 pub mod solve_general;
 pub mod solve_triangular;
 pub mod svd;
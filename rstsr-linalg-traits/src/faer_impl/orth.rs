@@ -0,0 +1,235 @@
+use crate::faer_impl::eigh::faer_impl_standard_eigh_f;
+use crate::faer_impl::svd::faer_impl_svd_f;
+use crate::prelude_dev::*;
+use faer::traits::ComplexField;
+use num::complex::ComplexFloat;
+use num::FromPrimitive;
+use rstsr_blas_traits::prelude::BlasFloat;
+use rstsr_core::prelude_dev::*;
+
+pub fn faer_impl_polar_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    side: FlagSide,
+) -> Result<PolarResult<Tensor<T, DeviceFaer, Ix2>, Tensor<T, DeviceFaer, Ix2>>>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    let (u, s, vt) = faer_impl_svd_f(a, false)?.into();
+    ref_impl_polar_from_svd(u, s, vt, side)
+}
+
+pub fn faer_impl_orth_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    rcond: Option<<T as ComplexFloat>::Real>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    let n = a.shape()[1];
+    let (u, s, _) = faer_impl_svd_f(a, false)?.into();
+    ref_impl_orth_from_svd(u, s, n, rcond)
+}
+
+pub fn faer_impl_null_space_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    rcond: Option<<T as ComplexFloat>::Real>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    let m = a.shape()[0];
+    let (_, s, vt) = faer_impl_svd_f(a, true)?.into();
+    ref_impl_null_space_from_svd(s, vt, m, rcond)
+}
+
+pub fn faer_impl_lowdin_orth_f<T>(
+    s: TensorView<'_, T, DeviceFaer, Ix2>,
+    thresh: Option<<T as ComplexFloat>::Real>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    let (w, v) = faer_impl_standard_eigh_f(s, None)?;
+    ref_impl_lowdin_orth_from_eigh(w, v, thresh)
+}
+
+pub fn faer_impl_canonical_orth_f<T>(
+    s: TensorView<'_, T, DeviceFaer, Ix2>,
+    thresh: Option<<T as ComplexFloat>::Real>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    let (w, v) = faer_impl_standard_eigh_f(s, None)?;
+    ref_impl_canonical_orth_from_eigh(w, v, thresh)
+}
+
+/* #region polar */
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> PolarAPI<DeviceFaer> for (Tr, FlagSide)
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    D: DimAPI,
+{
+    type Out = PolarResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        let (a, side) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let result = faer_impl_polar_f(a_view, side)?;
+        // convert dimensions
+        Ok(PolarResult { u: result.u.into_dim::<IxD>().into_dim::<D>(), p: result.p.into_dim::<IxD>().into_dim::<D>() })
+    }
+}
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> PolarAPI<DeviceFaer> for Tr
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    D: DimAPI,
+{
+    type Out = PolarResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn polar_f(self) -> Result<Self::Out> {
+        PolarAPI::<DeviceFaer>::polar_f((self, FlagSide::R))
+    }
+}
+
+/* #endregion */
+
+/* #region orth, null_space, lowdin_orth, canonical_orth */
+
+#[duplicate_item(
+    TraitAPI            func_f              faer_impl_func_f            ;
+   [OrthAPI         ] [orth_f          ] [faer_impl_orth_f          ];
+   [NullSpaceAPI    ] [null_space_f    ] [faer_impl_null_space_f    ];
+   [LowdinOrthAPI   ] [lowdin_orth_f   ] [faer_impl_lowdin_orth_f   ];
+   [CanonicalOrthAPI] [canonical_orth_f] [faer_impl_canonical_orth_f];
+)]
+mod impl_func {
+    use super::*;
+
+    #[duplicate_item(
+        ImplType                          Tr                               ;
+       [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+       [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+       [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+    )]
+    impl<ImplType> TraitAPI<DeviceFaer> for (Tr, <T as ComplexFloat>::Real)
+    where
+        T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+        DeviceFaer: DeviceOrthAPI<T>,
+        <T as ComplexFloat>::Real: FromPrimitive,
+        D: DimAPI,
+    {
+        type Out = Tensor<T, DeviceFaer, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let (a, thresh) = self;
+            rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+            let a_view = a.view().into_dim::<Ix2>();
+            let result = faer_impl_func_f(a_view, Some(thresh))?;
+            Ok(result.into_dim::<IxD>().into_dim::<D>())
+        }
+    }
+
+    #[duplicate_item(
+        ImplType                          Tr                               ;
+       [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+       [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+       [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+    )]
+    impl<ImplType> TraitAPI<DeviceFaer> for Tr
+    where
+        T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+        DeviceFaer: DeviceOrthAPI<T>,
+        <T as ComplexFloat>::Real: FromPrimitive,
+        D: DimAPI,
+    {
+        type Out = Tensor<T, DeviceFaer, D>;
+        fn func_f(self) -> Result<Self::Out> {
+            let a = self;
+            rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+            let a_view = a.view().into_dim::<Ix2>();
+            let result = faer_impl_func_f(a_view, None)?;
+            Ok(result.into_dim::<IxD>().into_dim::<D>())
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region gram_schmidt */
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> GramSchmidtAPI<DeviceFaer> for (Tr, usize)
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    D: DimAPI,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let (a, block_size) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let result = ref_impl_gram_schmidt_f(a_view, Some(block_size))?;
+        // convert dimensions
+        Ok(GramSchmidtResult {
+            q: result.q.into_dim::<IxD>().into_dim::<D>(),
+            r: result.r.into_dim::<IxD>().into_dim::<D>(),
+        })
+    }
+}
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> GramSchmidtAPI<DeviceFaer> for Tr
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    D: DimAPI,
+{
+    type Out = GramSchmidtResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn gram_schmidt_f(self) -> Result<Self::Out> {
+        let a = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let result = ref_impl_gram_schmidt_f(a_view, None)?;
+        // convert dimensions
+        Ok(GramSchmidtResult {
+            q: result.q.into_dim::<IxD>().into_dim::<D>(),
+            r: result.r.into_dim::<IxD>().into_dim::<D>(),
+        })
+    }
+}
+
+/* #endregion */
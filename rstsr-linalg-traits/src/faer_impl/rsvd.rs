@@ -0,0 +1,112 @@
+use crate::faer_impl::svd::faer_impl_svd_f;
+use crate::prelude_dev::*;
+use faer::prelude::*;
+use faer::traits::ComplexField;
+use faer_ext::IntoFaer;
+use num::complex::ComplexFloat;
+use num::FromPrimitive;
+use rstsr_blas_traits::prelude::BlasFloat;
+use rstsr_core::prelude_dev::*;
+
+/// Thin `Q` of Householder QR, used to orthonormalize samples in rsvd.
+fn faer_impl_qr_thin_q<T>(a: TensorView<'_, T, DeviceFaer, Ix2>) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: ComplexField,
+{
+    // set parallel mode
+    let device = a.device().clone();
+    let pool = device.get_current_pool();
+    let faer_par_orig = faer::get_global_parallelism();
+    if let Some(pool) = pool {
+        faer::set_global_parallelism(Par::rayon(pool.current_num_threads()));
+    }
+
+    let q = a.into_faer().qr().compute_thin_Q();
+    let q = q.as_ref().into_rstsr().into_contig(device.default_order());
+
+    // restore parallel mode
+    if pool.is_some() {
+        faer::set_global_parallelism(faer_par_orig)
+    }
+
+    Ok(q)
+}
+
+pub fn faer_impl_rsvd_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    k: usize,
+    oversample: usize,
+    n_iter: usize,
+) -> Result<
+    SVDResult<
+        Tensor<T, DeviceFaer, Ix2>,
+        Tensor<<T as ComplexFloat>::Real, DeviceFaer, Ix1>,
+        Tensor<T, DeviceFaer, Ix2>,
+    >,
+>
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+{
+    ref_impl_rsvd_with_svd(a, k, oversample, n_iter, faer_impl_qr_thin_q, |b| Ok(faer_impl_svd_f(b, false)?.into()))
+}
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> RSVDAPI<DeviceFaer> for (Tr, usize, usize, usize)
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+    D: DimAPI + DimSmallerOneAPI,
+    D::SmallerOne: DimAPI,
+{
+    type Out = SVDResult<
+        Tensor<T, DeviceFaer, D>,
+        Tensor<<T as ComplexFloat>::Real, DeviceFaer, D::SmallerOne>,
+        Tensor<T, DeviceFaer, D>,
+    >;
+    fn rsvd_f(self) -> Result<Self::Out> {
+        let (a, k, oversample, n_iter) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let result = faer_impl_rsvd_f(a_view, k, oversample, n_iter)?;
+        // convert dimensions
+        Ok(SVDResult {
+            u: result.u.into_dim::<IxD>().into_dim::<D>(),
+            s: result.s.into_dim::<IxD>().into_dim::<D::SmallerOne>(),
+            vt: result.vt.into_dim::<IxD>().into_dim::<D>(),
+        })
+    }
+}
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> RSVDAPI<DeviceFaer> for (Tr, usize)
+where
+    T: BlasFloat + ComplexField<Real = <T as ComplexFloat>::Real>,
+    DeviceFaer: DeviceOrthAPI<T>,
+    <T as ComplexFloat>::Real: FromPrimitive,
+    D: DimAPI + DimSmallerOneAPI,
+    D::SmallerOne: DimAPI,
+{
+    type Out = SVDResult<
+        Tensor<T, DeviceFaer, D>,
+        Tensor<<T as ComplexFloat>::Real, DeviceFaer, D::SmallerOne>,
+        Tensor<T, DeviceFaer, D>,
+    >;
+    fn rsvd_f(self) -> Result<Self::Out> {
+        // same defaults to scikit-learn's `randomized_svd`
+        let (a, k) = self;
+        RSVDAPI::<DeviceFaer>::rsvd_f((a, k, 10, 4))
+    }
+}
@@ -0,0 +1,305 @@
+//! Schur decompositions for faer device.
+//!
+//! faer does not provide real Schur forms or reordering, so these are backed
+//! by the pure-Rust kernels of `rstsr_native_impl::cpu_serial::lapack_schur`.
+
+use crate::prelude_dev::*;
+use num::complex::ComplexFloat;
+use num::Zero;
+use rstsr_blas_traits::prelude::BlasFloat;
+use rstsr_core::prelude_dev::*;
+
+/// Col-major data of square matrix.
+fn faer_col_major<T>(a: &TensorView<'_, T, DeviceFaer, Ix2>) -> Vec<T>
+where
+    T: Clone,
+{
+    let [m, n] = *a.shape();
+    (0..n).flat_map(|j| (0..m).map(move |i| a[[i, j]].clone())).collect()
+}
+
+/// Tensor from col-major data, in device default order.
+fn faer_from_col_major<T>(data: Vec<T>, m: usize, n: usize, device: &DeviceFaer) -> Tensor<T, DeviceFaer, Ix2>
+where
+    T: BlasFloat,
+{
+    asarray((data, [m, n].f(), device)).into_dim::<Ix2>().into_contig(device.default_order())
+}
+
+/* #region schur, qz, ordschur */
+
+pub fn faer_impl_schur_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+) -> Result<SchurResult<Tensor<T, DeviceFaer, Ix2>, Tensor<T, DeviceFaer, Ix2>>>
+where
+    T: BlasFloat,
+{
+    let device = a.device().clone();
+    let n = a.shape()[0];
+    rstsr_assert_eq!(a.shape()[1], n, InvalidLayout, "Schur decomposition requires square matrix.")?;
+    let mut t = faer_col_major(&a);
+    let (z, info) = gees_cpu_serial(&mut t, n);
+    if info != 0 {
+        rstsr_errcode!(info as i32, "Schur decomposition did not converge")?;
+    }
+    let t = faer_from_col_major(t, n, n, &device);
+    let z = faer_from_col_major(z, n, n, &device);
+    Ok(SchurResult { t, z })
+}
+
+pub fn faer_impl_qz_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    b: TensorView<'_, T, DeviceFaer, Ix2>,
+) -> Result<
+    QZResult<
+        Tensor<T, DeviceFaer, Ix2>,
+        Tensor<T, DeviceFaer, Ix2>,
+        Tensor<T, DeviceFaer, Ix2>,
+        Tensor<T, DeviceFaer, Ix2>,
+    >,
+>
+where
+    T: BlasFloat,
+{
+    let device = a.device().clone();
+    let n = a.shape()[0];
+    rstsr_assert_eq!(a.shape(), &[n, n], InvalidLayout, "QZ decomposition requires square matrix.")?;
+    rstsr_assert_eq!(b.shape(), &[n, n], InvalidLayout, "B should be square of the same order of A.")?;
+    let mut s = faer_col_major(&a);
+    let mut t = faer_col_major(&b);
+    let mut alphar = vec![<T as ComplexFloat>::Real::zero(); n];
+    let mut alphai = vec![<T as ComplexFloat>::Real::zero(); n];
+    let mut beta = vec![T::zero(); n];
+    let (q, z, info) = gges_cpu_serial(&mut s, &mut t, n, &mut alphar, &mut alphai, &mut beta);
+    if info != 0 {
+        rstsr_errcode!(info as i32, "QZ decomposition did not converge")?;
+    }
+    let aa = faer_from_col_major(s, n, n, &device);
+    let bb = faer_from_col_major(t, n, n, &device);
+    let q = faer_from_col_major(q, n, n, &device);
+    let z = faer_from_col_major(z, n, n, &device);
+    Ok(QZResult { aa, bb, q, z })
+}
+
+/// Reorder Schur decomposition `(T, Z)`, so that the selected eigenvalues
+/// lead the diagonal of `T`.
+pub fn faer_impl_ordschur_f<T>(
+    t: TensorView<'_, T, DeviceFaer, Ix2>,
+    z: TensorView<'_, T, DeviceFaer, Ix2>,
+    select: &[bool],
+) -> Result<SchurResult<Tensor<T, DeviceFaer, Ix2>, Tensor<T, DeviceFaer, Ix2>>>
+where
+    T: BlasFloat,
+{
+    let device = t.device().clone();
+    let n = t.shape()[0];
+    rstsr_assert_eq!(t.shape(), &[n, n], InvalidLayout, "T should be square.")?;
+    rstsr_assert_eq!(z.shape(), &[n, n], InvalidLayout, "Z should be square of the same order of T.")?;
+    let mut t = faer_col_major(&t);
+    let mut z = faer_col_major(&z);
+    trsen_cpu_serial(&mut t, &mut z, n, select);
+    let t = faer_from_col_major(t, n, n, &device);
+    let z = faer_from_col_major(z, n, n, &device);
+    Ok(SchurResult { t, z })
+}
+
+/* #endregion */
+
+/* #region solve_sylvester, solve_continuous_lyapunov */
+
+/// Solve `A X + X B = C` (Bartels-Stewart).
+///
+/// See also [`ref_impl_solve_sylvester_f`].
+pub fn faer_impl_solve_sylvester_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    b: TensorView<'_, T, DeviceFaer, Ix2>,
+    c: TensorView<'_, T, DeviceFaer, Ix2>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    let device = a.device().clone();
+    let [m, n] = *c.shape();
+    rstsr_assert_eq!(a.shape(), &[m, m], InvalidLayout, "A should be square with the same rows of C.")?;
+    rstsr_assert_eq!(b.shape(), &[n, n], InvalidLayout, "B should be square with the same columns of C.")?;
+
+    let (r, u) = faer_impl_schur_f(a)?.into();
+    let (s, v) = faer_impl_schur_f(b)?.into();
+    let f = u.h() % c % &v;
+    let mut f = faer_col_major(&f.view());
+    let info = trsyl_cpu_serial(
+        NoTrans,
+        NoTrans,
+        T::one(),
+        m,
+        n,
+        &faer_col_major(&r.view()),
+        &faer_col_major(&s.view()),
+        &mut f,
+    );
+    if info != 0 {
+        rstsr_errcode!(info as i32, "A and -B have close eigenvalues, solution of Sylvester equation is perturbed")?;
+    }
+    let f = faer_from_col_major(f, m, n, &device);
+    Ok(u % f % v.h())
+}
+
+/// Solve `A X + X A† = Q`.
+pub fn faer_impl_solve_continuous_lyapunov_f<T>(
+    a: TensorView<'_, T, DeviceFaer, Ix2>,
+    q: TensorView<'_, T, DeviceFaer, Ix2>,
+) -> Result<Tensor<T, DeviceFaer, Ix2>>
+where
+    T: BlasFloat,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    let ah = a.h().to_owned();
+    faer_impl_solve_sylvester_f(a, ah.view(), q)
+}
+
+/* #endregion */
+
+/* #region trait impls */
+
+#[duplicate_item(
+    ImplType                          Tr                               ;
+   [T, D, R: DataAPI<Data = Vec<T>>] [&TensorAny<R, T, DeviceFaer, D> ];
+   [T, D                           ] [TensorView<'_, T, DeviceFaer, D>];
+   [T, D                           ] [Tensor<T, DeviceFaer, D>        ];
+)]
+impl<ImplType> SchurAPI<DeviceFaer> for Tr
+where
+    T: BlasFloat,
+    D: DimAPI,
+{
+    type Out = SchurResult<Tensor<T, DeviceFaer, D>, Tensor<T, DeviceFaer, D>>;
+    fn schur_f(self) -> Result<Self::Out> {
+        rstsr_assert_eq!(self.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a = self;
+        let a_view = a.view().into_dim::<Ix2>();
+        let (t, z) = faer_impl_schur_f(a_view)?.into();
+        let t = t.into_dim::<IxD>().into_dim::<D>();
+        let z = z.into_dim::<IxD>().into_dim::<D>();
+        Ok(SchurResult { t, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                         TrA                                  TrB                                ;
+   [T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceFaer, DA>] [&TensorAny<Rb, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceFaer, DA> ] [TensorView<'_, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceFaer, DA>] [&TensorAny<R, T, DeviceFaer, DB> ];
+   [T, DA, DB,                                                       ] [TensorView<'_, T, DeviceFaer, DA>] [TensorView<'_, T, DeviceFaer, DB>];
+)]
+impl<ImplType> QZAPI<DeviceFaer> for (TrA, TrB)
+where
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+{
+    type Out = QZResult<
+        Tensor<T, DeviceFaer, DA>,
+        Tensor<T, DeviceFaer, DA>,
+        Tensor<T, DeviceFaer, DA>,
+        Tensor<T, DeviceFaer, DA>,
+    >;
+    fn qz_f(self) -> Result<Self::Out> {
+        let (a, b) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(b.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let b_view = b.view().into_dim::<Ix2>();
+        let (aa, bb, q, z) = faer_impl_qz_f(a_view, b_view)?.into();
+        let aa = aa.into_dim::<IxD>().into_dim::<DA>();
+        let bb = bb.into_dim::<IxD>().into_dim::<DA>();
+        let q = q.into_dim::<IxD>().into_dim::<DA>();
+        let z = z.into_dim::<IxD>().into_dim::<DA>();
+        Ok(QZResult { aa, bb, q, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                         TrA                                  TrB                                ;
+   [T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceFaer, DA>] [&TensorAny<Rb, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceFaer, DA> ] [TensorView<'_, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceFaer, DA>] [&TensorAny<R, T, DeviceFaer, DB> ];
+   [T, DA, DB,                                                       ] [TensorView<'_, T, DeviceFaer, DA>] [TensorView<'_, T, DeviceFaer, DB>];
+)]
+impl<ImplType> OrdSchurAPI<DeviceFaer> for (TrA, TrB, &[bool])
+where
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+{
+    type Out = SchurResult<Tensor<T, DeviceFaer, DA>, Tensor<T, DeviceFaer, DA>>;
+    fn ordschur_f(self) -> Result<Self::Out> {
+        let (t, z, select) = self;
+        rstsr_assert_eq!(t.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(z.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(select.len(), t.shape()[0], InvalidLayout, "Length of select should be order of T.")?;
+        let t_view = t.view().into_dim::<Ix2>();
+        let z_view = z.view().into_dim::<Ix2>();
+        let (t, z) = faer_impl_ordschur_f(t_view, z_view, select)?.into();
+        let t = t.into_dim::<IxD>().into_dim::<DA>();
+        let z = z.into_dim::<IxD>().into_dim::<DA>();
+        Ok(SchurResult { t, z })
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                         TrA                                  TrB                                ;
+   [T, DA, DB, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceFaer, DA>] [&TensorAny<Rb, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [&TensorAny<R, T, DeviceFaer, DA> ] [TensorView<'_, T, DeviceFaer, DB>];
+   [T, DA, DB, R: DataAPI<Data = Vec<T>>                             ] [TensorView<'_, T, DeviceFaer, DA>] [&TensorAny<R, T, DeviceFaer, DB> ];
+   [T, DA, DB,                                                       ] [TensorView<'_, T, DeviceFaer, DA>] [TensorView<'_, T, DeviceFaer, DB>];
+)]
+impl<ImplType> SolveContinuousLyapunovAPI<DeviceFaer> for (TrA, TrB)
+where
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    type Out = Tensor<T, DeviceFaer, DB>;
+    fn solve_continuous_lyapunov_f(self) -> Result<Self::Out> {
+        let (a, q) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(q.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let q_view = q.view().into_dim::<Ix2>();
+        let x = faer_impl_solve_continuous_lyapunov_f(a_view, q_view)?;
+        Ok(x.into_dim::<IxD>().into_dim::<DB>())
+    }
+}
+
+#[duplicate_item(
+    ImplType                                                                                       TrA                                  TrB                                  TrC                                ;
+   [T, DA, DB, DC, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>, Rc: DataAPI<Data = Vec<T>>] [&TensorAny<Ra, T, DeviceFaer, DA>] [&TensorAny<Rb, T, DeviceFaer, DB>] [&TensorAny<Rc, T, DeviceFaer, DC>];
+   [T, DA, DB, DC, Ra: DataAPI<Data = Vec<T>>, Rb: DataAPI<Data = Vec<T>>                            ] [&TensorAny<Ra, T, DeviceFaer, DA>] [&TensorAny<Rb, T, DeviceFaer, DB>] [TensorView<'_, T, DeviceFaer, DC>];
+   [T, DA, DB, DC, R: DataAPI<Data = Vec<T>>                                                         ] [TensorView<'_, T, DeviceFaer, DA>] [TensorView<'_, T, DeviceFaer, DB>] [&TensorAny<R, T, DeviceFaer, DC> ];
+   [T, DA, DB, DC                                                                                    ] [TensorView<'_, T, DeviceFaer, DA>] [TensorView<'_, T, DeviceFaer, DB>] [TensorView<'_, T, DeviceFaer, DC>];
+)]
+impl<ImplType> SolveSylvesterAPI<DeviceFaer> for (TrA, TrB, TrC)
+where
+    T: BlasFloat,
+    DA: DimAPI,
+    DB: DimAPI,
+    DC: DimAPI,
+    DeviceFaer: DeviceOrthAPI<T>,
+{
+    type Out = Tensor<T, DeviceFaer, DC>;
+    fn solve_sylvester_f(self) -> Result<Self::Out> {
+        let (a, b, c) = self;
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(b.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        rstsr_assert_eq!(c.ndim(), 2, InvalidLayout, "Currently we can only handle 2-D matrix.")?;
+        let a_view = a.view().into_dim::<Ix2>();
+        let b_view = b.view().into_dim::<Ix2>();
+        let c_view = c.view().into_dim::<Ix2>();
+        let x = faer_impl_solve_sylvester_f(a_view, b_view, c_view)?;
+        Ok(x.into_dim::<IxD>().into_dim::<DC>())
+    }
+}
+
+/* #endregion */
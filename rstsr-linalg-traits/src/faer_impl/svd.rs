@@ -36,7 +36,7 @@ where
     let result = SVDResult {
         u: u.into_contig(device.default_order()),
         s: s.mapv(|v| T::real_part_impl(&v)).into_contig(device.default_order()),
-        vt: v.into_reverse_axes().into_contig(device.default_order()),
+        vt: v.mapv(|v| T::conj_impl(&v)).into_reverse_axes().into_contig(device.default_order()),
     };
 
     // restore parallel mode
@@ -1,9 +1,12 @@
+#![allow(clippy::needless_return)]
 #![allow(clippy::type_complexity)]
 #![allow(non_camel_case_types)]
 
+pub mod blas_impl;
 pub mod prelude;
 pub mod prelude_dev;
 pub mod ref_impl_blas;
+pub mod ref_impl_orth;
 pub mod traits_def;
 
 #[cfg(feature = "faer")]
@@ -1,20 +1,26 @@
 pub mod rstsr_traits {
     pub use crate::traits_def::{
-        CholeskyAPI, DetAPI, EighAPI, EigvalshAPI, InvAPI, PinvAPI, SLogDetAPI, SVDvalsAPI, SolveGeneralAPI,
-        SolveSymmetricAPI, SolveTriangularAPI, SVDAPI,
+        CanonicalOrthAPI, CholeskyAPI, DetAPI, EighAPI, EigvalshAPI, GramSchmidtAPI, HessenbergAPI, InvAPI,
+        LowdinOrthAPI, NullSpaceAPI, OrdSchurAPI, OrthAPI, PinvAPI, PolarAPI, SLogDetAPI, SVDPartialAPI, SVDvalsAPI,
+        SchurAPI, SolveContinuousLyapunovAPI, SolveGeneralAPI, SolveSylvesterAPI, SolveSymmetricAPI,
+        SolveTriangularAPI, QZAPI, RSVDAPI, SVDAPI,
     };
 }
 
 pub mod rstsr_funcs {
     pub use crate::traits_def::{
-        cholesky, cholesky_f, det, det_f, eigh, eigh_f, eigvalsh, eigvalsh_f, inv, inv_f, pinv, pinv_f, slogdet,
-        slogdet_f, solve_general, solve_general_f, solve_symmetric, solve_symmetric_f, solve_triangular,
-        solve_triangular_f, svd, svd_f, svdvals, svdvals_f,
+        canonical_orth, canonical_orth_f, cholesky, cholesky_f, det, det_f, eigh, eigh_f, eigvalsh, eigvalsh_f,
+        gram_schmidt, gram_schmidt_f, hessenberg, hessenberg_f, inv, inv_f, lowdin_orth, lowdin_orth_f, null_space,
+        null_space_f, ordschur, ordschur_f, orth, orth_f, pinv, pinv_f, polar, polar_f, qz, qz_f, rsvd, rsvd_f, schur,
+        schur_f, slogdet, slogdet_f, solve_continuous_lyapunov, solve_continuous_lyapunov_f, solve_general,
+        solve_general_f, solve_sylvester, solve_sylvester_f, solve_symmetric, solve_symmetric_f, solve_triangular,
+        solve_triangular_f, svd, svd_f, svd_partial, svd_partial_f, svdvals, svdvals_f,
     };
 }
 
 pub mod rstsr_structs {
     pub use crate::traits_def::{
-        EighArgs, EighArgs_, EighArgs_Builder, EighResult, SLogDetResult, SVDArgs, SVDArgs_, SVDArgs_Builder, SVDResult,
+        EighArgs, EighArgs_, EighArgs_Builder, EighResult, GramSchmidtResult, HessenbergResult, PolarResult, QZResult,
+        SLogDetResult, SVDArgs, SVDArgs_, SVDArgs_Builder, SVDResult, SchurResult,
     };
 }
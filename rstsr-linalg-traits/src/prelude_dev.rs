@@ -1,2 +1,3 @@
 pub use crate::ref_impl_blas::*;
+pub use crate::ref_impl_orth::*;
 pub use crate::traits_def::*;
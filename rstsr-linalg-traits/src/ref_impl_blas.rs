@@ -1,5 +1,8 @@
-use crate::traits_def::{EighArgs_, PinvResult, SVDArgs_};
-use num::{Float, FromPrimitive, Zero};
+use crate::ref_impl_orth::*;
+use crate::traits_def::{
+    EighArgs, EighArgs_, HessenbergResult, PinvResult, PolarResult, QZResult, SVDArgs, SVDArgs_, SVDResult, SchurResult,
+};
+use num::{Float, FromPrimitive, One, Zero};
 use rstsr_blas_traits::prelude::*;
 use rstsr_core::prelude::rt;
 use rstsr_core::prelude_dev::*;
@@ -15,7 +18,7 @@ where
     B: LapackDriverAPI<T>,
 {
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let uplo = uplo.unwrap_or_else(|| match device.default_order() {
         RowMajor => Lower,
         ColMajor => Upper,
@@ -40,11 +43,93 @@ where
     T: BlasFloat,
     B: LapackDriverAPI<T>,
 {
-    let EighArgs_ { a, b, uplo, eigvals_only, eig_type, subset_by_index: _, subset_by_value: _, driver } = eigh_args;
+    let EighArgs_ { a, b, uplo, eigvals_only, eig_type, subset_by_index, subset_by_value, driver } = eigh_args;
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
 
     let jobz = if eigvals_only { 'N' } else { 'V' };
+
+    // subset of eigenvalues, as lapack's (range, il, iu, vl, vu)
+    let zero = T::Real::zero();
+    let subset = match (subset_by_index, subset_by_value) {
+        (None, None) => None,
+        (Some((lo, hi)), None) => Some(('I', lo + 1, hi + 1, zero, zero)),
+        (None, Some((vl, vu))) => Some(('V', 0, 0, vl, vu)),
+        (Some(_), Some(_)) => {
+            rstsr_raise!(InvalidValue, "subset_by_index and subset_by_value cannot be specified together.")?
+        },
+    };
+    if let Some((range, il, iu, vl, vu)) = subset {
+        let (w, v) = if b.is_some() {
+            let driver = driver.unwrap_or("gvx");
+            match driver {
+                "gvx" => {
+                    let task = || {
+                        SYGVX::default()
+                            .a(a)
+                            .b(b.unwrap())
+                            .jobz(jobz)
+                            .range(range)
+                            .il(il)
+                            .iu(iu)
+                            .vl(vl)
+                            .vu(vu)
+                            .itype(eig_type)
+                            .uplo(uplo)
+                            .build()?
+                            .run()
+                    };
+                    device.with_blas_num_threads(nthreads, task)?
+                },
+                _ => rstsr_raise!(
+                    InvalidValue,
+                    "Driver {driver:?} does not support subset of eigenvalues, use \"gvx\"."
+                )?,
+            }
+        } else {
+            let driver = driver.unwrap_or("evr");
+            match driver {
+                "evr" => {
+                    let task = || {
+                        SYEVR::default()
+                            .a(a)
+                            .jobz(jobz)
+                            .range(range)
+                            .il(il)
+                            .iu(iu)
+                            .vl(vl)
+                            .vu(vu)
+                            .uplo(uplo)
+                            .build()?
+                            .run()
+                    };
+                    device.with_blas_num_threads(nthreads, task)?
+                },
+                "evx" => {
+                    let task = || {
+                        SYEVX::default()
+                            .a(a)
+                            .jobz(jobz)
+                            .range(range)
+                            .il(il)
+                            .iu(iu)
+                            .vl(vl)
+                            .vu(vu)
+                            .uplo(uplo)
+                            .build()?
+                            .run()
+                    };
+                    device.with_blas_num_threads(nthreads, task)?
+                },
+                _ => rstsr_raise!(
+                    InvalidValue,
+                    "Driver {driver:?} does not support subset of eigenvalues, use \"evr\" or \"evx\"."
+                )?,
+            }
+        };
+        return Ok((w, v.map(TensorMutable::Owned)));
+    }
+
     if b.is_some() {
         let driver = driver.unwrap_or("gvd");
         let (w, v) = match driver {
@@ -92,7 +177,7 @@ where
     B: LapackDriverAPI<T>,
 {
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let task = || {
         let (mut a, ipiv) = GETRF::default().a(a).build()?.run()?;
         GETRI::default().a(a.view_mut()).ipiv(ipiv.view()).build()?.run()?;
@@ -116,7 +201,7 @@ where
     B: LapackDriverAPI<T>,
 {
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let task = || {
         // compute rcond value
         let atol = atol.unwrap_or(T::Real::zero());
@@ -161,7 +246,7 @@ where
 {
     let device = a.device().clone();
     rstsr_assert!(device.same_device(b.device()), DeviceMismatch)?;
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let task = || GESV::default().a(a).b(b).build()?.run();
     let result = device.with_blas_num_threads(nthreads, task)?;
     let (_lu, _piv, x) = result;
@@ -184,7 +269,7 @@ where
 {
     let device = a.device().clone();
     rstsr_assert!(device.same_device(b.device()), DeviceMismatch)?;
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let uplo = uplo.unwrap_or_else(|| match device.default_order() {
         RowMajor => Lower,
         ColMajor => Upper,
@@ -213,7 +298,7 @@ where
 {
     let device = a.device().clone();
     rstsr_assert!(device.same_device(b.device()), DeviceMismatch)?;
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let task = || TRSM::default().a(a.view()).b(b).uplo(uplo).build()?.run();
     let result = device.with_blas_num_threads(nthreads, task)?;
     Ok(result.clone_to_mut())
@@ -229,7 +314,7 @@ where
     B: LapackDriverAPI<T>,
 {
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let task = || {
         let (a, piv) = GETRF::default().a(a).build()?.run()?;
         // pivot indices that may cause sign change
@@ -263,31 +348,309 @@ where
     T: BlasFloat,
     B: LapackDriverAPI<T>,
 {
-    let SVDArgs_ { a, full_matrices, driver } = svd_args;
+    let SVDArgs_ { a, full_matrices, compute_uv, compute_u_only, subset_by_index, subset_by_value, driver } = svd_args;
     let device = a.device().clone();
-    let nthreads = device.get_current_pool().map_or(1, |pool| pool.current_num_threads());
+    let nthreads = device.get_blas_num_threads_budget();
     let (full_matrices, compute_uv) = match full_matrices {
-        Some(true) => (true, true),
-        Some(false) => (false, true),
+        Some(full_matrices) => (full_matrices, compute_uv),
         None => (false, false),
     };
+    let compute_u = compute_uv;
+    let compute_vt = compute_uv && !compute_u_only;
+
+    // subset of singular values, as lapack's (range, il, iu, vl, vu)
+    let zero = T::Real::zero();
+    let subset = match (subset_by_index, subset_by_value) {
+        (None, None) => None,
+        (Some((lo, hi)), None) => Some(('I', lo + 1, hi + 1, zero, zero)),
+        (None, Some((vl, vu))) => Some(('V', 0, 0, vl, vu)),
+        (Some(_), Some(_)) => {
+            rstsr_raise!(InvalidValue, "subset_by_index and subset_by_value cannot be specified together.")?
+        },
+    };
 
-    let driver = driver.unwrap_or("gesdd");
-    match driver {
+    // GESVD is able to skip VT when only U is required
+    let driver = driver.unwrap_or(match (subset.is_some(), compute_uv && !compute_vt) {
+        (true, _) => "gesvdx",
+        (false, true) => "gesvd",
+        (false, false) => "gesdd",
+    });
+    if subset.is_some() && driver != "gesvdx" {
+        rstsr_raise!(InvalidValue, "Driver {driver:?} does not support subset of singular values, use \"gesvdx\".")?;
+    }
+    let (u, s, vt) = match driver {
         "gesvd" => {
-            let task =
-                || GESVD::default().a(a.view()).full_matrices(full_matrices).compute_uv(compute_uv).build()?.run();
+            let task = || {
+                GESVD::default()
+                    .a(a.view())
+                    .full_matrices(full_matrices)
+                    .compute_uv(compute_uv)
+                    .compute_vt(compute_vt)
+                    .build()?
+                    .run()
+            };
             let (s, u, vt, _) = device.with_blas_num_threads(nthreads, task)?;
-            Ok((u, s, vt))
+            (u, s, vt)
         },
         "gesdd" => {
             let task =
                 || GESDD::default().a(a.view()).full_matrices(full_matrices).compute_uv(compute_uv).build()?.run();
             let (s, u, vt) = device.with_blas_num_threads(nthreads, task)?;
-            Ok((u, s, vt))
+            (u, s, vt)
+        },
+        "gesvdx" => {
+            rstsr_assert!(
+                !(full_matrices && compute_uv),
+                InvalidValue,
+                "Driver \"gesvdx\" only computes thin singular vectors, set `full_matrices` to false."
+            )?;
+            let (range, il, iu, vl, vu) = subset.unwrap_or(('A', 0, 0, zero, zero));
+            let task = || {
+                GESVDX::default()
+                    .a(a.view())
+                    .jobu(if compute_u { 'V' } else { 'N' })
+                    .jobvt(if compute_vt { 'V' } else { 'N' })
+                    .range(range)
+                    .il(il)
+                    .iu(iu)
+                    .vl(vl)
+                    .vu(vu)
+                    .build()?
+                    .run()
+            };
+            let (s, u, vt) = device.with_blas_num_threads(nthreads, task)?;
+            (u, s, vt)
+        },
+        "gejsv" => {
+            let task = || {
+                GEJSV::default()
+                    .a(a.view())
+                    .full_matrices(full_matrices)
+                    .compute_u(compute_u)
+                    .compute_vt(compute_vt)
+                    .build()?
+                    .run()
+            };
+            let (s, u, vt) = device.with_blas_num_threads(nthreads, task)?;
+            (u, s, vt)
         },
         _ => rstsr_invalid!(driver)?,
+    };
+    Ok((u, s, if compute_vt { vt } else { None }))
+}
+
+/* #endregion */
+
+/* #region orthogonalization */
+
+pub fn ref_impl_polar_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+    side: FlagSide,
+) -> Result<PolarResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let svd_args = SVDArgs::default().a(a).full_matrices(false).build()?;
+    let (u, s, vt) = ref_impl_svd_simple_f(svd_args)?;
+    ref_impl_polar_from_svd(u.unwrap(), s, vt.unwrap(), side)
+}
+
+pub fn ref_impl_orth_f<T, B>(a: TensorView<T, B, Ix2>, rcond: Option<T::Real>) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let n = a.shape()[1];
+    let svd_args = SVDArgs::default().a(a).full_matrices(false).build()?;
+    let (u, s, _) = ref_impl_svd_simple_f(svd_args)?;
+    ref_impl_orth_from_svd(u.unwrap(), s, n, rcond)
+}
+
+pub fn ref_impl_null_space_f<T, B>(a: TensorView<T, B, Ix2>, rcond: Option<T::Real>) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let m = a.shape()[0];
+    let svd_args = SVDArgs::default().a(a).full_matrices(true).build()?;
+    let (_, s, vt) = ref_impl_svd_simple_f(svd_args)?;
+    ref_impl_null_space_from_svd(s, vt.unwrap(), m, rcond)
+}
+
+pub fn ref_impl_lowdin_orth_f<T, B>(s: TensorView<T, B, Ix2>, thresh: Option<T::Real>) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let eigh_args = EighArgs::default().a(s).build()?;
+    let (w, v) = ref_impl_eigh_simple_f(eigh_args)?;
+    ref_impl_lowdin_orth_from_eigh(w, v.unwrap().into_owned(), thresh)
+}
+
+pub fn ref_impl_canonical_orth_f<T, B>(s: TensorView<T, B, Ix2>, thresh: Option<T::Real>) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let eigh_args = EighArgs::default().a(s).build()?;
+    let (w, v) = ref_impl_eigh_simple_f(eigh_args)?;
+    ref_impl_canonical_orth_from_eigh(w, v.unwrap().into_owned(), thresh)
+}
+
+/* #endregion */
+
+/* #region rsvd */
+
+pub fn ref_impl_rsvd_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+    k: usize,
+    oversample: usize,
+    n_iter: usize,
+) -> Result<SVDResult<Tensor<T, B, Ix2>, Tensor<T::Real, B, Ix1>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let device = a.device().clone();
+    let nthreads = device.get_blas_num_threads_budget();
+    let qr_thin = |y: TensorView<T, B, Ix2>| {
+        let task = || -> Result<Tensor<T, B, Ix2>> {
+            let (r, tau) = GEQRF::default().a(y).build()?.run()?;
+            Ok(ORGQR::default().a(r.view()).tau(tau.view()).build()?.run()?.into_owned())
+        };
+        device.with_blas_num_threads(nthreads, task)
+    };
+    ref_impl_rsvd_with_svd(a, k, oversample, n_iter, qr_thin, |b| {
+        let svd_args = SVDArgs::default().a(b).full_matrices(false).build()?;
+        let (u, s, vt) = ref_impl_svd_simple_f(svd_args)?;
+        Ok((u.unwrap(), s, vt.unwrap()))
+    })
+}
+
+/* #endregion */
+
+/* #region schur, hessenberg, qz */
+
+pub fn ref_impl_schur_f<T, B>(a: TensorView<T, B, Ix2>) -> Result<SchurResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T>,
+{
+    let device = a.device().clone();
+    let nthreads = device.get_blas_num_threads_budget();
+    let task = || GEES::default().a(a).jobvs('V').build()?.run();
+    let (t, _, _, z) = device.with_blas_num_threads(nthreads, task)?;
+    Ok(SchurResult { t: t.into_owned(), z: z.unwrap() })
+}
+
+pub fn ref_impl_hessenberg_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+) -> Result<HessenbergResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T>,
+{
+    let device = a.device().clone();
+    let nthreads = device.get_blas_num_threads_budget();
+    let task = || -> Result<_> {
+        let (h, tau) = GEHRD::default().a(a).build()?.run()?;
+        let mut h = h.into_owned();
+        let q = ORGHR::default().a(h.view()).tau(tau.view()).build()?.run()?.into_owned();
+        triu((h.view_mut(), -1));
+        Ok((h, q))
+    };
+    let (h, q) = device.with_blas_num_threads(nthreads, task)?;
+    Ok(HessenbergResult { h, q })
+}
+
+pub fn ref_impl_qz_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+    b: TensorView<T, B, Ix2>,
+) -> Result<QZResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>, Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T>,
+{
+    let device = a.device().clone();
+    rstsr_assert!(device.same_device(b.device()), DeviceMismatch)?;
+    let nthreads = device.get_blas_num_threads_budget();
+    let task = || GGES::default().a(a).b(b).build()?.run();
+    let (aa, bb, _, _, _, q, z) = device.with_blas_num_threads(nthreads, task)?;
+    Ok(QZResult { aa: aa.into_owned(), bb: bb.into_owned(), q: q.unwrap(), z: z.unwrap() })
+}
+
+/// Reorder Schur decomposition `(T, Z)`, so that the selected eigenvalues
+/// lead the diagonal of `T`.
+pub fn ref_impl_ordschur_f<T, B>(
+    t: TensorView<T, B, Ix2>,
+    z: TensorView<T, B, Ix2>,
+    select: &[bool],
+) -> Result<SchurResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T>,
+{
+    let device = t.device().clone();
+    rstsr_assert!(device.same_device(z.device()), DeviceMismatch)?;
+    let nthreads = device.get_blas_num_threads_budget();
+    let task = || TRSEN::default().t(t).q(z).select(select.to_vec()).build()?.run();
+    let (t, z, _, _, _) = device.with_blas_num_threads(nthreads, task)?;
+    Ok(SchurResult { t: t.into_owned(), z: z.unwrap().into_owned() })
+}
+
+/* #endregion */
+
+/* #region solve_sylvester, solve_continuous_lyapunov */
+
+/// Solve `A X + X B = C` (Bartels-Stewart).
+///
+/// With Schur decompositions `A = U R U†`, `B = V S V†`, this is reduced to
+/// quasi-triangular `R Y + Y S = U† C V`, and `X = U Y V†`.
+pub fn ref_impl_solve_sylvester_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+    b: TensorView<T, B, Ix2>,
+    c: TensorView<T, B, Ix2>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let device = a.device().clone();
+    rstsr_assert!(device.same_device(b.device()), DeviceMismatch)?;
+    rstsr_assert!(device.same_device(c.device()), DeviceMismatch)?;
+    let [m, n] = *c.shape();
+    rstsr_assert_eq!(a.shape(), &[m, m], InvalidLayout, "A should be square with the same rows of C.")?;
+    rstsr_assert_eq!(b.shape(), &[n, n], InvalidLayout, "B should be square with the same columns of C.")?;
+    let nthreads = device.get_blas_num_threads_budget();
+
+    let (r, u) = ref_impl_schur_f(a)?.into();
+    let (s, v) = ref_impl_schur_f(b)?.into();
+    let mut f = u.h() % c % &v;
+    let task = || -> Result<_> { Ok(TRSYL::default().a(r.view()).b(s.view()).c(f.view_mut()).build()?.run()?.1) };
+    let scale = device.with_blas_num_threads(nthreads, task)?;
+    let mut x = u % f % v.h();
+    if scale != T::Real::one() {
+        x.raw_mut().iter_mut().for_each(|x| *x /= scale);
     }
+    Ok(x)
+}
+
+/// Solve `A X + X A† = Q`.
+pub fn ref_impl_solve_continuous_lyapunov_f<T, B>(
+    a: TensorView<T, B, Ix2>,
+    q: TensorView<T, B, Ix2>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    B: LapackDriverAPI<T> + DeviceOrthAPI<T>,
+{
+    let ah = a.h().to_owned();
+    ref_impl_solve_sylvester_f(a, ah.view(), q)
 }
 
 /* #endregion */
@@ -0,0 +1,391 @@
+//! Orthogonalization routines on top of SVD and eigen decomposition, and
+//! randomized SVD on top of QR and SVD.
+//!
+//! Functions in this module are device-agnostic. Decompositions are computed
+//! by the device-specific implementations (LAPACK drivers or faer), and are
+//! post-processed here by matmul and operations on (CPU) raw data.
+
+use crate::traits_def::{GramSchmidtResult, PolarResult, SVDResult};
+use num::{Float, FromPrimitive, One, Zero};
+use rstsr_blas_traits::prelude::BlasFloat;
+use rstsr_core::prelude_dev::*;
+
+/// Device requirements of the orthogonalization routines.
+pub trait DeviceOrthAPI<T>:
+    DeviceAPI<T, Raw = Vec<T>>
+    + DeviceAPI<T::Real, Raw = Vec<T::Real>>
+    + DeviceComplexFloatAPI<T, Ix2>
+    + DeviceMatMulAPI<T, T, T, Ix2, Ix2, Ix2>
+    + DeviceMatMulConjAPI<T, Ix2, Ix2, Ix2>
+where
+    T: BlasFloat,
+{
+}
+
+impl<T, B> DeviceOrthAPI<T> for B
+where
+    T: BlasFloat,
+    B: DeviceAPI<T, Raw = Vec<T>>
+        + DeviceAPI<T::Real, Raw = Vec<T::Real>>
+        + DeviceComplexFloatAPI<T, Ix2>
+        + DeviceMatMulAPI<T, T, T, Ix2, Ix2, Ix2>
+        + DeviceMatMulConjAPI<T, Ix2, Ix2, Ix2>,
+{
+}
+
+/* #region utilities */
+
+/// Multiply rows (`axis = 0`) or columns (`axis = 1`) of matrix `x` by `f`.
+fn scale_axis<T, B>(x: &mut Tensor<T, B, Ix2>, f: &[T::Real], axis: usize)
+where
+    T: BlasFloat,
+    B: DeviceAPI<T, Raw = Vec<T>>,
+{
+    let layout = x.layout().clone();
+    let [m, n] = *layout.shape();
+    let raw = x.raw_mut();
+    for i in 0..m {
+        for j in 0..n {
+            let idx = unsafe { layout.index_uncheck(&[i, j]) } as usize;
+            raw[idx] = raw[idx] * f[if axis == 0 { i } else { j }];
+        }
+    }
+}
+
+/// Values of a 1-D tensor, in order of its indices.
+fn to_vec_ix1<T, B>(x: &Tensor<T, B, Ix1>) -> Vec<T>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>>,
+{
+    let layout = x.layout();
+    (0..layout.shape()[0]).map(|i| x.raw()[unsafe { layout.index_uncheck(&[i]) } as usize].clone()).collect()
+}
+
+/// Number of singular values larger than `rcond * max(s)`.
+///
+/// If `rcond` is not given, `eps * max(m, n)` is used (same to SciPy).
+fn svd_rank<R>(s: &[R], rcond: Option<R>, m: usize, n: usize) -> usize
+where
+    R: Float + FromPrimitive,
+{
+    let rcond = rcond.unwrap_or_else(|| R::epsilon() * R::from_usize(m.max(n)).unwrap());
+    let smax = s.iter().fold(R::zero(), |acc, &x| acc.max(x));
+    s.iter().filter(|&&x| x > smax * rcond).count()
+}
+
+/* #endregion */
+
+/* #region polar */
+
+/// Polar decomposition from thin SVD `A = W S V†`.
+///
+/// - right (`A = U P`): `U = W V†`, `P = V S V†`;
+/// - left (`A = P U`): `U = W V†`, `P = W S W†`.
+pub fn ref_impl_polar_from_svd<T, B>(
+    u: Tensor<T, B, Ix2>,
+    s: Tensor<T::Real, B, Ix1>,
+    vt: Tensor<T, B, Ix2>,
+    side: FlagSide,
+) -> Result<PolarResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: DeviceOrthAPI<T>,
+{
+    let s = to_vec_ix1(&s);
+    let unitary = matmul_f(&u, &vt)?;
+    let p = match side {
+        Right => {
+            let mut svt = vt.to_owned();
+            scale_axis(&mut svt, &s, 0);
+            vt.h() % svt
+        },
+        Left => {
+            let mut us = u.to_owned();
+            scale_axis(&mut us, &s, 1);
+            us % u.h()
+        },
+    };
+    Ok(PolarResult { u: unitary, p })
+}
+
+/* #endregion */
+
+/* #region orth, null_space */
+
+/// Orthonormal basis for the range of `A`, from thin SVD `A = W S V†`.
+pub fn ref_impl_orth_from_svd<T, B>(
+    u: Tensor<T, B, Ix2>,
+    s: Tensor<T::Real, B, Ix1>,
+    n: usize,
+    rcond: Option<T::Real>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: DeviceOrthAPI<T>,
+{
+    let order = u.device().default_order();
+    let m = u.shape()[0];
+    let rank = svd_rank(&to_vec_ix1(&s), rcond, m, n);
+    u.into_slice((.., ..rank)).into_dim::<Ix2>().into_contig_f(order)
+}
+
+/// Orthonormal basis for the null space of `A`, from full SVD `A = W S V†`.
+pub fn ref_impl_null_space_from_svd<T, B>(
+    s: Tensor<T::Real, B, Ix1>,
+    vt: Tensor<T, B, Ix2>,
+    m: usize,
+    rcond: Option<T::Real>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: DeviceOrthAPI<T>,
+{
+    let order = vt.device().default_order();
+    let n = vt.shape()[1];
+    let rank = svd_rank(&to_vec_ix1(&s), rcond, m, n);
+    let null = vt.i((rank.., ..)).into_dim::<Ix2>().h().to_owned_f()?;
+    null.into_contig_f(order)
+}
+
+/* #endregion */
+
+/* #region lowdin_orth, canonical_orth */
+
+/// Number of (ascending) eigenvalues that are not larger than `thresh`.
+fn eigh_n_drop<R>(w: &[R], thresh: R) -> usize
+where
+    R: Float,
+{
+    w.iter().take_while(|&&x| x <= thresh).count()
+}
+
+/// Löwdin (symmetric) orthogonalization `X = S^{-1/2}` from eigen decomposition
+/// of overlap `S = V w V†`.
+///
+/// Eigenvalues not larger than `thresh` are projected out. If `thresh` is not
+/// given, `eps * n * max(w)` is used, which only removes numerically singular
+/// directions.
+pub fn ref_impl_lowdin_orth_from_eigh<T, B>(
+    w: Tensor<T::Real, B, Ix1>,
+    v: Tensor<T, B, Ix2>,
+    thresh: Option<T::Real>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: DeviceOrthAPI<T>,
+{
+    let order = v.device().default_order();
+    let w = to_vec_ix1(&w);
+    let n = w.len();
+    let thresh = thresh.unwrap_or_else(|| {
+        let wmax = w.iter().fold(T::Real::zero(), |acc, &x| acc.max(x.abs()));
+        T::Real::epsilon() * T::Real::from_usize(n).unwrap() * wmax
+    });
+    let n_drop = eigh_n_drop(&w, thresh);
+    let v = v.into_slice((.., n_drop..)).into_dim::<Ix2>().into_contig_f(order)?;
+    let mut v_scaled = v.to_owned();
+    let f = w[n_drop..].iter().map(|&x| T::Real::one() / x.sqrt()).collect::<Vec<_>>();
+    scale_axis(&mut v_scaled, &f, 1);
+    Ok(v_scaled % v.h())
+}
+
+/// Canonical orthogonalization `X = V w^{-1/2}` from eigen decomposition of
+/// overlap `S = V w V†`.
+///
+/// Eigenvectors with eigenvalues not larger than `thresh` (near-linear
+/// dependencies) are dropped, so `X` is of shape `(n, n - n_dropped)`. If
+/// `thresh` is not given, `sqrt(eps)` (about `1.5e-8` for `f64`) is used.
+pub fn ref_impl_canonical_orth_from_eigh<T, B>(
+    w: Tensor<T::Real, B, Ix1>,
+    v: Tensor<T, B, Ix2>,
+    thresh: Option<T::Real>,
+) -> Result<Tensor<T, B, Ix2>>
+where
+    T: BlasFloat,
+    B: DeviceOrthAPI<T>,
+{
+    let order = v.device().default_order();
+    let w = to_vec_ix1(&w);
+    let thresh = thresh.unwrap_or_else(|| T::Real::epsilon().sqrt());
+    let n_drop = eigh_n_drop(&w, thresh);
+    let mut x = v.into_slice((.., n_drop..)).into_dim::<Ix2>().into_contig_f(order)?;
+    let f = w[n_drop..].iter().map(|&x| T::Real::one() / x.sqrt()).collect::<Vec<_>>();
+    scale_axis(&mut x, &f, 1);
+    Ok(x)
+}
+
+/* #endregion */
+
+/* #region gram_schmidt */
+
+/// Block modified Gram-Schmidt with re-orthogonalization (BMGS2).
+///
+/// Columns of `A` are processed in blocks of `block_size` (default 32).
+/// Each block is first orthogonalized against all previous blocks one by one
+/// (block MGS, performed by matmul), and then its columns are orthogonalized
+/// by MGS. Both steps are done twice ("twice is enough"), so that loss of
+/// orthogonality stays at machine precision even for ill-conditioned `A`.
+///
+/// `A` should have full column rank; an error is raised if a column vanishes
+/// during orthogonalization.
+pub fn ref_impl_gram_schmidt_f<T, B>(
+    a: TensorView<'_, T, B, Ix2>,
+    block_size: Option<usize>,
+) -> Result<GramSchmidtResult<Tensor<T, B, Ix2>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    B: DeviceOrthAPI<T>,
+{
+    let device = a.device().clone();
+    let order = device.default_order();
+    let [m, n] = *a.shape();
+    rstsr_assert!(m >= n, InvalidLayout, "Gram-Schmidt requires rows ({m}) not less than columns ({n}).")?;
+    let nb = block_size.unwrap_or(32).max(1);
+
+    let mut q = a.into_contig_f(order)?;
+    // R is accumulated in row-major
+    let mut r = vec![T::zero(); n * n];
+
+    for jb in (0..n).step_by(nb) {
+        let je = (jb + nb).min(n);
+        let nbj = je - jb;
+
+        // current block in f-contiguous buffer, where columns are contiguous
+        let mut panel: Tensor<T, B, Ix2> = unsafe { empty(([m, nbj].f(), &device)) }.into_dim_f()?;
+        panel.assign_f(q.i((.., jb..je)).into_dim::<Ix2>())?;
+
+        // block MGS against previous blocks, twice
+        for _ in 0..2 {
+            for kb in (0..jb).step_by(nb) {
+                let ke = (kb + nb).min(jb);
+                let q_k = q.i((.., kb..ke)).into_dim::<Ix2>();
+                let r_kj = q_k.h() % &panel;
+                matmul_from_f(&mut panel, &q_k, &r_kj, -T::one(), T::one())?;
+                for i in kb..ke {
+                    for j in jb..je {
+                        r[i * n + j] = r[i * n + j] + r_kj[[i - kb, j - jb]];
+                    }
+                }
+            }
+        }
+
+        // MGS inside current block, twice
+        let raw = panel.raw_mut();
+        for j in 0..nbj {
+            for _ in 0..2 {
+                for i in 0..j {
+                    let (col_i, col_j) = raw.split_at_mut(j * m);
+                    let col_i = &col_i[i * m..(i + 1) * m];
+                    let col_j = &mut col_j[..m];
+                    let r_ij = col_i.iter().zip(col_j.iter()).fold(T::zero(), |acc, (&x, &y)| acc + x.conj() * y);
+                    col_j.iter_mut().zip(col_i).for_each(|(y, &x)| *y = *y - r_ij * x);
+                    r[(jb + i) * n + jb + j] = r[(jb + i) * n + jb + j] + r_ij;
+                }
+            }
+            let col_j = &mut raw[j * m..(j + 1) * m];
+            let norm = col_j.iter().fold(T::Real::zero(), |acc, &x| acc + (x.conj() * x).re()).sqrt();
+            rstsr_assert!(
+                norm > T::Real::zero(),
+                InvalidValue,
+                "Column {} is linearly dependent on previous columns in Gram-Schmidt.",
+                jb + j
+            )?;
+            col_j.iter_mut().for_each(|x| *x /= norm);
+            r[(jb + j) * n + jb + j] = T::from(norm).unwrap();
+        }
+
+        q.i_mut((.., jb..je)).into_dim::<Ix2>().assign_f(&panel)?;
+    }
+
+    let r = asarray((r, [n, n].c(), &device)).into_dim::<Ix2>().into_contig_f(order)?;
+    Ok(GramSchmidtResult { q, r })
+}
+
+/* #endregion */
+
+/* #region rsvd */
+
+/// Deterministic standard normal samples by splitmix64 and Box-Muller
+/// transform, so that randomized algorithms are reproducible.
+fn gaussian_samples<R>(len: usize, seed: u64) -> Vec<R>
+where
+    R: Float + FromPrimitive,
+{
+    let mut state = seed;
+    let mut next_uniform = || {
+        state = state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^= z >> 31;
+        // uniform in (0, 1]
+        ((z >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    };
+    let mut samples = Vec::with_capacity(len + 1);
+    while samples.len() < len {
+        let r = (-2.0 * next_uniform().ln()).sqrt();
+        let theta = 2.0 * core::f64::consts::PI * next_uniform();
+        samples.push(R::from_f64(r * theta.cos()).unwrap());
+        samples.push(R::from_f64(r * theta.sin()).unwrap());
+    }
+    samples.truncate(len);
+    samples
+}
+
+/// Randomized SVD of rank `k` (Halko, Martinsson and Tropp, 2011).
+///
+/// Range of `A` is sampled by `A Ω` with Gaussian `Ω` of `k + oversample`
+/// columns, and refined by `n_iter` power iterations `(A A†)^q A Ω`, where
+/// the samples are orthonormalized by `qr_thin` (thin `Q` of Householder QR)
+/// after each multiplication for numerical stability. Householder QR is used
+/// instead of Gram-Schmidt, since the samples are rank-deficient whenever the
+/// rank of `A` is smaller than `k + oversample`. The small matrix `Q† A` is
+/// then decomposed by `svd_thin`.
+///
+/// The test matrix is generated with a fixed seed, so results are
+/// reproducible.
+pub fn ref_impl_rsvd_with_svd<T, B, Q, F>(
+    a: TensorView<'_, T, B, Ix2>,
+    k: usize,
+    oversample: usize,
+    n_iter: usize,
+    qr_thin: Q,
+    svd_thin: F,
+) -> Result<SVDResult<Tensor<T, B, Ix2>, Tensor<T::Real, B, Ix1>, Tensor<T, B, Ix2>>>
+where
+    T: BlasFloat,
+    T::Real: FromPrimitive,
+    B: DeviceOrthAPI<T>,
+    Q: Fn(TensorView<'_, T, B, Ix2>) -> Result<Tensor<T, B, Ix2>>,
+    F: Fn(TensorView<'_, T, B, Ix2>) -> Result<(Tensor<T, B, Ix2>, Tensor<T::Real, B, Ix1>, Tensor<T, B, Ix2>)>,
+{
+    let device = a.device().clone();
+    let order = device.default_order();
+    let [m, n] = *a.shape();
+    let minmn = m.min(n);
+    rstsr_assert!(1 <= k && k <= minmn, InvalidValue, "rsvd requires 1 <= k <= min(m, n) = {minmn}, got k = {k}.")?;
+    let l = (k + oversample).min(minmn);
+
+    let omega = gaussian_samples::<T::Real>(n * l, 0).into_iter().map(|x| T::from(x).unwrap()).collect::<Vec<_>>();
+    let omega = asarray((omega, [n, l].c(), &device)).into_dim::<Ix2>();
+
+    // range finder with power iterations
+    let mut q = qr_thin((&a % &omega).view())?;
+    for _ in 0..n_iter {
+        let z = qr_thin((a.h() % &q).view())?;
+        q = qr_thin((&a % &z).view())?;
+    }
+
+    // SVD of the projected matrix Q† A
+    let (u_b, s, vt) = svd_thin((q.h() % &a).view())?;
+    let u = q % u_b.into_slice((.., ..k)).into_dim::<Ix2>();
+    let s = asarray((to_vec_ix1(&s)[..k].to_vec(), [k].c(), &device)).into_dim::<Ix1>();
+    let vt = vt.into_slice((..k, ..)).into_dim::<Ix2>().into_contig_f(order)?;
+    Ok(SVDResult { u, s, vt })
+}
+
+/* #endregion */
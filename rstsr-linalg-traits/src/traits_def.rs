@@ -18,6 +18,20 @@ use rstsr_core::prelude_dev::*;
    [SolveTriangularAPI] [solve_triangular] [solve_triangular_f];
    [SVDAPI            ] [svd             ] [svd_f             ];
    [SVDvalsAPI        ] [svdvals         ] [svdvals_f         ];
+   [SVDPartialAPI     ] [svd_partial     ] [svd_partial_f     ];
+   [PolarAPI          ] [polar           ] [polar_f           ];
+   [OrthAPI           ] [orth            ] [orth_f            ];
+   [NullSpaceAPI      ] [null_space      ] [null_space_f      ];
+   [LowdinOrthAPI     ] [lowdin_orth     ] [lowdin_orth_f     ];
+   [CanonicalOrthAPI  ] [canonical_orth  ] [canonical_orth_f  ];
+   [GramSchmidtAPI    ] [gram_schmidt    ] [gram_schmidt_f    ];
+   [RSVDAPI           ] [rsvd            ] [rsvd_f            ];
+   [SchurAPI          ] [schur           ] [schur_f           ];
+   [HessenbergAPI     ] [hessenberg      ] [hessenberg_f      ];
+   [QZAPI             ] [qz              ] [qz_f              ];
+   [OrdSchurAPI       ] [ordschur        ] [ordschur_f        ];
+   [SolveSylvesterAPI ] [solve_sylvester ] [solve_sylvester_f ];
+   [SolveContinuousLyapunovAPI] [solve_continuous_lyapunov] [solve_continuous_lyapunov_f];
 )]
 pub trait LinalgAPI<Inp> {
     type Out;
@@ -44,6 +58,20 @@ pub trait LinalgAPI<Inp> {
    [SolveTriangularAPI] [solve_triangular] [solve_triangular_f];
    [SVDAPI            ] [svd             ] [svd_f             ];
    [SVDvalsAPI        ] [svdvals         ] [svdvals_f         ];
+   [SVDPartialAPI     ] [svd_partial     ] [svd_partial_f     ];
+   [PolarAPI          ] [polar           ] [polar_f           ];
+   [OrthAPI           ] [orth            ] [orth_f            ];
+   [NullSpaceAPI      ] [null_space      ] [null_space_f      ];
+   [LowdinOrthAPI     ] [lowdin_orth     ] [lowdin_orth_f     ];
+   [CanonicalOrthAPI  ] [canonical_orth  ] [canonical_orth_f  ];
+   [GramSchmidtAPI    ] [gram_schmidt    ] [gram_schmidt_f    ];
+   [RSVDAPI           ] [rsvd            ] [rsvd_f            ];
+   [SchurAPI          ] [schur           ] [schur_f           ];
+   [HessenbergAPI     ] [hessenberg      ] [hessenberg_f      ];
+   [QZAPI             ] [qz              ] [qz_f              ];
+   [OrdSchurAPI       ] [ordschur        ] [ordschur_f        ];
+   [SolveSylvesterAPI ] [solve_sylvester ] [solve_sylvester_f ];
+   [SolveContinuousLyapunovAPI] [solve_continuous_lyapunov] [solve_continuous_lyapunov_f];
 )]
 pub fn func_f<Args, Inp>(args: Args) -> Result<<Args as LinalgAPI<Inp>>::Out>
 where
@@ -66,6 +94,20 @@ where
    [SolveTriangularAPI] [solve_triangular] [solve_triangular_f];
    [SVDAPI            ] [svd             ] [svd_f             ];
    [SVDvalsAPI        ] [svdvals         ] [svdvals_f         ];
+   [SVDPartialAPI     ] [svd_partial     ] [svd_partial_f     ];
+   [PolarAPI          ] [polar           ] [polar_f           ];
+   [OrthAPI           ] [orth            ] [orth_f            ];
+   [NullSpaceAPI      ] [null_space      ] [null_space_f      ];
+   [LowdinOrthAPI     ] [lowdin_orth     ] [lowdin_orth_f     ];
+   [CanonicalOrthAPI  ] [canonical_orth  ] [canonical_orth_f  ];
+   [GramSchmidtAPI    ] [gram_schmidt    ] [gram_schmidt_f    ];
+   [RSVDAPI           ] [rsvd            ] [rsvd_f            ];
+   [SchurAPI          ] [schur           ] [schur_f           ];
+   [HessenbergAPI     ] [hessenberg      ] [hessenberg_f      ];
+   [QZAPI             ] [qz              ] [qz_f              ];
+   [OrdSchurAPI       ] [ordschur        ] [ordschur_f        ];
+   [SolveSylvesterAPI ] [solve_sylvester ] [solve_sylvester_f ];
+   [SolveContinuousLyapunovAPI] [solve_continuous_lyapunov] [solve_continuous_lyapunov_f];
 )]
 pub fn func<Args, Inp>(args: Args) -> <Args as LinalgAPI<Inp>>::Out
 where
@@ -113,8 +155,10 @@ where
     pub eigvals_only: bool,
     #[builder(setter(into), default = 1)]
     pub eig_type: i32,
+    /// Eigenvalues with (0-based) indices in the inclusive range `[lo, hi]`.
     #[builder(setter(into, strip_option), default = "None")]
     pub subset_by_index: Option<(usize, usize)>,
+    /// Eigenvalues in the half-open interval `(vl, vu]`.
     #[builder(setter(into, strip_option), default = "None")]
     pub subset_by_value: Option<(T::Real, T::Real)>,
     #[builder(setter(into, strip_option), default = "None")]
@@ -205,8 +249,29 @@ where
 {
     #[builder(setter(into))]
     pub a: TensorReference<'a, T, B, Ix2>,
-    #[builder(setter(into), default = "Some(true)")]
+    /// Shape of `U` and `VT`; `None` skips singular vectors. Defaults to full matrices, or to thin
+    /// matrices if a subset of singular values is requested.
+    #[builder(
+        setter(into),
+        default = "Some(matches!(self.subset_by_index, None | Some(None)) && matches!(self.subset_by_value, None | Some(None)))"
+    )]
     pub full_matrices: Option<bool>,
+    /// Compute singular vectors; `false` computes singular values only (use with `svdvals` or
+    /// `svd_partial`).
+    #[builder(setter(into), default = true)]
+    pub compute_uv: bool,
+    /// Compute left singular vectors `U` only, `VT` is not built (use with `svd_partial`). Uses
+    /// `"gesvd"` unless `driver` is given.
+    #[builder(setter(into), default = false)]
+    pub compute_u_only: bool,
+    /// Singular values with (0-based) indices in the inclusive range `[lo, hi]`, in descending
+    /// order.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub subset_by_index: Option<(usize, usize)>,
+    /// Singular values in the half-open interval `(vl, vu]`.
+    #[builder(setter(into, strip_option), default = "None")]
+    pub subset_by_value: Option<(T::Real, T::Real)>,
+    /// LAPACK driver: `"gesdd"`, `"gesvd"`, `"gesvdx"` or `"gejsv"`.
     #[builder(setter(into), default = "None")]
     pub driver: Option<&'static str>,
 }
@@ -214,3 +279,117 @@ where
 pub type SVDArgs<'a, B, T> = SVDArgs_Builder<'a, B, T>;
 
 /* #endregion */
+
+/* #region polar */
+
+/// Polar decomposition `A = U P` (right) or `A = P U` (left).
+///
+/// `U` has orthonormal columns (or rows, if `A` is wide), and `P` is hermitian
+/// positive semi-definite.
+pub struct PolarResult<U, P> {
+    pub u: U,
+    pub p: P,
+}
+
+impl<U, P> From<(U, P)> for PolarResult<U, P> {
+    fn from((u, p): (U, P)) -> Self {
+        Self { u, p }
+    }
+}
+
+impl<U, P> From<PolarResult<U, P>> for (U, P) {
+    fn from(polar_result: PolarResult<U, P>) -> Self {
+        (polar_result.u, polar_result.p)
+    }
+}
+
+/* #endregion */
+
+/* #region gram_schmidt */
+
+/// QR factors `A = Q R` by Gram-Schmidt orthogonalization.
+pub struct GramSchmidtResult<Q, R> {
+    pub q: Q,
+    pub r: R,
+}
+
+impl<Q, R> From<(Q, R)> for GramSchmidtResult<Q, R> {
+    fn from((q, r): (Q, R)) -> Self {
+        Self { q, r }
+    }
+}
+
+impl<Q, R> From<GramSchmidtResult<Q, R>> for (Q, R) {
+    fn from(gram_schmidt_result: GramSchmidtResult<Q, R>) -> Self {
+        (gram_schmidt_result.q, gram_schmidt_result.r)
+    }
+}
+
+/* #endregion */
+
+/* #region schur, hessenberg, qz */
+
+/// Schur decomposition `A = Z T Z†`.
+///
+/// `T` is upper triangular (complex), or upper quasi-triangular with 2x2
+/// blocks of complex conjugate eigenvalue pairs (real); `Z` is unitary.
+pub struct SchurResult<T, Z> {
+    pub t: T,
+    pub z: Z,
+}
+
+impl<T, Z> From<(T, Z)> for SchurResult<T, Z> {
+    fn from((t, z): (T, Z)) -> Self {
+        Self { t, z }
+    }
+}
+
+impl<T, Z> From<SchurResult<T, Z>> for (T, Z) {
+    fn from(schur_result: SchurResult<T, Z>) -> Self {
+        (schur_result.t, schur_result.z)
+    }
+}
+
+/// Hessenberg decomposition `A = Q H Q†`, with `H` upper Hessenberg and `Q`
+/// unitary.
+pub struct HessenbergResult<H, Q> {
+    pub h: H,
+    pub q: Q,
+}
+
+impl<H, Q> From<(H, Q)> for HessenbergResult<H, Q> {
+    fn from((h, q): (H, Q)) -> Self {
+        Self { h, q }
+    }
+}
+
+impl<H, Q> From<HessenbergResult<H, Q>> for (H, Q) {
+    fn from(hessenberg_result: HessenbergResult<H, Q>) -> Self {
+        (hessenberg_result.h, hessenberg_result.q)
+    }
+}
+
+/// Generalized Schur (QZ) decomposition `A = Q AA Z†`, `B = Q BB Z†`.
+///
+/// `BB` is upper triangular, `AA` is upper (quasi-)triangular as in
+/// [`SchurResult`]; `Q` and `Z` are unitary.
+pub struct QZResult<AA, BB, Q, Z> {
+    pub aa: AA,
+    pub bb: BB,
+    pub q: Q,
+    pub z: Z,
+}
+
+impl<AA, BB, Q, Z> From<(AA, BB, Q, Z)> for QZResult<AA, BB, Q, Z> {
+    fn from((aa, bb, q, z): (AA, BB, Q, Z)) -> Self {
+        Self { aa, bb, q, z }
+    }
+}
+
+impl<AA, BB, Q, Z> From<QZResult<AA, BB, Q, Z>> for (AA, BB, Q, Z) {
+    fn from(qz_result: QZResult<AA, BB, Q, Z>) -> Self {
+        (qz_result.aa, qz_result.bb, qz_result.q, qz_result.z)
+    }
+}
+
+/* #endregion */
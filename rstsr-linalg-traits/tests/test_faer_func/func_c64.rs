@@ -192,6 +192,19 @@ mod test {
         assert!((fingerprint(&vt.abs()) - 1.4497879458575658).abs() < 1e-8);
     }
 
+    #[test]
+    fn test_svd_reconstruct() {
+        // vt should be V^H (not V^T) for complex matrices
+        let device = DeviceFaer::default();
+        for (m, n) in [(7, 5), (5, 7)] {
+            let a = rt::linspace((0.0, 4.0, m * n, &device)).mapv(|x: f64| c64!(x.sin(), (1.3 * x).cos()));
+            let a = a.into_shape([m, n]).into_dim::<Ix2>();
+            let (u, s, vt) = rt::linalg::svd((a.view(), false)).into();
+            let s = rt::diag(&s.mapv(|v| c64!(v)));
+            assert!((&u % &s % &vt - &a).abs().max_all() < 1e-10);
+        }
+    }
+
     #[test]
     fn test_svdvals() {
         let device = DeviceFaer::default();
@@ -208,6 +221,71 @@ mod test {
         let s = rt::linalg::svdvals(a.view());
         assert!((fingerprint(&s) - 47.599274835886646).abs() < 1e-8);
     }
+
+    #[test]
+    fn test_orth_funcs() {
+        let device = DeviceFaer::default();
+        let vec = (0..6 * 4).map(|i| c64!((i as f64 * 0.7).sin(), (i as f64 * 1.3).cos())).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [6, 4].c(), &device));
+        let conj_t = |x: &Tensor<c64, DeviceFaer>| x.t().mapv(|v| v.conj());
+        let eye = |n: usize| rt::eye((n, &device));
+
+        let (u, p) = rt::linalg::polar(a.view()).into();
+        assert!((conj_t(&u) % &u - eye(4)).abs().max_all() < 1e-10);
+        assert!((&p - conj_t(&p)).abs().max_all() < 1e-10);
+        assert!((&u % &p - &a).abs().max_all() < 1e-10);
+
+        let q = rt::linalg::orth(a.view());
+        assert!((conj_t(&q) % &q - eye(4)).abs().max_all() < 1e-10);
+        let n = rt::linalg::null_space(conj_t(&a).view());
+        assert_eq!(n.shape(), &[6, 2]);
+        assert!((conj_t(&a) % &n).abs().max_all() < 1e-10);
+
+        let s = conj_t(&a) % &a;
+        let x = rt::linalg::lowdin_orth(s.view());
+        assert!((&x % &s % &x - eye(4)).abs().max_all() < 1e-10);
+        let x = rt::linalg::canonical_orth(s.view());
+        assert!((conj_t(&x) % &s % &x - eye(4)).abs().max_all() < 1e-10);
+
+        let (q, r) = rt::linalg::gram_schmidt((a.view(), 3)).into();
+        assert!((conj_t(&q) % &q - eye(4)).abs().max_all() < 1e-12);
+        assert!((&q % &r - &a).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_schur_qz() {
+        let device = DeviceFaer::default();
+        let vec = (0..5 * 5).map(|i| c64!((i as f64 * 0.7).sin(), (i as f64 * 1.3).cos())).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [5, 5].c(), &device));
+        let vec = (0..5 * 5).map(|i| c64!((i as f64 * 0.3).cos(), (i as f64 * 0.9).sin())).collect::<Vec<_>>();
+        let b = rt::asarray((vec, [5, 5].c(), &device));
+        let conj_t = |x: &Tensor<c64, DeviceFaer>| x.t().mapv(|v| v.conj());
+
+        let (t, z) = rt::linalg::schur(a.view()).into();
+        assert!((0..5).all(|j| (j + 1..5).all(|i| t[[i, j]] == c64!(0.0))));
+        assert!((&z % &t % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        let (aa, bb, q, z) = rt::linalg::qz((a.view(), b.view())).into();
+        assert!((&q % &aa % conj_t(&z) - &a).abs().max_all() < 1e-10);
+        assert!((&q % &bb % conj_t(&z) - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_sylvester_lyapunov() {
+        let device = DeviceFaer::default();
+        let vec = (0..4 * 4).map(|i| c64!((i as f64 * 0.7).sin(), (i as f64 * 1.3).cos())).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [4, 4].c(), &device));
+        let vec = (0..3 * 3).map(|i| c64!((i as f64 * 0.3).cos(), (i as f64 * 0.9).sin())).collect::<Vec<_>>();
+        let b = rt::asarray((vec, [3, 3].c(), &device));
+        let vec = (0..4 * 3).map(|i| c64!((i as f64 * 0.4).sin(), (i as f64 * 0.5).cos())).collect::<Vec<_>>();
+        let c = rt::asarray((vec, [4, 3].c(), &device));
+        let conj_t = |x: &Tensor<c64, DeviceFaer>| x.t().mapv(|v| v.conj());
+
+        let x = rt::linalg::solve_sylvester((&a, &b, &c));
+        assert!((&a % &x + &x % &b - &c).abs().max_all() < 1e-10);
+        let q = &c % conj_t(&c);
+        let x = rt::linalg::solve_continuous_lyapunov((&a, &q));
+        assert!((&a % &x + &x % conj_t(&a) - &q).abs().max_all() < 1e-10);
+    }
 }
 
 #[cfg(test)]
@@ -73,6 +73,20 @@ mod test {
         assert!((fingerprint(&w) - -71.4902453763506).abs() < 1e-8);
     }
 
+    #[test]
+    fn test_eigh_subset_unsupported() {
+        let device = DeviceFaer::default();
+        let a: Tensor<f64, _, Ix2> = rt::eye((4, &device)).into_dim();
+
+        // full spectrum by EighArgs is fine
+        let (w, _) = rt::linalg::eigh(rt::linalg::EighArgs::default().a(a.view())).into();
+        assert_eq!(w.shape(), &[4]);
+
+        // faer can not compute subset of eigenvalues
+        let args = rt::linalg::EighArgs::default().a(a.view()).subset_by_index((0, 1));
+        assert!(rt::linalg::eigh_f(args).is_err());
+    }
+
     #[test]
     fn test_inv() {
         let device = DeviceFaer::default();
@@ -195,4 +209,112 @@ mod test {
         let s = rt::linalg::svdvals(a.view());
         assert!((fingerprint(&s) - 32.27742168207757).abs() < 1e-8);
     }
+
+    #[test]
+    fn test_orth_funcs() {
+        let device = DeviceFaer::default();
+        let vec = (0..7 * 5).map(|i| (i as f64 * 0.7).sin()).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [7, 5].c(), &device));
+        let eye = |n: usize| rt::eye((n, &device));
+
+        let (u, p) = rt::linalg::polar(a.view()).into();
+        assert!((u.t() % &u - eye(5)).abs().max_all() < 1e-10);
+        assert!((&u % &p - &a).abs().max_all() < 1e-10);
+
+        // rows of this matrix are linear-dependent (sin of arithmetic sequence)
+        let q = rt::linalg::orth(a.view());
+        let rank = q.shape()[1];
+        assert!((q.t() % &q - eye(rank)).abs().max_all() < 1e-10);
+        assert!((&q % (q.t() % &a) - &a).abs().max_all() < 1e-10);
+        let n = rt::linalg::null_space(a.view());
+        assert_eq!(n.shape(), &[5, 5 - rank]);
+        assert!((&a % &n).abs().max_all() < 1e-10);
+
+        let s = a.t() % &a + eye(5);
+        let x = rt::linalg::lowdin_orth(s.view());
+        assert!((&x % &s % &x - eye(5)).abs().max_all() < 1e-10);
+        let x = rt::linalg::canonical_orth(s.view());
+        assert!((x.t() % &s % &x - eye(5)).abs().max_all() < 1e-10);
+
+        let (q, r) = rt::linalg::gram_schmidt((s.view(), 2)).into();
+        assert!((q.t() % &q - eye(5)).abs().max_all() < 1e-12);
+        assert!((&q % &r - &s).abs().max_all() < 1e-12);
+    }
+
+    #[test]
+    fn test_hessenberg() {
+        let device = DeviceFaer::default();
+        let vec = (0..6 * 6).map(|i| (i as f64 * 0.7).sin() + (i % 5) as f64).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [6, 6].c(), &device));
+        let (h, q) = rt::linalg::hessenberg(a.view()).into();
+        assert!((0..6).all(|j| (j + 2..6).all(|i| h[[i, j]] == 0.0)));
+        assert!((q.t() % &q - rt::eye((6, &device))).abs().max_all() < 1e-10);
+        assert!((&q % &h % q.t() - &a).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_rsvd() {
+        let device = DeviceFaer::default();
+        // rank-2 matrix, since sin(x + y) = sin(x) cos(y) + cos(x) sin(y)
+        let vec = (0..30 * 20).map(|i| (i as f64 * 0.7).sin()).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [30, 20].c(), &device));
+        let s_ref = rt::linalg::svdvals(a.view());
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 2)).into();
+        assert!((&s - s_ref.i(..2)).abs().max_all() < 1e-10);
+        assert!((u.t() % &u - rt::eye((2, &device))).abs().max_all() < 1e-10);
+        assert!(((u * s.i((None, ..))) % vt - &a).abs().max_all() < 1e-10);
+
+        // zero matrix: samples are exactly rank-deficient
+        let a = &a * 0.0;
+        let (u, s, vt) = rt::linalg::rsvd((a.view(), 2)).into();
+        assert_eq!((&s).abs().max_all(), 0.0);
+        assert!((u.t() % &u - rt::eye((2, &device))).abs().max_all() < 1e-10);
+        assert_eq!(((u * s.i((None, ..))) % vt).abs().max_all(), 0.0);
+    }
+
+    #[test]
+    fn test_schur_ordschur_qz() {
+        let device = DeviceFaer::default();
+        let vec = (0..6 * 6).map(|i| (i as f64 * 0.7).sin() + (i % 5) as f64).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [6, 6].c(), &device));
+        let (t, z) = rt::linalg::schur(a.view()).into();
+        assert!((z.t() % &z - rt::eye((6, &device))).abs().max_all() < 1e-10);
+        assert!((&z % &t % z.t() - &a).abs().max_all() < 1e-10);
+        assert!((0..6).all(|j| (j + 2..6).all(|i| t[[i, j]] == 0.0)));
+
+        // move eigenvalues of the last diagonal block to the top
+        let n = 6;
+        let last = if t[[n - 1, n - 2]] != 0.0 { n - 2 } else { n - 1 };
+        let select = (0..n).map(|i| i >= last).collect::<Vec<_>>();
+        let (t2, z2) = rt::linalg::ordschur((&t, &z, select.as_slice())).into();
+        assert!((&z2 % &t2 % z2.t() - &a).abs().max_all() < 1e-10);
+        assert!((t2[[0, 0]] - t[[last, last]]).abs() < 1e-10);
+
+        let vec = (0..6 * 6).map(|i| (i as f64 * 1.3).cos() + (i % 7 == 0) as usize as f64 * 3.0).collect::<Vec<_>>();
+        let b = rt::asarray((vec, [6, 6].c(), &device));
+        let (aa, bb, q, z) = rt::linalg::qz((&a, &b)).into();
+        assert!((0..6).all(|j| (j + 1..6).all(|i| bb[[i, j]] == 0.0)));
+        assert!((&q % &aa % z.t() - &a).abs().max_all() < 1e-10);
+        assert!((&q % &bb % z.t() - &b).abs().max_all() < 1e-10);
+    }
+
+    #[test]
+    fn test_solve_sylvester_lyapunov() {
+        let device = DeviceFaer::default();
+        let vec = (0..5 * 5).map(|i| (i as f64 * 0.7).sin() + (i % 5) as f64).collect::<Vec<_>>();
+        let a = rt::asarray((vec, [5, 5].c(), &device)) + 4.0 * rt::eye((5, &device));
+        let vec = (0..3 * 3).map(|i| (i as f64 * 1.3).cos()).collect::<Vec<_>>();
+        let b = rt::asarray((vec, [3, 3].c(), &device)) + 2.0 * rt::eye((3, &device));
+        let vec = (0..5 * 3).map(|i| (i as f64 * 0.4).sin()).collect::<Vec<_>>();
+        let c = rt::asarray((vec, [5, 3].c(), &device));
+        let x = rt::linalg::solve_sylvester((&a, &b, &c));
+        assert!((&a % &x + &x % &b - &c).abs().max_all() < 1e-10);
+
+        // stable A: shift spectrum to the left half-plane
+        let a = &a - 16.0 * rt::eye((5, &device));
+        let q = &c % c.t() + rt::eye((5, &device));
+        let x = rt::linalg::solve_continuous_lyapunov((&a, &q));
+        assert!((&a % &x + &x % a.t() - &q).abs().max_all() < 1e-10);
+        assert!((&x - x.t()).abs().max_all() < 1e-10);
+    }
 }
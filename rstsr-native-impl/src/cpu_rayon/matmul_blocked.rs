@@ -0,0 +1,106 @@
+//! Parallel cache-blocked matrix multiplication for CPU backend.
+//!
+//! See [`crate::cpu_serial::matmul_blocked`] for the blocking scheme. The
+//! `KC x NC` block of B is packed in parallel and shared by all threads; the
+//! `MC x NC` block of C is split into independent tasks, each of which packs
+//! its own block of A.
+
+use crate::cpu_serial::matmul_blocked::*;
+use crate::prelude_dev::*;
+use core::ops::{Add, Mul};
+use num::Zero;
+use rayon::prelude::*;
+
+/// Problem size (`m * n * k`) below which the serial kernel is used.
+const GEMM_BLOCKED_PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+
+/// Parallel cache-blocked GEMM `C = alpha * A * B + beta * C` for generic
+/// types.
+///
+/// Layouts may be of arbitrary (including negative or zero) strides. Output is
+/// not read if `beta` is zero, following BLAS convention.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_blocked_cpu_rayon<TA, TB, TC>(
+    c: &mut [TC],
+    lc: &Layout<Ix2>,
+    a: &[TA],
+    la: &Layout<Ix2>,
+    b: &[TB],
+    lb: &Layout<Ix2>,
+    alpha: TC,
+    beta: TC,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    TA: Clone + Send + Sync + Mul<TB, Output = TC>,
+    TB: Clone + Send + Sync,
+    TC: Clone + Send + Sync + Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
+{
+    // shape check
+    let sc = lc.shape();
+    let sa = la.shape();
+    let sb = lb.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sa[1], sb[0], InvalidLayout)?;
+    rstsr_assert_eq!(sc[1], sb[1], InvalidLayout)?;
+    let (m, n, k) = (sc[0], sc[1], sa[1]);
+
+    let nthreads = pool.map_or_else(rayon::current_num_threads, |pool| pool.current_num_threads());
+    if nthreads == 1 || m * n * k < GEMM_BLOCKED_PARALLEL_THRESHOLD {
+        return gemm_blocked_cpu_serial(c, lc, a, la, b, lb, alpha, beta);
+    }
+    if k == 0 {
+        gemm_blocked_scale_c(c, lc, &beta);
+        return Ok(());
+    }
+
+    let [rsa, csa] = *la.stride();
+    let [rsb, csb] = *lb.stride();
+    let [rsc, csc] = *lc.stride();
+
+    // rows of A are split to at least `nthreads` blocks if possible; if there
+    // are still not enough blocks, micro-panels of B are further split to groups
+    let mc = m.div_ceil(nthreads).next_multiple_of(GEMM_MR).min(GEMM_MC);
+    let n_ic = m.div_ceil(mc);
+
+    let task = || unsafe {
+        for jc in (0..n).step_by(GEMM_NC) {
+            let nc = GEMM_NC.min(n - jc);
+            let n_jr = nc.div_ceil(GEMM_NR);
+            let n_jr_group = nthreads.div_ceil(n_ic).min(n_jr);
+            let jr_group_size = n_jr.div_ceil(n_jr_group);
+            for pc in (0..k).step_by(GEMM_KC) {
+                let kc = GEMM_KC.min(k - pc);
+                let offset_b = lb.offset() as isize + pc as isize * rsb + jc as isize * csb;
+                let bp: Vec<Vec<TB>> = (0..nc)
+                    .into_par_iter()
+                    .step_by(GEMM_NR)
+                    .map(|jr| {
+                        let offset = offset_b + jr as isize * csb;
+                        gemm_blocked_pack_b_panel(b, offset, rsb, csb, kc, GEMM_NR.min(nc - jr))
+                    })
+                    .collect();
+                let beta = if pc == 0 { Some(&beta) } else { None };
+                (0..n_ic * n_jr_group).into_par_iter().for_each(|idx| {
+                    let (i_ic, i_group) = (idx / n_jr_group, idx % n_jr_group);
+                    let ic = i_ic * mc;
+                    let mc = mc.min(m - ic);
+                    let jr_start = i_group * jr_group_size;
+                    let jr_end = (jr_start + jr_group_size).min(n_jr);
+                    if jr_start >= jr_end {
+                        return;
+                    }
+                    let offset_a = la.offset() as isize + ic as isize * rsa + pc as isize * csa;
+                    let ap = gemm_blocked_pack_a(a, offset_a, rsa, csa, mc, kc);
+                    let jc_task = jc + jr_start * GEMM_NR;
+                    let offset_c = lc.offset() as isize + ic as isize * rsc + jc_task as isize * csc;
+                    let ptr_c = c.as_ptr().offset(offset_c) as *mut TC;
+                    gemm_blocked_macro_kernel(&ap, &bp[jr_start..jr_end], kc, ptr_c, rsc, csc, &alpha, beta);
+                });
+            }
+        }
+    };
+
+    pool.map_or_else(task, |pool| pool.install(task));
+    Ok(())
+}
@@ -1,11 +1,13 @@
 //! This module is to implement parallel matrix multiplication, but by some
 //! naive way.
 //!
-//! This implementation should not be efficient. It is just for non-blas
-//! compatible types, or as reference implementation.
+//! This implementation should not be efficient. It is kept as reference
+//! implementation; generic GEMM of devices is performed by
+//! [`gemm_blocked_cpu_rayon`].
 
 use crate::prelude_dev::*;
 use core::ops::{Add, Mul};
+use num::complex::ComplexFloat;
 use num::Zero;
 use rayon::prelude::*;
 
@@ -39,7 +41,8 @@ where
         (0..n).into_par_iter().for_each(|j| {
             (0..m).into_par_iter().for_each(|i| unsafe {
                 let ptr_c = c.as_ptr().offset(lc.index_uncheck(&[i, j])) as *mut TC;
-                *ptr_c = (*ptr_c).clone() * beta.clone()
+                let c_scaled = if beta.is_zero() { TC::zero() } else { (*ptr_c).clone() * beta.clone() };
+                *ptr_c = c_scaled
                     + (0..k).fold(TC::zero(), |acc, p| {
                         let val_a = a[la.index_uncheck(&[i, p]) as usize].clone();
                         let val_b = b[lb.index_uncheck(&[p, j]) as usize].clone();
@@ -88,6 +91,87 @@ where
             .unwrap_or(TC::zero())
     };
     let c_innerdot = pool.map_or_else(task, |pool| pool.install(task));
-    *c = c_innerdot * alpha + c.clone() * beta;
+    let c_scaled = if beta.is_zero() { TC::zero() } else { c.clone() * beta };
+    *c = c_innerdot * alpha + c_scaled;
     Ok(())
 }
+
+/// Elementwise conjugate of `a`, copied to a new contiguous buffer.
+///
+/// This is used to perform matmul with conjugated operands, when the kernel
+/// itself can not handle conjugation.
+pub fn conj_to_contig_cpu_rayon<T, D>(
+    a: &[T],
+    la: &Layout<D>,
+    order: FlagOrder,
+    pool: Option<&ThreadPool>,
+) -> Result<(Vec<T>, Layout<D>)>
+where
+    T: ComplexFloat + Send + Sync,
+    D: DimAPI,
+{
+    let la_new = match order {
+        RowMajor => la.shape().new_c_contig(None),
+        ColMajor => la.shape().new_f_contig(None),
+    };
+    let mut a_new: Vec<MaybeUninit<T>> = unsafe { uninitialized_vec(la_new.size())? };
+    op_muta_refb_func_cpu_rayon(
+        &mut a_new,
+        &la_new,
+        a,
+        la,
+        &mut |x, y| {
+            x.write(y.conj());
+        },
+        pool,
+    )?;
+    let a_new = unsafe { core::mem::transmute::<Vec<MaybeUninit<T>>, Vec<T>>(a_new) };
+    Ok((a_new, la_new))
+}
+
+/// GEMM with operands promoted to the accumulation type `TAcc`.
+///
+/// Operands are packed into f-contiguous buffers of `TAcc` (e.g. `f16` to
+/// `f32`), multiplied by the optimized kernel `f_gemm`, and the result is cast
+/// back to `T`. Packing costs O(mk + kn + mn), which is negligible to the
+/// O(mnk) multiplication for all but tiny matrices.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_ix2_promoted_cpu_rayon<T, TAcc, F>(
+    c: &mut [T],
+    lc: &Layout<Ix2>,
+    a: &[T],
+    la: &Layout<Ix2>,
+    b: &[T],
+    lb: &Layout<Ix2>,
+    alpha: T,
+    beta: T,
+    pool: Option<&ThreadPool>,
+    f_gemm: F,
+) -> Result<()>
+where
+    T: Clone + Send + Sync + Zero + PartialEq + DTypeCastAPI<TAcc>,
+    TAcc: Clone + Send + Sync + Zero + DTypeCastAPI<T>,
+    F: FnOnce(&mut [TAcc], &Layout<Ix2>, &[TAcc], &Layout<Ix2>, &[TAcc], &Layout<Ix2>, TAcc, TAcc) -> Result<()>,
+{
+    let pack = |x: &[T], lx: &Layout<Ix2>| -> Result<(Vec<TAcc>, Layout<Ix2>)> {
+        let lx_new = lx.shape().new_f_contig(None);
+        let mut x_new: Vec<MaybeUninit<TAcc>> = unsafe { uninitialized_vec(lx_new.size())? };
+        assign_uninit_promote_cpu_rayon(&mut x_new, &lx_new, x, lx, pool)?;
+        let x_new = unsafe { core::mem::transmute::<Vec<MaybeUninit<TAcc>>, Vec<TAcc>>(x_new) };
+        Ok((x_new, lx_new))
+    };
+
+    let (a_acc, la_acc) = pack(a, la)?;
+    let (b_acc, lb_acc) = pack(b, lb)?;
+    let (mut c_acc, lc_acc) = if beta == T::zero() {
+        let lc_new = lc.shape().new_f_contig(None);
+        (vec![TAcc::zero(); lc_new.size()], lc_new)
+    } else {
+        pack(c, lc)?
+    };
+
+    let alpha = alpha.into_cast();
+    let beta = beta.into_cast();
+    f_gemm(&mut c_acc, &lc_acc, &a_acc, &la_acc, &b_acc, &lb_acc, alpha, beta)?;
+    assign_promote_cpu_rayon(c, lc, &c_acc, &lc_acc, pool)
+}
@@ -1,9 +1,11 @@
 pub mod adv_indexing;
 pub mod assignment;
 pub mod creation;
+pub mod matmul_blocked;
 pub mod matmul_naive;
 pub mod op_tri;
 pub mod op_with_func;
 pub mod reduction;
+pub mod reduction_stable;
 pub mod transpose;
 pub mod vecdot;
@@ -327,6 +327,84 @@ where
     Ok((out, lo))
 }
 
+/// Reduce along axes, writing results into a preallocated output.
+///
+/// See also [`reduce_axes_to_cpu_serial`]. Only the outer iteration over output is parallelized.
+#[allow(clippy::too_many_arguments)]
+pub fn reduce_axes_to_cpu_rayon<TI, TS, TO, I, F, FSum, FOut>(
+    c: &mut [TO],
+    lc: &Layout<IxD>,
+    a: &[TI],
+    la: &Layout<IxD>,
+    axes: &[isize],
+    init: I,
+    f: F,
+    f_sum: FSum,
+    f_out: FOut,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    TI: Clone + Send + Sync,
+    TS: Clone + Send + Sync,
+    TO: Clone + Send + Sync,
+    I: Fn() -> TS + Send + Sync,
+    F: Fn(TS, TI) -> TS + Send + Sync,
+    FSum: Fn(TS, TS) -> TS + Send + Sync,
+    FOut: Fn(TS) -> TO + Send + Sync,
+{
+    // determine whether to use parallel iteration
+    // broadcasted output is also handled in serial, since elements would be written by multiple threads
+    let size = la.size();
+    let lc_broadcasted = lc.shape().iter().zip(lc.stride().iter()).any(|(&n, &s)| n > 1 && s == 0);
+    if size < PARALLEL_SWITCH || lc_broadcasted {
+        return reduce_axes_to_cpu_serial(c, lc, a, la, axes, init, f, f_sum, f_out);
+    }
+
+    // naming convention follows `reduce_axes_cpu_rayon`
+    let (ls, lm) = la.dim_split_axes(axes)?;
+    rstsr_assert_eq!(lc.shape(), lm.shape(), InvalidLayout, "shape of output does not match the reduced shape")?;
+    if ls.size() == 0 {
+        return reduce_axes_to_cpu_serial(c, lc, a, la, axes, init, f, f_sum, f_out);
+    }
+
+    // summed axes are used together with remaining axes, where offset of layout may double-counted.
+    let offset = la.offset();
+
+    // iterate output and remaining axes in the same order, which is efficient for output
+    let layouts = translate_to_col_major(&[lc, &lm], TensorIterOrder::K)?;
+    let it_c = IterLayoutColMajor::new(&layouts[0])?;
+    let it_m = IterLayoutColMajor::new(&layouts[1])?;
+
+    // summed axes: contiguous part by unrolled_reduce, broadcast part by duplication
+    let (_as1, as0, asc, asd) = get_axes_composition(&ls);
+    let size_s0 = as0.iter().map(|&i| ls.shape()[i]).product::<usize>();
+    let size_sc = asc.iter().map(|&i| ls.shape()[i]).product::<usize>();
+    let asd = asd.iter().map(|&i| i as isize).collect_vec();
+    let (lsd, _) = ls.dim_split_axes(&asd)?;
+    let it_sd = IterLayoutColMajor::new(&lsd)?;
+
+    let ptr_c = AtomicPtr::new(c.as_mut_ptr());
+    let task = || {
+        it_c.into_par_iter().zip(it_m).for_each(|(i_c, i_m)| {
+            let mut acc = it_sd.clone().fold(init(), |acc, i_sd| {
+                let idx_in = i_m + i_sd - offset; // double-counted offset
+                f_sum(acc, unrolled_reduce(&a[idx_in..idx_in + size_sc], &init, &f, &f_sum))
+            });
+            let acc_before = acc.clone();
+            for _ in 1..size_s0 {
+                acc = f_sum(acc, acc_before.clone());
+            }
+            // Safety: output is not broadcasted, so each element is written by only one thread
+            unsafe { *ptr_c.load(Ordering::Relaxed).add(i_c) = f_out(acc) };
+        });
+    };
+    match pool {
+        None => task(),
+        Some(pool) => pool.install(task),
+    };
+    Ok(())
+}
+
 /* #endregion */
 
 /* #region reduce_binary */
@@ -0,0 +1,94 @@
+//! Numerically stable fused reductions for CPU with rayon parallelization.
+//!
+//! Accumulator functions are shared with the serial implementation; see
+//! [`crate::cpu_serial::reduction_stable`] for details.
+
+use crate::prelude_dev::*;
+use num::complex::ComplexFloat;
+use num::Float;
+
+/* #region logsumexp and norm */
+
+pub fn logsumexp_all_cpu_rayon<T, D>(a: &[T], la: &Layout<D>, pool: Option<&ThreadPool>) -> Result<T>
+where
+    T: Float + Send + Sync,
+    D: DimAPI,
+{
+    reduce_all_cpu_rayon(a, la, logsumexp_init, logsumexp_acc, logsumexp_sum, logsumexp_out, pool)
+}
+
+pub fn logsumexp_axes_cpu_rayon<T>(
+    a: &[T],
+    la: &Layout<IxD>,
+    axes: &[isize],
+    pool: Option<&ThreadPool>,
+) -> Result<(Vec<T>, Layout<IxD>)>
+where
+    T: Float + Send + Sync,
+{
+    reduce_axes_cpu_rayon(a, la, axes, logsumexp_init, logsumexp_acc, logsumexp_sum, logsumexp_out, pool)
+}
+
+pub fn norm_all_cpu_rayon<T, D>(a: &[T], la: &Layout<D>, pool: Option<&ThreadPool>) -> Result<T::Real>
+where
+    T: ComplexFloat + Send + Sync,
+    T::Real: Send + Sync,
+    D: DimAPI,
+{
+    reduce_all_cpu_rayon(a, la, nrm2_init, nrm2_acc, nrm2_sum, nrm2_out, pool)
+}
+
+pub fn norm_axes_cpu_rayon<T>(
+    a: &[T],
+    la: &Layout<IxD>,
+    axes: &[isize],
+    pool: Option<&ThreadPool>,
+) -> Result<(Vec<T::Real>, Layout<IxD>)>
+where
+    T: ComplexFloat + Send + Sync,
+    T::Real: Send + Sync,
+{
+    reduce_axes_cpu_rayon(a, la, axes, nrm2_init, nrm2_acc, nrm2_sum, nrm2_out, pool)
+}
+
+/* #endregion */
+
+/* #region softmax */
+
+/// Softmax (or log-softmax if `log` is true) over `axes`.
+///
+/// This evaluates logsumexp over `axes` once, then writes `exp(x - lse)` (or `x - lse`) to output.
+pub fn softmax_cpu_rayon<T, D>(
+    c: &mut [MaybeUninit<T>],
+    lc: &Layout<D>,
+    a: &[T],
+    la: &Layout<D>,
+    axes: &[isize],
+    log: bool,
+    pool: Option<&ThreadPool>,
+) -> Result<()>
+where
+    T: Float + Send + Sync,
+    D: DimAPI,
+{
+    let la = la.to_dim::<IxD>()?;
+    let lc = lc.to_dim::<IxD>()?;
+    let (lse, lo) = logsumexp_axes_cpu_rayon(a, &la, axes, pool)?;
+    let lb = layout_broadcast_reduced(&la, axes, &lo)?;
+    match log {
+        true => {
+            let mut f = |c: &mut MaybeUninit<T>, &a: &T, &b: &T| {
+                c.write(a - b);
+            };
+            op_mutc_refa_refb_func_cpu_rayon(c, &lc, a, &la, &lse, &lb, &mut f, pool)
+        },
+        false => {
+            let mut f = |c: &mut MaybeUninit<T>, &a: &T, &b: &T| {
+                c.write((a - b).exp());
+            };
+            op_mutc_refa_refb_func_cpu_rayon(c, &lc, a, &la, &lse, &lb, &mut f, pool)
+        },
+    }
+}
+
+/* #endregion */
@@ -0,0 +1,1156 @@
+//! Dense Schur decompositions in pure Rust.
+//!
+//! These kernels back devices without LAPACK (the reference BLAS device, and
+//! faer, which does not provide real Schur forms). Matrices are col-major
+//! with leading dimension equal to the number of rows.
+//!
+//! Hessenberg reduction follows the unblocked LAPACK algorithm (`xGEHD2`), so
+//! that the reflectors are stored the same way. Schur (`xGEES`) and
+//! generalized Schur (`xGGES`) forms are computed by Hessenberg(-triangular)
+//! reduction followed by shifted QR (QZ) sweeps with Givens rotations:
+//! single-shift for complex types, and Francis double-shift for real types,
+//! where 2x2 diagonal blocks are standardized as in LAPACK. Eigenvalues are
+//! not sorted.
+//!
+//! Reordering (`xTRSEN`) swaps adjacent diagonal blocks by orthogonal
+//! similarity (`xLAEXC`), and the quasi-triangular Sylvester equation
+//! (`xTRSYL`) is solved block by block; both rely on the Kronecker-form
+//! solver of small Sylvester equations. Condition numbers are not computed.
+//!
+//! QR factorization (`xGEQRF`, `xORGQR`) shares the elementary reflectors of
+//! Hessenberg reduction, and is also provided here.
+
+use crate::prelude_dev::*;
+use core::ops::{Div, Index, IndexMut, Mul};
+use num::complex::ComplexFloat;
+use num::traits::{Float, NumCast};
+use num::{One, Zero};
+
+/// Real or complex float type of Schur kernels.
+pub trait SchurFloat: ComplexFloat + Mul<Self::Real, Output = Self> + Div<Self::Real, Output = Self> {}
+
+impl<T> SchurFloat for T where T: ComplexFloat + Mul<T::Real, Output = T> + Div<T::Real, Output = T> {}
+
+/// Maximum number of QR (QZ) sweeps for each eigenvalue.
+const SCHUR_MAX_SWEEPS: usize = 30;
+
+/// 2x2 unitary transformation.
+type Rot<T> = [[T; 2]; 2];
+
+#[inline]
+fn is_complex<T: SchurFloat>() -> bool {
+    core::mem::size_of::<T>() != core::mem::size_of::<T::Real>()
+}
+
+#[inline]
+fn rcast<R: Float>(x: f64) -> R {
+    <R as NumCast>::from(x).unwrap()
+}
+
+/// Promote real value to (possibly complex) scalar.
+#[inline]
+fn real<T: SchurFloat>(x: T::Real) -> T {
+    T::one() * x
+}
+
+#[inline]
+fn norm_sqr<T: SchurFloat>(x: T) -> T::Real {
+    let (re, im) = (x.re(), x.im());
+    re * re + im * im
+}
+
+/* #region col-major work matrix */
+
+struct Mat<T> {
+    data: Vec<T>,
+    nrow: usize,
+    ncol: usize,
+}
+
+impl<T> Index<(usize, usize)> for Mat<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i + j * self.nrow]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Mat<T> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i + j * self.nrow]
+    }
+}
+
+impl<T> Mat<T>
+where
+    T: SchurFloat,
+{
+    fn new(a: &[T], nrow: usize, ncol: usize) -> Self {
+        Self { data: a[..nrow * ncol].to_vec(), nrow, ncol }
+    }
+
+    fn zeros(nrow: usize, ncol: usize) -> Self {
+        Self { data: vec![T::zero(); nrow * ncol], nrow, ncol }
+    }
+
+    fn eye(n: usize) -> Self {
+        let mut mat = Self::zeros(n, n);
+        (0..n).for_each(|i| mat[(i, i)] = T::one());
+        mat
+    }
+
+    fn adjoint(&self) -> Self {
+        let mut mat = Self::zeros(self.ncol, self.nrow);
+        for j in 0..self.ncol {
+            for i in 0..self.nrow {
+                mat[(j, i)] = self[(i, j)].conj();
+            }
+        }
+        mat
+    }
+}
+
+/* #endregion */
+
+fn frob<T: SchurFloat>(a: &Mat<T>) -> T::Real {
+    Float::sqrt(a.data.iter().fold(T::Real::zero(), |acc, &x| acc + norm_sqr(x)))
+}
+
+/* #region elementary transforms */
+
+/// Givens rotation `G = [[c, s], [-s^*, c]]` with real `c`, such that
+/// `G [x; y] = [r; 0]` (`xLARTG`).
+fn lartg<T: SchurFloat>(x: T, y: T) -> (Rot<T>, T) {
+    let (ax, ay) = (x.abs(), y.abs());
+    if ay == T::Real::zero() {
+        return ([[T::one(), T::zero()], [T::zero(), T::one()]], x);
+    }
+    if ax == T::Real::zero() {
+        let s = y.conj() / ay;
+        return ([[T::zero(), s], [-s.conj(), T::zero()]], real(ay));
+    }
+    let norm = Float::hypot(ax, ay);
+    let phase = x / ax;
+    let c: T = real(ax / norm);
+    let s = phase * y.conj() / norm;
+    ([[c, s], [-s.conj(), c]], phase * norm)
+}
+
+fn adjoint2<T: SchurFloat>(g: &Rot<T>) -> Rot<T> {
+    [[g[0][0].conj(), g[1][0].conj()], [g[0][1].conj(), g[1][1].conj()]]
+}
+
+/// Rows `p` and `q` of `a` are replaced by `g [a_p; a_q]`.
+fn rows2<T: SchurFloat>(a: &mut Mat<T>, p: usize, q: usize, g: &Rot<T>) {
+    for c in 0..a.ncol {
+        let (xp, xq) = (a[(p, c)], a[(q, c)]);
+        a[(p, c)] = g[0][0] * xp + g[0][1] * xq;
+        a[(q, c)] = g[1][0] * xp + g[1][1] * xq;
+    }
+}
+
+/// Columns `p` and `q` of `a` are replaced by `[a_p, a_q] g`.
+fn cols2<T: SchurFloat>(a: &mut Mat<T>, p: usize, q: usize, g: &Rot<T>) {
+    for r in 0..a.nrow {
+        let (xp, xq) = (a[(r, p)], a[(r, q)]);
+        a[(r, p)] = xp * g[0][0] + xq * g[1][0];
+        a[(r, q)] = xp * g[0][1] + xq * g[1][1];
+    }
+}
+
+/// Elementary reflector `H = I - tau v v^H` with `H^H x = beta e1` (`xLARFG`).
+///
+/// On exit, `x[0]` is `beta` and `x[1..]` is `v[1..]` (`v[0] = 1`).
+fn larfg<T: SchurFloat>(x: &mut [T]) -> T {
+    let alpha = x[0];
+    let xnorm = Float::sqrt(x[1..].iter().fold(T::Real::zero(), |acc, &xi| acc + norm_sqr(xi)));
+    if xnorm == T::Real::zero() && alpha.im() == T::Real::zero() {
+        return T::zero();
+    }
+    let beta = -Float::hypot(alpha.abs(), xnorm) * Float::signum(alpha.re());
+    let tau = (real::<T>(beta) - alpha) / beta;
+    let scal = T::one() / (alpha - real::<T>(beta));
+    x[1..].iter_mut().for_each(|xi| *xi = *xi * scal);
+    x[0] = real(beta);
+    tau
+}
+
+/// `a[r0.., cols] = (I - tau v v^H) a[r0.., cols]`.
+fn apply_left<T: SchurFloat>(a: &mut Mat<T>, v: &[T], tau: T, r0: usize, cols: core::ops::Range<usize>) {
+    for c in cols {
+        let w = v.iter().enumerate().fold(T::zero(), |acc, (j, &vj)| acc + vj.conj() * a[(r0 + j, c)]);
+        v.iter().enumerate().for_each(|(j, &vj)| a[(r0 + j, c)] = a[(r0 + j, c)] - tau * vj * w);
+    }
+}
+
+/// `a[rows, c0..] = a[rows, c0..] (I - tau v v^H)`.
+fn apply_right<T: SchurFloat>(a: &mut Mat<T>, v: &[T], tau: T, c0: usize, rows: core::ops::Range<usize>) {
+    for r in rows {
+        let w = v.iter().enumerate().fold(T::zero(), |acc, (j, &vj)| acc + a[(r, c0 + j)] * vj);
+        v.iter().enumerate().for_each(|(j, &vj)| a[(r, c0 + j)] = a[(r, c0 + j)] - tau * w * vj.conj());
+    }
+}
+
+/// Rows `k..k+nb` of `a` are replaced by `g^H a[k..k+nb, :]`.
+fn apply_small_left<T: SchurFloat>(a: &mut Mat<T>, k: usize, g: &Mat<T>) {
+    let gh = g.adjoint();
+    for c in 0..a.ncol {
+        let x: Vec<T> = (0..g.nrow).map(|i| a[(k + i, c)]).collect();
+        (0..g.nrow).for_each(|i| a[(k + i, c)] = (0..g.nrow).fold(T::zero(), |acc, l| acc + gh[(i, l)] * x[l]));
+    }
+}
+
+/// Columns `k..k+nb` of `a` are replaced by `a[:, k..k+nb] g`.
+fn apply_small_right<T: SchurFloat>(a: &mut Mat<T>, k: usize, g: &Mat<T>) {
+    for r in 0..a.nrow {
+        let x: Vec<T> = (0..g.nrow).map(|j| a[(r, k + j)]).collect();
+        (0..g.nrow).for_each(|j| a[(r, k + j)] = (0..g.nrow).fold(T::zero(), |acc, l| acc + x[l] * g[(l, j)]));
+    }
+}
+
+/* #endregion */
+
+/* #region Hessenberg */
+
+/// Hessenberg reduction (`xGEHD2`) of rows and columns `lo..hi`.
+///
+/// Reflector `H(k)` is stored below the subdiagonal of column `k`.
+fn gehd2<T: SchurFloat>(a: &mut Mat<T>, lo: usize, hi: usize, tau: &mut [T]) {
+    let n = a.nrow;
+    tau.iter_mut().for_each(|t| *t = T::zero());
+    for k in lo..hi.saturating_sub(1) {
+        let mut v: Vec<T> = (k + 1..hi).map(|i| a[(i, k)]).collect();
+        let t = larfg(&mut v);
+        let beta = v[0];
+        v[0] = T::one();
+        apply_right(a, &v, t, k + 1, 0..hi);
+        apply_left(a, &v, t.conj(), k + 1, k + 1..n);
+        a[(k + 1, k)] = beta;
+        (1..v.len()).for_each(|j| a[(k + 1 + j, k)] = v[j]);
+        tau[k] = t;
+    }
+}
+
+/// Unitary matrix `Q = H(lo) H(lo+1) ... H(hi-2)` from reflectors of
+/// [`gehd2`] (`xORGHR`).
+fn orghr<T: SchurFloat>(a: &Mat<T>, lo: usize, hi: usize, tau: &[T]) -> Mat<T> {
+    let n = a.nrow;
+    let mut q = Mat::eye(n);
+    for k in (lo..hi.saturating_sub(1)).rev() {
+        let v: Vec<T> = (k + 1..hi).map(|i| if i == k + 1 { T::one() } else { a[(i, k)] }).collect();
+        apply_left(&mut q, &v, tau[k], k + 1, 0..n);
+    }
+    q
+}
+
+/// Hessenberg reduction (`xGEHRD`) of rows and columns `lo..hi` of
+/// col-major `a` of order `n`.
+///
+/// `tau` should have at least `n - 1` elements.
+pub fn gehrd_cpu_serial<T>(a: &mut [T], n: usize, lo: usize, hi: usize, tau: &mut [T])
+where
+    T: SchurFloat,
+{
+    let mut h = Mat::new(a, n, n);
+    gehd2(&mut h, lo, hi, tau);
+    a[..n * n].copy_from_slice(&h.data);
+}
+
+/// Unitary matrix of Hessenberg reduction (`xORGHR`); `a` is overwritten
+/// by `Q`.
+pub fn orghr_cpu_serial<T>(a: &mut [T], n: usize, lo: usize, hi: usize, tau: &[T])
+where
+    T: SchurFloat,
+{
+    let q = orghr(&Mat::new(a, n, n), lo, hi, tau);
+    a[..n * n].copy_from_slice(&q.data);
+}
+
+/* #endregion */
+
+/* #region QR */
+
+/// QR factorization (`xGEQR2`).
+///
+/// Reflector `H(k)` is stored below the diagonal of column `k`. Columns that
+/// vanish give `tau = 0` (`H(k) = I`), so rank-deficient `a` is fine.
+fn geqr2<T: SchurFloat>(a: &mut Mat<T>, tau: &mut [T]) {
+    let (m, n) = (a.nrow, a.ncol);
+    for k in 0..m.min(n) {
+        let mut v: Vec<T> = (k..m).map(|i| a[(i, k)]).collect();
+        let t = larfg(&mut v);
+        let beta = v[0];
+        v[0] = T::one();
+        apply_left(a, &v, t.conj(), k, k + 1..n);
+        a[(k, k)] = beta;
+        (1..v.len()).for_each(|j| a[(k + j, k)] = v[j]);
+        tau[k] = t;
+    }
+}
+
+/// First `n` columns of `Q = H(0) H(1) ... H(k-1)` from reflectors of
+/// [`geqr2`] (`xORG2R`).
+fn org2r<T: SchurFloat>(a: &Mat<T>, k: usize, tau: &[T]) -> Mat<T> {
+    let (m, n) = (a.nrow, a.ncol);
+    let mut q = Mat::zeros(m, n);
+    (0..n).for_each(|i| q[(i, i)] = T::one());
+    for i in (0..k).rev() {
+        let v: Vec<T> = (i..m).map(|r| if r == i { T::one() } else { a[(r, i)] }).collect();
+        apply_left(&mut q, &v, tau[i], i, 0..n);
+    }
+    q
+}
+
+/// QR factorization (`xGEQRF`) of col-major `a` of shape `(m, n)`.
+///
+/// `tau` should have at least `min(m, n)` elements.
+pub fn geqrf_cpu_serial<T>(a: &mut [T], m: usize, n: usize, tau: &mut [T])
+where
+    T: SchurFloat,
+{
+    let mut r = Mat::new(a, m, n);
+    geqr2(&mut r, tau);
+    a[..m * n].copy_from_slice(&r.data);
+}
+
+/// Unitary factor of QR factorization (`xORGQR`) from the first `k`
+/// reflectors; col-major `a` of shape `(m, n)`, `m >= n >= k`, is
+/// overwritten by the first `n` columns of `Q`.
+pub fn orgqr_cpu_serial<T>(a: &mut [T], m: usize, n: usize, k: usize, tau: &[T])
+where
+    T: SchurFloat,
+{
+    let q = org2r(&Mat::new(a, m, n), k, tau);
+    a[..m * n].copy_from_slice(&q.data);
+}
+
+/* #endregion */
+
+/* #region Schur */
+
+/// Start of the active block ending at `hi - 1`: negligible subdiagonal
+/// elements above are set to zero.
+fn split_point<T: SchurFloat>(h: &mut Mat<T>, hi: usize, hnorm: T::Real) -> usize {
+    let eps = <T::Real as Float>::epsilon();
+    let mut l = hi - 1;
+    while l > 0 {
+        let s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+        let s = if s == T::Real::zero() { hnorm } else { s };
+        if h[(l, l - 1)].abs() <= eps * s {
+            h[(l, l - 1)] = T::zero();
+            break;
+        }
+        l -= 1;
+    }
+    l
+}
+
+/// Eigenvalue of `[[a, b], [c, d]]` closer to `d`.
+fn wilkinson<T: SchurFloat>(a: T, b: T, c: T, d: T) -> T {
+    let half: T = real(rcast(0.5));
+    let m = (a - d) * half;
+    let disc = (m * m + b * c).sqrt();
+    let (l1, l2) = (d + m + disc, d + m - disc);
+    if (l1 - d).abs() <= (l2 - d).abs() {
+        l1
+    } else {
+        l2
+    }
+}
+
+/// Standardize the real 2x2 diagonal block of Schur form at `k`, by
+/// similarity `h = G h G^T` (`xLANV2`).
+///
+/// Block of real eigenvalues is made upper triangular; block of complex
+/// conjugate pair is made to have equal diagonal elements.
+fn standardize_2x2<T: SchurFloat>(h: &mut Mat<T>, z: &mut Mat<T>, k: usize) {
+    let (a, b, c, d) = (h[(k, k)].re(), h[(k, k + 1)].re(), h[(k + 1, k)].re(), h[(k + 1, k + 1)].re());
+    if c == T::Real::zero() {
+        return;
+    }
+    let half = rcast::<T::Real>(0.5);
+    let p = (a - d) * half;
+    let disc = p * p + b * c;
+    let g = if disc >= T::Real::zero() {
+        // first column of G^T is eigenvector
+        let lam = (a + d) * half + Float::signum(p) * Float::sqrt(disc);
+        let (v0, v1) = if Float::abs(b) + Float::abs(lam - a) >= Float::abs(lam - d) + Float::abs(c) {
+            (b, lam - a)
+        } else {
+            (lam - d, c)
+        };
+        lartg::<T>(real(v0), real(v1)).0
+    } else {
+        // rotation that equalizes diagonal elements
+        let theta = Float::atan2(-(a - d), b + c) * half;
+        let (cs, sn): (T, T) = (real(Float::cos(theta)), real(Float::sin(theta)));
+        [[cs, sn], [-sn, cs]]
+    };
+    rows2(h, k, k + 1, &g);
+    let gh = adjoint2(&g);
+    cols2(h, k, k + 1, &gh);
+    cols2(z, k, k + 1, &gh);
+    if disc >= T::Real::zero() {
+        h[(k + 1, k)] = T::zero();
+    } else {
+        let avg = (h[(k, k)] + h[(k + 1, k + 1)]) * half;
+        h[(k, k)] = avg;
+        h[(k + 1, k + 1)] = avg;
+    }
+}
+
+/// Single-shift QR iteration on upper Hessenberg `h` (complex types).
+fn hqr_complex<T: SchurFloat>(h: &mut Mat<T>, z: &mut Mat<T>) -> usize {
+    let n = h.nrow;
+    let hnorm = frob(h);
+    let itmax = SCHUR_MAX_SWEEPS * n.max(10);
+    let (mut hi, mut iter) = (n, 0);
+    while hi > 0 {
+        let l = split_point(h, hi, hnorm);
+        if l + 1 == hi {
+            hi -= 1;
+            iter = 0;
+            continue;
+        }
+        iter += 1;
+        if iter > itmax {
+            return hi;
+        }
+        let mu = if iter % 10 == 0 {
+            h[(hi - 1, hi - 1)] + real::<T>(h[(hi - 1, hi - 2)].abs() * rcast(0.75))
+        } else {
+            wilkinson(h[(hi - 2, hi - 2)], h[(hi - 2, hi - 1)], h[(hi - 1, hi - 2)], h[(hi - 1, hi - 1)])
+        };
+        let (mut x, mut y) = (h[(l, l)] - mu, h[(l + 1, l)]);
+        for k in l..hi - 1 {
+            if k > l {
+                (x, y) = (h[(k, k - 1)], h[(k + 1, k - 1)]);
+            }
+            let g = lartg(x, y).0;
+            let gh = adjoint2(&g);
+            rows2(h, k, k + 1, &g);
+            cols2(h, k, k + 1, &gh);
+            cols2(z, k, k + 1, &gh);
+            if k > l {
+                h[(k + 1, k - 1)] = T::zero();
+            }
+        }
+    }
+    0
+}
+
+/// Francis double-shift QR iteration on upper Hessenberg `h` (real types).
+fn hqr_real<T: SchurFloat>(h: &mut Mat<T>, z: &mut Mat<T>) -> usize {
+    let n = h.nrow;
+    let hnorm = frob(h);
+    let itmax = SCHUR_MAX_SWEEPS * n.max(10);
+    let (mut hi, mut iter) = (n, 0);
+    while hi > 0 {
+        let l = split_point(h, hi, hnorm);
+        if l + 1 == hi {
+            hi -= 1;
+            iter = 0;
+            continue;
+        }
+        if l + 2 == hi {
+            standardize_2x2(h, z, l);
+            hi -= 2;
+            iter = 0;
+            continue;
+        }
+        iter += 1;
+        if iter > itmax {
+            return hi;
+        }
+        // sum and product of shifts
+        let p = hi - 1;
+        let (s, t) = if iter % 10 == 0 {
+            let w = h[(p, p - 1)].abs() + h[(p - 1, p - 2)].abs();
+            let h11 = real::<T>(w * rcast(0.75)) + h[(p, p)];
+            let h12 = real::<T>(w * rcast(-0.4375));
+            (h11 + h11, h11 * h11 - h12 * real::<T>(w))
+        } else {
+            (h[(p - 1, p - 1)] + h[(p, p)], h[(p - 1, p - 1)] * h[(p, p)] - h[(p - 1, p)] * h[(p, p - 1)])
+        };
+        // first column of (H - a1 I)(H - a2 I)
+        let (h00, h10, h01, h11, h21) = (h[(l, l)], h[(l + 1, l)], h[(l, l + 1)], h[(l + 1, l + 1)], h[(l + 2, l + 1)]);
+        let mut x = h00 * h00 + h01 * h10 - s * h00 + t;
+        let mut y = h10 * (h00 + h11 - s);
+        let mut zz = h10 * h21;
+        for k in l..hi - 1 {
+            if k > l {
+                x = h[(k, k - 1)];
+                y = h[(k + 1, k - 1)];
+                zz = if k + 2 < hi { h[(k + 2, k - 1)] } else { T::zero() };
+            }
+            if k + 2 < hi {
+                let g = lartg(y, zz).0;
+                let gh = adjoint2(&g);
+                rows2(h, k + 1, k + 2, &g);
+                cols2(h, k + 1, k + 2, &gh);
+                cols2(z, k + 1, k + 2, &gh);
+                y = g[0][0] * y + g[0][1] * zz;
+                if k > l {
+                    h[(k + 2, k - 1)] = T::zero();
+                }
+            }
+            let g = lartg(x, y).0;
+            let gh = adjoint2(&g);
+            rows2(h, k, k + 1, &g);
+            cols2(h, k, k + 1, &gh);
+            cols2(z, k, k + 1, &gh);
+            if k > l {
+                h[(k + 1, k - 1)] = T::zero();
+            }
+        }
+    }
+    0
+}
+
+/// Schur form `h = Z^H a Z` of general matrix. On exit `a` is overwritten by
+/// Schur form `T`.
+fn schur<T: SchurFloat>(a: &mut Mat<T>) -> (Mat<T>, usize) {
+    let n = a.nrow;
+    let mut tau = vec![T::zero(); n.saturating_sub(1)];
+    gehd2(a, 0, n, &mut tau);
+    let mut z = orghr(a, 0, n, &tau);
+    for j in 0..n {
+        (j + 2..n).for_each(|i| a[(i, j)] = T::zero());
+    }
+    let info = if is_complex::<T>() { hqr_complex(a, &mut z) } else { hqr_real(a, &mut z) };
+    (z, info)
+}
+
+/// Whether a real 2x2 diagonal block starts at `k` of quasi-triangular `t`.
+#[inline]
+fn is_block2<T: SchurFloat>(t: &Mat<T>, k: usize) -> bool {
+    !is_complex::<T>() && k + 1 < t.nrow && t[(k + 1, k)] != T::zero()
+}
+
+/// Schur decomposition `A = Z T Z^H` (`xGEES`) of col-major `a` of order
+/// `n`.
+///
+/// On exit `a` is overwritten by `T`. Returns `Z` (col-major) and info,
+/// where nonzero info is the order of the unconverged leading block.
+pub fn gees_cpu_serial<T>(a: &mut [T], n: usize) -> (Vec<T>, usize)
+where
+    T: SchurFloat,
+{
+    let mut t = Mat::new(a, n, n);
+    let (z, info) = schur(&mut t);
+    a[..n * n].copy_from_slice(&t.data);
+    (z.data, info)
+}
+
+/// Eigenvalues (real and imaginary parts) of col-major (quasi-)triangular
+/// Schur form `t` of order `n`.
+pub fn schur_eigvals_cpu_serial<T>(t: &[T], n: usize, wr: &mut [T::Real], wi: &mut [T::Real])
+where
+    T: SchurFloat,
+{
+    let t = Mat::new(t, n, n);
+    let mut k = 0;
+    while k < n {
+        if is_block2(&t, k) {
+            let p = t[(k, k)].re();
+            let q = Float::sqrt(t[(k, k + 1)].abs()) * Float::sqrt(t[(k + 1, k)].abs());
+            (wr[k], wi[k]) = (p, q);
+            (wr[k + 1], wi[k + 1]) = (p, -q);
+            k += 2;
+        } else {
+            (wr[k], wi[k]) = (t[(k, k)].re(), t[(k, k)].im());
+            k += 1;
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region generalized Schur */
+
+/// Generalized Schur (QZ) decomposition workspace: `A = Q S Z^H`,
+/// `B = Q T Z^H`, where `a` and `b` hold `S` and `T`.
+struct Pencil<T> {
+    a: Mat<T>,
+    b: Mat<T>,
+    q: Mat<T>,
+    z: Mat<T>,
+}
+
+impl<T> Pencil<T>
+where
+    T: SchurFloat,
+{
+    /// Apply `g` to rows `p`, `q` of `S` and `T`.
+    fn rot_left(&mut self, p: usize, q: usize, g: &Rot<T>) {
+        rows2(&mut self.a, p, q, g);
+        rows2(&mut self.b, p, q, g);
+        cols2(&mut self.q, p, q, &adjoint2(g));
+    }
+
+    /// Apply `g` to columns `p`, `q` of `S` and `T`.
+    fn rot_right(&mut self, p: usize, q: usize, g: &Rot<T>) {
+        cols2(&mut self.a, p, q, g);
+        cols2(&mut self.b, p, q, g);
+        cols2(&mut self.z, p, q, g);
+    }
+
+    /// Annihilate `T[i, j]` (`j < i`) by rotation of columns `j` and `i`.
+    fn zero_b(&mut self, i: usize, j: usize) {
+        let g = lartg(self.b[(i, i)], self.b[(i, j)]).0;
+        self.rot_right(j, i, &g);
+        self.b[(i, j)] = T::zero();
+    }
+
+    /// Reduce to Hessenberg-triangular form (`xGGHRD`, preceded by QR of `B`).
+    fn hessenberg_triangular(&mut self) {
+        let n = self.a.nrow;
+        for k in 0..n.saturating_sub(1) {
+            let mut v: Vec<T> = (k..n).map(|i| self.b[(i, k)]).collect();
+            let tau = larfg(&mut v);
+            let beta = v[0];
+            v[0] = T::one();
+            apply_left(&mut self.b, &v, tau.conj(), k, k + 1..n);
+            apply_left(&mut self.a, &v, tau.conj(), k, 0..n);
+            apply_right(&mut self.q, &v, tau, k, 0..n);
+            self.b[(k, k)] = beta;
+            (k + 1..n).for_each(|i| self.b[(i, k)] = T::zero());
+        }
+        for j in 0..n.saturating_sub(2) {
+            for i in (j + 2..n).rev() {
+                let g = lartg(self.a[(i - 1, j)], self.a[(i, j)]).0;
+                self.rot_left(i - 1, i, &g);
+                self.a[(i, j)] = T::zero();
+                self.zero_b(i, i - 1);
+            }
+        }
+    }
+
+    /// Deflate infinite eigenvalue at `last` when `T[last, last]` is zero.
+    fn deflate_last(&mut self, last: usize) {
+        let g = lartg(self.a[(last, last)], self.a[(last, last - 1)]).0;
+        self.rot_right(last - 1, last, &g);
+        self.a[(last, last - 1)] = T::zero();
+    }
+
+    /// Chase zero `T[j, j]` down to `T[last, last]`.
+    fn chase_zero(&mut self, j: usize, last: usize) {
+        for k in j..last {
+            let g = lartg(self.b[(k, k + 1)], self.b[(k + 1, k + 1)]).0;
+            self.rot_left(k, k + 1, &g);
+            self.b[(k + 1, k + 1)] = T::zero();
+            if k > 0 {
+                let g = lartg(self.a[(k + 1, k)], self.a[(k + 1, k - 1)]).0;
+                self.rot_right(k - 1, k, &g);
+                self.a[(k + 1, k - 1)] = T::zero();
+            }
+        }
+    }
+
+    /// Trailing 2x2 block of `S T^{-1}` restricted to rows and columns
+    /// `k..k+2`.
+    fn ratio_2x2(&self, k: usize) -> [[T; 2]; 2] {
+        let (a, b) = (&self.a, &self.b);
+        let b00 = T::one() / b[(k, k)];
+        let b11 = T::one() / b[(k + 1, k + 1)];
+        let b01 = -b[(k, k + 1)] * b00 * b11;
+        [[a[(k, k)] * b00, a[(k, k)] * b01 + a[(k, k + 1)] * b11], [
+            a[(k + 1, k)] * b00,
+            a[(k + 1, k)] * b01 + a[(k + 1, k + 1)] * b11,
+        ]]
+    }
+
+    /// Single-shift QZ sweep on active block `l..hi` (complex types).
+    fn sweep_complex(&mut self, l: usize, hi: usize, exceptional: bool) {
+        let m = self.ratio_2x2(hi - 2);
+        let mu = if exceptional {
+            m[1][1] + real::<T>(m[1][0].abs() * rcast(0.75))
+        } else {
+            wilkinson(m[0][0], m[0][1], m[1][0], m[1][1])
+        };
+        let (mut x, mut y) = (self.a[(l, l)] - mu * self.b[(l, l)], self.a[(l + 1, l)]);
+        for k in l..hi - 1 {
+            if k > l {
+                (x, y) = (self.a[(k, k - 1)], self.a[(k + 1, k - 1)]);
+            }
+            let g = lartg(x, y).0;
+            self.rot_left(k, k + 1, &g);
+            if k > l {
+                self.a[(k + 1, k - 1)] = T::zero();
+            }
+            self.zero_b(k + 1, k);
+        }
+    }
+
+    /// Double-shift QZ sweep on active block `l..hi` (real types).
+    fn sweep_real(&mut self, l: usize, hi: usize, exceptional: bool) {
+        let m = self.ratio_2x2(hi - 2);
+        let (s, t) = if exceptional {
+            let w = m[1][0].abs();
+            let h11 = real::<T>(w * rcast(0.75)) + m[1][1];
+            let h12 = real::<T>(w * rcast(-0.4375));
+            (h11 + h11, h11 * h11 - h12 * real::<T>(w))
+        } else {
+            (m[0][0] + m[1][1], m[0][0] * m[1][1] - m[0][1] * m[1][0])
+        };
+        // first column of (S T^{-1} - a1 I)(S T^{-1} - a2 I)
+        let m = self.ratio_2x2(l);
+        let m21 = self.a[(l + 2, l + 1)] / self.b[(l + 1, l + 1)];
+        let mut x = m[0][0] * m[0][0] + m[0][1] * m[1][0] - s * m[0][0] + t;
+        let mut y = m[1][0] * (m[0][0] + m[1][1] - s);
+        let mut zz = m[1][0] * m21;
+        for k in l..hi - 1 {
+            if k > l {
+                x = self.a[(k, k - 1)];
+                y = self.a[(k + 1, k - 1)];
+                zz = if k + 2 < hi { self.a[(k + 2, k - 1)] } else { T::zero() };
+            }
+            if k + 2 < hi {
+                let g = lartg(y, zz).0;
+                self.rot_left(k + 1, k + 2, &g);
+                y = g[0][0] * y + g[0][1] * zz;
+                if k > l {
+                    self.a[(k + 2, k - 1)] = T::zero();
+                }
+            }
+            let g = lartg(x, y).0;
+            self.rot_left(k, k + 1, &g);
+            if k > l {
+                self.a[(k + 1, k - 1)] = T::zero();
+            }
+            // restore triangular T
+            for i in (k + 1..(k + 3).min(hi)).rev() {
+                (k..i).for_each(|j| self.zero_b(i, j));
+            }
+        }
+    }
+
+    /// Standardize the real 2x2 diagonal block at `k` (`xLAGV2`).
+    ///
+    /// Block of real eigenvalues is made upper triangular; for complex
+    /// conjugate pair, `T` block is made diagonal with positive elements.
+    fn standardize_2x2(&mut self, k: usize) {
+        let m = self.ratio_2x2(k);
+        let half = rcast::<T::Real>(0.5);
+        let p = (m[0][0] - m[1][1]).re() * half;
+        let disc = p * p + (m[0][1] * m[1][0]).re();
+        if disc >= T::Real::zero() {
+            // right vector of `S - lam T` in the first column of Z
+            let lam: T = real((m[0][0] + m[1][1]).re() * half + Float::signum(p) * Float::sqrt(disc));
+            let n00 = self.a[(k, k)] - lam * self.b[(k, k)];
+            let n01 = self.a[(k, k + 1)] - lam * self.b[(k, k + 1)];
+            let n10 = self.a[(k + 1, k)];
+            let n11 = self.a[(k + 1, k + 1)] - lam * self.b[(k + 1, k + 1)];
+            let (v0, v1) = if n00.abs() + n01.abs() >= n10.abs() + n11.abs() { (-n01, n00) } else { (-n11, n10) };
+            let g = lartg(v0, v1).0;
+            self.rot_right(k, k + 1, &adjoint2(&g));
+            // S e1 and T e1 are parallel, rotate the larger one
+            let (w0, w1) =
+                if self.b[(k, k)].abs() + self.b[(k + 1, k)].abs() >= self.a[(k, k)].abs() + self.a[(k + 1, k)].abs() {
+                    (self.b[(k, k)], self.b[(k + 1, k)])
+                } else {
+                    (self.a[(k, k)], self.a[(k + 1, k)])
+                };
+            let g = lartg(w0, w1).0;
+            self.rot_left(k, k + 1, &g);
+            self.a[(k + 1, k)] = T::zero();
+            self.b[(k + 1, k)] = T::zero();
+        } else {
+            // SVD of T block by one Jacobi rotation
+            let (b00, b01, b11) = (self.b[(k, k)].re(), self.b[(k, k + 1)].re(), self.b[(k + 1, k + 1)].re());
+            let alpha = b00 * b00;
+            let beta = b01 * b01 + b11 * b11;
+            let gamma = b00 * b01;
+            let (c, s) = if gamma == T::Real::zero() {
+                (T::Real::one(), T::Real::zero())
+            } else {
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = Float::signum(zeta) / (Float::abs(zeta) + Float::sqrt(T::Real::one() + zeta * zeta));
+                let c = T::Real::one() / Float::sqrt(T::Real::one() + t * t);
+                (c, c * t)
+            };
+            let v: Rot<T> = [[real(c), real(s)], [real(-s), real(c)]];
+            self.rot_right(k, k + 1, &v);
+            // columns of T block are orthogonal now; normalize them to get U
+            let (u00, u10, u01, u11) = (self.b[(k, k)], self.b[(k + 1, k)], self.b[(k, k + 1)], self.b[(k + 1, k + 1)]);
+            let s0 = Float::hypot(u00.abs(), u10.abs());
+            let s1 = Float::hypot(u01.abs(), u11.abs());
+            let ut: Rot<T> = [[u00 / s0, u10 / s0], [u01 / s1, u11 / s1]];
+            self.rot_left(k, k + 1, &ut);
+            self.b[(k, k)] = real(s0);
+            self.b[(k + 1, k + 1)] = real(s1);
+            self.b[(k, k + 1)] = T::zero();
+            self.b[(k + 1, k)] = T::zero();
+        }
+    }
+
+    /// QZ iteration on Hessenberg-triangular pencil.
+    fn qz(&mut self) -> usize {
+        let n = self.a.nrow;
+        let complex = is_complex::<T>();
+        let eps = <T::Real as Float>::epsilon();
+        let atol = eps * frob(&self.a);
+        let btol = eps * frob(&self.b);
+        let itmax = SCHUR_MAX_SWEEPS * n.max(10);
+        let (mut hi, mut iter) = (n, 0);
+        'outer: while hi > 1 {
+            let last = hi - 1;
+            if self.a[(last, last - 1)].abs() <= atol {
+                self.a[(last, last - 1)] = T::zero();
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+            if self.b[(last, last)].abs() <= btol {
+                self.b[(last, last)] = T::zero();
+                self.deflate_last(last);
+                hi -= 1;
+                iter = 0;
+                continue;
+            }
+            let mut l = 0;
+            for j in (0..last).rev() {
+                if self.b[(j, j)].abs() <= btol {
+                    self.b[(j, j)] = T::zero();
+                    self.chase_zero(j, last);
+                    self.deflate_last(last);
+                    hi -= 1;
+                    iter = 0;
+                    continue 'outer;
+                }
+                if j > 0 && self.a[(j, j - 1)].abs() <= atol {
+                    self.a[(j, j - 1)] = T::zero();
+                    l = j;
+                    break;
+                }
+            }
+            if !complex && l + 2 == hi {
+                self.standardize_2x2(l);
+                hi -= 2;
+                iter = 0;
+                continue;
+            }
+            iter += 1;
+            if iter > itmax {
+                return hi;
+            }
+            match complex {
+                true => self.sweep_complex(l, hi, iter % 10 == 0),
+                false => self.sweep_real(l, hi, iter % 10 == 0),
+            }
+        }
+        // make diagonal of T real and nonnegative
+        let mut k = 0;
+        while k < n {
+            if is_block2(&self.a, k) {
+                k += 2;
+                continue;
+            }
+            let d = self.b[(k, k)];
+            let ad = d.abs();
+            if ad != T::Real::zero() && (complex || d.re() < T::Real::zero()) {
+                let phase = (d / ad).conj();
+                (0..n).for_each(|i| self.a[(i, k)] = self.a[(i, k)] * phase);
+                (0..n).for_each(|i| self.b[(i, k)] = self.b[(i, k)] * phase);
+                (0..n).for_each(|i| self.z[(i, k)] = self.z[(i, k)] * phase);
+                self.b[(k, k)] = real(ad);
+            }
+            k += 1;
+        }
+        0
+    }
+}
+
+/// Generalized Schur decomposition `A = Q S Z^H`, `B = Q T Z^H` (`xGGES`)
+/// of col-major `a` and `b` of order `n`.
+///
+/// On exit `a` and `b` are overwritten by `S` and `T`, and generalized
+/// eigenvalues are `(alphar + i alphai) / beta`. Returns `Q`, `Z` (col-major)
+/// and info, where nonzero info is the order of the unconverged leading
+/// block.
+pub fn gges_cpu_serial<T>(
+    a: &mut [T],
+    b: &mut [T],
+    n: usize,
+    alphar: &mut [T::Real],
+    alphai: &mut [T::Real],
+    beta: &mut [T],
+) -> (Vec<T>, Vec<T>, usize)
+where
+    T: SchurFloat,
+{
+    let mut pencil = Pencil { a: Mat::new(a, n, n), b: Mat::new(b, n, n), q: Mat::eye(n), z: Mat::eye(n) };
+    pencil.hessenberg_triangular();
+    let info = pencil.qz();
+    let Pencil { a: s, b: t, q, z } = pencil;
+    if info != 0 {
+        return (q.data, z.data, info);
+    }
+    let mut k = 0;
+    while k < n {
+        if is_block2(&s, k) {
+            // T block is diagonal and positive
+            let (t0, t1) = (t[(k, k)].re(), t[(k + 1, k + 1)].re());
+            let (m00, m01, m10, m11) =
+                (s[(k, k)].re() / t0, s[(k, k + 1)].re() / t0, s[(k + 1, k)].re() / t1, s[(k + 1, k + 1)].re() / t1);
+            let half = rcast::<T::Real>(0.5);
+            let p = (m00 + m11) * half;
+            let d = (m00 - m11) * half;
+            let q = Float::sqrt(Float::abs(d * d + m01 * m10));
+            (alphar[k], alphai[k], beta[k]) = (p * t0, q * t0, real(t0));
+            (alphar[k + 1], alphai[k + 1], beta[k + 1]) = (p * t1, -q * t1, real(t1));
+            k += 2;
+        } else {
+            (alphar[k], alphai[k], beta[k]) = (s[(k, k)].re(), s[(k, k)].im(), t[(k, k)]);
+            k += 1;
+        }
+    }
+    a[..n * n].copy_from_slice(&s.data);
+    b[..n * n].copy_from_slice(&t.data);
+    (q.data, z.data, 0)
+}
+
+/* #endregion */
+
+/* #region Sylvester and reordering */
+
+/// Total order of absolute values, with NaN above every number.
+///
+/// NaN entries are chosen as pivots and propagate to the solution instead of panicking.
+fn abs_total_cmp<R: Float>(a: R, b: R) -> core::cmp::Ordering {
+    a.partial_cmp(&b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Solve `a11 X + isgn X a22 = c` for blocks of order at most 2, by
+/// Gaussian elimination on the Kronecker form (`xLASY2`).
+///
+/// Tiny pivots are perturbed; returns `false` in this case.
+fn small_sylvester<T: SchurFloat>(a11: &Mat<T>, a22: &Mat<T>, isgn: T, c: &Mat<T>) -> (Mat<T>, bool) {
+    let (p, r) = (a11.nrow, a22.nrow);
+    let nk = p * r;
+    let mut k = Mat::<T>::zeros(nk, nk);
+    for j in 0..r {
+        for i in 0..p {
+            (0..p).for_each(|l| k[(i + j * p, l + j * p)] = k[(i + j * p, l + j * p)] + a11[(i, l)]);
+            (0..r).for_each(|m| k[(i + j * p, i + m * p)] = k[(i + j * p, i + m * p)] + isgn * a22[(m, j)]);
+        }
+    }
+    let mut x = c.data.clone();
+    let smin = Float::max(
+        <T::Real as Float>::epsilon() * Float::max(frob(a11), frob(a22)),
+        <T::Real as Float>::min_positive_value(),
+    );
+    let mut ok = true;
+    for col in 0..nk {
+        let piv = (col..nk).max_by(|&i, &j| abs_total_cmp(k[(i, col)].abs(), k[(j, col)].abs())).unwrap();
+        if piv != col {
+            (0..nk).for_each(|j| k.data.swap(col + j * nk, piv + j * nk));
+            x.swap(col, piv);
+        }
+        if k[(col, col)].abs() < smin {
+            k[(col, col)] = real(smin);
+            ok = false;
+        }
+        for i in col + 1..nk {
+            let f = k[(i, col)] / k[(col, col)];
+            (col..nk).for_each(|j| k[(i, j)] = k[(i, j)] - f * k[(col, j)]);
+            x[i] = x[i] - f * x[col];
+        }
+    }
+    for i in (0..nk).rev() {
+        let xi = (i + 1..nk).fold(x[i], |acc, j| acc - k[(i, j)] * x[j]);
+        x[i] = xi / k[(i, i)];
+    }
+    (Mat { data: x, nrow: p, ncol: r }, ok)
+}
+
+/// Submatrix `a[r0..r0+nrow, c0..c0+ncol]`.
+fn submat<T: SchurFloat>(a: &Mat<T>, r0: usize, c0: usize, nrow: usize, ncol: usize) -> Mat<T> {
+    let mut m = Mat::zeros(nrow, ncol);
+    for j in 0..ncol {
+        (0..nrow).for_each(|i| m[(i, j)] = a[(r0 + i, c0 + j)]);
+    }
+    m
+}
+
+/// Swap adjacent diagonal blocks of order `p` (at `k`) and `r` (at `k + p`)
+/// of Schur form `t` (`xLAEXC`/`xTREXC`), by orthogonal similarity computed
+/// from the QR factorization of `[-X; I]`, where `X` solves the Sylvester
+/// equation of the two blocks.
+fn swap_blocks<T: SchurFloat>(t: &mut Mat<T>, q: &mut Mat<T>, k: usize, p: usize, r: usize) {
+    let nb = p + r;
+    let a11 = submat(t, k, k, p, p);
+    let a22 = submat(t, k + p, k + p, r, r);
+    let a12 = submat(t, k, k + p, p, r);
+    let (x, _) = small_sylvester(&a11, &a22, -T::one(), &a12);
+    let mut w = Mat::<T>::zeros(nb, r);
+    for j in 0..r {
+        (0..p).for_each(|i| w[(i, j)] = -x[(i, j)]);
+        w[(p + j, j)] = T::one();
+    }
+    let mut g = Mat::<T>::eye(nb);
+    for c in 0..r {
+        let mut v: Vec<T> = (c..nb).map(|i| w[(i, c)]).collect();
+        let tau = larfg(&mut v);
+        v[0] = T::one();
+        apply_left(&mut w, &v, tau.conj(), c, c..r);
+        apply_right(&mut g, &v, tau, c, 0..nb);
+    }
+    apply_small_left(t, k, &g);
+    apply_small_right(t, k, &g);
+    apply_small_right(q, k, &g);
+    for j in k..k + r {
+        (k + r..k + nb).for_each(|i| t[(i, j)] = T::zero());
+    }
+    if r == 2 {
+        standardize_2x2(t, q, k);
+    }
+    if p == 2 {
+        standardize_2x2(t, q, k + r);
+    }
+}
+
+/// Size of the diagonal block starting at `k`.
+#[inline]
+fn block_size<T: SchurFloat>(t: &Mat<T>, k: usize) -> usize {
+    if is_block2(t, k) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Move the diagonal block at `from` to `to` (`to <= from`) by successive
+/// swaps with preceding blocks.
+fn move_block<T: SchurFloat>(t: &mut Mat<T>, q: &mut Mat<T>, from: usize, to: usize) {
+    let mut here = from;
+    while here > to {
+        let nprev = if here >= 2 && is_block2(t, here - 2) { 2 } else { 1 };
+        let nb = block_size(t, here);
+        swap_blocks(t, q, here - nprev, nprev, nb);
+        here -= nprev;
+    }
+}
+
+/// Reorder col-major Schur form `t` of order `n` (`xTRSEN`), so that the
+/// selected eigenvalues are in the leading block; Schur vectors `q` are
+/// updated accordingly.
+///
+/// A real 2x2 block is moved if any of its eigenvalues is selected. Returns
+/// the dimension of the leading invariant subspace.
+pub fn trsen_cpu_serial<T>(t: &mut [T], q: &mut [T], n: usize, select: &[bool]) -> usize
+where
+    T: SchurFloat,
+{
+    let mut tm = Mat::new(t, n, n);
+    let mut qm = Mat::new(q, n, n);
+    let (mut k, mut ks, mut m) = (0, 0, 0);
+    while k < n {
+        let nb = block_size(&tm, k);
+        if select[k..k + nb].iter().any(|&s| s) {
+            m += nb;
+            if k != ks {
+                move_block(&mut tm, &mut qm, k, ks);
+            }
+            ks += nb;
+        }
+        k += nb;
+    }
+    t[..n * n].copy_from_slice(&tm.data);
+    q[..n * n].copy_from_slice(&qm.data);
+    m
+}
+
+/// Partition of (quasi-)triangular matrix into diagonal blocks, as
+/// `(start, size)`.
+fn diag_blocks<T: SchurFloat>(a: &Mat<T>) -> Vec<(usize, usize)> {
+    let mut blocks = vec![];
+    let mut k = 0;
+    while k < a.nrow {
+        let nb = block_size(a, k);
+        blocks.push((k, nb));
+        k += nb;
+    }
+    blocks
+}
+
+/// `op(a)` of square matrix.
+fn op<T: SchurFloat>(a: &Mat<T>, trans: FlagTrans) -> Mat<T> {
+    match trans {
+        FlagTrans::N => Mat { data: a.data.clone(), nrow: a.nrow, ncol: a.ncol },
+        FlagTrans::T => {
+            let mut t = a.adjoint();
+            t.data.iter_mut().for_each(|x| *x = x.conj());
+            t
+        },
+        FlagTrans::C => a.adjoint(),
+        FlagTrans::CN => Mat { data: a.data.iter().map(|x| x.conj()).collect(), nrow: a.nrow, ncol: a.ncol },
+    }
+}
+
+/// Solve quasi-triangular Sylvester equation `op(A) X + isgn X op(B) = C`
+/// (`xTRSYL`), where `a` (order `m`), `b` (order `n`) and `c` are col-major.
+///
+/// On exit `c` is overwritten by `X`. Returns info 1 if the equation is
+/// perturbed to be solvable (close eigenvalues), otherwise 0; the scale
+/// factor is always one.
+#[allow(clippy::too_many_arguments)]
+pub fn trsyl_cpu_serial<T>(
+    trana: FlagTrans,
+    tranb: FlagTrans,
+    isgn: T,
+    m: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+) -> usize
+where
+    T: SchurFloat,
+{
+    let (a, b) = (Mat::new(a, m, m), Mat::new(b, n, n));
+    let aop = op(&a, trana);
+    let bop = op(&b, tranb);
+
+    // op(A) upper: solve from bottom; op(B) upper: solve from left
+    let mut blocks_a = diag_blocks(&a);
+    let mut blocks_b = diag_blocks(&b);
+    if trana == FlagTrans::N {
+        blocks_a.reverse();
+    }
+    if tranb != FlagTrans::N {
+        blocks_b.reverse();
+    }
+    let cm = Mat::new(c, m, n);
+    let mut x = Mat::<T>::zeros(m, n);
+    let mut info = 0;
+    for &(i0, p) in blocks_a.iter() {
+        for &(j0, r) in blocks_b.iter() {
+            let mut rhs = submat(&cm, i0, j0, p, r);
+            for jj in 0..r {
+                for ii in 0..p {
+                    let (i, j) = (i0 + ii, j0 + jj);
+                    let mut v = rhs[(ii, jj)];
+                    v = (0..m).filter(|&l| l < i0 || l >= i0 + p).fold(v, |acc, l| acc - aop[(i, l)] * x[(l, j)]);
+                    v = (0..n)
+                        .filter(|&l| l < j0 || l >= j0 + r)
+                        .fold(v, |acc, l| acc - isgn * x[(i, l)] * bop[(l, j)]);
+                    rhs[(ii, jj)] = v;
+                }
+            }
+            let (xb, ok) = small_sylvester(&submat(&aop, i0, i0, p, p), &submat(&bop, j0, j0, r, r), isgn, &rhs);
+            if !ok {
+                info = 1;
+            }
+            for jj in 0..r {
+                (0..p).for_each(|ii| x[(i0 + ii, j0 + jj)] = xb[(ii, jj)]);
+            }
+        }
+    }
+    c[..m * n].copy_from_slice(&x.data);
+    info
+}
+
+/* #endregion */
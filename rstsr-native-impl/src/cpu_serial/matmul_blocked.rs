@@ -0,0 +1,299 @@
+//! Cache-blocked matrix multiplication for CPU backend.
+//!
+//! This is a GotoBLAS-style GEMM for generic types that only requires `Mul`
+//! and `Add`. It is used for types that BLAS or faer could not handle (integers,
+//! `Complex<i64>`, user-defined scalars, etc.).
+//!
+//! The algorithm follows the usual three-level blocking:
+//!
+//! - columns of B and C are split into blocks of [`GEMM_NC`];
+//! - the contracted dimension is split into blocks of [`GEMM_KC`], and the `KC x NC` block of B is
+//!   packed into micro-panels of [`GEMM_NR`] columns;
+//! - rows of A and C are split into blocks of [`GEMM_MC`], and the `MC x KC` block of A is packed
+//!   into micro-panels of [`GEMM_MR`] rows;
+//! - a micro-kernel accumulates an `MR x NR` tile of C in registers (or at least in a small local
+//!   array) from one micro-panel of A and one of B.
+//!
+//! Packing makes the innermost loop run over contiguous memory regardless of
+//! the strides of input tensors, so broadcasted or transposed operands are
+//! handled at the same speed as contiguous ones.
+
+use crate::prelude_dev::*;
+use core::ops::{Add, Mul};
+use num::Zero;
+
+/// Rows of a micro-panel of A (and of a micro-tile of C).
+pub const GEMM_MR: usize = 4;
+/// Columns of a micro-panel of B (and of a micro-tile of C).
+pub const GEMM_NR: usize = 4;
+/// Block size of the contracted dimension.
+pub const GEMM_KC: usize = 256;
+/// Block size of rows of A and C.
+pub const GEMM_MC: usize = 64;
+/// Block size of columns of B and C.
+pub const GEMM_NC: usize = 2048;
+
+/* #region packing and kernels */
+
+/// Pack an `mc x kc` block of A into micro-panels of [`GEMM_MR`] rows.
+///
+/// Each micro-panel is stored as `kc` consecutive columns of `mr` elements.
+/// The last micro-panel may have less than [`GEMM_MR`] rows; it is not padded.
+///
+/// # Safety
+///
+/// `offset + i * rs + p * cs` must be a valid index of `a` for all
+/// `i < mc, p < kc`.
+pub(crate) unsafe fn gemm_blocked_pack_a<TA>(
+    a: &[TA],
+    offset: isize,
+    rs: isize,
+    cs: isize,
+    mc: usize,
+    kc: usize,
+) -> Vec<Vec<TA>>
+where
+    TA: Clone,
+{
+    (0..mc)
+        .step_by(GEMM_MR)
+        .map(|ir| {
+            let mr = GEMM_MR.min(mc - ir);
+            let mut panel = Vec::with_capacity(mr * kc);
+            for p in 0..kc {
+                let offset_p = offset + ir as isize * rs + p as isize * cs;
+                for i in 0..mr {
+                    panel.push(a.get_unchecked((offset_p + i as isize * rs) as usize).clone());
+                }
+            }
+            panel
+        })
+        .collect()
+}
+
+/// Pack one micro-panel (`kc x nr`) of B, starting at `offset`.
+///
+/// The micro-panel is stored as `kc` consecutive rows of `nr` elements.
+///
+/// # Safety
+///
+/// `offset + p * rs + j * cs` must be a valid index of `b` for all
+/// `p < kc, j < nr`.
+pub(crate) unsafe fn gemm_blocked_pack_b_panel<TB>(
+    b: &[TB],
+    offset: isize,
+    rs: isize,
+    cs: isize,
+    kc: usize,
+    nr: usize,
+) -> Vec<TB>
+where
+    TB: Clone,
+{
+    let mut panel = Vec::with_capacity(kc * nr);
+    for p in 0..kc {
+        let offset_p = offset + p as isize * rs;
+        for j in 0..nr {
+            panel.push(b.get_unchecked((offset_p + j as isize * cs) as usize).clone());
+        }
+    }
+    panel
+}
+
+/// Pack a `kc x nc` block of B into micro-panels of [`GEMM_NR`] columns.
+///
+/// # Safety
+///
+/// See [`gemm_blocked_pack_b_panel`].
+pub(crate) unsafe fn gemm_blocked_pack_b<TB>(
+    b: &[TB],
+    offset: isize,
+    rs: isize,
+    cs: isize,
+    kc: usize,
+    nc: usize,
+) -> Vec<Vec<TB>>
+where
+    TB: Clone,
+{
+    (0..nc)
+        .step_by(GEMM_NR)
+        .map(|jr| gemm_blocked_pack_b_panel(b, offset + jr as isize * cs, rs, cs, kc, GEMM_NR.min(nc - jr)))
+        .collect()
+}
+
+/// Micro-kernel: `C[0..mr, 0..nr] (+)= alpha * A_panel * B_panel`.
+///
+/// - `beta = Some(beta)`: C is overwritten by `alpha * AB + beta * C`; C is not read if `beta` is
+///   zero, following BLAS convention.
+/// - `beta = None`: C is accumulated by `alpha * AB` (later blocks of the contracted dimension).
+///
+/// # Safety
+///
+/// `c.offset(i * rsc + j * csc)` must be valid for all `i < mr, j < nr`, and
+/// not accessed by other threads.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+unsafe fn gemm_blocked_kernel<TA, TB, TC>(
+    ap: &[TA],
+    bp: &[TB],
+    kc: usize,
+    c: *mut TC,
+    rsc: isize,
+    csc: isize,
+    alpha: &TC,
+    beta: Option<&TC>,
+) where
+    TA: Clone + Mul<TB, Output = TC>,
+    TB: Clone,
+    TC: Clone + Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
+{
+    let mr = ap.len() / kc;
+    let nr = bp.len() / kc;
+    let mut acc: [[TC; GEMM_NR]; GEMM_MR] = core::array::from_fn(|_| core::array::from_fn(|_| TC::zero()));
+
+    if mr == GEMM_MR && nr == GEMM_NR {
+        // full tile: loop bounds are constants, which could be unrolled
+        for (a, b) in ap.chunks_exact(GEMM_MR).zip(bp.chunks_exact(GEMM_NR)) {
+            for i in 0..GEMM_MR {
+                for j in 0..GEMM_NR {
+                    acc[i][j] = acc[i][j].clone() + a[i].clone() * b[j].clone();
+                }
+            }
+        }
+    } else {
+        // edge tile
+        for (a, b) in ap.chunks_exact(mr).zip(bp.chunks_exact(nr)) {
+            for i in 0..mr {
+                for j in 0..nr {
+                    acc[i][j] = acc[i][j].clone() + a[i].clone() * b[j].clone();
+                }
+            }
+        }
+    }
+
+    for (i, acc_i) in acc.into_iter().enumerate().take(mr) {
+        for (j, acc_ij) in acc_i.into_iter().enumerate().take(nr) {
+            let ptr_c = c.offset(i as isize * rsc + j as isize * csc);
+            let val = alpha.clone() * acc_ij;
+            *ptr_c = match beta {
+                Some(beta) if beta.is_zero() => val,
+                Some(beta) => val + beta.clone() * (*ptr_c).clone(),
+                None => val + (*ptr_c).clone(),
+            };
+        }
+    }
+}
+
+/// Macro-kernel: multiply a packed block of A by a packed block of B.
+///
+/// # Safety
+///
+/// `c` points to the first element of the `mc x nc` block of C to be written.
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn gemm_blocked_macro_kernel<TA, TB, TC>(
+    ap: &[Vec<TA>],
+    bp: &[Vec<TB>],
+    kc: usize,
+    c: *mut TC,
+    rsc: isize,
+    csc: isize,
+    alpha: &TC,
+    beta: Option<&TC>,
+) where
+    TA: Clone + Mul<TB, Output = TC>,
+    TB: Clone,
+    TC: Clone + Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
+{
+    // B micro-panel is kept in L1 cache, while looping over A block in L2
+    for (jr, bp) in bp.iter().enumerate() {
+        let c_j = c.offset((jr * GEMM_NR) as isize * csc);
+        for (ir, ap) in ap.iter().enumerate() {
+            let c_ij = c_j.offset((ir * GEMM_MR) as isize * rsc);
+            gemm_blocked_kernel(ap, bp, kc, c_ij, rsc, csc, alpha, beta);
+        }
+    }
+}
+
+/// Scale C by `beta` (without reading C if `beta` is zero).
+///
+/// This is used when the contracted dimension is zero.
+pub(crate) fn gemm_blocked_scale_c<TC>(c: &mut [TC], lc: &Layout<Ix2>, beta: &TC)
+where
+    TC: Clone + Mul<TC, Output = TC> + Zero,
+{
+    let (m, n) = (lc.shape()[0], lc.shape()[1]);
+    for i in 0..m {
+        for j in 0..n {
+            let idx_c = unsafe { lc.index_uncheck(&[i, j]) } as usize;
+            c[idx_c] = if beta.is_zero() { TC::zero() } else { beta.clone() * c[idx_c].clone() };
+        }
+    }
+}
+
+/* #endregion */
+
+/// Cache-blocked GEMM `C = alpha * A * B + beta * C` for generic types.
+///
+/// Layouts may be of arbitrary (including negative or zero) strides. Output is
+/// not read if `beta` is zero, following BLAS convention.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm_blocked_cpu_serial<TA, TB, TC>(
+    c: &mut [TC],
+    lc: &Layout<Ix2>,
+    a: &[TA],
+    la: &Layout<Ix2>,
+    b: &[TB],
+    lb: &Layout<Ix2>,
+    alpha: TC,
+    beta: TC,
+) -> Result<()>
+where
+    TA: Clone,
+    TB: Clone,
+    TC: Clone,
+    TA: Mul<TB, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
+{
+    // shape check
+    let sc = lc.shape();
+    let sa = la.shape();
+    let sb = lb.shape();
+    rstsr_assert_eq!(sc[0], sa[0], InvalidLayout)?;
+    rstsr_assert_eq!(sa[1], sb[0], InvalidLayout)?;
+    rstsr_assert_eq!(sc[1], sb[1], InvalidLayout)?;
+    let (m, n, k) = (sc[0], sc[1], sa[1]);
+
+    if m == 0 || n == 0 {
+        return Ok(());
+    }
+    if k == 0 {
+        gemm_blocked_scale_c(c, lc, &beta);
+        return Ok(());
+    }
+
+    let [rsa, csa] = *la.stride();
+    let [rsb, csb] = *lb.stride();
+    let [rsc, csc] = *lc.stride();
+    let ptr_c = c.as_mut_ptr();
+
+    unsafe {
+        for jc in (0..n).step_by(GEMM_NC) {
+            let nc = GEMM_NC.min(n - jc);
+            for pc in (0..k).step_by(GEMM_KC) {
+                let kc = GEMM_KC.min(k - pc);
+                let offset_b = lb.offset() as isize + pc as isize * rsb + jc as isize * csb;
+                let bp = gemm_blocked_pack_b(b, offset_b, rsb, csb, kc, nc);
+                let beta = if pc == 0 { Some(&beta) } else { None };
+                for ic in (0..m).step_by(GEMM_MC) {
+                    let mc = GEMM_MC.min(m - ic);
+                    let offset_a = la.offset() as isize + ic as isize * rsa + pc as isize * csa;
+                    let ap = gemm_blocked_pack_a(a, offset_a, rsa, csa, mc, kc);
+                    let offset_c = lc.offset() as isize + ic as isize * rsc + jc as isize * csc;
+                    gemm_blocked_macro_kernel(&ap, &bp, kc, ptr_c.offset(offset_c), rsc, csc, &alpha, beta);
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,14 @@
 //! Matrix multiplication for CPU backend.
 //!
-//! **This implementation is not optimized!**
+//! GEMM (including broadcasted matmul) is performed by the cache-blocked
+//! kernel [`gemm_blocked_cpu_serial`]. Other functions in this module are not
+//! optimized, and [`gemm_naive_cpu_serial`] is kept as reference
+//! implementation.
 
 use crate::prelude_dev::*;
 use core::ops::{Add, Mul};
+use num::complex::ComplexFloat;
+use num::Zero;
 
 #[allow(clippy::too_many_arguments)]
 pub fn matmul_naive_cpu_serial<TA, TB, TC, DA, DB, DC>(
@@ -24,7 +29,7 @@ where
     DB: DimAPI,
     DC: DimAPI,
     TA: Mul<TB, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
 {
     // NOTE: this only works for row-major layout
     // for column-major layout, we need to transpose the input:
@@ -42,7 +47,7 @@ where
             let la = &la.clone().into_dim::<Ix2>().unwrap();
             let lb = &lb.clone().into_dim::<Ix2>().unwrap();
             let lc = &lc.clone().into_dim::<Ix2>().unwrap();
-            gemm_naive_cpu_serial(c, lc, a, la, b, lb, alpha, beta)?;
+            gemm_blocked_cpu_serial(c, lc, a, la, b, lb, alpha, beta)?;
         },
         _ => {
             // broadcasted rules 3..7: the config resolves the rule, broadcasts
@@ -67,7 +72,7 @@ where
                     lb_m.set_offset(ib_rest);
                     lc_m.set_offset(ic_rest);
                 }
-                gemm_naive_cpu_serial(c, &lc_m, a, &la_m, b, &lb_m, alpha.clone(), beta.clone())?;
+                gemm_blocked_cpu_serial(c, &lc_m, a, &la_m, b, &lb_m, alpha.clone(), beta.clone())?;
             }
         },
     }
@@ -90,7 +95,7 @@ where
     TB: Clone,
     TC: Clone,
     TA: Mul<TB, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
 {
     // shape check
     let sc = lc.shape();
@@ -106,7 +111,7 @@ where
         for i_m in 0..m {
             for i_n in 0..n {
                 let idx_c = lc.index_uncheck(&[i_m, i_n]) as usize;
-                c[idx_c] = beta.clone() * c[idx_c].clone();
+                c[idx_c] = scale_by_beta(&beta, &c[idx_c]);
             }
             for i_k in 0..k {
                 let idx_a = la.index_uncheck(&[i_m, i_k]) as usize;
@@ -137,7 +142,7 @@ where
     TB: Clone,
     TC: Clone,
     TA: Mul<TB, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
 {
     // shape check
     let sc = lc.shape();
@@ -151,7 +156,7 @@ where
     unsafe {
         for i_n in 0..n {
             let idx_c = lc.index_uncheck(&[i_n]) as usize;
-            c[idx_c] = beta.clone() * c[idx_c].clone();
+            c[idx_c] = scale_by_beta(&beta, &c[idx_c]);
             for i_k in 0..k {
                 let idx_a = la.index_uncheck(&[i_n, i_k]) as usize;
                 let idx_b = lb.index_uncheck(&[i_k]) as usize;
@@ -178,7 +183,7 @@ where
     TB: Clone,
     TC: Clone,
     TA: Mul<TB, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
 {
     // shape check
     let sc = lc.shape();
@@ -192,7 +197,7 @@ where
     unsafe {
         for i_n in 0..n {
             let idx_c = lc.index_uncheck(&[i_n]) as usize;
-            c[idx_c] = beta.clone() * c[idx_c].clone();
+            c[idx_c] = scale_by_beta(&beta, &c[idx_c]);
             for i_k in 0..k {
                 let idx_a = la.index_uncheck(&[i_k]) as usize;
                 let idx_b = lb.index_uncheck(&[i_k, i_n]) as usize;
@@ -219,7 +224,7 @@ where
     TB: Clone,
     TC: Clone,
     TA: Mul<TB, Output = TC>,
-    TC: Mul<TC, Output = TC> + Add<TC, Output = TC>,
+    TC: Mul<TC, Output = TC> + Add<TC, Output = TC> + Zero,
 {
     // shape check
     let sa = la.shape();
@@ -230,7 +235,7 @@ where
     // naive iteration
     unsafe {
         let idx_c = lc.index_uncheck(&[]) as usize;
-        let mut sum = beta * c[idx_c].clone();
+        let mut sum = scale_by_beta(&beta, &c[idx_c]);
         for i in 0..n {
             let idx_a = la.index_uncheck(&[i]) as usize;
             let idx_b = lb.index_uncheck(&[i]) as usize;
@@ -240,3 +245,40 @@ where
     }
     Ok(())
 }
+
+/// Scale output element by `beta`.
+///
+/// Output is not read if `beta` is zero, following BLAS convention; output
+/// may be uninitialized (with NaN or inf) in this case.
+#[inline]
+fn scale_by_beta<TC>(beta: &TC, c: &TC) -> TC
+where
+    TC: Clone + Mul<TC, Output = TC> + Zero,
+{
+    if beta.is_zero() {
+        TC::zero()
+    } else {
+        beta.clone() * c.clone()
+    }
+}
+
+/// Elementwise conjugate of `a`, copied to a new contiguous buffer.
+///
+/// This is used to perform matmul with conjugated operands, when the kernel
+/// itself can not handle conjugation.
+pub fn conj_to_contig_cpu_serial<T, D>(a: &[T], la: &Layout<D>, order: FlagOrder) -> Result<(Vec<T>, Layout<D>)>
+where
+    T: ComplexFloat,
+    D: DimAPI,
+{
+    let la_new = match order {
+        RowMajor => la.shape().new_c_contig(None),
+        ColMajor => la.shape().new_f_contig(None),
+    };
+    let mut a_new: Vec<MaybeUninit<T>> = unsafe { uninitialized_vec(la_new.size())? };
+    op_muta_refb_func_cpu_serial(&mut a_new, &la_new, a, la, |x, y| {
+        x.write(y.conj());
+    })?;
+    let a_new = unsafe { core::mem::transmute::<Vec<MaybeUninit<T>>, Vec<T>>(a_new) };
+    Ok((a_new, la_new))
+}
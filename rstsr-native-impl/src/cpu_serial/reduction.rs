@@ -367,6 +367,69 @@ where
     Ok((out, lo))
 }
 
+/// Reduce along axes, writing results into a preallocated output.
+///
+/// `lc` should have the same shape as the remaining (not reduced) axes of `la`. Results are written
+/// to `c` by `lc` directly, without temporary buffer.
+#[allow(clippy::too_many_arguments)]
+pub fn reduce_axes_to_cpu_serial<TI, TS, TO, I, F, FSum, FOut>(
+    c: &mut [TO],
+    lc: &Layout<IxD>,
+    a: &[TI],
+    la: &Layout<IxD>,
+    axes: &[isize],
+    init: I,
+    f: F,
+    f_sum: FSum,
+    f_out: FOut,
+) -> Result<()>
+where
+    TI: Clone,
+    TS: Clone,
+    I: Fn() -> TS,
+    F: Fn(TS, TI) -> TS,
+    FSum: Fn(TS, TS) -> TS,
+    FOut: Fn(TS) -> TO,
+{
+    // naming convention follows `reduce_axes_cpu_serial`
+    let (ls, lm) = la.dim_split_axes(axes)?;
+    rstsr_assert_eq!(lc.shape(), lm.shape(), InvalidLayout, "shape of output does not match the reduced shape")?;
+
+    // summed axes are used together with remaining axes, where offset of layout may double-counted.
+    let offset = la.offset();
+
+    // iterate output and remaining axes in the same order, which is efficient for output
+    let layouts = translate_to_col_major(&[lc, &lm], TensorIterOrder::K)?;
+    let it_c = IterLayoutColMajor::new(&layouts[0])?;
+    let it_m = IterLayoutColMajor::new(&layouts[1])?;
+
+    // summed axes: contiguous part by unrolled_reduce, broadcast part by duplication
+    let (_as1, as0, asc, asd) = get_axes_composition(&ls);
+    let size_s0 = as0.iter().map(|&i| ls.shape()[i]).product::<usize>();
+    let size_sc = asc.iter().map(|&i| ls.shape()[i]).product::<usize>();
+    let asd = asd.iter().map(|&i| i as isize).collect_vec();
+    let (lsd, _) = ls.dim_split_axes(&asd)?;
+    let it_sd = IterLayoutColMajor::new(&lsd)?;
+
+    if ls.size() == 0 {
+        it_c.for_each(|i_c| c[i_c] = f_out(init()));
+        return Ok(());
+    }
+
+    it_c.zip(it_m).for_each(|(i_c, i_m)| {
+        let mut acc = it_sd.clone().fold(init(), |acc, i_sd| {
+            let idx_in = i_m + i_sd - offset; // double-counted offset
+            f_sum(acc, unrolled_reduce(&a[idx_in..idx_in + size_sc], &init, &f, &f_sum))
+        });
+        let acc_before = acc.clone();
+        for _ in 1..size_s0 {
+            acc = f_sum(acc, acc_before.clone());
+        }
+        c[i_c] = f_out(acc);
+    });
+    Ok(())
+}
+
 /* #endregion */
 
 /* #region reduce_binary */
@@ -0,0 +1,250 @@
+//! Numerically stable fused reductions for CPU without parallelization.
+//!
+//! These reductions are built on the usual reduction kernels ([`reduce_all_cpu_serial`] and
+//! [`reduce_axes_cpu_serial`]), but carry a rescaled accumulator so that the whole reduction is
+//! done in a single pass without overflow:
+//!
+//! | reduction | accumulator | `f_out` |
+//! |---|---|---|
+//! | logsumexp | `(max, sum(exp(x - max)))` | `max + ln(sum)` |
+//! | norm (nrm2) | `(scale, sum((x / scale)^2))` | `scale * sqrt(sum)` |
+//!
+//! Softmax and log-softmax are evaluated by one logsumexp reduction over the given axes, followed
+//! by one elementwise pass that writes to output directly, without chained temporaries.
+
+use crate::prelude_dev::*;
+use num::complex::ComplexFloat;
+use num::Float;
+
+/* #region accumulator functions */
+
+/// Initializer of logsumexp accumulator `(max, sum(exp(x - max)))`.
+#[inline]
+pub fn logsumexp_init<T>() -> (T, T)
+where
+    T: Float,
+{
+    (T::neg_infinity(), T::zero())
+}
+
+/// Accumulate one value to logsumexp accumulator.
+///
+/// If the current value is larger than the running maximum, the running sum is rescaled to the new
+/// maximum. Infinities with the same sign as the running maximum are treated as equal values.
+#[inline]
+pub fn logsumexp_acc<T>((m, s): (T, T), x: T) -> (T, T)
+where
+    T: Float,
+{
+    if x == m {
+        (m, s + T::one())
+    } else if x > m {
+        (x, s * (m - x).exp() + T::one())
+    } else {
+        (m, s + (x - m).exp())
+    }
+}
+
+/// Merge two logsumexp accumulators.
+#[inline]
+pub fn logsumexp_sum<T>((m1, s1): (T, T), (m2, s2): (T, T)) -> (T, T)
+where
+    T: Float,
+{
+    if m1 == m2 {
+        (m1, s1 + s2)
+    } else if m1 > m2 {
+        (m1, s1 + s2 * (m2 - m1).exp())
+    } else {
+        (m2, s2 + s1 * (m1 - m2).exp())
+    }
+}
+
+/// Finalize logsumexp accumulator.
+#[inline]
+pub fn logsumexp_out<T>((m, s): (T, T)) -> T
+where
+    T: Float,
+{
+    if m.is_infinite() {
+        // all values are -inf (or empty), or at least one value is +inf
+        m
+    } else {
+        m + s.ln()
+    }
+}
+
+/// Initializer of scaled sum-of-squares accumulator `(scale, ssq)`.
+#[inline]
+pub fn nrm2_init<T>() -> (T, T)
+where
+    T: Float,
+{
+    (T::zero(), T::one())
+}
+
+/// Accumulate one absolute value to scaled sum-of-squares accumulator.
+///
+/// This follows the classical `xLASSQ` update from LAPACK. NaN and infinity are propagated
+/// without being squared.
+#[inline]
+pub fn nrm2_acc_real<T>((scale, ssq): (T, T), x: T) -> (T, T)
+where
+    T: Float,
+{
+    let absx = x.abs();
+    if absx.is_nan() {
+        (absx, ssq)
+    } else if absx.is_zero() || scale.is_nan() {
+        (scale, ssq)
+    } else if absx.is_infinite() || scale.is_infinite() {
+        (T::infinity(), T::one())
+    } else if scale < absx {
+        let r = scale / absx;
+        (absx, T::one() + ssq * r * r)
+    } else {
+        let r = absx / scale;
+        (scale, ssq + r * r)
+    }
+}
+
+/// Accumulate one (possibly complex) value to scaled sum-of-squares accumulator.
+///
+/// Real and imaginary parts are accumulated as two independent elements.
+#[inline]
+pub fn nrm2_acc<T>(acc: (T::Real, T::Real), x: T) -> (T::Real, T::Real)
+where
+    T: ComplexFloat,
+{
+    let acc = nrm2_acc_real(acc, x.re());
+    nrm2_acc_real(acc, x.im())
+}
+
+/// Merge two scaled sum-of-squares accumulators.
+#[inline]
+pub fn nrm2_sum<T>((scale1, ssq1): (T, T), (scale2, ssq2): (T, T)) -> (T, T)
+where
+    T: Float,
+{
+    if scale1.is_nan() || scale2.is_nan() {
+        (T::nan(), T::one())
+    } else if scale1.is_infinite() || scale2.is_infinite() {
+        (T::infinity(), T::one())
+    } else if scale1 >= scale2 {
+        if scale1.is_zero() {
+            (scale1, ssq1)
+        } else {
+            let r = scale2 / scale1;
+            (scale1, ssq1 + ssq2 * r * r)
+        }
+    } else {
+        let r = scale1 / scale2;
+        (scale2, ssq2 + ssq1 * r * r)
+    }
+}
+
+/// Finalize scaled sum-of-squares accumulator.
+#[inline]
+pub fn nrm2_out<T>((scale, ssq): (T, T)) -> T
+where
+    T: Float,
+{
+    scale * ssq.sqrt()
+}
+
+/* #endregion */
+
+/* #region logsumexp and norm */
+
+pub fn logsumexp_all_cpu_serial<T, D>(a: &[T], la: &Layout<D>) -> Result<T>
+where
+    T: Float,
+    D: DimAPI,
+{
+    reduce_all_cpu_serial(a, la, logsumexp_init, logsumexp_acc, logsumexp_sum, logsumexp_out)
+}
+
+pub fn logsumexp_axes_cpu_serial<T>(a: &[T], la: &Layout<IxD>, axes: &[isize]) -> Result<(Vec<T>, Layout<IxD>)>
+where
+    T: Float,
+{
+    reduce_axes_cpu_serial(a, la, axes, logsumexp_init, logsumexp_acc, logsumexp_sum, logsumexp_out)
+}
+
+pub fn norm_all_cpu_serial<T, D>(a: &[T], la: &Layout<D>) -> Result<T::Real>
+where
+    T: ComplexFloat,
+    D: DimAPI,
+{
+    reduce_all_cpu_serial(a, la, nrm2_init, nrm2_acc, nrm2_sum, nrm2_out)
+}
+
+pub fn norm_axes_cpu_serial<T>(a: &[T], la: &Layout<IxD>, axes: &[isize]) -> Result<(Vec<T::Real>, Layout<IxD>)>
+where
+    T: ComplexFloat,
+{
+    reduce_axes_cpu_serial(a, la, axes, nrm2_init, nrm2_acc, nrm2_sum, nrm2_out)
+}
+
+/* #endregion */
+
+/* #region softmax */
+
+/// Broadcast the layout of reduced tensor back to the shape of input tensor.
+///
+/// The reduced axes are inserted with zero stride, so the reduced values can be used as the
+/// second operand of elementwise kernels together with the input tensor.
+pub fn layout_broadcast_reduced(la: &Layout<IxD>, axes: &[isize], lo: &Layout<IxD>) -> Result<Layout<IxD>> {
+    let axes = normalize_axes_index(axes.into(), la.ndim(), false, true)?;
+    rstsr_assert_eq!(
+        lo.ndim() + axes.len(),
+        la.ndim(),
+        InvalidLayout,
+        "reduced layout is not compatible with the input layout"
+    )?;
+    let mut iter_lo = lo.shape().iter().zip(lo.stride().iter());
+    let mut shape = Vec::with_capacity(la.ndim());
+    let mut stride = Vec::with_capacity(la.ndim());
+    for (i, &n) in la.shape().iter().enumerate() {
+        if axes.contains(&(i as isize)) {
+            shape.push(n);
+            stride.push(0);
+        } else {
+            let (&n_o, &s_o) = iter_lo.next().unwrap();
+            shape.push(n_o);
+            stride.push(s_o);
+        }
+    }
+    Layout::new(shape, stride, lo.offset())
+}
+
+/// Softmax (or log-softmax if `log` is true) over `axes`.
+///
+/// This evaluates logsumexp over `axes` once, then writes `exp(x - lse)` (or `x - lse`) to output.
+pub fn softmax_cpu_serial<T, D>(
+    c: &mut [MaybeUninit<T>],
+    lc: &Layout<D>,
+    a: &[T],
+    la: &Layout<D>,
+    axes: &[isize],
+    log: bool,
+) -> Result<()>
+where
+    T: Float,
+    D: DimAPI,
+{
+    let la = la.to_dim::<IxD>()?;
+    let lc = lc.to_dim::<IxD>()?;
+    let (lse, lo) = logsumexp_axes_cpu_serial(a, &la, axes)?;
+    let lb = layout_broadcast_reduced(&la, axes, &lo)?;
+    match log {
+        true => op_mutc_refa_refb_func_cpu_serial(c, &lc, a, &la, &lse, &lb, |c, &a, &b| {
+            c.write(a - b);
+        }),
+        false => op_mutc_refa_refb_func_cpu_serial(c, &lc, a, &la, &lse, &lb, |c, &a, &b| {
+            c.write((a - b).exp());
+        }),
+    }
+}
+
+/* #endregion */
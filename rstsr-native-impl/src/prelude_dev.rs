@@ -4,10 +4,13 @@ pub(crate) use rstsr_dtype_traits::DTypeCastAPI;
 pub use crate::cpu_serial::adv_indexing::*;
 pub use crate::cpu_serial::assignment::*;
 pub use crate::cpu_serial::creation::*;
+pub use crate::cpu_serial::lapack_schur::*;
+pub use crate::cpu_serial::matmul_blocked::*;
 pub use crate::cpu_serial::matmul_naive::*;
 pub use crate::cpu_serial::op_tri::*;
 pub use crate::cpu_serial::op_with_func::*;
 pub use crate::cpu_serial::reduction::*;
+pub use crate::cpu_serial::reduction_stable::*;
 pub use crate::cpu_serial::transpose::*;
 pub use crate::cpu_serial::vecdot::*;
 
@@ -16,10 +19,12 @@ mod cpu_rayon {
     pub use crate::cpu_rayon::adv_indexing::*;
     pub use crate::cpu_rayon::assignment::*;
     pub use crate::cpu_rayon::creation::*;
+    pub use crate::cpu_rayon::matmul_blocked::*;
     pub use crate::cpu_rayon::matmul_naive::*;
     pub use crate::cpu_rayon::op_tri::*;
     pub use crate::cpu_rayon::op_with_func::*;
     pub use crate::cpu_rayon::reduction::*;
+    pub use crate::cpu_rayon::reduction_stable::*;
     pub use crate::cpu_rayon::transpose::*;
     pub use crate::cpu_rayon::vecdot::*;
 }
@@ -1,120 +1,184 @@
-use crate::prelude_dev::*;
-
-use num::Float;
-use rstsr_sci_traits::distance::metric::{MetricDistAPI, MetricDistWeightedAPI, MetricEuclidean};
-use rstsr_sci_traits::distance::native_impl::{cdist_rayon, cdist_weighted_rayon};
-use rstsr_sci_traits::distance::traits::CDistAPI;
-
-impl<T, D, M, TW, DW> CDistAPI<DeviceRayonAutoImpl>
-    for (
-        TensorView<'_, T, DeviceRayonAutoImpl, D>,
-        TensorView<'_, T, DeviceRayonAutoImpl, D>,
-        M,
-        TensorView<'_, TW, DeviceRayonAutoImpl, DW>,
-    )
-where
-    M: MetricDistWeightedAPI<Vec<T>, Weight = Vec<TW>, Out = TW> + Send + Sync,
-    T: Send + Sync,
-    TW: Float + Send + Sync,
-    M::Out: Send + Sync,
-    DeviceRayonAutoImpl: DeviceAPI<T, Raw = Vec<T>>
-        + DeviceAPI<TW, Raw = M::Weight>
-        + DeviceAPI<M::Out, Raw = Vec<M::Out>>
-        + DeviceCreationAnyAPI<M::Out>
-        + DeviceCreationAnyAPI<TW>
-        + OpAssignArbitaryAPI<TW, DW, DW>
-        + OpAssignAPI<TW, DW>,
-    D: DimAPI + DimIntoAPI<Ix2>,
-    DW: DimAPI + DimIntoAPI<Ix1>,
-{
-    type Out = Tensor<M::Out, DeviceRayonAutoImpl, D>;
-
-    fn cdist_f(self) -> Result<Self::Out> {
-        let (xa, xb, kernel, weight) = self;
-        rstsr_assert_eq!(xa.ndim(), 2, InvalidLayout, "xa must be a 2D tensor")?;
-        rstsr_assert_eq!(xb.ndim(), 2, InvalidLayout, "xb must be a 2D tensor")?;
-        rstsr_assert_eq!(weight.ndim(), 1, InvalidLayout, "weight must be a 1D tensor")?;
-        rstsr_assert!(xa.device().same_device(xb.device()), DeviceMismatch)?;
-        rstsr_assert!(xa.device().same_device(weight.device()), DeviceMismatch)?;
-        let la = xa.layout().to_dim::<Ix2>()?;
-        let lb = xb.layout().to_dim::<Ix2>()?;
-        let device = xa.device().clone();
-        let order = device.default_order();
-        let weight = weight.into_contig_f(RowMajor)?;
-        let pool = device.get_current_pool();
-        let dist = cdist_weighted_rayon(xa.raw(), xb.raw(), &la, &lb, weight.raw(), kernel, order, pool)?;
-
-        let m = la.shape()[0];
-        let n = lb.shape()[0];
-        asarray_f((dist, [m, n], &device))?.into_dim_f::<D>()
-    }
-}
+/// Rayon-parallel `distance` for CPU device `$Device` with generic parameters
+/// `$G` and their bounds, shared by `DeviceFaer` and `DeviceBLAS<B>`.
+macro_rules! impl_distance_rayon {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use crate::prelude_dev::*;
 
-impl<T, D, M> CDistAPI<DeviceRayonAutoImpl>
-    for (TensorView<'_, T, DeviceRayonAutoImpl, D>, TensorView<'_, T, DeviceRayonAutoImpl, D>, M)
-where
-    M: MetricDistAPI<Vec<T>> + Send + Sync,
-    T: Send + Sync,
-    M::Out: Send + Sync,
-    DeviceRayonAutoImpl:
-        DeviceAPI<T, Raw = Vec<T>> + DeviceAPI<M::Out, Raw = Vec<M::Out>> + DeviceCreationAnyAPI<M::Out>,
-    D: DimAPI + DimIntoAPI<Ix2>,
-{
-    type Out = Tensor<M::Out, DeviceRayonAutoImpl, D>;
-
-    fn cdist_f(self) -> Result<Self::Out> {
-        let (xa, xb, kernel) = self;
-        rstsr_assert_eq!(xa.ndim(), 2, InvalidLayout, "xa must be a 2D tensor")?;
-        rstsr_assert_eq!(xb.ndim(), 2, InvalidLayout, "xb must be a 2D tensor")?;
-        rstsr_assert!(xa.device().same_device(xb.device()), DeviceMismatch)?;
-        let la = xa.layout().to_dim::<Ix2>()?;
-        let lb = xb.layout().to_dim::<Ix2>()?;
-        let device = xa.device().clone();
-        let order = device.default_order();
-        let pool = device.get_current_pool();
-        let dist = cdist_rayon(xa.raw(), xb.raw(), &la, &lb, kernel, order, pool)?;
-
-        let m = la.shape()[0];
-        let n = lb.shape()[0];
-        asarray_f((dist, [m, n], &device))?.into_dim_f::<D>()
-    }
-}
+            use num::Float;
+            use rstsr_sci_traits::distance::metric::{MetricDistAPI, MetricDistWeightedAPI, MetricEuclidean};
+            use rstsr_sci_traits::distance::native_impl::{cdist_rayon, cdist_weighted_rayon, pdist_rayon, pdist_weighted_rayon};
+            use rstsr_sci_traits::distance::traits::{CDistAPI, PDistAPI};
 
-impl<T, D> CDistAPI<DeviceRayonAutoImpl>
-    for (TensorView<'_, T, DeviceRayonAutoImpl, D>, TensorView<'_, T, DeviceRayonAutoImpl, D>)
-where
-    T: Float + Send + Sync,
-    DeviceRayonAutoImpl: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T>,
-    D: DimAPI + DimIntoAPI<Ix2>,
-{
-    type Out = Tensor<T, DeviceRayonAutoImpl, D>;
-
-    fn cdist_f(self) -> Result<Self::Out> {
-        let (xa, xb) = self;
-        CDistAPI::<DeviceRayonAutoImpl>::cdist_f((xa, xb, MetricEuclidean))
-    }
-}
+            impl<$($G,)* T, D, M, TW, DW> CDistAPI<$Device>
+                for (
+                    TensorView<'_, T, $Device, D>,
+                    TensorView<'_, T, $Device, D>,
+                    M,
+                    TensorView<'_, TW, $Device, DW>,
+                )
+            where
+                $($bound)*
+                M: MetricDistWeightedAPI<Vec<T>, Weight = Vec<TW>, Out = TW> + Send + Sync,
+                T: Send + Sync,
+                TW: Float + Send + Sync,
+                M::Out: Send + Sync,
+                $Device: DeviceAPI<T, Raw = Vec<T>>
+                    + DeviceAPI<TW, Raw = M::Weight>
+                    + DeviceAPI<M::Out, Raw = Vec<M::Out>>
+                    + DeviceCreationAnyAPI<M::Out>
+                    + DeviceCreationAnyAPI<TW>
+                    + OpAssignArbitaryAPI<TW, DW, DW>
+                    + OpAssignAPI<TW, DW>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+                DW: DimAPI + DimIntoAPI<Ix1>,
+            {
+                type Out = Tensor<M::Out, $Device, D>;
+
+                fn cdist_f(self) -> Result<Self::Out> {
+                    let (xa, xb, kernel, weight) = self;
+                    rstsr_assert_eq!(xa.ndim(), 2, InvalidLayout, "xa must be a 2D tensor")?;
+                    rstsr_assert_eq!(xb.ndim(), 2, InvalidLayout, "xb must be a 2D tensor")?;
+                    rstsr_assert_eq!(weight.ndim(), 1, InvalidLayout, "weight must be a 1D tensor")?;
+                    rstsr_assert!(xa.device().same_device(xb.device()), DeviceMismatch)?;
+                    rstsr_assert!(xa.device().same_device(weight.device()), DeviceMismatch)?;
+                    let la = xa.layout().to_dim::<Ix2>()?;
+                    let lb = xb.layout().to_dim::<Ix2>()?;
+                    let device = xa.device().clone();
+                    let order = device.default_order();
+                    let weight = weight.into_contig_f(RowMajor)?;
+                    let pool = device.get_current_pool();
+                    let dist = cdist_weighted_rayon(xa.raw(), xb.raw(), &la, &lb, weight.raw(), kernel, order, pool)?;
+
+                    let m = la.shape()[0];
+                    let n = lb.shape()[0];
+                    asarray_f((dist, [m, n], &device))?.into_dim_f::<D>()
+                }
+            }
+
+            impl<$($G,)* T, D, M> CDistAPI<$Device>
+                for (TensorView<'_, T, $Device, D>, TensorView<'_, T, $Device, D>, M)
+            where
+                $($bound)*
+                M: MetricDistAPI<Vec<T>> + Send + Sync,
+                T: Send + Sync,
+                M::Out: Send + Sync,
+                $Device:
+                    DeviceAPI<T, Raw = Vec<T>> + DeviceAPI<M::Out, Raw = Vec<M::Out>> + DeviceCreationAnyAPI<M::Out>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+            {
+                type Out = Tensor<M::Out, $Device, D>;
+
+                fn cdist_f(self) -> Result<Self::Out> {
+                    let (xa, xb, kernel) = self;
+                    rstsr_assert_eq!(xa.ndim(), 2, InvalidLayout, "xa must be a 2D tensor")?;
+                    rstsr_assert_eq!(xb.ndim(), 2, InvalidLayout, "xb must be a 2D tensor")?;
+                    rstsr_assert!(xa.device().same_device(xb.device()), DeviceMismatch)?;
+                    let la = xa.layout().to_dim::<Ix2>()?;
+                    let lb = xb.layout().to_dim::<Ix2>()?;
+                    let device = xa.device().clone();
+                    let order = device.default_order();
+                    let pool = device.get_current_pool();
+                    let dist = cdist_rayon(xa.raw(), xb.raw(), &la, &lb, kernel, order, pool)?;
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use rstsr_sci_traits::distance::metric::MetricEuclidean;
-    use rstsr_sci_traits::distance::traits::cdist;
+                    let m = la.shape()[0];
+                    let n = lb.shape()[0];
+                    asarray_f((dist, [m, n], &device))?.into_dim_f::<D>()
+                }
+            }
 
-    #[test]
-    fn playground() {
-        let device = DeviceRayonAutoImpl::default();
-        let a = linspace((0., 1., 6400, &device)).into_shape((1600, 4));
-        let b = linspace((0., 1., 8000, &device)).into_shape((2000, 4)).into_flip(-1);
+            impl<$($G,)* T, D> CDistAPI<$Device>
+                for (TensorView<'_, T, $Device, D>, TensorView<'_, T, $Device, D>)
+            where
+                $($bound)*
+                T: Float + Send + Sync,
+                $Device: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+            {
+                type Out = Tensor<T, $Device, D>;
 
-        let d = cdist((a.view(), b.view(), MetricEuclidean));
-        println!("{d:16.8?}");
+                fn cdist_f(self) -> Result<Self::Out> {
+                    let (xa, xb) = self;
+                    CDistAPI::<$Device>::cdist_f((xa, xb, MetricEuclidean))
+                }
+            }
 
-        let d = cdist((a.view(), b.view()));
-        println!("{d:16.8?}");
+            impl<$($G,)* T, D, M, TW, DW> PDistAPI<$Device>
+                for (TensorView<'_, T, $Device, D>, M, TensorView<'_, TW, $Device, DW>)
+            where
+                $($bound)*
+                M: MetricDistWeightedAPI<Vec<T>, Weight = Vec<TW>, Out = TW> + Send + Sync,
+                T: Send + Sync,
+                TW: Float + Send + Sync,
+                M::Out: Send + Sync,
+                $Device: DeviceAPI<T, Raw = Vec<T>>
+                    + DeviceAPI<TW, Raw = M::Weight>
+                    + DeviceAPI<M::Out, Raw = Vec<M::Out>>
+                    + DeviceCreationAnyAPI<M::Out>
+                    + DeviceCreationAnyAPI<TW>
+                    + OpAssignArbitaryAPI<TW, DW, DW>
+                    + OpAssignAPI<TW, DW>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+                DW: DimAPI + DimIntoAPI<Ix1>,
+            {
+                type Out = Tensor<M::Out, $Device, Ix1>;
 
-        let w = asarray((vec![1.5, 1.2, 0.7, 1.3], &device));
-        let d_w = cdist((a.view(), b.view(), MetricEuclidean, w.view()));
-        println!("{d_w:16.8?}");
-    }
+                fn pdist_f(self) -> Result<Self::Out> {
+                    let (x, kernel, weight) = self;
+                    rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+                    rstsr_assert_eq!(weight.ndim(), 1, InvalidLayout, "weight must be a 1D tensor")?;
+                    rstsr_assert!(x.device().same_device(weight.device()), DeviceMismatch)?;
+                    let lx = x.layout().to_dim::<Ix2>()?;
+                    let device = x.device().clone();
+                    let weight = weight.into_contig_f(RowMajor)?;
+                    let pool = device.get_current_pool();
+                    let dist = pdist_weighted_rayon(x.raw(), &lx, weight.raw(), kernel, pool)?;
+
+                    let n = dist.len();
+                    asarray_f((dist, [n], &device))?.into_dim_f::<Ix1>()
+                }
+            }
+
+            impl<$($G,)* T, D, M> PDistAPI<$Device> for (TensorView<'_, T, $Device, D>, M)
+            where
+                $($bound)*
+                M: MetricDistAPI<Vec<T>> + Send + Sync,
+                T: Send + Sync,
+                M::Out: Send + Sync,
+                $Device:
+                    DeviceAPI<T, Raw = Vec<T>> + DeviceAPI<M::Out, Raw = Vec<M::Out>> + DeviceCreationAnyAPI<M::Out>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+            {
+                type Out = Tensor<M::Out, $Device, Ix1>;
+
+                fn pdist_f(self) -> Result<Self::Out> {
+                    let (x, kernel) = self;
+                    rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+                    let lx = x.layout().to_dim::<Ix2>()?;
+                    let device = x.device().clone();
+                    let pool = device.get_current_pool();
+                    let dist = pdist_rayon(x.raw(), &lx, kernel, pool)?;
+
+                    let n = dist.len();
+                    asarray_f((dist, [n], &device))?.into_dim_f::<Ix1>()
+                }
+            }
+
+            impl<$($G,)* T, D> PDistAPI<$Device> for TensorView<'_, T, $Device, D>
+            where
+                $($bound)*
+                T: Float + Send + Sync,
+                $Device: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T>,
+                D: DimAPI + DimIntoAPI<Ix2>,
+            {
+                type Out = Tensor<T, $Device, Ix1>;
+
+                fn pdist_f(self) -> Result<Self::Out> {
+                    PDistAPI::<$Device>::pdist_f((self, MetricEuclidean))
+                }
+            }
+        };
+    };
 }
+
+pub(crate) use impl_distance_rayon;
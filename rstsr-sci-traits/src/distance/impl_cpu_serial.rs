@@ -1,6 +1,6 @@
 use crate::distance::metric::{MetricDistAPI, MetricDistWeightedAPI, MetricEuclidean};
-use crate::distance::native_impl::{cdist_serial, cdist_weighted_serial};
-use crate::distance::traits::CDistAPI;
+use crate::distance::native_impl::{cdist_serial, cdist_weighted_serial, pdist_serial, pdist_weighted_serial};
+use crate::distance::traits::{CDistAPI, PDistAPI};
 use num::Float;
 use rstsr_core::prelude_dev::*;
 
@@ -86,6 +86,71 @@ where
     }
 }
 
+impl<T, D, M, TW, DW> PDistAPI<DeviceCpuSerial>
+    for (TensorView<'_, T, DeviceCpuSerial, D>, M, TensorView<'_, TW, DeviceCpuSerial, DW>)
+where
+    M: MetricDistWeightedAPI<Vec<T>, Weight = Vec<TW>, Out = TW>,
+    TW: Float + Clone,
+    DeviceCpuSerial: DeviceAPI<T, Raw = Vec<T>>
+        + DeviceAPI<TW, Raw = M::Weight>
+        + DeviceAPI<M::Out, Raw = Vec<M::Out>>
+        + DeviceCreationAnyAPI<M::Out>
+        + DeviceCreationAnyAPI<TW>
+        + OpAssignArbitaryAPI<TW, DW, DW>
+        + OpAssignAPI<TW, DW>,
+    D: DimAPI + DimIntoAPI<Ix2>,
+    DW: DimAPI + DimIntoAPI<Ix1>,
+{
+    type Out = Tensor<M::Out, DeviceCpuSerial, Ix1>;
+
+    fn pdist_f(self) -> Result<Self::Out> {
+        let (x, kernel, weight) = self;
+        rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+        rstsr_assert_eq!(weight.ndim(), 1, InvalidLayout, "weight must be a 1D tensor")?;
+        rstsr_assert!(x.device().same_device(weight.device()), DeviceMismatch)?;
+        let lx = x.layout().to_dim::<Ix2>()?;
+        let device = x.device().clone();
+        let weight = weight.into_contig_f(RowMajor)?;
+        let dist = pdist_weighted_serial(x.raw(), &lx, weight.raw(), kernel)?;
+
+        let n = dist.len();
+        asarray_f((dist, [n], &device))?.into_dim_f::<Ix1>()
+    }
+}
+
+impl<T, D, M> PDistAPI<DeviceCpuSerial> for (TensorView<'_, T, DeviceCpuSerial, D>, M)
+where
+    DeviceCpuSerial: DeviceAPI<T, Raw = Vec<T>> + DeviceAPI<M::Out, Raw = Vec<M::Out>> + DeviceCreationAnyAPI<M::Out>,
+    M: MetricDistAPI<Vec<T>>,
+    D: DimAPI + DimIntoAPI<Ix2>,
+{
+    type Out = Tensor<M::Out, DeviceCpuSerial, Ix1>;
+
+    fn pdist_f(self) -> Result<Self::Out> {
+        let (x, kernel) = self;
+        rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+        let lx = x.layout().to_dim::<Ix2>()?;
+        let device = x.device().clone();
+        let dist = pdist_serial(x.raw(), &lx, kernel)?;
+
+        let n = dist.len();
+        asarray_f((dist, [n], &device))?.into_dim_f::<Ix1>()
+    }
+}
+
+impl<T, D> PDistAPI<DeviceCpuSerial> for TensorView<'_, T, DeviceCpuSerial, D>
+where
+    T: Float,
+    DeviceCpuSerial: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T>,
+    D: DimAPI + DimIntoAPI<Ix2>,
+{
+    type Out = Tensor<T, DeviceCpuSerial, Ix1>;
+
+    fn pdist_f(self) -> Result<Self::Out> {
+        PDistAPI::<DeviceCpuSerial>::pdist_f((self, MetricEuclidean))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1 +0,0 @@
-auto_impl_rayon.rs
\ No newline at end of file
@@ -0,0 +1,42 @@
+use super::auto_impl_rayon::impl_distance_rayon;
+
+impl_distance_rayon!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude_dev::*;
+    use rstsr_sci_traits::distance::metric::{MetricEuclidean, MetricMinkowski};
+    use rstsr_sci_traits::distance::squareform::squareform;
+    use rstsr_sci_traits::distance::traits::{cdist, pdist};
+
+    #[test]
+    fn playground() {
+        let device = DeviceFaer::default();
+        let a = linspace((0., 1., 6400, &device)).into_shape((1600, 4));
+        let b = linspace((0., 1., 8000, &device)).into_shape((2000, 4)).into_flip(-1);
+
+        let d = cdist((a.view(), b.view(), MetricEuclidean));
+        println!("{d:16.8?}");
+
+        let d = cdist((a.view(), b.view()));
+        println!("{d:16.8?}");
+
+        let w = asarray((vec![1.5, 1.2, 0.7, 1.3], &device));
+        let d_w = cdist((a.view(), b.view(), MetricEuclidean, w.view()));
+        println!("{d_w:16.8?}");
+    }
+
+    #[test]
+    fn test_pdist() {
+        let device = DeviceFaer::default();
+        let a = linspace((0., 1., 4000, &device)).into_shape((1000, 4)).into_flip(-1);
+        let d = pdist(a.view());
+        let d_full = cdist((a.view(), a.view()));
+        assert!(allclose_f64(&squareform(d.view()), &d_full));
+
+        let w = asarray((vec![1.5, 1.2, 0.7, 1.3], &device));
+        let d = pdist((a.view(), MetricMinkowski::new(3.0), w.view()));
+        let d_full = cdist((a.view(), a.view(), MetricMinkowski::new(3.0), w.view()));
+        assert!(allclose_f64(&squareform(d.view()), &d_full));
+    }
+}
@@ -1,8 +1,13 @@
 pub mod prelude;
 
+#[cfg(any(feature = "faer", feature = "blas"))]
+mod auto_impl_rayon;
+#[cfg(feature = "blas")]
+pub mod impl_blas;
 pub mod impl_cpu_serial;
 pub mod metric;
 pub mod native_impl;
+pub mod squareform;
 pub mod traits;
 
 #[cfg(feature = "faer")]
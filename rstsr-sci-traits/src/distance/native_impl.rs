@@ -329,3 +329,231 @@ where
 
     Ok(dists)
 }
+
+/// Index of pair `(i, j)` (`i < j`) in condensed distance vector of `m` points.
+#[inline]
+fn condensed_index(m: usize, i: usize, j: usize) -> usize {
+    i * m - i * (i + 1) / 2 + (j - i - 1)
+}
+
+pub fn pdist_serial<T, M>(x: &Vec<T>, lx: &Layout<Ix2>, mut kernel: M) -> Result<Vec<M::Out>>
+where
+    M: MetricDistAPI<Vec<T>>,
+{
+    let shape = lx.shape();
+    let stride = lx.stride();
+    let offset = lx.offset();
+
+    let k = shape[1];
+    let m = shape[0];
+    let mut dists = unsafe { uninitialized_vec::<M::Out>(m * m.saturating_sub(1) / 2)? };
+
+    kernel.initialize(x, lx, x, lx)?;
+
+    let strided = stride[1] != 1;
+
+    macro_rules! perform_calc {
+        ($STRIDED: ident) => {
+            for i in 0..m {
+                for j in (i + 1)..m {
+                    let uv = (x, x);
+                    let offsets = (
+                        (offset as isize + i as isize * stride[0]) as usize,
+                        (offset as isize + j as isize * stride[0]) as usize,
+                    );
+                    let indices = (i, j);
+                    let strides = (stride[1], stride[1]);
+                    let dist = kernel.distance::<{ $STRIDED }>(uv, offsets, indices, strides, k);
+                    dists[condensed_index(m, i, j)] = dist;
+                }
+            }
+        };
+    }
+    match strided {
+        false => perform_calc!(false),
+        true => perform_calc!(true),
+    }
+
+    Ok(dists)
+}
+
+pub fn pdist_weighted_serial<T, M>(
+    x: &Vec<T>,
+    lx: &Layout<Ix2>,
+    weights: &M::Weight,
+    mut kernel: M,
+) -> Result<Vec<M::Out>>
+where
+    M: MetricDistWeightedAPI<Vec<T>, Weight: AsRef<[M::Out]>, Out: Float>,
+{
+    let shape = lx.shape();
+    let stride = lx.stride();
+    let offset = lx.offset();
+
+    let k = shape[1];
+    let m = shape[0];
+    let mut dists = unsafe { uninitialized_vec::<M::Out>(m * m.saturating_sub(1) / 2)? };
+
+    kernel.weighted_initialize(x, lx, x, lx, weights)?;
+
+    let strided = stride[1] != 1;
+    let weights_sum = weights.as_ref().iter().fold(M::Out::zero(), |acc, w| acc + *w);
+
+    macro_rules! perform_calc {
+        ($STRIDED: ident) => {
+            for i in 0..m {
+                for j in (i + 1)..m {
+                    let uv = (x, x);
+                    let offsets = (
+                        (offset as isize + i as isize * stride[0]) as usize,
+                        (offset as isize + j as isize * stride[0]) as usize,
+                    );
+                    let indices = (i, j);
+                    let strides = (stride[1], stride[1]);
+                    let dist = kernel.weighted_distance::<{ $STRIDED }>(
+                        uv,
+                        offsets,
+                        indices,
+                        strides,
+                        k,
+                        weights,
+                        weights_sum,
+                    );
+                    dists[condensed_index(m, i, j)] = dist;
+                }
+            }
+        };
+    }
+    match strided {
+        false => perform_calc!(false),
+        true => perform_calc!(true),
+    }
+
+    Ok(dists)
+}
+
+pub fn pdist_rayon<T, M>(
+    x: &Vec<T>,
+    lx: &Layout<Ix2>,
+    mut kernel: M,
+    pool: Option<&rayon::ThreadPool>,
+) -> Result<Vec<M::Out>>
+where
+    T: Send + Sync,
+    M: MetricDistAPI<Vec<T>> + Send + Sync,
+    M::Out: Send + Sync,
+{
+    if pool.is_none() {
+        return pdist_serial(x, lx, kernel);
+    }
+    let pool = pool.unwrap();
+
+    let shape = lx.shape();
+    let stride = lx.stride();
+    let offset = lx.offset();
+
+    let k = shape[1];
+    let m = shape[0];
+    let dists = unsafe { uninitialized_vec::<M::Out>(m * m.saturating_sub(1) / 2)? };
+
+    kernel.initialize(x, lx, x, lx)?;
+
+    let strided = stride[1] != 1;
+
+    // rows have different number of pairs; rayon work-stealing handles the imbalance
+    macro_rules! perform_calc {
+        ($STRIDED: ident) => {
+            (0..m).into_par_iter().for_each(|i| {
+                for j in (i + 1)..m {
+                    let uv = (x, x);
+                    let offsets = (
+                        (offset as isize + i as isize * stride[0]) as usize,
+                        (offset as isize + j as isize * stride[0]) as usize,
+                    );
+                    let indices = (i, j);
+                    let strides = (stride[1], stride[1]);
+                    let dist = kernel.distance::<{ $STRIDED }>(uv, offsets, indices, strides, k);
+                    unsafe {
+                        let dist_ij = dists.as_ptr().add(condensed_index(m, i, j)) as *mut _;
+                        *dist_ij = dist;
+                    }
+                }
+            })
+        };
+    }
+
+    pool.install(|| match strided {
+        false => perform_calc!(false),
+        true => perform_calc!(true),
+    });
+
+    Ok(dists)
+}
+
+pub fn pdist_weighted_rayon<T, M>(
+    x: &Vec<T>,
+    lx: &Layout<Ix2>,
+    weights: &M::Weight,
+    mut kernel: M,
+    pool: Option<&rayon::ThreadPool>,
+) -> Result<Vec<M::Out>>
+where
+    T: Send + Sync,
+    M: MetricDistWeightedAPI<Vec<T>> + Send + Sync,
+    M::Weight: AsRef<[M::Out]> + Send + Sync,
+    M::Out: Float + Send + Sync,
+{
+    if pool.is_none() {
+        return pdist_weighted_serial(x, lx, weights, kernel);
+    }
+    let pool = pool.unwrap();
+
+    let shape = lx.shape();
+    let stride = lx.stride();
+    let offset = lx.offset();
+
+    let k = shape[1];
+    let m = shape[0];
+    let dists = unsafe { uninitialized_vec::<M::Out>(m * m.saturating_sub(1) / 2)? };
+
+    kernel.weighted_initialize(x, lx, x, lx, weights)?;
+
+    let strided = stride[1] != 1;
+    let weights_sum = weights.as_ref().iter().fold(M::Out::zero(), |acc, w| acc + *w);
+
+    macro_rules! perform_calc {
+        ($STRIDED: ident) => {
+            (0..m).into_par_iter().for_each(|i| {
+                for j in (i + 1)..m {
+                    let uv = (x, x);
+                    let offsets = (
+                        (offset as isize + i as isize * stride[0]) as usize,
+                        (offset as isize + j as isize * stride[0]) as usize,
+                    );
+                    let indices = (i, j);
+                    let strides = (stride[1], stride[1]);
+                    let dist = kernel.weighted_distance::<{ $STRIDED }>(
+                        uv,
+                        offsets,
+                        indices,
+                        strides,
+                        k,
+                        weights,
+                        weights_sum,
+                    );
+                    unsafe {
+                        let dist_ij = dists.as_ptr().add(condensed_index(m, i, j)) as *mut _;
+                        *dist_ij = dist;
+                    }
+                }
+            })
+        };
+    }
+
+    pool.install(|| match strided {
+        false => perform_calc!(false),
+        true => perform_calc!(true),
+    });
+
+    Ok(dists)
+}
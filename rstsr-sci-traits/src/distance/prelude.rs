@@ -1,10 +1,12 @@
 pub mod rstsr_traits {
     pub use crate::distance::metric::{MetricDistAPI, MetricDistWeightedAPI};
-    pub use crate::distance::traits::CDistAPI;
+    pub use crate::distance::squareform::SquareFormAPI;
+    pub use crate::distance::traits::{CDistAPI, PDistAPI};
 }
 
 pub mod rstsr_funcs {
-    pub use crate::distance::traits::{cdist, cdist_f};
+    pub use crate::distance::squareform::{squareform, squareform_f};
+    pub use crate::distance::traits::{cdist, cdist_f, pdist, pdist_f};
 }
 
 pub mod rstsr_structs {
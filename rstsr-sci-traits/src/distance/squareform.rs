@@ -0,0 +1,203 @@
+//! Conversion between condensed and square distance matrices.
+
+use num::Num;
+use rstsr_core::prelude_dev::*;
+
+pub trait SquareFormAPI<Inp> {
+    type Out;
+
+    fn squareform_f(self) -> Result<Self::Out>;
+    fn squareform(self) -> Self::Out
+    where
+        Self: Sized,
+    {
+        Self::squareform_f(self).rstsr_unwrap()
+    }
+}
+
+/// Convert condensed distance vector to square distance matrix, and vice versa.
+///
+/// - 1-D input of length `n * (n - 1) / 2` is the condensed vector (as returned by
+///   [`pdist`](crate::distance::traits::pdist)); output is the symmetric `(n, n)` matrix with zero
+///   diagonal.
+/// - 2-D input of shape `(n, n)` is the square matrix; output is the condensed vector. Only the
+///   strict upper triangle is read; symmetry of the input is not checked.
+///
+/// For dynamic dimension input, the direction of conversion is decided by number of dimensions.
+///
+/// # See also
+///
+/// - [SciPy: `scipy.spatial.distance.squareform`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.spatial.distance.squareform.html)
+pub fn squareform<Args, Inp>(args: Args) -> Args::Out
+where
+    Args: SquareFormAPI<Inp>,
+{
+    args.squareform()
+}
+
+pub fn squareform_f<Args, Inp>(args: Args) -> Result<Args::Out>
+where
+    Args: SquareFormAPI<Inp>,
+{
+    args.squareform_f()
+}
+
+fn squareform_to_condensed_f<T, B>(x: TensorView<'_, T, B, Ix2>) -> Result<Tensor<T, B, Ix1>>
+where
+    T: Clone,
+    B: DeviceAPI<T> + OpPackTriAPI<T> + DeviceCreationAnyAPI<T>,
+{
+    rstsr_assert_eq!(x.shape()[0], x.shape()[1], InvalidLayout, "Distance matrix should be square for squareform.")?;
+    let n = x.shape()[0];
+    if n < 2 {
+        // SAFETY: tensor has no elements
+        return unsafe { empty_f(([0], x.device())) }?.into_dim_f::<Ix1>();
+    }
+
+    // strict upper triangle of x is the (non-strict) upper triangle of x[..n-1, 1..]
+    let x = x.into_slice_f((..n - 1, 1..))?.into_dim_f::<Ix2>()?;
+    match x.device().default_order() {
+        RowMajor => x.pack_triu_f(),
+        // column-major packing goes column-wise; transpose to get row-wise order of upper triangle
+        ColMajor => x.t().pack_tril_f(),
+    }
+}
+
+fn squareform_to_square_f<T, B>(v: TensorView<'_, T, B, Ix1>) -> Result<Tensor<T, B, Ix2>>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T>
+        + OpUnpackTriAPI<T>
+        + DeviceRawAPI<MaybeUninit<T>>
+        + DeviceCreationNumAPI<T>
+        + DeviceCreationTriAPI<T>
+        + DeviceCreationAnyAPI<T>
+        + OpAssignArbitaryAPI<T, Ix2, Ix2>
+        + OpAssignAPI<T, Ix2>,
+    for<'a, 'b> TensorView<'a, T, B, Ix2>: TensorAddAPI<TensorView<'b, T, B, Ix2>, Output = Tensor<T, B, Ix2>>,
+{
+    let n_condensed = v.shape()[0];
+    let n = ((1.0 + (1.0 + 8.0 * n_condensed as f64).sqrt()) / 2.0).round() as usize;
+    rstsr_assert_eq!(
+        n * (n - 1) / 2,
+        n_condensed,
+        InvalidLayout,
+        "Length of condensed distance vector should be n * (n - 1) / 2 for squareform."
+    )?;
+    let device = v.device().clone();
+    let mut out = zeros_f(([n, n], &device))?.into_dim_f::<Ix2>()?;
+    if n < 2 {
+        return Ok(out);
+    }
+
+    // unpacked symmetric matrix is the upper triangle of out[..n-1, 1..]
+    let sym = match device.default_order() {
+        RowMajor => v.unpack_triu_f(FlagSymm::Sy)?,
+        ColMajor => v.unpack_tril_f(FlagSymm::Sy)?,
+    };
+    out.slice_mut_f((..n - 1, 1..))?.into_dim_f::<Ix2>()?.assign_f(&sym)?;
+    triu_f((out.view_mut(), 1))?;
+    add_f(out.view(), out.t())
+}
+
+impl<T, B> SquareFormAPI<()> for TensorView<'_, T, B, Ix2>
+where
+    T: Clone,
+    B: DeviceAPI<T> + OpPackTriAPI<T> + DeviceCreationAnyAPI<T>,
+{
+    type Out = Tensor<T, B, Ix1>;
+
+    fn squareform_f(self) -> Result<Self::Out> {
+        squareform_to_condensed_f(self)
+    }
+}
+
+impl<T, B> SquareFormAPI<()> for TensorView<'_, T, B, Ix1>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T>
+        + OpUnpackTriAPI<T>
+        + DeviceRawAPI<MaybeUninit<T>>
+        + DeviceCreationNumAPI<T>
+        + DeviceCreationTriAPI<T>
+        + DeviceCreationAnyAPI<T>
+        + OpAssignArbitaryAPI<T, Ix2, Ix2>
+        + OpAssignAPI<T, Ix2>,
+    for<'a, 'b> TensorView<'a, T, B, Ix2>: TensorAddAPI<TensorView<'b, T, B, Ix2>, Output = Tensor<T, B, Ix2>>,
+{
+    type Out = Tensor<T, B, Ix2>;
+
+    fn squareform_f(self) -> Result<Self::Out> {
+        squareform_to_square_f(self)
+    }
+}
+
+impl<T, B> SquareFormAPI<()> for TensorView<'_, T, B, IxD>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T>
+        + OpPackTriAPI<T>
+        + OpUnpackTriAPI<T>
+        + DeviceRawAPI<MaybeUninit<T>>
+        + DeviceCreationNumAPI<T>
+        + DeviceCreationTriAPI<T>
+        + DeviceCreationAnyAPI<T>
+        + OpAssignArbitaryAPI<T, Ix2, Ix2>
+        + OpAssignAPI<T, Ix2>,
+    for<'a, 'b> TensorView<'a, T, B, Ix2>: TensorAddAPI<TensorView<'b, T, B, Ix2>, Output = Tensor<T, B, Ix2>>,
+{
+    type Out = Tensor<T, B, IxD>;
+
+    fn squareform_f(self) -> Result<Self::Out> {
+        match self.ndim() {
+            1 => Ok(squareform_to_square_f(self.into_dim_f::<Ix1>()?)?.into_dim()),
+            2 => Ok(squareform_to_condensed_f(self.into_dim_f::<Ix2>()?)?.into_dim()),
+            _ => rstsr_raise!(InvalidLayout, "squareform only accepts 1-D or 2-D tensor, got {}-D.", self.ndim()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distance::metric::*;
+    use crate::distance::traits::{cdist, pdist};
+
+    #[test]
+    fn test_pdist_squareform() {
+        let device = DeviceCpuSerial::default();
+        let a = linspace((0., 1., 28, &device)).mapv(|x: f64| (7.0 * x).sin()).into_shape((7, 4));
+
+        let d = pdist(a.view());
+        let d_full = cdist((a.view(), a.view()));
+        assert_eq!(d.shape(), &[21]);
+        assert!(allclose_f64(&squareform(d.view()), &d_full));
+        assert!(allclose_f64(&squareform(d_full.view()), &d));
+
+        // strided input and weighted kernel
+        let b = a.t();
+        let w = asarray((vec![1.5, 1.2, 0.7, 1.3, 0.4, 0.9, 1.1], &device));
+        let d = pdist((b.view(), MetricCityBlock, w.view()));
+        let d_full = cdist((b.view(), b.view(), MetricCityBlock, w.view()));
+        assert!(allclose_f64(&squareform(d.view()), &d_full));
+
+        // dynamic dimension
+        let d = pdist((a.view(), MetricChebyshev)).into_dim::<IxD>();
+        let d_full = squareform(d.view());
+        assert_eq!(d_full.shape(), &[7, 7]);
+        assert!(allclose_f64(&squareform(d_full.view()), &d));
+
+        // column-major device
+        let mut device_f = device.clone();
+        device_f.set_default_order(ColMajor);
+        let a = a.to_device(&device_f);
+        let d_f = pdist(a.view());
+        assert!(allclose_f64(&d_f.to_device(&device), &pdist(a.to_device(&device).view())));
+        assert!(allclose_f64(&squareform(d_f.view()), &cdist((a.view(), a.view()))));
+        assert!(allclose_f64(&squareform(squareform(d_f.view()).view()), &d_f));
+
+        // trivial cases
+        assert_eq!(squareform(asarray((Vec::<f64>::new(), &device)).view()).shape(), &[1, 1]);
+        assert_eq!(squareform(asarray((vec![0.0], [1, 1], &device)).into_dim::<Ix2>().view()).shape(), &[0]);
+    }
+}
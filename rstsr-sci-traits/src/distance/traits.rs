@@ -25,3 +25,35 @@ where
 {
     args.cdist_f()
 }
+
+/// Pairwise distances between observations of one point set.
+///
+/// Output is the condensed distance vector (upper triangle of the distance matrix without diagonal,
+/// row-wise), with `m * (m - 1) / 2` elements for `m` observations. Use
+/// [`squareform`](crate::distance::squareform::squareform) to convert it to the square distance
+/// matrix.
+pub trait PDistAPI<Inp> {
+    type Out;
+
+    fn pdist_f(self) -> Result<Self::Out>;
+    fn pdist(self) -> Self::Out
+    where
+        Self: Sized,
+    {
+        Self::pdist_f(self).rstsr_unwrap()
+    }
+}
+
+pub fn pdist<Args, Inp>(args: Args) -> Args::Out
+where
+    Args: PDistAPI<Inp>,
+{
+    args.pdist()
+}
+
+pub fn pdist_f<Args, Inp>(args: Args) -> Result<Args::Out>
+where
+    Args: PDistAPI<Inp>,
+{
+    args.pdist_f()
+}
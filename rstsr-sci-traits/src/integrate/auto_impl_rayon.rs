@@ -1,13 +1,50 @@
-use crate::prelude_dev::*;
-use rstsr_sci_traits::integrate::lebedev::*;
+/// Rayon-parallel `integrate` for CPU device `$Device` with generic parameters
+/// `$G` and their bounds, shared by `DeviceFaer` and `DeviceBLAS<B>`.
+macro_rules! impl_integrate_rayon {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use crate::prelude_dev::*;
+            use rstsr_sci_traits::integrate::lebedev::*;
+            use rstsr_sci_traits::integrate::partition::*;
 
-impl LebedevRuleAPI for DeviceRayonAutoImpl {
-    fn lebedev_rule_f(&self, n: usize) -> Result<LebedevQuad<Self>> {
-        let degree = lebedev_order_to_degree(n).map_err(|_| rstsr_error!(InvalidValue, "Invalid Lebedev order {n}"))?;
-        let (quads, weights) = lebedev_make_angular_grid(degree)?;
-        let ngrids = weights.len();
-        let quads = asarray((quads, [ngrids, 3].c(), self));
-        let weights = asarray((weights, [ngrids].c(), self));
-        Ok(LebedevQuad { quads, weights })
-    }
+            impl<$($G,)*> LebedevRuleAPI for $Device
+            where
+                $($bound)*
+            {
+                fn lebedev_rule_f(&self, n: usize) -> Result<LebedevQuad<Self>> {
+                    let degree = lebedev_order_to_degree(n).map_err(|_| rstsr_error!(InvalidValue, "Invalid Lebedev order {n}"))?;
+                    let (quads, weights) = lebedev_make_angular_grid(degree)?;
+                    let ngrids = weights.len();
+                    let quads = asarray((quads, [ngrids, 3].c(), self));
+                    let weights = asarray((weights, [ngrids].c(), self));
+                    Ok(LebedevQuad { quads, weights })
+                }
+            }
+
+            impl<$($G,)*> MolecularGridAPI for $Device
+            where
+                $($bound)*
+            {
+                fn molecular_grid_f(
+                    &self,
+                    atom_coords: &[f64],
+                    charges: &[usize],
+                    config: &MolecularGridConfig,
+                ) -> Result<MolecularGrid<Self>> {
+                    let (coords, weights, grid_atoms) = atomic_grids_raw(atom_coords, charges, config)?;
+                    let adjust = atomic_radii_adjust(charges, config.atom_size_adjust);
+                    let pool = self.get_current_pool();
+                    let scheme = config.partition_scheme;
+                    let partition = partition_weights_rayon(atom_coords, &coords, &grid_atoms, scheme, adjust.as_deref(), pool);
+                    let weights = izip!(weights, partition).map(|(w, p)| w * p).collect::<Vec<f64>>();
+                    let ngrids = weights.len();
+                    let coords = asarray((coords, [ngrids, 3].c(), self));
+                    let weights = asarray((weights, [ngrids].c(), self));
+                    Ok(MolecularGrid { coords, weights })
+                }
+            }
+        };
+    };
 }
+
+pub(crate) use impl_integrate_rayon;
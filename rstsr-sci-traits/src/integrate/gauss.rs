@@ -0,0 +1,569 @@
+//! Gaussian quadrature rules of classical orthogonal polynomials.
+//!
+//! Nodes are obtained by Golub-Welsch algorithm (eigenvalues of symmetric tridiagonal Jacobi
+//! matrix), and then refined by Newton iteration on the three-term recurrence, which keeps nodes
+//! accurate for high orders. Weights are evaluated as Christoffel numbers `1 / Σ_k q_k(x)²` of
+//! orthonormal polynomials `q_k`, instead of eigenvectors.
+//!
+//! All nodes are returned in ascending order.
+//!
+//! # See also
+//!
+//! [SciPy implementation](https://github.com/scipy/scipy/blob/v1.15.3/scipy/special/_orthogonal.py)
+//!
+//! # References
+//!
+//! G.H. Golub, J.H. Welsch, Math. Comp. 23, 221 (1969); DOI:10.1090/S0025-5718-69-99647-1
+
+use core::f64::consts::PI;
+use rstsr_core::prelude_dev::*;
+
+/* #region three-term recurrence */
+
+/// Eigenvalues of symmetric tridiagonal matrix by implicit QL algorithm.
+///
+/// This is kept local instead of calling `eigh` of `rstsr-linalg-traits`: quadrature rules are
+/// plain `Vec<f64>` functions that should also work for devices without LAPACK (e.g.
+/// `DeviceCpuSerial`), and only eigenvalues of a tridiagonal matrix are needed (`O(n²)`, compared
+/// to `O(n³)` of dense `eigh`). Nodes are refined by Newton iteration afterwards, so accuracy of
+/// this routine only affects the initial guess.
+///
+/// `d` is the diagonal and overwritten by unsorted eigenvalues; `e[i]` is the off-diagonal element
+/// between `i` and `i + 1` (last element is not referenced), and is destroyed on exit.
+fn tridiagonal_eigvalsh(d: &mut [f64], e: &mut [f64]) -> Result<()> {
+    let n = d.len();
+    if n == 0 {
+        return Ok(());
+    }
+    e[n - 1] = 0.0;
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m < n - 1 {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            rstsr_assert!(iter <= 60, RuntimeError, "Tridiagonal eigenvalue iteration does not converge.")?;
+
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut deflated = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    deflated = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+            }
+            if deflated {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+    Ok(())
+}
+
+/// Gaussian quadrature of orthogonal polynomials defined by recurrence coefficients.
+///
+/// Monic polynomials satisfy `p_{k+1}(x) = (x - a_k) p_k(x) - b_k p_{k-1}(x)`.
+///
+/// # Arguments
+///
+/// * `a` - Coefficients `a_0, ..., a_{n-1}`.
+/// * `sqrt_b` - Square root of coefficients `b_1, ..., b_n` (`b_n` is only used in Newton
+///   refinement).
+/// * `mu0` - Integral of weight function over the interval.
+pub fn gauss_quadrature_from_recurrence(a: &[f64], sqrt_b: &[f64], mu0: f64) -> Result<(Vec<f64>, Vec<f64>)> {
+    let n = a.len();
+    rstsr_assert_eq!(sqrt_b.len(), n, InvalidLayout, "Recurrence coefficients a and b should have the same length.")?;
+
+    // Golub-Welsch: eigenvalues of Jacobi matrix
+    let mut nodes = a.to_vec();
+    let mut e = sqrt_b.to_vec();
+    tridiagonal_eigvalsh(&mut nodes, &mut e)?;
+    nodes.sort_by(|x, y| x.total_cmp(y));
+
+    // orthonormal polynomials q_k(x), derivative of q_n(x), and log of Christoffel number
+    // to avoid overflow at high orders, values are rescaled, and the logarithm of scale is tracked
+    let q0 = 1.0 / mu0.sqrt();
+    let eval = |x: f64| -> (f64, f64, f64) {
+        let (mut q_prev, mut q) = (0.0, q0);
+        let (mut dq_prev, mut dq) = (0.0, 0.0);
+        let mut sum = q * q;
+        let mut log_scale = 0.0;
+        for k in 0..n {
+            let sb_prev = if k == 0 { 0.0 } else { sqrt_b[k - 1] };
+            let q_next = ((x - a[k]) * q - sb_prev * q_prev) / sqrt_b[k];
+            let dq_next = (q + (x - a[k]) * dq - sb_prev * dq_prev) / sqrt_b[k];
+            (q_prev, q, dq_prev, dq) = (q, q_next, dq, dq_next);
+            if k + 1 < n {
+                sum += q * q;
+            }
+            if q.abs() > 1e100 {
+                const SCALE: f64 = 1e-100;
+                (q_prev, q, dq_prev, dq) = (q_prev * SCALE, q * SCALE, dq_prev * SCALE, dq * SCALE);
+                sum *= SCALE * SCALE;
+                log_scale -= SCALE.ln();
+            }
+        }
+        (q, dq, -sum.ln() - 2.0 * log_scale)
+    };
+
+    // Newton refinement of nodes
+    for x in nodes.iter_mut() {
+        for _ in 0..3 {
+            let (q, dq, _) = eval(*x);
+            let dx = q / dq;
+            if !dx.is_finite() {
+                break;
+            }
+            *x -= dx;
+            if dx.abs() <= f64::EPSILON * x.abs().max(1.0) {
+                break;
+            }
+        }
+    }
+    let weights = nodes.iter().map(|&x| eval(x).2.exp()).collect();
+    Ok((nodes, weights))
+}
+
+/* #endregion */
+
+/* #region classical rules */
+
+/// Gauss-Legendre nodes and weights on `[-1, 1]` with weight function `1`.
+pub fn roots_legendre_raw(n: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of quadrature points should be positive.")?;
+    let a = vec![0.0; n];
+    let sqrt_b = (1..=n).map(|k| k as f64 / ((4 * k * k - 1) as f64).sqrt()).collect::<Vec<f64>>();
+    let (mut x, w) = gauss_quadrature_from_recurrence(&a, &sqrt_b, 2.0)?;
+    symmetrize_nodes(&mut x);
+    Ok((x, w))
+}
+
+/// Generalized Gauss-Laguerre nodes and weights on `[0, inf)` with weight function `x^α e^{-x}`.
+pub fn roots_genlaguerre_raw(n: usize, alpha: f64) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of quadrature points should be positive.")?;
+    rstsr_assert!(alpha > -1.0, InvalidValue, "Parameter alpha should be larger than -1.")?;
+    let a = (0..n).map(|k| 2.0 * k as f64 + alpha + 1.0).collect::<Vec<f64>>();
+    let sqrt_b = (1..=n).map(|k| (k as f64 * (k as f64 + alpha)).sqrt()).collect::<Vec<f64>>();
+    gauss_quadrature_from_recurrence(&a, &sqrt_b, (alpha + 1.0).ext_gamma())
+}
+
+/// Gauss-Laguerre nodes and weights on `[0, inf)` with weight function `e^{-x}`.
+pub fn roots_laguerre_raw(n: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+    roots_genlaguerre_raw(n, 0.0)
+}
+
+/// Gauss-Hermite (physicists') nodes and weights on `(-inf, inf)` with weight function `e^{-x²}`.
+pub fn roots_hermite_raw(n: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of quadrature points should be positive.")?;
+    let a = vec![0.0; n];
+    let sqrt_b = (1..=n).map(|k| (k as f64 / 2.0).sqrt()).collect::<Vec<f64>>();
+    let (mut x, w) = gauss_quadrature_from_recurrence(&a, &sqrt_b, PI.sqrt())?;
+    symmetrize_nodes(&mut x);
+    Ok((x, w))
+}
+
+/// Gauss-Chebyshev (first kind) nodes and weights on `[-1, 1]` with weight function
+/// `1 / sqrt(1 - x²)`.
+///
+/// Nodes and weights are evaluated in closed form.
+pub fn roots_chebyt_raw(n: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of quadrature points should be positive.")?;
+    let mut x = (0..n).rev().map(|k| ((2 * k + 1) as f64 * PI / (2 * n) as f64).cos()).collect::<Vec<f64>>();
+    symmetrize_nodes(&mut x);
+    Ok((x, vec![PI / n as f64; n]))
+}
+
+/// Gauss-Jacobi nodes and weights on `[-1, 1]` with weight function `(1 - x)^α (1 + x)^β`.
+pub fn roots_jacobi_raw(n: usize, alpha: f64, beta: f64) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of quadrature points should be positive.")?;
+    rstsr_assert!(alpha > -1.0 && beta > -1.0, InvalidValue, "Parameters alpha and beta should be larger than -1.")?;
+    let ab = alpha + beta;
+    let a = (0..n)
+        .map(|k| match k {
+            0 => (beta - alpha) / (ab + 2.0),
+            _ => {
+                let s = 2.0 * k as f64 + ab;
+                (beta * beta - alpha * alpha) / (s * (s + 2.0))
+            },
+        })
+        .collect::<Vec<f64>>();
+    let sqrt_b = (1..=n)
+        .map(|k| {
+            let kf = k as f64;
+            let s = 2.0 * kf + ab;
+            let b = match k {
+                // (1 + α + β) cancels, which is singular when α + β = -1
+                1 => 4.0 * (1.0 + alpha) * (1.0 + beta) / (s * s * (s + 1.0)),
+                _ => 4.0 * kf * (kf + alpha) * (kf + beta) * (kf + ab) / (s * s * (s + 1.0) * (s - 1.0)),
+            };
+            b.sqrt()
+        })
+        .collect::<Vec<f64>>();
+    let log_mu0 =
+        (ab + 1.0) * 2.0_f64.ln() + (alpha + 1.0).ext_lgamma() + (beta + 1.0).ext_lgamma() - (ab + 2.0).ext_lgamma();
+    let (mut x, w) = gauss_quadrature_from_recurrence(&a, &sqrt_b, log_mu0.exp())?;
+    if alpha == beta {
+        symmetrize_nodes(&mut x);
+    }
+    Ok((x, w))
+}
+
+/// Enforce exact symmetry of nodes for symmetric weight functions.
+fn symmetrize_nodes(x: &mut [f64]) {
+    let n = x.len();
+    for i in 0..n / 2 {
+        let v = 0.5 * (x[n - 1 - i] - x[i]);
+        x[i] = -v;
+        x[n - 1 - i] = v;
+    }
+    if n % 2 == 1 {
+        x[n / 2] = 0.0;
+    }
+}
+
+/// Tensor-product of 1-D quadrature rules.
+///
+/// Returns nodes (row-major, npts × ndim, last dimension varies fastest) and weights (npts).
+pub fn quadrature_product_raw(rules: &[(&[f64], &[f64])]) -> (Vec<f64>, Vec<f64>) {
+    let ndim = rules.len();
+    let npts = rules.iter().map(|(x, _)| x.len()).product::<usize>();
+    let mut nodes = Vec::with_capacity(npts * ndim);
+    let mut weights = Vec::with_capacity(npts);
+    if ndim == 0 {
+        return (nodes, weights);
+    }
+    let mut index = vec![0; ndim];
+    for _ in 0..npts {
+        let mut w = 1.0;
+        for (d, &i) in index.iter().enumerate() {
+            nodes.push(rules[d].0[i]);
+            w *= rules[d].1[i];
+        }
+        weights.push(w);
+        // increase multi-index, last dimension first
+        for d in (0..ndim).rev() {
+            index[d] += 1;
+            if index[d] < rules[d].0.len() {
+                break;
+            }
+            index[d] = 0;
+        }
+    }
+    (nodes, weights)
+}
+
+/* #endregion */
+
+/* #region tensor interface */
+
+/// Gaussian quadrature rule data structure.
+///
+/// * `nodes`: A tensor of shape (n,) representing the nodes, or shape (n, ndim) for tensor-product
+///   rules (c-contiguous).
+/// * `weights`: A tensor of shape (n,) representing the weights.
+pub struct GaussQuad<B>
+where
+    B: DeviceAPI<f64>,
+{
+    pub nodes: Tensor<f64, B, IxD>,
+    pub weights: Tensor<f64, B, IxD>,
+}
+
+impl<B> From<GaussQuad<B>> for (Tensor<f64, B, IxD>, Tensor<f64, B, IxD>)
+where
+    B: DeviceAPI<f64>,
+{
+    fn from(value: GaussQuad<B>) -> Self {
+        (value.nodes, value.weights)
+    }
+}
+
+fn gauss_quad_f<B>((nodes, weights): (Vec<f64>, Vec<f64>), device: &B) -> Result<GaussQuad<B>>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    let n = weights.len();
+    let nodes = asarray_f((nodes, [n].c(), device))?;
+    let weights = asarray_f((weights, [n].c(), device))?;
+    Ok(GaussQuad { nodes, weights })
+}
+
+#[duplicate_item(
+    func                func_f                func_raw                doc_weight                         ;
+   [roots_legendre ] [roots_legendre_f ] [roots_legendre_raw ] ["Gauss-Legendre quadrature on `[-1, 1]` with weight function `1`."];
+   [roots_laguerre ] [roots_laguerre_f ] [roots_laguerre_raw ] ["Gauss-Laguerre quadrature on `[0, inf)` with weight function `e^{-x}`."];
+   [roots_hermite  ] [roots_hermite_f  ] [roots_hermite_raw  ] ["Gauss-Hermite (physicists') quadrature on `(-inf, inf)` with weight function `e^{-x²}`."];
+   [roots_chebyt   ] [roots_chebyt_f   ] [roots_chebyt_raw   ] ["Gauss-Chebyshev (first kind) quadrature on `[-1, 1]` with weight function `1 / sqrt(1 - x²)`."];
+)]
+#[doc = doc_weight]
+///
+/// # Arguments
+///
+/// * `n` - Number of quadrature points.
+pub fn func<B>(n: usize, device: &B) -> GaussQuad<B>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    func_f(n, device).rstsr_unwrap()
+}
+
+#[duplicate_item(
+    func_f                func_raw              ;
+   [roots_legendre_f ] [roots_legendre_raw ];
+   [roots_laguerre_f ] [roots_laguerre_raw ];
+   [roots_hermite_f  ] [roots_hermite_raw  ];
+   [roots_chebyt_f   ] [roots_chebyt_raw   ];
+)]
+pub fn func_f<B>(n: usize, device: &B) -> Result<GaussQuad<B>>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    gauss_quad_f(func_raw(n)?, device)
+}
+
+/// Generalized Gauss-Laguerre quadrature on `[0, inf)` with weight function `x^α e^{-x}`.
+///
+/// # Arguments
+///
+/// * `n` - Number of quadrature points.
+/// * `alpha` - Parameter α > -1.
+pub fn roots_genlaguerre<B>(n: usize, alpha: f64, device: &B) -> GaussQuad<B>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    roots_genlaguerre_f(n, alpha, device).rstsr_unwrap()
+}
+
+pub fn roots_genlaguerre_f<B>(n: usize, alpha: f64, device: &B) -> Result<GaussQuad<B>>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    gauss_quad_f(roots_genlaguerre_raw(n, alpha)?, device)
+}
+
+/// Gauss-Jacobi quadrature on `[-1, 1]` with weight function `(1 - x)^α (1 + x)^β`.
+///
+/// # Arguments
+///
+/// * `n` - Number of quadrature points.
+/// * `alpha`, `beta` - Parameters α > -1 and β > -1.
+pub fn roots_jacobi<B>(n: usize, alpha: f64, beta: f64, device: &B) -> GaussQuad<B>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    roots_jacobi_f(n, alpha, beta, device).rstsr_unwrap()
+}
+
+pub fn roots_jacobi_f<B>(n: usize, alpha: f64, beta: f64, device: &B) -> Result<GaussQuad<B>>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    gauss_quad_f(roots_jacobi_raw(n, alpha, beta)?, device)
+}
+
+/// Tensor-product of 1-D quadrature rules.
+///
+/// Returns nodes of shape (npts, ndim) (last dimension varies fastest, c-contiguous) and weights of
+/// shape (npts,), where `npts` is the product of numbers of points of all rules.
+///
+/// # Example
+///
+/// ```ignore
+/// let leg = roots_legendre(10, &device);
+/// let lag = roots_laguerre(20, &device);
+/// let (nodes, weights) = quadrature_product(&[&leg, &leg, &lag]).into();
+/// assert_eq!(nodes.shape(), &[2000, 3]);
+/// ```
+pub fn quadrature_product<B>(rules: &[&GaussQuad<B>]) -> GaussQuad<B>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignAPI<f64, Ix1>,
+{
+    quadrature_product_f(rules).rstsr_unwrap()
+}
+
+pub fn quadrature_product_f<B>(rules: &[&GaussQuad<B>]) -> Result<GaussQuad<B>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignAPI<f64, Ix1>,
+{
+    rstsr_assert!(!rules.is_empty(), InvalidValue, "At least one quadrature rule is required for product.")?;
+    let raw = rules
+        .iter()
+        .map(|rule| Ok((rule.nodes.to_raw_f()?, rule.weights.to_raw_f()?)))
+        .collect::<Result<Vec<(Vec<f64>, Vec<f64>)>>>()?;
+    let refs = raw.iter().map(|(x, w)| (x.as_slice(), w.as_slice())).collect::<Vec<_>>();
+    let (nodes, weights) = quadrature_product_raw(&refs);
+    let (npts, ndim) = (weights.len(), rules.len());
+    let device = rules[0].weights.device();
+    let nodes = asarray_f((nodes, [npts, ndim].c(), device))?;
+    let weights = asarray_f((weights, [npts].c(), device))?;
+    Ok(GaussQuad { nodes, weights })
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Check that rule integrates `x^k` exactly (up to `k = 2n - 1`) against reference moments.
+    fn check_moments(rule: &(Vec<f64>, Vec<f64>), moments: impl Fn(usize) -> f64, kmax: usize, tol: f64) {
+        let (x, w) = rule;
+        assert!(x.windows(2).all(|v| v[0] < v[1]));
+        for k in 0..=kmax {
+            let val: f64 = izip!(x, w).map(|(&x, &w)| w * x.powi(k as i32)).sum();
+            // odd moments vanish by cancellation, so tolerance is relative to magnitude of terms
+            let scale: f64 = izip!(x, w).map(|(&x, &w)| (w * x.powi(k as i32)).abs()).sum();
+            let refv = moments(k);
+            assert!((val - refv).abs() <= tol * scale.max(1.0), "k = {k}: {val} vs {refv}");
+        }
+    }
+
+    #[test]
+    fn test_classical_rules() {
+        let fact = |k: usize| (1..=k).map(|i| i as f64).product::<f64>();
+        let double_fact = |k: usize| (1..=k).rev().step_by(2).map(|i| i as f64).product::<f64>();
+
+        for n in [1, 2, 5, 20] {
+            // ∫_{-1}^{1} x^k dx
+            let legendre = |k: usize| if k % 2 == 1 { 0.0 } else { 2.0 / (k + 1) as f64 };
+            check_moments(&roots_legendre_raw(n).unwrap(), legendre, 2 * n - 1, 1e-13);
+            // ∫_0^inf x^k e^{-x} dx = k!
+            check_moments(&roots_laguerre_raw(n).unwrap(), fact, 2 * n - 1, 1e-10);
+            // ∫ x^k e^{-x²} dx = (k-1)!! √π / 2^{k/2}
+            let hermite = |k: usize| {
+                if k % 2 == 1 {
+                    0.0
+                } else {
+                    double_fact(k.saturating_sub(1)) * PI.sqrt() / 2.0_f64.powi(k as i32 / 2)
+                }
+            };
+            check_moments(&roots_hermite_raw(n).unwrap(), hermite, 2 * n - 1, 1e-12);
+            // ∫ x^k / sqrt(1 - x²) dx = π (k-1)!! / k!!
+            let chebyt =
+                |k: usize| if k % 2 == 1 { 0.0 } else { PI * double_fact(k.saturating_sub(1)) / double_fact(k) };
+            check_moments(&roots_chebyt_raw(n).unwrap(), chebyt, 2 * n - 1, 1e-13);
+            // Jacobi with α = β = -1/2 is Chebyshev of first kind
+            check_moments(&roots_jacobi_raw(n, -0.5, -0.5).unwrap(), chebyt, 2 * n - 1, 1e-12);
+            // Jacobi with α = 1, β = 0: ∫ x^k (1 - x) dx
+            let jacobi = |k: usize| legendre(k) - legendre(k + 1);
+            check_moments(&roots_jacobi_raw(n, 1.0, 0.0).unwrap(), jacobi, 2 * n - 1, 1e-12);
+            // generalized Laguerre: ∫ x^k x^{1/2} e^{-x} dx = Γ(k + 3/2)
+            let genlaguerre = |k: usize| (k as f64 + 1.5).ext_gamma();
+            check_moments(&roots_genlaguerre_raw(n, 0.5).unwrap(), genlaguerre, 2 * n - 1, 1e-10);
+        }
+
+        // high order: weights sum and symmetry
+        let (x, w) = roots_legendre_raw(500).unwrap();
+        assert!((w.iter().sum::<f64>() - 2.0).abs() < 1e-12);
+        assert!(izip!(&x, x.iter().rev()).all(|(a, b)| *a == -*b));
+        let (_, w) = roots_hermite_raw(300).unwrap();
+        assert!((w.iter().sum::<f64>() - PI.sqrt()).abs() < 1e-12);
+        let (x, w) = roots_laguerre_raw(200).unwrap();
+        assert!((w.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(x[0] > 0.0);
+    }
+
+    #[test]
+    fn test_large_order_nodes() {
+        // Gauss-Legendre: reference by Newton iteration on Legendre recurrence, from Tricomi's
+        // initial guess; weights are 2 / ((1 - x²) P'_n(x)²)
+        // weights near endpoints lose a few digits at high orders (about 1e-11 relative at n = 1000)
+        let legendre = |n: usize, x: f64| -> (f64, f64) {
+            let (mut p_prev, mut p) = (1.0, x);
+            for k in 1..n {
+                let kf = k as f64;
+                (p_prev, p) = (p, ((2.0 * kf + 1.0) * x * p - kf * p_prev) / (kf + 1.0));
+            }
+            let dp = n as f64 * (x * p - p_prev) / ((x - 1.0) * (x + 1.0));
+            (p, dp)
+        };
+        for n in [100, 500, 1000] {
+            let (x, w) = roots_legendre_raw(n).unwrap();
+            for k in 0..n {
+                let mut x_ref = -((4 * k + 3) as f64 * PI / (4 * n + 2) as f64).cos();
+                for _ in 0..100 {
+                    let (p, dp) = legendre(n, x_ref);
+                    x_ref -= p / dp;
+                    if (p / dp).abs() < 1e-17 {
+                        break;
+                    }
+                }
+                let (_, dp) = legendre(n, x_ref);
+                let w_ref = 2.0 / ((1.0 - x_ref) * (1.0 + x_ref) * dp * dp);
+                assert!((x[k] - x_ref).abs() < 1e-14, "n = {n}, k = {k}: {} vs {x_ref}", x[k]);
+                assert!((w[k] - w_ref).abs() < 1e-10 * w_ref, "n = {n}, k = {k}: {} vs {w_ref}", w[k]);
+            }
+        }
+
+        // Gauss-Jacobi of α = β = ±1/2 are Gauss-Chebyshev rules with closed form nodes and weights
+        for n in [200, 1000] {
+            let (x, w) = roots_jacobi_raw(n, -0.5, -0.5).unwrap();
+            let (x_ref, w_ref) = roots_chebyt_raw(n).unwrap();
+            assert!(izip!(&x, &x_ref).all(|(a, b)| (a - b).abs() < 1e-14));
+            assert!(izip!(&w, &w_ref).all(|(a, b)| (a - b).abs() < 1e-10 * b));
+
+            // Chebyshev of second kind: x_k = cos(kπ / (n + 1)), w_k = π / (n + 1) sin²(kπ / (n + 1))
+            let (x, w) = roots_jacobi_raw(n, 0.5, 0.5).unwrap();
+            for (k, (x, w)) in izip!(&x, &w).enumerate() {
+                let theta = (n - k) as f64 * PI / (n + 1) as f64;
+                let (x_ref, w_ref) = (theta.cos(), PI / (n + 1) as f64 * theta.sin().powi(2));
+                assert!((x - x_ref).abs() < 1e-14, "n = {n}, k = {k}: {x} vs {x_ref}");
+                assert!((w - w_ref).abs() < 1e-10 * w_ref, "n = {n}, k = {k}: {w} vs {w_ref}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_tensor_interface() {
+        let device = DeviceCpuSerial::default();
+        let (nodes, weights) = roots_legendre(8, &device).into();
+        assert_eq!(nodes.shape(), &[8]);
+        assert!((weights.sum() - 2.0).abs() < 1e-14);
+
+        // ∫∫ (x² y) over [-1, 1] x [0, inf) with e^{-y} = 2/3 * 1
+        let leg = roots_legendre(4, &device);
+        let lag = roots_laguerre(4, &device);
+        let prod = quadrature_product(&[&leg, &lag]);
+        assert_eq!(prod.nodes.shape(), &[16, 2]);
+        let val: f64 =
+            izip!(prod.nodes.raw().chunks_exact(2), prod.weights.raw()).map(|(p, &w)| w * p[0] * p[0] * p[1]).sum();
+        assert!((val - 2.0 / 3.0).abs() < 1e-13);
+        assert_eq!(prod.nodes.raw()[..4], [
+            leg.nodes.raw()[0],
+            lag.nodes.raw()[0],
+            leg.nodes.raw()[0],
+            lag.nodes.raw()[1]
+        ]);
+
+        assert!(roots_jacobi_f(4, -1.5, 0.0, &device).is_err());
+        assert!(roots_legendre_f(0, &device).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::prelude_dev::*;
 use rstsr_sci_traits::integrate::lebedev::*;
+use rstsr_sci_traits::integrate::partition::*;
 
 impl LebedevRuleAPI for DeviceCpuSerial {
     fn lebedev_rule_f(&self, n: usize) -> Result<LebedevQuad<Self>> {
@@ -11,3 +12,22 @@ impl LebedevRuleAPI for DeviceCpuSerial {
         Ok(LebedevQuad { quads, weights })
     }
 }
+
+impl MolecularGridAPI for DeviceCpuSerial {
+    fn molecular_grid_f(
+        &self,
+        atom_coords: &[f64],
+        charges: &[usize],
+        config: &MolecularGridConfig,
+    ) -> Result<MolecularGrid<Self>> {
+        let (coords, weights, grid_atoms) = atomic_grids_raw(atom_coords, charges, config)?;
+        let adjust = atomic_radii_adjust(charges, config.atom_size_adjust);
+        let partition =
+            partition_weights_serial(atom_coords, &coords, &grid_atoms, config.partition_scheme, adjust.as_deref());
+        let weights = izip!(weights, partition).map(|(w, p)| w * p).collect::<Vec<f64>>();
+        let ngrids = weights.len();
+        let coords = asarray((coords, [ngrids, 3].c(), self));
+        let weights = asarray((weights, [ngrids].c(), self));
+        Ok(MolecularGrid { coords, weights })
+    }
+}
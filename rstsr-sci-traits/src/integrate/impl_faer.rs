@@ -0,0 +1,22 @@
+use super::auto_impl_rayon::impl_integrate_rayon;
+
+impl_integrate_rayon!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude_dev::*;
+    use rstsr_sci_traits::integrate::partition::*;
+
+    #[test]
+    fn test_molecular_grid() {
+        let device = DeviceFaer::default();
+        let device_serial = DeviceCpuSerial::default();
+        let atom_coords = [0.0, 0.0, 0.0, 0.0, 1.43, 1.11, 0.0, -1.43, 1.11];
+        let charges = [8, 1, 1];
+        let config = MolecularGridConfig { partition_scheme: PartitionScheme::Stratmann, ..Default::default() };
+        let grid = device.molecular_grid(&atom_coords, &charges, &config);
+        let grid_serial = device_serial.molecular_grid(&atom_coords, &charges, &config);
+        assert_eq!(grid.coords.raw(), grid_serial.coords.raw());
+        assert_eq!(grid.weights.raw(), grid_serial.weights.raw());
+    }
+}
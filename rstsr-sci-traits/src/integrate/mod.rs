@@ -1,7 +1,14 @@
 pub mod prelude;
 
+pub mod gauss;
 pub mod lebedev;
+pub mod partition;
+pub mod radial;
 
+#[cfg(any(feature = "faer", feature = "blas"))]
+mod auto_impl_rayon;
+#[cfg(feature = "blas")]
+pub mod impl_blas;
 pub mod impl_cpu_serial;
 #[cfg(feature = "faer")]
 pub mod impl_faer;
@@ -0,0 +1,429 @@
+//! Fuzzy-cell partitioning and molecular integration grids.
+//!
+//! Molecular grid is the union of atom-centered grids (radial quadrature times Lebedev angular
+//! quadrature), where weights of each atomic grid are multiplied by the fuzzy-cell weight of the
+//! atom.
+//!
+//! # See also
+//!
+//! [PySCF implementation](https://github.com/pyscf/pyscf/blob/v2.9.0/pyscf/dft/gen_grid.py)
+//!
+//! # References
+//!
+//! A.D. Becke, J. Chem. Phys. 88, 2547 (1988); DOI:10.1063/1.454033
+//!
+//! R.E. Stratmann, G.E. Scuseria, M.J. Frisch, Chem. Phys. Lett. 257, 213 (1996);
+//! DOI:10.1016/0009-2614(96)00600-8
+
+use crate::integrate::lebedev::{lebedev_make_angular_grid, lebedev_order_to_degree};
+use crate::integrate::radial::{bragg_radius, radial_grid_raw, RadialScheme};
+use core::f64::consts::PI;
+use rayon::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region partition schemes */
+
+/// Fuzzy-cell partitioning scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PartitionScheme {
+    /// Becke cell function: three iterations of `f(x) = (3x - x³) / 2`.
+    #[default]
+    Becke,
+    /// Stratmann-Scuseria-Frisch cell function (eq. 14, a = 0.64).
+    Stratmann,
+}
+
+/// Atom-size adjustment of cell boundaries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AtomSizeAdjust {
+    /// No adjustment; cell boundaries are at midpoint of atoms.
+    None,
+    /// Becke adjustment with ratio of Bragg radii (appendix of Becke's paper).
+    Becke,
+    /// Treutler-Ahlrichs adjustment with ratio of square root of Bragg radii.
+    #[default]
+    Treutler,
+}
+
+impl PartitionScheme {
+    /// Cell function `g(ν)` of confocal elliptical coordinate `ν`; step function is `(1 - g) / 2`.
+    #[inline]
+    pub fn cell_function(&self, nu: f64) -> f64 {
+        match self {
+            PartitionScheme::Becke => {
+                let mut g = nu;
+                for _ in 0..3 {
+                    g = (3.0 - g * g) * g * 0.5;
+                }
+                g
+            },
+            PartitionScheme::Stratmann => {
+                const A: f64 = 0.64;
+                if nu <= -A {
+                    -1.0
+                } else if nu >= A {
+                    1.0
+                } else {
+                    let ma = nu / A;
+                    let ma2 = ma * ma;
+                    (1.0 / 16.0) * (ma * (35.0 + ma2 * (-35.0 + ma2 * (21.0 - 5.0 * ma2))))
+                }
+            },
+        }
+    }
+}
+
+/// Atom-size adjustment parameters `a_ij` (row-major, natm × natm); `None` for no adjustment.
+///
+/// Confocal elliptical coordinate is adjusted as `ν' = ν + a_ij (1 - ν²)`.
+pub fn atomic_radii_adjust(charges: &[usize], adjust: AtomSizeAdjust) -> Option<Vec<f64>> {
+    let rad: Vec<f64> = match adjust {
+        AtomSizeAdjust::None => return None,
+        AtomSizeAdjust::Becke => charges.iter().map(|&c| bragg_radius(c) + 1e-200).collect(),
+        AtomSizeAdjust::Treutler => charges.iter().map(|&c| bragg_radius(c).sqrt() + 1e-200).collect(),
+    };
+    let natm = charges.len();
+    let mut a = vec![0.0; natm * natm];
+    for i in 0..natm {
+        for j in 0..natm {
+            a[i * natm + j] = (0.25 * (rad[j] / rad[i] - rad[i] / rad[j])).clamp(-0.5, 0.5);
+        }
+    }
+    Some(a)
+}
+
+/* #endregion */
+
+/* #region native implementations */
+
+/// Fuzzy-cell weight of grid point `coord` belonging to atom `iatm`.
+#[inline]
+fn partition_point(
+    coord: &[f64],
+    iatm: usize,
+    atom_coords: &[f64],
+    inv_atom_dist: &[f64],
+    scheme: PartitionScheme,
+    adjust: Option<&[f64]>,
+    cell: &mut [f64],
+    grid_dist: &mut [f64],
+) -> f64 {
+    let natm = cell.len();
+    for a in 0..natm {
+        let d = &atom_coords[a * 3..a * 3 + 3];
+        grid_dist[a] = ((coord[0] - d[0]).powi(2) + (coord[1] - d[1]).powi(2) + (coord[2] - d[2]).powi(2)).sqrt();
+    }
+    cell.fill(1.0);
+    for i in 0..natm {
+        for j in 0..i {
+            let mut nu = (grid_dist[i] - grid_dist[j]) * inv_atom_dist[i * natm + j];
+            if let Some(a) = adjust {
+                nu += a[i * natm + j] * (1.0 - nu * nu);
+            }
+            let g = scheme.cell_function(nu);
+            cell[i] *= 0.5 * (1.0 - g);
+            cell[j] *= 0.5 * (1.0 + g);
+        }
+    }
+    let sum: f64 = cell.iter().sum();
+    match sum == 0.0 {
+        true => 0.0,
+        false => cell[iatm] / sum,
+    }
+}
+
+fn inverse_atom_distances(atom_coords: &[f64]) -> Vec<f64> {
+    let natm = atom_coords.len() / 3;
+    let mut inv = vec![0.0; natm * natm];
+    for i in 0..natm {
+        for j in 0..natm {
+            if i != j {
+                let (a, b) = (&atom_coords[i * 3..i * 3 + 3], &atom_coords[j * 3..j * 3 + 3]);
+                let r = ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+                inv[i * natm + j] = 1.0 / r;
+            }
+        }
+    }
+    inv
+}
+
+/// Fuzzy-cell weights of grid points.
+///
+/// # Arguments
+///
+/// * `atom_coords` - Atom coordinates (row-major, natm × 3).
+/// * `grid_coords` - Grid coordinates (row-major, ngrid × 3).
+/// * `grid_atoms` - Index of atom that each grid point belongs to.
+/// * `scheme` - Partitioning scheme.
+/// * `adjust` - Atom-size adjustment parameters from [`atomic_radii_adjust`].
+pub fn partition_weights_serial(
+    atom_coords: &[f64],
+    grid_coords: &[f64],
+    grid_atoms: &[usize],
+    scheme: PartitionScheme,
+    adjust: Option<&[f64]>,
+) -> Vec<f64> {
+    let natm = atom_coords.len() / 3;
+    let inv_atom_dist = inverse_atom_distances(atom_coords);
+    let mut cell = vec![0.0; natm];
+    let mut grid_dist = vec![0.0; natm];
+    izip!(grid_coords.chunks_exact(3), grid_atoms)
+        .map(|(coord, &iatm)| {
+            partition_point(coord, iatm, atom_coords, &inv_atom_dist, scheme, adjust, &mut cell, &mut grid_dist)
+        })
+        .collect()
+}
+
+/// Fuzzy-cell weights of grid points, parallelized over grid points.
+///
+/// See also [`partition_weights_serial`].
+pub fn partition_weights_rayon(
+    atom_coords: &[f64],
+    grid_coords: &[f64],
+    grid_atoms: &[usize],
+    scheme: PartitionScheme,
+    adjust: Option<&[f64]>,
+    pool: Option<&rayon::ThreadPool>,
+) -> Vec<f64> {
+    if pool.is_none() {
+        return partition_weights_serial(atom_coords, grid_coords, grid_atoms, scheme, adjust);
+    }
+    let pool = pool.unwrap();
+
+    let natm = atom_coords.len() / 3;
+    let inv_atom_dist = inverse_atom_distances(atom_coords);
+    pool.install(|| {
+        grid_coords
+            .par_chunks_exact(3)
+            .zip(grid_atoms.par_iter())
+            .map_init(
+                || (vec![0.0; natm], vec![0.0; natm]),
+                |(cell, grid_dist), (coord, &iatm)| {
+                    partition_point(coord, iatm, atom_coords, &inv_atom_dist, scheme, adjust, cell, grid_dist)
+                },
+            )
+            .collect()
+    })
+}
+
+/// Atom-centered grids (before partitioning) of all atoms.
+///
+/// Returns grid coordinates (row-major, ngrid × 3), weights (including `4π r²` factor), and index
+/// of atom that each grid point belongs to.
+#[allow(clippy::type_complexity)]
+pub fn atomic_grids_raw(
+    atom_coords: &[f64],
+    charges: &[usize],
+    config: &MolecularGridConfig,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<usize>)> {
+    let degree = lebedev_order_to_degree(config.lebedev_order)
+        .map_err(|_| rstsr_error!(InvalidValue, "Invalid Lebedev order {}", config.lebedev_order))?;
+    let (quads, ang_weights) = lebedev_make_angular_grid(degree)?;
+
+    let ngrid = charges.len() * config.nrad * degree;
+    let mut coords = Vec::with_capacity(ngrid * 3);
+    let mut weights = Vec::with_capacity(ngrid);
+    let mut grid_atoms = Vec::with_capacity(ngrid);
+    for (iatm, &charge) in charges.iter().enumerate() {
+        let center = &atom_coords[iatm * 3..iatm * 3 + 3];
+        let (rads, rad_weights) = radial_grid_raw(config.radial_scheme, config.nrad, charge)?;
+        for (&r, &wr) in izip!(&rads, &rad_weights) {
+            let wr = 4.0 * PI * r * r * wr;
+            for (quad, &wa) in izip!(quads.chunks_exact(3), &ang_weights) {
+                coords.extend(izip!(center, quad).map(|(&c, &q)| c + r * q));
+                weights.push(wr * wa);
+                grid_atoms.push(iatm);
+            }
+        }
+    }
+    Ok((coords, weights, grid_atoms))
+}
+
+/* #endregion */
+
+/* #region molecular grid */
+
+/// Configuration of molecular integration grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MolecularGridConfig {
+    /// Radial quadrature scheme.
+    pub radial_scheme: RadialScheme,
+    /// Number of radial grid points per atom.
+    pub nrad: usize,
+    /// Order of Lebedev angular grid (see
+    /// [`lebedev_rule`](crate::integrate::lebedev::lebedev_rule)).
+    pub lebedev_order: usize,
+    /// Fuzzy-cell partitioning scheme.
+    pub partition_scheme: PartitionScheme,
+    /// Atom-size adjustment of cell boundaries.
+    pub atom_size_adjust: AtomSizeAdjust,
+}
+
+impl Default for MolecularGridConfig {
+    /// 75 Treutler-Ahlrichs radial points, 302 Lebedev angular points, Becke partitioning with
+    /// Treutler atom-size adjustment.
+    fn default() -> Self {
+        Self {
+            radial_scheme: RadialScheme::TreutlerAhlrichs,
+            nrad: 75,
+            lebedev_order: 29,
+            partition_scheme: PartitionScheme::Becke,
+            atom_size_adjust: AtomSizeAdjust::Treutler,
+        }
+    }
+}
+
+/// Molecular integration grid data structure.
+///
+/// * `coords`: A tensor of shape (ngrid, 3) representing the grid coordinates (c-contiguous).
+/// * `weights`: A tensor of shape (ngrid,) representing the grid weights.
+pub struct MolecularGrid<B>
+where
+    B: DeviceAPI<f64>,
+{
+    pub coords: Tensor<f64, B, IxD>,
+    pub weights: Tensor<f64, B, IxD>,
+}
+
+impl<B> From<MolecularGrid<B>> for (Tensor<f64, B, IxD>, Tensor<f64, B, IxD>)
+where
+    B: DeviceAPI<f64>,
+{
+    fn from(value: MolecularGrid<B>) -> Self {
+        (value.coords, value.weights)
+    }
+}
+
+pub trait MolecularGridAPI
+where
+    Self: DeviceAPI<f64>,
+{
+    /// Generate molecular integration grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `atom_coords` - Atom coordinates in Bohr (row-major, natm × 3).
+    /// * `charges` - Nuclear charges of atoms.
+    /// * `config` - Configuration of grid.
+    fn molecular_grid_f(
+        &self,
+        atom_coords: &[f64],
+        charges: &[usize],
+        config: &MolecularGridConfig,
+    ) -> Result<MolecularGrid<Self>>;
+
+    /// Generate molecular integration grid.
+    fn molecular_grid(
+        &self,
+        atom_coords: &[f64],
+        charges: &[usize],
+        config: &MolecularGridConfig,
+    ) -> MolecularGrid<Self> {
+        self.molecular_grid_f(atom_coords, charges, config).rstsr_unwrap()
+    }
+}
+
+/// Molecular integration grid generation.
+///
+/// # Arguments
+///
+/// * `atom_coords` - Atom coordinates in Bohr, of shape (natm, 3).
+/// * `charges` - Nuclear charges of atoms.
+/// * `config` - Configuration of grid (radial scheme, number of radial and angular points,
+///   partitioning scheme and atom-size adjustment).
+///
+/// Grid points of all atoms are concatenated (atom by atom, radial-major). Partition weights are
+/// evaluated in parallel on devices with rayon.
+pub fn molecular_grid<B, D>(
+    atom_coords: TensorView<'_, f64, B, D>,
+    charges: &[usize],
+    config: &MolecularGridConfig,
+) -> MolecularGrid<B>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + MolecularGridAPI,
+    D: DimAPI + DimIntoAPI<Ix2>,
+{
+    molecular_grid_f(atom_coords, charges, config).rstsr_unwrap()
+}
+
+pub fn molecular_grid_f<B, D>(
+    atom_coords: TensorView<'_, f64, B, D>,
+    charges: &[usize],
+    config: &MolecularGridConfig,
+) -> Result<MolecularGrid<B>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + MolecularGridAPI,
+    D: DimAPI + DimIntoAPI<Ix2>,
+{
+    rstsr_assert_eq!(atom_coords.ndim(), 2, InvalidLayout, "atom_coords must be a 2D tensor")?;
+    let layout = atom_coords.layout().to_dim::<Ix2>()?;
+    let [natm, ncart] = *layout.shape();
+    rstsr_assert_eq!(ncart, 3, InvalidLayout, "atom_coords should be of shape (natm, 3)")?;
+    rstsr_assert_eq!(natm, charges.len(), InvalidLayout, "Number of atoms and charges mismatch")?;
+    let [s0, s1] = *layout.stride();
+    let offset = layout.offset() as isize;
+    let raw = atom_coords.raw();
+    let coords = (0..natm * 3)
+        .map(|n| raw[(offset + (n / 3) as isize * s0 + (n % 3) as isize * s1) as usize])
+        .collect::<Vec<f64>>();
+    atom_coords.device().molecular_grid_f(&coords, charges, config)
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cell_function() {
+        for scheme in [PartitionScheme::Becke, PartitionScheme::Stratmann] {
+            assert_eq!(scheme.cell_function(0.0), 0.0);
+            assert!((scheme.cell_function(1.0) - 1.0).abs() < 1e-14);
+            assert!((scheme.cell_function(-0.3) + scheme.cell_function(0.3)).abs() < 1e-14);
+        }
+        let a = atomic_radii_adjust(&[1, 8], AtomSizeAdjust::Becke).unwrap();
+        assert_eq!(a[0], 0.0);
+        assert_eq!(a[1], -a[2]);
+    }
+
+    #[test]
+    fn test_molecular_grid() {
+        let device = DeviceCpuSerial::default();
+        // water molecule in Bohr
+        let atom_coords = asarray((vec![0.0, 0.0, 0.0, 0.0, 1.43, 1.11, 0.0, -1.43, 1.11], [3, 3].c(), &device));
+        let charges = [8, 1, 1];
+
+        // sum of normalized gaussians on each atom, with different exponents
+        let exponents = [2.0, 0.7, 0.7];
+        let integrate = |grid: &MolecularGrid<DeviceCpuSerial>| {
+            let coords = grid.coords.raw();
+            let weights = grid.weights.raw();
+            izip!(coords.chunks_exact(3), weights)
+                .map(|(c, &w)| {
+                    let f: f64 = (0..3)
+                        .map(|a| {
+                            let alpha: f64 = exponents[a];
+                            let r2: f64 = (0..3).map(|x| (c[x] - atom_coords.raw()[a * 3 + x]).powi(2)).sum();
+                            (alpha / PI).powf(1.5) * (-alpha * r2).exp()
+                        })
+                        .sum();
+                    w * f
+                })
+                .sum::<f64>()
+        };
+
+        for radial_scheme in [RadialScheme::TreutlerAhlrichs, RadialScheme::MuraKnowles] {
+            for partition_scheme in [PartitionScheme::Becke, PartitionScheme::Stratmann] {
+                for atom_size_adjust in [AtomSizeAdjust::None, AtomSizeAdjust::Becke, AtomSizeAdjust::Treutler] {
+                    let config =
+                        MolecularGridConfig { radial_scheme, partition_scheme, atom_size_adjust, ..Default::default() };
+                    let grid = molecular_grid(atom_coords.view(), &charges, &config);
+                    assert_eq!(grid.coords.shape(), &[3 * 75 * 302, 3]);
+                    let val = integrate(&grid);
+                    assert!((val - 3.0).abs() < 1e-5, "{config:?} {val}");
+                }
+            }
+        }
+    }
+}
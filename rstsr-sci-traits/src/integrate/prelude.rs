@@ -1,13 +1,24 @@
 pub mod rstsr_traits {
     pub use crate::integrate::lebedev::LebedevRuleAPI;
+    pub use crate::integrate::partition::MolecularGridAPI;
 }
 
 pub mod rstsr_funcs {
+    pub use crate::integrate::gauss::{
+        quadrature_product, quadrature_product_f, roots_chebyt, roots_chebyt_f, roots_genlaguerre, roots_genlaguerre_f,
+        roots_hermite, roots_hermite_f, roots_jacobi, roots_jacobi_f, roots_laguerre, roots_laguerre_f, roots_legendre,
+        roots_legendre_f,
+    };
     pub use crate::integrate::lebedev::{
         lebedev_rule, lebedev_rule_f, lebedev_rule_from_degree, lebedev_rule_from_degree_f,
     };
+    pub use crate::integrate::partition::{molecular_grid, molecular_grid_f};
+    pub use crate::integrate::radial::{radial_grid, radial_grid_f};
 }
 
 pub mod rstsr_structs {
+    pub use crate::integrate::gauss::GaussQuad;
     pub use crate::integrate::lebedev::LebedevQuad;
+    pub use crate::integrate::partition::{AtomSizeAdjust, MolecularGrid, MolecularGridConfig, PartitionScheme};
+    pub use crate::integrate::radial::{RadialQuad, RadialScheme};
 }
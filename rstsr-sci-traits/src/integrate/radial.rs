@@ -0,0 +1,253 @@
+//! Radial quadratures for atom-centered integration grids.
+//!
+//! All radial grids integrate on `[0, inf)` with uniform weighting, i.e. `∫ f(r) dr ≈ Σ_i w_i
+//! f(r_i)`. For integration in spherical coordinates, factor `r²` should be multiplied by caller.
+//!
+//! # See also
+//!
+//! [PySCF implementation](https://github.com/pyscf/pyscf/blob/v2.9.0/pyscf/dft/radi.py)
+
+use core::f64::consts::PI;
+use rstsr_core::prelude_dev::*;
+
+/* #region atomic radii */
+
+/// Bohr radius in Angstrom.
+pub const BOHR: f64 = 0.52917721092;
+
+/// Bragg-Slater radii (in Angstrom) of elements H to Rn, index by nuclear charge (0 for ghost
+/// atom).
+///
+/// Radius of H is 0.35 Angstrom, as recommended by Becke.
+#[rustfmt::skip]
+pub const BRAGG_RADII_ANGSTROM: [f64; 87] = [
+    0.0,
+    0.35,                                                             1.40,
+    1.45, 1.05,                               0.85, 0.70, 0.65, 0.60, 0.50, 1.50,
+    1.80, 1.50,                               1.25, 1.10, 1.00, 1.00, 1.00, 1.80,
+    2.20, 1.80,
+    1.60, 1.40, 1.35, 1.40, 1.40, 1.40, 1.35, 1.35, 1.35, 1.35,
+                                              1.30, 1.25, 1.15, 1.15, 1.15, 1.90,
+    2.35, 2.00,
+    1.80, 1.55, 1.45, 1.45, 1.35, 1.30, 1.35, 1.40, 1.60, 1.55,
+                                              1.55, 1.45, 1.45, 1.40, 1.40, 2.10,
+    2.60, 2.15,
+    1.95, 1.85, 1.85, 1.85, 1.85, 1.85, 1.85,
+    1.80, 1.75, 1.75, 1.75, 1.75, 1.75, 1.75, 1.75,
+          1.55, 1.45, 1.35, 1.35, 1.30, 1.35, 1.35, 1.35, 1.50,
+                                              1.90, 1.80, 1.60, 1.90, 1.45, 2.10,
+];
+
+/// Bragg-Slater radius (in Bohr) of element with nuclear charge `charge`.
+///
+/// Elements heavier than Rn are assigned with 1.75 Angstrom.
+pub fn bragg_radius(charge: usize) -> f64 {
+    BRAGG_RADII_ANGSTROM.get(charge).copied().unwrap_or(1.75) / BOHR
+}
+
+/// Treutler-Ahlrichs ξ parameters of elements H to Kr, index by nuclear charge.
+///
+/// Other elements are assigned with ξ = 1.
+#[rustfmt::skip]
+pub const TREUTLER_AHLRICHS_XI: [f64; 37] = [
+    1.0,
+    0.8,                                                    0.9,
+    1.8, 1.4,                               1.3, 1.1, 0.9, 0.9, 0.9, 0.9,
+    1.4, 1.3,                               1.3, 1.2, 1.1, 1.0, 1.0, 1.0,
+    1.5, 1.4,
+    1.3, 1.2, 1.2, 1.2, 1.2, 1.2, 1.2, 1.1, 1.1, 1.1,
+                                            1.1, 1.0, 0.9, 0.9, 0.9, 0.9,
+];
+
+/* #endregion */
+
+/* #region radial schemes */
+
+/// Radial quadrature scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadialScheme {
+    /// Treutler-Ahlrichs M4 mapping of Chebyshev grid.
+    ///
+    /// Treutler, Ahlrichs, J. Chem. Phys. 102, 346 (1995); DOI:10.1063/1.469408
+    #[default]
+    TreutlerAhlrichs,
+    /// Mura-Knowles Log3 quadrature.
+    ///
+    /// Mura, Knowles, J. Chem. Phys. 104, 9848 (1996); DOI:10.1063/1.471749
+    MuraKnowles,
+    /// Gauss-Chebyshev quadrature of second kind with logarithmic mapping.
+    ///
+    /// Pérez-Jordá, San-Fabián, Moscardó, J. Chem. Phys. 108, 3226 (1998); DOI:10.1063/1.475719
+    GaussChebyshev,
+    /// Becke mapping of Gauss-Chebyshev grid (second kind), scaled by Bragg radius.
+    ///
+    /// Becke, J. Chem. Phys. 88, 2547 (1988); DOI:10.1063/1.454033
+    Becke,
+}
+
+fn radial_treutler_ahlrichs(n: usize, charge: usize) -> (Vec<f64>, Vec<f64>) {
+    let xi = TREUTLER_AHLRICHS_XI.get(charge).copied().unwrap_or(1.0);
+    let step = PI / (n + 1) as f64;
+    let ln2 = xi / 2.0_f64.ln();
+    // original order is descending in r; reversed to ascending
+    let (r, w) = (0..n)
+        .rev()
+        .map(|i| {
+            let theta = (i + 1) as f64 * step;
+            let x = theta.cos();
+            let r = -ln2 * (1.0 + x).powf(0.6) * ((1.0 - x) / 2.0).ln();
+            let w = step
+                * theta.sin()
+                * ln2
+                * (1.0 + x).powf(0.6)
+                * (-0.6 / (1.0 + x) * ((1.0 - x) / 2.0).ln() + 1.0 / (1.0 - x));
+            (r, w)
+        })
+        .unzip();
+    (r, w)
+}
+
+fn radial_mura_knowles(n: usize, charge: usize) -> (Vec<f64>, Vec<f64>) {
+    // 7 for alkali and alkaline earth metals (Li, Be, Na, Mg, K, Ca), otherwise 5.2
+    let far = match charge {
+        3 | 4 | 11 | 12 | 19 | 20 => 7.0,
+        _ => 5.2,
+    };
+    (0..n)
+        .map(|i| {
+            let x = (i as f64 + 0.5) / n as f64;
+            let r = -far * (1.0 - x.powi(3)).ln();
+            let w = far * 3.0 * x * x / ((1.0 - x.powi(3)) * n as f64);
+            (r, w)
+        })
+        .unzip()
+}
+
+fn radial_gauss_chebyshev(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let ln2 = 1.0 / 2.0_f64.ln();
+    let fac = 16.0 / 3.0 / (n + 1) as f64;
+    let x1 = (1..=n).map(|i| i as f64 * PI / (n + 1) as f64).collect::<Vec<f64>>();
+    let xi = (0..n)
+        .map(|i| {
+            (n as f64 - 1.0 - 2.0 * i as f64) / (n + 1) as f64
+                + (1.0 + 2.0 / 3.0 * x1[i].sin().powi(2)) * (2.0 * x1[i]).sin() / PI
+        })
+        .collect::<Vec<f64>>();
+    // symmetrize abscissas
+    let xi = (0..n).map(|i| (xi[i] - xi[n - 1 - i]) / 2.0).collect::<Vec<f64>>();
+    let r = xi.iter().map(|&x| 1.0 - (1.0 + x).ln() * ln2).collect();
+    let w = izip!(&xi, &x1).map(|(&x, &x1)| fac * x1.sin().powi(4) * ln2 / (1.0 + x)).collect();
+    (r, w)
+}
+
+fn radial_becke(n: usize, charge: usize) -> (Vec<f64>, Vec<f64>) {
+    let rm = match charge {
+        1 => bragg_radius(charge),
+        _ => bragg_radius(charge) * 0.5,
+    };
+    (1..=n)
+        .map(|i| {
+            let theta = i as f64 * PI / (n + 1) as f64;
+            let t = theta.cos();
+            // Gauss-Chebyshev weights of second kind, adjusted to uniform weighting
+            let w = PI / (n + 1) as f64 * theta.sin();
+            let r = (1.0 + t) / (1.0 - t) * rm;
+            let w = w * 2.0 / (1.0 - t).powi(2) * rm;
+            (r, w)
+        })
+        .unzip()
+}
+
+/// Radial grid points and weights of `n` points for atom of nuclear charge `charge`.
+///
+/// Returns `(r, w)`, where weights do not include the `r²` factor.
+pub fn radial_grid_raw(scheme: RadialScheme, n: usize, charge: usize) -> Result<(Vec<f64>, Vec<f64>)> {
+    rstsr_assert!(n > 0, InvalidValue, "Number of radial grids should be positive.")?;
+    let grid = match scheme {
+        RadialScheme::TreutlerAhlrichs => radial_treutler_ahlrichs(n, charge),
+        RadialScheme::MuraKnowles => radial_mura_knowles(n, charge),
+        RadialScheme::GaussChebyshev => radial_gauss_chebyshev(n),
+        RadialScheme::Becke => radial_becke(n, charge),
+    };
+    Ok(grid)
+}
+
+/* #endregion */
+
+/* #region tensor interface */
+
+/// Radial quadrature rule data structure.
+///
+/// * `rads`: A tensor of shape (n,) representing the radial grid points.
+/// * `weights`: A tensor of shape (n,) representing the radial weights (without `r²` factor).
+pub struct RadialQuad<B>
+where
+    B: DeviceAPI<f64>,
+{
+    pub rads: Tensor<f64, B, IxD>,
+    pub weights: Tensor<f64, B, IxD>,
+}
+
+impl<B> From<RadialQuad<B>> for (Tensor<f64, B, IxD>, Tensor<f64, B, IxD>)
+where
+    B: DeviceAPI<f64>,
+{
+    fn from(value: RadialQuad<B>) -> Self {
+        (value.rads, value.weights)
+    }
+}
+
+/// Radial quadrature rule generation.
+///
+/// # Arguments
+///
+/// * `scheme` - Radial quadrature scheme.
+/// * `n` - Number of radial grid points.
+/// * `charge` - Nuclear charge of atom (affects Treutler-Ahlrichs, Mura-Knowles and Becke schemes).
+pub fn radial_grid<B>(scheme: RadialScheme, n: usize, charge: usize, device: &B) -> RadialQuad<B>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    radial_grid_f(scheme, n, charge, device).rstsr_unwrap()
+}
+
+pub fn radial_grid_f<B>(scheme: RadialScheme, n: usize, charge: usize, device: &B) -> Result<RadialQuad<B>>
+where
+    B: DeviceAPI<f64> + DeviceCreationAnyAPI<f64>,
+{
+    let (rads, weights) = radial_grid_raw(scheme, n, charge)?;
+    let rads = asarray_f((rads, [n].c(), device))?;
+    let weights = asarray_f((weights, [n].c(), device))?;
+    Ok(RadialQuad { rads, weights })
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_radial_grid() {
+        // ∫ r² exp(-r²) dr = √π / 4
+        let reference = PI.sqrt() / 4.0;
+        for scheme in [
+            RadialScheme::TreutlerAhlrichs,
+            RadialScheme::MuraKnowles,
+            RadialScheme::GaussChebyshev,
+            RadialScheme::Becke,
+        ] {
+            for charge in [1, 8, 26] {
+                let (r, w) = radial_grid_raw(scheme, 75, charge).unwrap();
+                let val: f64 = izip!(&r, &w).map(|(&r, &w)| w * r * r * (-r * r).exp()).sum();
+                assert!((val - reference).abs() < 1e-8, "{scheme:?} {charge} {val}");
+            }
+        }
+
+        let device = DeviceCpuSerial::default();
+        let (rads, weights) = radial_grid(RadialScheme::TreutlerAhlrichs, 10, 6, &device).into();
+        assert_eq!(rads.shape(), &[10]);
+        assert_eq!(weights.shape(), &[10]);
+        assert!(rads.raw().windows(2).all(|x| x[0] < x[1]));
+    }
+}
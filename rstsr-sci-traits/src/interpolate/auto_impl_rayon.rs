@@ -0,0 +1,52 @@
+/// Rayon-parallel `interpolate` for CPU device `$Device` with generic parameters
+/// `$G` and their bounds, shared by `DeviceFaer` and `DeviceBLAS<B>`.
+macro_rules! impl_interpolate_rayon {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use crate::prelude_dev::*;
+            use rstsr_sci_traits::interpolate::native_impl::*;
+            use rstsr_sci_traits::interpolate::traits::InterpolateAPI;
+
+            impl<$($G,)*> InterpolateAPI for $Device
+            where
+                $($bound)*
+            {
+                fn interp_f(&self, x: &[f64], xp: &[f64], fp: &[f64], left: f64, right: f64) -> Result<Vec<f64>> {
+                    let pool = self.get_current_pool();
+                    Ok(interpolate_map_rayon(x, 1, 1, |x, out| out[0] = interp_point(x[0], xp, fp, left, right), pool))
+                }
+
+                fn cubic_spline_eval_f(
+                    &self,
+                    x: &[f64],
+                    knots: &[f64],
+                    coeffs: &[f64],
+                    nvals: usize,
+                    nu: usize,
+                ) -> Result<Vec<f64>> {
+                    rstsr_assert!(nvals > 0, InvalidValue, "Number of spline components should be positive.")?;
+                    let pool = self.get_current_pool();
+                    Ok(interpolate_map_rayon(x, 1, nvals, |x, out| cubic_spline_point(x[0], knots, coeffs, nu, out), pool))
+                }
+
+                fn regular_grid_eval_f(
+                    &self,
+                    xi: &[f64],
+                    grid: &[Vec<f64>],
+                    values: &[f64],
+                    nvals: usize,
+                    method: RegularGridMethod,
+                    fill_value: Option<f64>,
+                ) -> Result<Vec<f64>> {
+                    rstsr_assert!(nvals > 0, InvalidValue, "Number of grid components should be positive.")?;
+                    rstsr_assert_eq!(xi.len() % grid.len(), 0, InvalidLayout, "Query length should be a multiple of ndim.")?;
+                    let pool = self.get_current_pool();
+                    let f = |xi: &[f64], out: &mut [f64]| regular_grid_point(xi, grid, values, method, fill_value, out);
+                    Ok(interpolate_map_rayon(xi, grid.len(), nvals, f, pool))
+                }
+            }
+        };
+    };
+}
+
+pub(crate) use impl_interpolate_rayon;
@@ -0,0 +1,186 @@
+//! Cubic spline interpolation.
+//!
+//! # See also
+//!
+//! [SciPy `CubicSpline`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.interpolate.CubicSpline.html)
+
+use crate::interpolate::native_impl::{cubic_spline_coeffs, CubicSplineBoundary};
+use crate::interpolate::traits::{interpolate_to_vec_f, InterpolateAPI};
+use rstsr_core::prelude_dev::*;
+
+/// Piecewise cubic interpolant with continuous second derivative.
+///
+/// Values `y` may have trailing dimensions: for knots of length `n`, `y` is of shape
+/// `(n, ...)`, and each trailing component is interpolated independently (e.g. many radial
+/// functions on the same radial grid). Evaluation at query tensor of shape `S` gives output of
+/// shape `(S, ...)`.
+///
+/// Points outside of knots are extrapolated by the polynomial of the first or last interval.
+#[derive(Debug, Clone)]
+pub struct CubicSpline<B> {
+    knots: Vec<f64>,
+    coeffs: Vec<f64>,
+    trailing_shape: Vec<usize>,
+    boundary: CubicSplineBoundary,
+    device: B,
+}
+
+impl<B> CubicSpline<B>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD> + InterpolateAPI,
+{
+    /// Build cubic spline from knots `x` (1-D, strictly increasing, at least 2 points) and values
+    /// `y` (first dimension matches `x`).
+    pub fn new_f<DX, DY>(
+        x: TensorView<'_, f64, B, DX>,
+        y: TensorView<'_, f64, B, DY>,
+        boundary: CubicSplineBoundary,
+    ) -> Result<Self>
+    where
+        DX: DimAPI,
+        DY: DimAPI,
+    {
+        rstsr_assert_eq!(x.ndim(), 1, InvalidLayout, "x must be a 1D tensor")?;
+        rstsr_assert!(y.ndim() >= 1, InvalidLayout, "y must be at least 1D tensor")?;
+        rstsr_assert_eq!(x.shape()[0], y.shape()[0], InvalidLayout, "Length of x and first dimension of y mismatch")?;
+        rstsr_assert!(x.size() >= 2, InvalidValue, "At least two knots are required for cubic spline.")?;
+        rstsr_assert!(x.device().same_device(y.device()), DeviceMismatch)?;
+
+        let device = x.device().clone();
+        let (knots, _) = interpolate_to_vec_f(x)?;
+        rstsr_assert!(
+            knots.windows(2).all(|w| w[0] < w[1]),
+            InvalidValue,
+            "Knots should be strictly increasing for cubic spline."
+        )?;
+        let (y, shape) = interpolate_to_vec_f(y)?;
+        let trailing_shape = shape[1..].to_vec();
+        let nvals = trailing_shape.iter().product::<usize>();
+        let coeffs = cubic_spline_coeffs(&knots, &y, nvals, boundary);
+        Ok(Self { knots, coeffs, trailing_shape, boundary, device })
+    }
+
+    /// Build cubic spline. See also [`CubicSpline::new_f`].
+    pub fn new<DX, DY>(
+        x: TensorView<'_, f64, B, DX>,
+        y: TensorView<'_, f64, B, DY>,
+        boundary: CubicSplineBoundary,
+    ) -> Self
+    where
+        DX: DimAPI,
+        DY: DimAPI,
+    {
+        Self::new_f(x, y, boundary).rstsr_unwrap()
+    }
+
+    /// Evaluate `nu`-th derivative of spline at query points.
+    pub fn derivative_f<D>(&self, x: TensorView<'_, f64, B, D>, nu: usize) -> Result<Tensor<f64, B, IxD>>
+    where
+        D: DimAPI,
+    {
+        rstsr_assert!(x.device().same_device(&self.device), DeviceMismatch)?;
+        let (x, mut shape) = interpolate_to_vec_f(x)?;
+        let nvals = self.trailing_shape.iter().product::<usize>();
+        let out = self.device.cubic_spline_eval_f(&x, &self.knots, &self.coeffs, nvals, nu)?;
+        shape.extend_from_slice(&self.trailing_shape);
+        asarray_f((out, shape.c(), &self.device))
+    }
+
+    /// Evaluate `nu`-th derivative of spline at query points. See also
+    /// [`CubicSpline::derivative_f`].
+    pub fn derivative<D>(&self, x: TensorView<'_, f64, B, D>, nu: usize) -> Tensor<f64, B, IxD>
+    where
+        D: DimAPI,
+    {
+        self.derivative_f(x, nu).rstsr_unwrap()
+    }
+
+    /// Evaluate spline at query points.
+    pub fn eval_f<D>(&self, x: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, IxD>>
+    where
+        D: DimAPI,
+    {
+        self.derivative_f(x, 0)
+    }
+
+    /// Evaluate spline at query points. See also [`CubicSpline::eval_f`].
+    pub fn eval<D>(&self, x: TensorView<'_, f64, B, D>) -> Tensor<f64, B, IxD>
+    where
+        D: DimAPI,
+    {
+        self.eval_f(x).rstsr_unwrap()
+    }
+
+    /// Polynomial coefficients of shape `(4, n - 1, ...)`, in the same convention to SciPy.
+    pub fn c(&self) -> Tensor<f64, B, IxD> {
+        let mut shape = vec![4, self.knots.len() - 1];
+        shape.extend_from_slice(&self.trailing_shape);
+        asarray((self.coeffs.clone(), shape.c(), &self.device))
+    }
+}
+
+impl<B> CubicSpline<B> {
+    /// Knots of spline.
+    pub fn x(&self) -> &[f64] {
+        &self.knots
+    }
+
+    pub fn boundary(&self) -> CubicSplineBoundary {
+        self.boundary
+    }
+
+    pub fn device(&self) -> &B {
+        &self.device
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cubic_spline() {
+        let device = DeviceCpuSerial::default();
+        // potential-energy-like scan, two functions sharing knots
+        let x = linspace((0.5, 4.0, 15, &device));
+        let f0 = |x: f64| (-x).exp() * (x - 1.0);
+        let f1 = |x: f64| x.sin();
+        let y = asarray((x.raw().iter().flat_map(|&x| [f0(x), f1(x)]).collect::<Vec<f64>>(), [15, 2].c(), &device));
+
+        let xq = linspace((0.6, 3.9, 12, &device)).into_shape((3, 4));
+        for boundary in [CubicSplineBoundary::NotAKnot, CubicSplineBoundary::Natural, CubicSplineBoundary::Clamped] {
+            let spl = CubicSpline::new(x.view(), y.view(), boundary);
+            assert_eq!(spl.c().shape(), &[4, 14, 2]);
+            let yq = spl.eval(xq.view());
+            assert_eq!(yq.shape(), &[3, 4, 2]);
+            // natural and clamped (zero slope) boundaries lose accuracy near ends
+            let tol = match boundary {
+                CubicSplineBoundary::NotAKnot => 5e-4,
+                CubicSplineBoundary::Natural => 1e-2,
+                CubicSplineBoundary::Clamped => 5e-2,
+            };
+            for (i, &xv) in xq.raw().iter().enumerate() {
+                assert!((yq.raw()[2 * i] - f0(xv)).abs() < tol);
+                assert!((yq.raw()[2 * i + 1] - f1(xv)).abs() < tol);
+            }
+            // interpolation at knots
+            let yk = spl.eval(x.view());
+            assert!(allclose_f64(&yk, &y.view().into_dim::<IxD>()));
+        }
+
+        // derivatives of 1-D spline; strided query
+        let y1 = x.mapv(f1);
+        let spl = CubicSpline::new(x.view(), y1.view(), CubicSplineBoundary::NotAKnot);
+        let xq_t = xq.t();
+        let d1 = spl.derivative(xq_t.view(), 1);
+        assert_eq!(d1.shape(), &[4, 3]);
+        assert!((d1[[1, 2]] - xq_t[[1, 2]].cos()).abs() < 1e-3);
+        let d2 = spl.derivative(xq_t.view(), 2);
+        assert!((d2[[1, 2]] + xq_t[[1, 2]].sin()).abs() < 3e-2);
+        assert_eq!(spl.derivative(xq.view(), 4).raw().iter().sum::<f64>(), 0.0);
+
+        // invalid knots
+        let bad = asarray((vec![0.0, 1.0, 1.0], &device));
+        assert!(CubicSpline::new_f(bad.view(), bad.view(), CubicSplineBoundary::Natural).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::interpolate::native_impl::*;
+use crate::interpolate::traits::InterpolateAPI;
+use rstsr_core::prelude_dev::*;
+
+impl InterpolateAPI for DeviceCpuSerial {
+    fn interp_f(&self, x: &[f64], xp: &[f64], fp: &[f64], left: f64, right: f64) -> Result<Vec<f64>> {
+        Ok(interpolate_map_serial(x, 1, 1, |x, out| out[0] = interp_point(x[0], xp, fp, left, right)))
+    }
+
+    fn cubic_spline_eval_f(
+        &self,
+        x: &[f64],
+        knots: &[f64],
+        coeffs: &[f64],
+        nvals: usize,
+        nu: usize,
+    ) -> Result<Vec<f64>> {
+        rstsr_assert!(nvals > 0, InvalidValue, "Number of spline components should be positive.")?;
+        Ok(interpolate_map_serial(x, 1, nvals, |x, out| cubic_spline_point(x[0], knots, coeffs, nu, out)))
+    }
+
+    fn regular_grid_eval_f(
+        &self,
+        xi: &[f64],
+        grid: &[Vec<f64>],
+        values: &[f64],
+        nvals: usize,
+        method: RegularGridMethod,
+        fill_value: Option<f64>,
+    ) -> Result<Vec<f64>> {
+        rstsr_assert!(nvals > 0, InvalidValue, "Number of grid components should be positive.")?;
+        rstsr_assert_eq!(xi.len() % grid.len(), 0, InvalidLayout, "Query length should be a multiple of ndim.")?;
+        Ok(interpolate_map_serial(xi, grid.len(), nvals, |xi, out| {
+            regular_grid_point(xi, grid, values, method, fill_value, out)
+        }))
+    }
+}
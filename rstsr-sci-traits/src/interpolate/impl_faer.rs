@@ -0,0 +1,54 @@
+use super::auto_impl_rayon::impl_interpolate_rayon;
+
+impl_interpolate_rayon!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude_dev::*;
+    use rstsr_sci_traits::interpolate::cubic_spline::*;
+    use rstsr_sci_traits::interpolate::native_impl::*;
+    use rstsr_sci_traits::interpolate::regular_grid::*;
+    use rstsr_sci_traits::interpolate::traits::*;
+
+    #[test]
+    fn test_interpolate_rayon() {
+        let device = DeviceFaer::default();
+        let device_serial = DeviceCpuSerial::default();
+        let x = linspace((0.0, 3.0, 31, &device));
+        let y = x.mapv(|v: f64| (-v * v).exp());
+        let xq = linspace((-0.5, 3.5, 1000, &device)).into_shape((8, 125));
+        let x_s = x.to_device(&device_serial);
+        let y_s = y.to_device(&device_serial);
+        let xq_s = xq.to_device(&device_serial);
+
+        let out = interp(xq.t(), x.view(), y.view(), None, 0.0);
+        let out_s = interp(xq_s.t(), x_s.view(), y_s.view(), None, 0.0);
+        assert_eq!(out.raw(), out_s.raw());
+
+        let spl = CubicSpline::new(x.view(), y.view(), CubicSplineBoundary::NotAKnot);
+        let spl_s = CubicSpline::new(x_s.view(), y_s.view(), CubicSplineBoundary::NotAKnot);
+        assert_eq!(spl.derivative(xq.view(), 1).raw(), spl_s.derivative(xq_s.view(), 1).raw());
+
+        let values = asarray((
+            x.raw().iter().flat_map(|a| x.raw().iter().map(move |b| a * b)).collect::<Vec<_>>(),
+            [31, 31].c(),
+            &device,
+        ));
+        let values_s = values.to_device(&device_serial);
+        let xi = xq.reshape((-1, 2));
+        let xi_s = xq_s.reshape((-1, 2));
+        let rgi = RegularGridInterpolator::new(
+            &[x.view(), x.view()],
+            values.view(),
+            RegularGridMethod::Linear,
+            OutOfBounds::Fill(0.0),
+        );
+        let rgi_s = RegularGridInterpolator::new(
+            &[x_s.view(), x_s.view()],
+            values_s.view(),
+            RegularGridMethod::Linear,
+            OutOfBounds::Fill(0.0),
+        );
+        assert_eq!(rgi.eval(xi.view()).raw(), rgi_s.eval(xi_s.view()).raw());
+    }
+}
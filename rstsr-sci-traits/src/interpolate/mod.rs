@@ -0,0 +1,14 @@
+pub mod prelude;
+
+#[cfg(any(feature = "faer", feature = "blas"))]
+mod auto_impl_rayon;
+pub mod cubic_spline;
+#[cfg(feature = "blas")]
+pub mod impl_blas;
+pub mod impl_cpu_serial;
+pub mod native_impl;
+pub mod regular_grid;
+pub mod traits;
+
+#[cfg(feature = "faer")]
+pub mod impl_faer;
@@ -0,0 +1,371 @@
+//! Native (slice-based) implementations of interpolation.
+
+use rayon::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region common */
+
+/// Index `i` of interval `[xp[i], xp[i+1]]` that `x` belongs to, clamped to `0..=n-2`.
+///
+/// `xp` should be ascending with at least two elements.
+#[inline]
+pub fn search_interval(xp: &[f64], x: f64) -> usize {
+    let count = xp.partition_point(|&v| v <= x);
+    count.clamp(1, xp.len() - 1) - 1
+}
+
+/// Chunk-wise map of contiguous data (serial).
+pub fn interpolate_map_serial<F>(x: &[f64], chunk_in: usize, chunk_out: usize, f: F) -> Vec<f64>
+where
+    F: Fn(&[f64], &mut [f64]),
+{
+    let mut out = vec![0.0; x.len() / chunk_in * chunk_out];
+    izip!(x.chunks_exact(chunk_in), out.chunks_exact_mut(chunk_out)).for_each(|(x, out)| f(x, out));
+    out
+}
+
+/// Chunk-wise map of contiguous data, parallelized over chunks.
+pub fn interpolate_map_rayon<F>(
+    x: &[f64],
+    chunk_in: usize,
+    chunk_out: usize,
+    f: F,
+    pool: Option<&rayon::ThreadPool>,
+) -> Vec<f64>
+where
+    F: Fn(&[f64], &mut [f64]) + Send + Sync,
+{
+    if pool.is_none() {
+        return interpolate_map_serial(x, chunk_in, chunk_out, f);
+    }
+    let pool = pool.unwrap();
+
+    let mut out = vec![0.0; x.len() / chunk_in * chunk_out];
+    pool.install(|| {
+        x.par_chunks_exact(chunk_in).zip(out.par_chunks_exact_mut(chunk_out)).for_each(|(x, out)| f(x, out))
+    });
+    out
+}
+
+/* #endregion */
+
+/* #region linear interpolation */
+
+/// One-dimensional linear interpolation at point `x` (NumPy `interp` semantics).
+///
+/// Values outside of `[xp[0], xp[n-1]]` are `left` and `right`.
+#[inline]
+pub fn interp_point(x: f64, xp: &[f64], fp: &[f64], left: f64, right: f64) -> f64 {
+    let n = xp.len();
+    if x.is_nan() {
+        return x;
+    }
+    if x < xp[0] {
+        return left;
+    }
+    if x > xp[n - 1] {
+        return right;
+    }
+    if x == xp[n - 1] {
+        return fp[n - 1];
+    }
+    let i = search_interval(xp, x);
+    let slope = (fp[i + 1] - fp[i]) / (xp[i + 1] - xp[i]);
+    fp[i] + slope * (x - xp[i])
+}
+
+/* #endregion */
+
+/* #region cubic spline */
+
+/// Boundary condition of cubic spline.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CubicSplineBoundary {
+    /// First and second segments at a curve end are the same polynomial.
+    #[default]
+    NotAKnot,
+    /// Second derivative at curve ends are zero.
+    Natural,
+    /// First derivative at curve ends are zero.
+    Clamped,
+}
+
+/// Solve tridiagonal system by Thomas algorithm; `sub[0]` and `sup[n-1]` are not referenced.
+fn solve_tridiagonal(sub: &[f64], diag: &mut [f64], sup: &[f64], rhs: &mut [f64]) {
+    let n = diag.len();
+    for i in 1..n {
+        let w = sub[i] / diag[i - 1];
+        diag[i] -= w * sup[i - 1];
+        rhs[i] -= w * rhs[i - 1];
+    }
+    rhs[n - 1] /= diag[n - 1];
+    for i in (0..n - 1).rev() {
+        rhs[i] = (rhs[i] - sup[i] * rhs[i + 1]) / diag[i];
+    }
+}
+
+/// Second derivatives at knots of cubic spline for one set of values.
+fn cubic_spline_second_derivatives(x: &[f64], y: &[f64], bc: CubicSplineBoundary) -> Vec<f64> {
+    let n = x.len();
+    let h = x.windows(2).map(|w| w[1] - w[0]).collect::<Vec<f64>>();
+    let d = izip!(y.windows(2), &h).map(|(w, &h)| (w[1] - w[0]) / h).collect::<Vec<f64>>();
+
+    if n == 2 {
+        return match bc {
+            // both not-a-knot and natural give the straight line
+            CubicSplineBoundary::NotAKnot | CubicSplineBoundary::Natural => vec![0.0; 2],
+            // 2 h M_0 + h M_1 = 6 d, h M_0 + 2 h M_1 = -6 d
+            CubicSplineBoundary::Clamped => vec![6.0 * d[0] / h[0], -6.0 * d[0] / h[0]],
+        };
+    }
+    if n == 3 && bc == CubicSplineBoundary::NotAKnot {
+        // single parabola through three points
+        return vec![2.0 * (d[1] - d[0]) / (h[0] + h[1]); 3];
+    }
+
+    // interior rows: h_{i-1} M_{i-1} + 2 (h_{i-1} + h_i) M_i + h_i M_{i+1} = 6 (d_i - d_{i-1})
+    let mut sub = vec![0.0; n];
+    let mut diag = vec![0.0; n];
+    let mut sup = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+    for i in 1..n - 1 {
+        sub[i] = h[i - 1];
+        diag[i] = 2.0 * (h[i - 1] + h[i]);
+        sup[i] = h[i];
+        rhs[i] = 6.0 * (d[i] - d[i - 1]);
+    }
+    match bc {
+        CubicSplineBoundary::Natural => {
+            diag[0] = 1.0;
+            diag[n - 1] = 1.0;
+            solve_tridiagonal(&sub, &mut diag, &sup, &mut rhs);
+            rhs
+        },
+        CubicSplineBoundary::Clamped => {
+            diag[0] = 2.0 * h[0];
+            sup[0] = h[0];
+            rhs[0] = 6.0 * d[0];
+            sub[n - 1] = h[n - 2];
+            diag[n - 1] = 2.0 * h[n - 2];
+            rhs[n - 1] = -6.0 * d[n - 2];
+            solve_tridiagonal(&sub, &mut diag, &sup, &mut rhs);
+            rhs
+        },
+        CubicSplineBoundary::NotAKnot => {
+            // continuity of third derivative at x_1: M_0 = ((h_0 + h_1) M_1 - h_0 M_2) / h_1;
+            // eliminate M_0 from the first interior row (and similarly M_{n-1} from the last),
+            // which keeps the reduced system diagonally dominant
+            let (h0, h1) = (h[0], h[1]);
+            let (hl, hm) = (h[n - 2], h[n - 3]);
+            diag[1] = (h0 + h1) * (h0 + 2.0 * h1) / h1;
+            sup[1] = (h1 * h1 - h0 * h0) / h1;
+            diag[n - 2] = (hl + hm) * (hl + 2.0 * hm) / hm;
+            sub[n - 2] = (hm * hm - hl * hl) / hm;
+            let mut m = rhs[1..n - 1].to_vec();
+            solve_tridiagonal(&sub[1..n - 1], &mut diag[1..n - 1], &sup[1..n - 1], &mut m);
+            let m0 = ((h0 + h1) * m[0] - h0 * m[1]) / h1;
+            let mn = ((hl + hm) * m[n - 3] - hl * m[n - 4]) / hm;
+            let mut result = Vec::with_capacity(n);
+            result.push(m0);
+            result.extend(m);
+            result.push(mn);
+            result
+        },
+    }
+}
+
+/// Polynomial coefficients of cubic spline.
+///
+/// # Arguments
+///
+/// * `x` - Knots (strictly ascending, at least 2 points).
+/// * `y` - Values of shape `(n, nvals)` (row-major).
+/// * `bc` - Boundary condition.
+///
+/// # Returns
+///
+/// Coefficients of shape `(4, n - 1, nvals)` (row-major), in the same convention to SciPy: on
+/// interval `i`, `S(x) = Σ_k c[k, i] (x - x_i)^(3-k)`.
+pub fn cubic_spline_coeffs(x: &[f64], y: &[f64], nvals: usize, bc: CubicSplineBoundary) -> Vec<f64> {
+    let n = x.len();
+    let nseg = n - 1;
+    let mut coeffs = vec![0.0; 4 * nseg * nvals];
+    let mut yj = vec![0.0; n];
+    for j in 0..nvals {
+        yj.iter_mut().enumerate().for_each(|(i, v)| *v = y[i * nvals + j]);
+        let m = cubic_spline_second_derivatives(x, &yj, bc);
+        for i in 0..nseg {
+            let h = x[i + 1] - x[i];
+            let d = (yj[i + 1] - yj[i]) / h;
+            coeffs[i * nvals + j] = (m[i + 1] - m[i]) / (6.0 * h);
+            coeffs[(nseg + i) * nvals + j] = 0.5 * m[i];
+            coeffs[(2 * nseg + i) * nvals + j] = d - h * (2.0 * m[i] + m[i + 1]) / 6.0;
+            coeffs[(3 * nseg + i) * nvals + j] = yj[i];
+        }
+    }
+    coeffs
+}
+
+/// Evaluate `nu`-th derivative of cubic spline at point `x`, for all `nvals` sets of values.
+///
+/// Points outside of knots are extrapolated by the polynomial of the first or last interval.
+#[inline]
+pub fn cubic_spline_point(x: f64, knots: &[f64], coeffs: &[f64], nu: usize, out: &mut [f64]) {
+    let nvals = out.len();
+    let nseg = knots.len() - 1;
+    let i = search_interval(knots, x);
+    let t = x - knots[i];
+    for (j, out) in out.iter_mut().enumerate() {
+        let c = |k: usize| coeffs[(k * nseg + i) * nvals + j];
+        *out = match nu {
+            0 => ((c(0) * t + c(1)) * t + c(2)) * t + c(3),
+            1 => (3.0 * c(0) * t + 2.0 * c(1)) * t + c(2),
+            2 => 6.0 * c(0) * t + 2.0 * c(1),
+            3 => 6.0 * c(0),
+            _ => 0.0,
+        };
+        if x.is_nan() {
+            *out = f64::NAN;
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region regular grid */
+
+/// Interpolation method of regular grid interpolator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RegularGridMethod {
+    /// Multilinear interpolation.
+    #[default]
+    Linear,
+    /// Value of the nearest grid point (ties go to the lower index).
+    Nearest,
+}
+
+/// Handling of points outside of grid.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutOfBounds {
+    /// Raise error.
+    #[default]
+    Error,
+    /// Fill with given value (e.g. NaN).
+    Fill(f64),
+    /// Extrapolate by the boundary interval.
+    Extrapolate,
+}
+
+/// Evaluate regular grid interpolation at point `xi` (length ndim), for all `nvals` sets of
+/// values.
+///
+/// # Arguments
+///
+/// * `grid` - Grid points of each dimension (ascending, at least 2 points).
+/// * `values` - Values of shape `(n_1, ..., n_d, nvals)` (row-major).
+/// * `fill_value` - Value for points outside of grid; `None` for extrapolation.
+#[inline]
+pub fn regular_grid_point(
+    xi: &[f64],
+    grid: &[Vec<f64>],
+    values: &[f64],
+    method: RegularGridMethod,
+    fill_value: Option<f64>,
+    out: &mut [f64],
+) {
+    let nvals = out.len();
+    if let Some(fill) = fill_value {
+        if izip!(xi, grid).any(|(&x, g)| x < g[0] || x > g[g.len() - 1]) {
+            out.fill(fill);
+            return;
+        }
+    }
+    let ndim = grid.len();
+    // strides of flattened values
+    let mut strides = vec![nvals; ndim];
+    for d in (0..ndim.saturating_sub(1)).rev() {
+        strides[d] = strides[d + 1] * grid[d + 1].len();
+    }
+    let intervals = izip!(xi, grid)
+        .map(|(&x, g)| {
+            let i = search_interval(g, x);
+            (i, (x - g[i]) / (g[i + 1] - g[i]))
+        })
+        .collect::<Vec<(usize, f64)>>();
+
+    match method {
+        RegularGridMethod::Nearest => {
+            let offset: usize =
+                izip!(&intervals, &strides).map(|(&(i, t), &s)| if t <= 0.5 { i } else { i + 1 } * s).sum();
+            out.copy_from_slice(&values[offset..offset + nvals]);
+            if xi.iter().any(|x| x.is_nan()) {
+                out.fill(f64::NAN);
+            }
+        },
+        RegularGridMethod::Linear => {
+            out.fill(0.0);
+            for corner in 0..(1_usize << ndim) {
+                let mut weight = 1.0;
+                let mut offset = 0;
+                for (d, (&(i, t), &s)) in izip!(&intervals, &strides).enumerate() {
+                    match (corner >> d) & 1 {
+                        0 => {
+                            weight *= 1.0 - t;
+                            offset += i * s;
+                        },
+                        _ => {
+                            weight *= t;
+                            offset += (i + 1) * s;
+                        },
+                    }
+                }
+                izip!(out.iter_mut(), &values[offset..offset + nvals]).for_each(|(o, &v)| *o += weight * v);
+            }
+        },
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cubic_spline_coeffs() {
+        // cubic polynomial is reproduced exactly by not-a-knot spline
+        let x = [0.0, 0.3, 1.1, 1.7, 2.0, 3.2];
+        let f = |x: f64| 1.0 - 2.0 * x + 0.5 * x * x - 0.3 * x * x * x;
+        let y = x.iter().map(|&x| f(x)).collect::<Vec<f64>>();
+        for n in [4, 5, 6] {
+            let coeffs = cubic_spline_coeffs(&x[..n], &y[..n], 1, CubicSplineBoundary::NotAKnot);
+            let mut out = [0.0];
+            for xq in [-0.5, 0.15, 0.9, 1.2, 2.5] {
+                cubic_spline_point(xq, &x[..n], &coeffs, 0, &mut out);
+                assert!((out[0] - f(xq)).abs() < 1e-12, "{n} {xq}");
+            }
+        }
+        // quadratic is reproduced by three-point not-a-knot spline
+        let coeffs = cubic_spline_coeffs(&[0.0, 1.0, 3.0], &[0.0, 1.0, 9.0], 1, CubicSplineBoundary::NotAKnot);
+        let mut out = [0.0];
+        cubic_spline_point(2.0, &[0.0, 1.0, 3.0], &coeffs, 0, &mut out);
+        assert!((out[0] - 4.0).abs() < 1e-14);
+
+        // boundary conditions
+        let coeffs = cubic_spline_coeffs(&x, &y, 1, CubicSplineBoundary::Natural);
+        for xq in [0.0, 3.2] {
+            cubic_spline_point(xq, &x, &coeffs, 2, &mut out);
+            assert!(out[0].abs() < 1e-12);
+        }
+        let coeffs = cubic_spline_coeffs(&x, &y, 1, CubicSplineBoundary::Clamped);
+        for xq in [0.0, 3.2] {
+            cubic_spline_point(xq, &x, &coeffs, 1, &mut out);
+            assert!(out[0].abs() < 1e-12);
+        }
+        let coeffs = cubic_spline_coeffs(&x[..2], &y[..2], 1, CubicSplineBoundary::Clamped);
+        for xq in [0.0, 0.3] {
+            cubic_spline_point(xq, &x[..2], &coeffs, 1, &mut out);
+            assert!(out[0].abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+pub mod rstsr_traits {
+    pub use crate::interpolate::traits::InterpolateAPI;
+}
+
+pub mod rstsr_funcs {
+    pub use crate::interpolate::traits::{interp, interp_f};
+}
+
+pub mod rstsr_structs {
+    pub use crate::interpolate::cubic_spline::CubicSpline;
+    pub use crate::interpolate::native_impl::{CubicSplineBoundary, OutOfBounds, RegularGridMethod};
+    pub use crate::interpolate::regular_grid::RegularGridInterpolator;
+}
@@ -0,0 +1,216 @@
+//! Interpolation on regular (rectilinear) grids of arbitrary dimensions.
+//!
+//! # See also
+//!
+//! [SciPy `RegularGridInterpolator`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.interpolate.RegularGridInterpolator.html)
+
+use crate::interpolate::native_impl::{OutOfBounds, RegularGridMethod};
+use crate::interpolate::traits::{interpolate_to_vec_f, InterpolateAPI};
+use rstsr_core::prelude_dev::*;
+
+/// Interpolator on a rectilinear grid in arbitrary dimensions.
+///
+/// Grid points of each dimension need not be evenly spaced, but should be strictly increasing.
+/// Values are of shape `(n_1, ..., n_d, ...)`; trailing dimensions are interpolated
+/// independently. Query points `xi` are of shape `(..., d)`, and output is of shape
+/// `(..., ...)` (query shape without last dimension, followed by trailing shape of values).
+#[derive(Debug, Clone)]
+pub struct RegularGridInterpolator<B> {
+    grid: Vec<Vec<f64>>,
+    values: Vec<f64>,
+    trailing_shape: Vec<usize>,
+    method: RegularGridMethod,
+    out_of_bounds: OutOfBounds,
+    device: B,
+}
+
+impl<B> RegularGridInterpolator<B>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD> + InterpolateAPI,
+{
+    /// Build interpolator from grid points of each dimension and values on grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - 1-D grid points of each dimension (strictly increasing, at least 2 points).
+    /// * `values` - Values on grid, of shape `(n_1, ..., n_d, ...)`.
+    /// * `method` - Interpolation method.
+    /// * `out_of_bounds` - Handling of query points outside of grid.
+    pub fn new_f<DP, DV>(
+        points: &[TensorView<'_, f64, B, DP>],
+        values: TensorView<'_, f64, B, DV>,
+        method: RegularGridMethod,
+        out_of_bounds: OutOfBounds,
+    ) -> Result<Self>
+    where
+        DP: DimAPI,
+        DV: DimAPI,
+    {
+        let ndim = points.len();
+        rstsr_assert!(ndim > 0, InvalidValue, "At least one dimension of grid points is required.")?;
+        rstsr_assert!(
+            values.ndim() >= ndim,
+            InvalidLayout,
+            "values has {} dimensions, but {ndim} dimensions of grid points are given",
+            values.ndim()
+        )?;
+        let device = values.device().clone();
+        let mut grid = Vec::with_capacity(ndim);
+        for (d, p) in points.iter().enumerate() {
+            rstsr_assert!(p.device().same_device(&device), DeviceMismatch)?;
+            rstsr_assert_eq!(p.ndim(), 1, InvalidLayout, "Grid points of dimension {d} must be 1D tensor")?;
+            rstsr_assert_eq!(
+                p.size(),
+                values.shape()[d],
+                InvalidLayout,
+                "Number of grid points and values mismatch at dimension {d}"
+            )?;
+            let (g, _) = interpolate_to_vec_f(p.view())?;
+            rstsr_assert!(g.len() >= 2, InvalidValue, "At least two grid points are required at dimension {d}")?;
+            rstsr_assert!(
+                g.windows(2).all(|w| w[0] < w[1]),
+                InvalidValue,
+                "Grid points should be strictly increasing at dimension {d}"
+            )?;
+            grid.push(g);
+        }
+        let (values, shape) = interpolate_to_vec_f(values)?;
+        let trailing_shape = shape[ndim..].to_vec();
+        Ok(Self { grid, values, trailing_shape, method, out_of_bounds, device })
+    }
+
+    /// Build interpolator. See also [`RegularGridInterpolator::new_f`].
+    pub fn new<DP, DV>(
+        points: &[TensorView<'_, f64, B, DP>],
+        values: TensorView<'_, f64, B, DV>,
+        method: RegularGridMethod,
+        out_of_bounds: OutOfBounds,
+    ) -> Self
+    where
+        DP: DimAPI,
+        DV: DimAPI,
+    {
+        Self::new_f(points, values, method, out_of_bounds).rstsr_unwrap()
+    }
+
+    /// Evaluate interpolant at query points `xi` of shape `(..., ndim)`.
+    pub fn eval_f<D>(&self, xi: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, IxD>>
+    where
+        D: DimAPI,
+    {
+        let ndim = self.grid.len();
+        rstsr_assert!(xi.device().same_device(&self.device), DeviceMismatch)?;
+        rstsr_assert!(xi.ndim() >= 1, InvalidLayout, "xi must be at least 1D tensor")?;
+        rstsr_assert_eq!(xi.shape()[xi.ndim() - 1], ndim, InvalidLayout, "Last dimension of xi should be {ndim}")?;
+        let (xi, mut shape) = interpolate_to_vec_f(xi)?;
+        shape.pop();
+
+        let fill_value = match self.out_of_bounds {
+            OutOfBounds::Error => {
+                for point in xi.chunks_exact(ndim) {
+                    for (d, (&x, g)) in izip!(point, &self.grid).enumerate() {
+                        rstsr_assert!(
+                            !(x < g[0] || x > g[g.len() - 1]),
+                            InvalidValue,
+                            "Query point {point:?} is out of bounds in dimension {d}"
+                        )?;
+                    }
+                }
+                None
+            },
+            OutOfBounds::Fill(v) => Some(v),
+            OutOfBounds::Extrapolate => None,
+        };
+        let nvals = self.trailing_shape.iter().product::<usize>();
+        let out = self.device.regular_grid_eval_f(&xi, &self.grid, &self.values, nvals, self.method, fill_value)?;
+        shape.extend_from_slice(&self.trailing_shape);
+        asarray_f((out, shape.c(), &self.device))
+    }
+
+    /// Evaluate interpolant at query points. See also [`RegularGridInterpolator::eval_f`].
+    pub fn eval<D>(&self, xi: TensorView<'_, f64, B, D>) -> Tensor<f64, B, IxD>
+    where
+        D: DimAPI,
+    {
+        self.eval_f(xi).rstsr_unwrap()
+    }
+}
+
+impl<B> RegularGridInterpolator<B> {
+    /// Grid points of each dimension.
+    pub fn grid(&self) -> &[Vec<f64>] {
+        &self.grid
+    }
+
+    pub fn method(&self) -> RegularGridMethod {
+        self.method
+    }
+
+    pub fn out_of_bounds(&self) -> OutOfBounds {
+        self.out_of_bounds
+    }
+
+    pub fn device(&self) -> &B {
+        &self.device
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_regular_grid_interpolator() {
+        let device = DeviceCpuSerial::default();
+        let gx = asarray((vec![0.0, 0.5, 1.5, 2.0], &device));
+        let gy = asarray((vec![-1.0, 0.0, 1.0], &device));
+        let gz = asarray((vec![0.0, 1.0], &device));
+        // trilinear function is reproduced exactly
+        let f = |x: f64, y: f64, z: f64| 1.0 + 2.0 * x - y + 0.5 * z + x * y - x * y * z;
+        let mut values = vec![];
+        for &x in gx.raw() {
+            for &y in gy.raw() {
+                for &z in gz.raw() {
+                    values.extend([f(x, y, z), 2.0 * f(x, y, z)]);
+                }
+            }
+        }
+        let values = asarray((values, [4, 3, 2, 2].c(), &device));
+        let points = [gx.view(), gy.view(), gz.view()];
+
+        let interp =
+            RegularGridInterpolator::new(&points, values.view(), RegularGridMethod::Linear, OutOfBounds::Error);
+        let xi = asarray((vec![0.3, -0.2, 0.7, 1.9, 0.9, 0.1, 1.0, 0.0, 0.5], [3, 3].c(), &device));
+        let out = interp.eval(xi.view());
+        assert_eq!(out.shape(), &[3, 2]);
+        for (i, p) in xi.raw().chunks_exact(3).enumerate() {
+            assert!((out[[i, 0]] - f(p[0], p[1], p[2])).abs() < 1e-14);
+            assert!((out[[i, 1]] - 2.0 * f(p[0], p[1], p[2])).abs() < 1e-14);
+        }
+
+        // nearest
+        let interp =
+            RegularGridInterpolator::new(&points, values.view(), RegularGridMethod::Nearest, OutOfBounds::Error);
+        let out = interp.eval(xi.view());
+        assert_eq!(out[[0, 0]], f(0.5, 0.0, 1.0));
+        assert_eq!(out[[1, 0]], f(2.0, 1.0, 0.0));
+
+        // out of bounds
+        let xo = asarray((vec![2.5, 0.0, 0.5], [1, 3].c(), &device));
+        assert!(interp.eval_f(xo.view()).is_err());
+        let interp = RegularGridInterpolator::new(
+            &points,
+            values.view(),
+            RegularGridMethod::Linear,
+            OutOfBounds::Fill(f64::NAN),
+        );
+        assert!(interp.eval(xo.view()).raw().iter().all(|v| v.is_nan()));
+        let interp =
+            RegularGridInterpolator::new(&points, values.view(), RegularGridMethod::Linear, OutOfBounds::Extrapolate);
+        assert!((interp.eval(xo.view())[[0, 0]] - f(2.5, 0.0, 0.5)).abs() < 1e-14);
+
+        // query of 1-D point gives output of trailing shape only
+        let x1 = asarray((vec![1.0, 0.5, 0.5], &device));
+        assert_eq!(interp.eval(x1.view()).shape(), &[2]);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::interpolate::native_impl::RegularGridMethod;
+use rstsr_core::prelude_dev::*;
+
+/// Vectorized evaluation of interpolants, dispatched by device.
+///
+/// All data are contiguous (row-major); devices with rayon parallelize over query points.
+pub trait InterpolateAPI
+where
+    Self: DeviceAPI<f64>,
+{
+    /// Linear interpolation of `x` on knots `xp` with values `fp`.
+    fn interp_f(&self, x: &[f64], xp: &[f64], fp: &[f64], left: f64, right: f64) -> Result<Vec<f64>>;
+
+    /// `nu`-th derivative of cubic spline at `x`; output is of shape `(npts, nvals)`.
+    fn cubic_spline_eval_f(
+        &self,
+        x: &[f64],
+        knots: &[f64],
+        coeffs: &[f64],
+        nvals: usize,
+        nu: usize,
+    ) -> Result<Vec<f64>>;
+
+    /// Regular grid interpolation at `xi` of shape `(npts, ndim)`; output is of shape
+    /// `(npts, nvals)`.
+    #[allow(clippy::too_many_arguments)]
+    fn regular_grid_eval_f(
+        &self,
+        xi: &[f64],
+        grid: &[Vec<f64>],
+        values: &[f64],
+        nvals: usize,
+        method: RegularGridMethod,
+        fill_value: Option<f64>,
+    ) -> Result<Vec<f64>>;
+}
+
+/// Data of tensor in c-contiguous order, together with its shape.
+pub(crate) fn interpolate_to_vec_f<B, D>(x: TensorView<'_, f64, B, D>) -> Result<(Vec<f64>, Vec<usize>)>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    D: DimAPI,
+{
+    let x = x.into_dim_f::<IxD>()?;
+    let shape = x.shape().clone();
+    let size = x.size();
+    let x = x.change_layout_f(shape.c())?;
+    Ok((x.raw()[..size].to_vec(), shape))
+}
+
+/// One-dimensional piecewise linear interpolation.
+///
+/// Same to NumPy's `interp`: for query points `x`, returns the linear interpolant of data points
+/// `(xp, fp)`. Points outside of `[xp[0], xp[-1]]` are assigned by `left` and `right`, which
+/// default to `fp[0]` and `fp[-1]`.
+///
+/// `xp` should be increasing; this is not checked.
+///
+/// # Arguments
+///
+/// * `x` - Query points, of any shape; output has the same shape.
+/// * `xp` - 1-D knots (increasing).
+/// * `fp` - 1-D values at knots, with the same length of `xp`.
+/// * `left`, `right` - Values for `x < xp[0]` and `x > xp[-1]`; `None` for the boundary values.
+///
+/// # See also
+///
+/// - [NumPy: `numpy.interp`](https://numpy.org/doc/stable/reference/generated/numpy.interp.html)
+pub fn interp<B, D, DP>(
+    x: TensorView<'_, f64, B, D>,
+    xp: TensorView<'_, f64, B, DP>,
+    fp: TensorView<'_, f64, B, DP>,
+    left: impl Into<Option<f64>>,
+    right: impl Into<Option<f64>>,
+) -> Tensor<f64, B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD> + InterpolateAPI,
+    D: DimAPI,
+    DP: DimAPI,
+{
+    interp_f(x, xp, fp, left, right).rstsr_unwrap()
+}
+
+pub fn interp_f<B, D, DP>(
+    x: TensorView<'_, f64, B, D>,
+    xp: TensorView<'_, f64, B, DP>,
+    fp: TensorView<'_, f64, B, DP>,
+    left: impl Into<Option<f64>>,
+    right: impl Into<Option<f64>>,
+) -> Result<Tensor<f64, B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD> + InterpolateAPI,
+    D: DimAPI,
+    DP: DimAPI,
+{
+    rstsr_assert_eq!(xp.ndim(), 1, InvalidLayout, "xp must be a 1D tensor")?;
+    rstsr_assert_eq!(fp.ndim(), 1, InvalidLayout, "fp must be a 1D tensor")?;
+    rstsr_assert_eq!(xp.size(), fp.size(), InvalidLayout, "xp and fp should have the same length")?;
+    rstsr_assert!(xp.size() > 0, InvalidValue, "xp should not be empty")?;
+    rstsr_assert!(x.device().same_device(xp.device()), DeviceMismatch)?;
+    rstsr_assert!(x.device().same_device(fp.device()), DeviceMismatch)?;
+
+    let device = x.device().clone();
+    let (xp, _) = interpolate_to_vec_f(xp)?;
+    let (fp, _) = interpolate_to_vec_f(fp)?;
+    let left = left.into().unwrap_or(fp[0]);
+    let right = right.into().unwrap_or(fp[fp.len() - 1]);
+    let (x, shape) = interpolate_to_vec_f(x)?;
+    let out = match xp.len() {
+        // single knot: NumPy gives fp[0] inside, left/right outside
+        1 => x
+            .iter()
+            .map(|&v| {
+                if v < xp[0] {
+                    left
+                } else if v > xp[0] {
+                    right
+                } else {
+                    fp[0]
+                }
+            })
+            .collect(),
+        _ => device.interp_f(&x, &xp, &fp, left, right)?,
+    };
+    asarray_f((out, shape.c(), &device))?.into_dim_f::<D>()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interp() {
+        let device = DeviceCpuSerial::default();
+        let xp = asarray((vec![1.0, 2.0, 4.0], &device));
+        let fp = asarray((vec![3.0, 2.0, 0.0], &device));
+        let x = asarray((vec![0.0, 1.0, 1.5, 2.72, 3.5, 4.0, 5.0, f64::NAN], [2, 4].c(), &device));
+        let y = interp(x.view(), xp.view(), fp.view(), None, None);
+        assert_eq!(y.shape(), &[2, 4]);
+        let y = y.raw();
+        let refv = [3.0, 3.0, 2.5, 1.28, 0.5, 0.0, 0.0];
+        assert!(izip!(y, &refv).all(|(a, b)| (a - b).abs() < 1e-14));
+        assert!(y[7].is_nan());
+
+        let y = interp(x.t(), xp.view(), fp.view(), -99.0, 99.0);
+        assert_eq!(y.shape(), &[4, 2]);
+        assert_eq!(y[[0, 0]], -99.0);
+        assert_eq!(y[[2, 1]], 99.0);
+        assert_eq!(y[[1, 0]], 3.0);
+    }
+}
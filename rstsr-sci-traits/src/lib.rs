@@ -3,3 +3,7 @@ pub mod prelude_dev;
 
 pub mod distance;
 pub mod integrate;
+pub mod interpolate;
+pub mod optimize;
+pub mod spatial;
+pub mod special;
@@ -0,0 +1,45 @@
+use rstsr_core::prelude_dev::*;
+
+/// Result of minimization.
+pub struct OptimizeResult<B, D>
+where
+    B: DeviceAPI<f64>,
+    D: DimAPI,
+{
+    /// Solution (or last iterate if not converged).
+    pub x: Tensor<f64, B, D>,
+    /// Objective value at `x`.
+    pub fun: f64,
+    /// Gradient at `x`.
+    pub grad: Tensor<f64, B, D>,
+    /// Number of iterations.
+    pub nit: usize,
+    /// Number of objective (and gradient) evaluations.
+    pub nfev: usize,
+    /// Whether the gradient tolerance is reached.
+    pub converged: bool,
+}
+
+/// Data of tensor in c-contiguous order.
+pub(crate) fn optimize_to_vec_f<T, B, D>(x: TensorView<'_, T, B, D>) -> Result<Vec<T>>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+    D: DimAPI,
+{
+    let x = x.into_dim_f::<IxD>()?;
+    let shape = x.shape().clone();
+    let size = x.size();
+    let x = x.change_layout_f(shape.c())?;
+    Ok(x.raw()[..size].to_vec())
+}
+
+#[inline]
+pub(crate) fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+#[inline]
+pub(crate) fn norm_inf(a: &[f64]) -> f64 {
+    a.iter().fold(0.0, |acc, v| acc.max(v.abs()))
+}
@@ -0,0 +1,316 @@
+//! Direct inversion in the iterative subspace (Pulay DIIS).
+//!
+//! # See also
+//!
+//! - P. Pulay, *Chem. Phys. Lett.* **1980**, 73, 393.
+//! - [PySCF `lib.diis.DIIS`](https://pyscf.org/pyscf_api_docs/pyscf.lib.html#pyscf.lib.diis.DIIS)
+
+use crate::optimize::common::optimize_to_vec_f;
+use num::complex::ComplexFloat;
+use num::{Float, NumCast, One, Zero};
+use rstsr_core::prelude_dev::*;
+use rstsr_linalg_traits::prelude::rstsr_traits::{PinvAPI, SolveGeneralAPI};
+use rstsr_linalg_traits::traits_def::PinvResult;
+
+/// Pulay DIIS accelerator over tensors of any shape, of real or complex type `T`.
+///
+/// Each call of [`DIIS::update`] pushes a trial vector `x` and its error vector `err` (shapes of
+/// `x` and `err` may differ, but should be the same across iterations), and returns the
+/// extrapolation $\sum_i c_i x_i$ that minimizes $\Vert \sum_i c_i e_i \Vert$ under
+/// $\sum_i c_i = 1$.
+///
+/// The subspace holds at most `space` vectors; when full, the vector of largest error norm is
+/// replaced. The (hermitian) B-matrix $B_{ij} = \langle e_i, e_j \rangle$ is built incrementally
+/// with [`vecdot`]; the DIIS equation is solved by
+/// [`solve_general`](rstsr_linalg_traits::prelude::rstsr_funcs::solve_general), falling back to
+/// [`pinv`](rstsr_linalg_traits::prelude::rstsr_funcs::pinv) when the subspace is (nearly) linearly
+/// dependent.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut diis = DIIS::new(8);
+/// for _ in 0..max_cycle {
+///     let fock = build_fock(&dm);
+///     let err = fock.view() % dm.view() % s.view() - s.view() % dm.view() % fock.view();
+///     let fock = diis.update(fock.view(), err.view());
+///     dm = make_dm(&fock);
+/// }
+/// ```
+pub struct DIIS<T, B>
+where
+    B: DeviceAPI<T>,
+{
+    space: usize,
+    min_space: usize,
+    xs: Vec<Tensor<T, B, IxD>>,
+    errs: Vec<Tensor<T, B, IxD>>,
+    /// B-matrix in row-major, of shape `(space, space)`.
+    bmat: Vec<T>,
+    /// index of the most recently updated vector
+    head: Option<usize>,
+}
+
+impl<T, B> DIIS<T, B>
+where
+    T: ComplexFloat<Real: Float> + Default,
+    B: DeviceAPI<T, Raw = Vec<T>>
+        + DeviceCreationAnyAPI<T>
+        + DeviceCreationNumAPI<T>
+        + OpAssignArbitaryAPI<T, IxD, IxD>
+        + OpAssignAPI<T, IxD>
+        + DeviceVecdotAPI<T, T, T, IxD, IxD, IxD>,
+    (Tensor<T, B, Ix2>, Tensor<T, B, Ix1>): SolveGeneralAPI<B, Out = Tensor<T, B, Ix1>>,
+    Tensor<T, B, Ix2>: PinvAPI<B, Out = PinvResult<Tensor<T, B, Ix2>>>,
+{
+    /// New DIIS accelerator holding at most `space` vectors.
+    pub fn new(space: usize) -> Self {
+        let space = space.max(1);
+        Self { space, min_space: 1, xs: vec![], errs: vec![], bmat: vec![T::zero(); space * space], head: None }
+    }
+
+    /// Do not extrapolate until `min_space` vectors have been collected (default 1).
+    pub fn with_min_space(mut self, min_space: usize) -> Self {
+        self.min_space = min_space.max(1);
+        self
+    }
+
+    /// Number of vectors currently in subspace.
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Maximum number of vectors in subspace.
+    pub fn space(&self) -> usize {
+        self.space
+    }
+
+    /// Clear subspace.
+    pub fn reset(&mut self) {
+        self.xs.clear();
+        self.errs.clear();
+        self.bmat.fill(T::zero());
+        self.head = None;
+    }
+
+    /// Squared norm of error vector pushed most recently.
+    pub fn last_error_norm_sq(&self) -> Option<T::Real> {
+        self.head.map(|i| self.error_norm_sq(i))
+    }
+
+    /// Squared norm of `i`-th error vector (diagonal of B-matrix, real by construction).
+    fn error_norm_sq(&self, i: usize) -> T::Real {
+        self.bmat[i * self.space + i].re()
+    }
+
+    /// Push `x` with its error vector `err`, and return extrapolated `x`.
+    pub fn update_f<DX, DE>(
+        &mut self,
+        x: TensorView<'_, T, B, DX>,
+        err: TensorView<'_, T, B, DE>,
+    ) -> Result<Tensor<T, B, DX>>
+    where
+        DX: DimAPI,
+        DE: DimAPI,
+    {
+        let shape = x.shape().as_ref().to_vec();
+        let x = x.into_dim_f::<IxD>()?.reshape_f(-1)?.into_owned();
+        let err = err.into_dim_f::<IxD>()?.reshape_f(-1)?.into_owned();
+        if let Some(x0) = self.xs.first() {
+            rstsr_assert!(x0.device().same_device(x.device()), DeviceMismatch)?;
+            rstsr_assert_eq!(x0.size(), x.size(), InvalidLayout, "Size of x changed between DIIS iterations.")?;
+            rstsr_assert_eq!(
+                self.errs[0].size(),
+                err.size(),
+                InvalidLayout,
+                "Size of error vector changed between DIIS iterations."
+            )?;
+        }
+
+        // slot for new vector: append, or replace the one of largest error
+        let space = self.space;
+        let idx = if self.xs.len() < space {
+            self.xs.push(x);
+            self.errs.push(err);
+            self.xs.len() - 1
+        } else {
+            // NaN error is regarded as the largest, so that it is replaced first
+            let key = |i| match self.error_norm_sq(i) {
+                v if Float::is_nan(v) => T::Real::infinity(),
+                v => v,
+            };
+            let idx = (0..space).max_by(|&i, &j| key(i).partial_cmp(&key(j)).unwrap()).unwrap();
+            self.xs[idx] = x;
+            self.errs[idx] = err;
+            idx
+        };
+        self.head = Some(idx);
+
+        // update B-matrix row/column
+        let err = &self.errs[idx];
+        for (i, e) in self.errs.iter().enumerate() {
+            let val = vecdot_f(e, err, -1)?.to_scalar_f()?;
+            self.bmat[i * space + idx] = val;
+            self.bmat[idx * space + i] = val.conj();
+        }
+
+        let nvec = self.xs.len();
+        if nvec < self.min_space {
+            let x = self.xs[idx].to_owned();
+            return x.into_shape_f(shape)?.into_dim_f::<DX>();
+        }
+
+        // vecdot conjugates its first argument
+        let coeff = self.solve_coeff_f()?.into_iter().map(|c| c.conj()).collect::<Vec<_>>();
+        let device = self.xs[0].device().clone();
+        let coeff = asarray_f((coeff, [nvec, 1].c(), &device))?;
+        let xs = stack_f((self.xs.iter().map(|x| x.view()).collect::<Vec<_>>(), 0))?;
+        let x = vecdot_f(&coeff, &xs, 0)?;
+        x.into_shape_f(shape)?.into_dim_f::<DX>()
+    }
+
+    /// Push `x` with its error vector `err`, and return extrapolated `x`.
+    ///
+    /// See also [`DIIS::update_f`].
+    pub fn update<DX, DE>(&mut self, x: TensorView<'_, T, B, DX>, err: TensorView<'_, T, B, DE>) -> Tensor<T, B, DX>
+    where
+        DX: DimAPI,
+        DE: DimAPI,
+    {
+        self.update_f(x, err).rstsr_unwrap()
+    }
+
+    /// Solve DIIS equation of current subspace
+    ///
+    /// $$
+    /// \begin{pmatrix} B & \mathbf{1} \\ \mathbf{1}^\dagger & 0 \end{pmatrix}
+    /// \begin{pmatrix} \mathbf{c} \\ \lambda \end{pmatrix} =
+    /// \begin{pmatrix} \mathbf{0} \\ 1 \end{pmatrix}
+    /// $$
+    fn solve_coeff_f(&self) -> Result<Vec<T>> {
+        let nvec = self.xs.len();
+        let space = self.space;
+        let n = nvec + 1;
+        // scale B-matrix to avoid ill-conditioning from tiny errors near convergence
+        let scale = (0..nvec).map(|i| self.error_norm_sq(i)).fold(T::Real::zero(), T::Real::max);
+        let scale = if scale > T::Real::zero() { Float::recip(scale) } else { T::Real::one() };
+        let scale = <T as NumCast>::from(scale).unwrap();
+        let mut h = vec![T::zero(); n * n];
+        for i in 0..nvec {
+            for j in 0..nvec {
+                h[i * n + j] = self.bmat[i * space + j] * scale;
+            }
+            h[i * n + nvec] = T::one();
+            h[nvec * n + i] = T::one();
+        }
+        let mut g = vec![T::zero(); n];
+        g[nvec] = T::one();
+
+        let device = self.xs[0].device().clone();
+        let to_matrix = |h: Vec<T>| asarray_f((h, [n, n].c(), &device))?.into_dim_f::<Ix2>();
+        let g = asarray_f((g, [n].c(), &device))?.into_dim_f::<Ix1>()?;
+        let coeff = match SolveGeneralAPI::<B>::solve_general_f((to_matrix(h.clone())?, g)) {
+            Ok(c) => Some(optimize_to_vec_f(c.view())?),
+            Err(_) => None,
+        };
+        let coeff = match coeff {
+            Some(coeff) if coeff.iter().all(|v| v.is_finite()) => coeff,
+            _ => {
+                let pinv = PinvAPI::<B>::pinv_f(to_matrix(h)?)?.pinv;
+                let pinv = optimize_to_vec_f(pinv.view())?;
+                (0..n).map(|i| pinv[i * n + nvec]).collect()
+            },
+        };
+        Ok(coeff[..nvec].to_vec())
+    }
+}
+
+#[cfg(all(test, feature = "faer"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_diis_linear_fixed_point() {
+        // fixed-point iteration x <- A x + b, slowly convergent; DIIS (Anderson mixing) solves it
+        // within dimension + 1 iterations (pushed error lags by one)
+        let device = DeviceFaer::default();
+        let n = 6;
+        let a = (0..n * n).map(|k| 0.5 * ((k * 7 % 11) as f64 / 11.0 - 0.3)).collect::<Vec<_>>();
+        let a = asarray((a, [n, n].c(), &device));
+        let b = asarray((vec![1.0, -0.5, 0.3, 0.8, -1.2, 0.1], &device));
+        let f = |x: &Tensor<f64, DeviceFaer, IxD>| &a % x + &b;
+
+        let mut diis = DIIS::new(8);
+        let mut x = zeros(([n], &device)).into_dim::<IxD>();
+        let mut err_norm = f64::INFINITY;
+        for _ in 0..n + 2 {
+            let x_new = f(&x);
+            let err = &x_new - &x;
+            x = diis.update(x_new.view(), err.view());
+            err_norm = diis.last_error_norm_sq().unwrap().sqrt();
+        }
+        assert!(err_norm < 1e-9);
+        assert!((&f(&x) - &x).l2_norm() < 1e-9);
+
+        // plain iteration is far from converged in the same number of steps
+        let mut y = zeros(([n], &device)).into_dim::<IxD>();
+        for _ in 0..n + 2 {
+            y = f(&y);
+        }
+        assert!((&f(&y) - &y).l2_norm() > 1e-8);
+    }
+
+    #[test]
+    fn test_diis_complex_linear_fixed_point() {
+        use num::complex::{c64, Complex64};
+
+        let device = DeviceFaer::default();
+        let n = 5;
+        let a =
+            (0..n * n).map(|k| c64(0.4 * ((k * 7 % 11) as f64 / 11.0 - 0.3), 0.2 * ((k * 3 % 7) as f64 / 7.0 - 0.5)));
+        let a = asarray((a.collect::<Vec<_>>(), [n, n].c(), &device));
+        let b = asarray((vec![c64(1.0, 0.5), c64(-0.5, 0.0), c64(0.3, -0.7), c64(0.8, 0.2), c64(-1.2, 1.0)], &device));
+        let f = |x: &Tensor<Complex64, DeviceFaer, IxD>| &a % x + &b;
+
+        let mut diis = DIIS::new(8);
+        let mut x = zeros(([n], &device)).into_dim::<IxD>();
+        for _ in 0..n + 2 {
+            let x_new = f(&x);
+            let err = &x_new - &x;
+            x = diis.update(x_new.view(), err.view());
+        }
+        assert!(diis.last_error_norm_sq().unwrap().sqrt() < 1e-9);
+        assert!((&f(&x) - &x).l2_norm() < 1e-9);
+    }
+
+    #[test]
+    fn test_diis_subspace_and_dependency() {
+        let device = DeviceFaer::default();
+        let mut diis = DIIS::new(3).with_min_space(2);
+        let x = asarray((vec![1.0, 2.0, 3.0, 4.0], [2, 2].c(), &device));
+        let err = asarray((vec![0.1, 0.2], &device));
+        // first update is not extrapolated
+        let out = diis.update(x.view(), err.view());
+        assert_eq!(out.shape(), &[2, 2]);
+        assert!(allclose_f64(&out, &x));
+        // identical error vectors make B-matrix singular; any affine combination is valid
+        let x2 = &x * 3.0;
+        let out = diis.update(x2.view(), err.view());
+        let ratio = out.raw()[0] / x.raw()[0];
+        assert!(ratio.is_finite());
+        assert!(allclose_f64(&out, &(&x * ratio)));
+        for k in 0..5 {
+            let xk = &x * k as f64;
+            let ek = &err * (0.5_f64).powi(k);
+            diis.update(xk.view(), ek.view());
+        }
+        assert_eq!(diis.len(), 3);
+        assert!((diis.last_error_norm_sq().unwrap() - 0.05 * 0.5_f64.powi(8)).abs() < 1e-15);
+        diis.reset();
+        assert!(diis.is_empty());
+    }
+}
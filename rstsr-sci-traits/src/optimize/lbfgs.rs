@@ -0,0 +1,193 @@
+//! Limited-memory BFGS minimization.
+//!
+//! # See also
+//!
+//! - J. Nocedal, S. J. Wright, *Numerical Optimization* (2nd ed.), Algorithm 7.4 and 7.5.
+//! - [SciPy `minimize(method='L-BFGS-B')`](https://docs.scipy.org/doc/scipy/reference/optimize.minimize-lbfgsb.html)
+
+use crate::optimize::common::*;
+use rstsr_core::prelude_dev::*;
+use std::collections::VecDeque;
+
+/// Configuration of [`lbfgs`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LbfgsConfig {
+    /// Number of stored correction pairs. A memory not smaller than the number of iterations
+    /// makes this equivalent to full BFGS.
+    pub memory: usize,
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+    /// Convergence threshold on max-abs of gradient.
+    pub gtol: f64,
+    /// Maximum length (2-norm) of one step; useful in geometry optimization.
+    pub max_step: f64,
+    /// Sufficient decrease (Armijo) parameter of backtracking line search.
+    pub c1: f64,
+    /// Maximum number of objective evaluations in one line search.
+    pub max_linesearch: usize,
+}
+
+impl Default for LbfgsConfig {
+    fn default() -> Self {
+        Self { memory: 10, max_iter: 500, gtol: 1e-6, max_step: f64::INFINITY, c1: 1e-4, max_linesearch: 30 }
+    }
+}
+
+/// Minimize objective by L-BFGS with backtracking line search.
+///
+/// # Arguments
+///
+/// * `fg` - Closure returning objective value and gradient (same shape as `x`) at `x`.
+/// * `x0` - Initial guess; tensor of any shape.
+/// * `config` - See [`LbfgsConfig`].
+///
+/// Not converging within `config.max_iter` is not an error; check
+/// [`OptimizeResult::converged`].
+pub fn lbfgs_f<B, D, F>(mut fg: F, x0: TensorView<'_, f64, B, D>, config: &LbfgsConfig) -> Result<OptimizeResult<B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    D: DimAPI,
+    F: FnMut(TensorView<'_, f64, B, D>) -> (f64, Tensor<f64, B, D>),
+{
+    rstsr_assert!(config.memory > 0, InvalidValue, "L-BFGS memory should be positive.")?;
+    rstsr_assert!(config.max_step > 0.0, InvalidValue, "Maximum step length should be positive.")?;
+    let device = x0.device().clone();
+    let layout = x0.shape().new_c_contig(None);
+    let to_tensor = |x: &[f64]| -> Result<Tensor<f64, B, D>> {
+        asarray_f((x.to_vec(), layout.clone(), &device))?.into_dim_f::<D>()
+    };
+    let mut eval = |x: &[f64], nfev: &mut usize| -> Result<(f64, Vec<f64>)> {
+        *nfev += 1;
+        let (f, g) = fg(to_tensor(x)?.view());
+        rstsr_assert_eq!(g.size(), x.len(), InvalidLayout, "Size of gradient and x mismatch.")?;
+        Ok((f, optimize_to_vec_f(g.view())?))
+    };
+
+    let mut nfev = 0;
+    let mut x = optimize_to_vec_f(x0)?;
+    let (mut f, mut g) = eval(&x, &mut nfev)?;
+    let mut history: VecDeque<(Vec<f64>, Vec<f64>, f64)> = VecDeque::with_capacity(config.memory);
+    let mut converged = norm_inf(&g) < config.gtol;
+    let mut nit = 0;
+
+    while !converged && nit < config.max_iter {
+        nit += 1;
+
+        // two-loop recursion for direction d = -H g
+        let mut q = g.clone();
+        let mut alphas = Vec::with_capacity(history.len());
+        for (s, y, rho) in history.iter().rev() {
+            let alpha = rho * dot(s, &q);
+            q.iter_mut().zip(y).for_each(|(q, y)| *q -= alpha * y);
+            alphas.push(alpha);
+        }
+        let gamma = match history.back() {
+            Some((s, y, _)) => dot(s, y) / dot(y, y),
+            None => 1.0 / dot(&g, &g).sqrt().max(1.0),
+        };
+        q.iter_mut().for_each(|q| *q *= gamma);
+        for ((s, y, rho), alpha) in history.iter().zip(alphas.iter().rev()) {
+            let beta = rho * dot(y, &q);
+            q.iter_mut().zip(s).for_each(|(q, s)| *q += (alpha - beta) * s);
+        }
+        let mut d = q.into_iter().map(|v| -v).collect_vec();
+        let mut dg = dot(&d, &g);
+        if dg >= 0.0 || !dg.is_finite() {
+            // not a descent direction; restart from steepest descent
+            history.clear();
+            let scale = 1.0 / dot(&g, &g).sqrt().max(1.0);
+            d = g.iter().map(|v| -scale * v).collect();
+            dg = dot(&d, &g);
+        }
+        let dnorm = dot(&d, &d).sqrt();
+        if dnorm > config.max_step {
+            let scale = config.max_step / dnorm;
+            d.iter_mut().for_each(|v| *v *= scale);
+            dg *= scale;
+        }
+
+        // backtracking line search (Armijo)
+        let mut step = 1.0;
+        let mut accepted = None;
+        for _ in 0..config.max_linesearch {
+            let x_new = izip!(&x, &d).map(|(x, d)| x + step * d).collect_vec();
+            let (f_new, g_new) = eval(&x_new, &mut nfev)?;
+            if f_new.is_finite() && f_new <= f + config.c1 * step * dg {
+                accepted = Some((x_new, f_new, g_new));
+                break;
+            }
+            step *= 0.5;
+        }
+        let Some((x_new, f_new, g_new)) = accepted else {
+            // line search failed; no further progress is possible
+            break;
+        };
+
+        let s = izip!(&x_new, &x).map(|(a, b)| a - b).collect_vec();
+        let y = izip!(&g_new, &g).map(|(a, b)| a - b).collect_vec();
+        let sy = dot(&s, &y);
+        // skip update if curvature condition is violated
+        if sy > f64::EPSILON * dot(&y, &y) {
+            if history.len() == config.memory {
+                history.pop_front();
+            }
+            history.push_back((s, y, 1.0 / sy));
+        }
+        (x, f, g) = (x_new, f_new, g_new);
+        converged = norm_inf(&g) < config.gtol;
+    }
+
+    Ok(OptimizeResult { x: to_tensor(&x)?, fun: f, grad: to_tensor(&g)?, nit, nfev, converged })
+}
+
+/// Minimize objective by L-BFGS with backtracking line search.
+///
+/// See also [`lbfgs_f`].
+pub fn lbfgs<B, D, F>(fg: F, x0: TensorView<'_, f64, B, D>, config: &LbfgsConfig) -> OptimizeResult<B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    D: DimAPI,
+    F: FnMut(TensorView<'_, f64, B, D>) -> (f64, Tensor<f64, B, D>),
+{
+    lbfgs_f(fg, x0, config).rstsr_unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Extended Rosenbrock function on tensor of any shape.
+    fn rosenbrock<B>(x: TensorView<'_, f64, B, IxD>) -> (f64, Tensor<f64, B, IxD>)
+    where
+        B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    {
+        let v = optimize_to_vec_f(x.view()).unwrap();
+        let mut f = 0.0;
+        let mut g = vec![0.0; v.len()];
+        for i in 0..v.len() - 1 {
+            let (a, b) = (v[i], v[i + 1]);
+            f += 100.0 * (b - a * a).powi(2) + (1.0 - a).powi(2);
+            g[i] += -400.0 * a * (b - a * a) - 2.0 * (1.0 - a);
+            g[i + 1] += 200.0 * (b - a * a);
+        }
+        (f, asarray((g, x.shape().c(), x.device())))
+    }
+
+    #[test]
+    fn test_lbfgs_rosenbrock() {
+        let device = DeviceCpuSerial::default();
+        let x0 = asarray((vec![-1.2, 1.0, -1.2, 1.0, 0.5, 0.0], [3, 2].c(), &device));
+        let result = lbfgs(rosenbrock, x0.view(), &LbfgsConfig::default());
+        assert!(result.converged);
+        assert_eq!(result.x.shape(), &[3, 2]);
+        assert!(result.x.raw().iter().all(|v| (v - 1.0).abs() < 1e-5));
+        assert!(result.fun < 1e-10);
+
+        // step capped; does not converge within few iterations
+        let config = LbfgsConfig { max_step: 0.1, max_iter: 5, ..Default::default() };
+        let result = lbfgs(rosenbrock, x0.view(), &config);
+        assert!(!result.converged);
+        assert_eq!(result.nit, 5);
+        assert!(izip!(result.x.raw(), x0.raw()).map(|(a, b)| (a - b).powi(2)).sum::<f64>().sqrt() <= 0.5 + 1e-12);
+    }
+}
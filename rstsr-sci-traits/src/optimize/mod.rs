@@ -0,0 +1,8 @@
+pub mod prelude;
+
+pub mod common;
+#[cfg(feature = "linalg")]
+pub mod diis;
+pub mod lbfgs;
+#[cfg(feature = "linalg")]
+pub mod newton;
@@ -0,0 +1,232 @@
+//! Trust-region Newton minimization (dogleg).
+//!
+//! # See also
+//!
+//! - J. Nocedal, S. J. Wright, *Numerical Optimization* (2nd ed.), Algorithm 4.1 and Section 4.1.
+//! - [SciPy `minimize(method='dogleg')`](https://docs.scipy.org/doc/scipy/reference/optimize.minimize-dogleg.html)
+
+use crate::optimize::common::*;
+use rstsr_core::prelude_dev::*;
+use rstsr_linalg_traits::prelude::rstsr_traits::SolveGeneralAPI;
+
+/// Configuration of [`newton_trust_region`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewtonConfig {
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+    /// Convergence threshold on max-abs of gradient.
+    pub gtol: f64,
+    /// Initial trust radius.
+    pub initial_trust_radius: f64,
+    /// Maximum trust radius.
+    pub max_trust_radius: f64,
+    /// Minimum ratio of actual to predicted reduction for accepting a step.
+    pub eta: f64,
+}
+
+impl Default for NewtonConfig {
+    fn default() -> Self {
+        Self { max_iter: 200, gtol: 1e-8, initial_trust_radius: 1.0, max_trust_radius: 1000.0, eta: 0.15 }
+    }
+}
+
+/// Minimize objective by trust-region Newton method with dogleg steps.
+///
+/// The Newton step $H p = -g$ is solved on device by
+/// [`solve_general`](rstsr_linalg_traits::prelude::rstsr_funcs::solve_general). When the Newton
+/// step is not a descent direction (or the Hessian is singular), the step falls back to steepest
+/// descent within the trust region. As for any dogleg method, the Hessian is expected to be
+/// positive definite near the solution.
+///
+/// # Arguments
+///
+/// * `fg` - Closure returning objective value and gradient (same shape as `x`) at `x`.
+/// * `hess` - Closure returning Hessian at `x`, of shape `(n, n)` for `x.size() == n`.
+/// * `x0` - Initial guess; tensor of any shape.
+/// * `config` - See [`NewtonConfig`].
+///
+/// Not converging within `config.max_iter` is not an error; check
+/// [`OptimizeResult::converged`].
+pub fn newton_trust_region_f<B, D, F, H>(
+    mut fg: F,
+    mut hess: H,
+    x0: TensorView<'_, f64, B, D>,
+    config: &NewtonConfig,
+) -> Result<OptimizeResult<B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    D: DimAPI,
+    F: FnMut(TensorView<'_, f64, B, D>) -> (f64, Tensor<f64, B, D>),
+    H: FnMut(TensorView<'_, f64, B, D>) -> Tensor<f64, B, Ix2>,
+    (Tensor<f64, B, Ix2>, Tensor<f64, B, Ix1>): SolveGeneralAPI<B, Out = Tensor<f64, B, Ix1>>,
+{
+    rstsr_assert!(config.initial_trust_radius > 0.0, InvalidValue, "Trust radius should be positive.")?;
+    rstsr_assert!(
+        config.max_trust_radius >= config.initial_trust_radius,
+        InvalidValue,
+        "Maximum trust radius should not be smaller than initial trust radius."
+    )?;
+    let device = x0.device().clone();
+    let layout = x0.shape().new_c_contig(None);
+    let n = x0.size();
+    let to_tensor = |x: &[f64]| -> Result<Tensor<f64, B, D>> {
+        asarray_f((x.to_vec(), layout.clone(), &device))?.into_dim_f::<D>()
+    };
+    let mut eval = |x: &[f64], nfev: &mut usize| -> Result<(f64, Vec<f64>)> {
+        *nfev += 1;
+        let (f, g) = fg(to_tensor(x)?.view());
+        rstsr_assert_eq!(g.size(), n, InvalidLayout, "Size of gradient and x mismatch.")?;
+        Ok((f, optimize_to_vec_f(g.view())?))
+    };
+
+    let mut nfev = 0;
+    let mut x = optimize_to_vec_f(x0)?;
+    let (mut f, mut g) = eval(&x, &mut nfev)?;
+    let mut radius = config.initial_trust_radius;
+    let mut converged = norm_inf(&g) < config.gtol;
+    let mut nit = 0;
+    // Hessian is only re-evaluated after an accepted step
+    let mut h_cache: Option<(Vec<f64>, Option<Vec<f64>>)> = None;
+
+    while !converged && nit < config.max_iter {
+        nit += 1;
+
+        if h_cache.is_none() {
+            let h = hess(to_tensor(&x)?.view());
+            rstsr_assert_eq!(h.shape(), &[n, n], InvalidLayout, "Hessian should be of shape ({n}, {n}).")?;
+            let h_vec = optimize_to_vec_f(h.view())?;
+            // Newton step, only kept if it is a descent direction
+            let rhs = asarray_f((g.iter().map(|v| -v).collect_vec(), [n].c(), &device))?.into_dim_f::<Ix1>()?;
+            let p_newton = match SolveGeneralAPI::<B>::solve_general_f((h, rhs)) {
+                Ok(p) => Some(optimize_to_vec_f(p.view())?),
+                Err(_) => None,
+            };
+            let p_newton = p_newton.filter(|p| p.iter().all(|v| v.is_finite()) && dot(p, &g) < 0.0);
+            h_cache = Some((h_vec, p_newton));
+        }
+        let (h_vec, p_newton) = h_cache.as_ref().unwrap();
+        let hdot = |p: &[f64]| -> Vec<f64> { h_vec.chunks_exact(n).map(|row| dot(row, p)).collect() };
+
+        // dogleg step
+        let gnorm = dot(&g, &g).sqrt();
+        let steepest_to_boundary = || g.iter().map(|v| -radius / gnorm * v).collect_vec();
+        let p = match p_newton {
+            Some(p_n) if dot(p_n, p_n).sqrt() <= radius => p_n.clone(),
+            _ => {
+                let ghg = dot(&g, &hdot(&g));
+                if ghg <= 0.0 {
+                    steepest_to_boundary()
+                } else {
+                    let tau = dot(&g, &g) / ghg;
+                    let p_u = g.iter().map(|v| -tau * v).collect_vec();
+                    let p_u_norm = tau * gnorm;
+                    match p_newton {
+                        _ if p_u_norm >= radius => steepest_to_boundary(),
+                        Some(p_n) => {
+                            // solve |p_u + t (p_n - p_u)| = radius for t in [0, 1]
+                            let diff = izip!(p_n, &p_u).map(|(a, b)| a - b).collect_vec();
+                            let a = dot(&diff, &diff);
+                            let b = 2.0 * dot(&p_u, &diff);
+                            let c = dot(&p_u, &p_u) - radius * radius;
+                            let t = (-b + (b * b - 4.0 * a * c).max(0.0).sqrt()) / (2.0 * a);
+                            izip!(&p_u, &diff).map(|(u, d)| u + t * d).collect_vec()
+                        },
+                        None => p_u,
+                    }
+                }
+            },
+        };
+        let pnorm = dot(&p, &p).sqrt();
+        let predicted = -(dot(&g, &p) + 0.5 * dot(&p, &hdot(&p)));
+
+        let x_new = izip!(&x, &p).map(|(x, p)| x + p).collect_vec();
+        let (f_new, g_new) = eval(&x_new, &mut nfev)?;
+        let actual = f - f_new;
+        let rho = if predicted > 0.0 && f_new.is_finite() { actual / predicted } else { -1.0 };
+
+        if rho < 0.25 {
+            radius = 0.25 * pnorm;
+        } else if rho > 0.75 && pnorm >= 0.99 * radius {
+            radius = (2.0 * radius).min(config.max_trust_radius);
+        }
+        if rho > config.eta {
+            (x, f, g) = (x_new, f_new, g_new);
+            h_cache = None;
+            converged = norm_inf(&g) < config.gtol;
+        } else if radius < f64::EPSILON * (1.0 + dot(&x, &x).sqrt()) {
+            // trust region collapsed; no further progress is possible
+            break;
+        }
+    }
+
+    Ok(OptimizeResult { x: to_tensor(&x)?, fun: f, grad: to_tensor(&g)?, nit, nfev, converged })
+}
+
+/// Minimize objective by trust-region Newton method with dogleg steps.
+///
+/// See also [`newton_trust_region_f`].
+pub fn newton_trust_region<B, D, F, H>(
+    fg: F,
+    hess: H,
+    x0: TensorView<'_, f64, B, D>,
+    config: &NewtonConfig,
+) -> OptimizeResult<B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceCreationAnyAPI<f64> + OpAssignArbitaryAPI<f64, IxD, IxD>,
+    D: DimAPI,
+    F: FnMut(TensorView<'_, f64, B, D>) -> (f64, Tensor<f64, B, D>),
+    H: FnMut(TensorView<'_, f64, B, D>) -> Tensor<f64, B, Ix2>,
+    (Tensor<f64, B, Ix2>, Tensor<f64, B, Ix1>): SolveGeneralAPI<B, Out = Tensor<f64, B, Ix1>>,
+{
+    newton_trust_region_f(fg, hess, x0, config).rstsr_unwrap()
+}
+
+#[cfg(all(test, feature = "faer"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_newton_trust_region() {
+        let device = DeviceFaer::default();
+        // Rosenbrock function of two variables
+        let fg = |x: TensorView<'_, f64, DeviceFaer, Ix1>| {
+            let (a, b) = (x[[0]], x[[1]]);
+            let f = 100.0 * (b - a * a).powi(2) + (1.0 - a).powi(2);
+            let g = vec![-400.0 * a * (b - a * a) - 2.0 * (1.0 - a), 200.0 * (b - a * a)];
+            (f, asarray((g, x.device())).into_dim::<Ix1>())
+        };
+        let hess = |x: TensorView<'_, f64, DeviceFaer, Ix1>| {
+            let (a, b) = (x[[0]], x[[1]]);
+            let h = vec![1200.0 * a * a - 400.0 * b + 2.0, -400.0 * a, -400.0 * a, 200.0];
+            asarray((h, [2, 2].c(), x.device())).into_dim::<Ix2>()
+        };
+        let x0 = asarray((vec![-1.2, 1.0], &device)).into_dim::<Ix1>();
+        let result = newton_trust_region(fg, hess, x0.view(), &NewtonConfig::default());
+        assert!(result.converged);
+        assert!((result.x[[0]] - 1.0).abs() < 1e-8);
+        assert!((result.x[[1]] - 1.0).abs() < 1e-8);
+        assert!(result.nit < 50);
+
+        // quadratic objective on tensor of shape (2, 2) is solved by one Newton step
+        let a = asarray((
+            vec![4.0, 1.0, 0.0, 0.0, 1.0, 3.0, 0.0, 0.0, 0.0, 0.0, 2.0, -1.0, 0.0, 0.0, -1.0, 2.0],
+            [4, 4].c(),
+            &device,
+        ))
+        .into_dim::<Ix2>();
+        let b = asarray((vec![1.0, 2.0, 3.0, 4.0], [2, 2].c(), &device)).into_dim::<Ix2>();
+        let fg = |x: TensorView<'_, f64, DeviceFaer, Ix2>| {
+            let ax = (&a % x.reshape(-1)).into_shape((2, 2)).into_dim::<Ix2>();
+            let f = 0.5 * (&ax * &x).sum() - (&b * &x).sum();
+            (f, &ax - &b)
+        };
+        let hess = |_: TensorView<'_, f64, DeviceFaer, Ix2>| a.to_owned();
+        let x0 = zeros(([2, 2], &device)).into_dim::<Ix2>();
+        let config = NewtonConfig { initial_trust_radius: 100.0, ..Default::default() };
+        let result = newton_trust_region(fg, hess, x0.view(), &config);
+        assert!(result.converged);
+        assert_eq!(result.nit, 1);
+        assert_eq!(result.x.shape(), &[2, 2]);
+        assert!((&a % result.x.reshape(-1) - b.reshape(-1)).l2_norm() < 1e-12);
+    }
+}
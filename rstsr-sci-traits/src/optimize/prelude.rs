@@ -0,0 +1,14 @@
+pub mod rstsr_funcs {
+    pub use crate::optimize::lbfgs::{lbfgs, lbfgs_f};
+    #[cfg(feature = "linalg")]
+    pub use crate::optimize::newton::{newton_trust_region, newton_trust_region_f};
+}
+
+pub mod rstsr_structs {
+    pub use crate::optimize::common::OptimizeResult;
+    #[cfg(feature = "linalg")]
+    pub use crate::optimize::diis::DIIS;
+    pub use crate::optimize::lbfgs::LbfgsConfig;
+    #[cfg(feature = "linalg")]
+    pub use crate::optimize::newton::NewtonConfig;
+}
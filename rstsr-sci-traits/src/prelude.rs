@@ -1,16 +1,25 @@
 pub mod rstsr_traits {
     pub use crate::distance::prelude::rstsr_traits::*;
     pub use crate::integrate::prelude::rstsr_traits::*;
+    pub use crate::interpolate::prelude::rstsr_traits::*;
+    pub use crate::spatial::prelude::rstsr_traits::*;
+    pub use crate::special::prelude::rstsr_traits::*;
 }
 
 pub mod rstsr_funcs {
     pub use crate::distance::prelude::rstsr_funcs::*;
     pub use crate::integrate::prelude::rstsr_funcs::*;
+    pub use crate::interpolate::prelude::rstsr_funcs::*;
+    pub use crate::optimize::prelude::rstsr_funcs::*;
+    pub use crate::special::prelude::rstsr_funcs::*;
 }
 
 pub mod rstsr_structs {
     pub use crate::distance::prelude::rstsr_structs::*;
     pub use crate::integrate::prelude::rstsr_structs::*;
+    pub use crate::interpolate::prelude::rstsr_structs::*;
+    pub use crate::optimize::prelude::rstsr_structs::*;
+    pub use crate::spatial::prelude::rstsr_structs::*;
 }
 
 pub mod rstsr_mods {
@@ -25,6 +34,28 @@ pub mod rstsr_mods {
         pub use crate::integrate::prelude::rstsr_structs::*;
         pub use crate::integrate::prelude::rstsr_traits::*;
     }
+
+    pub mod interpolate {
+        pub use crate::interpolate::prelude::rstsr_funcs::*;
+        pub use crate::interpolate::prelude::rstsr_structs::*;
+        pub use crate::interpolate::prelude::rstsr_traits::*;
+        pub use crate::optimize::prelude::rstsr_funcs::*;
+    }
+
+    pub mod optimize {
+        pub use crate::optimize::prelude::rstsr_funcs::*;
+        pub use crate::optimize::prelude::rstsr_structs::*;
+    }
+
+    pub mod spatial {
+        pub use crate::spatial::prelude::rstsr_structs::*;
+        pub use crate::spatial::prelude::rstsr_traits::*;
+    }
+
+    pub mod special {
+        pub use crate::special::prelude::rstsr_funcs::*;
+        pub use crate::special::prelude::rstsr_traits::*;
+    }
 }
 
 pub mod distance {
@@ -34,3 +65,19 @@ pub mod distance {
 pub mod integrate {
     pub use crate::integrate::prelude::*;
 }
+
+pub mod interpolate {
+    pub use crate::interpolate::prelude::*;
+}
+
+pub mod optimize {
+    pub use crate::optimize::prelude::*;
+}
+
+pub mod spatial {
+    pub use crate::spatial::prelude::*;
+}
+
+pub mod special {
+    pub use crate::special::prelude::*;
+}
@@ -0,0 +1,54 @@
+/// Rayon-parallel `spatial` for CPU device `$Device` with generic parameters
+/// `$G` and their bounds, shared by `DeviceFaer` and `DeviceBLAS<B>`.
+macro_rules! impl_spatial_rayon {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use crate::prelude_dev::*;
+            use num::Float;
+            use rstsr_sci_traits::spatial::kdtree::*;
+            use rstsr_sci_traits::spatial::metric::KDTreeMetricAPI;
+
+            impl<$($G,)* T, M> KDTreeQueryAPI<T, M> for $Device
+            where
+                $($bound)*
+                T: Float + Send + Sync,
+                M: KDTreeMetricAPI<T> + Sync,
+                $Device: DeviceAPI<T, Raw = Vec<T>>,
+            {
+                fn kdtree_query_f(
+                    &self,
+                    tree: &KDTree<T, Self>,
+                    x: &[T],
+                    lx: &Layout<Ix2>,
+                    k: usize,
+                    metric: &M,
+                ) -> Result<(Vec<T>, Vec<usize>)> {
+                    kdtree_query_rayon(tree, x, lx, k, metric, self.get_current_pool())
+                }
+
+                fn kdtree_query_ball_point_f(
+                    &self,
+                    tree: &KDTree<T, Self>,
+                    x: &[T],
+                    lx: &Layout<Ix2>,
+                    r: T,
+                    metric: &M,
+                ) -> Result<Vec<Vec<usize>>> {
+                    kdtree_query_ball_point_rayon(tree, x, lx, r, metric, self.get_current_pool())
+                }
+
+                fn kdtree_sparse_distance_matrix_f(
+                    &self,
+                    tree: &KDTree<T, Self>,
+                    other: &KDTree<T, Self>,
+                    max_distance: T,
+                    metric: &M,
+                ) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)> {
+                    kdtree_sparse_distance_matrix_rayon(tree, other, max_distance, metric, self.get_current_pool())
+                }
+            }
+        };
+    };
+}
+
+pub(crate) use impl_spatial_rayon;
@@ -0,0 +1,4 @@
+use super::auto_impl_rayon::impl_spatial_rayon;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
+
+impl_spatial_rayon!([B] DeviceBLAS<B> where [B: CblasBackendAPI,]);
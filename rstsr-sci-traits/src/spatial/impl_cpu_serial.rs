@@ -0,0 +1,43 @@
+use crate::prelude_dev::*;
+use num::Float;
+use rstsr_sci_traits::spatial::kdtree::*;
+use rstsr_sci_traits::spatial::metric::KDTreeMetricAPI;
+
+impl<T, M> KDTreeQueryAPI<T, M> for DeviceCpuSerial
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+    DeviceCpuSerial: DeviceAPI<T, Raw = Vec<T>>,
+{
+    fn kdtree_query_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        x: &[T],
+        lx: &Layout<Ix2>,
+        k: usize,
+        metric: &M,
+    ) -> Result<(Vec<T>, Vec<usize>)> {
+        kdtree_query_serial(tree, x, lx, k, metric)
+    }
+
+    fn kdtree_query_ball_point_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        x: &[T],
+        lx: &Layout<Ix2>,
+        r: T,
+        metric: &M,
+    ) -> Result<Vec<Vec<usize>>> {
+        kdtree_query_ball_point_serial(tree, x, lx, r, metric)
+    }
+
+    fn kdtree_sparse_distance_matrix_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        other: &KDTree<T, Self>,
+        max_distance: T,
+        metric: &M,
+    ) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)> {
+        kdtree_sparse_distance_matrix_serial(tree, other, max_distance, metric)
+    }
+}
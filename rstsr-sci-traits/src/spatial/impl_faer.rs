@@ -0,0 +1,32 @@
+use super::auto_impl_rayon::impl_spatial_rayon;
+
+impl_spatial_rayon!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude_dev::*;
+    use rstsr_sci_traits::distance::metric::MetricEuclidean;
+    use rstsr_sci_traits::distance::traits::cdist;
+    use rstsr_sci_traits::spatial::kdtree::*;
+
+    #[test]
+    fn test_kdtree() {
+        let device = DeviceFaer::default();
+        let x = linspace((0., 1., 30000, &device)).mapv(|v: f64| (v * 12345.678).sin()).into_shape((10000, 3));
+        let q = linspace((0., 1., 3000, &device)).mapv(|v: f64| (v * 987.654).cos()).into_shape((1000, 3));
+        let tree = KDTree::new(x.view());
+        let (dists, indices) = tree.query(q.view(), 4, MetricEuclidean);
+        let d_full = cdist((q.view(), x.view())).into_dim::<Ix2>();
+        for i in 0..1000 {
+            for j in 0..4 {
+                assert!((d_full[[i, indices[[i, j]]]] - dists[[i, j]]).abs() < 1e-12);
+            }
+        }
+
+        let balls = tree.query_ball_point(q.view(), 0.1, MetricEuclidean);
+        let tree_q = KDTree::new(q.view());
+        let (rows, _, _) = tree_q.sparse_distance_matrix(&tree, 0.1, MetricEuclidean);
+        assert_eq!(rows.size(), balls.iter().map(|b| b.len()).sum::<usize>());
+        assert_eq!(rows.size(), d_full.iter().filter(|&&d| d <= 0.1).count());
+    }
+}
@@ -0,0 +1,744 @@
+//! KD-tree for nearest-neighbor search.
+//!
+//! # See also
+//!
+//! [SciPy `cKDTree`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.spatial.cKDTree.html)
+
+use crate::spatial::metric::KDTreeMetricAPI;
+use core::cmp::Ordering;
+use num::Float;
+use rayon::prelude::*;
+use rstsr_core::prelude_dev::*;
+use std::collections::BinaryHeap;
+
+/* #region tree structure */
+
+/// Default number of points in leaf node (same to SciPy).
+pub const KDTREE_DEFAULT_LEAFSIZE: usize = 16;
+
+#[derive(Debug, Clone)]
+struct KDTreeNode {
+    /// Range of permuted indices covered by this node.
+    start: usize,
+    end: usize,
+    /// Children of this node; `None` for leaf node.
+    children: Option<(usize, usize)>,
+}
+
+/// KD-tree for fast nearest-neighbor lookup of points in Minkowski-family metrics.
+///
+/// The tree keeps its own (row-major) copy of points, so the original tensor can be dropped
+/// after construction. Splitting is performed at median of the dimension with largest spread;
+/// each node stores its bounding box, which is used for pruning.
+///
+/// Batch queries ([`query`](KDTree::query), [`query_ball_point`](KDTree::query_ball_point),
+/// [`sparse_distance_matrix`](KDTree::sparse_distance_matrix)) are dispatched by device through
+/// [`KDTreeQueryAPI`]; devices with rayon parallelize over query points.
+///
+/// Metric is given at query time, and should implement [`KDTreeMetricAPI`]
+/// (`MetricEuclidean`, `MetricSqEuclidean`, `MetricCityBlock`, `MetricChebyshev`,
+/// `MetricMinkowski`).
+#[derive(Debug, Clone)]
+pub struct KDTree<T, B> {
+    data: Vec<T>,
+    npoints: usize,
+    ndim: usize,
+    leafsize: usize,
+    indices: Vec<usize>,
+    nodes: Vec<KDTreeNode>,
+    bbox_mins: Vec<T>,
+    bbox_maxes: Vec<T>,
+    device: B,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct HeapItem<T> {
+    dist: T,
+    idx: usize,
+}
+
+impl<T: PartialOrd> PartialEq for HeapItem<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for HeapItem<T> {}
+
+impl<T: PartialOrd> PartialOrd for HeapItem<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for HeapItem<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist.partial_cmp(&other.dist).unwrap_or(Ordering::Equal).then(self.idx.cmp(&other.idx))
+    }
+}
+
+/* #endregion */
+
+/* #region tree construction and single-point search */
+
+impl<T, B> KDTree<T, B>
+where
+    T: Float,
+{
+    /// Build KD-tree from raw data of `x` with 2-D layout `lx` (points by rows).
+    pub fn build_f(x: &[T], lx: &Layout<Ix2>, leafsize: usize, device: B) -> Result<Self> {
+        rstsr_assert!(leafsize > 0, InvalidValue, "leafsize should be positive.")?;
+        let [npoints, ndim] = *lx.shape();
+        let [s0, s1] = *lx.stride();
+        let offset = lx.offset() as isize;
+        let mut data = Vec::with_capacity(npoints * ndim);
+        for i in 0..npoints {
+            for d in 0..ndim {
+                data.push(x[(offset + i as isize * s0 + d as isize * s1) as usize]);
+            }
+        }
+
+        let mut tree = Self {
+            data,
+            npoints,
+            ndim,
+            leafsize,
+            indices: (0..npoints).collect(),
+            nodes: vec![],
+            bbox_mins: vec![],
+            bbox_maxes: vec![],
+            device,
+        };
+        tree.build_node(0, npoints);
+        Ok(tree)
+    }
+
+    fn build_node(&mut self, start: usize, end: usize) -> usize {
+        let ndim = self.ndim;
+        let node = self.nodes.len();
+        self.nodes.push(KDTreeNode { start, end, children: None });
+
+        // bounding box of this node
+        let mut mins = vec![T::infinity(); ndim];
+        let mut maxes = vec![T::neg_infinity(); ndim];
+        for &i in &self.indices[start..end] {
+            for d in 0..ndim {
+                let val = self.data[i * ndim + d];
+                mins[d] = mins[d].min(val);
+                maxes[d] = maxes[d].max(val);
+            }
+        }
+        self.bbox_mins.extend_from_slice(&mins);
+        self.bbox_maxes.extend_from_slice(&maxes);
+
+        if end - start <= self.leafsize {
+            return node;
+        }
+
+        // split at median of dimension with largest spread
+        let (split_dim, spread) =
+            (0..ndim)
+                .map(|d| (d, maxes[d] - mins[d]))
+                .fold((0, T::zero()), |acc, cur| if cur.1 > acc.1 { cur } else { acc });
+        if spread == T::zero() {
+            // all points are identical, no need to split
+            return node;
+        }
+        let mid = start + (end - start) / 2;
+        let data = &self.data;
+        self.indices[start..end].select_nth_unstable_by(mid - start, |&a, &b| {
+            data[a * ndim + split_dim].partial_cmp(&data[b * ndim + split_dim]).unwrap_or(Ordering::Equal)
+        });
+
+        let lesser = self.build_node(start, mid);
+        let greater = self.build_node(mid, end);
+        self.nodes[node].children = Some((lesser, greater));
+        node
+    }
+
+    /// Number of points in tree.
+    pub fn npoints(&self) -> usize {
+        self.npoints
+    }
+
+    /// Dimension of points in tree.
+    pub fn ndim(&self) -> usize {
+        self.ndim
+    }
+
+    /// Maximum number of points in leaf node.
+    pub fn leafsize(&self) -> usize {
+        self.leafsize
+    }
+
+    /// Device of tree (also device of query results).
+    pub fn device(&self) -> &B {
+        &self.device
+    }
+
+    /// Coordinates of the `i`-th point (in original order).
+    pub fn point(&self, i: usize) -> &[T] {
+        &self.data[i * self.ndim..(i + 1) * self.ndim]
+    }
+
+    #[inline]
+    fn reduced_point_distance<M>(&self, q: &[T], i: usize, metric: &M) -> T
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        q.iter()
+            .zip(self.point(i))
+            .fold(T::zero(), |acc, (&a, &b)| metric.reduced_accumulate(acc, metric.reduced_component(a - b)))
+    }
+
+    #[inline]
+    fn reduced_node_distance<M>(&self, q: &[T], node: usize, metric: &M) -> T
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        let mins = &self.bbox_mins[node * self.ndim..(node + 1) * self.ndim];
+        let maxes = &self.bbox_maxes[node * self.ndim..(node + 1) * self.ndim];
+        izip!(q, mins, maxes).fold(T::zero(), |acc, (&q, &lo, &hi)| {
+            let diff = if q < lo {
+                lo - q
+            } else if q > hi {
+                q - hi
+            } else {
+                T::zero()
+            };
+            metric.reduced_accumulate(acc, metric.reduced_component(diff))
+        })
+    }
+
+    /// `k` nearest neighbors of point `q`, as (reduced distance, index) sorted by distance.
+    pub fn query_point_reduced<M>(&self, q: &[T], k: usize, metric: &M) -> Vec<(T, usize)>
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        if k == 0 || self.nodes.is_empty() {
+            return vec![];
+        }
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.query_node(q, 0, k, metric, &mut heap);
+        heap.into_sorted_vec().into_iter().map(|item| (item.dist, item.idx)).collect()
+    }
+
+    fn query_node<M>(&self, q: &[T], node: usize, k: usize, metric: &M, heap: &mut BinaryHeap<HeapItem<T>>)
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        let bound = |heap: &BinaryHeap<HeapItem<T>>| match heap.len() < k {
+            true => T::infinity(),
+            false => heap.peek().unwrap().dist,
+        };
+        let KDTreeNode { start, end, children } = self.nodes[node];
+        match children {
+            None => {
+                for &idx in &self.indices[start..end] {
+                    let dist = self.reduced_point_distance(q, idx, metric);
+                    if heap.len() < k {
+                        heap.push(HeapItem { dist, idx });
+                    } else if dist < heap.peek().unwrap().dist {
+                        heap.pop();
+                        heap.push(HeapItem { dist, idx });
+                    }
+                }
+            },
+            Some((lesser, greater)) => {
+                let dist_lesser = self.reduced_node_distance(q, lesser, metric);
+                let dist_greater = self.reduced_node_distance(q, greater, metric);
+                let ((near, dist_near), (far, dist_far)) = match dist_lesser <= dist_greater {
+                    true => ((lesser, dist_lesser), (greater, dist_greater)),
+                    false => ((greater, dist_greater), (lesser, dist_lesser)),
+                };
+                if dist_near < bound(heap) {
+                    self.query_node(q, near, k, metric, heap);
+                }
+                if dist_far < bound(heap) {
+                    self.query_node(q, far, k, metric, heap);
+                }
+            },
+        }
+    }
+
+    /// Points within reduced distance `r_reduced` of point `q`, as (index, reduced distance)
+    /// sorted by index.
+    pub fn query_ball_point_reduced<M>(&self, q: &[T], r_reduced: T, metric: &M) -> Vec<(usize, T)>
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        let mut result = vec![];
+        if !self.nodes.is_empty() {
+            self.query_ball_node(q, 0, r_reduced, metric, &mut result);
+        }
+        result.sort_unstable_by_key(|&(idx, _)| idx);
+        result
+    }
+
+    fn query_ball_node<M>(&self, q: &[T], node: usize, r_reduced: T, metric: &M, result: &mut Vec<(usize, T)>)
+    where
+        M: KDTreeMetricAPI<T>,
+    {
+        if self.reduced_node_distance(q, node, metric) > r_reduced {
+            return;
+        }
+        let KDTreeNode { start, end, children } = self.nodes[node];
+        match children {
+            None => {
+                for &idx in &self.indices[start..end] {
+                    let dist = self.reduced_point_distance(q, idx, metric);
+                    if dist <= r_reduced {
+                        result.push((idx, dist));
+                    }
+                }
+            },
+            Some((lesser, greater)) => {
+                self.query_ball_node(q, lesser, r_reduced, metric, result);
+                self.query_ball_node(q, greater, r_reduced, metric, result);
+            },
+        }
+    }
+}
+
+/* #endregion */
+
+/* #region batch query implementations */
+
+fn gather_point<T: Copy>(x: &[T], lx: &Layout<Ix2>, i: usize) -> Vec<T> {
+    let [_, ndim] = *lx.shape();
+    let [s0, s1] = *lx.stride();
+    let offset = lx.offset() as isize + i as isize * s0;
+    (0..ndim).map(|d| x[(offset + d as isize * s1) as usize]).collect()
+}
+
+fn batch_map_rayon<R, F>(n: usize, f: F, pool: Option<&rayon::ThreadPool>) -> Vec<R>
+where
+    R: Send,
+    F: Fn(usize) -> R + Send + Sync,
+{
+    match pool {
+        None => (0..n).map(f).collect(),
+        Some(pool) => pool.install(|| (0..n).into_par_iter().map(f).collect()),
+    }
+}
+
+fn finalize_query<T, B, M>(
+    tree: &KDTree<T, B>,
+    result: Vec<Vec<(T, usize)>>,
+    k: usize,
+    metric: &M,
+) -> (Vec<T>, Vec<usize>)
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+{
+    let nq = result.len();
+    let mut dists = vec![T::infinity(); nq * k];
+    let mut indices = vec![tree.npoints(); nq * k];
+    for (i, row) in result.into_iter().enumerate() {
+        for (j, (dist, idx)) in row.into_iter().enumerate() {
+            dists[i * k + j] = metric.reduced_to_distance(dist);
+            indices[i * k + j] = idx;
+        }
+    }
+    (dists, indices)
+}
+
+#[allow(clippy::type_complexity)]
+fn finalize_sparse<T, M>(result: Vec<Vec<(usize, T)>>, metric: &M) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+{
+    let nnz = result.iter().map(|row| row.len()).sum();
+    let mut rows = Vec::with_capacity(nnz);
+    let mut cols = Vec::with_capacity(nnz);
+    let mut vals = Vec::with_capacity(nnz);
+    for (i, row) in result.into_iter().enumerate() {
+        for (j, dist) in row {
+            rows.push(i);
+            cols.push(j);
+            vals.push(metric.reduced_to_distance(dist));
+        }
+    }
+    (rows, cols, vals)
+}
+
+fn check_query<T, B>(tree: &KDTree<T, B>, lx: &Layout<Ix2>) -> Result<()> {
+    rstsr_assert_eq!(lx.shape()[1], tree.ndim, InvalidLayout, "Dimension of query points does not match KD-tree.")
+}
+
+pub fn kdtree_query_serial<T, B, M>(
+    tree: &KDTree<T, B>,
+    x: &[T],
+    lx: &Layout<Ix2>,
+    k: usize,
+    metric: &M,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+{
+    check_query(tree, lx)?;
+    let result = (0..lx.shape()[0]).map(|i| tree.query_point_reduced(&gather_point(x, lx, i), k, metric)).collect();
+    Ok(finalize_query(tree, result, k, metric))
+}
+
+pub fn kdtree_query_rayon<T, B, M>(
+    tree: &KDTree<T, B>,
+    x: &[T],
+    lx: &Layout<Ix2>,
+    k: usize,
+    metric: &M,
+    pool: Option<&rayon::ThreadPool>,
+) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Float + Send + Sync,
+    B: Sync,
+    M: KDTreeMetricAPI<T> + Sync,
+{
+    check_query(tree, lx)?;
+    let f = |i| tree.query_point_reduced(&gather_point(x, lx, i), k, metric);
+    let result = batch_map_rayon(lx.shape()[0], f, pool);
+    Ok(finalize_query(tree, result, k, metric))
+}
+
+pub fn kdtree_query_ball_point_serial<T, B, M>(
+    tree: &KDTree<T, B>,
+    x: &[T],
+    lx: &Layout<Ix2>,
+    r: T,
+    metric: &M,
+) -> Result<Vec<Vec<usize>>>
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+{
+    check_query(tree, lx)?;
+    let r_reduced = metric.distance_to_reduced(r);
+    let f = |i| {
+        let row = tree.query_ball_point_reduced(&gather_point(x, lx, i), r_reduced, metric);
+        row.into_iter().map(|(idx, _)| idx).collect()
+    };
+    Ok((0..lx.shape()[0]).map(f).collect())
+}
+
+pub fn kdtree_query_ball_point_rayon<T, B, M>(
+    tree: &KDTree<T, B>,
+    x: &[T],
+    lx: &Layout<Ix2>,
+    r: T,
+    metric: &M,
+    pool: Option<&rayon::ThreadPool>,
+) -> Result<Vec<Vec<usize>>>
+where
+    T: Float + Send + Sync,
+    B: Sync,
+    M: KDTreeMetricAPI<T> + Sync,
+{
+    check_query(tree, lx)?;
+    let r_reduced = metric.distance_to_reduced(r);
+    let f = |i| {
+        let row = tree.query_ball_point_reduced(&gather_point(x, lx, i), r_reduced, metric);
+        row.into_iter().map(|(idx, _)| idx).collect()
+    };
+    Ok(batch_map_rayon(lx.shape()[0], f, pool))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn kdtree_sparse_distance_matrix_serial<T, B, M>(
+    tree: &KDTree<T, B>,
+    other: &KDTree<T, B>,
+    max_distance: T,
+    metric: &M,
+) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)>
+where
+    T: Float,
+    M: KDTreeMetricAPI<T>,
+{
+    rstsr_assert_eq!(tree.ndim, other.ndim, InvalidLayout, "Dimension of two KD-trees does not match.")?;
+    let r_reduced = metric.distance_to_reduced(max_distance);
+    let f = |i| other.query_ball_point_reduced(tree.point(i), r_reduced, metric);
+    let result = (0..tree.npoints).map(f).collect();
+    Ok(finalize_sparse(result, metric))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn kdtree_sparse_distance_matrix_rayon<T, B, M>(
+    tree: &KDTree<T, B>,
+    other: &KDTree<T, B>,
+    max_distance: T,
+    metric: &M,
+    pool: Option<&rayon::ThreadPool>,
+) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)>
+where
+    T: Float + Send + Sync,
+    B: Sync,
+    M: KDTreeMetricAPI<T> + Sync,
+{
+    rstsr_assert_eq!(tree.ndim, other.ndim, InvalidLayout, "Dimension of two KD-trees does not match.")?;
+    let r_reduced = metric.distance_to_reduced(max_distance);
+    let f = |i| other.query_ball_point_reduced(tree.point(i), r_reduced, metric);
+    let result = batch_map_rayon(tree.npoints, f, pool);
+    Ok(finalize_sparse(result, metric))
+}
+
+/* #endregion */
+
+/* #region device trait and tensor interface */
+
+/// Device-dispatched batch queries of [`KDTree`].
+///
+/// Query points are given by raw data with 2-D layout (points by rows); results are flattened
+/// row-major vectors.
+pub trait KDTreeQueryAPI<T, M>
+where
+    Self: DeviceAPI<T, Raw = Vec<T>> + Sized,
+{
+    fn kdtree_query_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        x: &[T],
+        lx: &Layout<Ix2>,
+        k: usize,
+        metric: &M,
+    ) -> Result<(Vec<T>, Vec<usize>)>;
+
+    fn kdtree_query_ball_point_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        x: &[T],
+        lx: &Layout<Ix2>,
+        r: T,
+        metric: &M,
+    ) -> Result<Vec<Vec<usize>>>;
+
+    #[allow(clippy::type_complexity)]
+    fn kdtree_sparse_distance_matrix_f(
+        &self,
+        tree: &KDTree<T, Self>,
+        other: &KDTree<T, Self>,
+        max_distance: T,
+        metric: &M,
+    ) -> Result<(Vec<usize>, Vec<usize>, Vec<T>)>;
+}
+
+impl<T, B> KDTree<T, B>
+where
+    T: Float,
+    B: DeviceAPI<T, Raw = Vec<T>>,
+{
+    /// Build KD-tree from points `x` of shape (npoints, ndim), with specified leaf size.
+    pub fn new_with_leafsize_f<D>(x: TensorView<'_, T, B, D>, leafsize: usize) -> Result<Self>
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+    {
+        rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+        let lx = x.layout().to_dim::<Ix2>()?;
+        Self::build_f(x.raw(), &lx, leafsize, x.device().clone())
+    }
+
+    /// Build KD-tree from points `x` of shape (npoints, ndim), with specified leaf size.
+    pub fn new_with_leafsize<D>(x: TensorView<'_, T, B, D>, leafsize: usize) -> Self
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+    {
+        Self::new_with_leafsize_f(x, leafsize).rstsr_unwrap()
+    }
+
+    /// Build KD-tree from points `x` of shape (npoints, ndim).
+    pub fn new_f<D>(x: TensorView<'_, T, B, D>) -> Result<Self>
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+    {
+        Self::new_with_leafsize_f(x, KDTREE_DEFAULT_LEAFSIZE)
+    }
+
+    /// Build KD-tree from points `x` of shape (npoints, ndim).
+    pub fn new<D>(x: TensorView<'_, T, B, D>) -> Self
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+    {
+        Self::new_f(x).rstsr_unwrap()
+    }
+
+    /// Query `k` nearest neighbors of points `x` of shape (nquery, ndim).
+    ///
+    /// Returns distances and indices, both of shape (nquery, k) (c-contiguous), sorted by
+    /// distance. If the tree has fewer than `k` points, missing neighbors are filled with
+    /// infinite distance and index `npoints`.
+    #[allow(clippy::type_complexity)]
+    pub fn query_f<D, M>(
+        &self,
+        x: TensorView<'_, T, B, D>,
+        k: usize,
+        metric: M,
+    ) -> Result<(Tensor<T, B, Ix2>, Tensor<usize, B, Ix2>)>
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+        B: KDTreeQueryAPI<T, M> + DeviceAPI<usize> + DeviceCreationAnyAPI<T> + DeviceCreationAnyAPI<usize>,
+    {
+        rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+        rstsr_assert!(x.device().same_device(&self.device), DeviceMismatch)?;
+        let lx = x.layout().to_dim::<Ix2>()?;
+        let (dists, indices) = self.device.kdtree_query_f(self, x.raw(), &lx, k, &metric)?;
+        let nquery = lx.shape()[0];
+        let dists = asarray_f((dists, [nquery, k].c(), &self.device))?.into_dim_f::<Ix2>()?;
+        let indices = asarray_f((indices, [nquery, k].c(), &self.device))?.into_dim_f::<Ix2>()?;
+        Ok((dists, indices))
+    }
+
+    /// Query `k` nearest neighbors of points `x` of shape (nquery, ndim).
+    #[allow(clippy::type_complexity)]
+    pub fn query<D, M>(
+        &self,
+        x: TensorView<'_, T, B, D>,
+        k: usize,
+        metric: M,
+    ) -> (Tensor<T, B, Ix2>, Tensor<usize, B, Ix2>)
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+        B: KDTreeQueryAPI<T, M> + DeviceAPI<usize> + DeviceCreationAnyAPI<T> + DeviceCreationAnyAPI<usize>,
+    {
+        self.query_f(x, k, metric).rstsr_unwrap()
+    }
+
+    /// Indices of tree points within distance `r` (inclusive) for each of points `x` of shape
+    /// (nquery, ndim).
+    ///
+    /// Indices of each query point are sorted in ascending order.
+    pub fn query_ball_point_f<D, M>(&self, x: TensorView<'_, T, B, D>, r: T, metric: M) -> Result<Vec<Vec<usize>>>
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+        B: KDTreeQueryAPI<T, M>,
+    {
+        rstsr_assert_eq!(x.ndim(), 2, InvalidLayout, "x must be a 2D tensor")?;
+        rstsr_assert!(x.device().same_device(&self.device), DeviceMismatch)?;
+        let lx = x.layout().to_dim::<Ix2>()?;
+        self.device.kdtree_query_ball_point_f(self, x.raw(), &lx, r, &metric)
+    }
+
+    /// Indices of tree points within distance `r` (inclusive) for each of points `x` of shape
+    /// (nquery, ndim).
+    pub fn query_ball_point<D, M>(&self, x: TensorView<'_, T, B, D>, r: T, metric: M) -> Vec<Vec<usize>>
+    where
+        D: DimAPI + DimIntoAPI<Ix2>,
+        B: KDTreeQueryAPI<T, M>,
+    {
+        self.query_ball_point_f(x, r, metric).rstsr_unwrap()
+    }
+
+    /// Distances between points of this tree and `other` tree, that are not larger than
+    /// `max_distance`.
+    ///
+    /// Returns sparse matrix of shape (self.npoints, other.npoints) in coordinate format
+    /// `(rows, cols, values)`, sorted by row and then column. Pairs of zero distance are also
+    /// included.
+    #[allow(clippy::type_complexity)]
+    pub fn sparse_distance_matrix_f<M>(
+        &self,
+        other: &KDTree<T, B>,
+        max_distance: T,
+        metric: M,
+    ) -> Result<(Tensor<usize, B, Ix1>, Tensor<usize, B, Ix1>, Tensor<T, B, Ix1>)>
+    where
+        B: KDTreeQueryAPI<T, M> + DeviceAPI<usize> + DeviceCreationAnyAPI<T> + DeviceCreationAnyAPI<usize>,
+    {
+        rstsr_assert!(other.device.same_device(&self.device), DeviceMismatch)?;
+        let (rows, cols, vals) = self.device.kdtree_sparse_distance_matrix_f(self, other, max_distance, &metric)?;
+        let nnz = vals.len();
+        let rows = asarray_f((rows, [nnz].c(), &self.device))?.into_dim_f::<Ix1>()?;
+        let cols = asarray_f((cols, [nnz].c(), &self.device))?.into_dim_f::<Ix1>()?;
+        let vals = asarray_f((vals, [nnz].c(), &self.device))?.into_dim_f::<Ix1>()?;
+        Ok((rows, cols, vals))
+    }
+
+    /// Distances between points of this tree and `other` tree, that are not larger than
+    /// `max_distance`.
+    #[allow(clippy::type_complexity)]
+    pub fn sparse_distance_matrix<M>(
+        &self,
+        other: &KDTree<T, B>,
+        max_distance: T,
+        metric: M,
+    ) -> (Tensor<usize, B, Ix1>, Tensor<usize, B, Ix1>, Tensor<T, B, Ix1>)
+    where
+        B: KDTreeQueryAPI<T, M> + DeviceAPI<usize> + DeviceCreationAnyAPI<T> + DeviceCreationAnyAPI<usize>,
+    {
+        self.sparse_distance_matrix_f(other, max_distance, metric).rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distance::metric::*;
+    use crate::distance::traits::cdist;
+
+    fn check_against_brute_force<M, R>(metric: impl Fn() -> M, reference: R)
+    where
+        M: KDTreeMetricAPI<f64>,
+        R: MetricDistAPI<Vec<f64>, Out = f64>,
+    {
+        let device = DeviceCpuSerial::default();
+        let x = linspace((0., 1., 1500, &device)).mapv(|v: f64| (v * 12345.678).sin()).into_shape((500, 3));
+        let q = linspace((0., 1., 60, &device)).mapv(|v: f64| (v * 987.654).cos()).into_shape((20, 3));
+        let tree = KDTree::new_with_leafsize(x.view(), 8);
+        let d_full = cdist((q.view(), x.view(), reference)).into_dim::<Ix2>();
+
+        // k nearest neighbors
+        let (dists, indices) = tree.query(q.view(), 5, metric());
+        for i in 0..20 {
+            let mut row = d_full.i(i).to_vec();
+            row.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert!(allclose_f64(&dists.i(i), &asarray(row[..5].to_vec())));
+            for j in 0..5 {
+                assert!((d_full[[i, indices[[i, j]]]] - dists[[i, j]]).abs() < 1e-12);
+            }
+        }
+
+        // ball point
+        let r = 0.4;
+        let balls = tree.query_ball_point(q.view(), r, metric());
+        for (i, ball) in balls.iter().enumerate() {
+            let expected = (0..500).filter(|&j| d_full[[i, j]] <= r).collect::<Vec<_>>();
+            assert_eq!(ball, &expected);
+        }
+
+        // sparse distance matrix
+        let tree_q = KDTree::new(q.view());
+        let (rows, cols, vals) = tree_q.sparse_distance_matrix(&tree, r, metric());
+        assert_eq!(rows.size(), balls.iter().map(|b| b.len()).sum::<usize>());
+        for n in 0..rows.size() {
+            assert!((d_full[[rows[[n]], cols[[n]]]] - vals[[n]]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_kdtree() {
+        check_against_brute_force(|| MetricEuclidean, MetricEuclidean);
+        check_against_brute_force(|| MetricSqEuclidean, MetricSqEuclidean);
+        check_against_brute_force(|| MetricCityBlock, MetricCityBlock);
+        check_against_brute_force(|| MetricChebyshev, MetricChebyshev);
+        check_against_brute_force(|| MetricMinkowski::new(3.0), MetricMinkowski::new(3.0));
+        // brute-force Minkowski does not handle p = inf
+        check_against_brute_force(|| MetricMinkowski::new(f64::INFINITY), MetricChebyshev);
+    }
+
+    #[test]
+    fn test_kdtree_edge_cases() {
+        let device = DeviceCpuSerial::default();
+        let x = asarray((vec![0.0, 0.0, 1.0, 1.0], [2, 2].c(), &device)).into_dim::<Ix2>();
+        let tree = KDTree::new(x.view());
+        let (dists, indices) = tree.query(x.view(), 3, MetricEuclidean);
+        assert_eq!(indices.reshape(-1).to_vec(), vec![0, 1, 2, 1, 0, 2]);
+        assert!(dists[[0, 2]].is_infinite());
+
+        // identical points are not split
+        let x = zeros(([100, 3], &device)).into_dim::<Ix2>();
+        let tree = KDTree::new_with_leafsize(x.view(), 1);
+        assert_eq!(tree.query_ball_point(x.i((0..1, ..)), 0.0, MetricEuclidean)[0].len(), 100);
+    }
+}
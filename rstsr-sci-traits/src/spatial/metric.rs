@@ -0,0 +1,95 @@
+//! Minkowski-family metrics for space-partitioning trees.
+
+use crate::distance::metric::{MetricChebyshev, MetricCityBlock, MetricEuclidean, MetricMinkowski, MetricSqEuclidean};
+use num::complex::ComplexFloat;
+use num::Float;
+use rstsr_core::prelude_dev::*;
+
+/// Minkowski-family metric, as required by [`KDTree`](crate::spatial::kdtree::KDTree).
+///
+/// Tree search works on the reduced distance (e.g. squared distance for Euclidean), which is
+/// accumulated coordinate by coordinate, and is monotonic to the true distance. This allows
+/// pruning of tree nodes by the distance from point to bounding box.
+pub trait KDTreeMetricAPI<T> {
+    /// Contribution of one coordinate difference to the reduced distance.
+    fn reduced_component(&self, diff: T) -> T;
+
+    /// Accumulate one coordinate contribution to the reduced distance.
+    fn reduced_accumulate(&self, acc: T, comp: T) -> T;
+
+    /// Convert reduced distance to the true distance.
+    fn reduced_to_distance(&self, reduced: T) -> T;
+
+    /// Convert true distance to the reduced distance.
+    fn distance_to_reduced(&self, dist: T) -> T;
+}
+
+#[duplicate_item(
+    StructType           dup_component       dup_accumulate    dup_to_distance   dup_to_reduced ;
+   [MetricEuclidean  ] [diff * diff     ] [acc + comp     ] [reduced.sqrt() ] [dist * dist   ];
+   [MetricSqEuclidean] [diff * diff     ] [acc + comp     ] [reduced        ] [dist          ];
+   [MetricCityBlock  ] [diff.abs()      ] [acc + comp     ] [reduced        ] [dist          ];
+   [MetricChebyshev  ] [diff.abs()      ] [acc.max(comp)  ] [reduced        ] [dist          ];
+)]
+impl<T> KDTreeMetricAPI<T> for StructType
+where
+    T: Float,
+{
+    #[inline]
+    fn reduced_component(&self, diff: T) -> T {
+        dup_component
+    }
+
+    #[inline]
+    fn reduced_accumulate(&self, acc: T, comp: T) -> T {
+        dup_accumulate
+    }
+
+    #[inline]
+    fn reduced_to_distance(&self, reduced: T) -> T {
+        dup_to_distance
+    }
+
+    #[inline]
+    fn distance_to_reduced(&self, dist: T) -> T {
+        dup_to_reduced
+    }
+}
+
+/// Minkowski metric with `p = inf` is handled as Chebyshev metric.
+impl<T> KDTreeMetricAPI<T> for MetricMinkowski<T>
+where
+    T: Float + ComplexFloat<Real = T>,
+{
+    #[inline]
+    fn reduced_component(&self, diff: T) -> T {
+        match Float::is_infinite(self.p) {
+            true => Float::abs(diff),
+            false => Float::powf(Float::abs(diff), self.p),
+        }
+    }
+
+    #[inline]
+    fn reduced_accumulate(&self, acc: T, comp: T) -> T {
+        match Float::is_infinite(self.p) {
+            true => Float::max(acc, comp),
+            false => acc + comp,
+        }
+    }
+
+    #[inline]
+    fn reduced_to_distance(&self, reduced: T) -> T {
+        match Float::is_infinite(self.p) {
+            true => reduced,
+            false => Float::powf(reduced, T::one() / self.p),
+        }
+    }
+
+    #[inline]
+    fn distance_to_reduced(&self, dist: T) -> T {
+        match Float::is_infinite(self.p) {
+            true => dist,
+            false => Float::powf(dist, self.p),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod prelude;
+
+pub mod kdtree;
+pub mod metric;
+
+#[cfg(any(feature = "faer", feature = "blas"))]
+mod auto_impl_rayon;
+#[cfg(feature = "blas")]
+pub mod impl_blas;
+pub mod impl_cpu_serial;
+#[cfg(feature = "faer")]
+pub mod impl_faer;
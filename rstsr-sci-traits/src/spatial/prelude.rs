@@ -0,0 +1,8 @@
+pub mod rstsr_traits {
+    pub use crate::spatial::kdtree::KDTreeQueryAPI;
+    pub use crate::spatial::metric::KDTreeMetricAPI;
+}
+
+pub mod rstsr_structs {
+    pub use crate::spatial::kdtree::KDTree;
+}
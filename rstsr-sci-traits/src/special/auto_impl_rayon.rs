@@ -0,0 +1,37 @@
+/// Rayon-parallel `special` for CPU device `$Device` with generic parameters
+/// `$G` and their bounds, shared by `DeviceFaer` and `DeviceBLAS<B>`.
+macro_rules! impl_special_rayon {
+    ([$($G:ident),*] $Device:ty where [$($bound:tt)*]) => {
+        const _: () = {
+            use crate::prelude_dev::*;
+            use rayon::prelude::*;
+            use rstsr_sci_traits::special::traits::SpecialFuncMapAPI;
+
+            impl<$($G,)* T> SpecialFuncMapAPI<T> for $Device
+            where
+                $($bound)*
+                T: Clone + Default + Send + Sync,
+            {
+                fn special_map_f<TI, F>(&self, x: &[TI], chunk_in: usize, chunk_out: usize, f: F) -> Result<Vec<T>>
+                where
+                    TI: Sync,
+                    F: Fn(&[TI], &mut [T]) + Send + Sync,
+                {
+                    rstsr_assert!(chunk_in > 0 && chunk_out > 0, InvalidValue, "Chunk size should be positive.")?;
+                    rstsr_assert_eq!(x.len() % chunk_in, 0, InvalidLayout, "Input length should be a multiple of chunk size.")?;
+                    let mut out = vec![T::default(); x.len() / chunk_in * chunk_out];
+                    let mut task = || {
+                        x.par_chunks_exact(chunk_in).zip(out.par_chunks_exact_mut(chunk_out)).for_each(|(x, out)| f(x, out));
+                    };
+                    match self.get_current_pool() {
+                        Some(pool) => pool.install(task),
+                        None => task(),
+                    }
+                    Ok(out)
+                }
+            }
+        };
+    };
+}
+
+pub(crate) use impl_special_rayon;
@@ -0,0 +1,341 @@
+//! Tensor-level special functions.
+//!
+//! Input tensors can be of any layout; outputs are always c-contiguous. Functions on grids
+//! (spherical harmonics) take Cartesian coordinates of shape `(npts, 3)`, which need not be
+//! normalized.
+
+use crate::special::native_impl::*;
+use crate::special::traits::SpecialFuncMapAPI;
+use num::Complex;
+use rstsr_core::prelude_dev::*;
+
+/// Map c-contiguous data of input tensor in chunks of `chunk_in` into output chunks of
+/// `chunk_out`.
+///
+/// Returns the output data, shape of input tensor, and device.
+fn special_map_raw_f<TO, B, D, F>(
+    x: TensorView<'_, f64, B, D>,
+    chunk_in: usize,
+    chunk_out: usize,
+    f: F,
+) -> Result<(Vec<TO>, Vec<usize>, B)>
+where
+    TO: Clone + Default + Send + Sync,
+    B: DeviceAPI<f64, Raw = Vec<f64>> + DeviceAPI<TO> + OpAssignArbitaryAPI<f64, IxD, IxD> + SpecialFuncMapAPI<TO>,
+    B: DeviceCreationAnyAPI<f64>,
+    D: DimAPI,
+    F: Fn(&[f64], &mut [TO]) + Send + Sync,
+{
+    let x = x.into_dim_f::<IxD>()?;
+    let shape = x.shape().clone();
+    let size = x.size();
+    let x = x.change_layout_f(shape.c())?;
+    let device = x.device().clone();
+    let out = device.special_map_f(&x.raw()[..size], chunk_in, chunk_out, f)?;
+    Ok((out, shape, device))
+}
+
+/// Map input tensor element-wise, keeping its shape.
+fn special_map_elementwise_f<TO, B, D, F>(x: TensorView<'_, f64, B, D>, f: F) -> Result<Tensor<TO, B, D>>
+where
+    TO: Clone + Default + Send + Sync,
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceAPI<TO>
+        + DeviceCreationAnyAPI<f64>
+        + DeviceCreationAnyAPI<TO>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<TO>,
+    D: DimAPI,
+    F: Fn(f64) -> TO + Send + Sync,
+{
+    let (out, shape, device) = special_map_raw_f(x, 1, 1, |x, out| out[0] = f(x[0]))?;
+    asarray_f((out, shape.c(), &device))?.into_dim_f::<D>()
+}
+
+/// Check that coordinates are of shape `(npts, 3)`.
+fn check_coords<B, D>(coords: &TensorView<'_, f64, B, D>) -> Result<()>
+where
+    B: DeviceAPI<f64>,
+    D: DimAPI,
+{
+    rstsr_assert_eq!(coords.ndim(), 2, InvalidLayout, "coords must be a 2D tensor")?;
+    rstsr_assert_eq!(coords.shape()[1], 3, InvalidLayout, "coords should be of shape (npts, 3)")
+}
+
+/* #region Boys function */
+
+/// Boys function `F_m(t) = ∫_0^1 u^{2m} exp(-t u²) du`, element-wise on `t`.
+///
+/// # See also
+///
+/// - [`boys_all`]: all orders `0..=mmax` at once, which is cheaper than calling this function for
+///   each order.
+pub fn boys<B, D>(m: usize, t: TensorView<'_, f64, B, D>) -> Tensor<f64, B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    boys_f(m, t).rstsr_unwrap()
+}
+
+pub fn boys_f<B, D>(m: usize, t: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    special_map_elementwise_f(t, |t| boys_scalar(m, t))
+}
+
+/// Boys function of all orders `0..=mmax`.
+///
+/// Output shape is the shape of `t` with an additional last dimension of size `mmax + 1`.
+pub fn boys_all<B, D>(mmax: usize, t: TensorView<'_, f64, B, D>) -> Tensor<f64, B, IxD>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    boys_all_f(mmax, t).rstsr_unwrap()
+}
+
+pub fn boys_all_f<B, D>(mmax: usize, t: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, IxD>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    let (out, mut shape, device) = special_map_raw_f(t, 1, mmax + 1, |t, out| boys_array(mmax, t[0], out))?;
+    shape.push(mmax + 1);
+    asarray_f((out, shape.c(), &device))
+}
+
+/* #endregion */
+
+/* #region Bessel and Legendre functions */
+
+#[duplicate_item(
+    func            func_f            func_scalar            doc_func;
+   [spherical_jn] [spherical_jn_f] [spherical_jn_scalar] ["Spherical Bessel function of the first kind `j_n(x)`, element-wise on `x`."];
+   [spherical_in] [spherical_in_f] [spherical_in_scalar] ["Modified spherical Bessel function of the first kind `i_n(x)`, element-wise on `x`."];
+)]
+#[doc = doc_func]
+///
+/// # See also
+///
+/// - [SciPy: `scipy.special.spherical_jn`, `scipy.special.spherical_in`](https://docs.scipy.org/doc/scipy/reference/special.html#bessel-functions)
+pub fn func<B, D>(n: usize, x: TensorView<'_, f64, B, D>) -> Tensor<f64, B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    func_f(n, x).rstsr_unwrap()
+}
+
+#[duplicate_item(
+    func_f            func_scalar          ;
+   [spherical_jn_f] [spherical_jn_scalar];
+   [spherical_in_f] [spherical_in_scalar];
+)]
+pub fn func_f<B, D>(n: usize, x: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    special_map_elementwise_f(x, |x| func_scalar(n, x))
+}
+
+/// Associated Legendre function `P_l^m(x)`, element-wise on `x`.
+///
+/// Condon-Shortley phase is included. Values are zero for `|m| > l`, and NaN for `|x| > 1`.
+///
+/// # See also
+///
+/// - [SciPy: `scipy.special.lpmv`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.lpmv.html)
+pub fn lpmv<B, D>(m: isize, l: usize, x: TensorView<'_, f64, B, D>) -> Tensor<f64, B, D>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    lpmv_f(m, l, x).rstsr_unwrap()
+}
+
+pub fn lpmv_f<B, D>(m: isize, l: usize, x: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, D>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    special_map_elementwise_f(x, |x| lpmv_scalar(m, l, x))
+}
+
+/* #endregion */
+
+/* #region spherical harmonics */
+
+/// Complex spherical harmonics `Y_l^m` on grid of Cartesian coordinates.
+///
+/// Condon-Shortley phase is included, same to SciPy. Output is of shape `(npts,)`.
+///
+/// # Arguments
+///
+/// * `l`, `m` - Degree and order; zero is returned for `|m| > l`.
+/// * `coords` - Cartesian coordinates of shape `(npts, 3)`, e.g. `quads` of
+///   [`LebedevQuad`](crate::integrate::lebedev::LebedevQuad). Only directions are used.
+///
+/// # See also
+///
+/// - [SciPy: `scipy.special.sph_harm_y`](https://docs.scipy.org/doc/scipy/reference/generated/scipy.special.sph_harm_y.html)
+pub fn sph_harm<B, D>(l: usize, m: isize, coords: TensorView<'_, f64, B, D>) -> Tensor<Complex<f64>, B, IxD>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceAPI<Complex<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + DeviceCreationAnyAPI<Complex<f64>>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<Complex<f64>>,
+    D: DimAPI,
+{
+    sph_harm_f(l, m, coords).rstsr_unwrap()
+}
+
+pub fn sph_harm_f<B, D>(l: usize, m: isize, coords: TensorView<'_, f64, B, D>) -> Result<Tensor<Complex<f64>, B, IxD>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceAPI<Complex<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + DeviceCreationAnyAPI<Complex<f64>>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<Complex<f64>>,
+    D: DimAPI,
+{
+    check_coords(&coords)?;
+    let (out, shape, device) = special_map_raw_f(coords, 3, 1, |c, out| out[0] = sph_harm_scalar(l, m, c))?;
+    asarray_f((out, vec![shape[0]].c(), &device))
+}
+
+/// Real spherical harmonics of degree `l` and order `m` on grid of Cartesian coordinates.
+///
+/// Convention without Condon-Shortley phase is used: `m > 0` is cosine-type and `m < 0` is
+/// sine-type; for `l = 1`, orders `(-1, 0, 1)` correspond to `(y, z, x)`. Output is of shape
+/// `(npts,)`.
+///
+/// # See also
+///
+/// - [`real_sph_harm_all`]: all orders of degree `l` at once.
+pub fn real_sph_harm<B, D>(l: usize, m: isize, coords: TensorView<'_, f64, B, D>) -> Tensor<f64, B, IxD>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    real_sph_harm_f(l, m, coords).rstsr_unwrap()
+}
+
+pub fn real_sph_harm_f<B, D>(l: usize, m: isize, coords: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, IxD>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    check_coords(&coords)?;
+    let (out, shape, device) = special_map_raw_f(coords, 3, 1, |c, out| out[0] = real_sph_harm_scalar(l, m, c))?;
+    asarray_f((out, vec![shape[0]].c(), &device))
+}
+
+/// Real spherical harmonics of degree `l` and all orders `m = -l..=l` on grid of Cartesian
+/// coordinates.
+///
+/// Output is of shape `(npts, 2l + 1)`. See [`real_sph_harm`] for convention.
+pub fn real_sph_harm_all<B, D>(l: usize, coords: TensorView<'_, f64, B, D>) -> Tensor<f64, B, IxD>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    real_sph_harm_all_f(l, coords).rstsr_unwrap()
+}
+
+pub fn real_sph_harm_all_f<B, D>(l: usize, coords: TensorView<'_, f64, B, D>) -> Result<Tensor<f64, B, IxD>>
+where
+    B: DeviceAPI<f64, Raw = Vec<f64>>
+        + DeviceCreationAnyAPI<f64>
+        + OpAssignArbitaryAPI<f64, IxD, IxD>
+        + SpecialFuncMapAPI<f64>,
+    D: DimAPI,
+{
+    check_coords(&coords)?;
+    let (out, shape, device) = special_map_raw_f(coords, 3, 2 * l + 1, |c, out| real_sph_harm_all_scalar(l, c, out))?;
+    asarray_f((out, vec![shape[0], 2 * l + 1].c(), &device))
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::integrate::lebedev::lebedev_rule;
+    use core::f64::consts::PI;
+
+    #[test]
+    fn test_special_tensor() {
+        let device = DeviceCpuSerial::default();
+        let t = linspace((0.0, 50.0, 24, &device)).into_shape((4, 6));
+
+        // strided input keeps shape, output is c-contiguous
+        let f = boys(2, t.t());
+        assert_eq!(f.shape(), &[6, 4]);
+        assert_eq!(f[[5, 1]], boys_scalar(2, t[[1, 5]]));
+        let f_all = boys_all(4, t.t());
+        assert_eq!(f_all.shape(), &[6, 4, 5]);
+        assert!((f_all[[5, 1, 2]] - f[[5, 1]]).abs() < 1e-15);
+
+        let j = spherical_jn(3, t.view());
+        assert_eq!(j[[2, 3]], spherical_jn_scalar(3, t[[2, 3]]));
+        let x = linspace((-1.0, 1.0, 11, &device));
+        assert_eq!(lpmv(-2, 5, x.view())[[3]], lpmv_scalar(-2, 5, x[[3]]));
+
+        // orthonormality of spherical harmonics on Lebedev grid
+        let (quads, weights) = lebedev_rule(41, &device).into();
+        let weights = weights * (4.0 * PI);
+        for l in 0..6 {
+            let ylm = real_sph_harm_all(l, quads.view());
+            assert_eq!(ylm.shape(), &[quads.shape()[0], 2 * l + 1]);
+            let ovlp = (ylm.t() * &weights) % &ylm;
+            assert!(allclose_f64(&ovlp, &eye((2 * l + 1, &device))));
+            assert_eq!(real_sph_harm(l, l as isize, quads.view())[[7]], ylm[[7, 2 * l]]);
+        }
+        let y = sph_harm(3, 2, quads.view());
+        let norm: f64 = izip!(y.raw(), weights.raw()).map(|(y, w)| w * y.norm_sqr()).sum();
+        assert!((norm - 1.0).abs() < 1e-12);
+
+        assert!(boys_all_f(2, t.view()).is_ok());
+        assert!(real_sph_harm_f(1, 0, t.view()).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::special::traits::SpecialFuncMapAPI;
+use rstsr_core::prelude_dev::*;
+
+impl<T> SpecialFuncMapAPI<T> for DeviceCpuSerial
+where
+    T: Clone + Default + Send + Sync,
+{
+    fn special_map_f<TI, F>(&self, x: &[TI], chunk_in: usize, chunk_out: usize, f: F) -> Result<Vec<T>>
+    where
+        TI: Sync,
+        F: Fn(&[TI], &mut [T]) + Send + Sync,
+    {
+        rstsr_assert!(chunk_in > 0 && chunk_out > 0, InvalidValue, "Chunk size should be positive.")?;
+        rstsr_assert_eq!(x.len() % chunk_in, 0, InvalidLayout, "Input length should be a multiple of chunk size.")?;
+        let mut out = vec![T::default(); x.len() / chunk_in * chunk_out];
+        izip!(x.chunks_exact(chunk_in), out.chunks_exact_mut(chunk_out)).for_each(|(x, out)| f(x, out));
+        Ok(out)
+    }
+}
@@ -0,0 +1,25 @@
+use super::auto_impl_rayon::impl_special_rayon;
+
+impl_special_rayon!([] DeviceFaer where []);
+
+#[cfg(test)]
+mod test {
+    use crate::prelude_dev::*;
+    use rstsr_sci_traits::special::funcs::*;
+
+    #[test]
+    fn test_special_rayon() {
+        let device = DeviceFaer::default();
+        let device_serial = DeviceCpuSerial::default();
+        let t = linspace((0.0, 60.0, 1001, &device)).into_shape((7, 143));
+        let t_serial = t.to_device(&device_serial);
+        assert_eq!(boys(3, t.view()).raw(), boys(3, t_serial.view()).raw());
+        assert_eq!(boys_all(8, t.t()).raw(), boys_all(8, t_serial.t()).raw());
+        assert_eq!(spherical_jn(4, t.view()).raw(), spherical_jn(4, t_serial.view()).raw());
+
+        let (coords, _) = rstsr_sci_traits::integrate::lebedev::lebedev_rule(11, &device).into();
+        let coords_serial = coords.to_device(&device_serial);
+        assert_eq!(real_sph_harm_all(3, coords.view()).raw(), real_sph_harm_all(3, coords_serial.view()).raw());
+        assert_eq!(sph_harm(3, -2, coords.view()).raw(), sph_harm(3, -2, coords_serial.view()).raw());
+    }
+}
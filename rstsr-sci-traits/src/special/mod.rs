@@ -0,0 +1,13 @@
+pub mod prelude;
+
+#[cfg(any(feature = "faer", feature = "blas"))]
+mod auto_impl_rayon;
+pub mod funcs;
+#[cfg(feature = "blas")]
+pub mod impl_blas;
+pub mod impl_cpu_serial;
+pub mod native_impl;
+pub mod traits;
+
+#[cfg(feature = "faer")]
+pub mod impl_faer;
@@ -0,0 +1,414 @@
+//! Scalar implementations of special functions.
+//!
+//! Tensor-level functions in [`funcs`](crate::special::funcs) map these scalar functions over
+//! tensors.
+
+use core::f64::consts::PI;
+use num::Complex;
+use rstsr_core::prelude_dev::*;
+
+/* #region Boys function */
+
+/// Threshold of `t` above which Boys function is evaluated by error function and upward recursion.
+const BOYS_T_LARGE: f64 = 40.0;
+
+/// Boys function `F_m(t) = ∫_0^1 u^{2m} exp(-t u²) du` of orders `0..=mmax`, written to `out`.
+///
+/// For small `t` (or `t` smaller than order), the highest order is evaluated by Taylor series,
+/// and lower orders by stable downward recursion. For large `t`, `F_0` is evaluated by error
+/// function, and higher orders by upward recursion.
+pub fn boys_array(mmax: usize, t: f64, out: &mut [f64]) {
+    let out = &mut out[..=mmax];
+    if t.is_nan() {
+        out.fill(f64::NAN);
+        return;
+    }
+    if t.abs() < 1e-15 {
+        // F_m(0) = 1 / (2m + 1)
+        out.iter_mut().enumerate().for_each(|(m, v)| *v = 1.0 / (2 * m + 1) as f64);
+        return;
+    }
+    let exp_t = (-t).exp();
+    if t < BOYS_T_LARGE || t < mmax as f64 {
+        // F_m(t) = exp(-t) Σ_k (2t)^k / ((2m+1)(2m+3)...(2m+2k+1))
+        let mut term = 1.0 / (2 * mmax + 1) as f64;
+        let mut sum = term;
+        let mut k = 1;
+        while term.abs() > f64::EPSILON * sum.abs() {
+            term *= 2.0 * t / (2 * mmax + 2 * k + 1) as f64;
+            sum += term;
+            k += 1;
+        }
+        out[mmax] = exp_t * sum;
+        for m in (0..mmax).rev() {
+            out[m] = (2.0 * t * out[m + 1] + exp_t) / (2 * m + 1) as f64;
+        }
+    } else {
+        out[0] = 0.5 * (PI / t).sqrt() * t.sqrt().ext_erf();
+        for m in 0..mmax {
+            out[m + 1] = ((2 * m + 1) as f64 * out[m] - exp_t) / (2.0 * t);
+        }
+    }
+}
+
+/// Boys function `F_m(t) = ∫_0^1 u^{2m} exp(-t u²) du`.
+pub fn boys_scalar(m: usize, t: f64) -> f64 {
+    if t.is_nan() {
+        return f64::NAN;
+    }
+    if t.abs() < 1e-15 {
+        return 1.0 / (2 * m + 1) as f64;
+    }
+    if t < BOYS_T_LARGE || t < m as f64 {
+        let exp_t = (-t).exp();
+        let mut term = 1.0 / (2 * m + 1) as f64;
+        let mut sum = term;
+        let mut k = 1;
+        while term.abs() > f64::EPSILON * sum.abs() {
+            term *= 2.0 * t / (2 * m + 2 * k + 1) as f64;
+            sum += term;
+            k += 1;
+        }
+        exp_t * sum
+    } else {
+        let mut out = vec![0.0; m + 1];
+        boys_array(m, t, &mut out);
+        out[m]
+    }
+}
+
+/* #endregion */
+
+/* #region spherical Bessel functions */
+
+/// Starting index of Miller's downward recurrence.
+fn miller_start(n: usize, x: f64) -> usize {
+    n + x.abs().ceil() as usize + 40
+}
+
+/// Spherical Bessel function of the first kind `j_n(x)`.
+pub fn spherical_jn_scalar(n: usize, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if x == 0.0 {
+        return if n == 0 { 1.0 } else { 0.0 };
+    }
+    // j_n(-x) = (-1)^n j_n(x)
+    if x < 0.0 {
+        let v = spherical_jn_scalar(n, -x);
+        return if n % 2 == 0 { v } else { -v };
+    }
+
+    let (s, c) = x.sin_cos();
+    let j0 = s / x;
+    if n == 0 {
+        return j0;
+    }
+    let j1 = (s / x - c) / x;
+    if x > n as f64 {
+        // upward recurrence is stable for x > n
+        let (mut jm, mut j) = (j0, j1);
+        for k in 1..n {
+            (jm, j) = (j, (2 * k + 1) as f64 / x * j - jm);
+        }
+        j
+    } else {
+        // Miller's downward recurrence, normalized by j_0 or j_1
+        let nstart = miller_start(n, x);
+        let (mut fp, mut f) = (0.0, 1e-300);
+        let mut fn_ = 0.0;
+        for k in (1..=nstart).rev() {
+            (fp, f) = (f, (2 * k + 1) as f64 / x * f - fp);
+            if k - 1 == n {
+                fn_ = f;
+            }
+            if f.abs() > 1e250 {
+                (fp, f, fn_) = (fp * 1e-250, f * 1e-250, fn_ * 1e-250);
+            }
+        }
+        // f is f_0, fp is f_1
+        match j0.abs() >= j1.abs() {
+            true => fn_ * (j0 / f),
+            false => fn_ * (j1 / fp),
+        }
+    }
+}
+
+/// Modified spherical Bessel function of the first kind `i_n(x)`.
+pub fn spherical_in_scalar(n: usize, x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return if n == 0 { 1.0 } else { 0.0 };
+    }
+    // i_n(-x) = (-1)^n i_n(x)
+    if x < 0.0 {
+        let v = spherical_in_scalar(n, -x);
+        return if n % 2 == 0 { v } else { -v };
+    }
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    let i0 = x.sinh() / x;
+    if n == 0 || i0.is_infinite() {
+        return i0;
+    }
+    if x > 2.0 * n as f64 {
+        // upward recurrence is stable enough when x is much larger than n
+        let i1 = (x.cosh() - i0) / x;
+        let (mut im, mut i) = (i0, i1);
+        for k in 1..n {
+            (im, i) = (i, im - (2 * k + 1) as f64 / x * i);
+        }
+        i
+    } else {
+        // Miller's downward recurrence, normalized by i_0
+        let nstart = miller_start(n, x);
+        let (mut fp, mut f) = (0.0, 1e-300);
+        let mut fn_ = 0.0;
+        for k in (1..=nstart).rev() {
+            (fp, f) = (f, (2 * k + 1) as f64 / x * f + fp);
+            if k - 1 == n {
+                fn_ = f;
+            }
+            if f.abs() > 1e250 {
+                (fp, f, fn_) = (fp * 1e-250, f * 1e-250, fn_ * 1e-250);
+            }
+        }
+        fn_ * (i0 / f)
+    }
+}
+
+/* #endregion */
+
+/* #region Legendre functions */
+
+/// Associated Legendre function `P_l^m(x)` of integer degree `l` and order `m`, for `|x| <= 1`.
+///
+/// Condon-Shortley phase `(-1)^m` is included (same to SciPy's `lpmv`). Returns zero for `|m| >
+/// l`, and NaN for `|x| > 1`.
+pub fn lpmv_scalar(m: isize, l: usize, x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    let mabs = m.unsigned_abs();
+    if mabs > l {
+        return 0.0;
+    }
+
+    // P_m^m = (-1)^m (2m-1)!! (1-x²)^{m/2}
+    let sx = ((1.0 - x) * (1.0 + x)).sqrt();
+    let mut pmm = 1.0;
+    for k in 0..mabs {
+        pmm *= -((2 * k + 1) as f64) * sx;
+    }
+    let val = if l == mabs {
+        pmm
+    } else {
+        let mut p_prev = pmm;
+        let mut p = x * (2 * mabs + 1) as f64 * pmm;
+        for k in mabs + 2..=l {
+            (p_prev, p) = (p, ((2 * k - 1) as f64 * x * p - (k + mabs - 1) as f64 * p_prev) / (k - mabs) as f64);
+        }
+        p
+    };
+    if m >= 0 {
+        val
+    } else {
+        // P_l^{-m} = (-1)^m (l-m)! / (l+m)! P_l^m
+        let ratio = (l - mabs + 1..=l + mabs).fold(1.0, |acc, k| acc / k as f64);
+        let sign = if mabs % 2 == 0 { 1.0 } else { -1.0 };
+        sign * ratio * val
+    }
+}
+
+/// Normalized associated Legendre functions (without Condon-Shortley phase) `P̃_l^m(cos θ)` of
+/// orders `0..=l` at degree `l`, written to `out`.
+///
+/// Normalization is `sqrt((2l+1)/(4π) (l-m)!/(l+m)!)`, so that `P̃_l^m(cos θ) e^{imφ}` is
+/// orthonormal on the unit sphere. Stable recursion of normalized functions is used, so that
+/// high degree does not overflow.
+fn normalized_legendre(l: usize, cos_theta: f64, sin_theta: f64, out: &mut [f64]) {
+    let x = cos_theta;
+    // P̃_m^m, computed from P̃_0^0 = 1 / sqrt(4π)
+    let mut pmm = 1.0 / (4.0 * PI).sqrt();
+    for (m, out_m) in out[..=l].iter_mut().enumerate() {
+        if m > 0 {
+            pmm *= ((2 * m + 1) as f64 / (2 * m) as f64).sqrt() * sin_theta;
+        }
+        if m == l {
+            *out_m = pmm;
+            break;
+        }
+        // upward in degree at fixed order m
+        let mut p_prev = pmm;
+        let mut p = (2.0 * m as f64 + 3.0).sqrt() * x * pmm;
+        let a = |k: usize| (((4 * k * k - 1) as f64) / ((k * k - m * m) as f64)).sqrt();
+        for k in m + 2..=l {
+            (p_prev, p) = (p, a(k) * (x * p - p_prev / a(k - 1)));
+        }
+        *out_m = p;
+    }
+}
+
+/// Polar angle cosine/sine and azimuthal angle of Cartesian coordinate.
+///
+/// Direction of zero vector is assigned to be `+z`.
+#[inline]
+fn cartesian_to_angles(coord: &[f64]) -> (f64, f64, f64) {
+    let (x, y, z) = (coord[0], coord[1], coord[2]);
+    let rxy = x.hypot(y);
+    let r = rxy.hypot(z);
+    if r == 0.0 {
+        return (1.0, 0.0, 0.0);
+    }
+    (z / r, rxy / r, y.atan2(x))
+}
+
+/// Complex spherical harmonics `Y_l^m` (Condon-Shortley phase included, same to SciPy) at
+/// direction of Cartesian coordinate `coord`.
+pub fn sph_harm_scalar(l: usize, m: isize, coord: &[f64]) -> Complex<f64> {
+    let mabs = m.unsigned_abs();
+    if mabs > l {
+        return Complex::new(0.0, 0.0);
+    }
+    let (cos_theta, sin_theta, phi) = cartesian_to_angles(coord);
+    let mut plm = vec![0.0; l + 1];
+    normalized_legendre(l, cos_theta, sin_theta, &mut plm);
+    let (s, c) = (m as f64 * phi).sin_cos();
+    // Condon-Shortley phase for positive m; Y_l^{-m} = (-1)^m conj(Y_l^m) cancels it for negative m
+    let sign = if m > 0 && mabs % 2 == 1 { -1.0 } else { 1.0 };
+    Complex::new(c, s) * (sign * plm[mabs])
+}
+
+/// Real spherical harmonics of degree `l` and all orders `m = -l..=l` at direction of Cartesian
+/// coordinate `coord`, written to `out` (length `2l + 1`).
+///
+/// Convention without Condon-Shortley phase is used: `m > 0` is the cosine-type `√2 P̃_l^m cos(mφ)`,
+/// and `m < 0` is the sine-type `√2 P̃_l^|m| sin(|m|φ)`. For `l = 1`, orders `(-1, 0, 1)`
+/// correspond to `(y, z, x)`.
+pub fn real_sph_harm_all_scalar(l: usize, coord: &[f64], out: &mut [f64]) {
+    let (cos_theta, sin_theta, phi) = cartesian_to_angles(coord);
+    let mut plm = vec![0.0; l + 1];
+    normalized_legendre(l, cos_theta, sin_theta, &mut plm);
+    out[l] = plm[0];
+    for m in 1..=l {
+        let (s, c) = (m as f64 * phi).sin_cos();
+        out[l + m] = core::f64::consts::SQRT_2 * plm[m] * c;
+        out[l - m] = core::f64::consts::SQRT_2 * plm[m] * s;
+    }
+}
+
+/// Real spherical harmonics of degree `l` and order `m` at direction of Cartesian coordinate
+/// `coord`.
+///
+/// See [`real_sph_harm_all_scalar`] for convention.
+pub fn real_sph_harm_scalar(l: usize, m: isize, coord: &[f64]) -> f64 {
+    if m.unsigned_abs() > l {
+        return 0.0;
+    }
+    let mut out = vec![0.0; 2 * l + 1];
+    real_sph_harm_all_scalar(l, coord, &mut out);
+    out[(l as isize + m) as usize]
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64, rtol: f64) {
+        assert!((a - b).abs() <= rtol * b.abs().max(1e-300), "{a} vs {b}");
+    }
+
+    #[test]
+    fn test_boys() {
+        // F_0(t) = √π erf(√t) / (2√t)
+        for t in [1e-10, 0.3, 5.0, 39.9, 40.1, 200.0] {
+            assert_close(boys_scalar(0, t), 0.5 * (PI / t).sqrt() * t.sqrt().ext_erf(), 1e-13);
+        }
+        // consistency of all routes: scalar and array, small and large t
+        let mut out = vec![0.0; 31];
+        for t in [0.0, 0.01, 1.5, 25.0, 45.0, 120.0] {
+            boys_array(30, t, &mut out);
+            for m in [0, 1, 7, 30] {
+                assert_close(out[m], boys_scalar(m, t), 1e-12);
+            }
+        }
+        // reference by numerical integration, F_5(3.7)
+        let (x, w) = crate::integrate::gauss::roots_legendre_raw(64).unwrap();
+        let refv: f64 = izip!(&x, &w)
+            .map(|(&x, &w)| 0.5 * w * (0.5 * (x + 1.0)).powi(10) * (-3.7 * (0.5 * (x + 1.0)).powi(2)).exp())
+            .sum();
+        assert_close(boys_scalar(5, 3.7), refv, 1e-13);
+    }
+
+    #[test]
+    fn test_spherical_bessel() {
+        // closed forms of j_2 and i_2
+        for x in [0.5_f64, 2.0, 7.3, 30.0] {
+            let (s, c) = x.sin_cos();
+            let j2 = (3.0 / (x * x) - 1.0) * s / x - 3.0 * c / (x * x);
+            assert!((spherical_jn_scalar(2, x) - j2).abs() < 1e-13);
+            let i2 = (3.0 / (x * x) + 1.0) * x.sinh() / x - 3.0 * x.cosh() / (x * x);
+            assert_close(spherical_in_scalar(2, x), i2, 1e-12);
+            assert_close(spherical_jn_scalar(3, -x), -spherical_jn_scalar(3, x), 1e-15);
+        }
+        // small x: j_2(x) ≈ x²/15 (1 - x²/14), i_2(x) ≈ x²/15 (1 + x²/14)
+        let x: f64 = 1e-3;
+        assert_close(spherical_jn_scalar(2, x), x * x / 15.0 * (1.0 - x * x / 14.0), 1e-12);
+        assert_close(spherical_in_scalar(2, x), x * x / 15.0 * (1.0 + x * x / 14.0), 1e-12);
+        // reference values by mpmath
+        assert_close(spherical_jn_scalar(10, 3.0), 3.526003893175256e-06, 1e-12);
+        assert_close(spherical_jn_scalar(5, 10.0), -0.05553451162145218, 1e-12);
+        assert_close(spherical_jn_scalar(40, 12.5), 4.493488241616456e-18, 1e-12);
+        assert_close(spherical_jn_scalar(3, 50.0), 0.01981259459566375, 1e-12);
+        assert_close(spherical_in_scalar(10, 3.0), 5.214946122763417e-06, 1e-12);
+        assert_close(spherical_in_scalar(3, 10.0), 589.2079640176235, 1e-12);
+        assert_close(spherical_in_scalar(30, 5.0), 6.368893001469948e-22, 1e-12);
+        assert_close(spherical_in_scalar(4, 100.0), 1.2155612624979014e+41, 1e-12);
+        assert_eq!(spherical_jn_scalar(0, 0.0), 1.0);
+        assert_eq!(spherical_in_scalar(1, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_legendre_sph_harm() {
+        // P_3^1(x) = -3/2 (5x² - 1) sqrt(1 - x²), P_3^{-1} = P_3^1 / (-12)
+        let x: f64 = 0.37;
+        let p31 = -1.5 * (5.0 * x * x - 1.0) * (1.0 - x * x).sqrt();
+        assert_close(lpmv_scalar(1, 3, x), p31, 1e-14);
+        assert_close(lpmv_scalar(-1, 3, x), -p31 / 12.0, 1e-14);
+        assert_eq!(lpmv_scalar(4, 3, x), 0.0);
+        assert!(lpmv_scalar(0, 2, 1.5).is_nan());
+
+        // Y_2^1 = -1/2 sqrt(15/2π) sinθ cosθ e^{iφ}
+        let coord = [0.3, -0.8, 0.5];
+        let r = (0.98_f64).sqrt();
+        let (ct, st, phi) = (0.5 / r, (0.73_f64).sqrt() / r, (-0.8_f64).atan2(0.3));
+        let y21 = sph_harm_scalar(2, 1, &coord);
+        let refv = Complex::new(phi.cos(), phi.sin()) * (-0.5 * (15.0 / (2.0 * PI)).sqrt() * st * ct);
+        assert!((y21 - refv).norm() < 1e-14);
+        assert!((sph_harm_scalar(2, -1, &coord) - (-refv.conj())).norm() < 1e-14);
+
+        // real p orbitals are (y, z, x) / r * sqrt(3/4π)
+        let mut out = [0.0; 3];
+        real_sph_harm_all_scalar(1, &coord, &mut out);
+        let fac = (3.0 / (4.0 * PI)).sqrt() / r;
+        assert_close(out[0], -0.8 * fac, 1e-14);
+        assert_close(out[1], 0.5 * fac, 1e-14);
+        assert_close(out[2], 0.3 * fac, 1e-14);
+        // relation to complex harmonics
+        let y = sph_harm_scalar(3, 2, &coord);
+        assert_close(real_sph_harm_scalar(3, 2, &coord), core::f64::consts::SQRT_2 * y.re, 1e-14);
+        assert_close(real_sph_harm_scalar(3, -2, &coord), core::f64::consts::SQRT_2 * y.im, 1e-14);
+        // high degree does not overflow
+        assert!(real_sph_harm_scalar(150, 75, &coord).is_finite());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod rstsr_traits {
+    pub use crate::special::traits::SpecialFuncMapAPI;
+}
+
+pub mod rstsr_funcs {
+    pub use crate::special::funcs::{
+        boys, boys_all, boys_all_f, boys_f, lpmv, lpmv_f, real_sph_harm, real_sph_harm_all, real_sph_harm_all_f,
+        real_sph_harm_f, sph_harm, sph_harm_f, spherical_in, spherical_in_f, spherical_jn, spherical_jn_f,
+    };
+}
@@ -0,0 +1,16 @@
+use rstsr_core::prelude_dev::*;
+
+/// Chunk-wise map of contiguous data, the device-dependent part of special functions.
+///
+/// Input `x` is split into chunks of `chunk_in` elements, and each chunk is mapped by `f` into
+/// an output chunk of `chunk_out` elements. On devices with rayon, chunks are evaluated in
+/// parallel.
+pub trait SpecialFuncMapAPI<T>
+where
+    Self: DeviceAPI<T>,
+{
+    fn special_map_f<TI, F>(&self, x: &[TI], chunk_in: usize, chunk_out: usize, f: F) -> Result<Vec<T>>
+    where
+        TI: Sync,
+        F: Fn(&[TI], &mut [T]) + Send + Sync;
+}
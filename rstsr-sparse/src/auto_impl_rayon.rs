@@ -0,0 +1,74 @@
+use crate::prelude_dev::*;
+use num::Num;
+use rstsr_sparse::native_impl::*;
+use rstsr_sparse::traits::DeviceSparseAPI;
+
+impl<T> DeviceSparseAPI<T> for DeviceRayonAutoImpl
+where
+    T: Num + Clone + Send + Sync,
+{
+    fn csr_matmul_dense_f(
+        &self,
+        c: &mut [T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        b: &[T],
+        k: usize,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        csr_matmul_dense_rayon(c, indptr, indices, data, b, k, pool);
+        Ok(())
+    }
+
+    fn dense_matmul_csr_f(
+        &self,
+        c: &mut [T],
+        b: &[T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        ncol: usize,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        dense_matmul_csr_rayon(c, b, indptr, indices, data, ncol, pool);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rstsr_sparse::csr::CsrMatrix;
+
+    #[test]
+    fn test_sparse_matmul_rayon() {
+        let device = DeviceRayonAutoImpl::default();
+        let device_serial = DeviceCpuSerial::default();
+        // banded matrix of shape (300, 200), with random-like dense operands
+        let a = (0..300 * 200_usize)
+            .map(|k| {
+                let (i, j) = (k / 200, k % 200);
+                if i.abs_diff(j) < 3 {
+                    (k % 7) as f64 - 3.0
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>();
+        let a = asarray((a, [300, 200].c(), &device)).into_dim::<Ix2>();
+        let b = linspace((-1.0, 1.0, 200 * 5, &device)).into_shape((200, 5)).into_dim::<Ix2>();
+        let v = linspace((-1.0, 1.0, 300, &device)).into_dim::<Ix1>();
+        let a_s = a.to_device(&device_serial);
+        let b_s = b.to_device(&device_serial);
+        let v_s = v.to_device(&device_serial);
+
+        let sp = CsrMatrix::from_dense(a.view(), 0.0);
+        let sp_s = CsrMatrix::from_dense(a_s.view(), 0.0);
+        assert_eq!(sp.nnz(), sp_s.nnz());
+        assert_eq!((&sp % &b).raw(), (&sp_s % &b_s).raw());
+        assert_eq!((&v % &sp).raw(), (&v_s % &sp_s).raw());
+        assert!(allclose_f64(&(&sp % &b), &(&a % &b)));
+        assert!(allclose_f64(&(&v % &sp), &(&v % &a)));
+    }
+}
@@ -0,0 +1,185 @@
+//! Coordinate (COO) sparse matrix.
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::native_impl::*;
+use crate::prelude_dev::*;
+use crate::traits::sparse_to_vec_f;
+use num::complex::ComplexFloat;
+use num::Num;
+
+/// Sparse matrix in coordinate (COO, triplet) format.
+///
+/// Entry `k` is `data[k]` at `(row[k], col[k])`. Entries may be unordered and duplicated;
+/// duplicated entries are summed when converting to other formats. This format is convenient
+/// for assembling matrices, while arithmetic should be performed in CSR or CSC format.
+pub struct CooMatrix<T, B>
+where
+    B: DeviceAPI<T>,
+{
+    pub(crate) shape: [usize; 2],
+    pub(crate) row: Vec<usize>,
+    pub(crate) col: Vec<usize>,
+    pub(crate) data: Tensor<T, B, Ix1>,
+}
+
+impl<T, B> CooMatrix<T, B>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// New COO matrix of `shape` from triplets `(row, col, data)`.
+    pub fn new_f(shape: [usize; 2], row: Vec<usize>, col: Vec<usize>, data: TensorView<'_, T, B, Ix1>) -> Result<Self> {
+        let device = data.device().clone();
+        let (data, _) = sparse_to_vec_f(data)?;
+        rstsr_assert!(
+            row.len() == data.len() && col.len() == data.len(),
+            InvalidLayout,
+            "Length of row, col and data mismatch."
+        )?;
+        rstsr_assert!(row.iter().all(|&i| i < shape[0]), InvalidValue, "Row index out of bound {}.", shape[0])?;
+        rstsr_assert!(col.iter().all(|&j| j < shape[1]), InvalidValue, "Column index out of bound {}.", shape[1])?;
+        Self::from_raw_parts_f(shape, row, col, data, &device)
+    }
+
+    /// New COO matrix of `shape` from triplets `(row, col, data)`.
+    ///
+    /// See also [`CooMatrix::new_f`].
+    pub fn new(shape: [usize; 2], row: Vec<usize>, col: Vec<usize>, data: TensorView<'_, T, B, Ix1>) -> Self {
+        Self::new_f(shape, row, col, data).rstsr_unwrap()
+    }
+
+    /// New COO matrix without checking bounds of indices.
+    pub(crate) fn from_raw_parts_f(
+        shape: [usize; 2],
+        row: Vec<usize>,
+        col: Vec<usize>,
+        data: Vec<T>,
+        device: &B,
+    ) -> Result<Self> {
+        let nnz = data.len();
+        let data = asarray_f((data, [nnz].c(), device))?.into_dim_f::<Ix1>()?;
+        Ok(Self { shape, row, col, data })
+    }
+
+    pub fn shape(&self) -> [usize; 2] {
+        self.shape
+    }
+
+    /// Number of stored values (including duplicates).
+    pub fn nnz(&self) -> usize {
+        self.row.len()
+    }
+
+    pub fn row(&self) -> &[usize] {
+        &self.row
+    }
+
+    pub fn col(&self) -> &[usize] {
+        &self.col
+    }
+
+    pub fn data(&self) -> &Tensor<T, B, Ix1> {
+        &self.data
+    }
+
+    pub fn device(&self) -> &B {
+        self.data.device()
+    }
+
+    /// Transpose (swap row and column indices).
+    pub fn t(&self) -> CooMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let data = self.data.raw().clone();
+        Self::from_raw_parts_f([ncol, nrow], self.col.clone(), self.row.clone(), data, self.device()).rstsr_unwrap()
+    }
+}
+
+impl<T, B> CooMatrix<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Convert to CSR format; duplicated entries are summed.
+    pub fn to_csr(&self) -> CsrMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let (indptr, indices, data) = compress_triplets(nrow, ncol, &self.row, &self.col, self.data.raw());
+        CsrMatrix::from_raw_parts_f(self.shape, indptr, indices, data, self.device()).rstsr_unwrap()
+    }
+
+    /// Convert to CSC format; duplicated entries are summed.
+    pub fn to_csc(&self) -> CscMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let (indptr, indices, data) = compress_triplets(ncol, nrow, &self.col, &self.row, self.data.raw());
+        CscMatrix::from_raw_parts_f(self.shape, indptr, indices, data, self.device()).rstsr_unwrap()
+    }
+
+    /// Convert to dense tensor; duplicated entries are summed.
+    pub fn to_dense_f(&self) -> Result<Tensor<T, B, Ix2>> {
+        let [nrow, ncol] = self.shape;
+        let mut out = vec![T::zero(); nrow * ncol];
+        for (&i, &j, v) in izip!(&self.row, &self.col, self.data.raw()) {
+            out[i * ncol + j] = out[i * ncol + j].clone() + v.clone();
+        }
+        asarray_f((out, [nrow, ncol].c(), self.device()))?.into_dim_f::<Ix2>()
+    }
+
+    /// Convert to dense tensor; duplicated entries are summed.
+    ///
+    /// See also [`CooMatrix::to_dense_f`].
+    pub fn to_dense(&self) -> Tensor<T, B, Ix2> {
+        self.to_dense_f().rstsr_unwrap()
+    }
+}
+
+impl<T, B> CooMatrix<T, B>
+where
+    T: ComplexFloat,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    pub fn from_dense_f<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Result<Self>
+    where
+        D: DimAPI,
+    {
+        Ok(CsrMatrix::from_dense_f(a, thresh)?.to_coo())
+    }
+
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    ///
+    /// See also [`CooMatrix::from_dense_f`].
+    pub fn from_dense<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Self
+    where
+        D: DimAPI,
+    {
+        Self::from_dense_f(a, thresh).rstsr_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_coo_duplicates() {
+        let device = DeviceCpuSerial::default();
+        let data = asarray((vec![1.0, 2.0, 3.0, 4.0, 5.0], &device)).into_dim::<Ix1>();
+        let coo = CooMatrix::new([2, 3], vec![1, 0, 1, 0, 1], vec![2, 1, 0, 1, 2], data.view());
+        assert_eq!(coo.nnz(), 5);
+        let dense = coo.to_dense();
+        assert_eq!(dense.raw(), &[0.0, 6.0, 0.0, 3.0, 0.0, 6.0]);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csr.indptr(), &[0, 1, 3]);
+        assert_eq!(csr.indices(), &[1, 0, 2]);
+        let csc = coo.to_csc();
+        assert_eq!(csc.indptr(), &[0, 1, 2, 3]);
+        assert_eq!(csc.indices(), &[1, 0, 1]);
+        assert!(allclose_f64(&csc.to_dense(), &dense));
+        assert!(allclose_f64(&coo.t().to_dense(), &dense.t()));
+
+        assert!(CooMatrix::new_f([2, 3], vec![2, 0, 1, 0, 1], vec![2, 1, 0, 1, 2], data.view()).is_err());
+        assert!(CooMatrix::new_f([2, 3], vec![0, 1], vec![2, 1], data.view()).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Compressed sparse column (CSC) matrix.
+
+use crate::coo::CooMatrix;
+use crate::csr::CsrMatrix;
+use crate::native_impl::*;
+use crate::prelude_dev::*;
+use crate::traits::sparse_to_vec_f;
+use num::complex::ComplexFloat;
+use num::Num;
+
+/// Sparse matrix in compressed sparse column (CSC) format.
+///
+/// Column `j` holds row indices `indices[indptr[j]..indptr[j+1]]` with values at the same
+/// positions of `data`. CSC of `A` has the same storage as CSR of `A^T`.
+///
+/// The matrix is always canonical: row indices in each column are sorted and unique. This is
+/// checked in [`CscMatrix::new`].
+pub struct CscMatrix<T, B>
+where
+    B: DeviceAPI<T>,
+{
+    pub(crate) shape: [usize; 2],
+    pub(crate) indptr: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) data: Tensor<T, B, Ix1>,
+}
+
+impl<T, B> CscMatrix<T, B>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// New CSC matrix of `shape` from `indptr` (length `ncol + 1`), `indices` and `data` (length
+    /// `nnz`).
+    pub fn new_f(
+        shape: [usize; 2],
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: TensorView<'_, T, B, Ix1>,
+    ) -> Result<Self> {
+        let device = data.device().clone();
+        let (data, _) = sparse_to_vec_f(data)?;
+        check_compressed(shape[1], shape[0], &indptr, &indices, data.len())?;
+        Self::from_raw_parts_f(shape, indptr, indices, data, &device)
+    }
+
+    /// New CSC matrix of `shape` from `indptr` (length `ncol + 1`), `indices` and `data` (length
+    /// `nnz`).
+    ///
+    /// See also [`CscMatrix::new_f`].
+    pub fn new(shape: [usize; 2], indptr: Vec<usize>, indices: Vec<usize>, data: TensorView<'_, T, B, Ix1>) -> Self {
+        Self::new_f(shape, indptr, indices, data).rstsr_unwrap()
+    }
+
+    /// New CSC matrix without checking canonical form.
+    pub(crate) fn from_raw_parts_f(
+        shape: [usize; 2],
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<T>,
+        device: &B,
+    ) -> Result<Self> {
+        let nnz = data.len();
+        let data = asarray_f((data, [nnz].c(), device))?.into_dim_f::<Ix1>()?;
+        Ok(Self { shape, indptr, indices, data })
+    }
+
+    pub fn shape(&self) -> [usize; 2] {
+        self.shape
+    }
+
+    /// Number of stored values.
+    pub fn nnz(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn data(&self) -> &Tensor<T, B, Ix1> {
+        &self.data
+    }
+
+    pub fn device(&self) -> &B {
+        self.data.device()
+    }
+
+    /// Transpose; CSC of `A` is reinterpreted as CSR of `A^T` without moving data.
+    pub fn t(&self) -> CsrMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let data = self.data.raw().clone();
+        CsrMatrix::from_raw_parts_f([ncol, nrow], self.indptr.clone(), self.indices.clone(), data, self.device())
+            .rstsr_unwrap()
+    }
+
+    /// Convert to CSR format.
+    pub fn to_csr(&self) -> CsrMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let (indptr, indices, data) = compressed_transpose(nrow, &self.indptr, &self.indices, self.data.raw());
+        CsrMatrix::from_raw_parts_f([nrow, ncol], indptr, indices, data, self.device()).rstsr_unwrap()
+    }
+
+    /// Convert to COO format (column-major order).
+    pub fn to_coo(&self) -> CooMatrix<T, B> {
+        let col = (0..self.shape[1]).flat_map(|j| core::iter::repeat_n(j, self.indptr[j + 1] - self.indptr[j]));
+        let col = col.collect_vec();
+        let data = self.data.raw().clone();
+        CooMatrix::from_raw_parts_f(self.shape, self.indices.clone(), col, data, self.device()).rstsr_unwrap()
+    }
+}
+
+impl<T, B> CscMatrix<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Convert to dense tensor.
+    pub fn to_dense_f(&self) -> Result<Tensor<T, B, Ix2>> {
+        let [nrow, ncol] = self.shape;
+        let data = self.data.raw();
+        let mut out = vec![T::zero(); nrow * ncol];
+        for j in 0..ncol {
+            for p in self.indptr[j]..self.indptr[j + 1] {
+                out[self.indices[p] * ncol + j] = data[p].clone();
+            }
+        }
+        asarray_f((out, [nrow, ncol].c(), self.device()))?.into_dim_f::<Ix2>()
+    }
+
+    /// Convert to dense tensor.
+    ///
+    /// See also [`CscMatrix::to_dense_f`].
+    pub fn to_dense(&self) -> Tensor<T, B, Ix2> {
+        self.to_dense_f().rstsr_unwrap()
+    }
+}
+
+impl<T, B> CscMatrix<T, B>
+where
+    T: ComplexFloat,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    ///
+    /// `thresh = 0` keeps all non-zero values.
+    pub fn from_dense_f<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Result<Self>
+    where
+        D: DimAPI,
+    {
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Dense matrix should be 2-D.")?;
+        Ok(CsrMatrix::from_dense_f(a.into_reverse_axes(), thresh)?.t())
+    }
+
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    ///
+    /// See also [`CscMatrix::from_dense_f`].
+    pub fn from_dense<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Self
+    where
+        D: DimAPI,
+    {
+        Self::from_dense_f(a, thresh).rstsr_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_csc_dense_conversion() {
+        let device = DeviceCpuSerial::default();
+        let a = asarray((vec![1.0, 0.0, 2.0, 0.0, 0.0, 3.0, 4.0, 5.0, 0.0, 0.0, 6.0, 0.0], [3, 4].c(), &device));
+        let sp = CscMatrix::from_dense(a.view(), 0.0);
+        assert_eq!(sp.shape(), [3, 4]);
+        assert_eq!(sp.indptr(), &[0, 1, 2, 5, 6]);
+        assert_eq!(sp.indices(), &[0, 1, 0, 1, 2, 1]);
+        assert_eq!(sp.data().raw(), &[1.0, 3.0, 2.0, 4.0, 6.0, 5.0]);
+        assert!(allclose_f64(&sp.to_dense(), &a));
+        assert!(allclose_f64(&sp.t().to_dense(), &a.t()));
+        assert!(allclose_f64(&sp.to_csr().to_dense(), &a));
+        assert!(allclose_f64(&sp.to_coo().to_dense(), &a));
+        assert_eq!(sp.to_csr().indptr(), &[0, 2, 5, 6]);
+    }
+}
@@ -0,0 +1,217 @@
+//! Compressed sparse row (CSR) matrix.
+
+use crate::coo::CooMatrix;
+use crate::csc::CscMatrix;
+use crate::native_impl::*;
+use crate::prelude_dev::*;
+use crate::traits::sparse_to_vec_f;
+use num::complex::ComplexFloat;
+use num::Num;
+
+/// Sparse matrix in compressed sparse row (CSR) format.
+///
+/// Row `i` holds column indices `indices[indptr[i]..indptr[i+1]]` with values at the same
+/// positions of `data`. Index arrays are kept on host, values are stored as 1-D tensor on device.
+///
+/// The matrix is always canonical: column indices in each row are sorted and unique. This is
+/// checked in [`CsrMatrix::new`].
+pub struct CsrMatrix<T, B>
+where
+    B: DeviceAPI<T>,
+{
+    pub(crate) shape: [usize; 2],
+    pub(crate) indptr: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) data: Tensor<T, B, Ix1>,
+}
+
+impl<T, B> CsrMatrix<T, B>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// New CSR matrix of `shape` from `indptr` (length `nrow + 1`), `indices` and `data` (length
+    /// `nnz`).
+    pub fn new_f(
+        shape: [usize; 2],
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: TensorView<'_, T, B, Ix1>,
+    ) -> Result<Self> {
+        let device = data.device().clone();
+        let (data, _) = sparse_to_vec_f(data)?;
+        check_compressed(shape[0], shape[1], &indptr, &indices, data.len())?;
+        Self::from_raw_parts_f(shape, indptr, indices, data, &device)
+    }
+
+    /// New CSR matrix of `shape` from `indptr` (length `nrow + 1`), `indices` and `data` (length
+    /// `nnz`).
+    ///
+    /// See also [`CsrMatrix::new_f`].
+    pub fn new(shape: [usize; 2], indptr: Vec<usize>, indices: Vec<usize>, data: TensorView<'_, T, B, Ix1>) -> Self {
+        Self::new_f(shape, indptr, indices, data).rstsr_unwrap()
+    }
+
+    /// New CSR matrix without checking canonical form.
+    pub(crate) fn from_raw_parts_f(
+        shape: [usize; 2],
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<T>,
+        device: &B,
+    ) -> Result<Self> {
+        let nnz = data.len();
+        let data = asarray_f((data, [nnz].c(), device))?.into_dim_f::<Ix1>()?;
+        Ok(Self { shape, indptr, indices, data })
+    }
+
+    pub fn shape(&self) -> [usize; 2] {
+        self.shape
+    }
+
+    /// Number of stored values.
+    pub fn nnz(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn data(&self) -> &Tensor<T, B, Ix1> {
+        &self.data
+    }
+
+    pub fn device(&self) -> &B {
+        self.data.device()
+    }
+
+    /// Transpose; CSR of `A` is reinterpreted as CSC of `A^T` without moving data.
+    pub fn t(&self) -> CscMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let data = self.data.raw().clone();
+        CscMatrix::from_raw_parts_f([ncol, nrow], self.indptr.clone(), self.indices.clone(), data, self.device())
+            .rstsr_unwrap()
+    }
+
+    /// Convert to CSC format.
+    pub fn to_csc(&self) -> CscMatrix<T, B> {
+        let [nrow, ncol] = self.shape;
+        let (indptr, indices, data) = compressed_transpose(ncol, &self.indptr, &self.indices, self.data.raw());
+        CscMatrix::from_raw_parts_f([nrow, ncol], indptr, indices, data, self.device()).rstsr_unwrap()
+    }
+
+    /// Convert to COO format (row-major order).
+    pub fn to_coo(&self) -> CooMatrix<T, B> {
+        let row = (0..self.shape[0]).flat_map(|i| core::iter::repeat_n(i, self.indptr[i + 1] - self.indptr[i]));
+        let row = row.collect_vec();
+        let data = self.data.raw().clone();
+        CooMatrix::from_raw_parts_f(self.shape, row, self.indices.clone(), data, self.device()).rstsr_unwrap()
+    }
+}
+
+impl<T, B> CsrMatrix<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Convert to dense tensor.
+    pub fn to_dense_f(&self) -> Result<Tensor<T, B, Ix2>> {
+        let [nrow, ncol] = self.shape;
+        let data = self.data.raw();
+        let mut out = vec![T::zero(); nrow * ncol];
+        for i in 0..nrow {
+            for p in self.indptr[i]..self.indptr[i + 1] {
+                out[i * ncol + self.indices[p]] = data[p].clone();
+            }
+        }
+        asarray_f((out, [nrow, ncol].c(), self.device()))?.into_dim_f::<Ix2>()
+    }
+
+    /// Convert to dense tensor.
+    ///
+    /// See also [`CsrMatrix::to_dense_f`].
+    pub fn to_dense(&self) -> Tensor<T, B, Ix2> {
+        self.to_dense_f().rstsr_unwrap()
+    }
+}
+
+impl<T, B> CsrMatrix<T, B>
+where
+    T: ComplexFloat,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    ///
+    /// `thresh = 0` keeps all non-zero values.
+    pub fn from_dense_f<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Result<Self>
+    where
+        D: DimAPI,
+    {
+        rstsr_assert_eq!(a.ndim(), 2, InvalidLayout, "Dense matrix should be 2-D.")?;
+        let device = a.device().clone();
+        let (a, shape) = sparse_to_vec_f(a)?;
+        let (nrow, ncol) = (shape[0], shape[1]);
+        let mut indptr = vec![0; nrow + 1];
+        let mut indices = vec![];
+        let mut data = vec![];
+        for i in 0..nrow {
+            for (j, &v) in a[i * ncol..(i + 1) * ncol].iter().enumerate() {
+                if v.abs() > thresh {
+                    indices.push(j);
+                    data.push(v);
+                }
+            }
+            indptr[i + 1] = indices.len();
+        }
+        Self::from_raw_parts_f([nrow, ncol], indptr, indices, data, &device)
+    }
+
+    /// Sparse matrix from 2-D dense tensor, keeping values with `|a_ij| > thresh`.
+    ///
+    /// See also [`CsrMatrix::from_dense_f`].
+    pub fn from_dense<D>(a: TensorView<'_, T, B, D>, thresh: T::Real) -> Self
+    where
+        D: DimAPI,
+    {
+        Self::from_dense_f(a, thresh).rstsr_unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_csr_dense_conversion() {
+        let device = DeviceCpuSerial::default();
+        let a = asarray((vec![1.0, 0.0, 2.0, 0.0, 1e-12, 3.0, 4.0, 5.0, 0.0], [3, 3].c(), &device));
+        let sp = CsrMatrix::from_dense(a.view(), 0.0);
+        assert_eq!(sp.nnz(), 6);
+        assert!(allclose_f64(&sp.to_dense(), &a));
+
+        let sp = CsrMatrix::from_dense(a.view(), 1e-8);
+        assert_eq!(sp.shape(), [3, 3]);
+        assert_eq!(sp.indptr(), &[0, 2, 3, 5]);
+        assert_eq!(sp.indices(), &[0, 2, 2, 0, 1]);
+        assert_eq!(sp.data().raw(), &[1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        // transpose, and conversion to other formats
+        let at = a.t().mapv(|v: f64| if v.abs() > 1e-8 { v } else { 0.0 });
+        assert!(allclose_f64(&sp.t().to_dense(), &at));
+        assert!(allclose_f64(&sp.to_csc().to_dense(), &sp.to_dense()));
+        assert!(allclose_f64(&sp.to_coo().to_dense(), &sp.to_dense()));
+        assert_eq!(sp.to_csc().indptr(), &[0, 2, 3, 5]);
+
+        // non-canonical input is rejected
+        let data = asarray((vec![1.0, 2.0], &device)).into_dim::<Ix1>();
+        assert!(CsrMatrix::new_f([2, 3], vec![0, 2, 2], vec![2, 1], data.view()).is_err());
+        assert!(CsrMatrix::new_f([2, 3], vec![0, 1, 2], vec![2, 3], data.view()).is_err());
+        let sp = CsrMatrix::new([2, 3], vec![0, 1, 2], vec![2, 0], data.view());
+        assert_eq!(sp.to_dense().raw(), &[0.0, 0.0, 1.0, 2.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::native_impl::*;
+use crate::prelude_dev::*;
+use crate::traits::DeviceSparseAPI;
+use num::Num;
+use rstsr_blas_traits::device_blas::{CblasBackendAPI, DeviceBLAS};
+
+impl<B, T> DeviceSparseAPI<T> for DeviceBLAS<B>
+where
+    B: CblasBackendAPI,
+    T: Num + Clone + Send + Sync,
+{
+    fn csr_matmul_dense_f(
+        &self,
+        c: &mut [T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        b: &[T],
+        k: usize,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        csr_matmul_dense_rayon(c, indptr, indices, data, b, k, pool);
+        Ok(())
+    }
+
+    fn dense_matmul_csr_f(
+        &self,
+        c: &mut [T],
+        b: &[T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        ncol: usize,
+    ) -> Result<()> {
+        let pool = self.get_current_pool();
+        dense_matmul_csr_rayon(c, b, indptr, indices, data, ncol, pool);
+        Ok(())
+    }
+}
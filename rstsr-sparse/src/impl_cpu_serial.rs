@@ -0,0 +1,35 @@
+use crate::native_impl::*;
+use crate::traits::DeviceSparseAPI;
+use num::Num;
+use rstsr_core::prelude_dev::*;
+
+impl<T> DeviceSparseAPI<T> for DeviceCpuSerial
+where
+    T: Num + Clone,
+{
+    fn csr_matmul_dense_f(
+        &self,
+        c: &mut [T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        b: &[T],
+        k: usize,
+    ) -> Result<()> {
+        csr_matmul_dense_serial(c, indptr, indices, data, b, k);
+        Ok(())
+    }
+
+    fn dense_matmul_csr_f(
+        &self,
+        c: &mut [T],
+        b: &[T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        ncol: usize,
+    ) -> Result<()> {
+        dense_matmul_csr_serial(c, b, indptr, indices, data, ncol);
+        Ok(())
+    }
+}
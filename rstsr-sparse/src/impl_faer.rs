@@ -0,0 +1 @@
+auto_impl_rayon.rs
\ No newline at end of file
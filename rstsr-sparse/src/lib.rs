@@ -0,0 +1,19 @@
+#![allow(clippy::needless_return)]
+#![doc = include_str!("../readme.md")]
+
+pub mod prelude;
+pub mod prelude_dev;
+
+pub mod coo;
+pub mod csc;
+pub mod csr;
+pub mod impl_cpu_serial;
+pub mod matmul;
+pub mod native_impl;
+pub mod ops;
+pub mod traits;
+
+#[cfg(feature = "blas")]
+pub mod impl_blas;
+#[cfg(feature = "faer")]
+pub mod impl_faer;
@@ -0,0 +1,251 @@
+//! Sparse-dense matrix multiplication.
+//!
+//! Dense operand can be 1-D (SpMV) or 2-D; output has the same dimension type to the dense
+//! operand. Both `sparse % dense` and `dense % sparse` are supported, similar to dense
+//! [`matmul`](rstsr_core::prelude::rstsr_funcs::matmul).
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::prelude_dev::*;
+use crate::traits::{sparse_to_vec_f, DeviceSparseAPI};
+use num::Num;
+
+/* #region kernel wrappers */
+
+/// `op(A) b`, with `A` given by CSR of shape `[nrow, ncol]`; `op(A) = A^T` if `trans`.
+///
+/// Transposed case is evaluated as `(b^T A)^T`.
+fn sparse_matmul_dense_f<T, B, D>(
+    csr: (&[usize], &[usize], &[T]),
+    shape: [usize; 2],
+    trans: bool,
+    b: TensorView<'_, T, B, D>,
+) -> Result<Tensor<T, B, D>>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD> + DeviceSparseAPI<T>,
+    D: DimAPI,
+{
+    let (indptr, indices, data) = csr;
+    let [nrow, ncol] = shape;
+    let (n_in, n_out) = if trans { (nrow, ncol) } else { (ncol, nrow) };
+    let ndim = b.ndim();
+    rstsr_assert!(ndim == 1 || ndim == 2, InvalidLayout, "Dense operand of sparse matmul should be 1-D or 2-D.")?;
+    rstsr_assert_eq!(b.shape()[0], n_in, InvalidLayout, "Shape mismatch for sparse matmul.")?;
+    let device = b.device().clone();
+    let k = if ndim == 1 { 1 } else { b.shape()[1] };
+    let mut out = vec![T::zero(); n_out * k];
+    let out = match (trans, ndim) {
+        (false, _) => {
+            let (b, _) = sparse_to_vec_f(b)?;
+            device.csr_matmul_dense_f(&mut out, indptr, indices, data, &b, k)?;
+            let out_shape = if ndim == 1 { vec![n_out] } else { vec![n_out, k] };
+            asarray_f((out, out_shape.c(), &device))?
+        },
+        (true, 1) => {
+            let (b, _) = sparse_to_vec_f(b)?;
+            device.dense_matmul_csr_f(&mut out, &b, indptr, indices, data, n_out)?;
+            asarray_f((out, [n_out].c(), &device))?.into_dim_f::<IxD>()?
+        },
+        (true, _) => {
+            let (bt, _) = sparse_to_vec_f(b.into_reverse_axes())?;
+            device.dense_matmul_csr_f(&mut out, &bt, indptr, indices, data, n_out)?;
+            asarray_f((out, [k, n_out].c(), &device))?.into_reverse_axes().into_dim_f::<IxD>()?
+        },
+    };
+    out.into_dim_f::<D>()
+}
+
+/// `b op(A)`, with `A` given by CSR of shape `[nrow, ncol]`; `op(A) = A^T` if `trans`.
+///
+/// Transposed case is evaluated as `(A b^T)^T`.
+fn dense_matmul_sparse_f<T, B, D>(
+    b: TensorView<'_, T, B, D>,
+    csr: (&[usize], &[usize], &[T]),
+    shape: [usize; 2],
+    trans: bool,
+) -> Result<Tensor<T, B, D>>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD> + DeviceSparseAPI<T>,
+    D: DimAPI,
+{
+    let (indptr, indices, data) = csr;
+    let [nrow, ncol] = shape;
+    let (n_in, n_out) = if trans { (ncol, nrow) } else { (nrow, ncol) };
+    let ndim = b.ndim();
+    rstsr_assert!(ndim == 1 || ndim == 2, InvalidLayout, "Dense operand of sparse matmul should be 1-D or 2-D.")?;
+    rstsr_assert_eq!(b.shape()[ndim - 1], n_in, InvalidLayout, "Shape mismatch for sparse matmul.")?;
+    let device = b.device().clone();
+    let m = if ndim == 1 { 1 } else { b.shape()[0] };
+    let mut out = vec![T::zero(); m * n_out];
+    let out = match (trans, ndim) {
+        (false, _) => {
+            let (b, _) = sparse_to_vec_f(b)?;
+            device.dense_matmul_csr_f(&mut out, &b, indptr, indices, data, n_out)?;
+            let out_shape = if ndim == 1 { vec![n_out] } else { vec![m, n_out] };
+            asarray_f((out, out_shape.c(), &device))?
+        },
+        (true, 1) => {
+            let (b, _) = sparse_to_vec_f(b)?;
+            device.csr_matmul_dense_f(&mut out, indptr, indices, data, &b, 1)?;
+            asarray_f((out, [n_out].c(), &device))?.into_dim_f::<IxD>()?
+        },
+        (true, _) => {
+            let (bt, _) = sparse_to_vec_f(b.into_reverse_axes())?;
+            device.csr_matmul_dense_f(&mut out, indptr, indices, data, &bt, m)?;
+            asarray_f((out, [n_out, m].c(), &device))?.into_reverse_axes().into_dim_f::<IxD>()?
+        },
+    };
+    out.into_dim_f::<D>()
+}
+
+/* #endregion */
+
+/* #region matmul methods */
+
+#[duplicate_item(
+    SpType        trans ;
+   [CsrMatrix]   [false];
+   [CscMatrix]   [true] ;
+)]
+impl<T, B> SpType<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD> + DeviceSparseAPI<T>,
+{
+    /// Sparse-dense matrix multiplication `A b`, for 1-D or 2-D `b`.
+    pub fn matmul_f<R, D>(&self, b: &TensorAny<R, T, B, D>) -> Result<Tensor<T, B, D>>
+    where
+        R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+        D: DimAPI,
+    {
+        rstsr_assert!(self.device().same_device(b.device()), DeviceMismatch)?;
+        let [nrow, ncol] = self.shape;
+        let shape = if trans { [ncol, nrow] } else { [nrow, ncol] };
+        sparse_matmul_dense_f((&self.indptr, &self.indices, self.data.raw()), shape, trans, b.view())
+    }
+
+    /// Sparse-dense matrix multiplication `A b`, for 1-D or 2-D `b`.
+    ///
+    /// See also [`matmul_f`](Self::matmul_f).
+    pub fn matmul<R, D>(&self, b: &TensorAny<R, T, B, D>) -> Tensor<T, B, D>
+    where
+        R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+        D: DimAPI,
+    {
+        self.matmul_f(b).rstsr_unwrap()
+    }
+
+    /// Dense-sparse matrix multiplication `b A`, for 1-D or 2-D `b`.
+    pub fn rmatmul_f<R, D>(&self, b: &TensorAny<R, T, B, D>) -> Result<Tensor<T, B, D>>
+    where
+        R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+        D: DimAPI,
+    {
+        rstsr_assert!(self.device().same_device(b.device()), DeviceMismatch)?;
+        let [nrow, ncol] = self.shape;
+        let shape = if trans { [ncol, nrow] } else { [nrow, ncol] };
+        dense_matmul_sparse_f(b.view(), (&self.indptr, &self.indices, self.data.raw()), shape, trans)
+    }
+
+    /// Dense-sparse matrix multiplication `b A`, for 1-D or 2-D `b`.
+    ///
+    /// See also [`rmatmul_f`](Self::rmatmul_f).
+    pub fn rmatmul<R, D>(&self, b: &TensorAny<R, T, B, D>) -> Tensor<T, B, D>
+    where
+        R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+        D: DimAPI,
+    {
+        self.rmatmul_f(b).rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+/* #region matmul implementation to core ops */
+
+#[duplicate_item(
+    SpType       TrB                      ;
+   [CsrMatrix]  [ TensorAny<R, T, B, D>] ;
+   [CsrMatrix]  [&TensorAny<R, T, B, D>] ;
+   [CscMatrix]  [ TensorAny<R, T, B, D>] ;
+   [CscMatrix]  [&TensorAny<R, T, B, D>] ;
+)]
+impl<R, T, B, D> Rem<TrB> for &SpType<T, B>
+where
+    T: Num + Clone,
+    R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD> + DeviceSparseAPI<T>,
+    D: DimAPI,
+{
+    type Output = Tensor<T, B, D>;
+    #[allow(clippy::needless_borrow)]
+    fn rem(self, rhs: TrB) -> Self::Output {
+        self.matmul_f(&rhs).rstsr_unwrap()
+    }
+}
+
+#[duplicate_item(
+    TrA                       SpType      ;
+   [ TensorAny<R, T, B, D>]  [CsrMatrix] ;
+   [&TensorAny<R, T, B, D>]  [CsrMatrix] ;
+   [ TensorAny<R, T, B, D>]  [CscMatrix] ;
+   [&TensorAny<R, T, B, D>]  [CscMatrix] ;
+)]
+impl<R, T, B, D> Rem<&SpType<T, B>> for TrA
+where
+    T: Num + Clone,
+    R: DataAPI<Data = <B as DeviceRawAPI<T>>::Raw>,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD> + DeviceSparseAPI<T>,
+    D: DimAPI,
+{
+    type Output = Tensor<T, B, D>;
+    #[allow(clippy::needless_borrow)]
+    fn rem(self, rhs: &SpType<T, B>) -> Self::Output {
+        rhs.rmatmul_f(&self).rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sparse_matmul() {
+        let device = DeviceCpuSerial::default();
+        let a = asarray((vec![1.0, 0.0, 2.0, 0.0, 0.0, 3.0, 4.0, 5.0, 0.0, 0.0, 6.0, 0.0], [3, 4].c(), &device))
+            .into_dim::<Ix2>();
+        let b = linspace((-1.0, 2.0, 8, &device)).into_shape((4, 2)).into_dim::<Ix2>();
+        let c = linspace((-1.0, 2.0, 6, &device)).into_shape((2, 3)).into_dim::<Ix2>();
+        let v = asarray((vec![1.0, -2.0, 0.5, 3.0], &device)).into_dim::<Ix1>();
+        let w = asarray((vec![1.0, -2.0, 0.5], &device)).into_dim::<Ix1>();
+        let csr = CsrMatrix::from_dense(a.view(), 0.0);
+        let csc = CscMatrix::from_dense(a.view(), 0.0);
+
+        // sparse % dense
+        assert!(allclose_f64(&(&csr % &b), &(&a % &b)));
+        assert!(allclose_f64(&(&csc % &b), &(&a % &b)));
+        assert_eq!((&csr % &v).shape(), &[3]);
+        assert!(allclose_f64(&(&csr % &v), &(&a % &v)));
+        assert!(allclose_f64(&(&csc % v.view()), &(&a % &v)));
+
+        // dense % sparse
+        assert!(allclose_f64(&(&c % &csr), &(&c % &a)));
+        assert!(allclose_f64(&(c.view() % &csc), &(&c % &a)));
+        assert!(allclose_f64(&(&w % &csr), &(&w % &a)));
+        assert!(allclose_f64(&(&w % &csc), &(&w % &a)));
+
+        // non-contiguous dense operand, and transposed sparse matrices
+        let bt = b.t().to_owned();
+        assert!(allclose_f64(&(&csr % bt.t()), &(&a % &b)));
+        assert!(allclose_f64(&(&csr.t() % &c.t()), &(&a.t() % &c.t())));
+        assert!(allclose_f64(&(&b.t() % &csc.t()), &(&b.t() % &a.t())));
+
+        // shape mismatch
+        assert!(csr.matmul_f(&c).is_err());
+        assert!(csc.rmatmul_f(&b).is_err());
+    }
+}
@@ -0,0 +1,358 @@
+//! Native (slice-based) kernels of sparse matrices.
+//!
+//! All dense operands are row-major (c-contiguous).
+
+use num::Num;
+use rayon::prelude::*;
+use rstsr_core::prelude_dev::*;
+
+/* #region CSR times dense */
+
+#[inline]
+fn csr_matmul_dense_row<T>(c_row: &mut [T], row: usize, indptr: &[usize], indices: &[usize], data: &[T], b: &[T])
+where
+    T: Num + Clone,
+{
+    let k = c_row.len();
+    c_row.iter_mut().for_each(|c| *c = T::zero());
+    for p in indptr[row]..indptr[row + 1] {
+        let (j, a) = (indices[p], &data[p]);
+        let b_row = &b[j * k..(j + 1) * k];
+        c_row.iter_mut().zip(b_row).for_each(|(c, b)| *c = c.clone() + a.clone() * b.clone());
+    }
+}
+
+/// `c = A b`, with `A` of CSR `(nrow, ncol)`, `b` of shape `(ncol, k)` and `c` of shape
+/// `(nrow, k)`.
+pub fn csr_matmul_dense_serial<T>(c: &mut [T], indptr: &[usize], indices: &[usize], data: &[T], b: &[T], k: usize)
+where
+    T: Num + Clone,
+{
+    if k == 0 {
+        return;
+    }
+    c.chunks_exact_mut(k)
+        .enumerate()
+        .for_each(|(row, c_row)| csr_matmul_dense_row(c_row, row, indptr, indices, data, b));
+}
+
+/// `c = A b`, with `A` of CSR `(nrow, ncol)`, `b` of shape `(ncol, k)` and `c` of shape
+/// `(nrow, k)`; parallel over rows.
+pub fn csr_matmul_dense_rayon<T>(
+    c: &mut [T],
+    indptr: &[usize],
+    indices: &[usize],
+    data: &[T],
+    b: &[T],
+    k: usize,
+    pool: Option<&rayon::ThreadPool>,
+) where
+    T: Num + Clone + Send + Sync,
+{
+    if pool.is_none() || k == 0 {
+        return csr_matmul_dense_serial(c, indptr, indices, data, b, k);
+    }
+    let pool = pool.unwrap();
+    pool.install(|| {
+        c.par_chunks_exact_mut(k)
+            .enumerate()
+            .for_each(|(row, c_row)| csr_matmul_dense_row(c_row, row, indptr, indices, data, b))
+    });
+}
+
+/* #endregion */
+
+/* #region dense times CSR */
+
+#[inline]
+fn dense_matmul_csr_row<T>(c_row: &mut [T], b_row: &[T], indptr: &[usize], indices: &[usize], data: &[T])
+where
+    T: Num + Clone,
+{
+    c_row.iter_mut().for_each(|c| *c = T::zero());
+    for (k, bk) in b_row.iter().enumerate() {
+        for p in indptr[k]..indptr[k + 1] {
+            let j = indices[p];
+            c_row[j] = c_row[j].clone() + bk.clone() * data[p].clone();
+        }
+    }
+}
+
+/// `c = b A`, with `b` of shape `(m, nrow)`, `A` of CSR `(nrow, ncol)` and `c` of shape
+/// `(m, ncol)`.
+pub fn dense_matmul_csr_serial<T>(c: &mut [T], b: &[T], indptr: &[usize], indices: &[usize], data: &[T], ncol: usize)
+where
+    T: Num + Clone,
+{
+    let nrow = indptr.len() - 1;
+    if ncol == 0 || nrow == 0 {
+        c.iter_mut().for_each(|c| *c = T::zero());
+        return;
+    }
+    izip!(c.chunks_exact_mut(ncol), b.chunks_exact(nrow))
+        .for_each(|(c_row, b_row)| dense_matmul_csr_row(c_row, b_row, indptr, indices, data));
+}
+
+/// `c = b A`, with `b` of shape `(m, nrow)`, `A` of CSR `(nrow, ncol)` and `c` of shape
+/// `(m, ncol)`; parallel over rows of `b`.
+pub fn dense_matmul_csr_rayon<T>(
+    c: &mut [T],
+    b: &[T],
+    indptr: &[usize],
+    indices: &[usize],
+    data: &[T],
+    ncol: usize,
+    pool: Option<&rayon::ThreadPool>,
+) where
+    T: Num + Clone + Send + Sync,
+{
+    let nrow = indptr.len() - 1;
+    if pool.is_none() || ncol == 0 || nrow == 0 {
+        return dense_matmul_csr_serial(c, b, indptr, indices, data, ncol);
+    }
+    let pool = pool.unwrap();
+    pool.install(|| {
+        c.par_chunks_exact_mut(ncol)
+            .zip(b.par_chunks_exact(nrow))
+            .for_each(|(c_row, b_row)| dense_matmul_csr_row(c_row, b_row, indptr, indices, data))
+    });
+}
+
+/* #endregion */
+
+/* #region format conversion */
+
+/// Convert compressed format along major axis to compressed format along minor axis (CSR to
+/// CSC, or CSC to CSR). Indices of output are sorted.
+///
+/// Returns `(indptr, indices, data)` of output.
+#[allow(clippy::needless_range_loop)]
+pub fn compressed_transpose<T>(
+    nminor: usize,
+    indptr: &[usize],
+    indices: &[usize],
+    data: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Clone,
+{
+    let nmajor = indptr.len() - 1;
+    let nnz = indptr[nmajor];
+    let mut out_indptr = vec![0; nminor + 1];
+    indices[..nnz].iter().for_each(|&j| out_indptr[j + 1] += 1);
+    for j in 0..nminor {
+        out_indptr[j + 1] += out_indptr[j];
+    }
+    let mut next = out_indptr[..nminor].to_vec();
+    let mut out_indices = vec![0; nnz];
+    let mut perm = vec![0; nnz];
+    for i in 0..nmajor {
+        for p in indptr[i]..indptr[i + 1] {
+            let j = indices[p];
+            out_indices[next[j]] = i;
+            perm[next[j]] = p;
+            next[j] += 1;
+        }
+    }
+    let out_data = perm.into_iter().map(|p| data[p].clone()).collect();
+    (out_indptr, out_indices, out_data)
+}
+
+/// Compress triplets `(major, minor, value)` into compressed format. Duplicated entries are
+/// summed, and indices are sorted.
+///
+/// Returns `(indptr, indices, data)`.
+pub fn compress_triplets<T>(
+    nmajor: usize,
+    nminor: usize,
+    major: &[usize],
+    minor: &[usize],
+    data: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Num + Clone,
+{
+    // bucket by minor, then stable bucket by major gives sorted order
+    let nnz = data.len();
+    let tmp_indptr = {
+        let mut indptr = vec![0; nmajor + 1];
+        major.iter().for_each(|&i| indptr[i + 1] += 1);
+        (0..nmajor).for_each(|i| indptr[i + 1] += indptr[i]);
+        indptr
+    };
+    let (by_minor_indptr, by_minor_major, by_minor_perm) = {
+        let mut indptr = vec![0; nminor + 1];
+        minor.iter().for_each(|&j| indptr[j + 1] += 1);
+        (0..nminor).for_each(|j| indptr[j + 1] += indptr[j]);
+        let mut next = indptr[..nminor].to_vec();
+        let mut rows = vec![0; nnz];
+        let mut perm = vec![0; nnz];
+        for p in 0..nnz {
+            let j = minor[p];
+            rows[next[j]] = major[p];
+            perm[next[j]] = p;
+            next[j] += 1;
+        }
+        (indptr, rows, perm)
+    };
+    let mut next = tmp_indptr[..nmajor].to_vec();
+    let mut sorted_minor = vec![0; nnz];
+    let mut sorted_perm = vec![0; nnz];
+    for j in 0..nminor {
+        for q in by_minor_indptr[j]..by_minor_indptr[j + 1] {
+            let i = by_minor_major[q];
+            sorted_minor[next[i]] = j;
+            sorted_perm[next[i]] = by_minor_perm[q];
+            next[i] += 1;
+        }
+    }
+
+    // sum duplicates
+    let mut indptr = vec![0; nmajor + 1];
+    let mut indices = Vec::with_capacity(nnz);
+    let mut values: Vec<T> = Vec::with_capacity(nnz);
+    for i in 0..nmajor {
+        let row_start = indices.len();
+        for q in tmp_indptr[i]..tmp_indptr[i + 1] {
+            let (j, v) = (sorted_minor[q], data[sorted_perm[q]].clone());
+            if indices.len() > row_start && indices[indices.len() - 1] == j {
+                let last = values.last_mut().unwrap();
+                *last = last.clone() + v;
+            } else {
+                indices.push(j);
+                values.push(v);
+            }
+        }
+        indptr[i + 1] = indices.len();
+    }
+    (indptr, indices, values)
+}
+
+/// Check compressed format is canonical: `indptr` is non-decreasing, and indices in each major
+/// slice are strictly increasing and less than `nminor`.
+pub fn check_compressed(nmajor: usize, nminor: usize, indptr: &[usize], indices: &[usize], nnz: usize) -> Result<()> {
+    rstsr_assert_eq!(indptr.len(), nmajor + 1, InvalidLayout, "Length of indptr should be {}.", nmajor + 1)?;
+    rstsr_assert_eq!(indptr[0], 0, InvalidValue, "indptr should start with zero.")?;
+    rstsr_assert_eq!(indptr[nmajor], nnz, InvalidLayout, "Last element of indptr should equal to nnz.")?;
+    rstsr_assert_eq!(indices.len(), nnz, InvalidLayout, "Length of indices and data mismatch.")?;
+    for i in 0..nmajor {
+        rstsr_assert!(indptr[i] <= indptr[i + 1], InvalidValue, "indptr should be non-decreasing.")?;
+        let slice = &indices[indptr[i]..indptr[i + 1]];
+        rstsr_assert!(
+            slice.windows(2).all(|w| w[0] < w[1]),
+            InvalidValue,
+            "Indices should be sorted and unique in each row (column)."
+        )?;
+        if let Some(&last) = slice.last() {
+            rstsr_assert!(last < nminor, InvalidValue, "Index {last} out of bound {nminor}.")?;
+        }
+    }
+    Ok(())
+}
+
+/* #endregion */
+
+/* #region elementwise */
+
+/// Merge two compressed matrices of the same shape elementwise.
+///
+/// `f(a, b)` is applied on the union of sparsity patterns (absent entries are `None`); results
+/// of `None` are not stored. Indices should be canonical.
+pub fn compressed_binary_op<TA, TB, TC, F>(
+    a: (&[usize], &[usize], &[TA]),
+    b: (&[usize], &[usize], &[TB]),
+    mut f: F,
+) -> (Vec<usize>, Vec<usize>, Vec<TC>)
+where
+    F: FnMut(Option<&TA>, Option<&TB>) -> Option<TC>,
+{
+    let (a_indptr, a_indices, a_data) = a;
+    let (b_indptr, b_indices, b_data) = b;
+    let nmajor = a_indptr.len() - 1;
+    let mut indptr = vec![0; nmajor + 1];
+    let mut indices = vec![];
+    let mut data = vec![];
+    let push = |indices: &mut Vec<usize>, data: &mut Vec<TC>, j: usize, v: Option<TC>| {
+        if let Some(v) = v {
+            indices.push(j);
+            data.push(v);
+        }
+    };
+    for i in 0..nmajor {
+        let (mut p, p_end) = (a_indptr[i], a_indptr[i + 1]);
+        let (mut q, q_end) = (b_indptr[i], b_indptr[i + 1]);
+        while p < p_end || q < q_end {
+            let ja = if p < p_end { a_indices[p] } else { usize::MAX };
+            let jb = if q < q_end { b_indices[q] } else { usize::MAX };
+            match ja.cmp(&jb) {
+                core::cmp::Ordering::Less => {
+                    push(&mut indices, &mut data, ja, f(Some(&a_data[p]), None));
+                    p += 1;
+                },
+                core::cmp::Ordering::Greater => {
+                    push(&mut indices, &mut data, jb, f(None, Some(&b_data[q])));
+                    q += 1;
+                },
+                core::cmp::Ordering::Equal => {
+                    push(&mut indices, &mut data, ja, f(Some(&a_data[p]), Some(&b_data[q])));
+                    p += 1;
+                    q += 1;
+                },
+            }
+        }
+        indptr[i + 1] = indices.len();
+    }
+    (indptr, indices, data)
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_compress_and_transpose() {
+        // [[1, 0, 2], [0, 0, 3], [4, 5, 0]] with shuffled and duplicated triplets
+        let rows = [2, 0, 1, 2, 0, 2, 0];
+        let cols = [1, 2, 2, 0, 0, 1, 2];
+        let vals = [2.0, 1.5, 3.0, 4.0, 1.0, 3.0, 0.5];
+        let (indptr, indices, data) = compress_triplets(3, 3, &rows, &cols, &vals);
+        assert_eq!(indptr, [0, 2, 3, 5]);
+        assert_eq!(indices, [0, 2, 2, 0, 1]);
+        assert_eq!(data, [1.0, 2.0, 3.0, 4.0, 5.0]);
+        check_compressed(3, 3, &indptr, &indices, 5).unwrap();
+
+        let (t_indptr, t_indices, t_data) = compressed_transpose(3, &indptr, &indices, &data);
+        assert_eq!(t_indptr, [0, 2, 3, 5]);
+        assert_eq!(t_indices, [0, 2, 2, 0, 1]);
+        assert_eq!(t_data, [1.0, 4.0, 5.0, 2.0, 3.0]);
+
+        assert!(check_compressed(3, 2, &indptr, &indices, 5).is_err());
+        assert!(check_compressed(3, 3, &[0, 2, 1, 5], &indices, 5).is_err());
+    }
+
+    #[test]
+    fn test_kernels() {
+        let (indptr, indices, data) = ([0, 2, 3, 5], [0, 2, 2, 0, 1], [1.0, 2.0, 3.0, 4.0, 5.0]);
+        // A b with b of shape (3, 2)
+        let b = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut c = [f64::NAN; 6];
+        csr_matmul_dense_serial(&mut c, &indptr, &indices, &data, &b, 2);
+        assert_eq!(c, [11.0, 14.0, 15.0, 18.0, 19.0, 28.0]);
+        // b A with b of shape (2, 3)
+        let mut c = [f64::NAN; 6];
+        dense_matmul_csr_serial(&mut c, &b, &indptr, &indices, &data, 3);
+        assert_eq!(c, [13.0, 15.0, 8.0, 28.0, 30.0, 23.0]);
+    }
+
+    #[test]
+    fn test_kernels_non_finite() {
+        // zeros of the dense operand do not hide NaN/Inf of stored entries (0 * inf = NaN)
+        let (indptr, indices, data) = ([0, 1, 2], [0, 1], [f64::INFINITY, f64::NAN]);
+        let b = [0.0, 1.0, 0.0, 0.0];
+        let mut c = [0.0; 4];
+        dense_matmul_csr_serial(&mut c, &b, &indptr, &indices, &data, 2);
+        assert!(c.iter().all(|c| c.is_nan()));
+    }
+}
@@ -0,0 +1,153 @@
+//! Elementwise arithmetics of sparse matrices.
+//!
+//! Binary operations are defined between sparse matrices of the same format and shape:
+//! - `+` and `-` on union of sparsity patterns (explicit zeros from cancellation are kept);
+//! - `*` is the elementwise (Hadamard) product, on intersection of sparsity patterns.
+//!
+//! Scalar `*` and `/`, and negation, keep the sparsity pattern.
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::native_impl::compressed_binary_op;
+use crate::prelude_dev::*;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num::Num;
+
+#[duplicate_item(SpType; [CsrMatrix]; [CscMatrix])]
+impl<T, B> SpType<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    /// Elementwise binary operation on union of sparsity patterns; see [`compressed_binary_op`].
+    fn binary_op_f<F>(&self, other: &Self, f: F) -> Result<Self>
+    where
+        F: FnMut(Option<&T>, Option<&T>) -> Option<T>,
+    {
+        rstsr_assert_eq!(self.shape, other.shape, InvalidLayout, "Shape of sparse matrices mismatch.")?;
+        rstsr_assert!(self.device().same_device(other.device()), DeviceMismatch)?;
+        let (indptr, indices, data) = compressed_binary_op(
+            (&self.indptr, &self.indices, self.data.raw()),
+            (&other.indptr, &other.indices, other.data.raw()),
+            f,
+        );
+        Self::from_raw_parts_f(self.shape, indptr, indices, data, self.device())
+    }
+
+    /// Apply `f` on stored values, keeping sparsity pattern.
+    fn map_values(&self, f: impl FnMut(&T) -> T) -> Self {
+        let data = self.data.raw().iter().map(f).collect_vec();
+        Self::from_raw_parts_f(self.shape, self.indptr.clone(), self.indices.clone(), data, self.device())
+            .rstsr_unwrap()
+    }
+}
+
+/* #region sparse-sparse */
+
+#[duplicate_item(
+    SpType       Op    op    f_both        f_left      f_right                   ;
+   [CsrMatrix]  [Add] [add] [a.clone() + b.clone()] [a.clone()] [b.clone()]            ;
+   [CsrMatrix]  [Sub] [sub] [a.clone() - b.clone()] [a.clone()] [T::zero() - b.clone()];
+   [CscMatrix]  [Add] [add] [a.clone() + b.clone()] [a.clone()] [b.clone()]            ;
+   [CscMatrix]  [Sub] [sub] [a.clone() - b.clone()] [a.clone()] [T::zero() - b.clone()];
+)]
+impl<T, B> Op<&SpType<T, B>> for &SpType<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    type Output = SpType<T, B>;
+    fn op(self, rhs: &SpType<T, B>) -> Self::Output {
+        self.binary_op_f(rhs, |a, b| match (a, b) {
+            (Some(a), Some(b)) => Some(f_both),
+            (Some(a), None) => Some(f_left),
+            (None, Some(b)) => Some(f_right),
+            (None, None) => None,
+        })
+        .rstsr_unwrap()
+    }
+}
+
+#[duplicate_item(SpType; [CsrMatrix]; [CscMatrix])]
+impl<T, B> Mul<&SpType<T, B>> for &SpType<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    type Output = SpType<T, B>;
+    fn mul(self, rhs: &SpType<T, B>) -> Self::Output {
+        self.binary_op_f(rhs, |a, b| match (a, b) {
+            (Some(a), Some(b)) => Some(a.clone() * b.clone()),
+            _ => None,
+        })
+        .rstsr_unwrap()
+    }
+}
+
+/* #endregion */
+
+/* #region sparse-scalar */
+
+#[duplicate_item(
+    SpType       Op    op  ;
+   [CsrMatrix]  [Mul] [mul];
+   [CsrMatrix]  [Div] [div];
+   [CscMatrix]  [Mul] [mul];
+   [CscMatrix]  [Div] [div];
+)]
+impl<T, B> Op<T> for &SpType<T, B>
+where
+    T: Num + Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    type Output = SpType<T, B>;
+    fn op(self, rhs: T) -> Self::Output {
+        self.map_values(|a| a.clone().op(rhs.clone()))
+    }
+}
+
+#[duplicate_item(SpType; [CsrMatrix]; [CscMatrix])]
+impl<T, B> Neg for &SpType<T, B>
+where
+    T: Num + Clone + Neg<Output = T>,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+{
+    type Output = SpType<T, B>;
+    fn neg(self) -> Self::Output {
+        self.map_values(|a| -a.clone())
+    }
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sparse_elementwise() {
+        let device = DeviceCpuSerial::default();
+        let a = asarray((vec![1.0, 0.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0], [3, 3].c(), &device));
+        let b = asarray((vec![0.0, 5.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 6.0], [3, 3].c(), &device));
+        let (sa, sb) = (CsrMatrix::from_dense(a.view(), 0.0), CsrMatrix::from_dense(b.view(), 0.0));
+
+        let s = &sa + &sb;
+        assert_eq!(s.nnz(), 6);
+        assert!(allclose_f64(&s.to_dense(), &(&a + &b)));
+        // cancellation keeps explicit zero
+        let s = &sa - &sb;
+        assert_eq!(s.nnz(), 6);
+        assert!(allclose_f64(&s.to_dense(), &(&a - &b)));
+        let s = &sa * &sb;
+        assert_eq!(s.nnz(), 2);
+        assert!(allclose_f64(&s.to_dense(), &(&a * &b)));
+        assert!(allclose_f64(&(&sa * 2.0).to_dense(), &(&a * 2.0)));
+        assert!(allclose_f64(&(&sa / 2.0).to_dense(), &(&a / 2.0)));
+        assert!(allclose_f64(&(-&sa).to_dense(), &(-&a)));
+
+        let (ca, cb) = (sa.to_csc(), sb.to_csc());
+        assert!(allclose_f64(&(&ca - &cb).to_dense(), &(&a - &b)));
+        assert!(allclose_f64(&(&ca * &cb).to_dense(), &(&a * &b)));
+        assert!(allclose_f64(&(-&(&ca * 3.0)).to_dense(), &(&a * -3.0)));
+    }
+}
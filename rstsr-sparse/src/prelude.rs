@@ -0,0 +1,9 @@
+pub mod rstsr_traits {
+    pub use crate::traits::DeviceSparseAPI;
+}
+
+pub mod rstsr_structs {
+    pub use crate::coo::CooMatrix;
+    pub use crate::csc::CscMatrix;
+    pub use crate::csr::CsrMatrix;
+}
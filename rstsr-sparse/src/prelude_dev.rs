@@ -0,0 +1,7 @@
+#![allow(unused_imports)]
+
+pub(crate) use crate as rstsr_sparse;
+pub(crate) use rstsr_core::prelude_dev::*;
+
+#[cfg(feature = "faer")]
+pub(crate) type DeviceRayonAutoImpl = rstsr_core::prelude_dev::DeviceFaer;
@@ -0,0 +1,48 @@
+use rstsr_core::prelude_dev::*;
+
+/// Sparse-dense matrix multiplication kernels, dispatched by device.
+///
+/// Sparse matrices are given in CSR (`indptr`, `indices`, `data`); dense operands and outputs
+/// are contiguous (row-major). Devices with rayon parallelize over rows of output.
+pub trait DeviceSparseAPI<T>
+where
+    Self: DeviceAPI<T>,
+{
+    /// `c = A b`, with `A` of CSR `(nrow, ncol)`, `b` of shape `(ncol, k)` and `c` of shape
+    /// `(nrow, k)`.
+    fn csr_matmul_dense_f(
+        &self,
+        c: &mut [T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        b: &[T],
+        k: usize,
+    ) -> Result<()>;
+
+    /// `c = b A`, with `b` of shape `(m, nrow)`, `A` of CSR `(nrow, ncol)` and `c` of shape
+    /// `(m, ncol)`.
+    fn dense_matmul_csr_f(
+        &self,
+        c: &mut [T],
+        b: &[T],
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+        ncol: usize,
+    ) -> Result<()>;
+}
+
+/// Data of tensor in c-contiguous order, together with its shape.
+pub(crate) fn sparse_to_vec_f<T, B, D>(x: TensorView<'_, T, B, D>) -> Result<(Vec<T>, Vec<usize>)>
+where
+    T: Clone,
+    B: DeviceAPI<T, Raw = Vec<T>> + DeviceCreationAnyAPI<T> + OpAssignArbitaryAPI<T, IxD, IxD>,
+    D: DimAPI,
+{
+    let x = x.into_dim_f::<IxD>()?;
+    let shape = x.shape().clone();
+    let size = x.size();
+    let x = x.change_layout_f(shape.c())?;
+    Ok((x.raw()[..size].to_vec(), shape))
+}
@@ -10,6 +10,11 @@ pub mod rstsr_traits {
         pub use rstsr_sci_traits::prelude::rstsr_traits::*;
     }
 
+    #[cfg(feature = "sparse")]
+    pub mod sparse {
+        pub use rstsr_sparse::prelude::rstsr_traits::*;
+    }
+
     #[cfg(feature = "tblis")]
     pub mod tblis {
         pub use rstsr_tblis::prelude::rstsr_traits::*;
@@ -28,6 +33,11 @@ pub mod rstsr_structs {
         pub use rstsr_sci_traits::prelude::rstsr_structs::*;
     }
 
+    #[cfg(feature = "sparse")]
+    pub mod sparse {
+        pub use rstsr_sparse::prelude::rstsr_structs::*;
+    }
+
     #[cfg(feature = "aocl")]
     pub use rstsr_aocl::DeviceAOCL;
     #[cfg(feature = "blis")]
@@ -38,13 +48,16 @@ pub mod rstsr_structs {
     pub use rstsr_mkl::DeviceMKL;
     #[cfg(feature = "openblas")]
     pub use rstsr_openblas::DeviceOpenBLAS;
+    #[cfg(feature = "refblas")]
+    pub use rstsr_refblas::DeviceRefBLAS;
 
     #[cfg(all(
         feature = "openblas",
         not(feature = "mkl"),
         not(feature = "blis"),
         not(feature = "aocl"),
-        not(feature = "kml")
+        not(feature = "kml"),
+        not(feature = "refblas")
     ))]
     pub type DeviceBLAS = DeviceOpenBLAS;
     #[cfg(all(
@@ -52,7 +65,8 @@ pub mod rstsr_structs {
         feature = "mkl",
         not(feature = "blis"),
         not(feature = "aocl"),
-        not(feature = "kml")
+        not(feature = "kml"),
+        not(feature = "refblas")
     ))]
     pub type DeviceBLAS = DeviceMKL;
     #[cfg(all(
@@ -60,7 +74,8 @@ pub mod rstsr_structs {
         not(feature = "mkl"),
         feature = "blis",
         not(feature = "aocl"),
-        not(feature = "kml")
+        not(feature = "kml"),
+        not(feature = "refblas")
     ))]
     pub type DeviceBLAS = DeviceBLIS;
     #[cfg(all(
@@ -68,7 +83,8 @@ pub mod rstsr_structs {
         not(feature = "mkl"),
         not(feature = "blis"),
         feature = "aocl",
-        not(feature = "kml")
+        not(feature = "kml"),
+        not(feature = "refblas")
     ))]
     pub type DeviceBLAS = DeviceAOCL;
     #[cfg(all(
@@ -76,9 +92,19 @@ pub mod rstsr_structs {
         not(feature = "mkl"),
         not(feature = "blis"),
         not(feature = "aocl"),
-        feature = "kml"
+        feature = "kml",
+        not(feature = "refblas")
     ))]
     pub type DeviceBLAS = DeviceKML;
+    #[cfg(all(
+        not(feature = "openblas"),
+        not(feature = "mkl"),
+        not(feature = "blis"),
+        not(feature = "aocl"),
+        not(feature = "kml"),
+        feature = "refblas"
+    ))]
+    pub type DeviceBLAS = DeviceRefBLAS;
 }
 
 pub mod rstsr_funcs {
@@ -123,6 +149,12 @@ pub mod sci {
     pub use rstsr_sci_traits::prelude::rstsr_traits::*;
 }
 
+#[cfg(feature = "sparse")]
+pub mod sparse {
+    pub use rstsr_sparse::prelude::rstsr_structs::*;
+    pub use rstsr_sparse::prelude::rstsr_traits::*;
+}
+
 #[cfg(feature = "tblis")]
 pub mod tblis {
     pub use rstsr_tblis::prelude::rstsr_funcs::*;
@@ -146,6 +178,9 @@ pub mod rt {
     #[cfg(feature = "sci")]
     pub use super::sci;
 
+    #[cfg(feature = "sparse")]
+    pub use super::sparse;
+
     #[cfg(feature = "tblis")]
     pub use super::tblis;
 